@@ -71,11 +71,6 @@ where
     Idx: ArenaIndex,
     T: Ord + Clone,
 {
-    /// Returns the next entity index.
-    fn next_index(&self) -> Idx {
-        self.entities.next_index()
-    }
-
     /// Allocates a new entity and returns its index.
     ///
     /// # Note
@@ -85,9 +80,8 @@ where
         match self.entity2idx.get(&entity) {
             Some(index) => *index,
             None => {
-                let index = self.next_index();
-                self.entity2idx.insert(entity.clone(), index);
-                self.entities.alloc(entity);
+                let index = self.entities.alloc(entity.clone());
+                self.entity2idx.insert(entity, index);
                 index
             }
         }
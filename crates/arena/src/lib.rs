@@ -1,6 +1,7 @@
 //! Fast arena allocators for different usage purposes.
 //!
-//! They cannot deallocate single allocated entities for extra efficiency.
+//! Slots of removed entities are reused for new entities. Indices carry the
+//! generation of their slot so that stale indices of removed entities are detected.
 //! These allocators mainly serve as the backbone for an efficient Wasm store
 //! implementation.
 
@@ -29,7 +30,7 @@ mod guarded;
 mod tests;
 
 pub use self::{component_vec::ComponentVec, dedup::DedupArena, guarded::GuardedEntity};
-use alloc::vec::Vec;
+use alloc::{collections::VecDeque, vec::Vec};
 use core::{
     iter::{DoubleEndedIterator, Enumerate, ExactSizeIterator},
     marker::PhantomData,
@@ -45,12 +46,88 @@ pub trait ArenaIndex: Copy {
     fn from_usize(value: usize) -> Self;
 }
 
-/// An arena allocator with a given index and entity type.
+/// The number of bits of an index that refer to the slot of its entity.
 ///
-/// For performance reasons the arena cannot deallocate single entities.
+/// The remaining bits of an index refer to the generation of the slot.
+const SLOT_BITS: u32 = 24;
+
+/// The maximum number of slots of an [`Arena`].
+///
+/// # Note
+///
+/// This is one less than what fits into [`SLOT_BITS`] so that no index
+/// is ever `u32::MAX` and indices with a niche at zero can be offset by one.
+const MAX_SLOTS: usize = (1 << SLOT_BITS) - 1;
+
+/// Returns the index of the entity in `slot` with the given `generation`.
+fn encode_index(slot: usize, generation: u8) -> usize {
+    debug_assert!(slot < MAX_SLOTS);
+    slot | (usize::from(generation) << SLOT_BITS)
+}
+
+/// Returns the slot and generation of the entity at `index`.
+///
+/// Returns `None` if `index` has not been encoded by [`encode_index`].
+fn decode_index(index: usize) -> Option<(usize, u8)> {
+    let generation = u8::try_from(index >> SLOT_BITS).ok()?;
+    let slot = index & ((1 << SLOT_BITS) - 1);
+    Some((slot, generation))
+}
+
+/// A slot of an [`Arena`] holding an entity unless the entity has been removed.
+#[derive(Debug, PartialEq, Eq)]
+struct Slot<T> {
+    /// The generation of the slot.
+    ///
+    /// This is incremented whenever the entity of the slot is removed.
+    generation: u8,
+    /// The entity of the slot if any.
+    entity: Option<T>,
+}
+
+impl<T> Slot<T> {
+    /// Returns the entity of the slot if its generation is `generation`.
+    fn get(&self, generation: u8) -> Option<&T> {
+        if self.generation != generation {
+            return None;
+        }
+        self.entity.as_ref()
+    }
+
+    /// Returns the entity of the slot if its generation is `generation`.
+    fn get_mut(&mut self, generation: u8) -> Option<&mut T> {
+        if self.generation != generation {
+            return None;
+        }
+        self.entity.as_mut()
+    }
+}
+
+/// A generational arena allocator with a given index and entity type.
+///
+/// # Note
+///
+/// Removing an entity makes its slot vacant and the [`Arena`] reuses vacant
+/// slots for new entities. Each slot has a generation that is incremented
+/// upon removal and that is encoded into the indices of its entities.
+/// Therefore stale indices of removed entities do not resolve to the new
+/// entity of their slot.
+///
+/// # Dev. Note
+///
+/// Indices encode the slot in their lower [`SLOT_BITS`] bits and the
+/// generation in the upper 8 bits so that they fit into 32 bits.
+/// Generations wrap around. Vacant slots are reused in the order in which
+/// they have been freed so that a slot is reused as late as possible.
+/// Therefore a stale index is only confused with a new entity if its slot
+/// has been reused a multiple of 256 times in the meantime.
 #[derive(Debug)]
 pub struct Arena<Idx, T> {
-    entities: Vec<T>,
+    entities: Vec<Slot<T>>,
+    /// The vacant slots in the order in which they have been freed.
+    free: VecDeque<usize>,
+    /// The number of entities that have not been removed.
+    len: usize,
     marker: PhantomData<Idx>,
 }
 
@@ -82,14 +159,16 @@ impl<Idx, T> Arena<Idx, T> {
     pub fn new() -> Self {
         Self {
             entities: Vec::new(),
+            free: VecDeque::new(),
+            len: 0,
             marker: PhantomData,
         }
     }
 
-    /// Returns the allocated number of entities.
+    /// Returns the number of allocated entities that have not been removed.
     #[inline]
     pub fn len(&self) -> usize {
-        self.entities.len()
+        self.len
     }
 
     /// Returns `true` if the arena has not yet allocated entities.
@@ -101,12 +180,15 @@ impl<Idx, T> Arena<Idx, T> {
     /// Clears all entities from the arena.
     pub fn clear(&mut self) {
         self.entities.clear();
+        self.free.clear();
+        self.len = 0;
     }
 
     /// Returns an iterator over the shared reference of the arena entities.
     pub fn iter(&self) -> Iter<Idx, T> {
         Iter {
             iter: self.entities.iter().enumerate(),
+            len: self.len,
             marker: PhantomData,
         }
     }
@@ -115,6 +197,7 @@ impl<Idx, T> Arena<Idx, T> {
     pub fn iter_mut(&mut self) -> IterMut<Idx, T> {
         IterMut {
             iter: self.entities.iter_mut().enumerate(),
+            len: self.len,
             marker: PhantomData,
         }
     }
@@ -124,29 +207,70 @@ impl<Idx, T> Arena<Idx, T>
 where
     Idx: ArenaIndex,
 {
-    /// Returns the next entity index.
-    fn next_index(&self) -> Idx {
-        Idx::from_usize(self.entities.len())
-    }
-
     /// Allocates a new entity and returns its index.
+    ///
+    /// # Note
+    ///
+    /// Reuses the slot of a removed entity if any.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Arena`] is out of slots.
     #[inline]
     pub fn alloc(&mut self, entity: T) -> Idx {
-        let index = self.next_index();
-        self.entities.push(entity);
-        index
+        let slot = match self.free.pop_front() {
+            Some(slot) => slot,
+            None => {
+                let slot = self.entities.len();
+                assert!(slot < MAX_SLOTS, "out of arena slots for index {slot}");
+                self.entities.push(Slot {
+                    generation: 0,
+                    entity: None,
+                });
+                slot
+            }
+        };
+        let vacant = &mut self.entities[slot];
+        vacant.entity = Some(entity);
+        self.len += 1;
+        Idx::from_usize(encode_index(slot, vacant.generation))
+    }
+
+    /// Removes the entity at the given index and returns it if any.
+    ///
+    /// # Note
+    ///
+    /// The slot of the removed entity is reused by later allocations
+    /// and the given index no longer resolves to any entity.
+    pub fn remove(&mut self, index: Idx) -> Option<T> {
+        let (slot, generation) = decode_index(index.into_usize())?;
+        let occupied = self.entities.get_mut(slot)?;
+        if occupied.generation != generation {
+            return None;
+        }
+        let entity = occupied.entity.take()?;
+        occupied.generation = occupied.generation.wrapping_add(1);
+        self.free.push_back(slot);
+        self.len -= 1;
+        Some(entity)
     }
 
     /// Returns a shared reference to the entity at the given index if any.
+    ///
+    /// Returns `None` if the entity at the given index has been removed.
     #[inline]
     pub fn get(&self, index: Idx) -> Option<&T> {
-        self.entities.get(index.into_usize())
+        let (slot, generation) = decode_index(index.into_usize())?;
+        self.entities.get(slot)?.get(generation)
     }
 
     /// Returns an exclusive reference to the entity at the given index if any.
+    ///
+    /// Returns `None` if the entity at the given index has been removed.
     #[inline]
     pub fn get_mut(&mut self, index: Idx) -> Option<&mut T> {
-        self.entities.get_mut(index.into_usize())
+        let (slot, generation) = decode_index(index.into_usize())?;
+        self.entities.get_mut(slot)?.get_mut(generation)
     }
 
     /// Returns an exclusive reference to the pair of entities at the given indices if any.
@@ -155,8 +279,8 @@ where
     /// Returns `None` if either `fst` or `snd` is invalid for this [`Arena`].
     #[inline]
     pub fn get_pair_mut(&mut self, fst: Idx, snd: Idx) -> Option<(&mut T, &mut T)> {
-        let fst_index = fst.into_usize();
-        let snd_index = snd.into_usize();
+        let (fst_index, fst_generation) = decode_index(fst.into_usize())?;
+        let (snd_index, snd_generation) = decode_index(snd.into_usize())?;
         if fst_index == snd_index {
             return None;
         }
@@ -166,8 +290,8 @@ where
         }
         // At this point we know that fst_index < snd_index.
         let (fst_set, snd_set) = self.entities.split_at_mut(snd_index);
-        let fst = fst_set.get_mut(fst_index)?;
-        let snd = snd_set.get_mut(0)?;
+        let fst = fst_set.get_mut(fst_index)?.get_mut(fst_generation)?;
+        let snd = snd_set.get_mut(0)?.get_mut(snd_generation)?;
         Some((fst, snd))
     }
 }
@@ -177,8 +301,17 @@ impl<Idx, T> FromIterator<T> for Arena<Idx, T> {
     where
         I: IntoIterator<Item = T>,
     {
+        let entities = iter
+            .into_iter()
+            .map(|entity| Slot {
+                generation: 0,
+                entity: Some(entity),
+            })
+            .collect::<Vec<_>>();
         Self {
-            entities: Vec::from_iter(iter),
+            len: entities.len(),
+            entities,
+            free: VecDeque::new(),
             marker: PhantomData,
         }
     }
@@ -211,7 +344,9 @@ where
 /// An iterator over shared references of arena entities and their indices.
 #[derive(Debug)]
 pub struct Iter<'a, Idx, T> {
-    iter: Enumerate<slice::Iter<'a, T>>,
+    iter: Enumerate<slice::Iter<'a, Slot<T>>>,
+    /// The number of remaining entities that have not been removed.
+    len: usize,
     marker: PhantomData<fn() -> Idx>,
 }
 
@@ -223,14 +358,17 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|(idx, entity)| (Idx::from_usize(idx), entity))
+        let (idx, entity) = self.iter.find_map(|(idx, slot)| {
+            let entity = slot.entity.as_ref()?;
+            Some((encode_index(idx, slot.generation), entity))
+        })?;
+        self.len -= 1;
+        Some((Idx::from_usize(idx), entity))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        (self.len, Some(self.len))
     }
 }
 
@@ -240,9 +378,15 @@ where
 {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|(idx, entity)| (Idx::from_usize(idx), entity))
+        let (idx, entity) = self
+            .iter
+            .rfind(|(_, slot)| slot.entity.is_some())
+            .and_then(|(idx, slot)| {
+                let entity = slot.entity.as_ref()?;
+                Some((encode_index(idx, slot.generation), entity))
+            })?;
+        self.len -= 1;
+        Some((Idx::from_usize(idx), entity))
     }
 }
 
@@ -251,14 +395,16 @@ where
     Idx: ArenaIndex,
 {
     fn len(&self) -> usize {
-        self.iter.len()
+        self.len
     }
 }
 
 /// An iterator over exclusive references of arena entities and their indices.
 #[derive(Debug)]
 pub struct IterMut<'a, Idx, T> {
-    iter: Enumerate<slice::IterMut<'a, T>>,
+    iter: Enumerate<slice::IterMut<'a, Slot<T>>>,
+    /// The number of remaining entities that have not been removed.
+    len: usize,
     marker: PhantomData<fn() -> Idx>,
 }
 
@@ -270,14 +416,17 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|(idx, entity)| (Idx::from_usize(idx), entity))
+        let (idx, entity) = self.iter.find_map(|(idx, slot)| {
+            let entity = slot.entity.as_mut()?;
+            Some((encode_index(idx, slot.generation), entity))
+        })?;
+        self.len -= 1;
+        Some((Idx::from_usize(idx), entity))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        (self.len, Some(self.len))
     }
 }
 
@@ -287,9 +436,15 @@ where
 {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|(idx, entity)| (Idx::from_usize(idx), entity))
+        let (idx, entity) = self
+            .iter
+            .rfind(|(_, slot)| slot.entity.is_some())
+            .and_then(|(idx, slot)| {
+                let entity = slot.entity.as_mut()?;
+                Some((encode_index(idx, slot.generation), entity))
+            })?;
+        self.len -= 1;
+        Some((Idx::from_usize(idx), entity))
     }
 }
 
@@ -299,7 +454,7 @@ where
 {
     #[inline]
     fn len(&self) -> usize {
-        self.iter.len()
+        self.len
     }
 }
 
@@ -86,6 +86,55 @@ mod arena {
         // there is no deduplication of equal entities.
         assert_eq!(arena.len(), previous_len + TEST_ENTITIES.len());
     }
+
+    #[test]
+    fn remove_works() {
+        let mut arena = alloc_arena(TEST_ENTITIES);
+        // Removing an entity makes its index stale.
+        assert_eq!(arena.remove(1), Some("b"));
+        assert_eq!(arena.remove(1), None);
+        assert_eq!(arena.get(1), None);
+        assert_eq!(arena.get_pair_mut(0, 1), None);
+        assert_eq!(arena.len(), TEST_ENTITIES.len() - 1);
+        // Iterators skip removed entities.
+        let expected = [(0, &"a"), (2, &"c"), (3, &"d")];
+        assert!(arena.iter().eq(expected.iter().copied()));
+        assert!(arena.iter().rev().eq(expected.iter().rev().copied()));
+        assert_eq!(arena.iter().len(), expected.len());
+        // The slot of the removed entity is reused with a new generation.
+        let reused = arena.alloc("e");
+        assert_eq!(reused, encode_index(1, 1));
+        assert_eq!(arena.get(reused), Some(&"e"));
+        assert_eq!(arena.get(1), None);
+        assert_eq!(arena.remove(1), None);
+        assert_eq!(arena.len(), TEST_ENTITIES.len());
+        assert_eq!(arena.iter().nth(1), Some((reused, &"e")));
+    }
+
+    #[test]
+    fn vacant_slots_are_reused_in_order() {
+        let mut arena = alloc_arena(TEST_ENTITIES);
+        assert_eq!(arena.remove(2), Some("c"));
+        assert_eq!(arena.remove(0), Some("a"));
+        assert_eq!(arena.alloc("e"), encode_index(2, 1));
+        assert_eq!(arena.alloc("f"), encode_index(0, 1));
+        // Without vacant slots new slots are allocated.
+        assert_eq!(arena.alloc("g"), TEST_ENTITIES.len());
+    }
+
+    #[test]
+    fn generations_wrap_around() {
+        let mut arena = <Arena<usize, &'static str>>::new();
+        let mut index = arena.alloc("a");
+        for generation in 1..=u32::from(u8::MAX) {
+            assert_eq!(arena.remove(index), Some("a"));
+            index = arena.alloc("a");
+            assert_eq!(index, encode_index(0, generation as u8));
+        }
+        assert_eq!(arena.remove(index), Some("a"));
+        assert_eq!(arena.alloc("b"), 0);
+        assert_eq!(arena.len(), 1);
+    }
 }
 
 mod dedup_arena {
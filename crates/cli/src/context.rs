@@ -28,6 +28,8 @@ impl Context {
         if fuel.is_some() {
             config.consume_fuel(true);
         }
+        #[cfg(feature = "simd")]
+        config.wasm_simd(true);
         let engine = wasmi::Engine::new(&config);
         let wasm_bytes = utils::read_wasm_or_wat(wasm_file)?;
         let module = wasmi::Module::new(&engine, &mut &wasm_bytes[..]).map_err(|error| {
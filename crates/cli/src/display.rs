@@ -18,6 +18,8 @@ impl Display for DisplayValueType<'_> {
             ValueType::I64 => write!(f, "i64"),
             ValueType::F32 => write!(f, "f32"),
             ValueType::F64 => write!(f, "f64"),
+            #[cfg(feature = "simd")]
+            ValueType::V128 => write!(f, "v128"),
             ValueType::FuncRef => write!(f, "funcref"),
             ValueType::ExternRef => write!(f, "externref"),
         }
@@ -40,6 +42,8 @@ impl<'a> fmt::Display for DisplayValue<'a> {
             Value::I64(value) => write!(f, "{value}"),
             Value::F32(value) => write!(f, "{value}"),
             Value::F64(value) => write!(f, "{value}"),
+            #[cfg(feature = "simd")]
+            Value::V128(value) => write!(f, "{:#034x}", value.to_bits()),
             Value::FuncRef(value) => panic!("cannot display funcref values but found {value:?}"),
             Value::ExternRef(value) => {
                 panic!("cannot display externref values but found {value:?}")
@@ -80,6 +80,12 @@ pub fn decode_func_args(ty: &FuncType, args: &[String]) -> Result<Box<[Value]>,
                     .map(F64::from)
                     .map(Value::from)
                     .map_err(make_err!()),
+                #[cfg(feature = "simd")]
+                ValueType::V128 => arg
+                    .parse::<u128>()
+                    .map(wasmi::core::V128::from)
+                    .map(Value::from)
+                    .map_err(make_err!()),
                 ValueType::FuncRef => {
                     bail!("the wasmi CLI cannot take arguments of type funcref")
                 }
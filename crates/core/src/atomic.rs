@@ -0,0 +1,469 @@
+use crate::{untyped::effective_address, TrapCode, UntypedValue};
+use core::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8, Ordering};
+
+/// The read-modify-write operation of an atomic Wasm instruction.
+///
+/// # Note
+///
+/// This is used by the `atomic.rmw` Wasm operations of the [`threads`] proposal.
+///
+/// [`threads`]: https://github.com/WebAssembly/threads
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AtomicRmwOp {
+    /// Wrapping addition: `*x = *x + value`
+    Add,
+    /// Wrapping subtraction: `*x = *x - value`
+    Sub,
+    /// Bitwise and: `*x = *x & value`
+    And,
+    /// Bitwise or: `*x = *x | value`
+    Or,
+    /// Bitwise exclusive or: `*x = *x ^ value`
+    Xor,
+    /// Exchange: `*x = value`
+    Xchg,
+}
+
+/// Integer types that can be atomically accessed in a linear memory.
+trait AtomicInt: Copy {
+    /// The number of bytes of the integer type.
+    const SIZE: usize;
+
+    /// Converts the lower bits of the [`UntypedValue`] into `Self`.
+    fn from_untyped(value: UntypedValue) -> Self;
+
+    /// Zero-extends `self` into an [`UntypedValue`].
+    fn into_untyped(self) -> UntypedValue;
+
+    /// Applies the read-modify-write `op` to `self` and `value`.
+    fn apply(self, op: AtomicRmwOp, value: Self) -> Self;
+
+    /// Atomically loads `Self` from `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `Self::SIZE` bytes.
+    unsafe fn load(ptr: *mut u8) -> Self;
+
+    /// Atomically stores `value` at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `Self::SIZE` bytes.
+    unsafe fn store(ptr: *mut u8, value: Self);
+
+    /// Atomically applies `op` to the value at `ptr` and returns the old value.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `Self::SIZE` bytes.
+    unsafe fn rmw(ptr: *mut u8, op: AtomicRmwOp, value: Self) -> Self;
+
+    /// Atomically replaces the value at `ptr` with `replacement` if it equals `expected`.
+    ///
+    /// Returns the old value at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `Self::SIZE` bytes.
+    unsafe fn cmpxchg(ptr: *mut u8, expected: Self, replacement: Self) -> Self;
+}
+
+macro_rules! impl_atomic_int {
+    ( $( $ty:ty => $atomic:ty ),* $(,)? ) => {
+        $(
+            impl AtomicInt for $ty {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                fn from_untyped(value: UntypedValue) -> Self {
+                    Self::from(value)
+                }
+
+                fn into_untyped(self) -> UntypedValue {
+                    UntypedValue::from(self)
+                }
+
+                fn apply(self, op: AtomicRmwOp, value: Self) -> Self {
+                    match op {
+                        AtomicRmwOp::Add => self.wrapping_add(value),
+                        AtomicRmwOp::Sub => self.wrapping_sub(value),
+                        AtomicRmwOp::And => self & value,
+                        AtomicRmwOp::Or => self | value,
+                        AtomicRmwOp::Xor => self ^ value,
+                        AtomicRmwOp::Xchg => value,
+                    }
+                }
+
+                unsafe fn load(ptr: *mut u8) -> Self {
+                    if ptr.align_offset(Self::SIZE) != 0 {
+                        // Only linear memories that are exclusively owned by a single
+                        // `Store` may be unaligned in their base address. Therefore
+                        // there cannot be concurrent accesses to them.
+                        return Self::from_le(ptr.cast::<Self>().read_unaligned());
+                    }
+                    Self::from_le(<$atomic>::from_ptr(ptr.cast()).load(Ordering::SeqCst))
+                }
+
+                unsafe fn store(ptr: *mut u8, value: Self) {
+                    if ptr.align_offset(Self::SIZE) != 0 {
+                        return ptr.cast::<Self>().write_unaligned(value.to_le());
+                    }
+                    <$atomic>::from_ptr(ptr.cast()).store(value.to_le(), Ordering::SeqCst)
+                }
+
+                unsafe fn rmw(ptr: *mut u8, op: AtomicRmwOp, value: Self) -> Self {
+                    if ptr.align_offset(Self::SIZE) != 0 {
+                        let old = Self::from_le(ptr.cast::<Self>().read_unaligned());
+                        ptr.cast::<Self>().write_unaligned(old.apply(op, value).to_le());
+                        return old;
+                    }
+                    let old = <$atomic>::from_ptr(ptr.cast())
+                        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |raw| {
+                            Some(Self::from_le(raw).apply(op, value).to_le())
+                        })
+                        .unwrap_or_else(|raw| raw);
+                    Self::from_le(old)
+                }
+
+                unsafe fn cmpxchg(ptr: *mut u8, expected: Self, replacement: Self) -> Self {
+                    if ptr.align_offset(Self::SIZE) != 0 {
+                        let old = Self::from_le(ptr.cast::<Self>().read_unaligned());
+                        if old == expected {
+                            ptr.cast::<Self>().write_unaligned(replacement.to_le());
+                        }
+                        return old;
+                    }
+                    let old = <$atomic>::from_ptr(ptr.cast())
+                        .compare_exchange(
+                            expected.to_le(),
+                            replacement.to_le(),
+                            Ordering::SeqCst,
+                            Ordering::SeqCst,
+                        )
+                        .unwrap_or_else(|raw| raw);
+                    Self::from_le(old)
+                }
+            }
+        )*
+    };
+}
+impl_atomic_int!(
+    u8 => AtomicU8,
+    u16 => AtomicU16,
+    u32 => AtomicU32,
+    u64 => AtomicU64,
+);
+
+/// Returns a pointer to the `T` accessed by an atomic operation.
+///
+/// # Errors
+///
+/// - If `address + offset` overflows.
+/// - If `address + offset` accesses out of bounds of `memory`.
+/// - If `address + offset` is not naturally aligned for `T`.
+fn atomic_ptr<T: AtomicInt>(
+    memory: *mut [u8],
+    address: UntypedValue,
+    offset: u64,
+) -> Result<*mut u8, TrapCode> {
+    let address = checked_atomic_address(memory.len(), T::SIZE, address, offset)?;
+    // Note: `address + T::SIZE` has been checked to be within bounds of `memory`.
+    Ok(memory.cast::<u8>().wrapping_add(address))
+}
+
+/// Validates the address of a `size` byte atomic access to a linear memory of `len` bytes.
+///
+/// Returns the effective address upon success.
+///
+/// # Errors
+///
+/// - If `address + offset` overflows.
+/// - If `address + offset` accesses out of bounds of the linear memory.
+/// - If `address + offset` is not naturally aligned to `size` bytes.
+fn checked_atomic_address(
+    len: usize,
+    size: usize,
+    address: UntypedValue,
+    offset: u64,
+) -> Result<usize, TrapCode> {
+    let address = effective_address(u64::from(address), offset)?;
+    address
+        .checked_add(size)
+        .filter(|&end| end <= len)
+        .ok_or(TrapCode::MemoryOutOfBounds)?;
+    if address % size != 0 {
+        return Err(TrapCode::UnalignedAtomic);
+    }
+    Ok(address)
+}
+
+impl UntypedValue {
+    /// Executes a generic `atomic.load` Wasm operation.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    unsafe fn atomic_load<T: AtomicInt>(
+        memory: *mut [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
+        let ptr = atomic_ptr::<T>(memory, address, offset)?;
+        // Safety: `ptr` points to `T::SIZE` bytes within `memory`.
+        Ok(T::load(ptr).into_untyped())
+    }
+
+    /// Executes a generic `atomic.store` Wasm operation.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    unsafe fn atomic_store<T: AtomicInt>(
+        memory: *mut [u8],
+        address: Self,
+        offset: u64,
+        value: Self,
+    ) -> Result<(), TrapCode> {
+        let ptr = atomic_ptr::<T>(memory, address, offset)?;
+        // Safety: `ptr` points to `T::SIZE` bytes within `memory`.
+        T::store(ptr, T::from_untyped(value));
+        Ok(())
+    }
+
+    /// Executes a generic `atomic.rmw` Wasm operation.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    unsafe fn atomic_rmw<T: AtomicInt>(
+        memory: *mut [u8],
+        address: Self,
+        offset: u64,
+        op: AtomicRmwOp,
+        value: Self,
+    ) -> Result<Self, TrapCode> {
+        let ptr = atomic_ptr::<T>(memory, address, offset)?;
+        // Safety: `ptr` points to `T::SIZE` bytes within `memory`.
+        Ok(T::rmw(ptr, op, T::from_untyped(value)).into_untyped())
+    }
+
+    /// Executes a generic `atomic.rmw.cmpxchg` Wasm operation.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    unsafe fn atomic_cmpxchg<T: AtomicInt>(
+        memory: *mut [u8],
+        address: Self,
+        offset: u64,
+        expected: Self,
+        replacement: Self,
+    ) -> Result<Self, TrapCode> {
+        let ptr = atomic_ptr::<T>(memory, address, offset)?;
+        let expected = T::from_untyped(expected);
+        let replacement = T::from_untyped(replacement);
+        // Safety: `ptr` points to `T::SIZE` bytes within `memory`.
+        Ok(T::cmpxchg(ptr, expected, replacement).into_untyped())
+    }
+
+    /// Validates the address of an `N` byte atomic access to a linear memory of `len` bytes.
+    ///
+    /// Returns the effective address upon success.
+    ///
+    /// # Note
+    ///
+    /// This is used by the `memory.atomic.wait` and `memory.atomic.notify` Wasm operations.
+    ///
+    /// # Errors
+    ///
+    /// - If `address + offset` overflows.
+    /// - If `address + offset` accesses out of bounds of the linear memory.
+    /// - If `address + offset` is not naturally aligned to `N` bytes.
+    pub fn atomic_address<const N: usize>(
+        len: usize,
+        address: Self,
+        offset: u64,
+    ) -> Result<usize, TrapCode> {
+        checked_atomic_address(len, N, address, offset)
+    }
+}
+
+macro_rules! impl_atomic_ops {
+    ( $( ($ty:ty, $bits:literal) ),* $(,)? ) => {
+        paste::paste! {
+            impl UntypedValue {
+                $(
+                    #[doc = concat!("Executes the ", $bits, "-bit `atomic.load` Wasm operations.")]
+                    ///
+                    /// # Note
+                    ///
+                    /// The loaded value is zero-extended and therefore serves
+                    /// both the `i32` and `i64` variants of the operation.
+                    ///
+                    /// # Errors
+                    ///
+                    /// - If `address + offset` overflows.
+                    /// - If `address + offset` loads out of bounds from `memory`.
+                    /// - If `address + offset` is not naturally aligned.
+                    ///
+                    /// # Safety
+                    ///
+                    /// `memory` must be valid for reads and writes.
+                    pub unsafe fn [<atomic_load $bits>](
+                        memory: *mut [u8],
+                        address: Self,
+                        offset: u64,
+                    ) -> Result<Self, TrapCode> {
+                        Self::atomic_load::<$ty>(memory, address, offset)
+                    }
+
+                    #[doc = concat!("Executes the ", $bits, "-bit `atomic.store` Wasm operations.")]
+                    ///
+                    /// # Errors
+                    ///
+                    /// - If `address + offset` overflows.
+                    /// - If `address + offset` stores out of bounds from `memory`.
+                    /// - If `address + offset` is not naturally aligned.
+                    ///
+                    /// # Safety
+                    ///
+                    /// `memory` must be valid for reads and writes.
+                    pub unsafe fn [<atomic_store $bits>](
+                        memory: *mut [u8],
+                        address: Self,
+                        offset: u64,
+                        value: Self,
+                    ) -> Result<(), TrapCode> {
+                        Self::atomic_store::<$ty>(memory, address, offset, value)
+                    }
+
+                    #[doc = concat!("Executes the ", $bits, "-bit `atomic.rmw` Wasm operations.")]
+                    ///
+                    /// Returns the zero-extended value before the operation.
+                    ///
+                    /// # Errors
+                    ///
+                    /// - If `address + offset` overflows.
+                    /// - If `address + offset` accesses out of bounds of `memory`.
+                    /// - If `address + offset` is not naturally aligned.
+                    ///
+                    /// # Safety
+                    ///
+                    /// `memory` must be valid for reads and writes.
+                    pub unsafe fn [<atomic_rmw $bits>](
+                        memory: *mut [u8],
+                        address: Self,
+                        offset: u64,
+                        op: AtomicRmwOp,
+                        value: Self,
+                    ) -> Result<Self, TrapCode> {
+                        Self::atomic_rmw::<$ty>(memory, address, offset, op, value)
+                    }
+
+                    #[doc = concat!("Executes the ", $bits, "-bit `atomic.rmw.cmpxchg` Wasm operations.")]
+                    ///
+                    /// Returns the zero-extended value before the operation.
+                    ///
+                    /// # Errors
+                    ///
+                    /// - If `address + offset` overflows.
+                    /// - If `address + offset` accesses out of bounds of `memory`.
+                    /// - If `address + offset` is not naturally aligned.
+                    ///
+                    /// # Safety
+                    ///
+                    /// `memory` must be valid for reads and writes.
+                    pub unsafe fn [<atomic_cmpxchg $bits>](
+                        memory: *mut [u8],
+                        address: Self,
+                        offset: u64,
+                        expected: Self,
+                        replacement: Self,
+                    ) -> Result<Self, TrapCode> {
+                        Self::atomic_cmpxchg::<$ty>(memory, address, offset, expected, replacement)
+                    }
+                )*
+            }
+        }
+    };
+}
+impl_atomic_ops!((u8, 8), (u16, 16), (u32, 32), (u64, 64));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atomic_rmw_works() {
+        let mut memory = [0x00_u8; 16];
+        let address = UntypedValue::from(8_u32);
+        let value = UntypedValue::from(u64::MAX);
+        unsafe { UntypedValue::atomic_store64(&mut memory, address, 0, value) }.unwrap();
+        // The old value is returned and the operation wraps on the accessed bits only.
+        let old = unsafe {
+            UntypedValue::atomic_rmw8(
+                &mut memory,
+                address,
+                0,
+                AtomicRmwOp::Add,
+                UntypedValue::from(2_u32),
+            )
+        }
+        .unwrap();
+        assert_eq!(old, UntypedValue::from(0xFF_u8));
+        assert_eq!(
+            memory[8..],
+            [0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        let loaded = unsafe { UntypedValue::atomic_load32(&mut memory, address, 0) }.unwrap();
+        assert_eq!(loaded, UntypedValue::from(0xFFFF_FF01_u32));
+    }
+
+    #[test]
+    fn atomic_cmpxchg_works() {
+        let mut memory = [0x00_u8; 8];
+        let address = UntypedValue::from(4_u32);
+        let cmpxchg = |memory: &mut [u8], expected: u32, replacement: u32| {
+            unsafe {
+                UntypedValue::atomic_cmpxchg32(
+                    memory,
+                    address,
+                    0,
+                    UntypedValue::from(expected),
+                    UntypedValue::from(replacement),
+                )
+            }
+            .unwrap()
+        };
+        assert_eq!(cmpxchg(&mut memory, 1, 42), UntypedValue::from(0_u32));
+        assert_eq!(memory[4..], [0x00; 4]);
+        assert_eq!(cmpxchg(&mut memory, 0, 42), UntypedValue::from(0_u32));
+        assert_eq!(cmpxchg(&mut memory, 42, 7), UntypedValue::from(42_u32));
+        assert_eq!(memory[4..], [0x07, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn atomic_access_traps() {
+        let mut memory = [0x00_u8; 8];
+        let load = |memory: &mut [u8], address: u32, offset: u64| unsafe {
+            UntypedValue::atomic_load32(memory, UntypedValue::from(address), offset)
+        };
+        assert!(matches!(
+            load(&mut memory, 2, 0),
+            Err(TrapCode::UnalignedAtomic)
+        ));
+        assert!(matches!(
+            load(&mut memory, 0, 1),
+            Err(TrapCode::UnalignedAtomic)
+        ));
+        assert!(matches!(
+            load(&mut memory, 8, 0),
+            Err(TrapCode::MemoryOutOfBounds)
+        ));
+        assert!(matches!(
+            load(&mut memory, 4, u64::MAX),
+            Err(TrapCode::MemoryOutOfBounds)
+        ));
+        assert!(load(&mut memory, 0, 4).is_ok());
+    }
+}
@@ -0,0 +1,242 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{self, Display};
+
+/// The Wasm function frames that were on the call stack when a [`Trap`] occurred.
+///
+/// The frames are ordered from the most recently called function to the
+/// function that has been called by the host.
+///
+/// [`Trap`]: crate::Trap
+#[derive(Debug, Clone, Default)]
+pub struct WasmBacktrace {
+    /// The frames of the backtrace starting with the innermost frame.
+    frames: Box<[FrameInfo]>,
+}
+
+impl WasmBacktrace {
+    /// Returns the [`FrameInfo`] of all frames of the [`WasmBacktrace`].
+    ///
+    /// The first frame is the frame of the function that trapped.
+    pub fn frames(&self) -> &[FrameInfo] {
+        &self.frames
+    }
+
+    /// Returns `true` if the [`WasmBacktrace`] has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl FromIterator<FrameInfo> for WasmBacktrace {
+    fn from_iter<T: IntoIterator<Item = FrameInfo>>(iter: T) -> Self {
+        Self {
+            frames: iter.into_iter().collect::<Vec<_>>().into(),
+        }
+    }
+}
+
+impl Display for WasmBacktrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "wasm backtrace:")?;
+        for (index, frame) in self.frames.iter().enumerate() {
+            write!(f, "\n    {index}: {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Information about a single Wasm function frame of a [`WasmBacktrace`].
+#[derive(Debug, Clone)]
+pub struct FrameInfo {
+    /// The index of the function within the function index space of its module.
+    func_index: u32,
+    /// The name of the module of the function if any.
+    module_name: Option<Box<str>>,
+    /// The name of the function if any.
+    func_name: Option<Box<str>>,
+    /// The offset of the executed instruction within the compiled function.
+    instr_offset: usize,
+    /// The offset of the executed Wasm operator within the Wasm binary if known.
+    wasm_offset: Option<usize>,
+    /// The source locations of the executed Wasm operator resolved from debug information.
+    source_locations: Box<[SourceLocation]>,
+}
+
+impl FrameInfo {
+    /// Creates a new [`FrameInfo`].
+    ///
+    /// - `func_index` is the index of the function within the function index space of its module.
+    /// - `module_name` and `func_name` are the names of the `name` custom section if any.
+    /// - `instr_offset` is the offset of the executed `wasmi` bytecode instruction
+    ///   relative to the start of the compiled function.
+    pub fn new(
+        func_index: u32,
+        module_name: Option<&str>,
+        func_name: Option<&str>,
+        instr_offset: usize,
+    ) -> Self {
+        Self {
+            func_index,
+            module_name: module_name.map(Into::into),
+            func_name: func_name.map(Into::into),
+            instr_offset,
+            wasm_offset: None,
+            source_locations: Box::default(),
+        }
+    }
+
+    /// Sets the offset of the executed Wasm operator within the Wasm binary of the module.
+    pub fn with_wasm_offset(mut self, wasm_offset: usize) -> Self {
+        self.wasm_offset = Some(wasm_offset);
+        self
+    }
+
+    /// Sets the source locations of the executed Wasm operator resolved from debug information.
+    ///
+    /// The source locations are ordered from the innermost inlined function
+    /// to the function that contains the executed Wasm operator.
+    pub fn with_source_locations<T>(mut self, source_locations: T) -> Self
+    where
+        T: IntoIterator<Item = SourceLocation>,
+    {
+        self.source_locations = source_locations.into_iter().collect::<Vec<_>>().into();
+        self
+    }
+
+    /// Returns the index of the function within the function index space of its module.
+    pub fn func_index(&self) -> u32 {
+        self.func_index
+    }
+
+    /// Returns the name of the module of the function if any.
+    pub fn module_name(&self) -> Option<&str> {
+        self.module_name.as_deref()
+    }
+
+    /// Returns the name of the function if any.
+    pub fn func_name(&self) -> Option<&str> {
+        self.func_name.as_deref()
+    }
+
+    /// Returns the offset of the executed `wasmi` bytecode instruction within the function.
+    ///
+    /// # Note
+    ///
+    /// For the trapping function this is the trapping instruction.
+    /// For all other functions this is the instruction following their call instruction.
+    pub fn instr_offset(&self) -> usize {
+        self.instr_offset
+    }
+
+    /// Returns the offset of the executed Wasm operator within the Wasm binary of the module.
+    ///
+    /// # Note
+    ///
+    /// For all but the trapping function this is the offset of their Wasm `call` operator.
+    /// Returns `None` if the function has been compiled without an address map.
+    pub fn wasm_offset(&self) -> Option<usize> {
+        self.wasm_offset
+    }
+
+    /// Returns the source locations of the executed Wasm operator resolved from debug information.
+    ///
+    /// # Note
+    ///
+    /// The source locations are ordered from the innermost inlined function
+    /// to the function that contains the executed Wasm operator.
+    /// This is empty if the module has no debug information.
+    pub fn source_locations(&self) -> &[SourceLocation] {
+        &self.source_locations
+    }
+}
+
+impl Display for FrameInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let module_name = self.module_name().unwrap_or("<unknown>");
+        match self.func_name() {
+            Some(func_name) => write!(f, "{module_name}!{func_name}")?,
+            None => write!(f, "{module_name}!<wasm function {}>", self.func_index)?,
+        }
+        write!(
+            f,
+            " (func {}, instruction {}",
+            self.func_index, self.instr_offset
+        )?;
+        if let Some(wasm_offset) = self.wasm_offset {
+            write!(f, ", wasm offset {wasm_offset:#x}")?;
+        }
+        write!(f, ")")?;
+        for location in self.source_locations() {
+            write!(f, "\n        at {location}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A source code location resolved from the debug information of a module.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The name of the source function if any.
+    func: Option<Box<str>>,
+    /// The path of the source file if any.
+    file: Option<Box<str>>,
+    /// The line within the source file if any.
+    line: Option<u32>,
+    /// The column within the source line if any.
+    column: Option<u32>,
+}
+
+impl SourceLocation {
+    /// Creates a new [`SourceLocation`].
+    pub fn new(
+        func: Option<&str>,
+        file: Option<&str>,
+        line: Option<u32>,
+        column: Option<u32>,
+    ) -> Self {
+        Self {
+            func: func.map(Into::into),
+            file: file.map(Into::into),
+            line,
+            column,
+        }
+    }
+
+    /// Returns the name of the source function if any.
+    pub fn func(&self) -> Option<&str> {
+        self.func.as_deref()
+    }
+
+    /// Returns the path of the source file if any.
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// Returns the line within the source file if any.
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    /// Returns the column within the source line if any.
+    pub fn column(&self) -> Option<u32> {
+        self.column
+    }
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.func().unwrap_or("<unknown>"))?;
+        let file = match self.file() {
+            Some(file) => file,
+            None => return Ok(()),
+        };
+        write!(f, " ({file}")?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+            if let Some(column) = self.column {
+                write!(f, ":{column}")?;
+            }
+        }
+        write!(f, ")")
+    }
+}
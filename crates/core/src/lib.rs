@@ -11,8 +11,12 @@
     clippy::items_after_statements
 )]
 
+mod atomic;
+mod backtrace;
 mod host_error;
 mod nan_preserving_float;
+#[cfg(feature = "simd")]
+mod simd;
 mod trap;
 mod units;
 mod untyped;
@@ -24,6 +28,8 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std as alloc;
 
+#[cfg(feature = "simd")]
+pub use self::simd::V128;
 use self::value::{
     ArithmeticOps,
     ExtendInto,
@@ -36,6 +42,8 @@ use self::value::{
     WrapInto,
 };
 pub use self::{
+    atomic::AtomicRmwOp,
+    backtrace::{FrameInfo, SourceLocation, WasmBacktrace},
     host_error::HostError,
     nan_preserving_float::{F32, F64},
     trap::{Trap, TrapCode},
@@ -0,0 +1,1344 @@
+use crate::{
+    untyped::effective_address,
+    value::{LoadInto, StoreFrom},
+    ArithmeticOps,
+    ExtendInto,
+    Float,
+    TrapCode,
+    TruncateSaturateInto,
+    UntypedValue,
+    WrapInto,
+    F32,
+    F64,
+};
+use core::ops::Neg;
+
+/// A 128-bit Wasm `v128` value.
+///
+/// The interpretation of the 128 bits depends on the operation that is
+/// applied to the value. Lanes are stored in little endian order.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct V128(u128);
+
+impl V128 {
+    /// Creates a [`V128`] from its underlying bits.
+    pub fn from_bits(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the underlying bits of the [`V128`].
+    pub fn to_bits(self) -> u128 {
+        self.0
+    }
+}
+
+impl From<u128> for V128 {
+    fn from(bits: u128) -> Self {
+        Self::from_bits(bits)
+    }
+}
+
+impl From<V128> for UntypedValue {
+    fn from(value: V128) -> Self {
+        Self::from_bits128(value.to_bits())
+    }
+}
+
+impl From<UntypedValue> for V128 {
+    fn from(untyped: UntypedValue) -> Self {
+        Self::from_bits(untyped.to_bits128())
+    }
+}
+
+/// A lane type of a [`V128`] value.
+trait Lane: Copy {
+    /// The array of all lanes of a [`V128`] interpreted as `Self`.
+    type Lanes: Copy + AsRef<[Self]> + AsMut<[Self]>;
+
+    /// Splits the [`V128`] into its lanes.
+    fn lanes(value: V128) -> Self::Lanes;
+
+    /// Combines the `lanes` into a [`V128`].
+    fn from_lanes(lanes: Self::Lanes) -> V128;
+}
+
+macro_rules! impl_lane_for_int {
+    ( $( $ty:ty; $n:literal ),* $(,)? ) => {
+        $(
+            impl Lane for $ty {
+                type Lanes = [$ty; $n];
+
+                fn lanes(value: V128) -> Self::Lanes {
+                    let bytes = value.to_bits().to_le_bytes();
+                    let mut lanes = [0; $n];
+                    let width = core::mem::size_of::<$ty>();
+                    for (lane, chunk) in lanes.iter_mut().zip(bytes.chunks_exact(width)) {
+                        *lane = <$ty>::from_le_bytes(
+                            chunk.try_into().expect("chunk has the size of a lane"),
+                        );
+                    }
+                    lanes
+                }
+
+                fn from_lanes(lanes: Self::Lanes) -> V128 {
+                    let mut bytes = [0x00_u8; 16];
+                    let width = core::mem::size_of::<$ty>();
+                    for (lane, chunk) in lanes.iter().zip(bytes.chunks_exact_mut(width)) {
+                        chunk.copy_from_slice(&lane.to_le_bytes());
+                    }
+                    V128::from_bits(u128::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+impl_lane_for_int!(
+    i8; 16, u8; 16,
+    i16; 8, u16; 8,
+    i32; 4, u32; 4,
+    i64; 2, u64; 2,
+);
+
+macro_rules! impl_lane_for_float {
+    ( $( $ty:ty as $bits:ty; $n:literal ),* $(,)? ) => {
+        $(
+            impl Lane for $ty {
+                type Lanes = [$ty; $n];
+
+                fn lanes(value: V128) -> Self::Lanes {
+                    <$bits as Lane>::lanes(value).map(<$ty>::from_bits)
+                }
+
+                fn from_lanes(lanes: Self::Lanes) -> V128 {
+                    <$bits as Lane>::from_lanes(lanes.map(<$ty>::to_bits))
+                }
+            }
+        )*
+    };
+}
+impl_lane_for_float!(F32 as u32; 4, F64 as u64; 2);
+
+/// Integer lane types that can represent the result of a lanewise comparison.
+trait Mask: Lane {
+    /// Returns a lane with all bits set if `value` is `true` and no bits set otherwise.
+    fn mask(value: bool) -> Self;
+}
+
+macro_rules! impl_mask {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl Mask for $ty {
+                fn mask(value: bool) -> Self {
+                    match value {
+                        true => -1,
+                        false => 0,
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_mask!(i8, i16, i32, i64);
+
+/// Returns a [`V128`] with all of its lanes set to zero.
+fn zeroed<T: Lane>() -> T::Lanes {
+    T::lanes(V128::default())
+}
+
+impl UntypedValue {
+    /// Returns the lanes of `self` interpreted as `T`.
+    fn simd_lanes<T: Lane>(self) -> T::Lanes {
+        T::lanes(V128::from(self))
+    }
+
+    /// Creates an [`UntypedValue`] from the given `lanes`.
+    fn simd_from_lanes<T: Lane>(lanes: T::Lanes) -> Self {
+        Self::from(T::from_lanes(lanes))
+    }
+
+    /// Applies `f` to all lanes of `self`.
+    fn simd_unary<T: Lane>(self, f: impl Fn(T) -> T) -> Self {
+        self.simd_convert::<T, T>(f)
+    }
+
+    /// Applies `f` to all pairs of lanes of `self` and `rhs`.
+    fn simd_binary<T: Lane>(self, rhs: Self, f: impl Fn(T, T) -> T) -> Self {
+        let mut lanes = self.simd_lanes::<T>();
+        let rhs = rhs.simd_lanes::<T>();
+        for (lhs, rhs) in lanes.as_mut().iter_mut().zip(rhs.as_ref()) {
+            *lhs = f(*lhs, *rhs);
+        }
+        Self::simd_from_lanes::<T>(lanes)
+    }
+
+    /// Converts all lanes of `self` from `T` to `U` using `f`.
+    ///
+    /// Lanes of the result that have no corresponding lane in `self` are set to zero.
+    fn simd_convert<T: Lane, U: Lane>(self, f: impl Fn(T) -> U) -> Self {
+        let lanes = self.simd_lanes::<T>();
+        let mut result = zeroed::<U>();
+        for (result, lane) in result.as_mut().iter_mut().zip(lanes.as_ref()) {
+            *result = f(*lane);
+        }
+        Self::simd_from_lanes::<U>(result)
+    }
+
+    /// Compares all pairs of lanes of `self` and `rhs` using `f`.
+    ///
+    /// Lanes for which `f` returns `true` are set to all ones, otherwise to all zeros.
+    fn simd_compare<T: Lane, M: Mask>(self, rhs: Self, f: impl Fn(T, T) -> bool) -> Self {
+        let lhs = self.simd_lanes::<T>();
+        let rhs = rhs.simd_lanes::<T>();
+        let mut result = zeroed::<M>();
+        for (result, (lhs, rhs)) in result
+            .as_mut()
+            .iter_mut()
+            .zip(lhs.as_ref().iter().zip(rhs.as_ref()))
+        {
+            *result = M::mask(f(*lhs, *rhs));
+        }
+        Self::simd_from_lanes::<M>(result)
+    }
+
+    /// Shifts all lanes of `self` by the `i32` shift amount `rhs` using `f`.
+    ///
+    /// # Note
+    ///
+    /// The `f` is expected to take the shift amount modulo the lane width.
+    fn simd_shift<T: Lane>(self, rhs: Self, f: impl Fn(T, u32) -> T) -> Self {
+        let amount = u32::from(rhs);
+        self.simd_unary::<T>(|lane| f(lane, amount))
+    }
+
+    /// Extends half of the lanes of `self` from `T` to `U` using `f`.
+    ///
+    /// Uses the upper half of the lanes if `high` is `true` and the lower half otherwise.
+    fn simd_extend<T: Lane, U: Lane>(self, high: bool, f: impl Fn(T) -> U) -> Self {
+        let lanes = self.simd_lanes::<T>();
+        let lanes = lanes.as_ref();
+        let half = lanes.len() / 2;
+        let lanes = if high { &lanes[half..] } else { &lanes[..half] };
+        let mut result = zeroed::<U>();
+        for (result, lane) in result.as_mut().iter_mut().zip(lanes) {
+            *result = f(*lane);
+        }
+        Self::simd_from_lanes::<U>(result)
+    }
+
+    /// Multiplies half of the lanes of `self` and `rhs` after extending them from `T` to `U`.
+    ///
+    /// Uses the upper half of the lanes if `high` is `true` and the lower half otherwise.
+    fn simd_extmul<T, U>(self, rhs: Self, high: bool) -> Self
+    where
+        T: Lane,
+        U: IntLane + From<T>,
+    {
+        let lhs = self.simd_extend::<T, U>(high, U::from);
+        let rhs = rhs.simd_extend::<T, U>(high, U::from);
+        lhs.simd_binary::<U>(rhs, <U as IntLane>::mul)
+    }
+
+    /// Adds adjacent pairs of lanes of `self` after extending them from `T` to `U`.
+    fn simd_extadd_pairwise<T, U>(self) -> Self
+    where
+        T: Lane,
+        U: IntLane + From<T>,
+    {
+        let lanes = self.simd_lanes::<T>();
+        let mut result = zeroed::<U>();
+        for (result, pair) in result
+            .as_mut()
+            .iter_mut()
+            .zip(lanes.as_ref().chunks_exact(2))
+        {
+            *result = U::from(pair[0]).add(U::from(pair[1]));
+        }
+        Self::simd_from_lanes::<U>(result)
+    }
+
+    /// Narrows the lanes of `self` and `rhs` from `T` to `U` using `f`.
+    ///
+    /// The lanes of `self` form the lower half and the lanes of `rhs` the upper half of the result.
+    fn simd_narrow<T: Lane, U: Lane>(self, rhs: Self, f: impl Fn(T) -> U) -> Self {
+        let lhs = self.simd_lanes::<T>();
+        let rhs = rhs.simd_lanes::<T>();
+        let mut result = zeroed::<U>();
+        for (result, lane) in result
+            .as_mut()
+            .iter_mut()
+            .zip(lhs.as_ref().iter().chain(rhs.as_ref()))
+        {
+            *result = f(*lane);
+        }
+        Self::simd_from_lanes::<U>(result)
+    }
+
+    /// Returns `true` if all lanes of `self` are non-zero.
+    fn simd_all_true<T: Lane + PartialEq + Default>(self) -> Self {
+        let lanes = self.simd_lanes::<T>();
+        Self::from(lanes.as_ref().iter().all(|lane| *lane != T::default()))
+    }
+
+    /// Returns a bitmask where each bit is the most significant bit of the respective lane.
+    fn simd_bitmask<T: Lane + PartialOrd + Default>(self) -> Self {
+        let lanes = self.simd_lanes::<T>();
+        let mask = lanes
+            .as_ref()
+            .iter()
+            .enumerate()
+            .filter(|(_, lane)| **lane < T::default())
+            .fold(0_u32, |mask, (n, _)| mask | (1 << n));
+        Self::from(mask)
+    }
+
+    /// Returns a `v128` value with all lanes set to `value`.
+    fn simd_splat<T: Lane>(value: T) -> Self {
+        let mut lanes = zeroed::<T>();
+        lanes.as_mut().fill(value);
+        Self::simd_from_lanes::<T>(lanes)
+    }
+
+    /// Returns the lane of `self` at index `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is out of bounds. This is prevented by Wasm validation.
+    fn simd_extract_lane<T: Lane>(self, lane: u8) -> T {
+        self.simd_lanes::<T>().as_ref()[usize::from(lane)]
+    }
+
+    /// Returns `self` with its lane at index `lane` replaced by `value`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is out of bounds. This is prevented by Wasm validation.
+    fn simd_replace_lane<T: Lane>(self, lane: u8, value: T) -> Self {
+        let mut lanes = self.simd_lanes::<T>();
+        lanes.as_mut()[usize::from(lane)] = value;
+        Self::simd_from_lanes::<T>(lanes)
+    }
+}
+
+/// Integer lane types that support the integer specific `v128` operations.
+trait IntLane: Lane + Ord + Default {
+    /// The wrapping addition of `self` and `rhs`.
+    fn add(self, rhs: Self) -> Self;
+    /// The wrapping subtraction of `self` and `rhs`.
+    fn sub(self, rhs: Self) -> Self;
+    /// The wrapping multiplication of `self` and `rhs`.
+    fn mul(self, rhs: Self) -> Self;
+    /// The wrapping negation of `self`.
+    fn neg(self) -> Self;
+    /// The saturating addition of `self` and `rhs`.
+    fn add_sat(self, rhs: Self) -> Self;
+    /// The saturating subtraction of `self` and `rhs`.
+    fn sub_sat(self, rhs: Self) -> Self;
+    /// The shift left of `self` by `rhs` modulo the lane width.
+    fn shl(self, rhs: u32) -> Self;
+    /// The shift right of `self` by `rhs` modulo the lane width.
+    ///
+    /// Arithmetic for signed and logical for unsigned lanes.
+    fn shr(self, rhs: u32) -> Self;
+}
+
+macro_rules! impl_int_lane {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl IntLane for $ty {
+                fn add(self, rhs: Self) -> Self { self.wrapping_add(rhs) }
+                fn sub(self, rhs: Self) -> Self { self.wrapping_sub(rhs) }
+                fn mul(self, rhs: Self) -> Self { self.wrapping_mul(rhs) }
+                fn neg(self) -> Self { self.wrapping_neg() }
+                fn add_sat(self, rhs: Self) -> Self { self.saturating_add(rhs) }
+                fn sub_sat(self, rhs: Self) -> Self { self.saturating_sub(rhs) }
+                fn shl(self, rhs: u32) -> Self { self.wrapping_shl(rhs) }
+                fn shr(self, rhs: u32) -> Self { self.wrapping_shr(rhs) }
+            }
+        )*
+    };
+}
+impl_int_lane!(i8, u8, i16, u16, i32, u32, i64, u64);
+
+macro_rules! impl_simd_int_ops {
+    (
+        $( $shape:ident: $signed:ty, $unsigned:ty; )*
+    ) => {
+        paste::paste! {
+            impl UntypedValue {
+                $(
+                    #[doc = concat!("Execute `", stringify!($shape), ".add` Wasm operation.")]
+                    pub fn [<$shape _add>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$signed>(rhs, <$signed as IntLane>::add)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".sub` Wasm operation.")]
+                    pub fn [<$shape _sub>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$signed>(rhs, <$signed as IntLane>::sub)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".neg` Wasm operation.")]
+                    pub fn [<$shape _neg>](self) -> Self {
+                        self.simd_unary::<$signed>(<$signed as IntLane>::neg)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".abs` Wasm operation.")]
+                    pub fn [<$shape _abs>](self) -> Self {
+                        self.simd_unary::<$signed>(<$signed>::wrapping_abs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".eq` Wasm operation.")]
+                    pub fn [<$shape _eq>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$signed, $signed>(rhs, |lhs, rhs| lhs == rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".ne` Wasm operation.")]
+                    pub fn [<$shape _ne>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$signed, $signed>(rhs, |lhs, rhs| lhs != rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".lt_s` Wasm operation.")]
+                    pub fn [<$shape _lt_s>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$signed, $signed>(rhs, |lhs, rhs| lhs < rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".gt_s` Wasm operation.")]
+                    pub fn [<$shape _gt_s>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$signed, $signed>(rhs, |lhs, rhs| lhs > rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".le_s` Wasm operation.")]
+                    pub fn [<$shape _le_s>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$signed, $signed>(rhs, |lhs, rhs| lhs <= rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".ge_s` Wasm operation.")]
+                    pub fn [<$shape _ge_s>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$signed, $signed>(rhs, |lhs, rhs| lhs >= rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".shl` Wasm operation.")]
+                    pub fn [<$shape _shl>](self, rhs: Self) -> Self {
+                        self.simd_shift::<$signed>(rhs, <$signed as IntLane>::shl)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".shr_s` Wasm operation.")]
+                    pub fn [<$shape _shr_s>](self, rhs: Self) -> Self {
+                        self.simd_shift::<$signed>(rhs, <$signed as IntLane>::shr)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".shr_u` Wasm operation.")]
+                    pub fn [<$shape _shr_u>](self, rhs: Self) -> Self {
+                        self.simd_shift::<$unsigned>(rhs, <$unsigned as IntLane>::shr)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".all_true` Wasm operation.")]
+                    pub fn [<$shape _all_true>](self) -> Self {
+                        self.simd_all_true::<$signed>()
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".bitmask` Wasm operation.")]
+                    pub fn [<$shape _bitmask>](self) -> Self {
+                        self.simd_bitmask::<$signed>()
+                    }
+                )*
+            }
+        }
+    };
+}
+impl_simd_int_ops! {
+    i8x16: i8, u8;
+    i16x8: i16, u16;
+    i32x4: i32, u32;
+    i64x2: i64, u64;
+}
+
+macro_rules! impl_simd_small_int_ops {
+    (
+        $( $shape:ident: $signed:ty, $unsigned:ty; )*
+    ) => {
+        paste::paste! {
+            impl UntypedValue {
+                $(
+                    #[doc = concat!("Execute `", stringify!($shape), ".lt_u` Wasm operation.")]
+                    pub fn [<$shape _lt_u>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$unsigned, $signed>(rhs, |lhs, rhs| lhs < rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".gt_u` Wasm operation.")]
+                    pub fn [<$shape _gt_u>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$unsigned, $signed>(rhs, |lhs, rhs| lhs > rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".le_u` Wasm operation.")]
+                    pub fn [<$shape _le_u>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$unsigned, $signed>(rhs, |lhs, rhs| lhs <= rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".ge_u` Wasm operation.")]
+                    pub fn [<$shape _ge_u>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$unsigned, $signed>(rhs, |lhs, rhs| lhs >= rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".min_s` Wasm operation.")]
+                    pub fn [<$shape _min_s>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$signed>(rhs, Ord::min)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".min_u` Wasm operation.")]
+                    pub fn [<$shape _min_u>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$unsigned>(rhs, Ord::min)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".max_s` Wasm operation.")]
+                    pub fn [<$shape _max_s>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$signed>(rhs, Ord::max)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".max_u` Wasm operation.")]
+                    pub fn [<$shape _max_u>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$unsigned>(rhs, Ord::max)
+                    }
+                )*
+            }
+        }
+    };
+}
+impl_simd_small_int_ops! {
+    i8x16: i8, u8;
+    i16x8: i16, u16;
+    i32x4: i32, u32;
+}
+
+macro_rules! impl_simd_sat_ops {
+    (
+        $( $shape:ident: $signed:ty, $unsigned:ty, $wide:ty; )*
+    ) => {
+        paste::paste! {
+            impl UntypedValue {
+                $(
+                    #[doc = concat!("Execute `", stringify!($shape), ".add_sat_s` Wasm operation.")]
+                    pub fn [<$shape _add_sat_s>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$signed>(rhs, <$signed as IntLane>::add_sat)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".add_sat_u` Wasm operation.")]
+                    pub fn [<$shape _add_sat_u>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$unsigned>(rhs, <$unsigned as IntLane>::add_sat)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".sub_sat_s` Wasm operation.")]
+                    pub fn [<$shape _sub_sat_s>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$signed>(rhs, <$signed as IntLane>::sub_sat)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".sub_sat_u` Wasm operation.")]
+                    pub fn [<$shape _sub_sat_u>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$unsigned>(rhs, <$unsigned as IntLane>::sub_sat)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".avgr_u` Wasm operation.")]
+                    pub fn [<$shape _avgr_u>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$unsigned>(rhs, |lhs, rhs| {
+                            (<$wide>::from(lhs) + <$wide>::from(rhs)).div_ceil(2) as $unsigned
+                        })
+                    }
+                )*
+            }
+        }
+    };
+}
+impl_simd_sat_ops! {
+    i8x16: i8, u8, u16;
+    i16x8: i16, u16, u32;
+}
+
+macro_rules! impl_simd_float_ops {
+    (
+        $( $shape:ident: $float:ty, $mask:ty; )*
+    ) => {
+        paste::paste! {
+            impl UntypedValue {
+                $(
+                    #[doc = concat!("Execute `", stringify!($shape), ".add` Wasm operation.")]
+                    pub fn [<$shape _add>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$float>(rhs, <$float as ArithmeticOps<$float>>::add)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".sub` Wasm operation.")]
+                    pub fn [<$shape _sub>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$float>(rhs, <$float as ArithmeticOps<$float>>::sub)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".mul` Wasm operation.")]
+                    pub fn [<$shape _mul>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$float>(rhs, <$float as ArithmeticOps<$float>>::mul)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".div` Wasm operation.")]
+                    pub fn [<$shape _div>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$float>(rhs, <$float as Float<$float>>::div)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".min` Wasm operation.")]
+                    pub fn [<$shape _min>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$float>(rhs, <$float as Float<$float>>::min)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".max` Wasm operation.")]
+                    pub fn [<$shape _max>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$float>(rhs, <$float as Float<$float>>::max)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".pmin` Wasm operation.")]
+                    pub fn [<$shape _pmin>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$float>(rhs, |lhs, rhs| if rhs < lhs { rhs } else { lhs })
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".pmax` Wasm operation.")]
+                    pub fn [<$shape _pmax>](self, rhs: Self) -> Self {
+                        self.simd_binary::<$float>(rhs, |lhs, rhs| if lhs < rhs { rhs } else { lhs })
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".abs` Wasm operation.")]
+                    pub fn [<$shape _abs>](self) -> Self {
+                        self.simd_unary::<$float>(<$float as Float<$float>>::abs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".neg` Wasm operation.")]
+                    pub fn [<$shape _neg>](self) -> Self {
+                        self.simd_unary::<$float>(<$float as Neg>::neg)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".sqrt` Wasm operation.")]
+                    pub fn [<$shape _sqrt>](self) -> Self {
+                        self.simd_unary::<$float>(<$float as Float<$float>>::sqrt)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".ceil` Wasm operation.")]
+                    pub fn [<$shape _ceil>](self) -> Self {
+                        self.simd_unary::<$float>(<$float as Float<$float>>::ceil)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".floor` Wasm operation.")]
+                    pub fn [<$shape _floor>](self) -> Self {
+                        self.simd_unary::<$float>(<$float as Float<$float>>::floor)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".trunc` Wasm operation.")]
+                    pub fn [<$shape _trunc>](self) -> Self {
+                        self.simd_unary::<$float>(<$float as Float<$float>>::trunc)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".nearest` Wasm operation.")]
+                    pub fn [<$shape _nearest>](self) -> Self {
+                        self.simd_unary::<$float>(<$float as Float<$float>>::nearest)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".eq` Wasm operation.")]
+                    pub fn [<$shape _eq>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$float, $mask>(rhs, |lhs, rhs| lhs == rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".ne` Wasm operation.")]
+                    pub fn [<$shape _ne>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$float, $mask>(rhs, |lhs, rhs| lhs != rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".lt` Wasm operation.")]
+                    pub fn [<$shape _lt>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$float, $mask>(rhs, |lhs, rhs| lhs < rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".gt` Wasm operation.")]
+                    pub fn [<$shape _gt>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$float, $mask>(rhs, |lhs, rhs| lhs > rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".le` Wasm operation.")]
+                    pub fn [<$shape _le>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$float, $mask>(rhs, |lhs, rhs| lhs <= rhs)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".ge` Wasm operation.")]
+                    pub fn [<$shape _ge>](self, rhs: Self) -> Self {
+                        self.simd_compare::<$float, $mask>(rhs, |lhs, rhs| lhs >= rhs)
+                    }
+                )*
+            }
+        }
+    };
+}
+impl_simd_float_ops! {
+    f32x4: F32, i32;
+    f64x2: F64, i64;
+}
+
+macro_rules! impl_simd_lane_ops {
+    (
+        $( $shape:ident: $lane:ty = $convert:expr; )*
+    ) => {
+        paste::paste! {
+            impl UntypedValue {
+                $(
+                    #[doc = concat!("Execute `", stringify!($shape), ".splat` Wasm operation.")]
+                    pub fn [<$shape _splat>](self) -> Self {
+                        Self::simd_splat::<$lane>($convert(self))
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".replace_lane` Wasm operation.")]
+                    pub fn [<$shape _replace_lane>](self, lane: u8, value: Self) -> Self {
+                        self.simd_replace_lane::<$lane>(lane, $convert(value))
+                    }
+                )*
+            }
+        }
+    };
+}
+impl_simd_lane_ops! {
+    i8x16: i8 = |value| i32::from(value) as i8;
+    i16x8: i16 = |value| i32::from(value) as i16;
+    i32x4: i32 = i32::from;
+    i64x2: i64 = i64::from;
+    f32x4: F32 = F32::from;
+    f64x2: F64 = F64::from;
+}
+
+impl UntypedValue {
+    /// Execute `i8x16.extract_lane_s` Wasm operation.
+    pub fn i8x16_extract_lane_s(self, lane: u8) -> Self {
+        Self::from(i32::from(self.simd_extract_lane::<i8>(lane)))
+    }
+
+    /// Execute `i8x16.extract_lane_u` Wasm operation.
+    pub fn i8x16_extract_lane_u(self, lane: u8) -> Self {
+        Self::from(u32::from(self.simd_extract_lane::<u8>(lane)))
+    }
+
+    /// Execute `i16x8.extract_lane_s` Wasm operation.
+    pub fn i16x8_extract_lane_s(self, lane: u8) -> Self {
+        Self::from(i32::from(self.simd_extract_lane::<i16>(lane)))
+    }
+
+    /// Execute `i16x8.extract_lane_u` Wasm operation.
+    pub fn i16x8_extract_lane_u(self, lane: u8) -> Self {
+        Self::from(u32::from(self.simd_extract_lane::<u16>(lane)))
+    }
+
+    /// Execute `i32x4.extract_lane` Wasm operation.
+    pub fn i32x4_extract_lane(self, lane: u8) -> Self {
+        Self::from(self.simd_extract_lane::<i32>(lane))
+    }
+
+    /// Execute `i64x2.extract_lane` Wasm operation.
+    pub fn i64x2_extract_lane(self, lane: u8) -> Self {
+        Self::from(self.simd_extract_lane::<i64>(lane))
+    }
+
+    /// Execute `f32x4.extract_lane` Wasm operation.
+    pub fn f32x4_extract_lane(self, lane: u8) -> Self {
+        Self::from(self.simd_extract_lane::<F32>(lane))
+    }
+
+    /// Execute `f64x2.extract_lane` Wasm operation.
+    pub fn f64x2_extract_lane(self, lane: u8) -> Self {
+        Self::from(self.simd_extract_lane::<F64>(lane))
+    }
+
+    /// Execute `v128.not` Wasm operation.
+    pub fn v128_not(self) -> Self {
+        Self::from_bits128(!self.to_bits128())
+    }
+
+    /// Execute `v128.and` Wasm operation.
+    pub fn v128_and(self, rhs: Self) -> Self {
+        Self::from_bits128(self.to_bits128() & rhs.to_bits128())
+    }
+
+    /// Execute `v128.andnot` Wasm operation.
+    pub fn v128_andnot(self, rhs: Self) -> Self {
+        Self::from_bits128(self.to_bits128() & !rhs.to_bits128())
+    }
+
+    /// Execute `v128.or` Wasm operation.
+    pub fn v128_or(self, rhs: Self) -> Self {
+        Self::from_bits128(self.to_bits128() | rhs.to_bits128())
+    }
+
+    /// Execute `v128.xor` Wasm operation.
+    pub fn v128_xor(self, rhs: Self) -> Self {
+        Self::from_bits128(self.to_bits128() ^ rhs.to_bits128())
+    }
+
+    /// Execute `v128.bitselect` Wasm operation.
+    ///
+    /// Selects the bits of `self` where `mask` is set and the bits of `rhs` otherwise.
+    pub fn v128_bitselect(self, rhs: Self, mask: Self) -> Self {
+        let mask = mask.to_bits128();
+        Self::from_bits128((self.to_bits128() & mask) | (rhs.to_bits128() & !mask))
+    }
+
+    /// Execute `v128.any_true` Wasm operation.
+    pub fn v128_any_true(self) -> Self {
+        Self::from(self.to_bits128() != 0)
+    }
+
+    /// Execute `i8x16.swizzle` Wasm operation.
+    pub fn i8x16_swizzle(self, selector: Self) -> Self {
+        let lanes = self.simd_lanes::<u8>();
+        selector
+            .simd_unary::<u8>(|index| lanes.get(usize::from(index)).copied().unwrap_or_default())
+    }
+
+    /// Execute `i8x16.shuffle` Wasm operation.
+    ///
+    /// The lanes of `selector` index into the concatenation of `self` and `rhs`.
+    ///
+    /// # Note
+    ///
+    /// The `selector` lanes are guaranteed to be less than 32 by Wasm validation.
+    pub fn i8x16_shuffle(self, rhs: Self, selector: Self) -> Self {
+        let lhs = self.simd_lanes::<u8>();
+        let rhs = rhs.simd_lanes::<u8>();
+        selector.simd_unary::<u8>(|index| {
+            let index = usize::from(index);
+            match index < 16 {
+                true => lhs[index],
+                false => rhs[index - 16],
+            }
+        })
+    }
+
+    /// Execute `i8x16.popcnt` Wasm operation.
+    pub fn i8x16_popcnt(self) -> Self {
+        self.simd_unary::<u8>(|lane| lane.count_ones() as u8)
+    }
+
+    /// Execute `i16x8.mul` Wasm operation.
+    pub fn i16x8_mul(self, rhs: Self) -> Self {
+        self.simd_binary::<i16>(rhs, <i16 as IntLane>::mul)
+    }
+
+    /// Execute `i32x4.mul` Wasm operation.
+    pub fn i32x4_mul(self, rhs: Self) -> Self {
+        self.simd_binary::<i32>(rhs, <i32 as IntLane>::mul)
+    }
+
+    /// Execute `i64x2.mul` Wasm operation.
+    pub fn i64x2_mul(self, rhs: Self) -> Self {
+        self.simd_binary::<i64>(rhs, <i64 as IntLane>::mul)
+    }
+
+    /// Execute `i16x8.q15mulr_sat_s` Wasm operation.
+    pub fn i16x8_q15mulr_sat_s(self, rhs: Self) -> Self {
+        self.simd_binary::<i16>(rhs, |lhs, rhs| {
+            let product = (i32::from(lhs) * i32::from(rhs) + 0x4000) >> 15;
+            product.clamp(i32::from(i16::MIN), i32::from(i16::MAX)) as i16
+        })
+    }
+
+    /// Execute `i32x4.dot_i16x8_s` Wasm operation.
+    pub fn i32x4_dot_i16x8_s(self, rhs: Self) -> Self {
+        let lhs = self.simd_lanes::<i16>();
+        let rhs = rhs.simd_lanes::<i16>();
+        let mut result = zeroed::<i32>();
+        for (n, result) in result.iter_mut().enumerate() {
+            let lo = i32::from(lhs[2 * n]) * i32::from(rhs[2 * n]);
+            let hi = i32::from(lhs[2 * n + 1]) * i32::from(rhs[2 * n + 1]);
+            *result = lo.wrapping_add(hi);
+        }
+        Self::simd_from_lanes::<i32>(result)
+    }
+
+    /// Execute `i8x16.narrow_i16x8_s` Wasm operation.
+    pub fn i8x16_narrow_i16x8_s(self, rhs: Self) -> Self {
+        self.simd_narrow::<i16, i8>(rhs, |lane| {
+            lane.clamp(i16::from(i8::MIN), i16::from(i8::MAX)) as i8
+        })
+    }
+
+    /// Execute `i8x16.narrow_i16x8_u` Wasm operation.
+    pub fn i8x16_narrow_i16x8_u(self, rhs: Self) -> Self {
+        self.simd_narrow::<i16, u8>(rhs, |lane| lane.clamp(0, i16::from(u8::MAX)) as u8)
+    }
+
+    /// Execute `i16x8.narrow_i32x4_s` Wasm operation.
+    pub fn i16x8_narrow_i32x4_s(self, rhs: Self) -> Self {
+        self.simd_narrow::<i32, i16>(rhs, |lane| {
+            lane.clamp(i32::from(i16::MIN), i32::from(i16::MAX)) as i16
+        })
+    }
+
+    /// Execute `i16x8.narrow_i32x4_u` Wasm operation.
+    pub fn i16x8_narrow_i32x4_u(self, rhs: Self) -> Self {
+        self.simd_narrow::<i32, u16>(rhs, |lane| lane.clamp(0, i32::from(u16::MAX)) as u16)
+    }
+
+    /// Execute `i32x4.trunc_sat_f32x4_s` Wasm operation.
+    pub fn i32x4_trunc_sat_f32x4_s(self) -> Self {
+        self.simd_convert::<F32, i32>(<F32 as TruncateSaturateInto<i32>>::truncate_saturate_into)
+    }
+
+    /// Execute `i32x4.trunc_sat_f32x4_u` Wasm operation.
+    pub fn i32x4_trunc_sat_f32x4_u(self) -> Self {
+        self.simd_convert::<F32, u32>(<F32 as TruncateSaturateInto<u32>>::truncate_saturate_into)
+    }
+
+    /// Execute `i32x4.trunc_sat_f64x2_s_zero` Wasm operation.
+    pub fn i32x4_trunc_sat_f64x2_s_zero(self) -> Self {
+        self.simd_convert::<F64, i32>(<F64 as TruncateSaturateInto<i32>>::truncate_saturate_into)
+    }
+
+    /// Execute `i32x4.trunc_sat_f64x2_u_zero` Wasm operation.
+    pub fn i32x4_trunc_sat_f64x2_u_zero(self) -> Self {
+        self.simd_convert::<F64, u32>(<F64 as TruncateSaturateInto<u32>>::truncate_saturate_into)
+    }
+
+    /// Execute `f32x4.convert_i32x4_s` Wasm operation.
+    pub fn f32x4_convert_i32x4_s(self) -> Self {
+        self.simd_convert::<i32, F32>(<i32 as ExtendInto<F32>>::extend_into)
+    }
+
+    /// Execute `f32x4.convert_i32x4_u` Wasm operation.
+    pub fn f32x4_convert_i32x4_u(self) -> Self {
+        self.simd_convert::<u32, F32>(<u32 as ExtendInto<F32>>::extend_into)
+    }
+
+    /// Execute `f64x2.convert_low_i32x4_s` Wasm operation.
+    pub fn f64x2_convert_low_i32x4_s(self) -> Self {
+        self.simd_extend::<i32, F64>(false, <i32 as ExtendInto<F64>>::extend_into)
+    }
+
+    /// Execute `f64x2.convert_low_i32x4_u` Wasm operation.
+    pub fn f64x2_convert_low_i32x4_u(self) -> Self {
+        self.simd_extend::<u32, F64>(false, <u32 as ExtendInto<F64>>::extend_into)
+    }
+
+    /// Execute `f32x4.demote_f64x2_zero` Wasm operation.
+    pub fn f32x4_demote_f64x2_zero(self) -> Self {
+        self.simd_convert::<F64, F32>(<F64 as WrapInto<F32>>::wrap_into)
+    }
+
+    /// Execute `f64x2.promote_low_f32x4` Wasm operation.
+    pub fn f64x2_promote_low_f32x4(self) -> Self {
+        self.simd_extend::<F32, F64>(false, <F32 as ExtendInto<F64>>::extend_into)
+    }
+}
+
+macro_rules! impl_simd_extend_ops {
+    (
+        $( $shape:ident: $narrow_s:ty, $narrow_u:ty => $wide_s:ty, $wide_u:ty, from $from:ident; )*
+    ) => {
+        paste::paste! {
+            impl UntypedValue {
+                $(
+                    #[doc = concat!("Execute `", stringify!($shape), ".extend_low_", stringify!($from), "_s` Wasm operation.")]
+                    pub fn [<$shape _extend_low_ $from _s>](self) -> Self {
+                        self.simd_extend::<$narrow_s, $wide_s>(false, <$wide_s>::from)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".extend_high_", stringify!($from), "_s` Wasm operation.")]
+                    pub fn [<$shape _extend_high_ $from _s>](self) -> Self {
+                        self.simd_extend::<$narrow_s, $wide_s>(true, <$wide_s>::from)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".extend_low_", stringify!($from), "_u` Wasm operation.")]
+                    pub fn [<$shape _extend_low_ $from _u>](self) -> Self {
+                        self.simd_extend::<$narrow_u, $wide_u>(false, <$wide_u>::from)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".extend_high_", stringify!($from), "_u` Wasm operation.")]
+                    pub fn [<$shape _extend_high_ $from _u>](self) -> Self {
+                        self.simd_extend::<$narrow_u, $wide_u>(true, <$wide_u>::from)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".extmul_low_", stringify!($from), "_s` Wasm operation.")]
+                    pub fn [<$shape _extmul_low_ $from _s>](self, rhs: Self) -> Self {
+                        self.simd_extmul::<$narrow_s, $wide_s>(rhs, false)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".extmul_high_", stringify!($from), "_s` Wasm operation.")]
+                    pub fn [<$shape _extmul_high_ $from _s>](self, rhs: Self) -> Self {
+                        self.simd_extmul::<$narrow_s, $wide_s>(rhs, true)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".extmul_low_", stringify!($from), "_u` Wasm operation.")]
+                    pub fn [<$shape _extmul_low_ $from _u>](self, rhs: Self) -> Self {
+                        self.simd_extmul::<$narrow_u, $wide_u>(rhs, false)
+                    }
+
+                    #[doc = concat!("Execute `", stringify!($shape), ".extmul_high_", stringify!($from), "_u` Wasm operation.")]
+                    pub fn [<$shape _extmul_high_ $from _u>](self, rhs: Self) -> Self {
+                        self.simd_extmul::<$narrow_u, $wide_u>(rhs, true)
+                    }
+                )*
+            }
+        }
+    };
+}
+impl_simd_extend_ops! {
+    i16x8: i8, u8 => i16, u16, from i8x16;
+    i32x4: i16, u16 => i32, u32, from i16x8;
+    i64x2: i32, u32 => i64, u64, from i32x4;
+}
+
+impl UntypedValue {
+    /// Execute `i16x8.extadd_pairwise_i8x16_s` Wasm operation.
+    pub fn i16x8_extadd_pairwise_i8x16_s(self) -> Self {
+        self.simd_extadd_pairwise::<i8, i16>()
+    }
+
+    /// Execute `i16x8.extadd_pairwise_i8x16_u` Wasm operation.
+    pub fn i16x8_extadd_pairwise_i8x16_u(self) -> Self {
+        self.simd_extadd_pairwise::<u8, u16>()
+    }
+
+    /// Execute `i32x4.extadd_pairwise_i16x8_s` Wasm operation.
+    pub fn i32x4_extadd_pairwise_i16x8_s(self) -> Self {
+        self.simd_extadd_pairwise::<i16, i32>()
+    }
+
+    /// Execute `i32x4.extadd_pairwise_i16x8_u` Wasm operation.
+    pub fn i32x4_extadd_pairwise_i16x8_u(self) -> Self {
+        self.simd_extadd_pairwise::<u16, u32>()
+    }
+}
+
+impl UntypedValue {
+    /// Loads `N` bytes from `memory` at the effective address of `address + offset`.
+    ///
+    /// The loaded bytes are stored in the lower bytes of the returned `u128`.
+    ///
+    /// # Errors
+    ///
+    /// - If `address + offset` overflows.
+    /// - If `address + offset` loads out of bounds from `memory`.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    unsafe fn simd_load_bytes<const N: usize>(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<u128, TrapCode> {
+        let address = effective_address(u64::from(address), offset)?;
+        let mut buffer = [0x00_u8; N];
+        buffer.load_into(memory, address)?;
+        let mut bytes = [0x00_u8; 16];
+        bytes[..N].copy_from_slice(&buffer);
+        Ok(u128::from_le_bytes(bytes))
+    }
+
+    /// Stores the `N` lower bytes of `value` to `memory` at the effective address of `address + offset`.
+    ///
+    /// # Errors
+    ///
+    /// - If `address + offset` overflows.
+    /// - If `address + offset` stores out of bounds from `memory`.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    unsafe fn simd_store_bytes<const N: usize>(
+        memory: *mut [u8],
+        address: Self,
+        offset: u64,
+        value: u128,
+    ) -> Result<(), TrapCode> {
+        let address = effective_address(u64::from(address), offset)?;
+        let mut buffer = [0x00_u8; N];
+        buffer.copy_from_slice(&value.to_le_bytes()[..N]);
+        buffer.store_from(memory, address)
+    }
+
+    /// Executes the `v128.load` Wasm operation.
+    ///
+    /// # Errors
+    ///
+    /// - If `address + offset` overflows.
+    /// - If `address + offset` loads out of bounds from `memory`.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn v128_load(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
+        Self::simd_load_bytes::<16>(memory, address, offset).map(Self::from_bits128)
+    }
+
+    /// Executes the `v128.store` Wasm operation.
+    ///
+    /// # Errors
+    ///
+    /// - If `address + offset` overflows.
+    /// - If `address + offset` stores out of bounds from `memory`.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn v128_store(
+        memory: *mut [u8],
+        address: Self,
+        offset: u64,
+        value: Self,
+    ) -> Result<(), TrapCode> {
+        Self::simd_store_bytes::<16>(memory, address, offset, value.to_bits128())
+    }
+
+    /// Executes the `v128.load32_zero` Wasm operation.
+    ///
+    /// # Errors
+    ///
+    /// - If `address + offset` overflows.
+    /// - If `address + offset` loads out of bounds from `memory`.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn v128_load32_zero(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
+        Self::simd_load_bytes::<4>(memory, address, offset).map(Self::from_bits128)
+    }
+
+    /// Executes the `v128.load64_zero` Wasm operation.
+    ///
+    /// # Errors
+    ///
+    /// - If `address + offset` overflows.
+    /// - If `address + offset` loads out of bounds from `memory`.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn v128_load64_zero(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
+        Self::simd_load_bytes::<8>(memory, address, offset).map(Self::from_bits128)
+    }
+}
+
+macro_rules! impl_simd_load_extend {
+    ( $( fn $name:ident = $extend:ident; )* ) => {
+        impl UntypedValue {
+            $(
+                #[doc = concat!("Executes the `", stringify!($name), "` Wasm operation.")]
+                ///
+                /// # Errors
+                ///
+                /// - If `address + offset` overflows.
+                /// - If `address + offset` loads out of bounds from `memory`.
+                ///
+                /// # Safety
+                ///
+                /// `memory` must be valid for reads.
+                pub unsafe fn $name(memory: *const [u8], address: Self, offset: u64) -> Result<Self, TrapCode> {
+                    Self::simd_load_bytes::<8>(memory, address, offset)
+                        .map(Self::from_bits128)
+                        .map(Self::$extend)
+                }
+            )*
+        }
+    };
+}
+impl_simd_load_extend! {
+    fn v128_load8x8_s = i16x8_extend_low_i8x16_s;
+    fn v128_load8x8_u = i16x8_extend_low_i8x16_u;
+    fn v128_load16x4_s = i32x4_extend_low_i16x8_s;
+    fn v128_load16x4_u = i32x4_extend_low_i16x8_u;
+    fn v128_load32x2_s = i64x2_extend_low_i32x4_s;
+    fn v128_load32x2_u = i64x2_extend_low_i32x4_u;
+}
+
+macro_rules! impl_simd_load_store_lane {
+    (
+        $(
+            fn $load_splat:ident, $load_lane:ident, $store_lane:ident = $lane:ty;
+        )*
+    ) => {
+        impl UntypedValue {
+            $(
+                #[doc = concat!("Executes the `", stringify!($load_splat), "` Wasm operation.")]
+                ///
+                /// # Errors
+                ///
+                /// - If `address + offset` overflows.
+                /// - If `address + offset` loads out of bounds from `memory`.
+                ///
+                /// # Safety
+                ///
+                /// `memory` must be valid for reads.
+                pub unsafe fn $load_splat(memory: *const [u8], address: Self, offset: u64) -> Result<Self, TrapCode> {
+                    const N: usize = core::mem::size_of::<$lane>();
+                    let bits = Self::simd_load_bytes::<N>(memory, address, offset)?;
+                    Ok(Self::simd_splat::<$lane>(bits as $lane))
+                }
+
+                #[doc = concat!("Executes the `", stringify!($load_lane), "` Wasm operation.")]
+                ///
+                /// Replaces the lane at index `lane` of `value` with the loaded value.
+                ///
+                /// # Errors
+                ///
+                /// - If `address + offset` overflows.
+                /// - If `address + offset` loads out of bounds from `memory`.
+                ///
+                /// # Safety
+                ///
+                /// `memory` must be valid for reads.
+                pub unsafe fn $load_lane(
+                    memory: *const [u8],
+                    address: Self,
+                    offset: u64,
+                    value: Self,
+                    lane: u8,
+                ) -> Result<Self, TrapCode> {
+                    const N: usize = core::mem::size_of::<$lane>();
+                    let bits = Self::simd_load_bytes::<N>(memory, address, offset)?;
+                    Ok(value.simd_replace_lane::<$lane>(lane, bits as $lane))
+                }
+
+                #[doc = concat!("Executes the `", stringify!($store_lane), "` Wasm operation.")]
+                ///
+                /// Stores the lane at index `lane` of `value`.
+                ///
+                /// # Errors
+                ///
+                /// - If `address + offset` overflows.
+                /// - If `address + offset` stores out of bounds from `memory`.
+                ///
+                /// # Safety
+                ///
+                /// `memory` must be valid for reads and writes.
+                pub unsafe fn $store_lane(
+                    memory: *mut [u8],
+                    address: Self,
+                    offset: u64,
+                    value: Self,
+                    lane: u8,
+                ) -> Result<(), TrapCode> {
+                    const N: usize = core::mem::size_of::<$lane>();
+                    let lane = value.simd_extract_lane::<$lane>(lane);
+                    Self::simd_store_bytes::<N>(memory, address, offset, u128::from(lane))
+                }
+            )*
+        }
+    };
+}
+impl_simd_load_store_lane! {
+    fn v128_load8_splat, v128_load8_lane, v128_store8_lane = u8;
+    fn v128_load16_splat, v128_load16_lane, v128_store16_lane = u16;
+    fn v128_load32_splat, v128_load32_lane, v128_store32_lane = u32;
+    fn v128_load64_splat, v128_load64_lane, v128_store64_lane = u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v128(bits: u128) -> UntypedValue {
+        UntypedValue::from(V128::from_bits(bits))
+    }
+
+    fn i32x4(lanes: [i32; 4]) -> UntypedValue {
+        UntypedValue::simd_from_lanes::<i32>(lanes)
+    }
+
+    #[test]
+    fn lanes_are_little_endian() {
+        let value = v128(0x0000_0004_0000_0003_0000_0002_0000_0001);
+        assert_eq!(value.simd_lanes::<i32>(), [1, 2, 3, 4]);
+        assert_eq!(value.simd_lanes::<i64>(), [0x2_0000_0001, 0x4_0000_0003]);
+    }
+
+    #[test]
+    fn scalar_values_do_not_leak_into_v128() {
+        let value = UntypedValue::from(-1_i64);
+        assert_eq!(value.to_bits128(), u128::from(u64::MAX));
+        assert_eq!(i64::from(value), -1);
+    }
+
+    #[test]
+    fn integer_arithmetic_wraps() {
+        let lhs = i32x4([i32::MAX, 1, -1, 0]);
+        let rhs = i32x4([1, 2, 1, 0]);
+        assert_eq!(lhs.i32x4_add(rhs).simd_lanes::<i32>(), [i32::MIN, 3, 0, 0]);
+        let lhs = UntypedValue::simd_splat::<u8>(250);
+        let rhs = UntypedValue::simd_splat::<u8>(10);
+        assert_eq!(lhs.i8x16_add_sat_u(rhs).simd_lanes::<u8>(), [u8::MAX; 16]);
+    }
+
+    #[test]
+    fn shifts_take_amount_modulo_lane_width() {
+        let value = UntypedValue::simd_splat::<i8>(-128);
+        let shifted = value.i8x16_shr_u(UntypedValue::from(9_i32));
+        assert_eq!(shifted.simd_lanes::<u8>(), [64; 16]);
+        let shifted = value.i8x16_shr_s(UntypedValue::from(9_i32));
+        assert_eq!(shifted.simd_lanes::<i8>(), [-64; 16]);
+    }
+
+    #[test]
+    fn comparisons_produce_masks() {
+        let lhs = i32x4([1, 2, 3, 4]);
+        let rhs = i32x4([4, 3, 2, 1]);
+        assert_eq!(lhs.i32x4_lt_s(rhs).simd_lanes::<i32>(), [-1, -1, 0, 0]);
+        assert_eq!(u32::from(lhs.i32x4_lt_s(rhs).i32x4_bitmask()), 0b0011);
+    }
+
+    #[test]
+    fn shuffle_and_swizzle() {
+        let lhs = v128(u128::from_le_bytes(core::array::from_fn(|n| n as u8)));
+        let rhs = v128(u128::from_le_bytes(core::array::from_fn(|n| 16 + n as u8)));
+        let selector = v128(u128::from_le_bytes(core::array::from_fn(|n| 2 * n as u8)));
+        let expected: [u8; 16] = core::array::from_fn(|n| 2 * n as u8);
+        assert_eq!(
+            lhs.i8x16_shuffle(rhs, selector).simd_lanes::<u8>(),
+            expected
+        );
+        let expected: [u8; 16] = core::array::from_fn(|n| if n < 8 { 2 * n as u8 } else { 0 });
+        assert_eq!(lhs.i8x16_swizzle(selector).simd_lanes::<u8>(), expected);
+    }
+
+    #[test]
+    fn narrow_saturates() {
+        let lhs = i32x4([70_000, -70_000, 1, -1]);
+        let rhs = i32x4([0; 4]);
+        assert_eq!(
+            lhs.i16x8_narrow_i32x4_s(rhs).simd_lanes::<i16>(),
+            [i16::MAX, i16::MIN, 1, -1, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            lhs.i16x8_narrow_i32x4_u(rhs).simd_lanes::<u16>(),
+            [u16::MAX, 0, 1, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn load_and_store_lanes() {
+        let mut memory = [0x00_u8; 32];
+        let value = i32x4([1, 2, 3, 4]);
+        // Safety: `memory` is valid for reads and writes.
+        unsafe {
+            UntypedValue::v128_store32_lane(&mut memory, UntypedValue::from(4_u32), 0, value, 2)
+        }
+        .unwrap();
+        assert_eq!(memory[4..8], 3_i32.to_le_bytes());
+        // Safety: `memory` is valid for reads.
+        let loaded =
+            unsafe { UntypedValue::v128_load32_zero(&memory, UntypedValue::from(4_u32), 0) };
+        assert_eq!(loaded.unwrap().simd_lanes::<i32>(), [3, 0, 0, 0]);
+        assert!(matches!(
+            // Safety: `memory` is valid for reads.
+            unsafe { UntypedValue::v128_load(&memory, UntypedValue::from(17_u32), 0) },
+            Err(TrapCode::MemoryOutOfBounds)
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::HostError;
+use crate::{HostError, WasmBacktrace};
 use alloc::{boxed::Box, string::String};
 use core::fmt::{self, Display};
 
@@ -13,8 +13,17 @@ use std::error::Error as StdError;
 /// host embedder.
 #[derive(Debug)]
 pub struct Trap {
-    /// The cloneable reason of a [`Trap`].
-    reason: Box<TrapReason>,
+    /// The reason and backtrace of a [`Trap`].
+    inner: Box<TrapInner>,
+}
+
+/// The boxed contents of a [`Trap`].
+#[derive(Debug)]
+struct TrapInner {
+    /// The reason of the [`Trap`].
+    reason: TrapReason,
+    /// The Wasm function frames on the call stack when the [`Trap`] occurred if any.
+    backtrace: Option<WasmBacktrace>,
 }
 
 #[test]
@@ -94,7 +103,10 @@ impl Trap {
     /// Create a new [`Trap`] from the [`TrapReason`].
     fn with_reason(reason: TrapReason) -> Self {
         Self {
-            reason: Box::new(reason),
+            inner: Box::new(TrapInner {
+                reason,
+                backtrace: None,
+            }),
         }
     }
 
@@ -115,7 +127,8 @@ impl Trap {
     where
         T: HostError,
     {
-        self.reason
+        self.inner
+            .reason
             .as_host()
             .and_then(<(dyn HostError + 'static)>::downcast_ref)
     }
@@ -128,7 +141,8 @@ impl Trap {
     where
         T: HostError,
     {
-        self.reason
+        self.inner
+            .reason
             .as_host_mut()
             .and_then(<(dyn HostError + 'static)>::downcast_mut)
     }
@@ -141,7 +155,8 @@ impl Trap {
     where
         T: HostError,
     {
-        self.reason
+        self.inner
+            .reason
             .into_host()
             .and_then(|error| error.downcast().ok())
             .map(|boxed| *boxed)
@@ -159,13 +174,33 @@ impl Trap {
     /// Otherwise returns `None`.
     #[inline]
     pub fn i32_exit_status(&self) -> Option<i32> {
-        self.reason.i32_exit_status()
+        self.inner.reason.i32_exit_status()
     }
 
     /// Returns the [`TrapCode`] traps originating from Wasm execution.
     #[inline]
     pub fn trap_code(&self) -> Option<TrapCode> {
-        self.reason.trap_code()
+        self.inner.reason.trap_code()
+    }
+
+    /// Returns the [`WasmBacktrace`] of the [`Trap`] if any.
+    ///
+    /// # Note
+    ///
+    /// Traps originating from Wasm execution as well as errors of host functions
+    /// called by Wasm functions carry a [`WasmBacktrace`] of the Wasm functions.
+    #[inline]
+    pub fn backtrace(&self) -> Option<&WasmBacktrace> {
+        self.inner.backtrace.as_ref()
+    }
+
+    /// Attaches the [`WasmBacktrace`] to the [`Trap`].
+    ///
+    /// This replaces the current [`WasmBacktrace`] of the [`Trap`] if any.
+    #[cold] // see Trap::new
+    pub fn with_backtrace(mut self, backtrace: WasmBacktrace) -> Self {
+        self.inner.backtrace = Some(backtrace);
+        self
     }
 }
 
@@ -200,7 +235,11 @@ impl Display for TrapReason {
 
 impl Display for Trap {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        <TrapReason as Display>::fmt(&self.reason, f)
+        <TrapReason as Display>::fmt(&self.inner.reason, f)?;
+        if let Some(backtrace) = self.backtrace().filter(|backtrace| !backtrace.is_empty()) {
+            write!(f, "\n\n{backtrace}")?;
+        }
+        Ok(())
     }
 }
 
@@ -216,7 +255,8 @@ impl StdError for Trap {
 /// See [`Trap`] for details.
 ///
 /// [`Trap`]: struct.Trap.html
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TrapCode {
     /// Wasm code executed `unreachable` opcode.
     ///
@@ -284,6 +324,34 @@ pub enum TrapCode {
     /// internal bytecode so that fuel is consumed for each executed instruction.
     /// This is useful to deterministically halt or yield a WebAssembly execution.
     OutOfFuel,
+
+    /// This trap is raised when a growth operation was attempted and an
+    /// installed `wasmi::ResourceLimiter` returned `Err(...)` from the
+    /// associated `table_growing` or `memory_growing` method, indicating a
+    /// desire on the part of the embedder to trap the interpreter rather than
+    /// merely fail the growth operation.
+    GrowthOperationLimited,
+
+    /// This trap is raised when a WebAssembly execution reached the epoch deadline
+    /// of its `wasmi::Store` while epoch interruption is enabled.
+    ///
+    /// The `wasmi` execution engine can be configured to check the current epoch
+    /// of its `wasmi::Engine` at function entries and loop headers. This is useful
+    /// to cheaply interrupt long running WebAssembly executions from another thread.
+    EpochDeadlineExceeded,
+
+    /// An atomic memory access was attempted at an address that is not
+    /// naturally aligned for the accessed type.
+    ///
+    /// Unlike ordinary memory accesses atomic memory accesses require
+    /// natural alignment as defined by the Wasm `threads` proposal.
+    UnalignedAtomic,
+
+    /// A `memory.atomic.wait` instruction was executed on a non-shared linear memory.
+    ///
+    /// Waiting on a non-shared linear memory would block forever since
+    /// no other thread could ever notify the waiting thread.
+    ExpectedSharedMemory,
 }
 
 impl TrapCode {
@@ -305,6 +373,10 @@ impl TrapCode {
             Self::StackOverflow => "call stack exhausted",
             Self::BadSignature => "indirect call type mismatch",
             Self::OutOfFuel => "all fuel consumed by WebAssembly",
+            Self::GrowthOperationLimited => "growth operation limited",
+            Self::EpochDeadlineExceeded => "epoch deadline exceeded",
+            Self::UnalignedAtomic => "unaligned atomic",
+            Self::ExpectedSharedMemory => "expected shared memory",
         }
     }
 }
@@ -1,7 +1,7 @@
 /// An amount of linear memory pages.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
-pub struct Pages(u32);
+pub struct Pages(u64);
 
 impl Pages {
     /// The maximum amount of pages on the `wasm32` target.
@@ -15,6 +15,18 @@ impl Pages {
     pub const fn max() -> Self {
         Self(65536) // 2^16
     }
+
+    /// The maximum amount of pages on the `wasm64` target.
+    ///
+    /// # Note
+    ///
+    /// This is the maximum since the `memory64` Wasm proposal uses
+    /// 64-bit addresses and a page is 2^16 bytes in size. Therefore
+    /// there can be at most 2^48 pages of a single 64-bit linear memory
+    /// so that all bytes are still accessible.
+    pub const fn max64() -> Self {
+        Self(1 << 48) // 2^48
+    }
 }
 
 impl From<u16> for Pages {
@@ -27,7 +39,7 @@ impl From<u16> for Pages {
     ///
     /// [`Pages::max()`]: struct.Pages.html#method.max
     fn from(amount: u16) -> Self {
-        Self(u32::from(amount))
+        Self(u64::from(amount))
     }
 }
 
@@ -38,7 +50,20 @@ impl Pages {
     ///
     /// [`Pages::max()`]: struct.Pages.html#method.max
     pub fn new(amount: u32) -> Option<Self> {
-        if amount > u32::from(Self::max()) {
+        let amount = u64::from(amount);
+        if amount > u64::from(Self::max()) {
+            return None;
+        }
+        Some(Self(amount))
+    }
+
+    /// Creates a new amount of [`Pages`] for a 64-bit linear memory if the amount is within bounds.
+    ///
+    /// Returns `None` if the given `amount` of [`Pages`] exceeds [`Pages::max64()`].
+    ///
+    /// [`Pages::max64()`]: struct.Pages.html#method.max64
+    pub fn new64(amount: u64) -> Option<Self> {
+        if amount > u64::from(Self::max64()) {
             return None;
         }
         Some(Self(amount))
@@ -47,13 +72,20 @@ impl Pages {
     /// Adds the given amount of pages to `self`.
     ///
     /// Returns `Some` if the result is within bounds and `None` otherwise.
+    ///
+    /// # Note
+    ///
+    /// The result is bounded by [`Pages::max64()`]. Linear memories
+    /// are responsible to check against their own maximum amount of pages.
+    ///
+    /// [`Pages::max64()`]: struct.Pages.html#method.max64
     pub fn checked_add<T>(self, rhs: T) -> Option<Self>
     where
-        T: Into<u32>,
+        T: Into<u64>,
     {
-        let lhs: u32 = self.into();
-        let rhs: u32 = rhs.into();
-        lhs.checked_add(rhs).and_then(Self::new)
+        let lhs: u64 = self.into();
+        let rhs: u64 = rhs.into();
+        lhs.checked_add(rhs).and_then(Self::new64)
     }
 
     /// Substracts the given amount of pages from `self`.
@@ -61,11 +93,11 @@ impl Pages {
     /// Returns `None` if the subtraction underflows or the result is out of bounds.
     pub fn checked_sub<T>(self, rhs: T) -> Option<Self>
     where
-        T: Into<u32>,
+        T: Into<u64>,
     {
-        let lhs: u32 = self.into();
-        let rhs: u32 = rhs.into();
-        lhs.checked_sub(rhs).and_then(Self::new)
+        let lhs: u64 = self.into();
+        let rhs: u64 = rhs.into();
+        lhs.checked_sub(rhs).and_then(Self::new64)
     }
 
     /// Returns the amount of bytes required for the amount of [`Pages`].
@@ -77,12 +109,20 @@ impl Pages {
     }
 }
 
-impl From<Pages> for u32 {
+impl From<Pages> for u64 {
     fn from(pages: Pages) -> Self {
         pages.0
     }
 }
 
+impl TryFrom<Pages> for u32 {
+    type Error = core::num::TryFromIntError;
+
+    fn try_from(pages: Pages) -> Result<Self, Self::Error> {
+        u32::try_from(pages.0)
+    }
+}
+
 /// An amount of bytes of a linear memory.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -99,9 +139,14 @@ impl Bytes {
         i32::MAX as u64 + 1
     }
 
-    /// A 64-bit platform can represent all possible u32::MAX + 1 Wasm pages.
+    /// A 64-bit platform can represent at most i64::MAX + 1 bytes.
+    ///
+    /// # Note
+    ///
+    /// This is sufficient to represent all 2^16 pages of a 32-bit linear memory
+    /// but not all of the 2^48 pages that a 64-bit linear memory may have.
     const fn max64() -> u64 {
-        u32::MAX as u64 + 1
+        i64::MAX as u64 + 1
     }
 
     /// The bytes per WebAssembly linear memory page.
@@ -170,7 +215,7 @@ impl Bytes {
 
     /// Actual underlying implementation of [`Bytes::new`].
     fn new_impl(pages: Pages, max: u64) -> Option<Bytes> {
-        let pages = u64::from(u32::from(pages));
+        let pages = u64::from(pages);
         let bytes_per_page = usize::from(Self::per_page()) as u64;
         let bytes = pages
             .checked_mul(bytes_per_page)
@@ -201,6 +246,7 @@ mod tests {
     #[test]
     fn pages_max() {
         assert_eq!(Pages::max(), pages(u32::from(u16::MAX) + 1));
+        assert_eq!(Pages::max64(), Pages::new64(1 << 48).unwrap());
     }
 
     #[test]
@@ -210,16 +256,29 @@ mod tests {
         assert_eq!(Pages::new(1000), Some(Pages(1000)));
         assert_eq!(
             Pages::new(u32::from(u16::MAX)),
-            Some(Pages(u32::from(u16::MAX)))
+            Some(Pages(u64::from(u16::MAX)))
         );
         assert_eq!(Pages::new(u32::from(u16::MAX) + 1), Some(Pages::max()));
         assert_eq!(Pages::new(u32::from(u16::MAX) + 2), None);
         assert_eq!(Pages::new(u32::MAX), None);
     }
 
+    #[test]
+    fn pages_new64() {
+        assert_eq!(Pages::new64(0), Some(Pages(0)));
+        assert_eq!(
+            Pages::new64(u64::from(u32::MAX)),
+            Some(Pages(u64::from(u32::MAX)))
+        );
+        assert_eq!(Pages::new64(1 << 48), Some(Pages::max64()));
+        assert_eq!(Pages::new64((1 << 48) + 1), None);
+        assert_eq!(Pages::new64(u64::MAX), None);
+    }
+
     #[test]
     fn pages_checked_add() {
-        let max_pages = u32::from(Pages::max());
+        let max_pages = u64::from(Pages::max());
+        let max64_pages = u64::from(Pages::max64());
 
         assert_eq!(pages(0).checked_add(0u32), Some(pages(0)));
         assert_eq!(pages(0).checked_add(1u32), Some(pages(1)));
@@ -227,12 +286,14 @@ mod tests {
 
         assert_eq!(pages(0).checked_add(max_pages), Some(Pages::max()));
         assert_eq!(pages(0).checked_add(Pages::max()), Some(Pages::max()));
-        assert_eq!(pages(1).checked_add(max_pages), None);
-        assert_eq!(pages(1).checked_add(Pages::max()), None);
+        assert_eq!(pages(0).checked_add(max64_pages), Some(Pages::max64()));
+        assert_eq!(pages(0).checked_add(Pages::max64()), Some(Pages::max64()));
+        assert_eq!(pages(1).checked_add(max64_pages), None);
+        assert_eq!(pages(1).checked_add(Pages::max64()), None);
 
-        assert_eq!(Pages::max().checked_add(0u32), Some(Pages::max()));
-        assert_eq!(Pages::max().checked_add(1u32), None);
-        assert_eq!(pages(0).checked_add(u32::MAX), None);
+        assert_eq!(Pages::max64().checked_add(0u32), Some(Pages::max64()));
+        assert_eq!(Pages::max64().checked_add(1u32), None);
+        assert_eq!(pages(0).checked_add(u64::MAX), None);
 
         for i in 0..100 {
             for j in 0..100 {
@@ -243,7 +304,7 @@ mod tests {
 
     #[test]
     fn pages_checked_sub() {
-        let max_pages = u32::from(Pages::max());
+        let max_pages = u32::try_from(Pages::max()).unwrap();
 
         assert_eq!(pages(0).checked_sub(0u32), Some(pages(0)));
         assert_eq!(pages(0).checked_sub(1u32), None);
@@ -309,8 +370,11 @@ mod tests {
                 Some(bytes(n * bytes_per_page))
             );
         }
-        assert!(Bytes::new64(Pages(u32::from(u16::MAX) + 1)).is_some());
-        assert!(Bytes::new64(Pages(u32::from(u16::MAX) + 2)).is_none());
+        assert!(Bytes::new64(Pages(u64::from(u16::MAX) + 1)).is_some());
+        assert!(Bytes::new64(Pages(u64::from(u16::MAX) + 2)).is_some());
         assert!(Bytes::new64(Pages::max()).is_some());
+        assert!(Bytes::new64(Pages(1 << 47)).is_some());
+        assert!(Bytes::new64(Pages((1 << 47) + 1)).is_none());
+        assert!(Bytes::new64(Pages::max64()).is_none());
     }
 }
@@ -19,6 +19,20 @@ use core::{
 };
 use paste::paste;
 
+/// The underlying bits of an [`UntypedValue`].
+///
+/// # Note
+///
+/// Only with the `simd` crate feature enabled the bits are wide enough
+/// to represent `v128` values so that all other workloads do not pay
+/// for twice the memory footprint of their values.
+#[cfg(feature = "simd")]
+type Bits = u128;
+
+/// The underlying bits of an [`UntypedValue`].
+#[cfg(not(feature = "simd"))]
+type Bits = u64;
+
 /// An untyped value.
 ///
 /// Provides a dense and simple interface to all functional Wasm operations.
@@ -26,15 +40,32 @@ use paste::paste;
 #[repr(transparent)]
 pub struct UntypedValue {
     /// This inner value is required to have enough bits to represent
-    /// all fundamental WebAssembly types `i32`, `i64`, `f32` and `f64`.
-    bits: u64,
+    /// all fundamental WebAssembly types `i32`, `i64`, `f32` and `f64`
+    /// as well as `v128` if the `simd` crate feature is enabled.
+    bits: Bits,
 }
 
 impl UntypedValue {
-    /// Returns the underlying bits of the [`UntypedValue`].
+    /// Returns the lower 64 underlying bits of the [`UntypedValue`].
+    ///
+    /// # Note
+    ///
+    /// This is sufficient to represent all non-`v128` WebAssembly values.
     pub fn to_bits(self) -> u64 {
+        self.bits as u64
+    }
+
+    /// Returns all 128 underlying bits of the [`UntypedValue`].
+    #[cfg(feature = "simd")]
+    pub fn to_bits128(self) -> u128 {
         self.bits
     }
+
+    /// Creates an [`UntypedValue`] from its 128 underlying bits.
+    #[cfg(feature = "simd")]
+    pub fn from_bits128(bits: u128) -> Self {
+        Self { bits }
+    }
 }
 
 macro_rules! impl_from_untyped_for_int {
@@ -127,13 +158,18 @@ macro_rules! op {
 
 /// Calculates the effective address of a linear memory access.
 ///
+/// # Note
+///
+/// Addresses of 32-bit linear memories are zero-extended to 64-bit
+/// so that this computation serves both 32-bit and 64-bit linear memories.
+///
 /// # Errors
 ///
 /// If the resulting effective address overflows.
-fn effective_address(address: u32, offset: u32) -> Result<usize, TrapCode> {
+pub(crate) fn effective_address(address: u64, offset: u64) -> Result<usize, TrapCode> {
     offset
         .checked_add(address)
-        .map(|address| address as usize)
+        .and_then(|address| usize::try_from(address).ok())
         .ok_or(TrapCode::MemoryOutOfBounds)
 }
 
@@ -144,12 +180,20 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    fn load_extend<T, U>(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode>
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    unsafe fn load_extend<T, U>(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode>
     where
         T: Into<Self>,
         U: LittleEndianConvert + ExtendInto<T>,
     {
-        let raw_address = u32::from(address);
+        let raw_address = u64::from(address);
         let address = effective_address(raw_address, offset)?;
         let mut buffer = <<U as LittleEndianConvert>::Bytes as Default>::default();
         buffer.load_into(memory, address)?;
@@ -165,7 +209,11 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    fn load<T>(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode>
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    unsafe fn load<T>(memory: *const [u8], address: Self, offset: u64) -> Result<Self, TrapCode>
     where
         T: LittleEndianConvert + ExtendInto<T> + Into<Self>,
     {
@@ -178,7 +226,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i32_load(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i32_load(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load::<i32>(memory, address, offset)
     }
 
@@ -188,7 +244,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i64_load(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i64_load(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load::<i64>(memory, address, offset)
     }
 
@@ -198,7 +262,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn f32_load(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn f32_load(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load::<F32>(memory, address, offset)
     }
 
@@ -208,7 +280,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn f64_load(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn f64_load(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load::<F64>(memory, address, offset)
     }
 
@@ -218,7 +298,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i32_load8_s(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i32_load8_s(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i32, i8>(memory, address, offset)
     }
 
@@ -228,7 +316,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i32_load8_u(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i32_load8_u(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i32, u8>(memory, address, offset)
     }
 
@@ -238,7 +334,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i32_load16_s(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i32_load16_s(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i32, i16>(memory, address, offset)
     }
 
@@ -248,7 +352,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i32_load16_u(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i32_load16_u(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i32, u16>(memory, address, offset)
     }
 
@@ -258,7 +370,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i64_load8_s(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i64_load8_s(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i64, i8>(memory, address, offset)
     }
 
@@ -268,7 +388,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i64_load8_u(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i64_load8_u(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i64, u8>(memory, address, offset)
     }
 
@@ -278,7 +406,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i64_load16_s(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i64_load16_s(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i64, i16>(memory, address, offset)
     }
 
@@ -288,7 +424,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i64_load16_u(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i64_load16_u(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i64, u16>(memory, address, offset)
     }
 
@@ -298,7 +442,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i64_load32_s(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i64_load32_s(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i64, i32>(memory, address, offset)
     }
 
@@ -308,7 +460,15 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` loads out of bounds from `memory`.
-    pub fn i64_load32_u(memory: &[u8], address: Self, offset: u32) -> Result<Self, TrapCode> {
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    pub unsafe fn i64_load32_u(
+        memory: *const [u8],
+        address: Self,
+        offset: u64,
+    ) -> Result<Self, TrapCode> {
         Self::load_extend::<i64, u32>(memory, address, offset)
     }
 
@@ -318,17 +478,21 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    fn store_wrap<T, U>(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    unsafe fn store_wrap<T, U>(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode>
     where
         T: From<Self> + WrapInto<U>,
         U: LittleEndianConvert,
     {
-        let raw_address = u32::from(address);
+        let raw_address = u64::from(address);
         let address = effective_address(raw_address, offset)?;
         let wrapped = T::from(value).wrap_into();
         let buffer = <U as LittleEndianConvert>::into_le_bytes(wrapped);
@@ -342,7 +506,16 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    fn store<T>(memory: &mut [u8], address: Self, offset: u32, value: Self) -> Result<(), TrapCode>
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    unsafe fn store<T>(
+        memory: *mut [u8],
+        address: Self,
+        offset: u64,
+        value: Self,
+    ) -> Result<(), TrapCode>
     where
         T: From<Self> + WrapInto<T> + LittleEndianConvert,
     {
@@ -355,10 +528,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn i32_store(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn i32_store(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store::<i32>(memory, address, offset, value)
@@ -370,10 +547,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn i64_store(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn i64_store(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store::<i64>(memory, address, offset, value)
@@ -385,10 +566,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn f32_store(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn f32_store(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store::<F32>(memory, address, offset, value)
@@ -400,10 +585,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn f64_store(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn f64_store(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store::<F64>(memory, address, offset, value)
@@ -415,10 +604,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn i32_store8(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn i32_store8(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store_wrap::<i32, i8>(memory, address, offset, value)
@@ -430,10 +623,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn i32_store16(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn i32_store16(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store_wrap::<i32, i16>(memory, address, offset, value)
@@ -445,10 +642,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn i64_store8(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn i64_store8(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store_wrap::<i64, i8>(memory, address, offset, value)
@@ -460,10 +661,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn i64_store16(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn i64_store16(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store_wrap::<i64, i16>(memory, address, offset, value)
@@ -475,10 +680,14 @@ impl UntypedValue {
     ///
     /// - If `address + offset` overflows.
     /// - If `address + offset` stores out of bounds from `memory`.
-    pub fn i64_store32(
-        memory: &mut [u8],
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    pub unsafe fn i64_store32(
+        memory: *mut [u8],
         address: Self,
-        offset: u32,
+        offset: u64,
         value: Self,
     ) -> Result<(), TrapCode> {
         Self::store_wrap::<i64, i32>(memory, address, offset, value)
@@ -2,7 +2,7 @@ use crate::{
     nan_preserving_float::{F32, F64},
     TrapCode,
 };
-use core::{f32, i32, i64, u32, u64};
+use core::{f32, i32, i64, ptr, u32, u64};
 
 /// Type of a value.
 ///
@@ -19,6 +19,9 @@ pub enum ValueType {
     F32,
     /// 64-bit IEEE 754-2008 floating point number.
     F64,
+    /// 128-bit vector of packed integer or floating point lanes.
+    #[cfg(feature = "simd")]
+    V128,
     /// A nullable function reference.
     FuncRef,
     /// A nullable external reference.
@@ -34,6 +37,14 @@ impl ValueType {
         matches!(self, Self::I32 | Self::I64 | Self::F32 | Self::F64)
     }
 
+    /// Returns `true` if [`ValueType`] is a Wasm vector type.
+    ///
+    /// This is `true` for [`ValueType::V128`].
+    #[cfg(feature = "simd")]
+    pub fn is_vec(&self) -> bool {
+        matches!(self, Self::V128)
+    }
+
     /// Returns `true` if [`ValueType`] is a Wasm reference type.
     ///
     /// This is `true` for [`ValueType::FuncRef`] and [`ValueType::ExternRef`].
@@ -103,21 +114,27 @@ pub trait TransmuteInto<T> {
 pub trait LoadInto {
     /// Loads bytes from `memory` into `self`.
     ///
+    /// # Note
+    ///
+    /// The `memory` is accessed through a raw pointer since the bytes
+    /// of shared linear memories might be concurrently accessed by other threads.
+    ///
     /// # Errors
     ///
     /// Traps if the `memory` access is out of bounds.
-    fn load_into(&mut self, memory: &[u8], address: usize) -> Result<(), TrapCode>;
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads.
+    unsafe fn load_into(&mut self, memory: *const [u8], address: usize) -> Result<(), TrapCode>;
 }
 
 impl<const N: usize> LoadInto for [u8; N] {
     #[inline]
-    fn load_into(&mut self, memory: &[u8], address: usize) -> Result<(), TrapCode> {
-        let slice: &Self = memory
-            .get(address..)
-            .and_then(|slice| slice.get(..N))
-            .and_then(|slice| slice.try_into().ok())
-            .ok_or(TrapCode::MemoryOutOfBounds)?;
-        *self = *slice;
+    unsafe fn load_into(&mut self, memory: *const [u8], address: usize) -> Result<(), TrapCode> {
+        checked_range::<N>(memory.len(), address)?;
+        // Safety: the `N` loaded bytes are within the bounds of `memory`.
+        ptr::copy_nonoverlapping(memory.cast::<u8>().add(address), self.as_mut_ptr(), N);
         Ok(())
     }
 }
@@ -126,25 +143,45 @@ impl<const N: usize> LoadInto for [u8; N] {
 pub trait StoreFrom {
     /// Writes bytes from `self` to `memory`.
     ///
+    /// # Note
+    ///
+    /// The `memory` is accessed through a raw pointer since the bytes
+    /// of shared linear memories might be concurrently accessed by other threads.
+    ///
     /// # Errors
     ///
     /// Traps if the `memory` access is out of bounds.
-    fn store_from(&self, memory: &mut [u8], address: usize) -> Result<(), TrapCode>;
+    ///
+    /// # Safety
+    ///
+    /// `memory` must be valid for reads and writes.
+    unsafe fn store_from(&self, memory: *mut [u8], address: usize) -> Result<(), TrapCode>;
 }
 
 impl<const N: usize> StoreFrom for [u8; N] {
     #[inline]
-    fn store_from(&self, memory: &mut [u8], address: usize) -> Result<(), TrapCode> {
-        let slice: &mut Self = memory
-            .get_mut(address..)
-            .and_then(|slice| slice.get_mut(..N))
-            .and_then(|slice| slice.try_into().ok())
-            .ok_or(TrapCode::MemoryOutOfBounds)?;
-        *slice = *self;
+    unsafe fn store_from(&self, memory: *mut [u8], address: usize) -> Result<(), TrapCode> {
+        checked_range::<N>(memory.len(), address)?;
+        // Safety: the `N` stored bytes are within the bounds of `memory`.
+        ptr::copy_nonoverlapping(self.as_ptr(), memory.cast::<u8>().add(address), N);
         Ok(())
     }
 }
 
+/// Checks that `N` bytes starting at `address` are within the bounds of `len` bytes.
+///
+/// # Errors
+///
+/// Traps if the accessed bytes are out of bounds.
+#[inline]
+fn checked_range<const N: usize>(len: usize, address: usize) -> Result<(), TrapCode> {
+    address
+        .checked_add(N)
+        .filter(|&end| end <= len)
+        .map(|_| ())
+        .ok_or(TrapCode::MemoryOutOfBounds)
+}
+
 /// Types that can be converted from and to little endian bytes.
 pub trait LittleEndianConvert {
     /// The little endian bytes representation.
@@ -1,16 +1,43 @@
+use core::marker::PhantomData;
+use wasmi::SharedMemory;
 use wiggle::{borrow::BorrowChecker, BorrowHandle, GuestError, GuestMemory, Region};
 
 /// Lightweight `wasmi::Memory` wrapper so we can implement the
 /// `wiggle::GuestMemory` trait on it.
 pub struct WasmiGuestMemory<'a> {
-    mem: &'a mut [u8],
+    base: *mut u8,
+    len: u32,
     bc: BorrowChecker,
+    lifetime: PhantomData<&'a mut [u8]>,
 }
 
+// Safety: `WasmiGuestMemory` only hands out its bytes through `wiggle` which
+//         guards all accesses via its `BorrowChecker`. The bytes of shared linear
+//         memories are meant to be accessed from multiple threads anyways.
+unsafe impl Send for WasmiGuestMemory<'_> {}
+unsafe impl Sync for WasmiGuestMemory<'_> {}
+
 impl<'a> WasmiGuestMemory<'a> {
     pub fn new(mem: &'a mut [u8]) -> Self {
+        Self::from_raw(mem.as_mut_ptr(), mem.len())
+    }
+
+    /// Creates a [`WasmiGuestMemory`] for the bytes of a [`SharedMemory`].
+    ///
+    /// # Note
+    ///
+    /// There are no Rust slices to the bytes of shared linear memories since other
+    /// threads might concurrently modify them. Instead the bytes are accessed through
+    /// the raw pointer returned by [`SharedMemory::data_ptr`] which stays valid for
+    /// the whole lifetime of the [`SharedMemory`].
+    pub fn shared(mem: &'a SharedMemory) -> Self {
+        Self::from_raw(mem.data_ptr(), mem.data_size())
+    }
+
+    fn from_raw(base: *mut u8, len: usize) -> Self {
         Self {
-            mem,
+            base,
+            len: len as u32,
             // Wiggle does not expose any methods for functions to re-enter
             // the WebAssembly instance, or expose the memory via non-wiggle
             // mechanisms. However, the user-defined code may end up
@@ -18,13 +45,14 @@ impl<'a> WasmiGuestMemory<'a> {
             // implementation - we require exactly one BorrowChecker exist per
             // instance.
             bc: BorrowChecker::new(),
+            lifetime: PhantomData,
         }
     }
 }
 
 unsafe impl GuestMemory for WasmiGuestMemory<'_> {
     fn base(&self) -> (*mut u8, u32) {
-        (self.mem.as_ptr() as *mut u8, self.mem.len() as u32)
+        (self.base, self.len)
     }
     fn has_outstanding_borrows(&self) -> bool {
         self.bc.has_outstanding_borrows()
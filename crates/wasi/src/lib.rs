@@ -1,11 +1,18 @@
 //! This crate provides support for WASI `preview1` for the `wasmi` interpreter.
 //!
 //! Use [`add_to_linker`] to add all supported WASI definitions to the `wasmi` linker.
+//!
+//! With the `tokio` feature enabled use `tokio::add_to_linker_async` instead to add
+//! all supported WASI definitions as asynchronous host functions backed by `tokio`.
 
 mod guest_memory;
+#[cfg(any(feature = "sync", feature = "tokio"))]
+mod preview_1;
 
 #[cfg(feature = "sync")]
 pub mod sync;
+#[cfg(feature = "tokio")]
+pub mod tokio;
 
 pub use self::guest_memory::WasmiGuestMemory;
 pub use wasi_common::{Error, WasiCtx, WasiDir, WasiFile};
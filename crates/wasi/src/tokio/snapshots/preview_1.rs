@@ -0,0 +1,71 @@
+use crate::{preview_1::for_each_preview1_func, WasmiGuestMemory};
+use wasi_common::Error;
+use wasmi::{core::Trap, Caller, Extern, Linker};
+
+// Creates the function item `add_wasi_snapshot_preview1_to_linker_async` which when called adds all
+// `wasi preview_1` functions as asynchronous host functions to the linker
+macro_rules! impl_add_to_linker_async_for_funcs {
+    (
+        $(
+            $( #[$docs:meta] )*
+            fn $fname:ident ($( $arg:ident : $typ:ty ),* $(,)? ) -> $ret:tt
+        );+ $(;)?
+    ) => {
+        /// Adds the entire `WASI API` as asynchronous host functions to the [`Linker`].
+        ///
+        /// This is the asynchronous counterpart of the synchronous `add_to_linker` where
+        /// WASI operations such as file, socket and `poll_oneoff` operations genuinely
+        /// await without blocking the thread that executes the Wasm guest.
+        ///
+        /// # Note
+        ///
+        /// - Wasm functions using the WASI definitions of this [`Linker`] must be called via
+        ///   [`Func::call_async`] or [`TypedFunc::call_async`] within a `tokio` runtime.
+        /// - Use the `WasiCtxBuilder` of this module to construct the `WasiCtx`
+        ///   so that its I/O operations are backed by `tokio`.
+        ///
+        /// [`Func::call_async`]: wasmi::Func::call_async
+        /// [`TypedFunc::call_async`]: wasmi::TypedFunc::call_async
+        pub fn add_wasi_snapshot_preview1_to_linker_async<T, U>(
+            linker: &mut Linker<T>,
+            wasi_ctx: impl Fn(&mut T) -> &mut U + Send + Sync + Copy + 'static,
+        ) -> Result<(), Error>
+        where T: Send,
+              U: wasi_common::snapshots::preview_1::wasi_snapshot_preview1::WasiSnapshotPreview1 +
+                 wasi_common::snapshots::preview_1::wasi_snapshot_preview1::UserErrorConversion +
+                 Send
+        {
+            $(
+                linker.func_wrap_async(
+                    "wasi_snapshot_preview1",
+                    stringify!($fname),
+                    move |mut caller: Caller<'_, T>, ($($arg,)*): ($($typ,)*)| {
+                        Box::new(async move {
+                            let memory = match caller.get_export("memory") {
+                                Some(Extern::Memory(m)) => m,
+                                _ => return Err(Trap::new(String::from("missing required WASI memory export"))),
+                            };
+                            let shared = memory.shared(&caller);
+                            let (memory, ctx) = match &shared {
+                                Some(shared) => (WasmiGuestMemory::shared(shared), caller.data_mut()),
+                                None => {
+                                    let (memory, ctx) = memory.data_and_store_mut(&mut caller);
+                                    (WasmiGuestMemory::new(memory), ctx)
+                                }
+                            };
+                            let ctx = wasi_ctx(ctx);
+                            match wasi_common::snapshots::preview_1::wasi_snapshot_preview1::$fname(ctx, &memory, $($arg,)*).await {
+                                Ok(r) => Ok(<$ret>::from(r)),
+                                Err(wiggle::Trap::String(err)) => Err(Trap::new(err)),
+                                Err(wiggle::Trap::I32Exit(i)) => Err(Trap::i32_exit(i)),
+                            }
+                        })
+                    }
+                )?;
+            )*
+            Ok(())
+        }
+    }
+}
+
+for_each_preview1_func!(impl_add_to_linker_async_for_funcs);
@@ -1,4 +1,6 @@
+use std::io::Cursor;
 use wasi_cap_std_sync::WasiCtxBuilder;
+use wasi_common::pipe::WritePipe;
 use wasmi::{Config, Engine, Extern, Instance, Linker, Module, Store};
 use wasmi_wasi::{add_to_linker, WasiCtx};
 
@@ -45,3 +47,35 @@ fn test_hello_world() {
     let mut result = [];
     f.call(&mut store, &[], &mut result).unwrap();
 }
+
+#[test]
+fn test_hello_world_shared_memory() {
+    let wasm = wat2wasm(include_bytes!("wat/hello_world_shared.wat"));
+    let mut config = Config::default();
+    config.wasm_threads(true);
+    let engine = Engine::new(&config);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<WasiCtx>>::new(&engine);
+    add_to_linker(&mut linker, |ctx| ctx).unwrap();
+    let stdout = WritePipe::new_in_memory();
+    let wasi = WasiCtxBuilder::new()
+        .stdout(Box::new(stdout.clone()))
+        .build();
+    let mut store = Store::new(&engine, wasi);
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    instance
+        .get_typed_func::<(), ()>(&store, "_start")
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap();
+    drop(store);
+    let stdout = stdout
+        .try_into_inner()
+        .map(Cursor::into_inner)
+        .expect("no other references to stdout must exist");
+    assert_eq!(stdout, b"hello world\n");
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "tokio")]
+
+use std::io::Cursor;
+use wasi_common::pipe::WritePipe;
+use wasmi::{Config, Engine, Linker, Module, Store};
+use wasmi_wasi::{
+    tokio::{add_to_linker_async, WasiCtxBuilder},
+    WasiCtx,
+};
+
+/// Runs the `hello_world.wat` test module asynchronously and returns what it wrote to stdout.
+async fn run_hello_world() -> Vec<u8> {
+    run_hello_world_with(include_bytes!("wat/hello_world.wat"), &Config::default()).await
+}
+
+/// Runs the `wat` test module asynchronously with `config` and returns what it wrote to stdout.
+async fn run_hello_world_with(wat: &[u8], config: &Config) -> Vec<u8> {
+    let wasm = wat::parse_bytes(wat).unwrap();
+    let engine = Engine::new(config);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<WasiCtx>>::new(&engine);
+    add_to_linker_async(&mut linker, |ctx| ctx).unwrap();
+    let stdout = WritePipe::new_in_memory();
+    let wasi = WasiCtxBuilder::new()
+        .stdout(Box::new(stdout.clone()))
+        .build();
+    let mut store = Store::new(&engine, wasi);
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    instance
+        .get_typed_func::<(), ()>(&store, "_start")
+        .unwrap()
+        .call_async(&mut store, ())
+        .await
+        .unwrap();
+    drop(store);
+    stdout
+        .try_into_inner()
+        .map(Cursor::into_inner)
+        .expect("no other references to stdout must exist")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_hello_world_async() {
+    assert_eq!(run_hello_world().await, b"hello world\n");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_hello_world_shared_memory_async() {
+    let mut config = Config::default();
+    config.wasm_threads(true);
+    let stdout = run_hello_world_with(include_bytes!("wat/hello_world_shared.wat"), &config).await;
+    assert_eq!(stdout, b"hello world\n");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_hello_world_async_concurrently() {
+    let guests: Vec<_> = (0..4).map(|_| tokio::spawn(run_hello_world())).collect();
+    for guest in guests {
+        assert_eq!(guest.await.unwrap(), b"hello world\n");
+    }
+}
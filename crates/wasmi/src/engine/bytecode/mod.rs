@@ -1,24 +1,33 @@
 //! The instruction architecture of the `wasmi` interpreter.
 
+mod serialize;
 mod utils;
 
 #[cfg(test)]
 mod tests;
 
-pub use self::utils::{
-    AddressOffset,
-    BlockFuel,
-    BranchOffset,
-    BranchTableTargets,
-    DataSegmentIdx,
-    DropKeep,
-    DropKeepError,
-    ElementSegmentIdx,
-    FuncIdx,
-    GlobalIdx,
-    LocalDepth,
-    SignatureIdx,
-    TableIdx,
+#[cfg(feature = "simd")]
+pub use self::utils::LaneIdx;
+pub use self::{
+    serialize::{DeserializeContext, IndexSpaces, SerializeContext},
+    utils::{
+        AddressOffset,
+        BlockFuel,
+        BranchOffset,
+        BranchTableTargets,
+        DataSegmentIdx,
+        DropKeep,
+        DropKeepError,
+        ElementSegmentIdx,
+        FuncIdx,
+        GlobalIdx,
+        LocalDepth,
+        MemArg,
+        MemoryIdx,
+        SignatureIdx,
+        TableIdx,
+        TagIdx,
+    },
 };
 use super::{const_pool::ConstRef, CompiledFunc, TranslationError};
 use core::fmt::Debug;
@@ -75,6 +84,16 @@ pub enum Instruction {
     BrTable(BranchTableTargets),
     Unreachable,
     ConsumeFuel(BlockFuel),
+    /// Checks the current epoch of the [`Engine`] against the epoch deadline of the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// This [`Instruction`] is only generated at function entries and loop headers
+    /// if epoch interruption is enabled for the [`Engine`].
+    ///
+    /// [`Engine`]: crate::Engine
+    /// [`Store`]: crate::Store
+    CheckEpoch,
     Return(DropKeep),
     ReturnIfNez(DropKeep),
     /// Tail calls an internal (compiled) function.
@@ -146,33 +165,52 @@ pub enum Instruction {
     Select,
     GlobalGet(GlobalIdx),
     GlobalSet(GlobalIdx),
-    I32Load(AddressOffset),
-    I64Load(AddressOffset),
-    F32Load(AddressOffset),
-    F64Load(AddressOffset),
-    I32Load8S(AddressOffset),
-    I32Load8U(AddressOffset),
-    I32Load16S(AddressOffset),
-    I32Load16U(AddressOffset),
-    I64Load8S(AddressOffset),
-    I64Load8U(AddressOffset),
-    I64Load16S(AddressOffset),
-    I64Load16U(AddressOffset),
-    I64Load32S(AddressOffset),
-    I64Load32U(AddressOffset),
-    I32Store(AddressOffset),
-    I64Store(AddressOffset),
-    F32Store(AddressOffset),
-    F64Store(AddressOffset),
-    I32Store8(AddressOffset),
-    I32Store16(AddressOffset),
-    I64Store8(AddressOffset),
-    I64Store16(AddressOffset),
-    I64Store32(AddressOffset),
-    MemorySize,
-    MemoryGrow,
-    MemoryFill,
-    MemoryCopy,
+    I32Load(MemArg),
+    I64Load(MemArg),
+    F32Load(MemArg),
+    F64Load(MemArg),
+    I32Load8S(MemArg),
+    I32Load8U(MemArg),
+    I32Load16S(MemArg),
+    I32Load16U(MemArg),
+    I64Load8S(MemArg),
+    I64Load8U(MemArg),
+    I64Load16S(MemArg),
+    I64Load16U(MemArg),
+    I64Load32S(MemArg),
+    I64Load32U(MemArg),
+    I32Store(MemArg),
+    I64Store(MemArg),
+    F32Store(MemArg),
+    F64Store(MemArg),
+    I32Store8(MemArg),
+    I32Store16(MemArg),
+    I64Store8(MemArg),
+    I64Store16(MemArg),
+    I64Store32(MemArg),
+    MemorySize(MemoryIdx),
+    MemoryGrow(MemoryIdx),
+    MemoryFill(MemoryIdx),
+    /// Copies bytes from one linear memory to another.
+    ///
+    /// # Note
+    ///
+    /// It is also possible to copy bytes within the same linear memory.
+    ///
+    /// # Encoding
+    ///
+    /// The [`MemoryIdx`] referred to by the [`Instruction::MemoryCopy`]
+    /// represents the `dst` (destination) memory. The [`Instruction::MemoryCopy`]
+    /// must be followed by an [`Instruction::MemorySize`] which stores a
+    /// [`MemoryIdx`] that refers to the `src` (source) memory.
+    MemoryCopy(MemoryIdx),
+    /// Initializes a linear memory given a [`DataSegmentIdx`].
+    ///
+    /// # Encoding
+    ///
+    /// The [`Instruction::MemoryInit`] must be followed by an
+    /// [`Instruction::MemorySize`] which stores a [`MemoryIdx`]
+    /// that refers to the linear memory to be initialized.
     MemoryInit(DataSegmentIdx),
     DataDrop(DataSegmentIdx),
     TableSize(TableIdx),
@@ -217,6 +255,10 @@ pub enum Instruction {
     /// Pushes a constant value onto the stack.
     ///
     /// The constant value is referred to indirectly by the [`ConstRef`].
+    ///
+    /// # Note
+    ///
+    /// This is also used to encode the Wasm `v128.const` instruction.
     ConstRef(ConstRef),
     I32Eqz,
     I32Eq,
@@ -350,6 +392,601 @@ pub enum Instruction {
     I64TruncSatF32U,
     I64TruncSatF64S,
     I64TruncSatF64U,
+    #[cfg(feature = "simd")]
+    V128Load(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load8x8S(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load8x8U(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load16x4S(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load16x4U(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load32x2S(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load32x2U(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load8Splat(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load16Splat(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load32Splat(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load64Splat(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load32Zero(MemArg),
+    #[cfg(feature = "simd")]
+    V128Load64Zero(MemArg),
+    #[cfg(feature = "simd")]
+    V128Store(MemArg),
+    /// # Encoding
+    ///
+    /// The [`Instruction::V128Load8Lane`] must be followed by an
+    /// [`Instruction::I8x16ExtractLaneS`] which stores the [`LaneIdx`]
+    /// of the loaded lane.
+    #[cfg(feature = "simd")]
+    V128Load8Lane(MemArg),
+    /// # Encoding
+    ///
+    /// The [`Instruction::V128Load16Lane`] must be followed by an
+    /// [`Instruction::I8x16ExtractLaneS`] which stores the [`LaneIdx`]
+    /// of the loaded lane.
+    #[cfg(feature = "simd")]
+    V128Load16Lane(MemArg),
+    /// # Encoding
+    ///
+    /// The [`Instruction::V128Load32Lane`] must be followed by an
+    /// [`Instruction::I8x16ExtractLaneS`] which stores the [`LaneIdx`]
+    /// of the loaded lane.
+    #[cfg(feature = "simd")]
+    V128Load32Lane(MemArg),
+    /// # Encoding
+    ///
+    /// The [`Instruction::V128Load64Lane`] must be followed by an
+    /// [`Instruction::I8x16ExtractLaneS`] which stores the [`LaneIdx`]
+    /// of the loaded lane.
+    #[cfg(feature = "simd")]
+    V128Load64Lane(MemArg),
+    /// # Encoding
+    ///
+    /// The [`Instruction::V128Store8Lane`] must be followed by an
+    /// [`Instruction::I8x16ExtractLaneS`] which stores the [`LaneIdx`]
+    /// of the stored lane.
+    #[cfg(feature = "simd")]
+    V128Store8Lane(MemArg),
+    /// # Encoding
+    ///
+    /// The [`Instruction::V128Store16Lane`] must be followed by an
+    /// [`Instruction::I8x16ExtractLaneS`] which stores the [`LaneIdx`]
+    /// of the stored lane.
+    #[cfg(feature = "simd")]
+    V128Store16Lane(MemArg),
+    /// # Encoding
+    ///
+    /// The [`Instruction::V128Store32Lane`] must be followed by an
+    /// [`Instruction::I8x16ExtractLaneS`] which stores the [`LaneIdx`]
+    /// of the stored lane.
+    #[cfg(feature = "simd")]
+    V128Store32Lane(MemArg),
+    /// # Encoding
+    ///
+    /// The [`Instruction::V128Store64Lane`] must be followed by an
+    /// [`Instruction::I8x16ExtractLaneS`] which stores the [`LaneIdx`]
+    /// of the stored lane.
+    #[cfg(feature = "simd")]
+    V128Store64Lane(MemArg),
+    /// Shuffles the lanes of two `v128` values.
+    ///
+    /// The [`ConstRef`] refers to the `v128` value storing the 16 lane indices.
+    #[cfg(feature = "simd")]
+    I8x16Shuffle(ConstRef),
+    #[cfg(feature = "simd")]
+    I8x16ExtractLaneS(LaneIdx),
+    #[cfg(feature = "simd")]
+    I8x16ExtractLaneU(LaneIdx),
+    #[cfg(feature = "simd")]
+    I8x16ReplaceLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    I16x8ExtractLaneS(LaneIdx),
+    #[cfg(feature = "simd")]
+    I16x8ExtractLaneU(LaneIdx),
+    #[cfg(feature = "simd")]
+    I16x8ReplaceLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    I32x4ExtractLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    I32x4ReplaceLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    I64x2ExtractLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    I64x2ReplaceLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    F32x4ExtractLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    F32x4ReplaceLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    F64x2ExtractLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    F64x2ReplaceLane(LaneIdx),
+    #[cfg(feature = "simd")]
+    I8x16Swizzle,
+    #[cfg(feature = "simd")]
+    I8x16Splat,
+    #[cfg(feature = "simd")]
+    I16x8Splat,
+    #[cfg(feature = "simd")]
+    I32x4Splat,
+    #[cfg(feature = "simd")]
+    I64x2Splat,
+    #[cfg(feature = "simd")]
+    F32x4Splat,
+    #[cfg(feature = "simd")]
+    F64x2Splat,
+    #[cfg(feature = "simd")]
+    I8x16Eq,
+    #[cfg(feature = "simd")]
+    I8x16Ne,
+    #[cfg(feature = "simd")]
+    I8x16LtS,
+    #[cfg(feature = "simd")]
+    I8x16LtU,
+    #[cfg(feature = "simd")]
+    I8x16GtS,
+    #[cfg(feature = "simd")]
+    I8x16GtU,
+    #[cfg(feature = "simd")]
+    I8x16LeS,
+    #[cfg(feature = "simd")]
+    I8x16LeU,
+    #[cfg(feature = "simd")]
+    I8x16GeS,
+    #[cfg(feature = "simd")]
+    I8x16GeU,
+    #[cfg(feature = "simd")]
+    I16x8Eq,
+    #[cfg(feature = "simd")]
+    I16x8Ne,
+    #[cfg(feature = "simd")]
+    I16x8LtS,
+    #[cfg(feature = "simd")]
+    I16x8LtU,
+    #[cfg(feature = "simd")]
+    I16x8GtS,
+    #[cfg(feature = "simd")]
+    I16x8GtU,
+    #[cfg(feature = "simd")]
+    I16x8LeS,
+    #[cfg(feature = "simd")]
+    I16x8LeU,
+    #[cfg(feature = "simd")]
+    I16x8GeS,
+    #[cfg(feature = "simd")]
+    I16x8GeU,
+    #[cfg(feature = "simd")]
+    I32x4Eq,
+    #[cfg(feature = "simd")]
+    I32x4Ne,
+    #[cfg(feature = "simd")]
+    I32x4LtS,
+    #[cfg(feature = "simd")]
+    I32x4LtU,
+    #[cfg(feature = "simd")]
+    I32x4GtS,
+    #[cfg(feature = "simd")]
+    I32x4GtU,
+    #[cfg(feature = "simd")]
+    I32x4LeS,
+    #[cfg(feature = "simd")]
+    I32x4LeU,
+    #[cfg(feature = "simd")]
+    I32x4GeS,
+    #[cfg(feature = "simd")]
+    I32x4GeU,
+    #[cfg(feature = "simd")]
+    I64x2Eq,
+    #[cfg(feature = "simd")]
+    I64x2Ne,
+    #[cfg(feature = "simd")]
+    I64x2LtS,
+    #[cfg(feature = "simd")]
+    I64x2GtS,
+    #[cfg(feature = "simd")]
+    I64x2LeS,
+    #[cfg(feature = "simd")]
+    I64x2GeS,
+    #[cfg(feature = "simd")]
+    F32x4Eq,
+    #[cfg(feature = "simd")]
+    F32x4Ne,
+    #[cfg(feature = "simd")]
+    F32x4Lt,
+    #[cfg(feature = "simd")]
+    F32x4Gt,
+    #[cfg(feature = "simd")]
+    F32x4Le,
+    #[cfg(feature = "simd")]
+    F32x4Ge,
+    #[cfg(feature = "simd")]
+    F64x2Eq,
+    #[cfg(feature = "simd")]
+    F64x2Ne,
+    #[cfg(feature = "simd")]
+    F64x2Lt,
+    #[cfg(feature = "simd")]
+    F64x2Gt,
+    #[cfg(feature = "simd")]
+    F64x2Le,
+    #[cfg(feature = "simd")]
+    F64x2Ge,
+    #[cfg(feature = "simd")]
+    V128Not,
+    #[cfg(feature = "simd")]
+    V128And,
+    #[cfg(feature = "simd")]
+    V128AndNot,
+    #[cfg(feature = "simd")]
+    V128Or,
+    #[cfg(feature = "simd")]
+    V128Xor,
+    #[cfg(feature = "simd")]
+    V128Bitselect,
+    #[cfg(feature = "simd")]
+    V128AnyTrue,
+    #[cfg(feature = "simd")]
+    I8x16Abs,
+    #[cfg(feature = "simd")]
+    I8x16Neg,
+    #[cfg(feature = "simd")]
+    I8x16Popcnt,
+    #[cfg(feature = "simd")]
+    I8x16AllTrue,
+    #[cfg(feature = "simd")]
+    I8x16Bitmask,
+    #[cfg(feature = "simd")]
+    I8x16NarrowI16x8S,
+    #[cfg(feature = "simd")]
+    I8x16NarrowI16x8U,
+    #[cfg(feature = "simd")]
+    I8x16Shl,
+    #[cfg(feature = "simd")]
+    I8x16ShrS,
+    #[cfg(feature = "simd")]
+    I8x16ShrU,
+    #[cfg(feature = "simd")]
+    I8x16Add,
+    #[cfg(feature = "simd")]
+    I8x16AddSatS,
+    #[cfg(feature = "simd")]
+    I8x16AddSatU,
+    #[cfg(feature = "simd")]
+    I8x16Sub,
+    #[cfg(feature = "simd")]
+    I8x16SubSatS,
+    #[cfg(feature = "simd")]
+    I8x16SubSatU,
+    #[cfg(feature = "simd")]
+    I8x16MinS,
+    #[cfg(feature = "simd")]
+    I8x16MinU,
+    #[cfg(feature = "simd")]
+    I8x16MaxS,
+    #[cfg(feature = "simd")]
+    I8x16MaxU,
+    #[cfg(feature = "simd")]
+    I8x16AvgrU,
+    #[cfg(feature = "simd")]
+    I16x8ExtAddPairwiseI8x16S,
+    #[cfg(feature = "simd")]
+    I16x8ExtAddPairwiseI8x16U,
+    #[cfg(feature = "simd")]
+    I16x8Abs,
+    #[cfg(feature = "simd")]
+    I16x8Neg,
+    #[cfg(feature = "simd")]
+    I16x8Q15MulrSatS,
+    #[cfg(feature = "simd")]
+    I16x8AllTrue,
+    #[cfg(feature = "simd")]
+    I16x8Bitmask,
+    #[cfg(feature = "simd")]
+    I16x8NarrowI32x4S,
+    #[cfg(feature = "simd")]
+    I16x8NarrowI32x4U,
+    #[cfg(feature = "simd")]
+    I16x8ExtendLowI8x16S,
+    #[cfg(feature = "simd")]
+    I16x8ExtendHighI8x16S,
+    #[cfg(feature = "simd")]
+    I16x8ExtendLowI8x16U,
+    #[cfg(feature = "simd")]
+    I16x8ExtendHighI8x16U,
+    #[cfg(feature = "simd")]
+    I16x8Shl,
+    #[cfg(feature = "simd")]
+    I16x8ShrS,
+    #[cfg(feature = "simd")]
+    I16x8ShrU,
+    #[cfg(feature = "simd")]
+    I16x8Add,
+    #[cfg(feature = "simd")]
+    I16x8AddSatS,
+    #[cfg(feature = "simd")]
+    I16x8AddSatU,
+    #[cfg(feature = "simd")]
+    I16x8Sub,
+    #[cfg(feature = "simd")]
+    I16x8SubSatS,
+    #[cfg(feature = "simd")]
+    I16x8SubSatU,
+    #[cfg(feature = "simd")]
+    I16x8Mul,
+    #[cfg(feature = "simd")]
+    I16x8MinS,
+    #[cfg(feature = "simd")]
+    I16x8MinU,
+    #[cfg(feature = "simd")]
+    I16x8MaxS,
+    #[cfg(feature = "simd")]
+    I16x8MaxU,
+    #[cfg(feature = "simd")]
+    I16x8AvgrU,
+    #[cfg(feature = "simd")]
+    I16x8ExtMulLowI8x16S,
+    #[cfg(feature = "simd")]
+    I16x8ExtMulHighI8x16S,
+    #[cfg(feature = "simd")]
+    I16x8ExtMulLowI8x16U,
+    #[cfg(feature = "simd")]
+    I16x8ExtMulHighI8x16U,
+    #[cfg(feature = "simd")]
+    I32x4ExtAddPairwiseI16x8S,
+    #[cfg(feature = "simd")]
+    I32x4ExtAddPairwiseI16x8U,
+    #[cfg(feature = "simd")]
+    I32x4Abs,
+    #[cfg(feature = "simd")]
+    I32x4Neg,
+    #[cfg(feature = "simd")]
+    I32x4AllTrue,
+    #[cfg(feature = "simd")]
+    I32x4Bitmask,
+    #[cfg(feature = "simd")]
+    I32x4ExtendLowI16x8S,
+    #[cfg(feature = "simd")]
+    I32x4ExtendHighI16x8S,
+    #[cfg(feature = "simd")]
+    I32x4ExtendLowI16x8U,
+    #[cfg(feature = "simd")]
+    I32x4ExtendHighI16x8U,
+    #[cfg(feature = "simd")]
+    I32x4Shl,
+    #[cfg(feature = "simd")]
+    I32x4ShrS,
+    #[cfg(feature = "simd")]
+    I32x4ShrU,
+    #[cfg(feature = "simd")]
+    I32x4Add,
+    #[cfg(feature = "simd")]
+    I32x4Sub,
+    #[cfg(feature = "simd")]
+    I32x4Mul,
+    #[cfg(feature = "simd")]
+    I32x4MinS,
+    #[cfg(feature = "simd")]
+    I32x4MinU,
+    #[cfg(feature = "simd")]
+    I32x4MaxS,
+    #[cfg(feature = "simd")]
+    I32x4MaxU,
+    #[cfg(feature = "simd")]
+    I32x4DotI16x8S,
+    #[cfg(feature = "simd")]
+    I32x4ExtMulLowI16x8S,
+    #[cfg(feature = "simd")]
+    I32x4ExtMulHighI16x8S,
+    #[cfg(feature = "simd")]
+    I32x4ExtMulLowI16x8U,
+    #[cfg(feature = "simd")]
+    I32x4ExtMulHighI16x8U,
+    #[cfg(feature = "simd")]
+    I64x2Abs,
+    #[cfg(feature = "simd")]
+    I64x2Neg,
+    #[cfg(feature = "simd")]
+    I64x2AllTrue,
+    #[cfg(feature = "simd")]
+    I64x2Bitmask,
+    #[cfg(feature = "simd")]
+    I64x2ExtendLowI32x4S,
+    #[cfg(feature = "simd")]
+    I64x2ExtendHighI32x4S,
+    #[cfg(feature = "simd")]
+    I64x2ExtendLowI32x4U,
+    #[cfg(feature = "simd")]
+    I64x2ExtendHighI32x4U,
+    #[cfg(feature = "simd")]
+    I64x2Shl,
+    #[cfg(feature = "simd")]
+    I64x2ShrS,
+    #[cfg(feature = "simd")]
+    I64x2ShrU,
+    #[cfg(feature = "simd")]
+    I64x2Add,
+    #[cfg(feature = "simd")]
+    I64x2Sub,
+    #[cfg(feature = "simd")]
+    I64x2Mul,
+    #[cfg(feature = "simd")]
+    I64x2ExtMulLowI32x4S,
+    #[cfg(feature = "simd")]
+    I64x2ExtMulHighI32x4S,
+    #[cfg(feature = "simd")]
+    I64x2ExtMulLowI32x4U,
+    #[cfg(feature = "simd")]
+    I64x2ExtMulHighI32x4U,
+    #[cfg(feature = "simd")]
+    F32x4Ceil,
+    #[cfg(feature = "simd")]
+    F32x4Floor,
+    #[cfg(feature = "simd")]
+    F32x4Trunc,
+    #[cfg(feature = "simd")]
+    F32x4Nearest,
+    #[cfg(feature = "simd")]
+    F32x4Abs,
+    #[cfg(feature = "simd")]
+    F32x4Neg,
+    #[cfg(feature = "simd")]
+    F32x4Sqrt,
+    #[cfg(feature = "simd")]
+    F32x4Add,
+    #[cfg(feature = "simd")]
+    F32x4Sub,
+    #[cfg(feature = "simd")]
+    F32x4Mul,
+    #[cfg(feature = "simd")]
+    F32x4Div,
+    #[cfg(feature = "simd")]
+    F32x4Min,
+    #[cfg(feature = "simd")]
+    F32x4Max,
+    #[cfg(feature = "simd")]
+    F32x4PMin,
+    #[cfg(feature = "simd")]
+    F32x4PMax,
+    #[cfg(feature = "simd")]
+    F64x2Ceil,
+    #[cfg(feature = "simd")]
+    F64x2Floor,
+    #[cfg(feature = "simd")]
+    F64x2Trunc,
+    #[cfg(feature = "simd")]
+    F64x2Nearest,
+    #[cfg(feature = "simd")]
+    F64x2Abs,
+    #[cfg(feature = "simd")]
+    F64x2Neg,
+    #[cfg(feature = "simd")]
+    F64x2Sqrt,
+    #[cfg(feature = "simd")]
+    F64x2Add,
+    #[cfg(feature = "simd")]
+    F64x2Sub,
+    #[cfg(feature = "simd")]
+    F64x2Mul,
+    #[cfg(feature = "simd")]
+    F64x2Div,
+    #[cfg(feature = "simd")]
+    F64x2Min,
+    #[cfg(feature = "simd")]
+    F64x2Max,
+    #[cfg(feature = "simd")]
+    F64x2PMin,
+    #[cfg(feature = "simd")]
+    F64x2PMax,
+    #[cfg(feature = "simd")]
+    I32x4TruncSatF32x4S,
+    #[cfg(feature = "simd")]
+    I32x4TruncSatF32x4U,
+    #[cfg(feature = "simd")]
+    F32x4ConvertI32x4S,
+    #[cfg(feature = "simd")]
+    F32x4ConvertI32x4U,
+    #[cfg(feature = "simd")]
+    I32x4TruncSatF64x2SZero,
+    #[cfg(feature = "simd")]
+    I32x4TruncSatF64x2UZero,
+    #[cfg(feature = "simd")]
+    F64x2ConvertLowI32x4S,
+    #[cfg(feature = "simd")]
+    F64x2ConvertLowI32x4U,
+    #[cfg(feature = "simd")]
+    F32x4DemoteF64x2Zero,
+    #[cfg(feature = "simd")]
+    F64x2PromoteLowF32x4,
+    MemoryAtomicNotify(MemArg),
+    MemoryAtomicWait32(MemArg),
+    MemoryAtomicWait64(MemArg),
+    AtomicFence,
+    I32AtomicLoad(MemArg),
+    I64AtomicLoad(MemArg),
+    I32AtomicLoad8U(MemArg),
+    I32AtomicLoad16U(MemArg),
+    I64AtomicLoad8U(MemArg),
+    I64AtomicLoad16U(MemArg),
+    I64AtomicLoad32U(MemArg),
+    I32AtomicStore(MemArg),
+    I64AtomicStore(MemArg),
+    I32AtomicStore8(MemArg),
+    I32AtomicStore16(MemArg),
+    I64AtomicStore8(MemArg),
+    I64AtomicStore16(MemArg),
+    I64AtomicStore32(MemArg),
+    I32AtomicRmwAdd(MemArg),
+    I64AtomicRmwAdd(MemArg),
+    I32AtomicRmw8AddU(MemArg),
+    I32AtomicRmw16AddU(MemArg),
+    I64AtomicRmw8AddU(MemArg),
+    I64AtomicRmw16AddU(MemArg),
+    I64AtomicRmw32AddU(MemArg),
+    I32AtomicRmwSub(MemArg),
+    I64AtomicRmwSub(MemArg),
+    I32AtomicRmw8SubU(MemArg),
+    I32AtomicRmw16SubU(MemArg),
+    I64AtomicRmw8SubU(MemArg),
+    I64AtomicRmw16SubU(MemArg),
+    I64AtomicRmw32SubU(MemArg),
+    I32AtomicRmwAnd(MemArg),
+    I64AtomicRmwAnd(MemArg),
+    I32AtomicRmw8AndU(MemArg),
+    I32AtomicRmw16AndU(MemArg),
+    I64AtomicRmw8AndU(MemArg),
+    I64AtomicRmw16AndU(MemArg),
+    I64AtomicRmw32AndU(MemArg),
+    I32AtomicRmwOr(MemArg),
+    I64AtomicRmwOr(MemArg),
+    I32AtomicRmw8OrU(MemArg),
+    I32AtomicRmw16OrU(MemArg),
+    I64AtomicRmw8OrU(MemArg),
+    I64AtomicRmw16OrU(MemArg),
+    I64AtomicRmw32OrU(MemArg),
+    I32AtomicRmwXor(MemArg),
+    I64AtomicRmwXor(MemArg),
+    I32AtomicRmw8XorU(MemArg),
+    I32AtomicRmw16XorU(MemArg),
+    I64AtomicRmw8XorU(MemArg),
+    I64AtomicRmw16XorU(MemArg),
+    I64AtomicRmw32XorU(MemArg),
+    I32AtomicRmwXchg(MemArg),
+    I64AtomicRmwXchg(MemArg),
+    I32AtomicRmw8XchgU(MemArg),
+    I32AtomicRmw16XchgU(MemArg),
+    I64AtomicRmw8XchgU(MemArg),
+    I64AtomicRmw16XchgU(MemArg),
+    I64AtomicRmw32XchgU(MemArg),
+    I32AtomicRmwCmpxchg(MemArg),
+    I64AtomicRmwCmpxchg(MemArg),
+    I32AtomicRmw8CmpxchgU(MemArg),
+    I32AtomicRmw16CmpxchgU(MemArg),
+    I64AtomicRmw8CmpxchgU(MemArg),
+    I64AtomicRmw16CmpxchgU(MemArg),
+    I64AtomicRmw32CmpxchgU(MemArg),
+    /// Throws a Wasm exception with the tag at [`TagIdx`].
+    ///
+    /// # Note
+    ///
+    /// The payload of the exception is popped from the value stack
+    /// according to the parameters of the tag type.
+    Throw(TagIdx),
+    /// Rethrows the exception caught by an enclosing `catch` or `catch_all` block.
+    ///
+    /// # Note
+    ///
+    /// The [`LocalDepth`] refers to the hidden value stack slot that
+    /// the executor pushes upon entering a `catch` or `catch_all` block
+    /// and which identifies the caught exception.
+    Rethrow(LocalDepth),
 }
 
 impl Instruction {
@@ -391,6 +1028,10 @@ impl Instruction {
     }
 
     /// Convenience method to create a new `ConsumeFuel` instruction.
+    ///
+    /// # Errors
+    ///
+    /// If the `amount` is out of bounds as block fuel.
     pub fn consume_fuel(amount: u64) -> Result<Self, TranslationError> {
         let block_fuel = BlockFuel::try_from(amount)?;
         Ok(Self::ConsumeFuel(block_fuel))
@@ -403,6 +1044,10 @@ impl Instruction {
     /// - If `self` is not a [`ConsumeFuel`] instruction.
     /// - If the new fuel consumption overflows the internal `u64` value.
     ///
+    /// # Errors
+    ///
+    /// If the new fuel consumption is out of bounds as block fuel.
+    ///
     /// [`ConsumeFuel`]: Instruction::ConsumeFuel
     pub fn bump_fuel_consumption(&mut self, delta: u64) -> Result<(), TranslationError> {
         match self {
@@ -0,0 +1,1107 @@
+//! Serialization of `wasmi` bytecode [`Instruction`] sequences.
+//!
+//! Every [`Instruction`] is encoded as a `u16` opcode followed by the encoding
+//! of its payload. Opcodes are part of the serialization format and must never
+//! be reassigned. New [`Instruction`] variants must use new opcodes.
+
+#[cfg(feature = "simd")]
+use super::LaneIdx;
+use super::{
+    AddressOffset,
+    BlockFuel,
+    BranchOffset,
+    BranchTableTargets,
+    DataSegmentIdx,
+    DropKeep,
+    ElementSegmentIdx,
+    FuncIdx,
+    GlobalIdx,
+    Instruction,
+    LocalDepth,
+    MemArg,
+    MemoryIdx,
+    SignatureIdx,
+    TableIdx,
+    TagIdx,
+};
+use crate::{
+    engine::{const_pool::ConstRef, CompiledFunc},
+    module::{Decoder, Encoder, SerializationError},
+};
+use wasmi_core::UntypedValue;
+
+/// The context required to serialize [`Instruction`] sequences.
+pub trait SerializeContext {
+    /// Returns the value of the constant referenced by `cref`.
+    fn const_value(&self, cref: ConstRef) -> UntypedValue;
+
+    /// Returns the index of `func` within the compiled functions of the serialized module.
+    fn func_index(&self, func: CompiledFunc) -> u32;
+}
+
+/// The context required to deserialize [`Instruction`] sequences.
+pub trait DeserializeContext {
+    /// Allocates a new constant with the given `value`.
+    fn alloc_const(&mut self, value: UntypedValue) -> Result<ConstRef, SerializationError>;
+
+    /// Returns the compiled function at `index` of the deserialized module.
+    fn func(&self, index: u32) -> Result<CompiledFunc, SerializationError>;
+
+    /// Returns the [`IndexSpaces`] of the deserialized module.
+    fn index_spaces(&self) -> &IndexSpaces;
+
+    /// Returns the maximum value stack height of the deserialized function.
+    ///
+    /// # Note
+    ///
+    /// This includes the parameters and local variables of the function.
+    fn max_stack_height(&self) -> usize;
+}
+
+/// The sizes of the index spaces of a deserialized module.
+///
+/// Used to bounds check the indices of deserialized [`Instruction`]s.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct IndexSpaces {
+    /// The amount of functions including imported functions.
+    pub funcs: usize,
+    /// The amount of tables including imported tables.
+    pub tables: usize,
+    /// The amount of linear memories including imported linear memories.
+    pub memories: usize,
+    /// The amount of global variables including imported global variables.
+    pub globals: usize,
+    /// The amount of function types.
+    pub signatures: usize,
+    /// The amount of exception tags including imported exception tags.
+    pub tags: usize,
+    /// The amount of data segments.
+    pub data_segments: usize,
+    /// The amount of element segments.
+    pub element_segments: usize,
+}
+
+/// The payload of an [`Instruction`] that can be serialized.
+trait Payload: Sized {
+    /// Encodes the payload using `encoder`.
+    fn serialize(&self, encoder: &mut Encoder, ctx: &dyn SerializeContext);
+
+    /// Decodes the payload using `decoder`.
+    fn deserialize(
+        decoder: &mut Decoder,
+        ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError>;
+}
+
+macro_rules! impl_payload_for_index {
+    ( $($ty:ty => $space:ident),* $(,)? ) => {
+        $(
+            impl Payload for $ty {
+                fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+                    encoder.write_u32(self.to_u32());
+                }
+
+                fn deserialize(
+                    decoder: &mut Decoder,
+                    ctx: &mut dyn DeserializeContext,
+                ) -> Result<Self, SerializationError> {
+                    let index = decoder.read_u32()?;
+                    if index as usize >= ctx.index_spaces().$space {
+                        return Err(SerializationError::malformed(concat!(
+                            "out of bounds ",
+                            stringify!($ty)
+                        )));
+                    }
+                    Ok(<$ty>::from(index))
+                }
+            }
+        )*
+    };
+}
+impl_payload_for_index! {
+    FuncIdx => funcs,
+    TableIdx => tables,
+    MemoryIdx => memories,
+    SignatureIdx => signatures,
+    GlobalIdx => globals,
+    TagIdx => tags,
+    DataSegmentIdx => data_segments,
+    ElementSegmentIdx => element_segments,
+}
+
+impl Payload for LocalDepth {
+    fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+        encoder.write_len(self.to_usize());
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        let depth = decoder.read_u32()?;
+        if depth as usize > ctx.max_stack_height() {
+            return Err(SerializationError::malformed("out of bounds LocalDepth"));
+        }
+        Ok(LocalDepth::from(depth))
+    }
+}
+
+impl Payload for BranchOffset {
+    fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+        encoder.write_u32(self.to_i32() as u32);
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        _ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        decoder
+            .read_u32()
+            .map(|offset| BranchOffset::from(offset as i32))
+    }
+}
+
+impl Payload for BranchTableTargets {
+    fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+        encoder.write_len(self.to_usize());
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        _ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        BranchTableTargets::try_from(decoder.read_len()?)
+            .map_err(|_| SerializationError::malformed("invalid branch table targets"))
+    }
+}
+
+impl Payload for BlockFuel {
+    fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+        encoder.write_u64(self.to_u64());
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        _ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        BlockFuel::try_from(decoder.read_u64()?)
+            .map_err(|_| SerializationError::malformed("invalid block fuel"))
+    }
+}
+
+impl Payload for DropKeep {
+    fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+        encoder.write_u16(self.drop());
+        encoder.write_u16(self.keep());
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        let drop = usize::from(decoder.read_u16()?);
+        let keep = usize::from(decoder.read_u16()?);
+        if drop + keep > ctx.max_stack_height() {
+            return Err(SerializationError::malformed("out of bounds drop keep"));
+        }
+        DropKeep::new(drop, keep).map_err(|_| SerializationError::malformed("invalid drop keep"))
+    }
+}
+
+impl Payload for ConstRef {
+    fn serialize(&self, encoder: &mut Encoder, ctx: &dyn SerializeContext) {
+        encoder.write_untyped(ctx.const_value(*self));
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        let value = decoder.read_untyped()?;
+        ctx.alloc_const(value)
+    }
+}
+
+impl Payload for CompiledFunc {
+    fn serialize(&self, encoder: &mut Encoder, ctx: &dyn SerializeContext) {
+        encoder.write_u32(ctx.func_index(*self));
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        let index = decoder.read_u32()?;
+        ctx.func(index)
+    }
+}
+
+impl Payload for MemArg {
+    fn serialize(&self, encoder: &mut Encoder, ctx: &dyn SerializeContext) {
+        self.memory().serialize(encoder, ctx);
+        match self.const_offset() {
+            Some(cref) => {
+                encoder.write_bool(true);
+                cref.serialize(encoder, ctx);
+            }
+            None => {
+                encoder.write_bool(false);
+                encoder.write_u32(self.offset().into_inner());
+            }
+        }
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        let memory = MemoryIdx::deserialize(decoder, ctx)?;
+        let memarg = match decoder.read_bool()? {
+            true => {
+                let cref = ConstRef::deserialize(decoder, ctx)?;
+                MemArg::with_const_offset(memory, cref)
+            }
+            false => {
+                let offset = AddressOffset::from(decoder.read_u32()?);
+                MemArg::new(memory, offset)
+            }
+        };
+        memarg.map_err(|_| SerializationError::malformed("invalid memory immediates"))
+    }
+}
+
+#[cfg(feature = "simd")]
+impl Payload for LaneIdx {
+    fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+        encoder.write_u8(self.into_inner());
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        _ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        let lane = decoder.read_u8()?;
+        if lane >= 16 {
+            return Err(SerializationError::malformed("out of bounds LaneIdx"));
+        }
+        Ok(LaneIdx::from(lane))
+    }
+}
+
+impl Payload for [u8; 4] {
+    fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+        // Note: `Instruction::Const32` stores its value in native byte order.
+        encoder.write_u32(u32::from_ne_bytes(*self));
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        _ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        decoder.read_u32().map(u32::to_ne_bytes)
+    }
+}
+
+impl Payload for i32 {
+    fn serialize(&self, encoder: &mut Encoder, _ctx: &dyn SerializeContext) {
+        encoder.write_u32(*self as u32);
+    }
+
+    fn deserialize(
+        decoder: &mut Decoder,
+        _ctx: &mut dyn DeserializeContext,
+    ) -> Result<Self, SerializationError> {
+        decoder.read_u32().map(|value| value as i32)
+    }
+}
+
+macro_rules! define_instruction_codec {
+    (
+        unit { $( $(#[$unit_attr:meta])* $unit_opcode:literal => $unit_name:ident ),* $(,)? }
+        payload { $( $(#[$attr:meta])* $opcode:literal => $name:ident($payload:ty) ),* $(,)? }
+    ) => {
+        impl Instruction {
+            /// Encodes the [`Instruction`] using `encoder`.
+            pub fn serialize(&self, encoder: &mut Encoder, ctx: &dyn SerializeContext) {
+                match self {
+                    $(
+                        $(#[$unit_attr])*
+                        Self::$unit_name => encoder.write_u16($unit_opcode),
+                    )*
+                    $(
+                        $(#[$attr])*
+                        Self::$name(payload) => {
+                            encoder.write_u16($opcode);
+                            <$payload as Payload>::serialize(payload, encoder, ctx);
+                        }
+                    )*
+                }
+            }
+
+            /// Decodes an [`Instruction`] using `decoder`.
+            ///
+            /// # Errors
+            ///
+            /// If the encoded [`Instruction`] is malformed.
+            pub fn deserialize(
+                decoder: &mut Decoder,
+                ctx: &mut dyn DeserializeContext,
+            ) -> Result<Self, SerializationError> {
+                let instr = match decoder.read_u16()? {
+                    $(
+                        $(#[$unit_attr])*
+                        $unit_opcode => Self::$unit_name,
+                    )*
+                    $(
+                        $(#[$attr])*
+                        $opcode => Self::$name(<$payload as Payload>::deserialize(decoder, ctx)?),
+                    )*
+                    opcode => return Err(SerializationError::InvalidOpcode { opcode }),
+                };
+                Ok(instr)
+            }
+        }
+    };
+}
+define_instruction_codec! {
+    unit {
+        0x009 => Unreachable,
+        0x00B => CheckEpoch,
+        0x014 => Drop,
+        0x015 => Select,
+        0x041 => I32Eqz,
+        0x042 => I32Eq,
+        0x043 => I32Ne,
+        0x044 => I32LtS,
+        0x045 => I32LtU,
+        0x046 => I32GtS,
+        0x047 => I32GtU,
+        0x048 => I32LeS,
+        0x049 => I32LeU,
+        0x04A => I32GeS,
+        0x04B => I32GeU,
+        0x04C => I64Eqz,
+        0x04D => I64Eq,
+        0x04E => I64Ne,
+        0x04F => I64LtS,
+        0x050 => I64LtU,
+        0x051 => I64GtS,
+        0x052 => I64GtU,
+        0x053 => I64LeS,
+        0x054 => I64LeU,
+        0x055 => I64GeS,
+        0x056 => I64GeU,
+        0x057 => F32Eq,
+        0x058 => F32Ne,
+        0x059 => F32Lt,
+        0x05A => F32Gt,
+        0x05B => F32Le,
+        0x05C => F32Ge,
+        0x05D => F64Eq,
+        0x05E => F64Ne,
+        0x05F => F64Lt,
+        0x060 => F64Gt,
+        0x061 => F64Le,
+        0x062 => F64Ge,
+        0x063 => I32Clz,
+        0x064 => I32Ctz,
+        0x065 => I32Popcnt,
+        0x066 => I32Add,
+        0x067 => I32Sub,
+        0x068 => I32Mul,
+        0x069 => I32DivS,
+        0x06A => I32DivU,
+        0x06B => I32RemS,
+        0x06C => I32RemU,
+        0x06D => I32And,
+        0x06E => I32Or,
+        0x06F => I32Xor,
+        0x070 => I32Shl,
+        0x071 => I32ShrS,
+        0x072 => I32ShrU,
+        0x073 => I32Rotl,
+        0x074 => I32Rotr,
+        0x075 => I64Clz,
+        0x076 => I64Ctz,
+        0x077 => I64Popcnt,
+        0x078 => I64Add,
+        0x079 => I64Sub,
+        0x07A => I64Mul,
+        0x07B => I64DivS,
+        0x07C => I64DivU,
+        0x07D => I64RemS,
+        0x07E => I64RemU,
+        0x07F => I64And,
+        0x080 => I64Or,
+        0x081 => I64Xor,
+        0x082 => I64Shl,
+        0x083 => I64ShrS,
+        0x084 => I64ShrU,
+        0x085 => I64Rotl,
+        0x086 => I64Rotr,
+        0x087 => F32Abs,
+        0x088 => F32Neg,
+        0x089 => F32Ceil,
+        0x08A => F32Floor,
+        0x08B => F32Trunc,
+        0x08C => F32Nearest,
+        0x08D => F32Sqrt,
+        0x08E => F32Add,
+        0x08F => F32Sub,
+        0x090 => F32Mul,
+        0x091 => F32Div,
+        0x092 => F32Min,
+        0x093 => F32Max,
+        0x094 => F32Copysign,
+        0x095 => F64Abs,
+        0x096 => F64Neg,
+        0x097 => F64Ceil,
+        0x098 => F64Floor,
+        0x099 => F64Trunc,
+        0x09A => F64Nearest,
+        0x09B => F64Sqrt,
+        0x09C => F64Add,
+        0x09D => F64Sub,
+        0x09E => F64Mul,
+        0x09F => F64Div,
+        0x0A0 => F64Min,
+        0x0A1 => F64Max,
+        0x0A2 => F64Copysign,
+        0x0A3 => I32WrapI64,
+        0x0A4 => I32TruncF32S,
+        0x0A5 => I32TruncF32U,
+        0x0A6 => I32TruncF64S,
+        0x0A7 => I32TruncF64U,
+        0x0A8 => I64ExtendI32S,
+        0x0A9 => I64ExtendI32U,
+        0x0AA => I64TruncF32S,
+        0x0AB => I64TruncF32U,
+        0x0AC => I64TruncF64S,
+        0x0AD => I64TruncF64U,
+        0x0AE => F32ConvertI32S,
+        0x0AF => F32ConvertI32U,
+        0x0B0 => F32ConvertI64S,
+        0x0B1 => F32ConvertI64U,
+        0x0B2 => F32DemoteF64,
+        0x0B3 => F64ConvertI32S,
+        0x0B4 => F64ConvertI32U,
+        0x0B5 => F64ConvertI64S,
+        0x0B6 => F64ConvertI64U,
+        0x0B7 => F64PromoteF32,
+        0x0B8 => I32Extend8S,
+        0x0B9 => I32Extend16S,
+        0x0BA => I64Extend8S,
+        0x0BB => I64Extend16S,
+        0x0BC => I64Extend32S,
+        0x0BD => I32TruncSatF32S,
+        0x0BE => I32TruncSatF32U,
+        0x0BF => I32TruncSatF64S,
+        0x0C0 => I32TruncSatF64U,
+        0x0C1 => I64TruncSatF32S,
+        0x0C2 => I64TruncSatF32U,
+        0x0C3 => I64TruncSatF64S,
+        0x0C4 => I64TruncSatF64U,
+        #[cfg(feature = "simd")]
+        0x0EA => I8x16Swizzle,
+        #[cfg(feature = "simd")]
+        0x0EB => I8x16Splat,
+        #[cfg(feature = "simd")]
+        0x0EC => I16x8Splat,
+        #[cfg(feature = "simd")]
+        0x0ED => I32x4Splat,
+        #[cfg(feature = "simd")]
+        0x0EE => I64x2Splat,
+        #[cfg(feature = "simd")]
+        0x0EF => F32x4Splat,
+        #[cfg(feature = "simd")]
+        0x0F0 => F64x2Splat,
+        #[cfg(feature = "simd")]
+        0x0F1 => I8x16Eq,
+        #[cfg(feature = "simd")]
+        0x0F2 => I8x16Ne,
+        #[cfg(feature = "simd")]
+        0x0F3 => I8x16LtS,
+        #[cfg(feature = "simd")]
+        0x0F4 => I8x16LtU,
+        #[cfg(feature = "simd")]
+        0x0F5 => I8x16GtS,
+        #[cfg(feature = "simd")]
+        0x0F6 => I8x16GtU,
+        #[cfg(feature = "simd")]
+        0x0F7 => I8x16LeS,
+        #[cfg(feature = "simd")]
+        0x0F8 => I8x16LeU,
+        #[cfg(feature = "simd")]
+        0x0F9 => I8x16GeS,
+        #[cfg(feature = "simd")]
+        0x0FA => I8x16GeU,
+        #[cfg(feature = "simd")]
+        0x0FB => I16x8Eq,
+        #[cfg(feature = "simd")]
+        0x0FC => I16x8Ne,
+        #[cfg(feature = "simd")]
+        0x0FD => I16x8LtS,
+        #[cfg(feature = "simd")]
+        0x0FE => I16x8LtU,
+        #[cfg(feature = "simd")]
+        0x0FF => I16x8GtS,
+        #[cfg(feature = "simd")]
+        0x100 => I16x8GtU,
+        #[cfg(feature = "simd")]
+        0x101 => I16x8LeS,
+        #[cfg(feature = "simd")]
+        0x102 => I16x8LeU,
+        #[cfg(feature = "simd")]
+        0x103 => I16x8GeS,
+        #[cfg(feature = "simd")]
+        0x104 => I16x8GeU,
+        #[cfg(feature = "simd")]
+        0x105 => I32x4Eq,
+        #[cfg(feature = "simd")]
+        0x106 => I32x4Ne,
+        #[cfg(feature = "simd")]
+        0x107 => I32x4LtS,
+        #[cfg(feature = "simd")]
+        0x108 => I32x4LtU,
+        #[cfg(feature = "simd")]
+        0x109 => I32x4GtS,
+        #[cfg(feature = "simd")]
+        0x10A => I32x4GtU,
+        #[cfg(feature = "simd")]
+        0x10B => I32x4LeS,
+        #[cfg(feature = "simd")]
+        0x10C => I32x4LeU,
+        #[cfg(feature = "simd")]
+        0x10D => I32x4GeS,
+        #[cfg(feature = "simd")]
+        0x10E => I32x4GeU,
+        #[cfg(feature = "simd")]
+        0x10F => I64x2Eq,
+        #[cfg(feature = "simd")]
+        0x110 => I64x2Ne,
+        #[cfg(feature = "simd")]
+        0x111 => I64x2LtS,
+        #[cfg(feature = "simd")]
+        0x112 => I64x2GtS,
+        #[cfg(feature = "simd")]
+        0x113 => I64x2LeS,
+        #[cfg(feature = "simd")]
+        0x114 => I64x2GeS,
+        #[cfg(feature = "simd")]
+        0x115 => F32x4Eq,
+        #[cfg(feature = "simd")]
+        0x116 => F32x4Ne,
+        #[cfg(feature = "simd")]
+        0x117 => F32x4Lt,
+        #[cfg(feature = "simd")]
+        0x118 => F32x4Gt,
+        #[cfg(feature = "simd")]
+        0x119 => F32x4Le,
+        #[cfg(feature = "simd")]
+        0x11A => F32x4Ge,
+        #[cfg(feature = "simd")]
+        0x11B => F64x2Eq,
+        #[cfg(feature = "simd")]
+        0x11C => F64x2Ne,
+        #[cfg(feature = "simd")]
+        0x11D => F64x2Lt,
+        #[cfg(feature = "simd")]
+        0x11E => F64x2Gt,
+        #[cfg(feature = "simd")]
+        0x11F => F64x2Le,
+        #[cfg(feature = "simd")]
+        0x120 => F64x2Ge,
+        #[cfg(feature = "simd")]
+        0x121 => V128Not,
+        #[cfg(feature = "simd")]
+        0x122 => V128And,
+        #[cfg(feature = "simd")]
+        0x123 => V128AndNot,
+        #[cfg(feature = "simd")]
+        0x124 => V128Or,
+        #[cfg(feature = "simd")]
+        0x125 => V128Xor,
+        #[cfg(feature = "simd")]
+        0x126 => V128Bitselect,
+        #[cfg(feature = "simd")]
+        0x127 => V128AnyTrue,
+        #[cfg(feature = "simd")]
+        0x128 => I8x16Abs,
+        #[cfg(feature = "simd")]
+        0x129 => I8x16Neg,
+        #[cfg(feature = "simd")]
+        0x12A => I8x16Popcnt,
+        #[cfg(feature = "simd")]
+        0x12B => I8x16AllTrue,
+        #[cfg(feature = "simd")]
+        0x12C => I8x16Bitmask,
+        #[cfg(feature = "simd")]
+        0x12D => I8x16NarrowI16x8S,
+        #[cfg(feature = "simd")]
+        0x12E => I8x16NarrowI16x8U,
+        #[cfg(feature = "simd")]
+        0x12F => I8x16Shl,
+        #[cfg(feature = "simd")]
+        0x130 => I8x16ShrS,
+        #[cfg(feature = "simd")]
+        0x131 => I8x16ShrU,
+        #[cfg(feature = "simd")]
+        0x132 => I8x16Add,
+        #[cfg(feature = "simd")]
+        0x133 => I8x16AddSatS,
+        #[cfg(feature = "simd")]
+        0x134 => I8x16AddSatU,
+        #[cfg(feature = "simd")]
+        0x135 => I8x16Sub,
+        #[cfg(feature = "simd")]
+        0x136 => I8x16SubSatS,
+        #[cfg(feature = "simd")]
+        0x137 => I8x16SubSatU,
+        #[cfg(feature = "simd")]
+        0x138 => I8x16MinS,
+        #[cfg(feature = "simd")]
+        0x139 => I8x16MinU,
+        #[cfg(feature = "simd")]
+        0x13A => I8x16MaxS,
+        #[cfg(feature = "simd")]
+        0x13B => I8x16MaxU,
+        #[cfg(feature = "simd")]
+        0x13C => I8x16AvgrU,
+        #[cfg(feature = "simd")]
+        0x13D => I16x8ExtAddPairwiseI8x16S,
+        #[cfg(feature = "simd")]
+        0x13E => I16x8ExtAddPairwiseI8x16U,
+        #[cfg(feature = "simd")]
+        0x13F => I16x8Abs,
+        #[cfg(feature = "simd")]
+        0x140 => I16x8Neg,
+        #[cfg(feature = "simd")]
+        0x141 => I16x8Q15MulrSatS,
+        #[cfg(feature = "simd")]
+        0x142 => I16x8AllTrue,
+        #[cfg(feature = "simd")]
+        0x143 => I16x8Bitmask,
+        #[cfg(feature = "simd")]
+        0x144 => I16x8NarrowI32x4S,
+        #[cfg(feature = "simd")]
+        0x145 => I16x8NarrowI32x4U,
+        #[cfg(feature = "simd")]
+        0x146 => I16x8ExtendLowI8x16S,
+        #[cfg(feature = "simd")]
+        0x147 => I16x8ExtendHighI8x16S,
+        #[cfg(feature = "simd")]
+        0x148 => I16x8ExtendLowI8x16U,
+        #[cfg(feature = "simd")]
+        0x149 => I16x8ExtendHighI8x16U,
+        #[cfg(feature = "simd")]
+        0x14A => I16x8Shl,
+        #[cfg(feature = "simd")]
+        0x14B => I16x8ShrS,
+        #[cfg(feature = "simd")]
+        0x14C => I16x8ShrU,
+        #[cfg(feature = "simd")]
+        0x14D => I16x8Add,
+        #[cfg(feature = "simd")]
+        0x14E => I16x8AddSatS,
+        #[cfg(feature = "simd")]
+        0x14F => I16x8AddSatU,
+        #[cfg(feature = "simd")]
+        0x150 => I16x8Sub,
+        #[cfg(feature = "simd")]
+        0x151 => I16x8SubSatS,
+        #[cfg(feature = "simd")]
+        0x152 => I16x8SubSatU,
+        #[cfg(feature = "simd")]
+        0x153 => I16x8Mul,
+        #[cfg(feature = "simd")]
+        0x154 => I16x8MinS,
+        #[cfg(feature = "simd")]
+        0x155 => I16x8MinU,
+        #[cfg(feature = "simd")]
+        0x156 => I16x8MaxS,
+        #[cfg(feature = "simd")]
+        0x157 => I16x8MaxU,
+        #[cfg(feature = "simd")]
+        0x158 => I16x8AvgrU,
+        #[cfg(feature = "simd")]
+        0x159 => I16x8ExtMulLowI8x16S,
+        #[cfg(feature = "simd")]
+        0x15A => I16x8ExtMulHighI8x16S,
+        #[cfg(feature = "simd")]
+        0x15B => I16x8ExtMulLowI8x16U,
+        #[cfg(feature = "simd")]
+        0x15C => I16x8ExtMulHighI8x16U,
+        #[cfg(feature = "simd")]
+        0x15D => I32x4ExtAddPairwiseI16x8S,
+        #[cfg(feature = "simd")]
+        0x15E => I32x4ExtAddPairwiseI16x8U,
+        #[cfg(feature = "simd")]
+        0x15F => I32x4Abs,
+        #[cfg(feature = "simd")]
+        0x160 => I32x4Neg,
+        #[cfg(feature = "simd")]
+        0x161 => I32x4AllTrue,
+        #[cfg(feature = "simd")]
+        0x162 => I32x4Bitmask,
+        #[cfg(feature = "simd")]
+        0x163 => I32x4ExtendLowI16x8S,
+        #[cfg(feature = "simd")]
+        0x164 => I32x4ExtendHighI16x8S,
+        #[cfg(feature = "simd")]
+        0x165 => I32x4ExtendLowI16x8U,
+        #[cfg(feature = "simd")]
+        0x166 => I32x4ExtendHighI16x8U,
+        #[cfg(feature = "simd")]
+        0x167 => I32x4Shl,
+        #[cfg(feature = "simd")]
+        0x168 => I32x4ShrS,
+        #[cfg(feature = "simd")]
+        0x169 => I32x4ShrU,
+        #[cfg(feature = "simd")]
+        0x16A => I32x4Add,
+        #[cfg(feature = "simd")]
+        0x16B => I32x4Sub,
+        #[cfg(feature = "simd")]
+        0x16C => I32x4Mul,
+        #[cfg(feature = "simd")]
+        0x16D => I32x4MinS,
+        #[cfg(feature = "simd")]
+        0x16E => I32x4MinU,
+        #[cfg(feature = "simd")]
+        0x16F => I32x4MaxS,
+        #[cfg(feature = "simd")]
+        0x170 => I32x4MaxU,
+        #[cfg(feature = "simd")]
+        0x171 => I32x4DotI16x8S,
+        #[cfg(feature = "simd")]
+        0x172 => I32x4ExtMulLowI16x8S,
+        #[cfg(feature = "simd")]
+        0x173 => I32x4ExtMulHighI16x8S,
+        #[cfg(feature = "simd")]
+        0x174 => I32x4ExtMulLowI16x8U,
+        #[cfg(feature = "simd")]
+        0x175 => I32x4ExtMulHighI16x8U,
+        #[cfg(feature = "simd")]
+        0x176 => I64x2Abs,
+        #[cfg(feature = "simd")]
+        0x177 => I64x2Neg,
+        #[cfg(feature = "simd")]
+        0x178 => I64x2AllTrue,
+        #[cfg(feature = "simd")]
+        0x179 => I64x2Bitmask,
+        #[cfg(feature = "simd")]
+        0x17A => I64x2ExtendLowI32x4S,
+        #[cfg(feature = "simd")]
+        0x17B => I64x2ExtendHighI32x4S,
+        #[cfg(feature = "simd")]
+        0x17C => I64x2ExtendLowI32x4U,
+        #[cfg(feature = "simd")]
+        0x17D => I64x2ExtendHighI32x4U,
+        #[cfg(feature = "simd")]
+        0x17E => I64x2Shl,
+        #[cfg(feature = "simd")]
+        0x17F => I64x2ShrS,
+        #[cfg(feature = "simd")]
+        0x180 => I64x2ShrU,
+        #[cfg(feature = "simd")]
+        0x181 => I64x2Add,
+        #[cfg(feature = "simd")]
+        0x182 => I64x2Sub,
+        #[cfg(feature = "simd")]
+        0x183 => I64x2Mul,
+        #[cfg(feature = "simd")]
+        0x184 => I64x2ExtMulLowI32x4S,
+        #[cfg(feature = "simd")]
+        0x185 => I64x2ExtMulHighI32x4S,
+        #[cfg(feature = "simd")]
+        0x186 => I64x2ExtMulLowI32x4U,
+        #[cfg(feature = "simd")]
+        0x187 => I64x2ExtMulHighI32x4U,
+        #[cfg(feature = "simd")]
+        0x188 => F32x4Ceil,
+        #[cfg(feature = "simd")]
+        0x189 => F32x4Floor,
+        #[cfg(feature = "simd")]
+        0x18A => F32x4Trunc,
+        #[cfg(feature = "simd")]
+        0x18B => F32x4Nearest,
+        #[cfg(feature = "simd")]
+        0x18C => F32x4Abs,
+        #[cfg(feature = "simd")]
+        0x18D => F32x4Neg,
+        #[cfg(feature = "simd")]
+        0x18E => F32x4Sqrt,
+        #[cfg(feature = "simd")]
+        0x18F => F32x4Add,
+        #[cfg(feature = "simd")]
+        0x190 => F32x4Sub,
+        #[cfg(feature = "simd")]
+        0x191 => F32x4Mul,
+        #[cfg(feature = "simd")]
+        0x192 => F32x4Div,
+        #[cfg(feature = "simd")]
+        0x193 => F32x4Min,
+        #[cfg(feature = "simd")]
+        0x194 => F32x4Max,
+        #[cfg(feature = "simd")]
+        0x195 => F32x4PMin,
+        #[cfg(feature = "simd")]
+        0x196 => F32x4PMax,
+        #[cfg(feature = "simd")]
+        0x197 => F64x2Ceil,
+        #[cfg(feature = "simd")]
+        0x198 => F64x2Floor,
+        #[cfg(feature = "simd")]
+        0x199 => F64x2Trunc,
+        #[cfg(feature = "simd")]
+        0x19A => F64x2Nearest,
+        #[cfg(feature = "simd")]
+        0x19B => F64x2Abs,
+        #[cfg(feature = "simd")]
+        0x19C => F64x2Neg,
+        #[cfg(feature = "simd")]
+        0x19D => F64x2Sqrt,
+        #[cfg(feature = "simd")]
+        0x19E => F64x2Add,
+        #[cfg(feature = "simd")]
+        0x19F => F64x2Sub,
+        #[cfg(feature = "simd")]
+        0x1A0 => F64x2Mul,
+        #[cfg(feature = "simd")]
+        0x1A1 => F64x2Div,
+        #[cfg(feature = "simd")]
+        0x1A2 => F64x2Min,
+        #[cfg(feature = "simd")]
+        0x1A3 => F64x2Max,
+        #[cfg(feature = "simd")]
+        0x1A4 => F64x2PMin,
+        #[cfg(feature = "simd")]
+        0x1A5 => F64x2PMax,
+        #[cfg(feature = "simd")]
+        0x1A6 => I32x4TruncSatF32x4S,
+        #[cfg(feature = "simd")]
+        0x1A7 => I32x4TruncSatF32x4U,
+        #[cfg(feature = "simd")]
+        0x1A8 => F32x4ConvertI32x4S,
+        #[cfg(feature = "simd")]
+        0x1A9 => F32x4ConvertI32x4U,
+        #[cfg(feature = "simd")]
+        0x1AA => I32x4TruncSatF64x2SZero,
+        #[cfg(feature = "simd")]
+        0x1AB => I32x4TruncSatF64x2UZero,
+        #[cfg(feature = "simd")]
+        0x1AC => F64x2ConvertLowI32x4S,
+        #[cfg(feature = "simd")]
+        0x1AD => F64x2ConvertLowI32x4U,
+        #[cfg(feature = "simd")]
+        0x1AE => F32x4DemoteF64x2Zero,
+        #[cfg(feature = "simd")]
+        0x1AF => F64x2PromoteLowF32x4,
+        0x1B3 => AtomicFence,
+    }
+    payload {
+        0x000 => LocalGet(LocalDepth),
+        0x001 => LocalSet(LocalDepth),
+        0x002 => LocalTee(LocalDepth),
+        0x003 => Br(BranchOffset),
+        0x004 => BrIfEqz(BranchOffset),
+        0x005 => BrIfNez(BranchOffset),
+        0x006 => BrAdjust(BranchOffset),
+        0x007 => BrAdjustIfNez(BranchOffset),
+        0x008 => BrTable(BranchTableTargets),
+        0x00A => ConsumeFuel(BlockFuel),
+        0x00C => Return(DropKeep),
+        0x00D => ReturnIfNez(DropKeep),
+        0x00E => ReturnCallInternal(CompiledFunc),
+        0x00F => ReturnCall(FuncIdx),
+        0x010 => ReturnCallIndirect(SignatureIdx),
+        0x011 => CallInternal(CompiledFunc),
+        0x012 => Call(FuncIdx),
+        0x013 => CallIndirect(SignatureIdx),
+        0x016 => GlobalGet(GlobalIdx),
+        0x017 => GlobalSet(GlobalIdx),
+        0x018 => I32Load(MemArg),
+        0x019 => I64Load(MemArg),
+        0x01A => F32Load(MemArg),
+        0x01B => F64Load(MemArg),
+        0x01C => I32Load8S(MemArg),
+        0x01D => I32Load8U(MemArg),
+        0x01E => I32Load16S(MemArg),
+        0x01F => I32Load16U(MemArg),
+        0x020 => I64Load8S(MemArg),
+        0x021 => I64Load8U(MemArg),
+        0x022 => I64Load16S(MemArg),
+        0x023 => I64Load16U(MemArg),
+        0x024 => I64Load32S(MemArg),
+        0x025 => I64Load32U(MemArg),
+        0x026 => I32Store(MemArg),
+        0x027 => I64Store(MemArg),
+        0x028 => F32Store(MemArg),
+        0x029 => F64Store(MemArg),
+        0x02A => I32Store8(MemArg),
+        0x02B => I32Store16(MemArg),
+        0x02C => I64Store8(MemArg),
+        0x02D => I64Store16(MemArg),
+        0x02E => I64Store32(MemArg),
+        0x02F => MemorySize(MemoryIdx),
+        0x030 => MemoryGrow(MemoryIdx),
+        0x031 => MemoryFill(MemoryIdx),
+        0x032 => MemoryCopy(MemoryIdx),
+        0x033 => MemoryInit(DataSegmentIdx),
+        0x034 => DataDrop(DataSegmentIdx),
+        0x035 => TableSize(TableIdx),
+        0x036 => TableGrow(TableIdx),
+        0x037 => TableFill(TableIdx),
+        0x038 => TableGet(TableIdx),
+        0x039 => TableSet(TableIdx),
+        0x03A => TableCopy(TableIdx),
+        0x03B => TableInit(ElementSegmentIdx),
+        0x03C => ElemDrop(ElementSegmentIdx),
+        0x03D => RefFunc(FuncIdx),
+        0x03E => Const32([u8; 4]),
+        0x03F => I64Const32(i32),
+        0x040 => ConstRef(ConstRef),
+        #[cfg(feature = "simd")]
+        0x0C5 => V128Load(MemArg),
+        #[cfg(feature = "simd")]
+        0x0C6 => V128Load8x8S(MemArg),
+        #[cfg(feature = "simd")]
+        0x0C7 => V128Load8x8U(MemArg),
+        #[cfg(feature = "simd")]
+        0x0C8 => V128Load16x4S(MemArg),
+        #[cfg(feature = "simd")]
+        0x0C9 => V128Load16x4U(MemArg),
+        #[cfg(feature = "simd")]
+        0x0CA => V128Load32x2S(MemArg),
+        #[cfg(feature = "simd")]
+        0x0CB => V128Load32x2U(MemArg),
+        #[cfg(feature = "simd")]
+        0x0CC => V128Load8Splat(MemArg),
+        #[cfg(feature = "simd")]
+        0x0CD => V128Load16Splat(MemArg),
+        #[cfg(feature = "simd")]
+        0x0CE => V128Load32Splat(MemArg),
+        #[cfg(feature = "simd")]
+        0x0CF => V128Load64Splat(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D0 => V128Load32Zero(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D1 => V128Load64Zero(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D2 => V128Store(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D3 => V128Load8Lane(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D4 => V128Load16Lane(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D5 => V128Load32Lane(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D6 => V128Load64Lane(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D7 => V128Store8Lane(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D8 => V128Store16Lane(MemArg),
+        #[cfg(feature = "simd")]
+        0x0D9 => V128Store32Lane(MemArg),
+        #[cfg(feature = "simd")]
+        0x0DA => V128Store64Lane(MemArg),
+        #[cfg(feature = "simd")]
+        0x0DB => I8x16Shuffle(ConstRef),
+        #[cfg(feature = "simd")]
+        0x0DC => I8x16ExtractLaneS(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0DD => I8x16ExtractLaneU(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0DE => I8x16ReplaceLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0DF => I16x8ExtractLaneS(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E0 => I16x8ExtractLaneU(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E1 => I16x8ReplaceLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E2 => I32x4ExtractLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E3 => I32x4ReplaceLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E4 => I64x2ExtractLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E5 => I64x2ReplaceLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E6 => F32x4ExtractLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E7 => F32x4ReplaceLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E8 => F64x2ExtractLane(LaneIdx),
+        #[cfg(feature = "simd")]
+        0x0E9 => F64x2ReplaceLane(LaneIdx),
+        0x1B0 => MemoryAtomicNotify(MemArg),
+        0x1B1 => MemoryAtomicWait32(MemArg),
+        0x1B2 => MemoryAtomicWait64(MemArg),
+        0x1B4 => I32AtomicLoad(MemArg),
+        0x1B5 => I64AtomicLoad(MemArg),
+        0x1B6 => I32AtomicLoad8U(MemArg),
+        0x1B7 => I32AtomicLoad16U(MemArg),
+        0x1B8 => I64AtomicLoad8U(MemArg),
+        0x1B9 => I64AtomicLoad16U(MemArg),
+        0x1BA => I64AtomicLoad32U(MemArg),
+        0x1BB => I32AtomicStore(MemArg),
+        0x1BC => I64AtomicStore(MemArg),
+        0x1BD => I32AtomicStore8(MemArg),
+        0x1BE => I32AtomicStore16(MemArg),
+        0x1BF => I64AtomicStore8(MemArg),
+        0x1C0 => I64AtomicStore16(MemArg),
+        0x1C1 => I64AtomicStore32(MemArg),
+        0x1C2 => I32AtomicRmwAdd(MemArg),
+        0x1C3 => I64AtomicRmwAdd(MemArg),
+        0x1C4 => I32AtomicRmw8AddU(MemArg),
+        0x1C5 => I32AtomicRmw16AddU(MemArg),
+        0x1C6 => I64AtomicRmw8AddU(MemArg),
+        0x1C7 => I64AtomicRmw16AddU(MemArg),
+        0x1C8 => I64AtomicRmw32AddU(MemArg),
+        0x1C9 => I32AtomicRmwSub(MemArg),
+        0x1CA => I64AtomicRmwSub(MemArg),
+        0x1CB => I32AtomicRmw8SubU(MemArg),
+        0x1CC => I32AtomicRmw16SubU(MemArg),
+        0x1CD => I64AtomicRmw8SubU(MemArg),
+        0x1CE => I64AtomicRmw16SubU(MemArg),
+        0x1CF => I64AtomicRmw32SubU(MemArg),
+        0x1D0 => I32AtomicRmwAnd(MemArg),
+        0x1D1 => I64AtomicRmwAnd(MemArg),
+        0x1D2 => I32AtomicRmw8AndU(MemArg),
+        0x1D3 => I32AtomicRmw16AndU(MemArg),
+        0x1D4 => I64AtomicRmw8AndU(MemArg),
+        0x1D5 => I64AtomicRmw16AndU(MemArg),
+        0x1D6 => I64AtomicRmw32AndU(MemArg),
+        0x1D7 => I32AtomicRmwOr(MemArg),
+        0x1D8 => I64AtomicRmwOr(MemArg),
+        0x1D9 => I32AtomicRmw8OrU(MemArg),
+        0x1DA => I32AtomicRmw16OrU(MemArg),
+        0x1DB => I64AtomicRmw8OrU(MemArg),
+        0x1DC => I64AtomicRmw16OrU(MemArg),
+        0x1DD => I64AtomicRmw32OrU(MemArg),
+        0x1DE => I32AtomicRmwXor(MemArg),
+        0x1DF => I64AtomicRmwXor(MemArg),
+        0x1E0 => I32AtomicRmw8XorU(MemArg),
+        0x1E1 => I32AtomicRmw16XorU(MemArg),
+        0x1E2 => I64AtomicRmw8XorU(MemArg),
+        0x1E3 => I64AtomicRmw16XorU(MemArg),
+        0x1E4 => I64AtomicRmw32XorU(MemArg),
+        0x1E5 => I32AtomicRmwXchg(MemArg),
+        0x1E6 => I64AtomicRmwXchg(MemArg),
+        0x1E7 => I32AtomicRmw8XchgU(MemArg),
+        0x1E8 => I32AtomicRmw16XchgU(MemArg),
+        0x1E9 => I64AtomicRmw8XchgU(MemArg),
+        0x1EA => I64AtomicRmw16XchgU(MemArg),
+        0x1EB => I64AtomicRmw32XchgU(MemArg),
+        0x1EC => I32AtomicRmwCmpxchg(MemArg),
+        0x1ED => I64AtomicRmwCmpxchg(MemArg),
+        0x1EE => I32AtomicRmw8CmpxchgU(MemArg),
+        0x1EF => I32AtomicRmw16CmpxchgU(MemArg),
+        0x1F0 => I64AtomicRmw8CmpxchgU(MemArg),
+        0x1F1 => I64AtomicRmw16CmpxchgU(MemArg),
+        0x1F2 => I64AtomicRmw32CmpxchgU(MemArg),
+        0x1F3 => Throw(TagIdx),
+        0x1F4 => Rethrow(LocalDepth),
+    }
+}
@@ -13,6 +13,10 @@ fn size_of_instruction() {
     assert_eq!(size_of::<FuncIdx>(), 4);
     assert_eq!(size_of::<GlobalIdx>(), 4);
     assert_eq!(size_of::<TableIdx>(), 4);
+    assert_eq!(size_of::<MemoryIdx>(), 4);
+    assert_eq!(size_of::<MemArg>(), 6);
     assert_eq!(size_of::<SignatureIdx>(), 4);
     assert_eq!(size_of::<LocalDepth>(), 4);
+    #[cfg(feature = "simd")]
+    assert_eq!(size_of::<LaneIdx>(), 1);
 }
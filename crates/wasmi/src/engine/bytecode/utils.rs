@@ -1,4 +1,7 @@
-use crate::engine::{func_builder::TranslationErrorInner, Instr, TranslationError};
+use crate::{
+    engine::{const_pool::ConstRef, func_builder::TranslationErrorInner, Instr, TranslationError},
+    module::DEFAULT_MEMORY_INDEX,
+};
 use core::fmt::{self, Display};
 
 /// A function index.
@@ -37,6 +40,29 @@ impl TableIdx {
     }
 }
 
+/// A linear memory index.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MemoryIdx(u32);
+
+impl From<u32> for MemoryIdx {
+    fn from(index: u32) -> Self {
+        Self(index)
+    }
+}
+
+impl MemoryIdx {
+    /// Returns the index value as `u32`.
+    pub fn to_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if the [`MemoryIdx`] refers to the default linear memory.
+    pub fn is_default(self) -> bool {
+        self.0 == DEFAULT_MEMORY_INDEX
+    }
+}
+
 /// An index of a unique function signature.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(transparent)]
@@ -103,6 +129,30 @@ impl GlobalIdx {
     }
 }
 
+/// A tag index.
+///
+/// # Note
+///
+/// Refers to a tag of a [`Store`].
+///
+/// [`Store`]: [`crate::Store`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TagIdx(u32);
+
+impl From<u32> for TagIdx {
+    fn from(index: u32) -> Self {
+        Self(index)
+    }
+}
+
+impl TagIdx {
+    /// Returns the index value as `u32`.
+    pub fn to_u32(self) -> u32 {
+        self.0
+    }
+}
+
 /// A data segment index.
 ///
 /// # Note
@@ -244,6 +294,124 @@ impl AddressOffset {
     }
 }
 
+/// The immediates of a linear memory access.
+///
+/// # Note
+///
+/// Combines the [`MemoryIdx`] of the accessed linear memory with the
+/// [`AddressOffset`] used to calculate the effective address.
+/// Both are packed so that they fit into a single instruction word.
+///
+/// Offsets of 64-bit linear memories that do not fit into an [`AddressOffset`]
+/// are stored in the constant pool and referenced via [`ConstRef`] instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MemArg {
+    /// The [`MemoryIdx`] and the [`MemArg::CONST_OFFSET`] flag.
+    memory: u16,
+    /// Either the [`AddressOffset`] or the index of the [`ConstRef`].
+    offset: [u16; 2],
+}
+
+impl MemArg {
+    /// Flag signalling that the offset is referenced via [`ConstRef`].
+    const CONST_OFFSET: u16 = 1 << 15;
+
+    /// Creates a new [`MemArg`] for the `memory` and `offset`.
+    ///
+    /// # Errors
+    ///
+    /// If the [`MemoryIdx`] is out of bounds for the [`MemArg`] encoding.
+    pub fn new(memory: MemoryIdx, offset: AddressOffset) -> Result<Self, TranslationError> {
+        Self::new_impl(memory, offset.into_inner(), false)
+    }
+
+    /// Creates a new [`MemArg`] for the `memory` with an offset stored in the constant pool.
+    ///
+    /// # Note
+    ///
+    /// This is used for offsets of 64-bit linear memories that do not fit into an [`AddressOffset`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`MemoryIdx`] is out of bounds for the [`MemArg`] encoding.
+    pub fn with_const_offset(
+        memory: MemoryIdx,
+        offset: ConstRef,
+    ) -> Result<Self, TranslationError> {
+        Self::new_impl(memory, offset.to_u32(), true)
+    }
+
+    /// Creates a new [`MemArg`] from its raw parts.
+    fn new_impl(memory: MemoryIdx, offset: u32, is_const: bool) -> Result<Self, TranslationError> {
+        let mut memory = u16::try_from(memory.to_u32())
+            .ok()
+            .filter(|&memory| memory < Self::CONST_OFFSET)
+            .ok_or_else(|| TranslationError::new(TranslationErrorInner::MemoryIndexOutOfBounds))?;
+        if is_const {
+            memory |= Self::CONST_OFFSET;
+        }
+        Ok(Self {
+            memory,
+            offset: [offset as u16, (offset >> 16) as u16],
+        })
+    }
+
+    /// Returns the [`MemoryIdx`] of the accessed linear memory.
+    pub fn memory(self) -> MemoryIdx {
+        MemoryIdx::from(u32::from(self.memory & !Self::CONST_OFFSET))
+    }
+
+    /// Returns the raw `u32` offset value.
+    fn raw_offset(self) -> u32 {
+        let [lo, hi] = self.offset;
+        u32::from(lo) | (u32::from(hi) << 16)
+    }
+
+    /// Returns the [`ConstRef`] to the offset if it is stored in the constant pool.
+    pub fn const_offset(self) -> Option<ConstRef> {
+        if self.memory & Self::CONST_OFFSET == 0 {
+            return None;
+        }
+        Some(ConstRef::from_u32(self.raw_offset()))
+    }
+
+    /// Returns the [`AddressOffset`] of the linear memory access.
+    ///
+    /// # Note
+    ///
+    /// The returned [`AddressOffset`] is only meaningful if
+    /// [`MemArg::const_offset`] returns `None`.
+    pub fn offset(self) -> AddressOffset {
+        AddressOffset::from(self.raw_offset())
+    }
+}
+
+/// A lane index of a `v128` value.
+///
+/// # Note
+///
+/// Wasm validation guarantees that the index is in bounds of the lanes
+/// of the `v128` shape of the respective instruction.
+#[cfg(feature = "simd")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct LaneIdx(u8);
+
+#[cfg(feature = "simd")]
+impl From<u8> for LaneIdx {
+    fn from(index: u8) -> Self {
+        Self(index)
+    }
+}
+
+#[cfg(feature = "simd")]
+impl LaneIdx {
+    /// Returns the inner `u8` index.
+    pub fn into_inner(self) -> u8 {
+        self.0
+    }
+}
+
 /// A signed offset for branch instructions.
 ///
 /// This defines how much the instruction pointer is offset
@@ -251,7 +419,6 @@ impl AddressOffset {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct BranchOffset(i32);
 
-#[cfg(test)]
 impl From<i32> for BranchOffset {
     fn from(index: i32) -> Self {
         Self(index)
@@ -1,4 +1,4 @@
-use super::bytecode::{DataSegmentIdx, ElementSegmentIdx, FuncIdx, GlobalIdx, TableIdx};
+use super::bytecode::{DataSegmentIdx, ElementSegmentIdx, FuncIdx, GlobalIdx, MemoryIdx, TableIdx};
 use crate::{
     instance::InstanceEntity,
     memory::DataSegment,
@@ -7,13 +7,14 @@ use crate::{
     ElementSegment,
     ElementSegmentEntity,
     Func,
+    FuncRefCounts,
     Instance,
     Memory,
     StoreInner,
     Table,
 };
-use core::ptr::NonNull;
-use wasmi_core::UntypedValue;
+use core::{mem, ptr::NonNull};
+use wasmi_core::{UntypedValue, ValueType};
 
 /// A cache for frequently used entities of an [`Instance`].
 #[derive(Debug)]
@@ -22,11 +23,15 @@ pub struct InstanceCache {
     /// The bytes of a default linear memory of the currently used [`Instance`].
     default_memory_bytes: Option<NonNull<[u8]>>,
     /// The last accessed global variable value of the currently used [`Instance`].
-    last_global: Option<(GlobalIdx, NonNull<UntypedValue>)>,
+    ///
+    /// The flag tells if the global variable holds a `funcref` value.
+    last_global: Option<(GlobalIdx, NonNull<UntypedValue>, bool)>,
     /// The current instance in use.
     instance: Instance,
     /// The default linear memory of the currently used [`Instance`].
     default_memory: Option<Memory>,
+    /// The last accessed non-default linear memory of the currently used [`Instance`].
+    last_memory: Option<(MemoryIdx, Memory)>,
     /// The last accessed table of the currently used [`Instance`].
     last_table: Option<(TableIdx, Table)>,
     /// The last accessed function of the currently used [`Instance`].
@@ -38,6 +43,7 @@ impl From<&'_ Instance> for InstanceCache {
         Self {
             instance: *instance,
             default_memory: None,
+            last_memory: None,
             last_table: None,
             last_func: None,
             last_global: None,
@@ -59,6 +65,7 @@ impl InstanceCache {
     fn set_instance(&mut self, instance: &Instance) {
         self.instance = *instance;
         self.default_memory = None;
+        self.last_memory = None;
         self.last_table = None;
         self.last_func = None;
         self.last_global = None;
@@ -108,21 +115,24 @@ impl InstanceCache {
             })
     }
 
-    /// Loads the [`DataSegment`] at `index` of the currently used [`Instance`].
+    /// Loads the [`Memory`] at `memory` and the [`DataSegment`] at `segment`
+    /// of the currently used [`Instance`].
     ///
     /// # Panics
     ///
-    /// If there is no [`DataSegment`] for the [`Instance`] at the `index`.
+    /// - If there is no [`Memory`] for the [`Instance`] at the `memory` index.
+    /// - If there is no [`DataSegment`] for the [`Instance`] at the `segment` index.
     #[inline]
-    pub fn get_default_memory_and_data_segment<'a>(
+    pub fn get_memory_and_data_segment<'a>(
         &mut self,
         ctx: &'a mut StoreInner,
+        memory: MemoryIdx,
         segment: DataSegmentIdx,
-    ) -> (&'a mut [u8], &'a [u8]) {
+    ) -> (*mut [u8], &'a [u8]) {
         let seg = self.get_data_segment(ctx, segment.to_u32());
-        let mem = self.default_memory(ctx);
-        let (memory, segment) = ctx.resolve_memory_mut_and_data_segment(mem, &seg);
-        (memory.data_mut(), segment.bytes())
+        let mem = self.get_memory(ctx, memory);
+        let (memory, segment) = ctx.resolve_memory_mut_and_data_segment(&mem, &seg);
+        (memory.raw_data(), segment.bytes())
     }
 
     /// Loads the [`ElementSegment`] at `index` of the currently used [`Instance`].
@@ -140,6 +150,7 @@ impl InstanceCache {
         &'a InstanceEntity,
         &'a mut TableEntity,
         &'a ElementSegmentEntity,
+        &'a mut FuncRefCounts,
     ) {
         let tab = self.get_table(ctx, table);
         let seg = self.get_element_segment(ctx, segment);
@@ -182,25 +193,94 @@ impl InstanceCache {
     ///
     /// # Note
     ///
-    /// This avoids one indirection compared to using the `default_memory`.
+    /// - This avoids one indirection compared to using the `default_memory`.
+    /// - The returned bytes are valid for reads and writes until the next
+    ///   call to [`InstanceCache::reset_default_memory_bytes`].
     #[inline]
-    pub fn default_memory_bytes<'ctx>(&mut self, ctx: &'ctx mut StoreInner) -> &'ctx mut [u8] {
-        let bytes = match self.default_memory_bytes {
-            Some(ref mut cached) => cached,
+    pub fn default_memory_bytes(&mut self, ctx: &mut StoreInner) -> *mut [u8] {
+        match self.default_memory_bytes {
+            Some(cached) => cached.as_ptr(),
             None => self.load_default_memory_bytes(ctx),
-        };
-        unsafe { bytes.as_mut() }
+        }
     }
 
     /// Loads and populates the cached default memory instance.
     ///
-    /// Returns an exclusive reference to the cached default memory.
+    /// Returns a raw pointer to the bytes of the default memory.
+    ///
+    /// # Note
+    ///
+    /// The bytes of shared linear memories are never cached since
+    /// they might be concurrently grown by other threads.
     #[cold]
     #[inline]
-    fn load_default_memory_bytes(&mut self, ctx: &mut StoreInner) -> &mut NonNull<[u8]> {
+    fn load_default_memory_bytes(&mut self, ctx: &mut StoreInner) -> *mut [u8] {
         let memory = *self.default_memory(ctx);
-        self.default_memory_bytes
-            .insert(ctx.resolve_memory_mut(&memory).data().into())
+        let entity = ctx.resolve_memory_mut(&memory);
+        let bytes = entity.raw_data();
+        if !entity.ty().is_shared() {
+            self.default_memory_bytes = NonNull::new(bytes);
+        }
+        bytes
+    }
+
+    /// Returns the [`Memory`] at the `index` of the currently used [`Instance`].
+    ///
+    /// # Panics
+    ///
+    /// If the currently used [`Instance`] does not have a [`Memory`] at the index.
+    #[inline]
+    pub fn get_memory(&mut self, ctx: &StoreInner, index: MemoryIdx) -> Memory {
+        if index.is_default() {
+            return *self.default_memory(ctx);
+        }
+        match self.last_memory {
+            Some((memory_index, memory)) if index == memory_index => memory,
+            _ => self.load_memory_at(ctx, index),
+        }
+    }
+
+    /// Loads the non-default [`Memory`] at `index` of the currently used [`Instance`].
+    ///
+    /// # Panics
+    ///
+    /// If the currently used [`Instance`] does not have the linear memory.
+    #[cold]
+    #[inline]
+    fn load_memory_at(&mut self, ctx: &StoreInner, index: MemoryIdx) -> Memory {
+        let memory = ctx
+            .resolve_instance(self.instance())
+            .get_memory(index.to_u32())
+            .unwrap_or_else(|| {
+                unreachable!(
+                    "missing linear memory at index {index:?} for instance: {:?}",
+                    self.instance
+                )
+            });
+        self.last_memory = Some((index, memory));
+        memory
+    }
+
+    /// Returns the bytes of the linear memory at `index` of the currently used [`Instance`].
+    ///
+    /// # Note
+    ///
+    /// - Accesses to the default linear memory are served by the cached
+    ///   default memory bytes while all other linear memories are resolved
+    ///   through the [`StoreInner`] upon every access.
+    /// - The bytes are returned as raw pointer since the bytes of shared
+    ///   linear memories might be concurrently accessed by other threads.
+    ///
+    /// # Panics
+    ///
+    /// If the currently used [`Instance`] does not have a [`Memory`] at the index.
+    #[inline]
+    pub fn memory_bytes(&mut self, ctx: &mut StoreInner, index: MemoryIdx) -> *mut [u8] {
+        if index.is_default() {
+            return self.default_memory_bytes(ctx);
+        }
+        let memory = self.get_memory(ctx, index);
+        ctx.resolve_memory_mut(&memory).raw_data()
     }
 
     /// Clears the cached default memory instance.
@@ -303,31 +383,39 @@ impl InstanceCache {
     }
 
     /// Loads the pointer to the value of the global variable at `index`
-    /// of the currently used [`Instance`].
+    /// of the currently used [`Instance`] and whether it holds a `funcref` value.
     ///
     /// # Panics
     ///
     /// If the currently used [`Instance`] does not have a default table.
     #[cold]
     #[inline]
-    fn load_global_at(&mut self, ctx: &mut StoreInner, index: GlobalIdx) -> NonNull<UntypedValue> {
-        let global = ctx
+    fn load_global_at(
+        &mut self,
+        ctx: &mut StoreInner,
+        index: GlobalIdx,
+    ) -> (NonNull<UntypedValue>, bool) {
+        let (global, is_funcref) = ctx
             .resolve_instance(self.instance())
             .get_global(index.to_u32())
             .as_ref()
-            .map(|global| ctx.resolve_global_mut(global).get_untyped_ptr())
+            .map(|global| {
+                let global = ctx.resolve_global_mut(global);
+                let is_funcref = matches!(global.ty().content(), ValueType::FuncRef);
+                (global.get_untyped_ptr(), is_funcref)
+            })
             .unwrap_or_else(|| {
                 unreachable!(
                     "missing global variable at index {index:?} for instance: {:?}",
                     self.instance
                 )
             });
-        self.last_global = Some((index, global));
-        global
+        self.last_global = Some((index, global, is_funcref));
+        (global, is_funcref)
     }
 
     /// Returns a pointer to the value of the global variable at `index`
-    /// of the currently used [`Instance`].
+    /// of the currently used [`Instance`] and whether it holds a `funcref` value.
     ///
     /// # Panics
     ///
@@ -337,15 +425,15 @@ impl InstanceCache {
         &mut self,
         ctx: &'ctx mut StoreInner,
         global_index: GlobalIdx,
-    ) -> &'ctx mut UntypedValue {
-        let mut ptr = match self.last_global {
-            Some((index, global)) if index == global_index => global,
+    ) -> (&'ctx mut UntypedValue, bool) {
+        let (mut ptr, is_funcref) = match self.last_global {
+            Some((index, global, is_funcref)) if index == global_index => (global, is_funcref),
             _ => self.load_global_at(ctx, global_index),
         };
         // SAFETY: This deref is safe since we only hold this pointer
         //         as long as we are sure that nothing else can manipulate
         //         the global in a way that would invalidate the pointer.
-        unsafe { ptr.as_mut() }
+        (unsafe { ptr.as_mut() }, is_funcref)
     }
 
     /// Returns a pointer to the value of the global variable at `index`
@@ -356,7 +444,7 @@ impl InstanceCache {
     /// If the currently used [`Instance`] does not have a [`Func`] at the index.
     #[inline(always)]
     pub fn get_global(&mut self, ctx: &mut StoreInner, global_index: GlobalIdx) -> UntypedValue {
-        *self.get_global_mut(ctx, global_index)
+        *self.get_global_mut(ctx, global_index).0
     }
 
     /// Returns a pointer to the value of the global variable at `index`
@@ -372,6 +460,10 @@ impl InstanceCache {
         global_index: GlobalIdx,
         new_value: UntypedValue,
     ) {
-        *self.get_global_mut(ctx, global_index) = new_value;
+        let (value, is_funcref) = self.get_global_mut(ctx, global_index);
+        let old_value = mem::replace(value, new_value);
+        if is_funcref {
+            ctx.func_refs_mut().replace(old_value, new_value);
+        }
     }
 }
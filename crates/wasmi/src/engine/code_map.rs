@@ -1,11 +1,16 @@
 //! Datastructure to efficiently store function bodies and their instructions.
 
-use super::Instruction;
-use alloc::vec::Vec;
+use super::{bytecode::TagIdx, Instruction};
+use crate::{
+    core::Trap,
+    module::{Decoder, Encoder, LazyFunc, SerializationError},
+};
+use alloc::{boxed::Box, collections::BTreeMap, string::ToString, vec::Vec};
+use core::{mem, ops::Range};
 use wasmi_arena::ArenaIndex;
 
 /// A reference to a compiled function stored in the [`CodeMap`] of an [`Engine`](crate::Engine).
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CompiledFunc(u32);
 
 impl ArenaIndex for CompiledFunc {
@@ -64,22 +69,33 @@ impl InstructionsRef {
 pub struct FuncHeader {
     /// A reference to the instructions of the function.
     iref: InstructionsRef,
+    /// The number of instructions of the function.
+    len_instrs: usize,
     /// The number of local variables of the function.
     len_locals: usize,
     /// The maximum stack height usage of the function during execution.
     max_stack_height: usize,
+    /// Is `true` if the function body awaits its translation upon its first execution.
+    uncompiled: bool,
 }
 
 impl FuncHeader {
     /// Create a new initialized [`FuncHeader`].
-    pub fn new(iref: InstructionsRef, len_locals: usize, local_stack_height: usize) -> Self {
+    pub fn new(
+        iref: InstructionsRef,
+        len_instrs: usize,
+        len_locals: usize,
+        local_stack_height: usize,
+    ) -> Self {
         let max_stack_height = local_stack_height
             .checked_add(len_locals)
             .unwrap_or_else(|| panic!("invalid maximum stack height for function"));
         Self {
             iref,
+            len_instrs,
             len_locals,
             max_stack_height,
+            uncompiled: false,
         }
     }
 
@@ -87,16 +103,36 @@ impl FuncHeader {
     pub fn uninit() -> Self {
         Self {
             iref: InstructionsRef::uninit(),
+            len_instrs: 0,
             len_locals: 0,
             max_stack_height: 0,
+            uncompiled: false,
+        }
+    }
+
+    /// Create a new uninitialized [`FuncHeader`] for a function that is translated lazily.
+    pub fn uncompiled() -> Self {
+        Self {
+            uncompiled: true,
+            ..Self::uninit()
         }
     }
 
     /// Returns `true` if the [`FuncHeader`] is uninitialized.
+    ///
+    /// # Note
+    ///
+    /// This is also `true` for functions that await their lazy translation.
+    #[inline]
     pub fn is_uninit(&self) -> bool {
         self.iref.is_uninit()
     }
 
+    /// Returns `true` if the function body awaits its translation upon its first execution.
+    pub fn is_uncompiled(&self) -> bool {
+        self.uncompiled
+    }
+
     /// Returns a reference to the instructions of the function.
     pub fn iref(&self) -> InstructionsRef {
         self.iref
@@ -118,6 +154,365 @@ impl FuncHeader {
     }
 }
 
+/// An exception handler of a compiled function.
+///
+/// # Note
+///
+/// Exception handlers are created for the `catch`, `catch_all` and `delegate`
+/// clauses of Wasm `try` blocks. Each handler covers the instructions of
+/// the body of its `try` block. Handlers of inner `try` blocks always come
+/// before handlers of their enclosing `try` blocks so that a linear search
+/// finds the innermost handler first.
+#[derive(Debug, Clone)]
+pub struct ExceptionHandler {
+    /// The instructions covered by the [`ExceptionHandler`].
+    ///
+    /// # Note
+    ///
+    /// These are relative to the start of the compiled function until the
+    /// [`ExceptionHandler`] is stored in the [`CodeMap`].
+    range: Range<u32>,
+    /// The kind of the [`ExceptionHandler`].
+    kind: ExceptionHandlerKind,
+}
+
+/// The kind of an [`ExceptionHandler`].
+#[derive(Debug, Copy, Clone)]
+pub enum ExceptionHandlerKind {
+    /// Handles exceptions with the tag at [`TagIdx`] of the function's instance.
+    Catch(TagIdx, HandlerTarget),
+    /// Handles all exceptions.
+    CatchAll(HandlerTarget),
+    /// Forwards exceptions to the handlers of an enclosing `try` block.
+    ///
+    /// # Note
+    ///
+    /// Exceptions are propagated to the caller if this is `None`.
+    Delegate(Option<DelegateTarget>),
+}
+
+/// The instruction and value stack height at which exception handling continues.
+#[derive(Debug, Copy, Clone)]
+pub struct HandlerTarget {
+    /// The first instruction of the `catch` or `catch_all` block.
+    instr: u32,
+    /// The value stack height relative to the function frame upon entering the handler.
+    height: u32,
+}
+
+impl HandlerTarget {
+    /// Creates a new [`HandlerTarget`].
+    pub fn new(instr: u32, height: u32) -> Self {
+        Self { instr, height }
+    }
+
+    /// Returns the first instruction of the `catch` or `catch_all` block.
+    pub fn instr(&self) -> u32 {
+        self.instr
+    }
+
+    /// Returns the value stack height relative to the function frame upon entering the handler.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+impl HandlerTarget {
+    /// Encodes the [`HandlerTarget`] using `encoder`.
+    fn serialize(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.instr);
+        encoder.write_u32(self.height);
+    }
+
+    /// Decodes a [`HandlerTarget`] of a compiled function with `len_instrs` instructions.
+    ///
+    /// The value stack height of the compiled function is at most `max_stack_height`.
+    fn deserialize(
+        decoder: &mut Decoder,
+        len_instrs: u32,
+        max_stack_height: usize,
+    ) -> Result<Self, SerializationError> {
+        let instr = decoder.read_u32()?;
+        let height = decoder.read_u32()?;
+        if instr >= len_instrs || height as usize > max_stack_height {
+            return Err(SerializationError::malformed("invalid exception handler"));
+        }
+        Ok(Self::new(instr, height))
+    }
+}
+
+/// The `try` block to which exceptions are delegated.
+#[derive(Debug, Copy, Clone)]
+pub struct DelegateTarget {
+    /// The index of the first [`ExceptionHandler`] of the `try` block.
+    handler: u32,
+    /// The first instruction of the body of the `try` block.
+    instr: u32,
+}
+
+impl DelegateTarget {
+    /// Creates a new [`DelegateTarget`].
+    pub fn new(handler: u32, instr: u32) -> Self {
+        Self { handler, instr }
+    }
+}
+
+impl ExceptionHandler {
+    /// Creates a new [`ExceptionHandler`] covering the instructions in `range`.
+    pub fn new(range: Range<u32>, kind: ExceptionHandlerKind) -> Self {
+        Self { range, kind }
+    }
+
+    /// Returns the [`ExceptionHandlerKind`] of the [`ExceptionHandler`].
+    pub fn kind(&self) -> ExceptionHandlerKind {
+        self.kind
+    }
+
+    /// Updates the [`DelegateTarget`] of a delegating [`ExceptionHandler`].
+    ///
+    /// # Panics
+    ///
+    /// If `self` is not a delegating [`ExceptionHandler`].
+    pub fn update_delegate_target(&mut self, target: DelegateTarget) {
+        match &mut self.kind {
+            ExceptionHandlerKind::Delegate(delegate) => *delegate = Some(target),
+            kind => panic!("tried to update delegate target of non-delegate handler: {kind:?}"),
+        }
+    }
+
+    /// Encodes the [`ExceptionHandler`] of a compiled function using `encoder`.
+    pub fn serialize(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.range.start);
+        encoder.write_u32(self.range.end);
+        match self.kind {
+            ExceptionHandlerKind::Catch(tag, target) => {
+                encoder.write_u8(0);
+                encoder.write_u32(tag.to_u32());
+                target.serialize(encoder);
+            }
+            ExceptionHandlerKind::CatchAll(target) => {
+                encoder.write_u8(1);
+                target.serialize(encoder);
+            }
+            ExceptionHandlerKind::Delegate(None) => {
+                encoder.write_u8(2);
+            }
+            ExceptionHandlerKind::Delegate(Some(target)) => {
+                encoder.write_u8(3);
+                encoder.write_u32(target.handler);
+                encoder.write_u32(target.instr);
+            }
+        }
+    }
+
+    /// Decodes an [`ExceptionHandler`] of a compiled function using `decoder`.
+    ///
+    /// The compiled function has `len_instrs` instructions, `len_handlers` handlers
+    /// and a value stack height of at most `max_stack_height`. Its module defines
+    /// `len_tags` exception tags.
+    ///
+    /// # Errors
+    ///
+    /// If the encoded [`ExceptionHandler`] is malformed.
+    pub fn deserialize(
+        decoder: &mut Decoder,
+        len_instrs: u32,
+        len_handlers: u32,
+        len_tags: usize,
+        max_stack_height: usize,
+    ) -> Result<Self, SerializationError> {
+        let invalid = || SerializationError::malformed("invalid exception handler");
+        let start = decoder.read_u32()?;
+        let end = decoder.read_u32()?;
+        if start > end || end > len_instrs {
+            return Err(invalid());
+        }
+        let kind = match decoder.read_u8()? {
+            0 => {
+                let tag = decoder.read_u32()?;
+                if tag as usize >= len_tags {
+                    return Err(invalid());
+                }
+                let target = HandlerTarget::deserialize(decoder, len_instrs, max_stack_height)?;
+                ExceptionHandlerKind::Catch(TagIdx::from(tag), target)
+            }
+            1 => ExceptionHandlerKind::CatchAll(HandlerTarget::deserialize(
+                decoder,
+                len_instrs,
+                max_stack_height,
+            )?),
+            2 => ExceptionHandlerKind::Delegate(None),
+            3 => {
+                let handler = decoder.read_u32()?;
+                let instr = decoder.read_u32()?;
+                if handler >= len_handlers || instr >= len_instrs {
+                    return Err(invalid());
+                }
+                ExceptionHandlerKind::Delegate(Some(DelegateTarget::new(handler, instr)))
+            }
+            _ => return Err(invalid()),
+        };
+        Ok(Self::new(start..end, kind))
+    }
+
+    /// Returns the first instruction of the `catch` or `catch_all` block if any.
+    pub fn target_instr(&self) -> Option<u32> {
+        match self.kind {
+            ExceptionHandlerKind::Catch(_, target) | ExceptionHandlerKind::CatchAll(target) => {
+                Some(target.instr)
+            }
+            ExceptionHandlerKind::Delegate(_) => None,
+        }
+    }
+
+    /// Reverts [`ExceptionHandler::relocate`] with the same instruction and handler offsets.
+    fn unrelocate(mut self, instrs: u32, handlers: u32) -> Self {
+        self.range = (self.range.start - instrs)..(self.range.end - instrs);
+        match &mut self.kind {
+            ExceptionHandlerKind::Catch(_, target) | ExceptionHandlerKind::CatchAll(target) => {
+                target.instr -= instrs;
+            }
+            ExceptionHandlerKind::Delegate(Some(target)) => {
+                target.instr -= instrs;
+                target.handler -= handlers;
+            }
+            ExceptionHandlerKind::Delegate(None) => {}
+        }
+        self
+    }
+
+    /// Relocates the [`ExceptionHandler`] by the given instruction and handler offsets.
+    fn relocate(mut self, instrs: u32, handlers: u32) -> Self {
+        self.range = (self.range.start + instrs)..(self.range.end + instrs);
+        match &mut self.kind {
+            ExceptionHandlerKind::Catch(_, target) | ExceptionHandlerKind::CatchAll(target) => {
+                target.instr += instrs;
+            }
+            ExceptionHandlerKind::Delegate(Some(target)) => {
+                target.instr += instrs;
+                target.handler += handlers;
+            }
+            ExceptionHandlerKind::Delegate(None) => {}
+        }
+        self
+    }
+}
+
+/// Maps an instruction of a compiled function to the Wasm operator it was translated from.
+///
+/// # Note
+///
+/// An [`AddressMapping`] applies to all instructions of the compiled function
+/// starting at its instruction up to the instruction of the next [`AddressMapping`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AddressMapping {
+    /// The first instruction relative to the start of the compiled function.
+    instr: u32,
+    /// The offset of the Wasm operator within the Wasm binary of its [`Module`].
+    ///
+    /// [`Module`]: crate::Module
+    wasm_offset: u32,
+}
+
+impl AddressMapping {
+    /// Creates a new [`AddressMapping`] from the instruction `instr` to `wasm_offset`.
+    pub fn new(instr: u32, wasm_offset: u32) -> Self {
+        Self { instr, wasm_offset }
+    }
+
+    /// Returns the offset of the Wasm operator within the Wasm binary of its [`Module`].
+    ///
+    /// [`Module`]: crate::Module
+    pub fn wasm_offset(&self) -> u32 {
+        self.wasm_offset
+    }
+
+    /// Encodes the [`AddressMapping`] of a compiled function using `encoder`.
+    pub fn serialize(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.instr);
+        encoder.write_u32(self.wasm_offset);
+    }
+
+    /// Decodes an [`AddressMapping`] of a compiled function using `decoder`.
+    ///
+    /// The compiled function has `len_instrs` instructions and the
+    /// decoded [`AddressMapping`] must follow the `previous` one.
+    ///
+    /// # Errors
+    ///
+    /// If the encoded [`AddressMapping`] is malformed.
+    pub fn deserialize(
+        decoder: &mut Decoder,
+        len_instrs: u32,
+        previous: Option<AddressMapping>,
+    ) -> Result<Self, SerializationError> {
+        let instr = decoder.read_u32()?;
+        let wasm_offset = decoder.read_u32()?;
+        let unordered = matches!(previous, Some(previous) if previous.instr >= instr);
+        if instr >= len_instrs || unordered {
+            return Err(SerializationError::malformed("invalid address mapping"));
+        }
+        Ok(Self::new(instr, wasm_offset))
+    }
+}
+
+/// The entry of an exception handler found by [`CodeMap::find_handler`].
+#[derive(Debug, Copy, Clone)]
+pub struct HandlerEntry {
+    /// The first instruction of the exception handler.
+    ip: InstructionPtr,
+    /// The value stack height relative to the function frame upon entering the handler.
+    height: usize,
+    /// Is `true` if the exception handler is a `catch_all` handler.
+    catch_all: bool,
+}
+
+impl HandlerEntry {
+    /// Returns the [`InstructionPtr`] to the first instruction of the exception handler.
+    pub fn ip(&self) -> InstructionPtr {
+        self.ip
+    }
+
+    /// Returns the value stack height relative to the function frame upon entering the handler.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `true` if the exception handler does not receive the exception values.
+    pub fn is_catch_all(&self) -> bool {
+        self.catch_all
+    }
+}
+
+/// The exception handlers of a single compiled function in the [`CodeMap`].
+#[derive(Debug, Clone)]
+struct FuncHandlers {
+    /// The index of the first instruction of the compiled function.
+    instrs: usize,
+    /// The indices of the [`ExceptionHandler`] of the compiled function.
+    handlers: Range<u32>,
+}
+
+/// The first instruction of a single compiled function in the [`CodeMap`].
+#[derive(Debug, Copy, Clone)]
+struct FuncStart {
+    /// The index of the first instruction of the compiled function.
+    instrs: usize,
+    /// The compiled function.
+    func: CompiledFunc,
+    /// The index of the first [`AddressMapping`] of the compiled function.
+    addrs: usize,
+}
+
+/// A function whose translation has been deferred until its first execution.
+#[derive(Debug)]
+enum UncompiledFunc {
+    /// The function body awaits its translation.
+    Pending(LazyFunc),
+    /// The translation of the function body failed with the given message.
+    Failed(Box<str>),
+}
+
 /// Datastructure to efficiently store Wasm function bodies.
 #[derive(Debug)]
 pub struct CodeMap {
@@ -133,6 +528,16 @@ pub struct CodeMap {
     /// Also this improves efficiency of deallocating the [`CodeMap`]
     /// and generally improves data locality.
     instrs: Vec<Instruction>,
+    /// The exception handlers of all compiled functions.
+    handlers: Vec<ExceptionHandler>,
+    /// The compiled functions that have exception handlers, ordered by their instructions.
+    func_handlers: Vec<FuncHandlers>,
+    /// The initialized compiled functions ordered by their instructions.
+    func_starts: Vec<FuncStart>,
+    /// The [`AddressMapping`] of all compiled functions ordered by their instructions.
+    addrs: Vec<AddressMapping>,
+    /// The functions that await their translation upon their first execution.
+    uncompiled: BTreeMap<usize, UncompiledFunc>,
 }
 
 impl Default for CodeMap {
@@ -144,6 +549,11 @@ impl Default for CodeMap {
             // index value for compiled functions that have yet to be
             // initialized with their actual function bodies.
             instrs: vec![Instruction::Unreachable],
+            handlers: Vec::new(),
+            func_handlers: Vec::new(),
+            func_starts: Vec::new(),
+            addrs: Vec::new(),
+            uncompiled: BTreeMap::new(),
         }
     }
 }
@@ -167,14 +577,18 @@ impl CodeMap {
     ///
     /// - If `func` is an invalid [`CompiledFunc`] reference for this [`CodeMap`].
     /// - If `func` refers to an already initialized [`CompiledFunc`].
-    pub fn init_func<I>(
+    pub fn init_func<I, H, A>(
         &mut self,
         func: CompiledFunc,
         len_locals: usize,
         local_stack_height: usize,
         instrs: I,
+        handlers: H,
+        addrs: A,
     ) where
         I: IntoIterator<Item = Instruction>,
+        H: IntoIterator<Item = ExceptionHandler>,
+        A: IntoIterator<Item = AddressMapping>,
     {
         assert!(
             self.header(func).is_uninit(),
@@ -183,7 +597,162 @@ impl CodeMap {
         let start = self.instrs.len();
         self.instrs.extend(instrs);
         let iref = InstructionsRef::new(start);
-        self.headers[func.into_usize()] = FuncHeader::new(iref, len_locals, local_stack_height);
+        let len_instrs = self.instrs.len() - start;
+        self.headers[func.into_usize()] =
+            FuncHeader::new(iref, len_instrs, len_locals, local_stack_height);
+        // Note: Functions are initialized in the order of their instructions
+        //       so that `func_starts` stays ordered.
+        self.func_starts.push(FuncStart {
+            instrs: start,
+            func,
+            addrs: self.addrs.len(),
+        });
+        self.addrs.extend(addrs);
+        self.init_handlers(start, handlers);
+    }
+
+    /// Initializes the uninitialized [`CompiledFunc`] to be translated lazily from `lazy_func`.
+    ///
+    /// # Panics
+    ///
+    /// - If `func` is an invalid [`CompiledFunc`] reference for this [`CodeMap`].
+    /// - If `func` refers to an already initialized [`CompiledFunc`].
+    pub fn init_lazy_func(&mut self, func: CompiledFunc, lazy_func: LazyFunc) {
+        let header = self.header(func);
+        assert!(
+            header.is_uninit() && !header.is_uncompiled(),
+            "func {func:?} is already initialized"
+        );
+        self.headers[func.into_usize()] = FuncHeader::uncompiled();
+        self.uncompiled
+            .insert(func.into_usize(), UncompiledFunc::Pending(lazy_func));
+    }
+
+    /// Takes the [`LazyFunc`] of the [`CompiledFunc`] for its translation.
+    ///
+    /// Returns `Ok(None)` if `func` has already been translated.
+    ///
+    /// # Errors
+    ///
+    /// If a former translation of `func` failed.
+    ///
+    /// # Panics
+    ///
+    /// If `func` is neither translated nor awaits its translation.
+    pub fn take_lazy_func(&mut self, func: CompiledFunc) -> Result<Option<LazyFunc>, Trap> {
+        if !self.header(func).is_uninit() {
+            return Ok(None);
+        }
+        match self.uncompiled.remove(&func.into_usize()) {
+            Some(UncompiledFunc::Pending(lazy_func)) => Ok(Some(lazy_func)),
+            Some(UncompiledFunc::Failed(message)) => {
+                let trap = Trap::new(message.to_string());
+                self.uncompiled
+                    .insert(func.into_usize(), UncompiledFunc::Failed(message));
+                Err(trap)
+            }
+            None => panic!("func {func:?} is uninitialized"),
+        }
+    }
+
+    /// Records that the translation of the lazily translated [`CompiledFunc`] failed.
+    pub fn fail_lazy_func(&mut self, func: CompiledFunc, message: Box<str>) {
+        debug_assert!(self.header(func).is_uncompiled());
+        self.uncompiled
+            .insert(func.into_usize(), UncompiledFunc::Failed(message));
+    }
+
+    /// Stores the [`ExceptionHandler`] of a compiled function starting at instruction `start`.
+    ///
+    /// # Panics
+    ///
+    /// If the amount of stored instructions or handlers exceeds `u32::MAX`.
+    fn init_handlers<H>(&mut self, start: usize, handlers: H)
+    where
+        H: IntoIterator<Item = ExceptionHandler>,
+    {
+        let instrs_offset =
+            u32::try_from(start).unwrap_or_else(|_| panic!("out of bounds instruction: {start}"));
+        let first = self.handlers.len();
+        let handlers_offset =
+            u32::try_from(first).unwrap_or_else(|_| panic!("out of bounds handler: {first}"));
+        self.handlers.extend(
+            handlers
+                .into_iter()
+                .map(|handler| handler.relocate(instrs_offset, handlers_offset)),
+        );
+        let last = self.handlers.len();
+        if first == last {
+            return;
+        }
+        let handlers = u32::try_from(last)
+            .map(|last| handlers_offset..last)
+            .unwrap_or_else(|_| panic!("out of bounds handler: {last}"));
+        // Note: Functions are initialized in the order of their instructions
+        //       so that `func_handlers` stays ordered.
+        self.func_handlers.push(FuncHandlers {
+            instrs: start,
+            handlers,
+        });
+    }
+
+    /// Returns the index of the instruction pointed to by `ip`.
+    fn instr_index(&self, ip: InstructionPtr) -> usize {
+        // SAFETY: An `InstructionPtr` always points to an instruction of the `CodeMap`.
+        let offset = unsafe { ip.ptr.offset_from(self.instrs.as_ptr()) };
+        offset as usize
+    }
+
+    /// Searches the [`ExceptionHandler`] responsible for the instruction at `ip`.
+    ///
+    /// The `matches` closure decides if a `catch` handler with the given [`TagIdx`]
+    /// handles the exception.
+    ///
+    /// Returns `None` if no handler of the function handles the exception.
+    pub fn find_handler<F>(&self, ip: InstructionPtr, mut matches: F) -> Option<HandlerEntry>
+    where
+        F: FnMut(TagIdx) -> bool,
+    {
+        let mut pc = self.instr_index(ip);
+        let func = match self
+            .func_handlers
+            .partition_point(|func| func.instrs <= pc)
+            .checked_sub(1)
+        {
+            Some(index) => &self.func_handlers[index],
+            None => return None,
+        };
+        let mut index = func.handlers.start as usize;
+        while let Some(handler) = self.handlers[..func.handlers.end as usize].get(index) {
+            index += 1;
+            if !(handler.range.start as usize <= pc && pc < handler.range.end as usize) {
+                continue;
+            }
+            let (target, catch_all) = match handler.kind {
+                ExceptionHandlerKind::Catch(tag, target) => {
+                    if !matches(tag) {
+                        continue;
+                    }
+                    (target, false)
+                }
+                ExceptionHandlerKind::CatchAll(target) => (target, true),
+                ExceptionHandlerKind::Delegate(None) => return None,
+                ExceptionHandlerKind::Delegate(Some(target)) => {
+                    // The exception is rethrown as if thrown at the start of the
+                    // target `try` block and its handlers are searched next.
+                    index = target.handler as usize;
+                    pc = target.instr as usize;
+                    continue;
+                }
+            };
+            let ip = InstructionPtr::new(self.instrs[target.instr as usize..].as_ptr());
+            return Some(HandlerEntry {
+                ip,
+                height: target.height as usize,
+                catch_all,
+            });
+        }
+        None
     }
 
     /// Returns an [`InstructionPtr`] to the instruction at [`InstructionsRef`].
@@ -192,36 +761,129 @@ impl CodeMap {
         InstructionPtr::new(self.instrs[iref.to_usize()..].as_ptr())
     }
 
+    /// Returns an [`InstructionPtr`] to the first instruction of the [`CodeMap`].
+    ///
+    /// # Note
+    ///
+    /// All [`InstructionPtr`]s into the [`CodeMap`] are invalidated whenever this
+    /// changes due to a reallocation of the instructions upon lazy translation.
+    #[inline]
+    pub fn base_ptr(&self) -> InstructionPtr {
+        InstructionPtr::new(self.instrs.as_ptr())
+    }
+
+    /// Returns an [`InstructionsRef`] to the instruction pointed to by `ip`.
+    #[inline]
+    pub fn instr_ref(&self, ip: InstructionPtr) -> InstructionsRef {
+        InstructionsRef {
+            index: self.instr_index(ip),
+        }
+    }
+
+    /// Returns the [`CompiledFunc`] containing the instruction at `iref`
+    /// and the offset of the instruction within the [`CompiledFunc`].
+    ///
+    /// Returns `None` if `iref` does not refer to an instruction of a compiled function.
+    pub fn func_at(&self, iref: InstructionsRef) -> Option<(CompiledFunc, usize)> {
+        let index = iref.to_usize();
+        let start = self
+            .func_starts
+            .partition_point(|func| func.instrs <= index)
+            .checked_sub(1)
+            .map(|position| self.func_starts[position])?;
+        let offset = index - start.instrs;
+        if offset >= self.header(start.func).len_instrs {
+            return None;
+        }
+        Some((start.func, offset))
+    }
+
+    /// Returns the [`AddressMapping`] of the compiled [`CompiledFunc`].
+    ///
+    /// # Note
+    ///
+    /// This is empty if the [`CompiledFunc`] has been compiled without an address map.
+    pub fn address_map(&self, func_body: CompiledFunc) -> &[AddressMapping] {
+        let start = self.header(func_body).iref.to_usize();
+        let index = match self
+            .func_starts
+            .binary_search_by_key(&start, |func| func.instrs)
+        {
+            Ok(index) => index,
+            Err(_) => return &[],
+        };
+        let first = self.func_starts[index].addrs;
+        let last = self
+            .func_starts
+            .get(index + 1)
+            .map_or(self.addrs.len(), |func| func.addrs);
+        &self.addrs[first..last]
+    }
+
+    /// Returns the offset of the Wasm operator within the Wasm binary of its [`Module`]
+    /// from which the instruction at `instr_offset` of the [`CompiledFunc`] was translated.
+    ///
+    /// Returns `None` if the [`CompiledFunc`] has been compiled without an address map,
+    /// has not been compiled, yet, or if `instr_offset` is out of bounds.
+    ///
+    /// [`Module`]: crate::Module
+    pub fn wasm_offset(&self, func_body: CompiledFunc, instr_offset: usize) -> Option<usize> {
+        let header = self.header(func_body);
+        if header.is_uninit() || instr_offset >= header.len_instrs {
+            return None;
+        }
+        let addrs = self.address_map(func_body);
+        let index = addrs
+            .partition_point(|mapping| mapping.instr as usize <= instr_offset)
+            .checked_sub(1)?;
+        Some(addrs[index].wasm_offset as usize)
+    }
+
     /// Returns the [`FuncHeader`] of the [`CompiledFunc`].
     pub fn header(&self, func_body: CompiledFunc) -> &FuncHeader {
         &self.headers[func_body.into_usize()]
     }
 
-    /// Resolves the instruction at `index` of the compiled [`CompiledFunc`].
-    #[cfg(test)]
-    pub fn get_instr(&self, func_body: CompiledFunc, index: usize) -> Option<&Instruction> {
+    /// Returns the instructions of the compiled [`CompiledFunc`].
+    pub fn instrs(&self, func_body: CompiledFunc) -> &[Instruction] {
         let header = self.header(func_body);
         let start = header.iref.to_usize();
-        let end = self.instr_end(func_body);
-        let instrs = &self.instrs[start..end];
-        instrs.get(index)
+        &self.instrs[start..start + header.len_instrs]
     }
 
-    /// Returns the `end` index of the instructions of [`CompiledFunc`].
+    /// Returns the [`ExceptionHandler`] of the compiled [`CompiledFunc`].
+    ///
+    /// # Note
     ///
-    /// This is important to synthesize how many instructions there are in
-    /// the function referred to by [`CompiledFunc`].
+    /// The returned [`ExceptionHandler`] are relative to the start of the
+    /// compiled function as if they were not yet stored in the [`CodeMap`].
+    pub fn handlers(
+        &self,
+        func_body: CompiledFunc,
+    ) -> impl ExactSizeIterator<Item = ExceptionHandler> + '_ {
+        let start = self.header(func_body).iref.to_usize();
+        let handlers = self
+            .func_handlers
+            .binary_search_by_key(&start, |func| func.instrs)
+            .map(|index| self.func_handlers[index].handlers.clone())
+            .unwrap_or(0..0);
+        let instrs_offset = start as u32;
+        let handlers_offset = handlers.start;
+        self.handlers[handlers.start as usize..handlers.end as usize]
+            .iter()
+            .cloned()
+            .map(move |handler| handler.unrelocate(instrs_offset, handlers_offset))
+    }
+
+    /// Resolves the instruction at `index` of the compiled [`CompiledFunc`].
     #[cfg(test)]
-    pub fn instr_end(&self, func_body: CompiledFunc) -> usize {
-        self.headers
-            .get(func_body.into_usize() + 1)
-            .map(|header| header.iref.to_usize())
-            .unwrap_or(self.instrs.len())
+    pub fn get_instr(&self, func_body: CompiledFunc, index: usize) -> Option<&Instruction> {
+        self.instrs(func_body).get(index)
     }
 }
 
 /// The instruction pointer to the instruction of a function on the call stack.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct InstructionPtr {
     /// The pointer to the instruction.
     ptr: *const Instruction,
@@ -267,6 +929,18 @@ impl InstructionPtr {
         self.ptr = unsafe { self.ptr.add(delta) };
     }
 
+    /// Re-bases the [`InstructionPtr`] from the instructions at `from` onto the instructions at `to`.
+    ///
+    /// # Note
+    ///
+    /// This keeps the index of the pointed-to [`Instruction`] and is used to update
+    /// [`InstructionPtr`]s after the instructions of the [`CodeMap`] have been reallocated.
+    pub fn rebase(&mut self, from: InstructionPtr, to: InstructionPtr) {
+        // Note: We cannot use `offset_from` since `from` may point to deallocated memory.
+        let index = (self.ptr as usize - from.ptr as usize) / mem::size_of::<Instruction>();
+        self.ptr = to.ptr.wrapping_add(index);
+    }
+
     /// Returns a shared reference to the currently pointed at [`Instruction`].
     ///
     /// # Safety
@@ -1,6 +1,5 @@
 use super::{stack::StackLimits, DropKeep};
 use core::{mem::size_of, num::NonZeroU64};
-use wasmi_core::UntypedValue;
 use wasmparser::WasmFeatures;
 
 /// The default amount of stacks kept in the cache at most.
@@ -31,6 +30,16 @@ pub struct Config {
     tail_call: bool,
     /// Is `true` if the [`extended-const`] Wasm proposal is enabled.
     extended_const: bool,
+    /// Is `true` if the [`simd`] Wasm proposal is enabled.
+    simd: bool,
+    /// Is `true` if the [`multi-memory`] Wasm proposal is enabled.
+    multi_memory: bool,
+    /// Is `true` if the [`memory64`] Wasm proposal is enabled.
+    memory64: bool,
+    /// Is `true` if the [`threads`] Wasm proposal is enabled.
+    threads: bool,
+    /// Is `true` if the [`exception-handling`] Wasm proposal is enabled.
+    exceptions: bool,
     /// Is `true` if Wasm instructions on `f32` and `f64` types are allowed.
     floats: bool,
     /// Is `true` if `wasmi` executions shall consume fuel.
@@ -39,6 +48,44 @@ pub struct Config {
     fuel_consumption_mode: FuelConsumptionMode,
     /// The configured fuel costs of all `wasmi` bytecode instructions.
     fuel_costs: FuelCosts,
+    /// Is `true` if `wasmi` executions shall check for epoch deadlines.
+    epoch_interruption: bool,
+    /// The compilation mode of the `wasmi` [`Engine`](crate::Engine).
+    compilation_mode: CompilationMode,
+    /// Is `true` if compiled functions keep a map from their instructions to Wasm code offsets.
+    address_map: bool,
+}
+
+/// The compilation mode of the `wasmi` [`Engine`].
+///
+/// This mode decides when the Wasm function bodies of a [`Module`] are
+/// validated and translated into `wasmi` bytecode.
+///
+/// The default compilation mode is [`CompilationMode::Eager`].
+///
+/// [`Engine`]: crate::Engine
+/// [`Module`]: crate::Module
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum CompilationMode {
+    /// All function bodies are validated and translated upon [`Module`] creation.
+    ///
+    /// [`Module`]: crate::Module
+    #[default]
+    Eager,
+    /// All function bodies are validated upon [`Module`] creation but only
+    /// translated into `wasmi` bytecode when they are first executed.
+    ///
+    /// [`Module`]: crate::Module
+    LazyTranslation,
+    /// Function bodies are validated and translated when they are first executed.
+    ///
+    /// # Note
+    ///
+    /// This speeds up [`Module`] creation the most. However, an invalid function
+    /// body is only detected upon its first execution which then traps.
+    ///
+    /// [`Module`]: crate::Module
+    Lazy,
 }
 
 /// The fuel consumption mode of the `wasmi` [`Engine`].
@@ -175,7 +222,9 @@ impl FuelCosts {
 impl Default for FuelCosts {
     fn default() -> Self {
         let memory_bytes_per_fuel = 64;
-        let bytes_per_register = size_of::<UntypedValue>() as u64;
+        // Registers are accounted as 64-bit wide so that fuel costs
+        // do not depend on the width required for `v128` values.
+        let bytes_per_register = size_of::<u64>() as u64;
         let registers_per_fuel = memory_bytes_per_fuel / bytes_per_register;
         Self {
             base: 1,
@@ -204,10 +253,18 @@ impl Default for Config {
             reference_types: true,
             tail_call: false,
             extended_const: false,
+            simd: false,
+            multi_memory: false,
+            memory64: false,
+            threads: false,
+            exceptions: false,
             floats: true,
             consume_fuel: false,
             fuel_costs: FuelCosts::default(),
             fuel_consumption_mode: FuelConsumptionMode::default(),
+            epoch_interruption: false,
+            compilation_mode: CompilationMode::default(),
+            address_map: true,
         }
     }
 }
@@ -336,6 +393,80 @@ impl Config {
         self
     }
 
+    /// Enable or disable the [`simd`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// - Disabled by default.
+    /// - Requires the `simd` crate feature which widens all values
+    ///   of the `wasmi` interpreter to 128 bits.
+    ///
+    /// [`simd`]: https://github.com/WebAssembly/simd
+    #[cfg(feature = "simd")]
+    pub fn wasm_simd(&mut self, enable: bool) -> &mut Self {
+        self.simd = enable;
+        self
+    }
+
+    /// Enable or disable the [`multi-memory`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// Disabled by default.
+    ///
+    /// [`multi-memory`]: https://github.com/WebAssembly/multi-memory
+    pub fn wasm_multi_memory(&mut self, enable: bool) -> &mut Self {
+        self.multi_memory = enable;
+        self
+    }
+
+    /// Enable or disable the [`memory64`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// Disabled by default.
+    ///
+    /// [`memory64`]: https://github.com/WebAssembly/memory64
+    pub fn wasm_memory64(&mut self, enable: bool) -> &mut Self {
+        self.memory64 = enable;
+        self
+    }
+
+    /// Enable or disable the [`threads`] Wasm proposal for the [`Config`].
+    ///
+    /// This enables shared linear memories as well as atomic instructions.
+    ///
+    /// # Note
+    ///
+    /// - Disabled by default.
+    /// - Compiling a [`Module`] has to wait for all ongoing executions of the
+    ///   same [`Engine`] to finish. This includes executions that are blocked
+    ///   in a `memory.atomic.wait` instruction.
+    ///
+    /// [`Module`]: crate::Module
+    /// [`Engine`]: crate::Engine
+    ///
+    /// [`threads`]: https://github.com/WebAssembly/threads
+    pub fn wasm_threads(&mut self, enable: bool) -> &mut Self {
+        self.threads = enable;
+        self
+    }
+
+    /// Enable or disable the [`exception-handling`] Wasm proposal for the [`Config`].
+    ///
+    /// This enables tags as well as the `try`, `catch`, `catch_all`,
+    /// `delegate`, `throw` and `rethrow` instructions.
+    ///
+    /// # Note
+    ///
+    /// Disabled by default.
+    ///
+    /// [`exception-handling`]: https://github.com/WebAssembly/exception-handling
+    pub fn wasm_exceptions(&mut self, enable: bool) -> &mut Self {
+        self.exceptions = enable;
+        self
+    }
+
     /// Enable or disable Wasm floating point (`f32` and `f64`) instructions and types.
     ///
     /// Enabled by default.
@@ -400,6 +531,141 @@ impl Config {
             .then_some(self.fuel_consumption_mode)
     }
 
+    /// Configures whether `wasmi` executions will be interruptible via epochs.
+    ///
+    /// # Note
+    ///
+    /// This configuration makes `wasmi` instrument its internal bytecode so that
+    /// it checks the current epoch of the [`Engine`] against the epoch deadline of
+    /// the [`Store`] at every function entry and loop header. Once the deadline has
+    /// been reached the execution either traps with
+    /// [`TrapCode::EpochDeadlineExceeded`](crate::core::TrapCode::EpochDeadlineExceeded)
+    /// or yields, depending on the configuration of the [`Store`].
+    ///
+    /// Epoch interruption is a cheaper yet less precise alternative to fuel metering
+    /// that is primarily useful to implement timeouts for Wasm executions.
+    ///
+    /// - Use [`Engine::increment_epoch`](crate::Engine::increment_epoch) to advance
+    ///   the epoch, for example periodically from another thread.
+    /// - Use [`Store::set_epoch_deadline`](crate::Store::set_epoch_deadline) to set
+    ///   the deadline of the [`Store`] before executing some code.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Store`]: crate::Store
+    /// [`Engine`]: crate::Engine
+    pub fn epoch_interruption(&mut self, enable: bool) -> &mut Self {
+        self.epoch_interruption = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] enables epoch interruption by the [`Engine`].
+    ///
+    /// [`Engine`]: crate::Engine
+    pub(crate) fn get_epoch_interruption(&self) -> bool {
+        self.epoch_interruption
+    }
+
+    /// Configures the [`CompilationMode`] for the [`Engine`].
+    ///
+    /// [`Engine`]: crate::Engine
+    pub fn compilation_mode(&mut self, mode: CompilationMode) -> &mut Self {
+        self.compilation_mode = mode;
+        self
+    }
+
+    /// Returns the [`CompilationMode`] of the [`Config`].
+    pub(crate) fn get_compilation_mode(&self) -> CompilationMode {
+        self.compilation_mode
+    }
+
+    /// Configures whether compiled functions keep a map from their `wasmi` bytecode
+    /// instructions to the offsets of the Wasm operators they were translated from.
+    ///
+    /// # Note
+    ///
+    /// The offsets are relative to the start of the Wasm binary of the [`Module`].
+    /// They are reported by the frames of Wasm backtraces attached to traps and
+    /// allow tools to resolve source locations, for example using DWARF line info.
+    /// Disabling this saves some memory per compiled function.
+    ///
+    /// Enabled by default.
+    ///
+    /// [`Module`]: crate::Module
+    pub fn generate_address_map(&mut self, enable: bool) -> &mut Self {
+        self.address_map = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] enables generation of address maps.
+    pub(crate) fn get_generate_address_map(&self) -> bool {
+        self.address_map
+    }
+
+    /// Returns a fingerprint of all [`Config`] settings that affect Wasm translation.
+    ///
+    /// # Note
+    ///
+    /// The `wasmi` bytecode of a serialized [`Module`] depends on these settings.
+    /// Therefore it can only be deserialized by an [`Engine`] with a [`Config`]
+    /// that has the same fingerprint.
+    ///
+    /// [`Module`]: crate::Module
+    /// [`Engine`]: crate::Engine
+    pub(crate) fn translation_fingerprint(&self) -> u64 {
+        /// The offset basis of the 64-bit FNV-1a hash function.
+        const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+        /// The prime of the 64-bit FNV-1a hash function.
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+        let flags = [
+            self.mutable_global,
+            self.sign_extension,
+            self.saturating_float_to_int,
+            self.multi_value,
+            self.bulk_memory,
+            self.reference_types,
+            self.tail_call,
+            self.extended_const,
+            self.simd,
+            self.multi_memory,
+            self.memory64,
+            self.threads,
+            self.exceptions,
+            self.floats,
+            self.consume_fuel,
+            self.epoch_interruption,
+        ]
+        .into_iter()
+        .enumerate()
+        .fold(0_u64, |flags, (n, enabled)| {
+            flags | (u64::from(enabled) << n)
+        });
+        let costs = &self.fuel_costs;
+        let fuel_costs = [
+            costs.base,
+            costs.entity,
+            costs.load,
+            costs.store,
+            costs.call,
+            costs.branch_kept_per_fuel,
+            costs.func_locals_per_fuel,
+            costs.memory_bytes_per_fuel,
+            costs.table_elements_per_fuel,
+        ];
+        // Fuel costs only affect the translation if fuel metering is enabled.
+        let fuel_costs = match self.consume_fuel {
+            true => &fuel_costs[..],
+            false => &[],
+        };
+        [flags]
+            .iter()
+            .chain(fuel_costs)
+            .flat_map(|value| value.to_le_bytes())
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+
     /// Returns the [`WasmFeatures`] represented by the [`Config`].
     pub(crate) fn wasm_features(&self) -> WasmFeatures {
         WasmFeatures {
@@ -413,12 +679,12 @@ impl Config {
             extended_const: self.extended_const,
             floats: self.floats,
             component_model: false,
-            simd: false,
+            simd: self.simd,
             relaxed_simd: false,
-            threads: false,
-            multi_memory: false,
-            exceptions: false,
-            memory64: false,
+            threads: self.threads,
+            multi_memory: self.multi_memory,
+            exceptions: self.exceptions,
+            memory64: self.memory64,
             memory_control: false,
         }
     }
@@ -26,6 +26,16 @@ impl ConstRef {
     pub fn to_usize(self) -> usize {
         self.0 as usize
     }
+
+    /// Returns the index of the [`ConstRef`] as `u32` value.
+    pub fn to_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Creates a [`ConstRef`] from the `u32` value returned by [`ConstRef::to_u32`].
+    pub fn from_u32(index: u32) -> Self {
+        Self(index)
+    }
 }
 
 /// A pool of deduplicated reusable constant values.
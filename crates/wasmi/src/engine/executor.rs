@@ -1,9 +1,12 @@
 use super::{bytecode::BranchOffset, const_pool::ConstRef, CompiledFunc, ConstPoolView};
+#[cfg(feature = "simd")]
+use crate::engine::bytecode::LaneIdx;
+#[cfg(feature = "trace")]
+use crate::engine::{MemoryAccess, Tracer};
 use crate::{
     core::TrapCode,
     engine::{
         bytecode::{
-            AddressOffset,
             BlockFuel,
             BranchTableTargets,
             DataSegmentIdx,
@@ -12,28 +15,44 @@ use crate::{
             GlobalIdx,
             Instruction,
             LocalDepth,
+            MemArg,
+            MemoryIdx,
             SignatureIdx,
             TableIdx,
+            TagIdx,
         },
         cache::InstanceCache,
-        code_map::{CodeMap, InstructionPtr},
+        code_map::{CodeMap, HandlerEntry, InstructionPtr},
         config::FuelCosts,
-        stack::{CallStack, ValueStackPtr},
+        stack::{CallStack, CaughtExceptions, ValueStackPtr},
         DropKeep,
         FuncFrame,
         ValueStack,
     },
     func::FuncEntity,
-    table::TableEntity,
+    memory::MemoryError,
+    store::EpochDeadlineBehavior,
+    table::{TableEntity, TableError},
+    value::WithType,
+    Exception,
     FuelConsumptionMode,
     Func,
     FuncRef,
     Instance,
+    Memory,
+    ResourceLimiterRef,
     StoreInner,
     Table,
+    Value,
 };
-use core::cmp::{self};
-use wasmi_core::{Pages, UntypedValue};
+use alloc::boxed::Box;
+use core::{
+    cmp::{self},
+    ptr,
+    sync::atomic,
+    time::Duration,
+};
+use wasmi_core::{AtomicRmwOp, Pages, UntypedValue};
 
 /// The outcome of a Wasm execution.
 ///
@@ -41,12 +60,18 @@ use wasmi_core::{Pages, UntypedValue};
 ///
 /// A Wasm execution includes everything but host calls.
 /// In other words: Everything in between host calls is a Wasm execution.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub enum WasmOutcome {
     /// The Wasm execution has ended and returns to the host side.
     Return,
     /// The Wasm execution calls a host function.
     Call { host_func: Func, instance: Instance },
+    /// The Wasm execution reached the epoch deadline and yields to the host side.
+    EpochYield,
+    /// The Wasm execution threw an [`Exception`] that has not been caught.
+    Throw(Exception),
+    /// The Wasm execution calls a Wasm function that has yet to be translated.
+    Translate(CompiledFunc),
 }
 
 /// The outcome of a Wasm execution.
@@ -61,6 +86,8 @@ pub enum CallOutcome {
     Continue,
     /// The Wasm execution calls a host function.
     Call { host_func: Func, instance: Instance },
+    /// The Wasm execution calls a Wasm function that has yet to be translated.
+    Translate(CompiledFunc),
 }
 
 /// The kind of a function call.
@@ -81,6 +108,15 @@ pub enum ReturnOutcome {
     Host,
 }
 
+/// The outcome of a Wasm epoch deadline check.
+#[derive(Debug, Copy, Clone)]
+pub enum EpochOutcome {
+    /// The Wasm execution continues since the epoch deadline has not yet been reached.
+    Continue,
+    /// The Wasm execution yields back to the host since the epoch deadline has been reached.
+    Yield,
+}
+
 /// Executes the given function `frame`.
 ///
 /// # Note
@@ -88,33 +124,143 @@ pub enum ReturnOutcome {
 /// This executes Wasm instructions until either the execution calls
 /// into a host function or the Wasm execution has come to an end.
 ///
+/// If `exception` is `Some` it is thrown by the host function called
+/// by the given function `frame` before the execution continues.
+///
 /// # Errors
 ///
 /// If the Wasm execution traps.
 #[inline(never)]
-pub fn execute_wasm<'engine>(
-    ctx: &mut StoreInner,
+#[allow(clippy::too_many_arguments)]
+pub fn execute_wasm<'ctx, 'engine>(
+    ctx: &'ctx mut StoreInner,
     cache: &'engine mut InstanceCache,
     value_stack: &'engine mut ValueStack,
     call_stack: &'engine mut CallStack,
+    exceptions: &'engine mut CaughtExceptions,
     code_map: &'engine CodeMap,
     const_pool: ConstPoolView<'engine>,
+    resource_limiter: &'engine mut ResourceLimiterRef<'ctx>,
+    exception: Option<Exception>,
 ) -> Result<WasmOutcome, TrapCode> {
-    Executor::new(ctx, cache, value_stack, call_stack, code_map, const_pool).execute()
+    let mut executor = Executor::new(
+        ctx,
+        cache,
+        value_stack,
+        call_stack,
+        exceptions,
+        code_map,
+        const_pool,
+        resource_limiter,
+    );
+    if let Some(exception) = exception {
+        if let Some(exception) = executor.throw_from_host(exception) {
+            return Ok(WasmOutcome::Throw(exception));
+        }
+    }
+    executor
+        .execute()
+        .map_err(|trap_code| executor.push_trapping_frame(trap_code))
+}
+
+/// Notifies the [`Tracer`] of the `ctx` that the execution enters `func` of `instance`.
+///
+/// # Note
+///
+/// This is used for calls that do not originate from the [`Executor`].
+#[cfg(feature = "trace")]
+pub fn trace_func_enter(ctx: &mut StoreInner, instance: &Instance, func: CompiledFunc) {
+    trace_func(ctx, instance, func, <dyn Tracer>::func_enter)
+}
+
+/// Notifies the [`Tracer`] of the `ctx` about `func` of `instance` using `notify`.
+#[cfg(feature = "trace")]
+fn trace_func(
+    ctx: &mut StoreInner,
+    instance: &Instance,
+    func: CompiledFunc,
+    notify: fn(&mut (dyn Tracer + 'static), &Instance, u32),
+) {
+    if ctx.tracer_mut().is_none() {
+        return;
+    }
+    let func_index = match ctx.resolve_instance(instance).func_index(func) {
+        Some(func_index) => func_index,
+        None => return,
+    };
+    if let Some(tracer) = ctx.tracer_mut() {
+        notify(tracer, instance, func_index)
+    }
 }
 
 /// The function signature of Wasm load operations.
-type WasmLoadOp =
-    fn(memory: &[u8], address: UntypedValue, offset: u32) -> Result<UntypedValue, TrapCode>;
+type WasmLoadOp = unsafe fn(
+    memory: *const [u8],
+    address: UntypedValue,
+    offset: u64,
+) -> Result<UntypedValue, TrapCode>;
 
 /// The function signature of Wasm store operations.
-type WasmStoreOp = fn(
-    memory: &mut [u8],
+type WasmStoreOp = unsafe fn(
+    memory: *mut [u8],
+    address: UntypedValue,
+    offset: u64,
+    value: UntypedValue,
+) -> Result<(), TrapCode>;
+
+#[cfg(feature = "simd")]
+/// The function signature of Wasm `v128` lane load operations.
+type WasmLoadLaneOp = unsafe fn(
+    memory: *const [u8],
+    address: UntypedValue,
+    offset: u64,
+    value: UntypedValue,
+    lane: u8,
+) -> Result<UntypedValue, TrapCode>;
+
+#[cfg(feature = "simd")]
+/// The function signature of Wasm `v128` lane store operations.
+type WasmStoreLaneOp = unsafe fn(
+    memory: *mut [u8],
+    address: UntypedValue,
+    offset: u64,
+    value: UntypedValue,
+    lane: u8,
+) -> Result<(), TrapCode>;
+
+/// The function signature of Wasm atomic load operations.
+type WasmAtomicLoadOp = unsafe fn(
+    memory: *mut [u8],
+    address: UntypedValue,
+    offset: u64,
+) -> Result<UntypedValue, TrapCode>;
+
+/// The function signature of Wasm atomic store operations.
+type WasmAtomicStoreOp = unsafe fn(
+    memory: *mut [u8],
     address: UntypedValue,
-    offset: u32,
+    offset: u64,
     value: UntypedValue,
 ) -> Result<(), TrapCode>;
 
+/// The function signature of Wasm atomic read-modify-write operations.
+type WasmAtomicRmwOp = unsafe fn(
+    memory: *mut [u8],
+    address: UntypedValue,
+    offset: u64,
+    op: AtomicRmwOp,
+    value: UntypedValue,
+) -> Result<UntypedValue, TrapCode>;
+
+/// The function signature of Wasm atomic compare-exchange operations.
+type WasmAtomicCmpxchgOp = unsafe fn(
+    memory: *mut [u8],
+    address: UntypedValue,
+    offset: u64,
+    expected: UntypedValue,
+    replacement: UntypedValue,
+) -> Result<UntypedValue, TrapCode>;
+
 /// An error that can occur upon `memory.grow` or `table.grow`.
 #[derive(Copy, Clone)]
 pub enum EntityGrowError {
@@ -134,6 +280,43 @@ impl From<TrapCode> for EntityGrowError {
 /// if the `memory.grow` or `table.grow` operations fail.
 const INVALID_GROWTH_ERRCODE: u32 = u32::MAX;
 
+/// The WebAssembly specification demands to return this value
+/// if the `memory.grow` operation fails for a 64-bit linear memory.
+const INVALID_GROWTH_ERRCODE_64: u64 = u64::MAX;
+
+/// Converts an address or length operand of a linear memory operation to `usize`.
+///
+/// # Note
+///
+/// - Operands of 32-bit linear memories are zero-extended to 64-bit
+///   so that this conversion serves both 32-bit and 64-bit linear memories.
+/// - Returns `usize::MAX` if the operand cannot be represented as `usize`
+///   which results in an out of bounds access for all linear memories.
+#[inline]
+fn memory_operand(value: UntypedValue) -> usize {
+    usize::try_from(u64::from(value)).unwrap_or(usize::MAX)
+}
+
+/// Returns a raw pointer to the `n` bytes of `memory` starting at `offset`.
+///
+/// # Note
+///
+/// Linear memories are accessed through raw pointers since the bytes
+/// of shared linear memories might be concurrently accessed by other threads.
+///
+/// # Errors
+///
+/// If the `n` bytes starting at `offset` are out of bounds of `memory`.
+#[inline]
+fn memory_range(memory: *mut [u8], offset: usize, n: usize) -> Result<*mut u8, TrapCode> {
+    offset
+        .checked_add(n)
+        .filter(|&end| end <= memory.len())
+        .ok_or(TrapCode::MemoryOutOfBounds)?;
+    // Note: `offset + n` has been checked to be within bounds of `memory`.
+    Ok(memory.cast::<u8>().wrapping_add(offset))
+}
+
 /// An execution context for executing a `wasmi` function frame.
 #[derive(Debug)]
 struct Executor<'ctx, 'engine> {
@@ -160,6 +343,14 @@ struct Executor<'ctx, 'engine> {
     ///
     /// This is used to store the stack of nested function calls.
     call_stack: &'engine mut CallStack,
+    /// The value stack height after the parameters and locals of the current function.
+    ///
+    /// # Note
+    ///
+    /// This is used to restore the value stack upon entering exception handlers.
+    base: usize,
+    /// The exceptions caught by live `catch` and `catch_all` blocks.
+    exceptions: &'engine mut CaughtExceptions,
     /// The Wasm function code map.
     ///
     /// # Note
@@ -168,19 +359,31 @@ struct Executor<'ctx, 'engine> {
     code_map: &'engine CodeMap,
     /// A read-only view to a pool of constant values.
     const_pool: ConstPoolView<'engine>,
+    /// The optional [`ResourceLimiter`] of the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// This is consulted upon `memory.grow` and `table.grow` instructions.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`Store`]: crate::Store
+    resource_limiter: &'engine mut ResourceLimiterRef<'ctx>,
 }
 
 macro_rules! forward_call {
     ($expr:expr) => {{
-        if let CallOutcome::Call {
-            host_func,
-            instance,
-        } = $expr?
-        {
-            return Ok(WasmOutcome::Call {
+        match $expr? {
+            CallOutcome::Continue => {}
+            CallOutcome::Call {
                 host_func,
                 instance,
-            });
+            } => {
+                return Ok(WasmOutcome::Call {
+                    host_func,
+                    instance,
+                })
+            }
+            CallOutcome::Translate(func) => return Ok(WasmOutcome::Translate(func)),
         }
     }};
 }
@@ -188,17 +391,21 @@ macro_rules! forward_call {
 impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     /// Creates a new [`Executor`] for executing a `wasmi` function frame.
     #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         ctx: &'ctx mut StoreInner,
         cache: &'engine mut InstanceCache,
         value_stack: &'engine mut ValueStack,
         call_stack: &'engine mut CallStack,
+        exceptions: &'engine mut CaughtExceptions,
         code_map: &'engine CodeMap,
         const_pool: ConstPoolView<'engine>,
+        resource_limiter: &'engine mut ResourceLimiterRef<'ctx>,
     ) -> Self {
         let frame = call_stack.pop().expect("must have frame on the call stack");
         let sp = value_stack.stack_ptr();
         let ip = frame.ip();
+        let base = frame.base();
         Self {
             sp,
             ip,
@@ -206,17 +413,32 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
             ctx,
             value_stack,
             call_stack,
+            base,
+            exceptions,
             code_map,
             const_pool,
+            resource_limiter,
         }
     }
 
     /// Executes the function frame until it returns or traps.
     #[inline(always)]
-    fn execute(mut self) -> Result<WasmOutcome, TrapCode> {
+    fn execute(&mut self) -> Result<WasmOutcome, TrapCode> {
         use Instruction as Instr;
         loop {
+            #[cfg(feature = "trace")]
+            self.trace_instr();
             match *self.ip.get() {
+                Instr::Throw(tag) => {
+                    if let Some(exception) = self.visit_throw(tag) {
+                        return Ok(WasmOutcome::Throw(exception));
+                    }
+                }
+                Instr::Rethrow(local_depth) => {
+                    if let Some(exception) = self.visit_rethrow(local_depth) {
+                        return Ok(WasmOutcome::Throw(exception));
+                    }
+                }
                 Instr::LocalGet(local_depth) => self.visit_local_get(local_depth),
                 Instr::LocalSet(local_depth) => self.visit_local_set(local_depth),
                 Instr::LocalTee(local_depth) => self.visit_local_tee(local_depth),
@@ -228,6 +450,11 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::BrTable(targets) => self.visit_br_table(targets),
                 Instr::Unreachable => self.visit_unreachable()?,
                 Instr::ConsumeFuel(block_fuel) => self.visit_consume_fuel(block_fuel)?,
+                Instr::CheckEpoch => {
+                    if let EpochOutcome::Yield = self.visit_check_epoch()? {
+                        return Ok(WasmOutcome::EpochYield);
+                    }
+                }
                 Instr::Return(drop_keep) => {
                     if let ReturnOutcome::Host = self.visit_ret(drop_keep) {
                         return Ok(WasmOutcome::Return);
@@ -239,7 +466,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                     }
                 }
                 Instr::ReturnCallInternal(compiled_func) => {
-                    self.visit_return_call_internal(compiled_func)?
+                    forward_call!(self.visit_return_call_internal(compiled_func))
                 }
                 Instr::ReturnCall(func) => {
                     forward_call!(self.visit_return_call(func))
@@ -247,7 +474,9 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::ReturnCallIndirect(func_type) => {
                     forward_call!(self.visit_return_call_indirect(func_type))
                 }
-                Instr::CallInternal(compiled_func) => self.visit_call_internal(compiled_func)?,
+                Instr::CallInternal(compiled_func) => {
+                    forward_call!(self.visit_call_internal(compiled_func))
+                }
                 Instr::Call(func) => forward_call!(self.visit_call(func)),
                 Instr::CallIndirect(func_type) => {
                     forward_call!(self.visit_call_indirect(func_type))
@@ -256,33 +485,33 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::Select => self.visit_select(),
                 Instr::GlobalGet(global_idx) => self.visit_global_get(global_idx),
                 Instr::GlobalSet(global_idx) => self.visit_global_set(global_idx),
-                Instr::I32Load(offset) => self.visit_i32_load(offset)?,
-                Instr::I64Load(offset) => self.visit_i64_load(offset)?,
-                Instr::F32Load(offset) => self.visit_f32_load(offset)?,
-                Instr::F64Load(offset) => self.visit_f64_load(offset)?,
-                Instr::I32Load8S(offset) => self.visit_i32_load_i8_s(offset)?,
-                Instr::I32Load8U(offset) => self.visit_i32_load_i8_u(offset)?,
-                Instr::I32Load16S(offset) => self.visit_i32_load_i16_s(offset)?,
-                Instr::I32Load16U(offset) => self.visit_i32_load_i16_u(offset)?,
-                Instr::I64Load8S(offset) => self.visit_i64_load_i8_s(offset)?,
-                Instr::I64Load8U(offset) => self.visit_i64_load_i8_u(offset)?,
-                Instr::I64Load16S(offset) => self.visit_i64_load_i16_s(offset)?,
-                Instr::I64Load16U(offset) => self.visit_i64_load_i16_u(offset)?,
-                Instr::I64Load32S(offset) => self.visit_i64_load_i32_s(offset)?,
-                Instr::I64Load32U(offset) => self.visit_i64_load_i32_u(offset)?,
-                Instr::I32Store(offset) => self.visit_i32_store(offset)?,
-                Instr::I64Store(offset) => self.visit_i64_store(offset)?,
-                Instr::F32Store(offset) => self.visit_f32_store(offset)?,
-                Instr::F64Store(offset) => self.visit_f64_store(offset)?,
-                Instr::I32Store8(offset) => self.visit_i32_store_8(offset)?,
-                Instr::I32Store16(offset) => self.visit_i32_store_16(offset)?,
-                Instr::I64Store8(offset) => self.visit_i64_store_8(offset)?,
-                Instr::I64Store16(offset) => self.visit_i64_store_16(offset)?,
-                Instr::I64Store32(offset) => self.visit_i64_store_32(offset)?,
-                Instr::MemorySize => self.visit_memory_size(),
-                Instr::MemoryGrow => self.visit_memory_grow()?,
-                Instr::MemoryFill => self.visit_memory_fill()?,
-                Instr::MemoryCopy => self.visit_memory_copy()?,
+                Instr::I32Load(memarg) => self.visit_i32_load(memarg)?,
+                Instr::I64Load(memarg) => self.visit_i64_load(memarg)?,
+                Instr::F32Load(memarg) => self.visit_f32_load(memarg)?,
+                Instr::F64Load(memarg) => self.visit_f64_load(memarg)?,
+                Instr::I32Load8S(memarg) => self.visit_i32_load_i8_s(memarg)?,
+                Instr::I32Load8U(memarg) => self.visit_i32_load_i8_u(memarg)?,
+                Instr::I32Load16S(memarg) => self.visit_i32_load_i16_s(memarg)?,
+                Instr::I32Load16U(memarg) => self.visit_i32_load_i16_u(memarg)?,
+                Instr::I64Load8S(memarg) => self.visit_i64_load_i8_s(memarg)?,
+                Instr::I64Load8U(memarg) => self.visit_i64_load_i8_u(memarg)?,
+                Instr::I64Load16S(memarg) => self.visit_i64_load_i16_s(memarg)?,
+                Instr::I64Load16U(memarg) => self.visit_i64_load_i16_u(memarg)?,
+                Instr::I64Load32S(memarg) => self.visit_i64_load_i32_s(memarg)?,
+                Instr::I64Load32U(memarg) => self.visit_i64_load_i32_u(memarg)?,
+                Instr::I32Store(memarg) => self.visit_i32_store(memarg)?,
+                Instr::I64Store(memarg) => self.visit_i64_store(memarg)?,
+                Instr::F32Store(memarg) => self.visit_f32_store(memarg)?,
+                Instr::F64Store(memarg) => self.visit_f64_store(memarg)?,
+                Instr::I32Store8(memarg) => self.visit_i32_store_8(memarg)?,
+                Instr::I32Store16(memarg) => self.visit_i32_store_16(memarg)?,
+                Instr::I64Store8(memarg) => self.visit_i64_store_8(memarg)?,
+                Instr::I64Store16(memarg) => self.visit_i64_store_16(memarg)?,
+                Instr::I64Store32(memarg) => self.visit_i64_store_32(memarg)?,
+                Instr::MemorySize(memory) => self.visit_memory_size(memory),
+                Instr::MemoryGrow(memory) => self.visit_memory_grow(memory)?,
+                Instr::MemoryFill(memory) => self.visit_memory_fill(memory)?,
+                Instr::MemoryCopy(dst) => self.visit_memory_copy(dst)?,
                 Instr::MemoryInit(segment) => self.visit_memory_init(segment)?,
                 Instr::DataDrop(segment) => self.visit_data_drop(segment),
                 Instr::TableSize(table) => self.visit_table_size(table),
@@ -429,6 +658,553 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::I64Extend8S => self.visit_i64_extend8_s(),
                 Instr::I64Extend16S => self.visit_i64_extend16_s(),
                 Instr::I64Extend32S => self.visit_i64_extend32_s(),
+                #[cfg(feature = "simd")]
+                Instr::V128Load(memarg) => self.visit_v128_load(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load8x8S(memarg) => self.visit_v128_load8x8_s(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load8x8U(memarg) => self.visit_v128_load8x8_u(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load16x4S(memarg) => self.visit_v128_load16x4_s(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load16x4U(memarg) => self.visit_v128_load16x4_u(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load32x2S(memarg) => self.visit_v128_load32x2_s(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load32x2U(memarg) => self.visit_v128_load32x2_u(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load8Splat(memarg) => self.visit_v128_load8_splat(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load16Splat(memarg) => self.visit_v128_load16_splat(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load32Splat(memarg) => self.visit_v128_load32_splat(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load64Splat(memarg) => self.visit_v128_load64_splat(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load32Zero(memarg) => self.visit_v128_load32_zero(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load64Zero(memarg) => self.visit_v128_load64_zero(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Store(memarg) => self.visit_v128_store(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load8Lane(memarg) => self.visit_v128_load8_lane(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load16Lane(memarg) => self.visit_v128_load16_lane(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load32Lane(memarg) => self.visit_v128_load32_lane(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Load64Lane(memarg) => self.visit_v128_load64_lane(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Store8Lane(memarg) => self.visit_v128_store8_lane(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Store16Lane(memarg) => self.visit_v128_store16_lane(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Store32Lane(memarg) => self.visit_v128_store32_lane(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::V128Store64Lane(memarg) => self.visit_v128_store64_lane(memarg)?,
+                #[cfg(feature = "simd")]
+                Instr::I8x16Shuffle(selector) => self.visit_i8x16_shuffle(selector),
+                #[cfg(feature = "simd")]
+                Instr::I8x16ExtractLaneS(lane) => self.visit_i8x16_extract_lane_s(lane),
+                #[cfg(feature = "simd")]
+                Instr::I8x16ExtractLaneU(lane) => self.visit_i8x16_extract_lane_u(lane),
+                #[cfg(feature = "simd")]
+                Instr::I8x16ReplaceLane(lane) => self.visit_i8x16_replace_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtractLaneS(lane) => self.visit_i16x8_extract_lane_s(lane),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtractLaneU(lane) => self.visit_i16x8_extract_lane_u(lane),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ReplaceLane(lane) => self.visit_i16x8_replace_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtractLane(lane) => self.visit_i32x4_extract_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ReplaceLane(lane) => self.visit_i32x4_replace_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtractLane(lane) => self.visit_i64x2_extract_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ReplaceLane(lane) => self.visit_i64x2_replace_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::F32x4ExtractLane(lane) => self.visit_f32x4_extract_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::F32x4ReplaceLane(lane) => self.visit_f32x4_replace_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::F64x2ExtractLane(lane) => self.visit_f64x2_extract_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::F64x2ReplaceLane(lane) => self.visit_f64x2_replace_lane(lane),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Swizzle => self.visit_i8x16_swizzle(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Splat => self.visit_i8x16_splat(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Splat => self.visit_i16x8_splat(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Splat => self.visit_i32x4_splat(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Splat => self.visit_i64x2_splat(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Splat => self.visit_f32x4_splat(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Splat => self.visit_f64x2_splat(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Eq => self.visit_i8x16_eq(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Ne => self.visit_i8x16_ne(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16LtS => self.visit_i8x16_lt_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16LtU => self.visit_i8x16_lt_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16GtS => self.visit_i8x16_gt_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16GtU => self.visit_i8x16_gt_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16LeS => self.visit_i8x16_le_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16LeU => self.visit_i8x16_le_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16GeS => self.visit_i8x16_ge_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16GeU => self.visit_i8x16_ge_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Eq => self.visit_i16x8_eq(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Ne => self.visit_i16x8_ne(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8LtS => self.visit_i16x8_lt_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8LtU => self.visit_i16x8_lt_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8GtS => self.visit_i16x8_gt_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8GtU => self.visit_i16x8_gt_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8LeS => self.visit_i16x8_le_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8LeU => self.visit_i16x8_le_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8GeS => self.visit_i16x8_ge_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8GeU => self.visit_i16x8_ge_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Eq => self.visit_i32x4_eq(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Ne => self.visit_i32x4_ne(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4LtS => self.visit_i32x4_lt_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4LtU => self.visit_i32x4_lt_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4GtS => self.visit_i32x4_gt_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4GtU => self.visit_i32x4_gt_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4LeS => self.visit_i32x4_le_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4LeU => self.visit_i32x4_le_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4GeS => self.visit_i32x4_ge_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4GeU => self.visit_i32x4_ge_u(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Eq => self.visit_i64x2_eq(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Ne => self.visit_i64x2_ne(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2LtS => self.visit_i64x2_lt_s(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2GtS => self.visit_i64x2_gt_s(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2LeS => self.visit_i64x2_le_s(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2GeS => self.visit_i64x2_ge_s(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Eq => self.visit_f32x4_eq(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Ne => self.visit_f32x4_ne(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Lt => self.visit_f32x4_lt(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Gt => self.visit_f32x4_gt(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Le => self.visit_f32x4_le(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Ge => self.visit_f32x4_ge(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Eq => self.visit_f64x2_eq(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Ne => self.visit_f64x2_ne(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Lt => self.visit_f64x2_lt(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Gt => self.visit_f64x2_gt(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Le => self.visit_f64x2_le(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Ge => self.visit_f64x2_ge(),
+                #[cfg(feature = "simd")]
+                Instr::V128Not => self.visit_v128_not(),
+                #[cfg(feature = "simd")]
+                Instr::V128And => self.visit_v128_and(),
+                #[cfg(feature = "simd")]
+                Instr::V128AndNot => self.visit_v128_andnot(),
+                #[cfg(feature = "simd")]
+                Instr::V128Or => self.visit_v128_or(),
+                #[cfg(feature = "simd")]
+                Instr::V128Xor => self.visit_v128_xor(),
+                #[cfg(feature = "simd")]
+                Instr::V128Bitselect => self.visit_v128_bitselect(),
+                #[cfg(feature = "simd")]
+                Instr::V128AnyTrue => self.visit_v128_any_true(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Abs => self.visit_i8x16_abs(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Neg => self.visit_i8x16_neg(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Popcnt => self.visit_i8x16_popcnt(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16AllTrue => self.visit_i8x16_all_true(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Bitmask => self.visit_i8x16_bitmask(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16NarrowI16x8S => self.visit_i8x16_narrow_i16x8_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16NarrowI16x8U => self.visit_i8x16_narrow_i16x8_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Shl => self.visit_i8x16_shl(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16ShrS => self.visit_i8x16_shr_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16ShrU => self.visit_i8x16_shr_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Add => self.visit_i8x16_add(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16AddSatS => self.visit_i8x16_add_sat_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16AddSatU => self.visit_i8x16_add_sat_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16Sub => self.visit_i8x16_sub(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16SubSatS => self.visit_i8x16_sub_sat_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16SubSatU => self.visit_i8x16_sub_sat_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16MinS => self.visit_i8x16_min_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16MinU => self.visit_i8x16_min_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16MaxS => self.visit_i8x16_max_s(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16MaxU => self.visit_i8x16_max_u(),
+                #[cfg(feature = "simd")]
+                Instr::I8x16AvgrU => self.visit_i8x16_avgr_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtAddPairwiseI8x16S => self.visit_i16x8_extadd_pairwise_i8x16_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtAddPairwiseI8x16U => self.visit_i16x8_extadd_pairwise_i8x16_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Abs => self.visit_i16x8_abs(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Neg => self.visit_i16x8_neg(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Q15MulrSatS => self.visit_i16x8_q15mulr_sat_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8AllTrue => self.visit_i16x8_all_true(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Bitmask => self.visit_i16x8_bitmask(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8NarrowI32x4S => self.visit_i16x8_narrow_i32x4_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8NarrowI32x4U => self.visit_i16x8_narrow_i32x4_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtendLowI8x16S => self.visit_i16x8_extend_low_i8x16_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtendHighI8x16S => self.visit_i16x8_extend_high_i8x16_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtendLowI8x16U => self.visit_i16x8_extend_low_i8x16_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtendHighI8x16U => self.visit_i16x8_extend_high_i8x16_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Shl => self.visit_i16x8_shl(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ShrS => self.visit_i16x8_shr_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ShrU => self.visit_i16x8_shr_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Add => self.visit_i16x8_add(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8AddSatS => self.visit_i16x8_add_sat_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8AddSatU => self.visit_i16x8_add_sat_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Sub => self.visit_i16x8_sub(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8SubSatS => self.visit_i16x8_sub_sat_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8SubSatU => self.visit_i16x8_sub_sat_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8Mul => self.visit_i16x8_mul(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8MinS => self.visit_i16x8_min_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8MinU => self.visit_i16x8_min_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8MaxS => self.visit_i16x8_max_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8MaxU => self.visit_i16x8_max_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8AvgrU => self.visit_i16x8_avgr_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtMulLowI8x16S => self.visit_i16x8_extmul_low_i8x16_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtMulHighI8x16S => self.visit_i16x8_extmul_high_i8x16_s(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtMulLowI8x16U => self.visit_i16x8_extmul_low_i8x16_u(),
+                #[cfg(feature = "simd")]
+                Instr::I16x8ExtMulHighI8x16U => self.visit_i16x8_extmul_high_i8x16_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtAddPairwiseI16x8S => self.visit_i32x4_extadd_pairwise_i16x8_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtAddPairwiseI16x8U => self.visit_i32x4_extadd_pairwise_i16x8_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Abs => self.visit_i32x4_abs(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Neg => self.visit_i32x4_neg(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4AllTrue => self.visit_i32x4_all_true(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Bitmask => self.visit_i32x4_bitmask(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtendLowI16x8S => self.visit_i32x4_extend_low_i16x8_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtendHighI16x8S => self.visit_i32x4_extend_high_i16x8_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtendLowI16x8U => self.visit_i32x4_extend_low_i16x8_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtendHighI16x8U => self.visit_i32x4_extend_high_i16x8_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Shl => self.visit_i32x4_shl(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ShrS => self.visit_i32x4_shr_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ShrU => self.visit_i32x4_shr_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Add => self.visit_i32x4_add(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Sub => self.visit_i32x4_sub(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4Mul => self.visit_i32x4_mul(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4MinS => self.visit_i32x4_min_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4MinU => self.visit_i32x4_min_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4MaxS => self.visit_i32x4_max_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4MaxU => self.visit_i32x4_max_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4DotI16x8S => self.visit_i32x4_dot_i16x8_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtMulLowI16x8S => self.visit_i32x4_extmul_low_i16x8_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtMulHighI16x8S => self.visit_i32x4_extmul_high_i16x8_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtMulLowI16x8U => self.visit_i32x4_extmul_low_i16x8_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4ExtMulHighI16x8U => self.visit_i32x4_extmul_high_i16x8_u(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Abs => self.visit_i64x2_abs(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Neg => self.visit_i64x2_neg(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2AllTrue => self.visit_i64x2_all_true(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Bitmask => self.visit_i64x2_bitmask(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtendLowI32x4S => self.visit_i64x2_extend_low_i32x4_s(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtendHighI32x4S => self.visit_i64x2_extend_high_i32x4_s(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtendLowI32x4U => self.visit_i64x2_extend_low_i32x4_u(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtendHighI32x4U => self.visit_i64x2_extend_high_i32x4_u(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Shl => self.visit_i64x2_shl(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ShrS => self.visit_i64x2_shr_s(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ShrU => self.visit_i64x2_shr_u(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Add => self.visit_i64x2_add(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Sub => self.visit_i64x2_sub(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2Mul => self.visit_i64x2_mul(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtMulLowI32x4S => self.visit_i64x2_extmul_low_i32x4_s(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtMulHighI32x4S => self.visit_i64x2_extmul_high_i32x4_s(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtMulLowI32x4U => self.visit_i64x2_extmul_low_i32x4_u(),
+                #[cfg(feature = "simd")]
+                Instr::I64x2ExtMulHighI32x4U => self.visit_i64x2_extmul_high_i32x4_u(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Ceil => self.visit_f32x4_ceil(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Floor => self.visit_f32x4_floor(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Trunc => self.visit_f32x4_trunc(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Nearest => self.visit_f32x4_nearest(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Abs => self.visit_f32x4_abs(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Neg => self.visit_f32x4_neg(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Sqrt => self.visit_f32x4_sqrt(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Add => self.visit_f32x4_add(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Sub => self.visit_f32x4_sub(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Mul => self.visit_f32x4_mul(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Div => self.visit_f32x4_div(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Min => self.visit_f32x4_min(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4Max => self.visit_f32x4_max(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4PMin => self.visit_f32x4_pmin(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4PMax => self.visit_f32x4_pmax(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Ceil => self.visit_f64x2_ceil(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Floor => self.visit_f64x2_floor(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Trunc => self.visit_f64x2_trunc(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Nearest => self.visit_f64x2_nearest(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Abs => self.visit_f64x2_abs(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Neg => self.visit_f64x2_neg(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Sqrt => self.visit_f64x2_sqrt(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Add => self.visit_f64x2_add(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Sub => self.visit_f64x2_sub(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Mul => self.visit_f64x2_mul(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Div => self.visit_f64x2_div(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Min => self.visit_f64x2_min(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2Max => self.visit_f64x2_max(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2PMin => self.visit_f64x2_pmin(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2PMax => self.visit_f64x2_pmax(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4TruncSatF32x4S => self.visit_i32x4_trunc_sat_f32x4_s(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4TruncSatF32x4U => self.visit_i32x4_trunc_sat_f32x4_u(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4ConvertI32x4S => self.visit_f32x4_convert_i32x4_s(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4ConvertI32x4U => self.visit_f32x4_convert_i32x4_u(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4TruncSatF64x2SZero => self.visit_i32x4_trunc_sat_f64x2_s_zero(),
+                #[cfg(feature = "simd")]
+                Instr::I32x4TruncSatF64x2UZero => self.visit_i32x4_trunc_sat_f64x2_u_zero(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2ConvertLowI32x4S => self.visit_f64x2_convert_low_i32x4_s(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2ConvertLowI32x4U => self.visit_f64x2_convert_low_i32x4_u(),
+                #[cfg(feature = "simd")]
+                Instr::F32x4DemoteF64x2Zero => self.visit_f32x4_demote_f64x2_zero(),
+                #[cfg(feature = "simd")]
+                Instr::F64x2PromoteLowF32x4 => self.visit_f64x2_promote_low_f32x4(),
+                Instr::MemoryAtomicNotify(memarg) => self.visit_memory_atomic_notify(memarg)?,
+                Instr::MemoryAtomicWait32(memarg) => self.visit_memory_atomic_wait32(memarg)?,
+                Instr::MemoryAtomicWait64(memarg) => self.visit_memory_atomic_wait64(memarg)?,
+                Instr::AtomicFence => self.visit_atomic_fence(),
+                Instr::I32AtomicLoad(memarg) => self.visit_i32_atomic_load(memarg)?,
+                Instr::I64AtomicLoad(memarg) => self.visit_i64_atomic_load(memarg)?,
+                Instr::I32AtomicLoad8U(memarg) => self.visit_i32_atomic_load8_u(memarg)?,
+                Instr::I32AtomicLoad16U(memarg) => self.visit_i32_atomic_load16_u(memarg)?,
+                Instr::I64AtomicLoad8U(memarg) => self.visit_i64_atomic_load8_u(memarg)?,
+                Instr::I64AtomicLoad16U(memarg) => self.visit_i64_atomic_load16_u(memarg)?,
+                Instr::I64AtomicLoad32U(memarg) => self.visit_i64_atomic_load32_u(memarg)?,
+                Instr::I32AtomicStore(memarg) => self.visit_i32_atomic_store(memarg)?,
+                Instr::I64AtomicStore(memarg) => self.visit_i64_atomic_store(memarg)?,
+                Instr::I32AtomicStore8(memarg) => self.visit_i32_atomic_store8(memarg)?,
+                Instr::I32AtomicStore16(memarg) => self.visit_i32_atomic_store16(memarg)?,
+                Instr::I64AtomicStore8(memarg) => self.visit_i64_atomic_store8(memarg)?,
+                Instr::I64AtomicStore16(memarg) => self.visit_i64_atomic_store16(memarg)?,
+                Instr::I64AtomicStore32(memarg) => self.visit_i64_atomic_store32(memarg)?,
+                Instr::I32AtomicRmwAdd(memarg) => self.visit_i32_atomic_rmw_add(memarg)?,
+                Instr::I64AtomicRmwAdd(memarg) => self.visit_i64_atomic_rmw_add(memarg)?,
+                Instr::I32AtomicRmw8AddU(memarg) => self.visit_i32_atomic_rmw8_add_u(memarg)?,
+                Instr::I32AtomicRmw16AddU(memarg) => self.visit_i32_atomic_rmw16_add_u(memarg)?,
+                Instr::I64AtomicRmw8AddU(memarg) => self.visit_i64_atomic_rmw8_add_u(memarg)?,
+                Instr::I64AtomicRmw16AddU(memarg) => self.visit_i64_atomic_rmw16_add_u(memarg)?,
+                Instr::I64AtomicRmw32AddU(memarg) => self.visit_i64_atomic_rmw32_add_u(memarg)?,
+                Instr::I32AtomicRmwSub(memarg) => self.visit_i32_atomic_rmw_sub(memarg)?,
+                Instr::I64AtomicRmwSub(memarg) => self.visit_i64_atomic_rmw_sub(memarg)?,
+                Instr::I32AtomicRmw8SubU(memarg) => self.visit_i32_atomic_rmw8_sub_u(memarg)?,
+                Instr::I32AtomicRmw16SubU(memarg) => self.visit_i32_atomic_rmw16_sub_u(memarg)?,
+                Instr::I64AtomicRmw8SubU(memarg) => self.visit_i64_atomic_rmw8_sub_u(memarg)?,
+                Instr::I64AtomicRmw16SubU(memarg) => self.visit_i64_atomic_rmw16_sub_u(memarg)?,
+                Instr::I64AtomicRmw32SubU(memarg) => self.visit_i64_atomic_rmw32_sub_u(memarg)?,
+                Instr::I32AtomicRmwAnd(memarg) => self.visit_i32_atomic_rmw_and(memarg)?,
+                Instr::I64AtomicRmwAnd(memarg) => self.visit_i64_atomic_rmw_and(memarg)?,
+                Instr::I32AtomicRmw8AndU(memarg) => self.visit_i32_atomic_rmw8_and_u(memarg)?,
+                Instr::I32AtomicRmw16AndU(memarg) => self.visit_i32_atomic_rmw16_and_u(memarg)?,
+                Instr::I64AtomicRmw8AndU(memarg) => self.visit_i64_atomic_rmw8_and_u(memarg)?,
+                Instr::I64AtomicRmw16AndU(memarg) => self.visit_i64_atomic_rmw16_and_u(memarg)?,
+                Instr::I64AtomicRmw32AndU(memarg) => self.visit_i64_atomic_rmw32_and_u(memarg)?,
+                Instr::I32AtomicRmwOr(memarg) => self.visit_i32_atomic_rmw_or(memarg)?,
+                Instr::I64AtomicRmwOr(memarg) => self.visit_i64_atomic_rmw_or(memarg)?,
+                Instr::I32AtomicRmw8OrU(memarg) => self.visit_i32_atomic_rmw8_or_u(memarg)?,
+                Instr::I32AtomicRmw16OrU(memarg) => self.visit_i32_atomic_rmw16_or_u(memarg)?,
+                Instr::I64AtomicRmw8OrU(memarg) => self.visit_i64_atomic_rmw8_or_u(memarg)?,
+                Instr::I64AtomicRmw16OrU(memarg) => self.visit_i64_atomic_rmw16_or_u(memarg)?,
+                Instr::I64AtomicRmw32OrU(memarg) => self.visit_i64_atomic_rmw32_or_u(memarg)?,
+                Instr::I32AtomicRmwXor(memarg) => self.visit_i32_atomic_rmw_xor(memarg)?,
+                Instr::I64AtomicRmwXor(memarg) => self.visit_i64_atomic_rmw_xor(memarg)?,
+                Instr::I32AtomicRmw8XorU(memarg) => self.visit_i32_atomic_rmw8_xor_u(memarg)?,
+                Instr::I32AtomicRmw16XorU(memarg) => self.visit_i32_atomic_rmw16_xor_u(memarg)?,
+                Instr::I64AtomicRmw8XorU(memarg) => self.visit_i64_atomic_rmw8_xor_u(memarg)?,
+                Instr::I64AtomicRmw16XorU(memarg) => self.visit_i64_atomic_rmw16_xor_u(memarg)?,
+                Instr::I64AtomicRmw32XorU(memarg) => self.visit_i64_atomic_rmw32_xor_u(memarg)?,
+                Instr::I32AtomicRmwXchg(memarg) => self.visit_i32_atomic_rmw_xchg(memarg)?,
+                Instr::I64AtomicRmwXchg(memarg) => self.visit_i64_atomic_rmw_xchg(memarg)?,
+                Instr::I32AtomicRmw8XchgU(memarg) => self.visit_i32_atomic_rmw8_xchg_u(memarg)?,
+                Instr::I32AtomicRmw16XchgU(memarg) => self.visit_i32_atomic_rmw16_xchg_u(memarg)?,
+                Instr::I64AtomicRmw8XchgU(memarg) => self.visit_i64_atomic_rmw8_xchg_u(memarg)?,
+                Instr::I64AtomicRmw16XchgU(memarg) => self.visit_i64_atomic_rmw16_xchg_u(memarg)?,
+                Instr::I64AtomicRmw32XchgU(memarg) => self.visit_i64_atomic_rmw32_xchg_u(memarg)?,
+                Instr::I32AtomicRmwCmpxchg(memarg) => self.visit_i32_atomic_rmw_cmpxchg(memarg)?,
+                Instr::I64AtomicRmwCmpxchg(memarg) => self.visit_i64_atomic_rmw_cmpxchg(memarg)?,
+                Instr::I32AtomicRmw8CmpxchgU(memarg) => {
+                    self.visit_i32_atomic_rmw8_cmpxchg_u(memarg)?
+                }
+                Instr::I32AtomicRmw16CmpxchgU(memarg) => {
+                    self.visit_i32_atomic_rmw16_cmpxchg_u(memarg)?
+                }
+                Instr::I64AtomicRmw8CmpxchgU(memarg) => {
+                    self.visit_i64_atomic_rmw8_cmpxchg_u(memarg)?
+                }
+                Instr::I64AtomicRmw16CmpxchgU(memarg) => {
+                    self.visit_i64_atomic_rmw16_cmpxchg_u(memarg)?
+                }
+                Instr::I64AtomicRmw32CmpxchgU(memarg) => {
+                    self.visit_i64_atomic_rmw32_cmpxchg_u(memarg)?
+                }
             }
         }
     }
@@ -449,17 +1225,41 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     #[inline(always)]
     fn execute_load_extend(
         &mut self,
-        offset: AddressOffset,
+        memarg: MemArg,
         load_extend: WasmLoadOp,
     ) -> Result<(), TrapCode> {
+        let offset = self.resolve_offset(memarg);
+        #[cfg(feature = "trace")]
+        let address = self.sp.last();
         self.sp.try_eval_top(|address| {
-            let memory = self.cache.default_memory_bytes(self.ctx);
-            let value = load_extend(memory, address, offset.into_inner())?;
-            Ok(value)
+            let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+            // Safety: `memory` is valid for reads and writes.
+            unsafe { load_extend(memory, address, offset) }
         })?;
+        #[cfg(feature = "trace")]
+        self.trace_memory_load(memarg, address, offset);
         self.try_next_instr()
     }
 
+    /// Returns the offset of the linear memory access described by `memarg`.
+    #[inline(always)]
+    fn resolve_offset(&self, memarg: MemArg) -> u64 {
+        match memarg.const_offset() {
+            None => u64::from(memarg.offset().into_inner()),
+            Some(offset) => self.resolve_const_offset(offset),
+        }
+    }
+
+    /// Returns the 64-bit offset of a linear memory access stored in the constant pool.
+    #[cold]
+    #[inline(never)]
+    fn resolve_const_offset(&self, offset: ConstRef) -> u64 {
+        self.const_pool
+            .get(offset)
+            .map(u64::from)
+            .unwrap_or_else(|| unreachable!("missing constant value for const reference"))
+    }
+
     /// Executes a generic Wasm `store[N]` operation.
     ///
     /// # Note
@@ -473,12 +1273,177 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     #[inline(always)]
     fn execute_store_wrap(
         &mut self,
-        offset: AddressOffset,
+        memarg: MemArg,
         store_wrap: WasmStoreOp,
     ) -> Result<(), TrapCode> {
+        let offset = self.resolve_offset(memarg);
         let (address, value) = self.sp.pop2();
-        let memory = self.cache.default_memory_bytes(self.ctx);
-        store_wrap(memory, address, offset.into_inner(), value)?;
+        let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+        // Safety: `memory` is valid for reads and writes.
+        unsafe { store_wrap(memory, address, offset, value) }?;
+        #[cfg(feature = "trace")]
+        self.trace_memory_store(memarg, address, offset, value);
+        self.try_next_instr()
+    }
+
+    /// Executes a generic Wasm `atomic.load` operation.
+    ///
+    /// # Note
+    ///
+    /// This can be used to emulate the following Wasm operands:
+    ///
+    /// - `{i32, i64}.atomic.load`
+    /// - `{i32, i64}.atomic.load8_u`
+    /// - `{i32, i64}.atomic.load16_u`
+    /// - `i64.atomic.load32_u`
+    #[inline(always)]
+    fn execute_atomic_load(
+        &mut self,
+        memarg: MemArg,
+        load: WasmAtomicLoadOp,
+    ) -> Result<(), TrapCode> {
+        let offset = self.resolve_offset(memarg);
+        #[cfg(feature = "trace")]
+        let address = self.sp.last();
+        self.sp.try_eval_top(|address| {
+            let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+            // Safety: `memory` is valid for reads and writes.
+            unsafe { load(memory, address, offset) }
+        })?;
+        #[cfg(feature = "trace")]
+        self.trace_memory_load(memarg, address, offset);
+        self.try_next_instr()
+    }
+
+    /// Executes a generic Wasm `atomic.store` operation.
+    ///
+    /// # Note
+    ///
+    /// This can be used to emulate the following Wasm operands:
+    ///
+    /// - `{i32, i64}.atomic.store`
+    /// - `{i32, i64}.atomic.store8`
+    /// - `{i32, i64}.atomic.store16`
+    /// - `i64.atomic.store32`
+    #[inline(always)]
+    fn execute_atomic_store(
+        &mut self,
+        memarg: MemArg,
+        store: WasmAtomicStoreOp,
+    ) -> Result<(), TrapCode> {
+        let offset = self.resolve_offset(memarg);
+        let (address, value) = self.sp.pop2();
+        let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+        // Safety: `memory` is valid for reads and writes.
+        unsafe { store(memory, address, offset, value) }?;
+        #[cfg(feature = "trace")]
+        self.trace_memory_store(memarg, address, offset, value);
+        self.try_next_instr()
+    }
+
+    /// Executes a generic Wasm `atomic.rmw` operation.
+    ///
+    /// # Note
+    ///
+    /// This can be used to emulate the following Wasm operands:
+    ///
+    /// - `{i32, i64}.atomic.rmw.{add, sub, and, or, xor, xchg}`
+    /// - `{i32, i64}.atomic.rmw{8, 16}.{add, sub, and, or, xor, xchg}_u`
+    /// - `i64.atomic.rmw32.{add, sub, and, or, xor, xchg}_u`
+    #[inline(always)]
+    fn execute_atomic_rmw(
+        &mut self,
+        memarg: MemArg,
+        op: AtomicRmwOp,
+        rmw: WasmAtomicRmwOp,
+    ) -> Result<(), TrapCode> {
+        let offset = self.resolve_offset(memarg);
+        #[cfg(feature = "trace")]
+        let (address, operand) = (self.sp.nth_back(2), self.sp.last());
+        self.sp.try_eval_top2(|address, value| {
+            let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+            // Safety: `memory` is valid for reads and writes.
+            unsafe { rmw(memory, address, offset, op, value) }
+        })?;
+        #[cfg(feature = "trace")]
+        self.trace_memory_rmw(memarg, address, offset, operand);
+        self.try_next_instr()
+    }
+
+    /// Executes a generic Wasm `atomic.rmw.cmpxchg` operation.
+    ///
+    /// # Note
+    ///
+    /// This can be used to emulate the following Wasm operands:
+    ///
+    /// - `{i32, i64}.atomic.rmw.cmpxchg`
+    /// - `{i32, i64}.atomic.rmw{8, 16}.cmpxchg_u`
+    /// - `i64.atomic.rmw32.cmpxchg_u`
+    #[inline(always)]
+    fn execute_atomic_cmpxchg(
+        &mut self,
+        memarg: MemArg,
+        cmpxchg: WasmAtomicCmpxchgOp,
+    ) -> Result<(), TrapCode> {
+        let offset = self.resolve_offset(memarg);
+        let (expected, replacement) = self.sp.pop2();
+        #[cfg(feature = "trace")]
+        let address = self.sp.last();
+        self.sp.try_eval_top(|address| {
+            let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+            // Safety: `memory` is valid for reads and writes.
+            unsafe { cmpxchg(memory, address, offset, expected, replacement) }
+        })?;
+        #[cfg(feature = "trace")]
+        self.trace_memory_cmpxchg(memarg, address, offset, expected, replacement);
+        self.try_next_instr()
+    }
+
+    /// Executes a generic Wasm `memory.atomic.wait` operation.
+    ///
+    /// # Note
+    ///
+    /// This can be used to emulate the following Wasm operands:
+    ///
+    /// - `memory.atomic.wait32` with `N = 4`
+    /// - `memory.atomic.wait64` with `N = 8`
+    ///
+    /// # Errors
+    ///
+    /// - If the accessed address is out of bounds or unaligned.
+    /// - If the accessed linear memory is not shared.
+    #[inline(always)]
+    fn execute_atomic_wait<const N: usize>(
+        &mut self,
+        memarg: MemArg,
+        load: WasmAtomicLoadOp,
+    ) -> Result<(), TrapCode> {
+        let offset = self.resolve_offset(memarg);
+        let (address, expected, timeout) = self.sp.pop3();
+        let memory = self.cache.get_memory(self.ctx, memarg.memory());
+        let entity = self.ctx.resolve_memory(&memory);
+        let effective_address =
+            UntypedValue::atomic_address::<N>(entity.data_size(), address, offset)?;
+        let shared = entity
+            .shared()
+            .cloned()
+            .ok_or(TrapCode::ExpectedSharedMemory)?;
+        // Negative timeouts represent an infinite timeout.
+        let timeout = u64::try_from(i64::from(timeout))
+            .ok()
+            .map(Duration::from_nanos);
+        let result = shared.atomic_wait(
+            effective_address,
+            || {
+                let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+                // Note: the loaded value is zero-extended the same way as `expected`.
+                // Safety: `memory` is valid for reads and writes.
+                let loaded = unsafe { load(memory, address, offset) };
+                matches!(loaded, Ok(loaded) if loaded == expected)
+            },
+            timeout,
+        );
+        self.sp.push_as(result as i32);
         self.try_next_instr()
     }
 
@@ -608,19 +1573,34 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         func: &Func,
         kind: CallKind,
     ) -> Result<CallOutcome, TrapCode> {
+        #[cfg(feature = "trace")]
+        if matches!(kind, CallKind::Tail) {
+            self.trace_func_exit();
+        }
         self.next_instr_at(skip);
         self.sync_stack_ptr();
         if matches!(kind, CallKind::Nested) {
-            self.call_stack
-                .push(FuncFrame::new(self.ip, self.cache.instance()))?;
+            self.push_caller_frame()?;
         }
         match self.ctx.resolve_func(func) {
             FuncEntity::Wasm(wasm_func) => {
-                let header = self.code_map.header(wasm_func.func_body());
-                self.value_stack.prepare_wasm_call(header)?;
+                let func_body = wasm_func.func_body();
+                let header = self.code_map.header(func_body);
+                if header.is_uninit() {
+                    self.cache.update_instance(wasm_func.instance());
+                    return Ok(CallOutcome::Translate(func_body));
+                }
+                self.value_stack
+                    .prepare_wasm_call(header)
+                    .map_err(|trap_code| {
+                        Self::pop_caller_frame(self.call_stack, kind, trap_code)
+                    })?;
                 self.sp = self.value_stack.stack_ptr();
+                self.base = self.value_stack.len();
                 self.cache.update_instance(wasm_func.instance());
                 self.ip = self.code_map.instr_ptr(header.iref());
+                #[cfg(feature = "trace")]
+                self.trace_func_enter(func_body);
                 Ok(CallOutcome::Continue)
             }
             FuncEntity::Host(_host_func) => {
@@ -639,21 +1619,73 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     /// the function call so that the stack and execution state is synchronized
     /// with the outer structures.
     #[inline(always)]
-    fn call_func_internal(&mut self, func: CompiledFunc, kind: CallKind) -> Result<(), TrapCode> {
+    fn call_func_internal(
+        &mut self,
+        func: CompiledFunc,
+        kind: CallKind,
+    ) -> Result<CallOutcome, TrapCode> {
+        #[cfg(feature = "trace")]
+        if matches!(kind, CallKind::Tail) {
+            self.trace_func_exit();
+        }
         self.next_instr_at(match kind {
             CallKind::Nested => 1,
             CallKind::Tail => 2,
         });
         self.sync_stack_ptr();
         if matches!(kind, CallKind::Nested) {
-            self.call_stack
-                .push(FuncFrame::new(self.ip, self.cache.instance()))?;
+            self.push_caller_frame()?;
         }
         let header = self.code_map.header(func);
-        self.value_stack.prepare_wasm_call(header)?;
+        if header.is_uninit() {
+            return Ok(CallOutcome::Translate(func));
+        }
+        self.value_stack
+            .prepare_wasm_call(header)
+            .map_err(|trap_code| Self::pop_caller_frame(self.call_stack, kind, trap_code))?;
         self.sp = self.value_stack.stack_ptr();
+        self.base = self.value_stack.len();
         self.ip = self.code_map.instr_ptr(header.iref());
-        Ok(())
+        #[cfg(feature = "trace")]
+        self.trace_func_enter(func);
+        Ok(CallOutcome::Continue)
+    }
+
+    /// Pushes the frame of the current function onto the call stack to resume it later.
+    #[inline(always)]
+    fn push_caller_frame(&mut self) -> Result<(), TrapCode> {
+        self.call_stack
+            .push(FuncFrame::new(self.ip, self.cache.instance(), self.base))
+    }
+
+    /// Pops the frame of the current function pushed by a nested call that trapped.
+    ///
+    /// # Note
+    ///
+    /// The frame of the current function is pushed again by [`Executor::push_trapping_frame`].
+    #[cold]
+    fn pop_caller_frame(
+        call_stack: &mut CallStack,
+        kind: CallKind,
+        trap_code: TrapCode,
+    ) -> TrapCode {
+        if matches!(kind, CallKind::Nested) {
+            call_stack.pop();
+        }
+        trap_code
+    }
+
+    /// Pushes the frame of the current function onto the call stack after it trapped.
+    ///
+    /// # Note
+    ///
+    /// This allows to capture a backtrace of all Wasm functions on the call stack
+    /// including the trapping function.
+    #[cold]
+    fn push_trapping_frame(&mut self, trap_code: TrapCode) -> TrapCode {
+        self.call_stack
+            .push_callee(FuncFrame::new(self.ip, self.cache.instance(), self.base));
+        trap_code
     }
 
     /// Returns to the caller.
@@ -662,11 +1694,14 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     /// and synchronizes the execution state with the outer structures.
     #[inline(always)]
     fn ret(&mut self, drop_keep: DropKeep) -> ReturnOutcome {
+        #[cfg(feature = "trace")]
+        self.trace_func_exit();
         self.sp.drop_keep(drop_keep);
         self.sync_stack_ptr();
         match self.call_stack.pop() {
             Some(caller) => {
                 self.ip = caller.ip();
+                self.base = caller.base();
                 self.cache.update_instance(caller.instance());
                 ReturnOutcome::Wasm
             }
@@ -674,6 +1709,70 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         }
     }
 
+    /// Throws the `exception` at the instruction at `ip` of the current function.
+    ///
+    /// Unwinds the call stack until an exception handler catches the `exception`
+    /// and continues execution at the exception handler.
+    ///
+    /// Returns the `exception` back if no Wasm exception handler caught it.
+    #[cold]
+    fn throw(&mut self, exception: Exception, mut ip: InstructionPtr) -> Option<Exception> {
+        loop {
+            let ctx = &*self.ctx;
+            let instance = self.cache.instance();
+            let handler = self.code_map.find_handler(ip, |tag| {
+                ctx.resolve_instance(instance)
+                    .get_tag(tag.to_u32())
+                    .map(|tag| tag.same(&exception.tag()))
+                    .unwrap_or_else(|| panic!("missing tag at index {tag:?}"))
+            });
+            if let Some(handler) = handler {
+                self.enter_handler(handler, exception);
+                return None;
+            }
+            let caller = match self.call_stack.pop() {
+                Some(caller) => caller,
+                None => return Some(exception),
+            };
+            // Note: The instruction pointer of the caller points to the
+            //       instruction after its call instruction.
+            ip = caller.ip();
+            ip.offset(-1);
+            self.base = caller.base();
+            self.cache.update_instance(caller.instance());
+        }
+    }
+
+    /// Throws the `exception` thrown by the host function called by the current function.
+    ///
+    /// Returns the `exception` back if no Wasm exception handler caught it.
+    #[cold]
+    fn throw_from_host(&mut self, exception: Exception) -> Option<Exception> {
+        let mut ip = self.ip;
+        // Note: The instruction pointer points to the instruction after the call.
+        ip.offset(-1);
+        self.throw(exception, ip)
+    }
+
+    /// Enters the exception `handler` that caught `exception`.
+    ///
+    /// This unwinds the value stack of the current function and pushes a hidden value
+    /// referring to the caught `exception` followed by its values unless the `handler`
+    /// is a `catch_all` handler.
+    fn enter_handler(&mut self, handler: HandlerEntry, exception: Exception) {
+        let slot = self.base + handler.height();
+        self.sync_stack_ptr();
+        self.value_stack.shrink_to(slot);
+        self.sp = self.value_stack.stack_ptr();
+        let values = (!handler.is_catch_all()).then(|| exception.values().to_vec());
+        let index = self.exceptions.push(slot, exception);
+        self.sp.push_as(index);
+        for value in values.into_iter().flatten() {
+            self.sp.push(UntypedValue::from(value));
+        }
+        self.ip = handler.ip();
+    }
+
     /// Consume an amount of fuel specified by `delta` if `exec` succeeds.
     ///
     /// # Note
@@ -823,6 +1922,114 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     }
 }
 
+#[cfg(feature = "trace")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    /// Notifies the [`Tracer`] about the [`Instruction`] that is about to be executed.
+    #[inline(always)]
+    fn trace_instr(&mut self) {
+        if let Some(tracer) = self.ctx.tracer_mut() {
+            tracer.instr(self.ip.get(), self.value_stack.values_until(self.sp));
+        }
+    }
+
+    /// Notifies the [`Tracer`] that the execution entered the compiled function `func`.
+    fn trace_func_enter(&mut self, func: CompiledFunc) {
+        trace_func(
+            self.ctx,
+            self.cache.instance(),
+            func,
+            <dyn Tracer>::func_enter,
+        )
+    }
+
+    /// Notifies the [`Tracer`] that the execution returns from the current function.
+    fn trace_func_exit(&mut self) {
+        if self.ctx.tracer_mut().is_none() {
+            return;
+        }
+        let ip = self.code_map.instr_ref(self.ip);
+        if let Some((func, _)) = self.code_map.func_at(ip) {
+            trace_func(
+                self.ctx,
+                self.cache.instance(),
+                func,
+                <dyn Tracer>::func_exit,
+            )
+        }
+    }
+
+    /// Notifies the [`Tracer`] about the load of the value on top of the stack.
+    ///
+    /// The `address` and `offset` are the operands of the executed load instruction.
+    fn trace_memory_load(&mut self, memarg: MemArg, address: UntypedValue, offset: u64) {
+        let value = self.sp.last();
+        if let Some(tracer) = self.ctx.tracer_mut() {
+            tracer.memory_load(memory_access(memarg, address, offset, value))
+        }
+    }
+
+    /// Notifies the [`Tracer`] about the store of `value`.
+    ///
+    /// The `address` and `offset` are the operands of the executed store instruction.
+    fn trace_memory_store(
+        &mut self,
+        memarg: MemArg,
+        address: UntypedValue,
+        offset: u64,
+        value: UntypedValue,
+    ) {
+        if let Some(tracer) = self.ctx.tracer_mut() {
+            tracer.memory_store(memory_access(memarg, address, offset, value))
+        }
+    }
+
+    /// Notifies the [`Tracer`] about the read-modify-write of the value on top of the stack.
+    ///
+    /// The `address`, `offset` and `operand` are the operands of the executed instruction.
+    fn trace_memory_rmw(
+        &mut self,
+        memarg: MemArg,
+        address: UntypedValue,
+        offset: u64,
+        operand: UntypedValue,
+    ) {
+        let value = self.sp.last();
+        if let Some(tracer) = self.ctx.tracer_mut() {
+            tracer.memory_rmw(memory_access(memarg, address, offset, value), operand)
+        }
+    }
+
+    /// Notifies the [`Tracer`] about the compare-exchange of the value on top of the stack.
+    ///
+    /// The `address`, `offset`, `expected` and `replacement` are the operands of the executed instruction.
+    fn trace_memory_cmpxchg(
+        &mut self,
+        memarg: MemArg,
+        address: UntypedValue,
+        offset: u64,
+        expected: UntypedValue,
+        replacement: UntypedValue,
+    ) {
+        let value = self.sp.last();
+        if let Some(tracer) = self.ctx.tracer_mut() {
+            let access = memory_access(memarg, address, offset, value);
+            tracer.memory_cmpxchg(access, expected, replacement)
+        }
+    }
+}
+
+/// Returns the [`MemoryAccess`] of the memory instruction with the given operands.
+#[cfg(feature = "trace")]
+fn memory_access(
+    memarg: MemArg,
+    address: UntypedValue,
+    offset: u64,
+    value: UntypedValue,
+) -> MemoryAccess {
+    let address = u64::from(address).saturating_add(offset);
+    MemoryAccess::new(memarg.memory().to_u32(), address, value)
+}
+
 impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     #[inline(always)]
     fn visit_unreachable(&mut self) -> Result<(), TrapCode> {
@@ -838,6 +2045,56 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         self.try_next_instr()
     }
 
+    #[cold]
+    fn visit_throw(&mut self, tag: TagIdx) -> Option<Exception> {
+        let tag = self
+            .ctx
+            .resolve_instance(self.cache.instance())
+            .get_tag(tag.to_u32())
+            .unwrap_or_else(|| panic!("missing tag at index {tag:?}"));
+        let params = self.ctx.resolve_tag(&tag).ty().params();
+        let len_params = params.len();
+        let values: Box<[Value]> = params
+            .iter()
+            .enumerate()
+            .map(|(n, ty)| self.sp.nth_back(len_params - n).with_type(*ty))
+            .collect();
+        self.throw(Exception::new_unchecked(tag, values), self.ip)
+    }
+
+    #[cold]
+    fn visit_rethrow(&mut self, local_depth: LocalDepth) -> Option<Exception> {
+        let index = u32::from(self.sp.nth_back(local_depth.to_usize()));
+        let exception = self.exceptions.get(index).clone();
+        self.throw(exception, self.ip)
+    }
+
+    #[inline(always)]
+    fn visit_check_epoch(&mut self) -> Result<EpochOutcome, TrapCode> {
+        // We do not have to check if epoch interruption is enabled since
+        // these `wasmi` instructions are only generated if epoch interruption
+        // is enabled to begin with.
+        let current = self.ctx.engine().current_epoch();
+        let epoch_deadline = self.ctx.epoch_deadline();
+        if current < epoch_deadline.deadline() {
+            self.next_instr();
+            return Ok(EpochOutcome::Continue);
+        }
+        match epoch_deadline.behavior() {
+            EpochDeadlineBehavior::Trap => Err(TrapCode::EpochDeadlineExceeded),
+            EpochDeadlineBehavior::YieldAndUpdate(delta) => {
+                self.ctx.epoch_deadline_mut().set_deadline(current, delta);
+                // We push the current function frame back onto the call stack
+                // so that the execution can be resumed right after this instruction.
+                self.next_instr();
+                self.sync_stack_ptr();
+                self.push_caller_frame()?;
+                self.cache.reset();
+                Ok(EpochOutcome::Yield)
+            }
+        }
+    }
+
     /// Fetches the [`DropKeep`] parameter for an instruction.
     ///
     /// # Note
@@ -876,6 +2133,25 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         }
     }
 
+    /// Fetches the [`MemoryIdx`] parameter for an instruction.
+    ///
+    /// # Note
+    ///
+    /// - This is done by encoding an [`Instruction::MemorySize`] instruction
+    ///   word following the actual instruction where the [`MemoryIdx`]
+    ///   paremeter belongs to.
+    /// - This is required for some instructions that do not fit into
+    ///   a single instruction word and store a [`MemoryIdx`] value in
+    ///   another instruction word.
+    fn fetch_memory_idx(&self, offset: usize) -> MemoryIdx {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(offset);
+        match addr.get() {
+            Instruction::MemorySize(memory_idx) => *memory_idx,
+            _ => unreachable!("expected MemorySize instruction word at this point"),
+        }
+    }
+
     #[inline(always)]
     fn visit_br(&mut self, offset: BranchOffset) {
         self.branch_to(offset)
@@ -981,7 +2257,10 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     }
 
     #[inline(always)]
-    fn visit_return_call_internal(&mut self, compiled_func: CompiledFunc) -> Result<(), TrapCode> {
+    fn visit_return_call_internal(
+        &mut self,
+        compiled_func: CompiledFunc,
+    ) -> Result<CallOutcome, TrapCode> {
         let drop_keep = self.fetch_drop_keep(1);
         self.sp.drop_keep(drop_keep);
         self.call_func_internal(compiled_func, CallKind::Tail)
@@ -1008,7 +2287,10 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     }
 
     #[inline(always)]
-    fn visit_call_internal(&mut self, compiled_func: CompiledFunc) -> Result<(), TrapCode> {
+    fn visit_call_internal(
+        &mut self,
+        compiled_func: CompiledFunc,
+    ) -> Result<CallOutcome, TrapCode> {
         self.call_func_internal(compiled_func, CallKind::Nested)
     }
 
@@ -1069,21 +2351,60 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     }
 
     #[inline(always)]
-    fn visit_memory_size(&mut self) {
-        let memory = self.cache.default_memory(self.ctx);
-        let result: u32 = self.ctx.resolve_memory(memory).current_pages().into();
+    fn visit_atomic_fence(&mut self) {
+        atomic::fence(atomic::Ordering::SeqCst);
+        self.next_instr()
+    }
+
+    #[inline(always)]
+    fn visit_memory_atomic_notify(&mut self, memarg: MemArg) -> Result<(), TrapCode> {
+        let offset = self.resolve_offset(memarg);
+        let (address, count) = self.sp.pop2();
+        let memory = self.cache.get_memory(self.ctx, memarg.memory());
+        let entity = self.ctx.resolve_memory(&memory);
+        let address = UntypedValue::atomic_address::<4>(entity.data_size(), address, offset)?;
+        // There are never waiters on non-shared linear memories.
+        let woken = entity
+            .shared()
+            .map(|shared| shared.atomic_notify(address, u32::from(count)))
+            .unwrap_or(0);
+        self.sp.push_as(woken);
+        self.try_next_instr()
+    }
+
+    #[inline(always)]
+    fn visit_memory_atomic_wait32(&mut self, memarg: MemArg) -> Result<(), TrapCode> {
+        self.execute_atomic_wait::<4>(memarg, UntypedValue::atomic_load32)
+    }
+
+    #[inline(always)]
+    fn visit_memory_atomic_wait64(&mut self, memarg: MemArg) -> Result<(), TrapCode> {
+        self.execute_atomic_wait::<8>(memarg, UntypedValue::atomic_load64)
+    }
+
+    #[inline(always)]
+    fn visit_memory_size(&mut self, memory_index: MemoryIdx) {
+        let memory = self.cache.get_memory(self.ctx, memory_index);
+        // Note: 32-bit linear memories never have more than `u32::MAX` pages
+        //       and thus `i32` and `i64` results share the same representation.
+        let result: u64 = self.ctx.resolve_memory(&memory).current_pages().into();
         self.sp.push_as(result);
         self.next_instr()
     }
 
     #[inline(always)]
-    fn visit_memory_grow(&mut self) -> Result<(), TrapCode> {
-        let delta: u32 = self.sp.pop_as();
-        let delta = match Pages::new(delta) {
+    fn visit_memory_grow(&mut self, memory_index: MemoryIdx) -> Result<(), TrapCode> {
+        let memory = self.cache.get_memory(self.ctx, memory_index);
+        let error_code = match self.ctx.resolve_memory(&memory).ty().is_64() {
+            true => INVALID_GROWTH_ERRCODE_64,
+            false => u64::from(INVALID_GROWTH_ERRCODE),
+        };
+        let delta: u64 = self.sp.pop_as();
+        let delta = match Pages::new64(delta) {
             Some(pages) => pages,
             None => {
                 // Cannot grow memory so we push the expected error value.
-                self.sp.push_as(INVALID_GROWTH_ERRCODE);
+                self.sp.push_as(error_code);
                 return self.try_next_instr();
             }
         };
@@ -1093,13 +2414,17 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 costs.fuel_for_bytes(delta_in_bytes)
             },
             |this| {
-                let memory = this.cache.default_memory(this.ctx);
                 let new_pages = this
                     .ctx
-                    .resolve_memory_mut(memory)
-                    .grow(delta)
-                    .map(u32::from)
-                    .map_err(|_| EntityGrowError::InvalidGrow)?;
+                    .resolve_memory_mut(&memory)
+                    .grow(delta, this.resource_limiter)
+                    .map(u64::from)
+                    .map_err(|error| match error {
+                        MemoryError::OutOfBoundsAllocation | MemoryError::OutOfBoundsGrowth => {
+                            EntityGrowError::InvalidGrow
+                        }
+                        _ => EntityGrowError::TrapCode(TrapCode::GrowthOperationLimited),
+                    })?;
                 // The `memory.grow` operation might have invalidated the cached
                 // linear memory so we need to reset it in order for the cache to
                 // reload in case it is used again.
@@ -1109,7 +2434,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         );
         let result = match result {
             Ok(result) => result,
-            Err(EntityGrowError::InvalidGrow) => INVALID_GROWTH_ERRCODE,
+            Err(EntityGrowError::InvalidGrow) => error_code,
             Err(EntityGrowError::TrapCode(trap_code)) => return Err(trap_code),
         };
         self.sp.push_as(result);
@@ -1117,79 +2442,112 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     }
 
     #[inline(always)]
-    fn visit_memory_fill(&mut self) -> Result<(), TrapCode> {
+    fn visit_memory_fill(&mut self, memory_index: MemoryIdx) -> Result<(), TrapCode> {
         // The `n`, `val` and `d` variable bindings are extracted from the Wasm specification.
         let (d, val, n) = self.sp.pop3();
-        let n = i32::from(n) as usize;
-        let offset = i32::from(d) as usize;
+        let n = memory_operand(n);
+        let offset = memory_operand(d);
         let byte = u8::from(val);
         self.consume_fuel_with(
             |costs| costs.fuel_for_bytes(n as u64),
             |this| {
-                let memory = this
-                    .cache
-                    .default_memory_bytes(this.ctx)
-                    .get_mut(offset..)
-                    .and_then(|memory| memory.get_mut(..n))
-                    .ok_or(TrapCode::MemoryOutOfBounds)?;
-                memory.fill(byte);
+                let memory = this.cache.memory_bytes(this.ctx, memory_index);
+                let dst = memory_range(memory, offset, n)?;
+                // Safety: `dst` points to `n` bytes of `memory` which is valid for writes.
+                unsafe { ptr::write_bytes(dst, byte, n) };
                 Ok(())
             },
         )?;
+        #[cfg(feature = "trace")]
+        if let Some(tracer) = self.ctx.tracer_mut() {
+            tracer.memory_fill(memory_index.to_u32(), offset as u64, byte, n as u64);
+        }
         self.try_next_instr()
     }
 
     #[inline(always)]
-    fn visit_memory_copy(&mut self) -> Result<(), TrapCode> {
+    fn visit_memory_copy(&mut self, dst: MemoryIdx) -> Result<(), TrapCode> {
+        let src = self.fetch_memory_idx(1);
         // The `n`, `s` and `d` variable bindings are extracted from the Wasm specification.
         let (d, s, n) = self.sp.pop3();
-        let n = i32::from(n) as usize;
-        let src_offset = i32::from(s) as usize;
-        let dst_offset = i32::from(d) as usize;
+        let n = memory_operand(n);
+        let src_offset = memory_operand(s);
+        let dst_offset = memory_operand(d);
         self.consume_fuel_with(
             |costs| costs.fuel_for_bytes(n as u64),
             |this| {
-                let data = this.cache.default_memory_bytes(this.ctx);
-                // These accesses just perform the bounds checks required by the Wasm spec.
-                data.get(src_offset..)
-                    .and_then(|memory| memory.get(..n))
-                    .ok_or(TrapCode::MemoryOutOfBounds)?;
-                data.get(dst_offset..)
-                    .and_then(|memory| memory.get(..n))
-                    .ok_or(TrapCode::MemoryOutOfBounds)?;
-                data.copy_within(src_offset..src_offset.wrapping_add(n), dst_offset);
+                let dst = this.cache.get_memory(this.ctx, dst);
+                let src = this.cache.get_memory(this.ctx, src);
+                let (dst, src) = match Memory::eq(&dst, &src) {
+                    true => {
+                        // Copy within the same linear memory:
+                        let data = this.ctx.resolve_memory_mut(&dst).raw_data();
+                        (data, data)
+                    }
+                    false => {
+                        // Copy from one linear memory to another linear memory:
+                        let (dst, src) = this.ctx.resolve_memory_pair_mut(&dst, &src);
+                        (dst.raw_data(), src.raw_data())
+                    }
+                };
+                let src = memory_range(src, src_offset, n)?;
+                let dst = memory_range(dst, dst_offset, n)?;
+                // Safety: `src` and `dst` point to `n` bytes of valid linear memories.
+                //         The ranges might overlap, e.g. for copies within the same
+                //         linear memory or between handles to the same shared memory.
+                unsafe { ptr::copy(src, dst, n) };
                 Ok(())
             },
         )?;
-        self.try_next_instr()
+        #[cfg(feature = "trace")]
+        if let Some(tracer) = self.ctx.tracer_mut() {
+            tracer.memory_copy(
+                dst.to_u32(),
+                dst_offset as u64,
+                src.to_u32(),
+                src_offset as u64,
+                n as u64,
+            );
+        }
+        self.try_next_instr_at(2)
     }
 
     #[inline(always)]
     fn visit_memory_init(&mut self, segment: DataSegmentIdx) -> Result<(), TrapCode> {
+        let memory_index = self.fetch_memory_idx(1);
         // The `n`, `s` and `d` variable bindings are extracted from the Wasm specification.
         let (d, s, n) = self.sp.pop3();
-        let n = i32::from(n) as usize;
-        let src_offset = i32::from(s) as usize;
-        let dst_offset = i32::from(d) as usize;
+        let n = memory_operand(n);
+        let src_offset = memory_operand(s);
+        let dst_offset = memory_operand(d);
         self.consume_fuel_with(
             |costs| costs.fuel_for_bytes(n as u64),
             |this| {
-                let (memory, data) = this
-                    .cache
-                    .get_default_memory_and_data_segment(this.ctx, segment);
-                let memory = memory
-                    .get_mut(dst_offset..)
-                    .and_then(|memory| memory.get_mut(..n))
-                    .ok_or(TrapCode::MemoryOutOfBounds)?;
+                let (memory, data) =
+                    this.cache
+                        .get_memory_and_data_segment(this.ctx, memory_index, segment);
+                let dst = memory_range(memory, dst_offset, n)?;
                 let data = data
                     .get(src_offset..)
                     .and_then(|data| data.get(..n))
                     .ok_or(TrapCode::MemoryOutOfBounds)?;
-                memory.copy_from_slice(data);
+                // Safety: `dst` points to `n` bytes of `memory` which is valid for writes
+                //         and cannot overlap with the bytes of the data segment.
+                unsafe { ptr::copy_nonoverlapping(data.as_ptr(), dst, n) };
                 Ok(())
             },
         )?;
-        self.try_next_instr()
+        #[cfg(feature = "trace")]
+        if let Some(tracer) = self.ctx.tracer_mut() {
+            tracer.memory_init(
+                memory_index.to_u32(),
+                dst_offset as u64,
+                segment.to_u32(),
+                src_offset as u64,
+                n as u64,
+            );
+        }
+        self.try_next_instr_at(2)
     }
 
     #[inline(always)]
@@ -1217,10 +2575,13 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
             |costs| costs.fuel_for_elements(u64::from(delta)),
             |this| {
                 let table = this.cache.get_table(this.ctx, table_index);
-                this.ctx
-                    .resolve_table_mut(&table)
-                    .grow_untyped(delta, init)
-                    .map_err(|_| EntityGrowError::InvalidGrow)
+                let (table, func_refs) = this.ctx.resolve_table_and_func_refs_mut(&table);
+                table
+                    .grow_untyped(delta, init, this.resource_limiter, func_refs)
+                    .map_err(|error| match error {
+                        TableError::GrowOutOfBounds { .. } => EntityGrowError::InvalidGrow,
+                        _ => EntityGrowError::TrapCode(TrapCode::GrowthOperationLimited),
+                    })
             },
         );
         let result = match result {
@@ -1242,9 +2603,8 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
             |costs| costs.fuel_for_elements(u64::from(len)),
             |this| {
                 let table = this.cache.get_table(this.ctx, table_index);
-                this.ctx
-                    .resolve_table_mut(&table)
-                    .fill_untyped(dst, val, len)?;
+                let (table, func_refs) = this.ctx.resolve_table_and_func_refs_mut(&table);
+                table.fill_untyped(dst, val, len, func_refs)?;
                 Ok(())
             },
         )?;
@@ -1269,9 +2629,9 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         let (index, value) = self.sp.pop2();
         let index: u32 = index.into();
         let table = self.cache.get_table(self.ctx, table_index);
-        self.ctx
-            .resolve_table_mut(&table)
-            .set_untyped(index, value)
+        let (table, func_refs) = self.ctx.resolve_table_and_func_refs_mut(&table);
+        table
+            .set_untyped(index, value, func_refs)
             .map_err(|_| TrapCode::TableOutOfBounds)?;
         self.try_next_instr()
     }
@@ -1292,12 +2652,13 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 let src = this.cache.get_table(this.ctx, src);
                 if Table::eq(&dst, &src) {
                     // Copy within the same table:
-                    let table = this.ctx.resolve_table_mut(&dst);
-                    table.copy_within(dst_index, src_index, len)?;
+                    let (table, func_refs) = this.ctx.resolve_table_and_func_refs_mut(&dst);
+                    table.copy_within(dst_index, src_index, len, func_refs)?;
                 } else {
                     // Copy from one table to another table:
-                    let (dst, src) = this.ctx.resolve_table_pair_mut(&dst, &src);
-                    TableEntity::copy(dst, dst_index, src, src_index, len)?;
+                    let (dst, src, func_refs) =
+                        this.ctx.resolve_table_pair_and_func_refs_mut(&dst, &src);
+                    TableEntity::copy(dst, dst_index, src, src_index, len, func_refs)?;
                 }
                 Ok(())
             },
@@ -1316,14 +2677,21 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         self.consume_fuel_with(
             |costs| costs.fuel_for_elements(u64::from(len)),
             |this| {
-                let (instance, table, element) = this
+                let (instance, table, element, func_refs) = this
                     .cache
                     .get_table_and_element_segment(this.ctx, table, elem);
-                table.init(dst_index, element, src_index, len, |func_index| {
-                    instance
-                        .get_func(func_index)
-                        .unwrap_or_else(|| panic!("missing function at index {func_index}"))
-                })?;
+                table.init(
+                    dst_index,
+                    element,
+                    src_index,
+                    len,
+                    |func_index| {
+                        instance
+                            .get_func(func_index)
+                            .unwrap_or_else(|| panic!("missing function at index {func_index}"))
+                    },
+                    func_refs,
+                )?;
                 Ok(())
             },
         )?;
@@ -1352,9 +2720,9 @@ macro_rules! impl_visit_load {
             #[inline(always)]
             fn $visit_ident(
                 &mut self,
-                offset: AddressOffset,
+                memarg: MemArg,
             ) -> Result<(), TrapCode> {
-                self.execute_load_extend(offset, UntypedValue::$untyped_ident)
+                self.execute_load_extend(memarg, UntypedValue::$untyped_ident)
             }
         )*
     }
@@ -1386,9 +2754,9 @@ macro_rules! impl_visit_store {
             #[inline(always)]
             fn $visit_ident(
                 &mut self,
-                offset: AddressOffset,
+                memarg: MemArg,
             ) -> Result<(), TrapCode> {
-                self.execute_store_wrap(offset, UntypedValue::$untyped_ident)
+                self.execute_store_wrap(memarg, UntypedValue::$untyped_ident)
             }
         )*
     }
@@ -1409,6 +2777,141 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     }
 }
 
+macro_rules! impl_visit_atomic_load {
+    ( $( fn $visit_ident:ident($untyped_ident:ident); )* ) => {
+        $(
+            #[inline(always)]
+            fn $visit_ident(
+                &mut self,
+                memarg: MemArg,
+            ) -> Result<(), TrapCode> {
+                self.execute_atomic_load(memarg, UntypedValue::$untyped_ident)
+            }
+        )*
+    }
+}
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_atomic_load! {
+        fn visit_i32_atomic_load(atomic_load32);
+        fn visit_i64_atomic_load(atomic_load64);
+        fn visit_i32_atomic_load8_u(atomic_load8);
+        fn visit_i32_atomic_load16_u(atomic_load16);
+        fn visit_i64_atomic_load8_u(atomic_load8);
+        fn visit_i64_atomic_load16_u(atomic_load16);
+        fn visit_i64_atomic_load32_u(atomic_load32);
+    }
+}
+
+macro_rules! impl_visit_atomic_store {
+    ( $( fn $visit_ident:ident($untyped_ident:ident); )* ) => {
+        $(
+            #[inline(always)]
+            fn $visit_ident(
+                &mut self,
+                memarg: MemArg,
+            ) -> Result<(), TrapCode> {
+                self.execute_atomic_store(memarg, UntypedValue::$untyped_ident)
+            }
+        )*
+    }
+}
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_atomic_store! {
+        fn visit_i32_atomic_store(atomic_store32);
+        fn visit_i64_atomic_store(atomic_store64);
+        fn visit_i32_atomic_store8(atomic_store8);
+        fn visit_i32_atomic_store16(atomic_store16);
+        fn visit_i64_atomic_store8(atomic_store8);
+        fn visit_i64_atomic_store16(atomic_store16);
+        fn visit_i64_atomic_store32(atomic_store32);
+    }
+}
+
+macro_rules! impl_visit_atomic_rmw {
+    ( $( fn $visit_ident:ident($untyped_ident:ident, $op:ident); )* ) => {
+        $(
+            #[inline(always)]
+            fn $visit_ident(
+                &mut self,
+                memarg: MemArg,
+            ) -> Result<(), TrapCode> {
+                self.execute_atomic_rmw(memarg, AtomicRmwOp::$op, UntypedValue::$untyped_ident)
+            }
+        )*
+    }
+}
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_atomic_rmw! {
+        fn visit_i32_atomic_rmw_add(atomic_rmw32, Add);
+        fn visit_i64_atomic_rmw_add(atomic_rmw64, Add);
+        fn visit_i32_atomic_rmw8_add_u(atomic_rmw8, Add);
+        fn visit_i32_atomic_rmw16_add_u(atomic_rmw16, Add);
+        fn visit_i64_atomic_rmw8_add_u(atomic_rmw8, Add);
+        fn visit_i64_atomic_rmw16_add_u(atomic_rmw16, Add);
+        fn visit_i64_atomic_rmw32_add_u(atomic_rmw32, Add);
+        fn visit_i32_atomic_rmw_sub(atomic_rmw32, Sub);
+        fn visit_i64_atomic_rmw_sub(atomic_rmw64, Sub);
+        fn visit_i32_atomic_rmw8_sub_u(atomic_rmw8, Sub);
+        fn visit_i32_atomic_rmw16_sub_u(atomic_rmw16, Sub);
+        fn visit_i64_atomic_rmw8_sub_u(atomic_rmw8, Sub);
+        fn visit_i64_atomic_rmw16_sub_u(atomic_rmw16, Sub);
+        fn visit_i64_atomic_rmw32_sub_u(atomic_rmw32, Sub);
+        fn visit_i32_atomic_rmw_and(atomic_rmw32, And);
+        fn visit_i64_atomic_rmw_and(atomic_rmw64, And);
+        fn visit_i32_atomic_rmw8_and_u(atomic_rmw8, And);
+        fn visit_i32_atomic_rmw16_and_u(atomic_rmw16, And);
+        fn visit_i64_atomic_rmw8_and_u(atomic_rmw8, And);
+        fn visit_i64_atomic_rmw16_and_u(atomic_rmw16, And);
+        fn visit_i64_atomic_rmw32_and_u(atomic_rmw32, And);
+        fn visit_i32_atomic_rmw_or(atomic_rmw32, Or);
+        fn visit_i64_atomic_rmw_or(atomic_rmw64, Or);
+        fn visit_i32_atomic_rmw8_or_u(atomic_rmw8, Or);
+        fn visit_i32_atomic_rmw16_or_u(atomic_rmw16, Or);
+        fn visit_i64_atomic_rmw8_or_u(atomic_rmw8, Or);
+        fn visit_i64_atomic_rmw16_or_u(atomic_rmw16, Or);
+        fn visit_i64_atomic_rmw32_or_u(atomic_rmw32, Or);
+        fn visit_i32_atomic_rmw_xor(atomic_rmw32, Xor);
+        fn visit_i64_atomic_rmw_xor(atomic_rmw64, Xor);
+        fn visit_i32_atomic_rmw8_xor_u(atomic_rmw8, Xor);
+        fn visit_i32_atomic_rmw16_xor_u(atomic_rmw16, Xor);
+        fn visit_i64_atomic_rmw8_xor_u(atomic_rmw8, Xor);
+        fn visit_i64_atomic_rmw16_xor_u(atomic_rmw16, Xor);
+        fn visit_i64_atomic_rmw32_xor_u(atomic_rmw32, Xor);
+        fn visit_i32_atomic_rmw_xchg(atomic_rmw32, Xchg);
+        fn visit_i64_atomic_rmw_xchg(atomic_rmw64, Xchg);
+        fn visit_i32_atomic_rmw8_xchg_u(atomic_rmw8, Xchg);
+        fn visit_i32_atomic_rmw16_xchg_u(atomic_rmw16, Xchg);
+        fn visit_i64_atomic_rmw8_xchg_u(atomic_rmw8, Xchg);
+        fn visit_i64_atomic_rmw16_xchg_u(atomic_rmw16, Xchg);
+        fn visit_i64_atomic_rmw32_xchg_u(atomic_rmw32, Xchg);
+    }
+}
+
+macro_rules! impl_visit_atomic_cmpxchg {
+    ( $( fn $visit_ident:ident($untyped_ident:ident); )* ) => {
+        $(
+            #[inline(always)]
+            fn $visit_ident(
+                &mut self,
+                memarg: MemArg,
+            ) -> Result<(), TrapCode> {
+                self.execute_atomic_cmpxchg(memarg, UntypedValue::$untyped_ident)
+            }
+        )*
+    }
+}
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_atomic_cmpxchg! {
+        fn visit_i32_atomic_rmw_cmpxchg(atomic_cmpxchg32);
+        fn visit_i64_atomic_rmw_cmpxchg(atomic_cmpxchg64);
+        fn visit_i32_atomic_rmw8_cmpxchg_u(atomic_cmpxchg8);
+        fn visit_i32_atomic_rmw16_cmpxchg_u(atomic_cmpxchg16);
+        fn visit_i64_atomic_rmw8_cmpxchg_u(atomic_cmpxchg8);
+        fn visit_i64_atomic_rmw16_cmpxchg_u(atomic_cmpxchg16);
+        fn visit_i64_atomic_rmw32_cmpxchg_u(atomic_cmpxchg32);
+    }
+}
+
 macro_rules! impl_visit_unary {
     ( $( fn $visit_ident:ident($untyped_ident:ident); )* ) => {
         $(
@@ -1617,3 +3120,419 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         fn visit_i64_rem_u(i64_rem_u);
     }
 }
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    /// Executes a generic Wasm `v128.loadN_lane` operation.
+    ///
+    /// # Note
+    ///
+    /// The [`LaneIdx`] is stored in the instruction word following the load instruction.
+    #[inline(always)]
+    fn execute_load_lane(
+        &mut self,
+        memarg: MemArg,
+        load_lane: WasmLoadLaneOp,
+    ) -> Result<(), TrapCode> {
+        let lane = self.fetch_lane_idx(1);
+        let offset = self.resolve_offset(memarg);
+        self.sp.try_eval_top2(|address, value| {
+            let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+            // Safety: `memory` is valid for reads and writes.
+            unsafe { load_lane(memory, address, offset, value, lane.into_inner()) }
+        })?;
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes a generic Wasm `v128.storeN_lane` operation.
+    ///
+    /// # Note
+    ///
+    /// The [`LaneIdx`] is stored in the instruction word following the store instruction.
+    #[inline(always)]
+    fn execute_store_lane(
+        &mut self,
+        memarg: MemArg,
+        store_lane: WasmStoreLaneOp,
+    ) -> Result<(), TrapCode> {
+        let lane = self.fetch_lane_idx(1);
+        let offset = self.resolve_offset(memarg);
+        let (address, value) = self.sp.pop2();
+        let memory = self.cache.memory_bytes(self.ctx, memarg.memory());
+        // Safety: `memory` is valid for reads and writes.
+        unsafe { store_lane(memory, address, offset, value, lane.into_inner()) }?;
+        self.try_next_instr_at(2)
+    }
+
+    /// Fetches the [`LaneIdx`] parameter for an instruction.
+    ///
+    /// # Note
+    ///
+    /// - This is done by encoding an [`Instruction::I8x16ExtractLaneS`] instruction
+    ///   word following the actual instruction where the [`LaneIdx`]
+    ///   paremeter belongs to.
+    /// - This is required for `v128` lane load and store instructions
+    ///   that do not fit into a single instruction word.
+    fn fetch_lane_idx(&self, offset: usize) -> LaneIdx {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(offset);
+        match addr.get() {
+            Instruction::I8x16ExtractLaneS(lane) => *lane,
+            _ => unreachable!("expected I8x16ExtractLaneS instruction word at this point"),
+        }
+    }
+
+    #[inline(always)]
+    fn visit_i8x16_shuffle(&mut self, selector: ConstRef) {
+        let selector = self
+            .const_pool
+            .get(selector)
+            .unwrap_or_else(|| unreachable!("missing constant value for const reference"));
+        self.sp
+            .eval_top2(|lhs, rhs| UntypedValue::i8x16_shuffle(lhs, rhs, selector));
+        self.next_instr()
+    }
+
+    #[inline(always)]
+    fn visit_v128_bitselect(&mut self) {
+        self.sp.eval_top3(UntypedValue::v128_bitselect);
+        self.next_instr()
+    }
+}
+
+#[cfg(feature = "simd")]
+macro_rules! impl_visit_load_lane {
+    ( $( fn $visit_ident:ident($untyped_ident:ident); )* ) => {
+        $(
+            #[inline(always)]
+            fn $visit_ident(
+                &mut self,
+                memarg: MemArg,
+            ) -> Result<(), TrapCode> {
+                self.execute_load_lane(memarg, UntypedValue::$untyped_ident)
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "simd")]
+macro_rules! impl_visit_store_lane {
+    ( $( fn $visit_ident:ident($untyped_ident:ident); )* ) => {
+        $(
+            #[inline(always)]
+            fn $visit_ident(
+                &mut self,
+                memarg: MemArg,
+            ) -> Result<(), TrapCode> {
+                self.execute_store_lane(memarg, UntypedValue::$untyped_ident)
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "simd")]
+macro_rules! impl_visit_extract_lane {
+    ( $( fn $visit_ident:ident($untyped_ident:ident); )* ) => {
+        $(
+            #[inline(always)]
+            fn $visit_ident(&mut self, lane: LaneIdx) {
+                self.sp.eval_top(|value| value.$untyped_ident(lane.into_inner()));
+                self.next_instr()
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "simd")]
+macro_rules! impl_visit_replace_lane {
+    ( $( fn $visit_ident:ident($untyped_ident:ident); )* ) => {
+        $(
+            #[inline(always)]
+            fn $visit_ident(&mut self, lane: LaneIdx) {
+                self.sp.eval_top2(|value, scalar| value.$untyped_ident(lane.into_inner(), scalar));
+                self.next_instr()
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_load! {
+        fn visit_v128_load(v128_load);
+        fn visit_v128_load8x8_s(v128_load8x8_s);
+        fn visit_v128_load8x8_u(v128_load8x8_u);
+        fn visit_v128_load16x4_s(v128_load16x4_s);
+        fn visit_v128_load16x4_u(v128_load16x4_u);
+        fn visit_v128_load32x2_s(v128_load32x2_s);
+        fn visit_v128_load32x2_u(v128_load32x2_u);
+        fn visit_v128_load8_splat(v128_load8_splat);
+        fn visit_v128_load16_splat(v128_load16_splat);
+        fn visit_v128_load32_splat(v128_load32_splat);
+        fn visit_v128_load64_splat(v128_load64_splat);
+        fn visit_v128_load32_zero(v128_load32_zero);
+        fn visit_v128_load64_zero(v128_load64_zero);
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_store! {
+        fn visit_v128_store(v128_store);
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_load_lane! {
+        fn visit_v128_load8_lane(v128_load8_lane);
+        fn visit_v128_load16_lane(v128_load16_lane);
+        fn visit_v128_load32_lane(v128_load32_lane);
+        fn visit_v128_load64_lane(v128_load64_lane);
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_store_lane! {
+        fn visit_v128_store8_lane(v128_store8_lane);
+        fn visit_v128_store16_lane(v128_store16_lane);
+        fn visit_v128_store32_lane(v128_store32_lane);
+        fn visit_v128_store64_lane(v128_store64_lane);
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_extract_lane! {
+        fn visit_i8x16_extract_lane_s(i8x16_extract_lane_s);
+        fn visit_i8x16_extract_lane_u(i8x16_extract_lane_u);
+        fn visit_i16x8_extract_lane_s(i16x8_extract_lane_s);
+        fn visit_i16x8_extract_lane_u(i16x8_extract_lane_u);
+        fn visit_i32x4_extract_lane(i32x4_extract_lane);
+        fn visit_i64x2_extract_lane(i64x2_extract_lane);
+        fn visit_f32x4_extract_lane(f32x4_extract_lane);
+        fn visit_f64x2_extract_lane(f64x2_extract_lane);
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_replace_lane! {
+        fn visit_i8x16_replace_lane(i8x16_replace_lane);
+        fn visit_i16x8_replace_lane(i16x8_replace_lane);
+        fn visit_i32x4_replace_lane(i32x4_replace_lane);
+        fn visit_i64x2_replace_lane(i64x2_replace_lane);
+        fn visit_f32x4_replace_lane(f32x4_replace_lane);
+        fn visit_f64x2_replace_lane(f64x2_replace_lane);
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_unary! {
+        fn visit_i8x16_splat(i8x16_splat);
+        fn visit_i16x8_splat(i16x8_splat);
+        fn visit_i32x4_splat(i32x4_splat);
+        fn visit_i64x2_splat(i64x2_splat);
+        fn visit_f32x4_splat(f32x4_splat);
+        fn visit_f64x2_splat(f64x2_splat);
+        fn visit_v128_not(v128_not);
+        fn visit_v128_any_true(v128_any_true);
+        fn visit_i8x16_abs(i8x16_abs);
+        fn visit_i8x16_neg(i8x16_neg);
+        fn visit_i8x16_popcnt(i8x16_popcnt);
+        fn visit_i8x16_all_true(i8x16_all_true);
+        fn visit_i8x16_bitmask(i8x16_bitmask);
+        fn visit_i16x8_extadd_pairwise_i8x16_s(i16x8_extadd_pairwise_i8x16_s);
+        fn visit_i16x8_extadd_pairwise_i8x16_u(i16x8_extadd_pairwise_i8x16_u);
+        fn visit_i16x8_abs(i16x8_abs);
+        fn visit_i16x8_neg(i16x8_neg);
+        fn visit_i16x8_all_true(i16x8_all_true);
+        fn visit_i16x8_bitmask(i16x8_bitmask);
+        fn visit_i16x8_extend_low_i8x16_s(i16x8_extend_low_i8x16_s);
+        fn visit_i16x8_extend_high_i8x16_s(i16x8_extend_high_i8x16_s);
+        fn visit_i16x8_extend_low_i8x16_u(i16x8_extend_low_i8x16_u);
+        fn visit_i16x8_extend_high_i8x16_u(i16x8_extend_high_i8x16_u);
+        fn visit_i32x4_extadd_pairwise_i16x8_s(i32x4_extadd_pairwise_i16x8_s);
+        fn visit_i32x4_extadd_pairwise_i16x8_u(i32x4_extadd_pairwise_i16x8_u);
+        fn visit_i32x4_abs(i32x4_abs);
+        fn visit_i32x4_neg(i32x4_neg);
+        fn visit_i32x4_all_true(i32x4_all_true);
+        fn visit_i32x4_bitmask(i32x4_bitmask);
+        fn visit_i32x4_extend_low_i16x8_s(i32x4_extend_low_i16x8_s);
+        fn visit_i32x4_extend_high_i16x8_s(i32x4_extend_high_i16x8_s);
+        fn visit_i32x4_extend_low_i16x8_u(i32x4_extend_low_i16x8_u);
+        fn visit_i32x4_extend_high_i16x8_u(i32x4_extend_high_i16x8_u);
+        fn visit_i64x2_abs(i64x2_abs);
+        fn visit_i64x2_neg(i64x2_neg);
+        fn visit_i64x2_all_true(i64x2_all_true);
+        fn visit_i64x2_bitmask(i64x2_bitmask);
+        fn visit_i64x2_extend_low_i32x4_s(i64x2_extend_low_i32x4_s);
+        fn visit_i64x2_extend_high_i32x4_s(i64x2_extend_high_i32x4_s);
+        fn visit_i64x2_extend_low_i32x4_u(i64x2_extend_low_i32x4_u);
+        fn visit_i64x2_extend_high_i32x4_u(i64x2_extend_high_i32x4_u);
+        fn visit_f32x4_ceil(f32x4_ceil);
+        fn visit_f32x4_floor(f32x4_floor);
+        fn visit_f32x4_trunc(f32x4_trunc);
+        fn visit_f32x4_nearest(f32x4_nearest);
+        fn visit_f32x4_abs(f32x4_abs);
+        fn visit_f32x4_neg(f32x4_neg);
+        fn visit_f32x4_sqrt(f32x4_sqrt);
+        fn visit_f64x2_ceil(f64x2_ceil);
+        fn visit_f64x2_floor(f64x2_floor);
+        fn visit_f64x2_trunc(f64x2_trunc);
+        fn visit_f64x2_nearest(f64x2_nearest);
+        fn visit_f64x2_abs(f64x2_abs);
+        fn visit_f64x2_neg(f64x2_neg);
+        fn visit_f64x2_sqrt(f64x2_sqrt);
+        fn visit_i32x4_trunc_sat_f32x4_s(i32x4_trunc_sat_f32x4_s);
+        fn visit_i32x4_trunc_sat_f32x4_u(i32x4_trunc_sat_f32x4_u);
+        fn visit_f32x4_convert_i32x4_s(f32x4_convert_i32x4_s);
+        fn visit_f32x4_convert_i32x4_u(f32x4_convert_i32x4_u);
+        fn visit_i32x4_trunc_sat_f64x2_s_zero(i32x4_trunc_sat_f64x2_s_zero);
+        fn visit_i32x4_trunc_sat_f64x2_u_zero(i32x4_trunc_sat_f64x2_u_zero);
+        fn visit_f64x2_convert_low_i32x4_s(f64x2_convert_low_i32x4_s);
+        fn visit_f64x2_convert_low_i32x4_u(f64x2_convert_low_i32x4_u);
+        fn visit_f32x4_demote_f64x2_zero(f32x4_demote_f64x2_zero);
+        fn visit_f64x2_promote_low_f32x4(f64x2_promote_low_f32x4);
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_visit_binary! {
+        fn visit_i8x16_swizzle(i8x16_swizzle);
+        fn visit_i8x16_eq(i8x16_eq);
+        fn visit_i8x16_ne(i8x16_ne);
+        fn visit_i8x16_lt_s(i8x16_lt_s);
+        fn visit_i8x16_lt_u(i8x16_lt_u);
+        fn visit_i8x16_gt_s(i8x16_gt_s);
+        fn visit_i8x16_gt_u(i8x16_gt_u);
+        fn visit_i8x16_le_s(i8x16_le_s);
+        fn visit_i8x16_le_u(i8x16_le_u);
+        fn visit_i8x16_ge_s(i8x16_ge_s);
+        fn visit_i8x16_ge_u(i8x16_ge_u);
+        fn visit_i16x8_eq(i16x8_eq);
+        fn visit_i16x8_ne(i16x8_ne);
+        fn visit_i16x8_lt_s(i16x8_lt_s);
+        fn visit_i16x8_lt_u(i16x8_lt_u);
+        fn visit_i16x8_gt_s(i16x8_gt_s);
+        fn visit_i16x8_gt_u(i16x8_gt_u);
+        fn visit_i16x8_le_s(i16x8_le_s);
+        fn visit_i16x8_le_u(i16x8_le_u);
+        fn visit_i16x8_ge_s(i16x8_ge_s);
+        fn visit_i16x8_ge_u(i16x8_ge_u);
+        fn visit_i32x4_eq(i32x4_eq);
+        fn visit_i32x4_ne(i32x4_ne);
+        fn visit_i32x4_lt_s(i32x4_lt_s);
+        fn visit_i32x4_lt_u(i32x4_lt_u);
+        fn visit_i32x4_gt_s(i32x4_gt_s);
+        fn visit_i32x4_gt_u(i32x4_gt_u);
+        fn visit_i32x4_le_s(i32x4_le_s);
+        fn visit_i32x4_le_u(i32x4_le_u);
+        fn visit_i32x4_ge_s(i32x4_ge_s);
+        fn visit_i32x4_ge_u(i32x4_ge_u);
+        fn visit_i64x2_eq(i64x2_eq);
+        fn visit_i64x2_ne(i64x2_ne);
+        fn visit_i64x2_lt_s(i64x2_lt_s);
+        fn visit_i64x2_gt_s(i64x2_gt_s);
+        fn visit_i64x2_le_s(i64x2_le_s);
+        fn visit_i64x2_ge_s(i64x2_ge_s);
+        fn visit_f32x4_eq(f32x4_eq);
+        fn visit_f32x4_ne(f32x4_ne);
+        fn visit_f32x4_lt(f32x4_lt);
+        fn visit_f32x4_gt(f32x4_gt);
+        fn visit_f32x4_le(f32x4_le);
+        fn visit_f32x4_ge(f32x4_ge);
+        fn visit_f64x2_eq(f64x2_eq);
+        fn visit_f64x2_ne(f64x2_ne);
+        fn visit_f64x2_lt(f64x2_lt);
+        fn visit_f64x2_gt(f64x2_gt);
+        fn visit_f64x2_le(f64x2_le);
+        fn visit_f64x2_ge(f64x2_ge);
+        fn visit_v128_and(v128_and);
+        fn visit_v128_andnot(v128_andnot);
+        fn visit_v128_or(v128_or);
+        fn visit_v128_xor(v128_xor);
+        fn visit_i8x16_narrow_i16x8_s(i8x16_narrow_i16x8_s);
+        fn visit_i8x16_narrow_i16x8_u(i8x16_narrow_i16x8_u);
+        fn visit_i8x16_shl(i8x16_shl);
+        fn visit_i8x16_shr_s(i8x16_shr_s);
+        fn visit_i8x16_shr_u(i8x16_shr_u);
+        fn visit_i8x16_add(i8x16_add);
+        fn visit_i8x16_add_sat_s(i8x16_add_sat_s);
+        fn visit_i8x16_add_sat_u(i8x16_add_sat_u);
+        fn visit_i8x16_sub(i8x16_sub);
+        fn visit_i8x16_sub_sat_s(i8x16_sub_sat_s);
+        fn visit_i8x16_sub_sat_u(i8x16_sub_sat_u);
+        fn visit_i8x16_min_s(i8x16_min_s);
+        fn visit_i8x16_min_u(i8x16_min_u);
+        fn visit_i8x16_max_s(i8x16_max_s);
+        fn visit_i8x16_max_u(i8x16_max_u);
+        fn visit_i8x16_avgr_u(i8x16_avgr_u);
+        fn visit_i16x8_q15mulr_sat_s(i16x8_q15mulr_sat_s);
+        fn visit_i16x8_narrow_i32x4_s(i16x8_narrow_i32x4_s);
+        fn visit_i16x8_narrow_i32x4_u(i16x8_narrow_i32x4_u);
+        fn visit_i16x8_shl(i16x8_shl);
+        fn visit_i16x8_shr_s(i16x8_shr_s);
+        fn visit_i16x8_shr_u(i16x8_shr_u);
+        fn visit_i16x8_add(i16x8_add);
+        fn visit_i16x8_add_sat_s(i16x8_add_sat_s);
+        fn visit_i16x8_add_sat_u(i16x8_add_sat_u);
+        fn visit_i16x8_sub(i16x8_sub);
+        fn visit_i16x8_sub_sat_s(i16x8_sub_sat_s);
+        fn visit_i16x8_sub_sat_u(i16x8_sub_sat_u);
+        fn visit_i16x8_mul(i16x8_mul);
+        fn visit_i16x8_min_s(i16x8_min_s);
+        fn visit_i16x8_min_u(i16x8_min_u);
+        fn visit_i16x8_max_s(i16x8_max_s);
+        fn visit_i16x8_max_u(i16x8_max_u);
+        fn visit_i16x8_avgr_u(i16x8_avgr_u);
+        fn visit_i16x8_extmul_low_i8x16_s(i16x8_extmul_low_i8x16_s);
+        fn visit_i16x8_extmul_high_i8x16_s(i16x8_extmul_high_i8x16_s);
+        fn visit_i16x8_extmul_low_i8x16_u(i16x8_extmul_low_i8x16_u);
+        fn visit_i16x8_extmul_high_i8x16_u(i16x8_extmul_high_i8x16_u);
+        fn visit_i32x4_shl(i32x4_shl);
+        fn visit_i32x4_shr_s(i32x4_shr_s);
+        fn visit_i32x4_shr_u(i32x4_shr_u);
+        fn visit_i32x4_add(i32x4_add);
+        fn visit_i32x4_sub(i32x4_sub);
+        fn visit_i32x4_mul(i32x4_mul);
+        fn visit_i32x4_min_s(i32x4_min_s);
+        fn visit_i32x4_min_u(i32x4_min_u);
+        fn visit_i32x4_max_s(i32x4_max_s);
+        fn visit_i32x4_max_u(i32x4_max_u);
+        fn visit_i32x4_dot_i16x8_s(i32x4_dot_i16x8_s);
+        fn visit_i32x4_extmul_low_i16x8_s(i32x4_extmul_low_i16x8_s);
+        fn visit_i32x4_extmul_high_i16x8_s(i32x4_extmul_high_i16x8_s);
+        fn visit_i32x4_extmul_low_i16x8_u(i32x4_extmul_low_i16x8_u);
+        fn visit_i32x4_extmul_high_i16x8_u(i32x4_extmul_high_i16x8_u);
+        fn visit_i64x2_shl(i64x2_shl);
+        fn visit_i64x2_shr_s(i64x2_shr_s);
+        fn visit_i64x2_shr_u(i64x2_shr_u);
+        fn visit_i64x2_add(i64x2_add);
+        fn visit_i64x2_sub(i64x2_sub);
+        fn visit_i64x2_mul(i64x2_mul);
+        fn visit_i64x2_extmul_low_i32x4_s(i64x2_extmul_low_i32x4_s);
+        fn visit_i64x2_extmul_high_i32x4_s(i64x2_extmul_high_i32x4_s);
+        fn visit_i64x2_extmul_low_i32x4_u(i64x2_extmul_low_i32x4_u);
+        fn visit_i64x2_extmul_high_i32x4_u(i64x2_extmul_high_i32x4_u);
+        fn visit_f32x4_add(f32x4_add);
+        fn visit_f32x4_sub(f32x4_sub);
+        fn visit_f32x4_mul(f32x4_mul);
+        fn visit_f32x4_div(f32x4_div);
+        fn visit_f32x4_min(f32x4_min);
+        fn visit_f32x4_max(f32x4_max);
+        fn visit_f32x4_pmin(f32x4_pmin);
+        fn visit_f32x4_pmax(f32x4_pmax);
+        fn visit_f64x2_add(f64x2_add);
+        fn visit_f64x2_sub(f64x2_sub);
+        fn visit_f64x2_mul(f64x2_mul);
+        fn visit_f64x2_div(f64x2_div);
+        fn visit_f64x2_min(f64x2_min);
+        fn visit_f64x2_max(f64x2_max);
+        fn visit_f64x2_pmin(f64x2_pmin);
+        fn visit_f64x2_pmax(f64x2_pmax);
+    }
+}
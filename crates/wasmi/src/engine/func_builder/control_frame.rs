@@ -1,4 +1,4 @@
-use super::{labels::LabelRef, Instr};
+use super::{inst_builder::TryBlockRef, labels::LabelRef, Instr};
 use crate::module::BlockType;
 
 /// A Wasm `block` control flow frame.
@@ -269,6 +269,138 @@ impl IfControlFrame {
     }
 }
 
+/// A Wasm `try` control flow frame including its `catch`, `catch_all` and `delegate` clauses.
+#[derive(Debug, Copy, Clone)]
+pub struct TryControlFrame {
+    /// The type of the [`TryControlFrame`].
+    block_type: BlockType,
+    /// The value stack height upon entering the [`TryControlFrame`].
+    stack_height: u32,
+    /// Label representing the end of the [`TryControlFrame`].
+    end_label: LabelRef,
+    /// The `try` block registered at the instruction builder.
+    try_block: TryBlockRef,
+    /// The first instruction of the body of the `try` block.
+    start: Instr,
+    /// The end of the body of the `try` block.
+    ///
+    /// # Note
+    ///
+    /// This is `None` until the first `catch` or `catch_all` clause is reached.
+    /// Afterwards the [`TryControlFrame`] represents the current `catch` or
+    /// `catch_all` block.
+    body_end: Option<Instr>,
+    /// Instruction to consume fuel upon entering the basic block if fuel metering is enabled.
+    ///
+    /// This is used for the `try` body as well as for all `catch` and `catch_all`
+    /// blocks. When entering a `catch` or `catch_all` block this field is updated
+    /// to represent the [`ConsumeFuel`] instruction of the entered block.
+    ///
+    /// # Note
+    ///
+    /// This might be a reference to the consume fuel instruction of the parent
+    /// [`ControlFrame`] of the [`TryControlFrame`] for the `try` body.
+    ///
+    /// [`ConsumeFuel`]: enum.Instruction.html#variant.ConsumeFuel
+    consume_fuel: Option<Instr>,
+}
+
+impl TryControlFrame {
+    /// Creates a new [`TryControlFrame`].
+    pub fn new(
+        block_type: BlockType,
+        end_label: LabelRef,
+        stack_height: u32,
+        try_block: TryBlockRef,
+        start: Instr,
+        consume_fuel: Option<Instr>,
+    ) -> Self {
+        Self {
+            block_type,
+            stack_height,
+            end_label,
+            try_block,
+            start,
+            body_end: None,
+            consume_fuel,
+        }
+    }
+
+    /// Returns the label for the branch destination of the [`TryControlFrame`].
+    ///
+    /// # Note
+    ///
+    /// Branches to [`TryControlFrame`] jump to the end of the frame.
+    pub fn branch_destination(&self) -> LabelRef {
+        self.end_label
+    }
+
+    /// Returns the label to the end of the [`TryControlFrame`].
+    pub fn end_label(&self) -> LabelRef {
+        self.end_label
+    }
+
+    /// Returns the value stack height upon entering the [`TryControlFrame`].
+    pub fn stack_height(&self) -> u32 {
+        self.stack_height
+    }
+
+    /// Returns the [`BlockType`] of the [`TryControlFrame`].
+    pub fn block_type(&self) -> BlockType {
+        self.block_type
+    }
+
+    /// Returns the `try` block of the [`TryControlFrame`].
+    pub fn try_block(&self) -> TryBlockRef {
+        self.try_block
+    }
+
+    /// Returns the first instruction of the body of the `try` block.
+    pub fn start(&self) -> Instr {
+        self.start
+    }
+
+    /// Returns the end of the body of the `try` block if it has been reached.
+    pub fn body_end(&self) -> Option<Instr> {
+        self.body_end
+    }
+
+    /// Returns `true` if the [`TryControlFrame`] is in a `catch` or `catch_all` block.
+    pub fn is_catching(&self) -> bool {
+        self.body_end.is_some()
+    }
+
+    /// Updates the end of the body of the `try` block.
+    ///
+    /// # Panics
+    ///
+    /// If this information has already been provided prior.
+    pub fn update_body_end(&mut self, end: Instr) {
+        assert!(self.body_end.is_none());
+        self.body_end = Some(end);
+    }
+
+    /// Returns a reference to the [`ConsumeFuel`] instruction of the [`TryControlFrame`] if any.
+    ///
+    /// Returns `None` if fuel metering is disabled.
+    ///
+    /// [`ConsumeFuel`]: enum.Instruction.html#variant.ConsumeFuel
+    pub fn consume_fuel_instr(&self) -> Option<Instr> {
+        self.consume_fuel
+    }
+
+    /// Updates the [`ConsumeFuel`] instruction for when a `catch` or `catch_all` block is entered.
+    ///
+    /// [`ConsumeFuel`]: enum.Instruction.html#variant.ConsumeFuel
+    pub fn update_consume_fuel_instr(&mut self, instr: Instr) {
+        assert!(
+            self.consume_fuel.is_some(),
+            "can only update the consume fuel instruction if it existed before"
+        );
+        self.consume_fuel = Some(instr);
+    }
+}
+
 /// An unreachable control flow frame of any kind.
 #[derive(Debug, Copy, Clone)]
 pub struct UnreachableControlFrame {
@@ -287,6 +419,8 @@ pub enum ControlFrameKind {
     Loop,
     /// An `if` and `else` block control flow frame.
     If,
+    /// A `try` block control flow frame including its `catch` and `catch_all` blocks.
+    Try,
 }
 
 impl UnreachableControlFrame {
@@ -315,6 +449,8 @@ pub enum ControlFrame {
     Loop(LoopControlFrame),
     /// If and else control frame.
     If(IfControlFrame),
+    /// Try, catch and catch all control frame.
+    Try(TryControlFrame),
     /// An unreachable control frame.
     Unreachable(UnreachableControlFrame),
 }
@@ -337,6 +473,12 @@ impl From<IfControlFrame> for ControlFrame {
     }
 }
 
+impl From<TryControlFrame> for ControlFrame {
+    fn from(frame: TryControlFrame) -> Self {
+        Self::Try(frame)
+    }
+}
+
 impl From<UnreachableControlFrame> for ControlFrame {
     fn from(frame: UnreachableControlFrame) -> Self {
         Self::Unreachable(frame)
@@ -350,6 +492,7 @@ impl ControlFrame {
             ControlFrame::Block(_) => ControlFrameKind::Block,
             ControlFrame::Loop(_) => ControlFrameKind::Loop,
             ControlFrame::If(_) => ControlFrameKind::If,
+            ControlFrame::Try(_) => ControlFrameKind::Try,
             ControlFrame::Unreachable(frame) => frame.kind(),
         }
     }
@@ -360,6 +503,7 @@ impl ControlFrame {
             Self::Block(frame) => frame.branch_destination(),
             Self::Loop(frame) => frame.branch_destination(),
             Self::If(frame) => frame.branch_destination(),
+            Self::Try(frame) => frame.branch_destination(),
             Self::Unreachable(frame) => panic!(
                 "tried to get `branch_destination` for an unreachable control frame: {frame:?}"
             ),
@@ -375,6 +519,7 @@ impl ControlFrame {
         match self {
             Self::Block(frame) => frame.end_label(),
             Self::If(frame) => frame.end_label(),
+            Self::Try(frame) => frame.end_label(),
             Self::Loop(frame) => {
                 panic!("tried to get `end_label` for a loop control frame: {frame:?}")
             }
@@ -390,6 +535,7 @@ impl ControlFrame {
             Self::Block(frame) => Some(frame.stack_height()),
             Self::Loop(frame) => Some(frame.stack_height()),
             Self::If(frame) => Some(frame.stack_height()),
+            Self::Try(frame) => Some(frame.stack_height()),
             Self::Unreachable(_frame) => None,
         }
     }
@@ -400,6 +546,7 @@ impl ControlFrame {
             Self::Block(frame) => frame.block_type(),
             Self::Loop(frame) => frame.block_type(),
             Self::If(frame) => frame.block_type(),
+            Self::Try(frame) => frame.block_type(),
             Self::Unreachable(frame) => frame.block_type(),
         }
     }
@@ -419,6 +566,7 @@ impl ControlFrame {
             ControlFrame::Block(frame) => frame.consume_fuel_instr(),
             ControlFrame::Loop(frame) => frame.consume_fuel_instr(),
             ControlFrame::If(frame) => frame.consume_fuel_instr(),
+            ControlFrame::Try(frame) => frame.consume_fuel_instr(),
             ControlFrame::Unreachable(_) => None,
         }
     }
@@ -82,6 +82,9 @@ impl Display for TranslationError {
                     "fuel required to execute a block is out of bounds for wasmi bytecode"
                 )
             }
+            TranslationErrorInner::MemoryIndexOutOfBounds => {
+                write!(f, "linear memory index is out of bounds for wasmi bytecode")
+            }
         }
     }
 }
@@ -105,4 +108,6 @@ pub enum TranslationErrorInner {
     BlockFuelOutOfBounds,
     /// The constant reference index is out of bounds.
     ConstRefOutOfBounds,
+    /// The linear memory index of a memory access is out of bounds.
+    MemoryIndexOutOfBounds,
 }
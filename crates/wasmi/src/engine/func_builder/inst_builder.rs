@@ -6,6 +6,7 @@ use super::{
 };
 use crate::engine::{
     bytecode::{BranchOffset, Instruction},
+    code_map::{AddressMapping, DelegateTarget, ExceptionHandler, ExceptionHandlerKind},
     CompiledFunc,
     DropKeep,
     Engine,
@@ -66,6 +67,17 @@ impl RelativeDepth {
     }
 }
 
+/// A reference to a Wasm `try` block of the partially constructed function body.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryBlockRef(u32);
+
+impl TryBlockRef {
+    /// Returns an `usize` representation of the `try` block reference.
+    fn into_usize(self) -> usize {
+        self.0 as usize
+    }
+}
+
 /// An instruction builder.
 ///
 /// Allows to incrementally and efficiently build up the instructions
@@ -77,6 +89,24 @@ pub struct InstructionsBuilder {
     insts: Vec<Instruction>,
     /// All labels and their uses.
     labels: LabelRegistry,
+    /// The exception handlers of the partially constructed function body.
+    handlers: Vec<ExceptionHandler>,
+    /// The delegate targets of all `try` blocks indexed by [`TryBlockRef`].
+    ///
+    /// # Note
+    ///
+    /// This is `None` for `try` blocks whose body has not yet been finished.
+    try_blocks: Vec<Option<DelegateTarget>>,
+    /// The `delegate` handlers and the `try` blocks they are delegating to.
+    delegates: Vec<(usize, TryBlockRef)>,
+    /// The offset of the currently translated Wasm operator within the Wasm binary.
+    ///
+    /// # Note
+    ///
+    /// This is `None` if no address map is generated for the function body.
+    wasm_offset: Option<u32>,
+    /// The address map of the partially constructed function body.
+    addrs: Vec<AddressMapping>,
 }
 
 impl InstructionsBuilder {
@@ -84,6 +114,11 @@ impl InstructionsBuilder {
     pub fn reset(&mut self) {
         self.insts.clear();
         self.labels.reset();
+        self.handlers.clear();
+        self.try_blocks.clear();
+        self.delegates.clear();
+        self.wasm_offset = None;
+        self.addrs.clear();
     }
 
     /// Returns the current instruction pointer as index.
@@ -126,15 +161,51 @@ impl InstructionsBuilder {
             .unwrap_or_else(|err| panic!("failed to pin label: {err}"));
     }
 
+    /// Updates the offset of the currently translated Wasm operator within the Wasm binary.
+    ///
+    /// # Note
+    ///
+    /// All instructions pushed after this are mapped to `wasm_offset` in the address map.
+    ///
+    /// # Panics
+    ///
+    /// If `wasm_offset` is out of bounds for Wasm binaries.
+    pub fn update_wasm_offset(&mut self, wasm_offset: usize) {
+        let wasm_offset = u32::try_from(wasm_offset)
+            .unwrap_or_else(|_| panic!("out of bounds Wasm offset: {wasm_offset}"));
+        self.wasm_offset = Some(wasm_offset);
+    }
+
     /// Pushes the internal instruction bytecode to the [`InstructionsBuilder`].
     ///
     /// Returns an [`Instr`] to refer to the pushed instruction.
     pub fn push_inst(&mut self, inst: Instruction) -> Instr {
         let idx = self.current_pc();
         self.insts.push(inst);
+        self.map_wasm_offset(idx);
         idx
     }
 
+    /// Maps the pushed instruction `instr` to the current Wasm offset if necessary.
+    ///
+    /// # Note
+    ///
+    /// Instructions pushed before the first Wasm operator, such as the fuel
+    /// metering of the function entry, are mapped to the first Wasm operator.
+    fn map_wasm_offset(&mut self, instr: Instr) {
+        let wasm_offset = match self.wasm_offset {
+            Some(wasm_offset) => wasm_offset,
+            None => return,
+        };
+        match self.addrs.last() {
+            Some(last) if last.wasm_offset() == wasm_offset => {}
+            Some(_) => self
+                .addrs
+                .push(AddressMapping::new(instr.into_u32(), wasm_offset)),
+            None => self.addrs.push(AddressMapping::new(0, wasm_offset)),
+        }
+    }
+
     /// Pushes an [`Instruction::BrAdjust`] to the [`InstructionsBuilder`].
     ///
     /// Returns an [`Instr`] to refer to the pushed instruction.
@@ -161,6 +232,57 @@ impl InstructionsBuilder {
         idx
     }
 
+    /// Creates a new `try` block and returns a reference to it.
+    pub fn new_try_block(&mut self) -> TryBlockRef {
+        let index = u32::try_from(self.try_blocks.len())
+            .unwrap_or_else(|error| panic!("too many try blocks: {error}"));
+        self.try_blocks.push(None);
+        TryBlockRef(index)
+    }
+
+    /// Finishes the body of the `try` block starting at instruction `start`.
+    ///
+    /// # Note
+    ///
+    /// The exception handlers of the `try` block are pushed after this point.
+    pub fn finish_try_block(&mut self, block: TryBlockRef, start: Instr) {
+        let handler = u32::try_from(self.handlers.len())
+            .unwrap_or_else(|error| panic!("too many exception handlers: {error}"));
+        self.try_blocks[block.into_usize()] = Some(DelegateTarget::new(handler, start.into_u32()));
+    }
+
+    /// Pushes an [`ExceptionHandler`] covering the body of a `try` block from `start` to `end`.
+    pub fn push_handler(&mut self, start: Instr, end: Instr, kind: ExceptionHandlerKind) {
+        self.handlers.push(ExceptionHandler::new(
+            start.into_u32()..end.into_u32(),
+            kind,
+        ));
+    }
+
+    /// Pushes a `delegate` [`ExceptionHandler`] covering the body of a `try` block from `start` to `end`.
+    ///
+    /// Exceptions are delegated to the handlers of the `target` `try` block
+    /// or to the caller if `target` is `None`.
+    pub fn push_delegate_handler(&mut self, start: Instr, end: Instr, target: Option<TryBlockRef>) {
+        if let Some(target) = target {
+            self.delegates.push((self.handlers.len(), target));
+        }
+        self.push_handler(start, end, ExceptionHandlerKind::Delegate(None));
+    }
+
+    /// Updates the targets of all `delegate` exception handlers inplace.
+    ///
+    /// # Panics
+    ///
+    /// If this is used before all `try` blocks have been finished.
+    fn update_delegate_targets(&mut self) {
+        for (handler, block) in self.delegates.drain(..) {
+            let target = self.try_blocks[block.into_usize()]
+                .unwrap_or_else(|| panic!("unfinished try block: {block:?}"));
+            self.handlers[handler].update_delegate_target(target);
+        }
+    }
+
     /// Try resolving the `label` for the currently constructed instruction.
     ///
     /// Returns an uninitialized [`BranchOffset`] if the `label` cannot yet
@@ -198,7 +320,15 @@ impl InstructionsBuilder {
         local_stack_height: usize,
     ) -> Result<(), TranslationError> {
         self.update_branch_offsets()?;
-        engine.init_func(func, len_locals, local_stack_height, self.insts.drain(..));
+        self.update_delegate_targets();
+        engine.init_func(
+            func,
+            len_locals,
+            local_stack_height,
+            self.insts.drain(..),
+            self.handlers.drain(..),
+            self.addrs.drain(..),
+        );
         Ok(())
     }
 
@@ -28,12 +28,15 @@ type FuncValidator = wasmparser::FuncValidator<wasmparser::ValidatorResources>;
 ///
 /// # Note
 ///
-/// This includes validation of the incoming Wasm bytecode.
+/// This includes validation of the incoming Wasm bytecode
+/// unless it has already been validated before.
 pub struct FuncBuilder<'parser> {
     /// The current position in the Wasm binary while parsing operators.
     pos: usize,
     /// The Wasm function validator.
-    validator: FuncValidator,
+    ///
+    /// This is `None` if the Wasm function body has already been validated.
+    validator: Option<FuncValidator>,
     /// The underlying Wasm to `wasmi` bytecode translator.
     translator: FuncTranslator<'parser>,
 }
@@ -44,7 +47,7 @@ impl<'parser> FuncBuilder<'parser> {
         func: FuncIdx,
         compiled_func: CompiledFunc,
         res: ModuleResources<'parser>,
-        validator: FuncValidator,
+        validator: Option<FuncValidator>,
         allocations: FuncTranslatorAllocations,
     ) -> Self {
         Self {
@@ -61,7 +64,9 @@ impl<'parser> FuncBuilder<'parser> {
         amount: u32,
         value_type: wasmparser::ValType,
     ) -> Result<(), TranslationError> {
-        self.validator.define_locals(offset, amount, value_type)?;
+        if let Some(validator) = &mut self.validator {
+            validator.define_locals(offset, amount, value_type)?;
+        }
         self.translator.register_locals(amount);
         Ok(())
     }
@@ -80,6 +85,7 @@ impl<'parser> FuncBuilder<'parser> {
     /// Updates the current position within the Wasm binary while parsing operators.
     pub fn update_pos(&mut self, pos: usize) {
         self.pos = pos;
+        self.translator.update_pos(pos);
     }
 
     /// Returns the current position within the Wasm binary while parsing operators.
@@ -89,11 +95,16 @@ impl<'parser> FuncBuilder<'parser> {
 
     /// Finishes constructing the function by initializing its [`CompiledFunc`].
     pub fn finish(mut self, offset: usize) -> Result<ReusableAllocations, TranslationError> {
-        self.validator.finish(offset)?;
+        if let Some(validator) = &mut self.validator {
+            validator.finish(offset)?;
+        }
         self.translator.finish()?;
         let allocations = ReusableAllocations {
             translation: self.translator.into_allocations(),
-            validation: self.validator.into_allocations(),
+            validation: self
+                .validator
+                .map(FuncValidator::into_allocations)
+                .unwrap_or_default(),
         };
         Ok(allocations)
     }
@@ -108,7 +119,9 @@ impl<'parser> FuncBuilder<'parser> {
         V: FnOnce(&mut FuncValidator) -> Result<(), BinaryReaderError>,
         T: FnOnce(&mut FuncTranslator<'parser>) -> Result<(), TranslationError>,
     {
-        validate(&mut self.validator)?;
+        if let Some(validator) = &mut self.validator {
+            validate(validator)?;
+        }
         translate(&mut self.translator)?;
         Ok(())
     }
@@ -147,6 +160,33 @@ macro_rules! impl_visit_operator {
     ( @tail_call $($rest:tt)* ) => {
         impl_visit_operator!(@@supported $($rest)*);
     };
+    ( @exceptions $($rest:tt)* ) => {
+        impl_visit_operator!(@@supported $($rest)*);
+    };
+    ( @simd $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        // We handle Wasm `simd` operators directly since there are too many of them
+        // for the macro recursion limit when forwarding to `@@supported`.
+        fn $visit(&mut self $($(,$arg: $argty)*)?) -> Self::Output {
+            let offset = self.current_pos();
+            self.validate_then_translate(
+                |v| v.visitor(offset).$visit($($($arg),*)?),
+                |t| t.$visit($($($arg),*)?),
+            )
+        }
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        // We handle Wasm `threads` operators directly since there are too many of them
+        // for the macro recursion limit when forwarding to `@@supported`.
+        fn $visit(&mut self $($(,$arg: $argty)*)?) -> Self::Output {
+            let offset = self.current_pos();
+            self.validate_then_translate(
+                |v| v.visitor(offset).$visit($($($arg),*)?),
+                |t| t.$visit($($($arg),*)?),
+            )
+        }
+        impl_visit_operator!($($rest)*);
+    };
     ( @@supported $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
         fn $visit(&mut self $($(,$arg: $argty)*)?) -> Self::Output {
             let offset = self.current_pos();
@@ -161,7 +201,15 @@ macro_rules! impl_visit_operator {
         // Wildcard match arm for all the other (yet) unsupported Wasm proposals.
         fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
             let offset = self.current_pos();
-            self.validator.visitor(offset).$visit($($($arg),*)?).map_err(::core::convert::Into::into)
+            match &mut self.validator {
+                Some(validator) => validator
+                    .visitor(offset)
+                    .$visit($($($arg),*)?)
+                    .map_err(::core::convert::Into::into),
+                // Note: Validation of the function body already succeeded
+                //       which implies that the operator is supported.
+                None => Ok(()),
+            }
         }
         impl_visit_operator!($($rest)*);
     };
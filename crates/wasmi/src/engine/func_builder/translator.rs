@@ -4,6 +4,7 @@ use super::{
         ControlFrame,
         IfControlFrame,
         LoopControlFrame,
+        TryControlFrame,
         UnreachableControlFrame,
     },
     labels::LabelRef,
@@ -13,6 +14,8 @@ use super::{
     InstructionsBuilder,
     TranslationError,
 };
+#[cfg(feature = "simd")]
+use crate::engine::bytecode::LaneIdx;
 use crate::{
     engine::{
         bytecode::{
@@ -23,9 +26,12 @@ use crate::{
             DataSegmentIdx,
             ElementSegmentIdx,
             Instruction,
+            MemArg,
+            MemoryIdx,
             SignatureIdx,
             TableIdx,
         },
+        code_map::{ExceptionHandlerKind, HandlerTarget},
         config::FuelCosts,
         func_builder::control_frame::ControlFrameKind,
         CompiledFunc,
@@ -33,22 +39,15 @@ use crate::{
         Instr,
         RelativeDepth,
     },
-    module::{
-        BlockType,
-        ConstExpr,
-        FuncIdx,
-        FuncTypeIdx,
-        GlobalIdx,
-        MemoryIdx,
-        ModuleResources,
-        DEFAULT_MEMORY_INDEX,
-    },
+    module::{BlockType, ConstExpr, FuncIdx, FuncTypeIdx, GlobalIdx, ModuleResources, TagIdx},
     Engine,
     FuncType,
     GlobalType,
     Mutability,
 };
 use alloc::vec::Vec;
+#[cfg(feature = "simd")]
+use wasmi_core::V128;
 use wasmi_core::{UntypedValue, ValueType, F32, F64};
 use wasmparser::VisitOperator;
 
@@ -145,6 +144,7 @@ impl<'parser> FuncTranslator<'parser> {
         let func_type = self.res.get_type_of_func(self.func);
         let block_type = BlockType::func_type(func_type);
         let end_label = self.alloc.inst_builder.new_label();
+        self.push_check_epoch();
         let consume_fuel = self.is_fuel_metering_enabled().then(|| {
             self.alloc
                 .inst_builder
@@ -185,6 +185,15 @@ impl<'parser> FuncTranslator<'parser> {
         Ok(())
     }
 
+    /// Updates the offset of the currently translated Wasm operator within the Wasm binary.
+    ///
+    /// Does nothing unless address maps are generated for the [`Engine`].
+    pub fn update_pos(&mut self, pos: usize) {
+        if self.engine().config().get_generate_address_map() {
+            self.alloc.inst_builder.update_wasm_offset(pos);
+        }
+    }
+
     /// Finishes constructing the function and returns its [`CompiledFunc`].
     pub fn finish(&mut self) -> Result<(), TranslationError> {
         self.alloc.inst_builder.finish(
@@ -213,6 +222,24 @@ impl<'parser> FuncTranslator<'parser> {
         self.engine().config().get_consume_fuel()
     }
 
+    /// Returns `true` if epoch interruption is enabled for the [`Engine`].
+    ///
+    /// # Note
+    ///
+    /// This is important for the [`FuncTranslator`] to know since it
+    /// has to create [`Instruction::CheckEpoch`] instructions at function
+    /// entries and loop headers so that long running executions can be interrupted.
+    fn is_epoch_interruption_enabled(&self) -> bool {
+        self.engine().config().get_epoch_interruption()
+    }
+
+    /// Pushes an [`Instruction::CheckEpoch`] if epoch interruption is enabled.
+    fn push_check_epoch(&mut self) {
+        if self.is_epoch_interruption_enabled() {
+            self.alloc.inst_builder.push_inst(Instruction::CheckEpoch);
+        }
+    }
+
     /// Creates an [`Instruction::ConsumeFuel`] with base costs.
     fn make_consume_fuel_base(&self) -> Instruction {
         Instruction::consume_fuel(self.fuel_costs().base).expect("base fuel costs must be valid")
@@ -270,6 +297,14 @@ impl<'parser> FuncTranslator<'parser> {
             .resolve_func_type(dedup_func_type, Clone::clone)
     }
 
+    /// Returns the number of exception values of the tag at `tag_index`.
+    fn len_tag_params(&self, tag_index: u32) -> u32 {
+        let dedup_func_type = self.res.get_type_of_tag(TagIdx::from(tag_index));
+        self.res
+            .engine()
+            .resolve_func_type(dedup_func_type, |func_type| func_type.params().len() as u32)
+    }
+
     /// Returns the number of local variables of the function under construction.
     fn len_locals(&self) -> usize {
         let len_params_locals = self.locals.len_registered() as usize;
@@ -326,7 +361,7 @@ impl<'parser> FuncTranslator<'parser> {
         let frame = self.alloc.control_frames.nth_back(depth);
         // Find out how many values we need to keep (copy to the new stack location after the drop).
         let keep = match frame.kind() {
-            ControlFrameKind::Block | ControlFrameKind::If => {
+            ControlFrameKind::Block | ControlFrameKind::If | ControlFrameKind::Try => {
                 frame.block_type().len_results(self.res.engine())
             }
             ControlFrameKind::Loop => frame.block_type().len_params(self.res.engine()),
@@ -460,11 +495,28 @@ impl<'parser> FuncTranslator<'parser> {
         Ok(None)
     }
 
-    /// Decompose a [`wasmparser::MemArg`] into its raw parts.
-    fn decompose_memarg(memarg: wasmparser::MemArg) -> (MemoryIdx, u32) {
+    /// Converts a [`wasmparser::MemArg`] into its `wasmi` bytecode representation.
+    ///
+    /// # Note
+    ///
+    /// Offsets of 64-bit linear memories that do not fit into 32-bit
+    /// are stored in the constant pool of the [`Engine`].
+    ///
+    /// # Errors
+    ///
+    /// - If the linear memory index is out of bounds for the `wasmi` bytecode.
+    /// - If too many constant values have been allocated.
+    fn translate_memarg(&self, memarg: wasmparser::MemArg) -> Result<MemArg, TranslationError> {
         let memory_idx = MemoryIdx::from(memarg.memory);
-        let offset = memarg.offset as u32;
-        (memory_idx, offset)
+        match u32::try_from(memarg.offset) {
+            Ok(offset) => MemArg::new(memory_idx, AddressOffset::from(offset)),
+            Err(_) => {
+                let offset = self
+                    .engine()
+                    .alloc_const(UntypedValue::from(memarg.offset))?;
+                MemArg::with_const_offset(memory_idx, offset)
+            }
+        }
     }
 
     /// Translate a Wasm `<ty>.load` instruction.
@@ -491,16 +543,14 @@ impl<'parser> FuncTranslator<'parser> {
         &mut self,
         memarg: wasmparser::MemArg,
         _loaded_type: ValueType,
-        make_inst: fn(AddressOffset) -> Instruction,
+        make_inst: fn(MemArg) -> Instruction,
     ) -> Result<(), TranslationError> {
         self.translate_if_reachable(|builder| {
-            let (memory_idx, offset) = Self::decompose_memarg(memarg);
-            debug_assert_eq!(memory_idx.into_u32(), DEFAULT_MEMORY_INDEX);
+            let memarg = builder.translate_memarg(memarg)?;
             builder.bump_fuel_consumption(builder.fuel_costs().load)?;
             builder.stack_height.pop1();
             builder.stack_height.push();
-            let offset = AddressOffset::from(offset);
-            builder.alloc.inst_builder.push_inst(make_inst(offset));
+            builder.alloc.inst_builder.push_inst(make_inst(memarg));
             Ok(())
         })
     }
@@ -524,15 +574,173 @@ impl<'parser> FuncTranslator<'parser> {
         &mut self,
         memarg: wasmparser::MemArg,
         _stored_value: ValueType,
-        make_inst: fn(AddressOffset) -> Instruction,
+        make_inst: fn(MemArg) -> Instruction,
+    ) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            let memarg = builder.translate_memarg(memarg)?;
+            builder.bump_fuel_consumption(builder.fuel_costs().store)?;
+            builder.stack_height.pop2();
+            builder.alloc.inst_builder.push_inst(make_inst(memarg));
+            Ok(())
+        })
+    }
+
+    /// Translate a Wasm atomic read-modify-write instruction.
+    ///
+    /// # Note
+    ///
+    /// This is used as the translation backend of the following Wasm instructions:
+    ///
+    /// - `{i32, i64}.atomic.rmw.{add, sub, and, or, xor, xchg}`
+    /// - `i32.atomic.rmw{8, 16}.{add, sub, and, or, xor, xchg}_u`
+    /// - `i64.atomic.rmw{8, 16, 32}.{add, sub, and, or, xor, xchg}_u`
+    /// - `memory.atomic.notify`
+    fn translate_atomic_rmw(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        make_inst: fn(MemArg) -> Instruction,
+    ) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            let memarg = builder.translate_memarg(memarg)?;
+            builder.bump_fuel_consumption(builder.fuel_costs().store)?;
+            builder.stack_height.pop2();
+            builder.stack_height.push();
+            builder.alloc.inst_builder.push_inst(make_inst(memarg));
+            Ok(())
+        })
+    }
+
+    /// Translate a Wasm atomic compare-exchange instruction.
+    ///
+    /// # Note
+    ///
+    /// This is used as the translation backend of the following Wasm instructions:
+    ///
+    /// - `{i32, i64}.atomic.rmw.cmpxchg`
+    /// - `i32.atomic.rmw{8, 16}.cmpxchg_u`
+    /// - `i64.atomic.rmw{8, 16, 32}.cmpxchg_u`
+    /// - `memory.atomic.wait{32, 64}`
+    fn translate_atomic_cmpxchg(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        make_inst: fn(MemArg) -> Instruction,
+    ) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            let memarg = builder.translate_memarg(memarg)?;
+            builder.bump_fuel_consumption(builder.fuel_costs().store)?;
+            builder.stack_height.pop3();
+            builder.stack_height.push();
+            builder.alloc.inst_builder.push_inst(make_inst(memarg));
+            Ok(())
+        })
+    }
+
+    #[cfg(feature = "simd")]
+    /// Translate a Wasm `v128.load<N>_lane` instruction.
+    ///
+    /// # Note
+    ///
+    /// This is used as the translation backend of the following Wasm instructions:
+    ///
+    /// - `v128.load8_lane`
+    /// - `v128.load16_lane`
+    /// - `v128.load32_lane`
+    /// - `v128.load64_lane`
+    fn translate_load_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+        make_inst: fn(MemArg) -> Instruction,
+    ) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            let memarg = builder.translate_memarg(memarg)?;
+            builder.bump_fuel_consumption(builder.fuel_costs().load)?;
+            builder.stack_height.pop2();
+            builder.stack_height.push();
+            builder.alloc.inst_builder.push_inst(make_inst(memarg));
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::I8x16ExtractLaneS(LaneIdx::from(lane)));
+            Ok(())
+        })
+    }
+
+    #[cfg(feature = "simd")]
+    /// Translate a Wasm `v128.store<N>_lane` instruction.
+    ///
+    /// # Note
+    ///
+    /// This is used as the translation backend of the following Wasm instructions:
+    ///
+    /// - `v128.store8_lane`
+    /// - `v128.store16_lane`
+    /// - `v128.store32_lane`
+    /// - `v128.store64_lane`
+    fn translate_store_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+        make_inst: fn(MemArg) -> Instruction,
     ) -> Result<(), TranslationError> {
         self.translate_if_reachable(|builder| {
-            let (memory_idx, offset) = Self::decompose_memarg(memarg);
-            debug_assert_eq!(memory_idx.into_u32(), DEFAULT_MEMORY_INDEX);
+            let memarg = builder.translate_memarg(memarg)?;
             builder.bump_fuel_consumption(builder.fuel_costs().store)?;
             builder.stack_height.pop2();
-            let offset = AddressOffset::from(offset);
-            builder.alloc.inst_builder.push_inst(make_inst(offset));
+            builder.alloc.inst_builder.push_inst(make_inst(memarg));
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::I8x16ExtractLaneS(LaneIdx::from(lane)));
+            Ok(())
+        })
+    }
+
+    #[cfg(feature = "simd")]
+    /// Translate a Wasm `<shape>.extract_lane` instruction.
+    ///
+    /// # Note
+    ///
+    /// This is used to translate the following Wasm instructions:
+    ///
+    /// - `{i8x16, i16x8}.extract_lane_{s, u}`
+    /// - `{i32x4, i64x2, f32x4, f64x2}.extract_lane`
+    fn translate_extract_lane(
+        &mut self,
+        lane: u8,
+        make_inst: fn(LaneIdx) -> Instruction,
+    ) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            builder.bump_fuel_consumption(builder.fuel_costs().base)?;
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(make_inst(LaneIdx::from(lane)));
+            Ok(())
+        })
+    }
+
+    #[cfg(feature = "simd")]
+    /// Translate a Wasm `<shape>.replace_lane` instruction.
+    ///
+    /// # Note
+    ///
+    /// This is used to translate the following Wasm instructions:
+    ///
+    /// - `{i8x16, i16x8, i32x4, i64x2, f32x4, f64x2}.replace_lane`
+    fn translate_replace_lane(
+        &mut self,
+        lane: u8,
+        make_inst: fn(LaneIdx) -> Instruction,
+    ) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            builder.bump_fuel_consumption(builder.fuel_costs().base)?;
+            builder.stack_height.pop2();
+            builder.stack_height.push();
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(make_inst(LaneIdx::from(lane)));
             Ok(())
         })
     }
@@ -546,6 +754,7 @@ impl<'parser> FuncTranslator<'parser> {
     ///
     /// - `i64.const`
     /// - `f64.const`
+    /// - `v128.const`
     fn translate_const_ref<T>(&mut self, value: T) -> Result<(), TranslationError>
     where
         T: Into<UntypedValue>,
@@ -737,6 +946,107 @@ impl<'parser> FuncTranslator<'parser> {
         Ok(())
     }
 
+    /// Translates the branch to the end of the `try` frame on top of the control stack.
+    ///
+    /// # Note
+    ///
+    /// This is used at the end of the `try` body as well as at the end of its
+    /// `catch` and `catch_all` blocks. The latter drop the hidden value that
+    /// refers to the caught exception.
+    fn translate_branch_to_try_end(&mut self) -> Result<(), TranslationError> {
+        debug_assert!(self.is_reachable());
+        let end_label = self.alloc.control_frames.last().end_label();
+        let drop_keep = self.compute_drop_keep(0)?;
+        self.bump_fuel_consumption(self.fuel_costs().base)?;
+        let offset = self.branch_offset(end_label)?;
+        if drop_keep.is_noop() {
+            self.alloc.inst_builder.push_inst(Instruction::Br(offset));
+        } else {
+            self.bump_fuel_consumption(self.fuel_costs().fuel_for_drop_keep(drop_keep))?;
+            self.alloc
+                .inst_builder
+                .push_br_adjust_instr(offset, drop_keep);
+        }
+        Ok(())
+    }
+
+    /// Translates a Wasm `catch` clause for the tag at `tag_index` or a `catch_all` clause if `None`.
+    ///
+    /// # Note
+    ///
+    /// Upon entering the handler the executor pushes a hidden value that refers to the
+    /// caught exception followed by the exception values in case of `catch` clauses.
+    /// The hidden value is used by `rethrow` instructions.
+    fn translate_catch(&mut self, tag_index: Option<u32>) -> Result<(), TranslationError> {
+        match self.alloc.control_frames.last() {
+            ControlFrame::Try(_) => {}
+            ControlFrame::Unreachable(frame) if matches!(frame.kind(), ControlFrameKind::Try) => {
+                // Encountered `catch` or `catch_all` block for unreachable `try` block.
+                //
+                // In this case we can simply ignore the entire block
+                // since it is unreachable anyways.
+                return Ok(());
+            }
+            unexpected => panic!(
+                "expected `try` control flow frame on top \
+                for `catch` or `catch_all` but found: {unexpected:?}",
+            ),
+        }
+        if self.is_reachable() {
+            self.translate_branch_to_try_end()?;
+        }
+        let mut try_frame = match self.alloc.control_frames.pop_frame() {
+            ControlFrame::Try(try_frame) => try_frame,
+            unexpected => {
+                unreachable!("expected `try` control flow frame but found: {unexpected:?}")
+            }
+        };
+        let body_end = match try_frame.body_end() {
+            Some(body_end) => body_end,
+            None => {
+                let body_end = self.alloc.inst_builder.current_pc();
+                self.alloc
+                    .inst_builder
+                    .finish_try_block(try_frame.try_block(), try_frame.start());
+                try_frame.update_body_end(body_end);
+                body_end
+            }
+        };
+        let target = HandlerTarget::new(
+            self.alloc.inst_builder.current_pc().into_u32(),
+            try_frame.stack_height(),
+        );
+        let kind = match tag_index {
+            Some(tag_index) => {
+                ExceptionHandlerKind::Catch(bytecode::TagIdx::from(tag_index), target)
+            }
+            None => ExceptionHandlerKind::CatchAll(target),
+        };
+        self.alloc
+            .inst_builder
+            .push_handler(try_frame.start(), body_end, kind);
+        self.is_fuel_metering_enabled().then(|| {
+            let consume_fuel = self
+                .alloc
+                .inst_builder
+                .push_inst(self.make_consume_fuel_base());
+            try_frame.update_consume_fuel_instr(consume_fuel);
+        });
+        // The hidden value referring to the caught exception is pushed
+        // right on top of the value stack height of the `try` block.
+        self.stack_height.shrink_to(try_frame.stack_height());
+        self.stack_height.push();
+        if let Some(tag_index) = tag_index {
+            for _ in 0..self.len_tag_params(tag_index) {
+                self.stack_height.push();
+            }
+        }
+        self.alloc.control_frames.push_frame(try_frame);
+        // We can reset reachability now since the parent `try` block was reachable.
+        self.reachable = true;
+        Ok(())
+    }
+
     /// Called when translating an unsupported Wasm operator.
     ///
     /// # Note
@@ -809,6 +1119,26 @@ macro_rules! impl_visit_operator {
     ( @tail_call $($rest:tt)* ) => {
         impl_visit_operator!(@@skipped $($rest)*);
     };
+    ( @exceptions $($rest:tt)* ) => {
+        impl_visit_operator!(@@skipped $($rest)*);
+    };
+    ( @simd $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        // We skip Wasm `simd` operators directly since there are too many of them
+        // for the macro recursion limit when forwarding to `@@skipped`.
+        //
+        // Without the `simd` crate feature they are unsupported instead.
+        #[cfg(not(feature = "simd"))]
+        #[allow(unused_variables)]
+        fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
+            self.unsupported_operator(stringify!($op))
+        }
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        // We skip Wasm `threads` operators directly since there are too many of them
+        // for the macro recursion limit when forwarding to `@@skipped`.
+        impl_visit_operator!($($rest)*);
+    };
     ( @@skipped $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
         // We skip Wasm operators that we already implement manually.
         impl_visit_operator!($($rest)*);
@@ -876,6 +1206,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator<'a> {
             let stack_height = self.frame_stack_height(block_type);
             let header = self.alloc.inst_builder.new_label();
             self.alloc.inst_builder.pin_label(header);
+            self.push_check_epoch();
             let consume_fuel = self.is_fuel_metering_enabled().then(|| {
                 self.alloc
                     .inst_builder
@@ -991,7 +1322,137 @@ impl<'a> VisitOperator<'a> for FuncTranslator<'a> {
         Ok(())
     }
 
+    fn visit_try(&mut self, block_type: wasmparser::BlockType) -> Result<(), TranslationError> {
+        let block_type = BlockType::new(block_type, self.res);
+        if self.is_reachable() {
+            // Inherit `ConsumeFuel` instruction from parent control frame.
+            // This is applicable since the Wasm `try` body is executed like a Wasm `block`.
+            let consume_fuel = self.alloc.control_frames.last().consume_fuel_instr();
+            let stack_height = self.frame_stack_height(block_type);
+            let end_label = self.alloc.inst_builder.new_label();
+            let try_block = self.alloc.inst_builder.new_try_block();
+            let start = self.alloc.inst_builder.current_pc();
+            self.alloc.control_frames.push_frame(TryControlFrame::new(
+                block_type,
+                end_label,
+                stack_height,
+                try_block,
+                start,
+                consume_fuel,
+            ));
+        } else {
+            self.alloc
+                .control_frames
+                .push_frame(UnreachableControlFrame::new(
+                    ControlFrameKind::Try,
+                    block_type,
+                ));
+        }
+        Ok(())
+    }
+
+    fn visit_catch(&mut self, tag_index: u32) -> Result<(), TranslationError> {
+        self.translate_catch(Some(tag_index))
+    }
+
+    fn visit_catch_all(&mut self) -> Result<(), TranslationError> {
+        self.translate_catch(None)
+    }
+
+    fn visit_delegate(&mut self, relative_depth: u32) -> Result<(), TranslationError> {
+        let try_frame = match self.alloc.control_frames.last() {
+            ControlFrame::Try(try_frame) => *try_frame,
+            ControlFrame::Unreachable(frame) if matches!(frame.kind(), ControlFrameKind::Try) => {
+                // Encountered `delegate` for unreachable `try` block.
+                return self.visit_end();
+            }
+            unexpected => panic!(
+                "expected `try` control flow frame on top \
+                for `delegate` but found: {unexpected:?}",
+            ),
+        };
+        // Exceptions are delegated to the `try` block at `relative_depth`
+        // relative to the enclosing control frames of the ended `try` block.
+        // Since exceptions are thrown at that `try` block its own handlers only
+        // apply if it is not already in one of its `catch` or `catch_all` blocks.
+        // Otherwise its enclosing `try` blocks are searched instead.
+        // Exceptions are propagated to the caller if there is no such `try` block.
+        let target = (relative_depth + 1..=self.max_depth()).find_map(|depth| {
+            match self.alloc.control_frames.nth_back(depth) {
+                ControlFrame::Try(frame) if !frame.is_catching() => Some(frame.try_block()),
+                _ => None,
+            }
+        });
+        let end = self.alloc.inst_builder.current_pc();
+        self.alloc
+            .inst_builder
+            .finish_try_block(try_frame.try_block(), try_frame.start());
+        self.alloc
+            .inst_builder
+            .push_delegate_handler(try_frame.start(), end, target);
+        // From here on the `try` block ends just like a Wasm `block`.
+        self.alloc.control_frames.pop_frame();
+        self.alloc.control_frames.push_frame(BlockControlFrame::new(
+            try_frame.block_type(),
+            try_frame.end_label(),
+            try_frame.stack_height(),
+            try_frame.consume_fuel_instr(),
+        ));
+        self.visit_end()
+    }
+
+    fn visit_throw(&mut self, tag_index: u32) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            builder.bump_fuel_consumption(builder.fuel_costs().base)?;
+            builder
+                .stack_height
+                .pop_n(builder.len_tag_params(tag_index));
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::Throw(bytecode::TagIdx::from(tag_index)));
+            builder.reachable = false;
+            Ok(())
+        })
+    }
+
+    fn visit_rethrow(&mut self, relative_depth: u32) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            builder.bump_fuel_consumption(builder.fuel_costs().base)?;
+            // The hidden value referring to the caught exception is
+            // located at the value stack height of the `try` block.
+            let catch_height = builder
+                .alloc
+                .control_frames
+                .nth_back(relative_depth)
+                .stack_height()
+                .expect("frame is reachable");
+            let depth = builder.stack_height.height() - catch_height;
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::Rethrow(bytecode::LocalDepth::from(depth)));
+            builder.reachable = false;
+            Ok(())
+        })
+    }
+
     fn visit_end(&mut self) -> Result<(), TranslationError> {
+        if let ControlFrame::Try(try_frame) = self.alloc.control_frames.last() {
+            match try_frame.is_catching() {
+                true if self.is_reachable() => {
+                    // Drop the hidden value referring to the caught exception.
+                    self.translate_branch_to_try_end()?;
+                }
+                true => {}
+                false => {
+                    // The `try` block has no `catch` or `catch_all` handlers.
+                    self.alloc
+                        .inst_builder
+                        .finish_try_block(try_frame.try_block(), try_frame.start());
+                }
+            }
+        }
         let frame = self.alloc.control_frames.last();
         if let ControlFrame::If(if_frame) = &frame {
             // At this point we can resolve the `Else` label.
@@ -1548,12 +2009,11 @@ impl<'a> VisitOperator<'a> for FuncTranslator<'a> {
         self.translate_if_reachable(|builder| {
             builder.bump_fuel_consumption(builder.fuel_costs().entity)?;
             let memory_idx = MemoryIdx::from(memory_idx);
-            debug_assert_eq!(memory_idx.into_u32(), DEFAULT_MEMORY_INDEX);
             builder.stack_height.push();
             builder
                 .alloc
                 .inst_builder
-                .push_inst(Instruction::MemorySize);
+                .push_inst(Instruction::MemorySize(memory_idx));
             Ok(())
         })
     }
@@ -1564,12 +2024,12 @@ impl<'a> VisitOperator<'a> for FuncTranslator<'a> {
         _mem_byte: u8,
     ) -> Result<(), TranslationError> {
         self.translate_if_reachable(|builder| {
-            debug_assert_eq!(memory_index, DEFAULT_MEMORY_INDEX);
             builder.bump_fuel_consumption(builder.fuel_costs().entity)?;
+            let memory_index = MemoryIdx::from(memory_index);
             builder
                 .alloc
                 .inst_builder
-                .push_inst(Instruction::MemoryGrow);
+                .push_inst(Instruction::MemoryGrow(memory_index));
             Ok(())
         })
     }
@@ -1580,40 +2040,49 @@ impl<'a> VisitOperator<'a> for FuncTranslator<'a> {
         memory_index: u32,
     ) -> Result<(), TranslationError> {
         self.translate_if_reachable(|builder| {
-            debug_assert_eq!(memory_index, DEFAULT_MEMORY_INDEX);
             builder.bump_fuel_consumption(builder.fuel_costs().entity)?;
             builder.stack_height.pop3();
+            let segment_index = DataSegmentIdx::from(segment_index);
+            let memory_index = MemoryIdx::from(memory_index);
             builder
                 .alloc
                 .inst_builder
-                .push_inst(Instruction::MemoryInit(DataSegmentIdx::from(segment_index)));
+                .push_inst(Instruction::MemoryInit(segment_index));
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::MemorySize(memory_index));
             Ok(())
         })
     }
 
     fn visit_memory_fill(&mut self, memory_index: u32) -> Result<(), TranslationError> {
         self.translate_if_reachable(|builder| {
-            debug_assert_eq!(memory_index, DEFAULT_MEMORY_INDEX);
             builder.bump_fuel_consumption(builder.fuel_costs().entity)?;
             builder.stack_height.pop3();
+            let memory_index = MemoryIdx::from(memory_index);
             builder
                 .alloc
                 .inst_builder
-                .push_inst(Instruction::MemoryFill);
+                .push_inst(Instruction::MemoryFill(memory_index));
             Ok(())
         })
     }
 
     fn visit_memory_copy(&mut self, dst_mem: u32, src_mem: u32) -> Result<(), TranslationError> {
         self.translate_if_reachable(|builder| {
-            debug_assert_eq!(dst_mem, DEFAULT_MEMORY_INDEX);
-            debug_assert_eq!(src_mem, DEFAULT_MEMORY_INDEX);
             builder.bump_fuel_consumption(builder.fuel_costs().entity)?;
             builder.stack_height.pop3();
+            let dst = MemoryIdx::from(dst_mem);
+            let src = MemoryIdx::from(src_mem);
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::MemoryCopy(dst));
             builder
                 .alloc
                 .inst_builder
-                .push_inst(Instruction::MemoryCopy);
+                .push_inst(Instruction::MemorySize(src));
             Ok(())
         })
     }
@@ -2336,4 +2805,1739 @@ impl<'a> VisitOperator<'a> for FuncTranslator<'a> {
     fn visit_i64_trunc_sat_f64_u(&mut self) -> Result<(), TranslationError> {
         self.translate_conversion(ValueType::F64, ValueType::I64, Instruction::I64TruncSatF64U)
     }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load(&mut self, memarg: wasmparser::MemArg) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load8x8_s(&mut self, memarg: wasmparser::MemArg) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load8x8S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load8x8_u(&mut self, memarg: wasmparser::MemArg) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load8x8U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load16x4_s(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load16x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load16x4_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load16x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load32x2_s(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load32x2S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load32x2_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load32x2U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load8_splat(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load8Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load16_splat(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load16Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load32_splat(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load32Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load64_splat(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load64Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load32_zero(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load32Zero)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load64_zero(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::V128, Instruction::V128Load64Zero)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_store(&mut self, memarg: wasmparser::MemArg) -> Result<(), TranslationError> {
+        self.translate_store(memarg, ValueType::V128, Instruction::V128Store)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load8_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+    ) -> Result<(), TranslationError> {
+        self.translate_load_lane(memarg, lane, Instruction::V128Load8Lane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load16_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+    ) -> Result<(), TranslationError> {
+        self.translate_load_lane(memarg, lane, Instruction::V128Load16Lane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load32_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+    ) -> Result<(), TranslationError> {
+        self.translate_load_lane(memarg, lane, Instruction::V128Load32Lane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_load64_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+    ) -> Result<(), TranslationError> {
+        self.translate_load_lane(memarg, lane, Instruction::V128Load64Lane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_store8_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+    ) -> Result<(), TranslationError> {
+        self.translate_store_lane(memarg, lane, Instruction::V128Store8Lane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_store16_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+    ) -> Result<(), TranslationError> {
+        self.translate_store_lane(memarg, lane, Instruction::V128Store16Lane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_store32_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+    ) -> Result<(), TranslationError> {
+        self.translate_store_lane(memarg, lane, Instruction::V128Store32Lane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_store64_lane(
+        &mut self,
+        memarg: wasmparser::MemArg,
+        lane: u8,
+    ) -> Result<(), TranslationError> {
+        self.translate_store_lane(memarg, lane, Instruction::V128Store64Lane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_const(&mut self, value: wasmparser::V128) -> Result<(), TranslationError> {
+        self.translate_const_ref(V128::from(u128::from_le_bytes(*value.bytes())))
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_shuffle(&mut self, lanes: [u8; 16]) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            builder.bump_fuel_consumption(builder.fuel_costs().base)?;
+            builder.stack_height.pop2();
+            builder.stack_height.push();
+            let selector = UntypedValue::from(V128::from(u128::from_le_bytes(lanes)));
+            let cref = builder.engine().alloc_const(selector)?;
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::I8x16Shuffle(cref));
+            Ok(())
+        })
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_extract_lane_s(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_extract_lane(lane, Instruction::I8x16ExtractLaneS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_extract_lane_u(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_extract_lane(lane, Instruction::I8x16ExtractLaneU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_replace_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_replace_lane(lane, Instruction::I8x16ReplaceLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extract_lane_s(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_extract_lane(lane, Instruction::I16x8ExtractLaneS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extract_lane_u(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_extract_lane(lane, Instruction::I16x8ExtractLaneU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_replace_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_replace_lane(lane, Instruction::I16x8ReplaceLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extract_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_extract_lane(lane, Instruction::I32x4ExtractLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_replace_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_replace_lane(lane, Instruction::I32x4ReplaceLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extract_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_extract_lane(lane, Instruction::I64x2ExtractLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_replace_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_replace_lane(lane, Instruction::I64x2ReplaceLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_extract_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_extract_lane(lane, Instruction::F32x4ExtractLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_replace_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_replace_lane(lane, Instruction::F32x4ReplaceLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_extract_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_extract_lane(lane, Instruction::F64x2ExtractLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_replace_lane(&mut self, lane: u8) -> Result<(), TranslationError> {
+        self.translate_replace_lane(lane, Instruction::F64x2ReplaceLane)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_swizzle(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16Swizzle)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_splat(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::I32, ValueType::V128, Instruction::I8x16Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_splat(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::I32, ValueType::V128, Instruction::I16x8Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_splat(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::I32, ValueType::V128, Instruction::I32x4Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_splat(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::I64, ValueType::V128, Instruction::I64x2Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_splat(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::F32, ValueType::V128, Instruction::F32x4Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_splat(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::F64, ValueType::V128, Instruction::F64x2Splat)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_eq(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16Eq)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_ne(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16Ne)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_lt_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16LtS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_lt_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16LtU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_gt_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16GtS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_gt_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16GtU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_le_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16LeS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_le_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16LeU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_ge_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16GeS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_ge_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I8x16GeU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_eq(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8Eq)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_ne(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8Ne)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_lt_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8LtS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_lt_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8LtU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_gt_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8GtS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_gt_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8GtU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_le_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8LeS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_le_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8LeU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_ge_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8GeS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_ge_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I16x8GeU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_eq(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4Eq)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_ne(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4Ne)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_lt_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4LtS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_lt_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4LtU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_gt_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4GtS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_gt_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4GtU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_le_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4LeS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_le_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4LeU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_ge_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4GeS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_ge_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I32x4GeU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_eq(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I64x2Eq)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_ne(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I64x2Ne)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_lt_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I64x2LtS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_gt_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I64x2GtS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_le_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I64x2LeS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_ge_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::I64x2GeS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_eq(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F32x4Eq)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_ne(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F32x4Ne)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_lt(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F32x4Lt)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_gt(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F32x4Gt)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_le(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F32x4Le)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_ge(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F32x4Ge)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_eq(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F64x2Eq)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_ne(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F64x2Ne)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_lt(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F64x2Lt)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_gt(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F64x2Gt)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_le(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F64x2Le)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_ge(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_cmp(ValueType::V128, Instruction::F64x2Ge)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_not(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::V128Not)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_and(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::V128And)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_andnot(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::V128AndNot)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_or(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::V128Or)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_xor(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::V128Xor)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_bitselect(&mut self) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            builder.bump_fuel_consumption(builder.fuel_costs().base)?;
+            builder.stack_height.pop3();
+            builder.stack_height.push();
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::V128Bitselect);
+            Ok(())
+        })
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_v128_any_true(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::V128AnyTrue)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_abs(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I8x16Abs)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_neg(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I8x16Neg)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_popcnt(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I8x16Popcnt)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_all_true(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::I8x16AllTrue)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_bitmask(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::I8x16Bitmask)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_narrow_i16x8_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16NarrowI16x8S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_narrow_i16x8_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16NarrowI16x8U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_shl(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16Shl)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_shr_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16ShrS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_shr_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16ShrU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_add(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16Add)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_add_sat_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16AddSatS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_add_sat_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16AddSatU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_sub(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16Sub)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_sub_sat_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16SubSatS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_sub_sat_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16SubSatU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_min_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16MinS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_min_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16MinU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_max_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16MaxS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_max_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16MaxU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i8x16_avgr_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I8x16AvgrU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extadd_pairwise_i8x16_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I16x8ExtAddPairwiseI8x16S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extadd_pairwise_i8x16_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I16x8ExtAddPairwiseI8x16U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_abs(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I16x8Abs)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_neg(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I16x8Neg)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_q15mulr_sat_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8Q15MulrSatS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_all_true(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::I16x8AllTrue)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_bitmask(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::I16x8Bitmask)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_narrow_i32x4_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8NarrowI32x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_narrow_i32x4_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8NarrowI32x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extend_low_i8x16_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I16x8ExtendLowI8x16S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extend_high_i8x16_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I16x8ExtendHighI8x16S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extend_low_i8x16_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I16x8ExtendLowI8x16U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extend_high_i8x16_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I16x8ExtendHighI8x16U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_shl(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8Shl)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_shr_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8ShrS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_shr_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8ShrU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_add(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8Add)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_add_sat_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8AddSatS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_add_sat_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8AddSatU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_sub(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8Sub)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_sub_sat_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8SubSatS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_sub_sat_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8SubSatU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_mul(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8Mul)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_min_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8MinS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_min_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8MinU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_max_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8MaxS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_max_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8MaxU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_avgr_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8AvgrU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extmul_low_i8x16_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8ExtMulLowI8x16S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extmul_high_i8x16_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8ExtMulHighI8x16S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extmul_low_i8x16_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8ExtMulLowI8x16U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i16x8_extmul_high_i8x16_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I16x8ExtMulHighI8x16U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extadd_pairwise_i16x8_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4ExtAddPairwiseI16x8S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extadd_pairwise_i16x8_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4ExtAddPairwiseI16x8U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_abs(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4Abs)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_neg(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4Neg)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_all_true(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::I32x4AllTrue)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_bitmask(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::I32x4Bitmask)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extend_low_i16x8_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4ExtendLowI16x8S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extend_high_i16x8_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4ExtendHighI16x8S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extend_low_i16x8_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4ExtendLowI16x8U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extend_high_i16x8_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4ExtendHighI16x8U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_shl(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4Shl)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_shr_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4ShrS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_shr_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4ShrU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_add(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4Add)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_sub(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4Sub)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_mul(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4Mul)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_min_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4MinS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_min_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4MinU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_max_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4MaxS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_max_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4MaxU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_dot_i16x8_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4DotI16x8S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extmul_low_i16x8_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4ExtMulLowI16x8S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extmul_high_i16x8_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4ExtMulHighI16x8S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extmul_low_i16x8_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4ExtMulLowI16x8U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_extmul_high_i16x8_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I32x4ExtMulHighI16x8U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_abs(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I64x2Abs)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_neg(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I64x2Neg)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_all_true(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::I64x2AllTrue)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_bitmask(&mut self) -> Result<(), TranslationError> {
+        self.translate_conversion(ValueType::V128, ValueType::I32, Instruction::I64x2Bitmask)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extend_low_i32x4_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I64x2ExtendLowI32x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extend_high_i32x4_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I64x2ExtendHighI32x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extend_low_i32x4_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I64x2ExtendLowI32x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extend_high_i32x4_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I64x2ExtendHighI32x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_shl(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2Shl)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_shr_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2ShrS)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_shr_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2ShrU)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_add(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2Add)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_sub(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2Sub)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_mul(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2Mul)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extmul_low_i32x4_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2ExtMulLowI32x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extmul_high_i32x4_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2ExtMulHighI32x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extmul_low_i32x4_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2ExtMulLowI32x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i64x2_extmul_high_i32x4_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::I64x2ExtMulHighI32x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_ceil(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4Ceil)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_floor(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4Floor)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_trunc(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4Trunc)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_nearest(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4Nearest)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_abs(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4Abs)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_neg(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4Neg)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_sqrt(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4Sqrt)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_add(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F32x4Add)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_sub(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F32x4Sub)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_mul(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F32x4Mul)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_div(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F32x4Div)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_min(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F32x4Min)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_max(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F32x4Max)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_pmin(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F32x4PMin)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_pmax(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F32x4PMax)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_ceil(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2Ceil)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_floor(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2Floor)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_trunc(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2Trunc)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_nearest(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2Nearest)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_abs(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2Abs)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_neg(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2Neg)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_sqrt(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2Sqrt)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_add(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F64x2Add)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_sub(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F64x2Sub)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_mul(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F64x2Mul)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_div(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F64x2Div)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_min(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F64x2Min)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_max(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F64x2Max)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_pmin(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F64x2PMin)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_pmax(&mut self) -> Result<(), TranslationError> {
+        self.translate_binary_operation(ValueType::V128, Instruction::F64x2PMax)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_trunc_sat_f32x4_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4TruncSatF32x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_trunc_sat_f32x4_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4TruncSatF32x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_convert_i32x4_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4ConvertI32x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_convert_i32x4_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4ConvertI32x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_trunc_sat_f64x2_s_zero(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4TruncSatF64x2SZero)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_i32x4_trunc_sat_f64x2_u_zero(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::I32x4TruncSatF64x2UZero)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_convert_low_i32x4_s(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2ConvertLowI32x4S)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_convert_low_i32x4_u(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2ConvertLowI32x4U)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f32x4_demote_f64x2_zero(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F32x4DemoteF64x2Zero)
+    }
+
+    #[cfg(feature = "simd")]
+    fn visit_f64x2_promote_low_f32x4(&mut self) -> Result<(), TranslationError> {
+        self.translate_unary_operation(ValueType::V128, Instruction::F64x2PromoteLowF32x4)
+    }
+
+    fn visit_memory_atomic_notify(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::MemoryAtomicNotify)
+    }
+
+    fn visit_memory_atomic_wait32(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::MemoryAtomicWait32)
+    }
+
+    fn visit_memory_atomic_wait64(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::MemoryAtomicWait64)
+    }
+
+    fn visit_atomic_fence(&mut self) -> Result<(), TranslationError> {
+        self.translate_if_reachable(|builder| {
+            builder.bump_fuel_consumption(builder.fuel_costs().base)?;
+            builder
+                .alloc
+                .inst_builder
+                .push_inst(Instruction::AtomicFence);
+            Ok(())
+        })
+    }
+
+    fn visit_i32_atomic_load(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::I32, Instruction::I32AtomicLoad)
+    }
+
+    fn visit_i64_atomic_load(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::I64, Instruction::I64AtomicLoad)
+    }
+
+    fn visit_i32_atomic_load8_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::I32, Instruction::I32AtomicLoad8U)
+    }
+
+    fn visit_i32_atomic_load16_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::I32, Instruction::I32AtomicLoad16U)
+    }
+
+    fn visit_i64_atomic_load8_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::I64, Instruction::I64AtomicLoad8U)
+    }
+
+    fn visit_i64_atomic_load16_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::I64, Instruction::I64AtomicLoad16U)
+    }
+
+    fn visit_i64_atomic_load32_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_load(memarg, ValueType::I64, Instruction::I64AtomicLoad32U)
+    }
+
+    fn visit_i32_atomic_store(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_store(memarg, ValueType::I32, Instruction::I32AtomicStore)
+    }
+
+    fn visit_i64_atomic_store(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_store(memarg, ValueType::I64, Instruction::I64AtomicStore)
+    }
+
+    fn visit_i32_atomic_store8(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_store(memarg, ValueType::I32, Instruction::I32AtomicStore8)
+    }
+
+    fn visit_i32_atomic_store16(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_store(memarg, ValueType::I32, Instruction::I32AtomicStore16)
+    }
+
+    fn visit_i64_atomic_store8(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_store(memarg, ValueType::I64, Instruction::I64AtomicStore8)
+    }
+
+    fn visit_i64_atomic_store16(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_store(memarg, ValueType::I64, Instruction::I64AtomicStore16)
+    }
+
+    fn visit_i64_atomic_store32(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_store(memarg, ValueType::I64, Instruction::I64AtomicStore32)
+    }
+
+    fn visit_i32_atomic_rmw_add(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmwAdd)
+    }
+
+    fn visit_i64_atomic_rmw_add(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmwAdd)
+    }
+
+    fn visit_i32_atomic_rmw8_add_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw8AddU)
+    }
+
+    fn visit_i32_atomic_rmw16_add_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw16AddU)
+    }
+
+    fn visit_i64_atomic_rmw8_add_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw8AddU)
+    }
+
+    fn visit_i64_atomic_rmw16_add_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw16AddU)
+    }
+
+    fn visit_i64_atomic_rmw32_add_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw32AddU)
+    }
+
+    fn visit_i32_atomic_rmw_sub(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmwSub)
+    }
+
+    fn visit_i64_atomic_rmw_sub(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmwSub)
+    }
+
+    fn visit_i32_atomic_rmw8_sub_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw8SubU)
+    }
+
+    fn visit_i32_atomic_rmw16_sub_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw16SubU)
+    }
+
+    fn visit_i64_atomic_rmw8_sub_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw8SubU)
+    }
+
+    fn visit_i64_atomic_rmw16_sub_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw16SubU)
+    }
+
+    fn visit_i64_atomic_rmw32_sub_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw32SubU)
+    }
+
+    fn visit_i32_atomic_rmw_and(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmwAnd)
+    }
+
+    fn visit_i64_atomic_rmw_and(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmwAnd)
+    }
+
+    fn visit_i32_atomic_rmw8_and_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw8AndU)
+    }
+
+    fn visit_i32_atomic_rmw16_and_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw16AndU)
+    }
+
+    fn visit_i64_atomic_rmw8_and_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw8AndU)
+    }
+
+    fn visit_i64_atomic_rmw16_and_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw16AndU)
+    }
+
+    fn visit_i64_atomic_rmw32_and_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw32AndU)
+    }
+
+    fn visit_i32_atomic_rmw_or(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmwOr)
+    }
+
+    fn visit_i64_atomic_rmw_or(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmwOr)
+    }
+
+    fn visit_i32_atomic_rmw8_or_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw8OrU)
+    }
+
+    fn visit_i32_atomic_rmw16_or_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw16OrU)
+    }
+
+    fn visit_i64_atomic_rmw8_or_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw8OrU)
+    }
+
+    fn visit_i64_atomic_rmw16_or_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw16OrU)
+    }
+
+    fn visit_i64_atomic_rmw32_or_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw32OrU)
+    }
+
+    fn visit_i32_atomic_rmw_xor(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmwXor)
+    }
+
+    fn visit_i64_atomic_rmw_xor(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmwXor)
+    }
+
+    fn visit_i32_atomic_rmw8_xor_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw8XorU)
+    }
+
+    fn visit_i32_atomic_rmw16_xor_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw16XorU)
+    }
+
+    fn visit_i64_atomic_rmw8_xor_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw8XorU)
+    }
+
+    fn visit_i64_atomic_rmw16_xor_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw16XorU)
+    }
+
+    fn visit_i64_atomic_rmw32_xor_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw32XorU)
+    }
+
+    fn visit_i32_atomic_rmw_xchg(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmwXchg)
+    }
+
+    fn visit_i64_atomic_rmw_xchg(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmwXchg)
+    }
+
+    fn visit_i32_atomic_rmw8_xchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw8XchgU)
+    }
+
+    fn visit_i32_atomic_rmw16_xchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I32AtomicRmw16XchgU)
+    }
+
+    fn visit_i64_atomic_rmw8_xchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw8XchgU)
+    }
+
+    fn visit_i64_atomic_rmw16_xchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw16XchgU)
+    }
+
+    fn visit_i64_atomic_rmw32_xchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_rmw(memarg, Instruction::I64AtomicRmw32XchgU)
+    }
+
+    fn visit_i32_atomic_rmw_cmpxchg(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::I32AtomicRmwCmpxchg)
+    }
+
+    fn visit_i64_atomic_rmw_cmpxchg(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::I64AtomicRmwCmpxchg)
+    }
+
+    fn visit_i32_atomic_rmw8_cmpxchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::I32AtomicRmw8CmpxchgU)
+    }
+
+    fn visit_i32_atomic_rmw16_cmpxchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::I32AtomicRmw16CmpxchgU)
+    }
+
+    fn visit_i64_atomic_rmw8_cmpxchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::I64AtomicRmw8CmpxchgU)
+    }
+
+    fn visit_i64_atomic_rmw16_cmpxchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::I64AtomicRmw16CmpxchgU)
+    }
+
+    fn visit_i64_atomic_rmw32_cmpxchg_u(
+        &mut self,
+        memarg: wasmparser::MemArg,
+    ) -> Result<(), TranslationError> {
+        self.translate_atomic_cmpxchg(memarg, Instruction::I64AtomicRmw32CmpxchgU)
+    }
 }
@@ -10,16 +10,24 @@ mod func_args;
 mod func_builder;
 mod func_types;
 mod resumable;
+mod serialize;
 pub mod stack;
+#[cfg(feature = "trace")]
+mod trace;
 mod traits;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "trace")]
+use self::executor::trace_func_enter;
+#[cfg(feature = "trace")]
+pub use self::trace::{MemoryAccess, Tracer};
 pub use self::{
     bytecode::DropKeep,
     code_map::CompiledFunc,
-    config::{Config, FuelConsumptionMode},
+    config::{CompilationMode, Config, FuelConsumptionMode},
+    const_pool::ConstRef,
     func_builder::{
         FuncBuilder,
         FuncTranslatorAllocations,
@@ -27,36 +35,48 @@ pub use self::{
         RelativeDepth,
         TranslationError,
     },
-    resumable::{ResumableCall, ResumableInvocation, TypedResumableCall, TypedResumableInvocation},
+    resumable::{
+        EpochYieldInvocation,
+        ResumableCall,
+        ResumableInvocation,
+        TypedEpochYieldInvocation,
+        TypedResumableCall,
+        TypedResumableInvocation,
+    },
     stack::StackLimits,
     traits::{CallParams, CallResults},
 };
+pub(crate) use self::{
+    bytecode::IndexSpaces,
+    func_args::{FuncFinished, FuncParams, FuncResults},
+    func_types::DedupFuncType,
+    serialize::ModuleTypes,
+};
 use self::{
     bytecode::Instruction,
     cache::InstanceCache,
-    code_map::CodeMap,
-    const_pool::{ConstPool, ConstPoolView, ConstRef},
+    code_map::{AddressMapping, CodeMap, ExceptionHandler},
+    const_pool::{ConstPool, ConstPoolView},
     executor::{execute_wasm, WasmOutcome},
     func_types::FuncTypeRegistry,
     resumable::ResumableCallBase,
-    stack::{FuncFrame, Stack, ValueStack},
-};
-pub(crate) use self::{
-    func_args::{FuncFinished, FuncParams, FuncResults},
-    func_types::DedupFuncType,
+    stack::{CallStack, FuncFrame, Stack, ValueStack},
 };
 use crate::{
     core::{Trap, TrapCode},
-    func::FuncEntity,
+    func::{AsyncHostCall, FuncEntity},
+    module::{Decoder, Encoder, LazyFunc, SerializationError},
     AsContext,
     AsContextMut,
+    Exception,
     Func,
     FuncType,
     StoreContextMut,
+    StoreInner,
 };
-use alloc::{sync::Arc, vec::Vec};
-use core::sync::atomic::{AtomicU32, Ordering};
-use spin::{Mutex, RwLock};
+use alloc::{string::ToString, sync::Arc, vec::Vec};
+use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use spin::{Mutex, RwLock, RwLockReadGuard};
 use wasmi_arena::{ArenaIndex, GuardedEntity};
 use wasmi_core::UntypedValue;
 
@@ -134,6 +154,27 @@ impl Engine {
         Arc::ptr_eq(&a.inner, &b.inner)
     }
 
+    /// Increments the epoch of the [`Engine`] by one.
+    ///
+    /// # Note
+    ///
+    /// - This is intended to be called periodically, for example from another thread,
+    ///   in order to interrupt Wasm executions that reached the epoch deadline of
+    ///   their [`Store`]. Since an [`Engine`] is cheap to clone it can easily be
+    ///   shared with such a thread.
+    /// - This has no effect on Wasm executions unless epoch interruption
+    ///   has been enabled via [`Config::epoch_interruption`].
+    ///
+    /// [`Store`]: crate::Store
+    pub fn increment_epoch(&self) {
+        self.inner.increment_epoch();
+    }
+
+    /// Returns the current epoch of the [`Engine`].
+    pub(crate) fn current_epoch(&self) -> u64 {
+        self.inner.current_epoch()
+    }
+
     /// Allocates a new function type to the [`Engine`].
     pub(super) fn alloc_func_type(&self, func_type: FuncType) -> DedupFuncType {
         self.inner.alloc_func_type(func_type)
@@ -174,17 +215,50 @@ impl Engine {
     ///
     /// - If `func` is an invalid [`CompiledFunc`] reference for this [`CodeMap`].
     /// - If `func` refers to an already initialized [`CompiledFunc`].
-    pub(super) fn init_func<I>(
+    pub(super) fn init_func<I, H, A>(
         &self,
         func: CompiledFunc,
         len_locals: usize,
         local_stack_height: usize,
         instrs: I,
+        handlers: H,
+        addrs: A,
     ) where
         I: IntoIterator<Item = Instruction>,
+        H: IntoIterator<Item = ExceptionHandler>,
+        A: IntoIterator<Item = AddressMapping>,
     {
-        self.inner
-            .init_func(func, len_locals, local_stack_height, instrs)
+        self.inner.init_func(
+            func,
+            len_locals,
+            local_stack_height,
+            instrs,
+            handlers,
+            addrs,
+        )
+    }
+
+    /// Initializes the uninitialized [`CompiledFunc`] to be translated lazily from `lazy_func`.
+    ///
+    /// # Panics
+    ///
+    /// - If `func` is an invalid [`CompiledFunc`] reference for this [`CodeMap`].
+    /// - If `func` refers to an already initialized [`CompiledFunc`].
+    pub(super) fn init_lazy_func(&self, func: CompiledFunc, lazy_func: LazyFunc) {
+        self.inner.init_lazy_func(func, lazy_func)
+    }
+
+    /// Translates the lazily translated [`CompiledFunc`] unless it has already been translated.
+    ///
+    /// # Errors
+    ///
+    /// If the function body of `func` fails to validate.
+    ///
+    /// # Panics
+    ///
+    /// If `func` is an invalid [`CompiledFunc`] reference for this [`CodeMap`].
+    pub(super) fn translate_func(&self, func: CompiledFunc) -> Result<(), Trap> {
+        self.inner.translate_func(self, func)
     }
 
     /// Resolves the [`CompiledFunc`] to the underlying `wasmi` bytecode instructions.
@@ -207,6 +281,54 @@ impl Engine {
         self.inner.resolve_instr(func_body, index)
     }
 
+    /// Returns the offset of the Wasm operator within the Wasm binary of its [`Module`]
+    /// from which the instruction at `instr_offset` of the [`CompiledFunc`] was translated.
+    ///
+    /// # Panics
+    ///
+    /// If the [`CompiledFunc`] is invalid for the [`Engine`].
+    ///
+    /// [`Module`]: crate::Module
+    pub(crate) fn wasm_offset(
+        &self,
+        func_body: CompiledFunc,
+        instr_offset: usize,
+    ) -> Option<usize> {
+        self.inner.wasm_offset(func_body, instr_offset)
+    }
+
+    /// Encodes the compiled functions `funcs` of a [`Module`] using `encoder`.
+    ///
+    /// [`Module`]: crate::Module
+    pub(super) fn serialize_funcs(&self, funcs: &[CompiledFunc], encoder: &mut Encoder) {
+        self.inner.serialize_funcs(funcs, encoder)
+    }
+
+    /// Decodes the compiled functions `funcs` of a [`Module`] using `decoder`.
+    ///
+    /// The compiled functions have the function types `func_types` and all
+    /// indices of their instructions are bounds checked against `spaces`.
+    ///
+    /// If the function types `types` of the [`Module`] are given the value stack
+    /// heights of the instructions of all compiled functions are verified as well.
+    ///
+    /// # Errors
+    ///
+    /// If the encoded compiled functions are malformed.
+    ///
+    /// [`Module`]: crate::Module
+    pub(super) fn deserialize_funcs(
+        &self,
+        funcs: &[CompiledFunc],
+        func_types: &[DedupFuncType],
+        spaces: &IndexSpaces,
+        types: Option<ModuleTypes>,
+        decoder: &mut Decoder,
+    ) -> Result<(), SerializationError> {
+        self.inner
+            .deserialize_funcs(funcs, func_types, spaces, types, decoder)
+    }
+
     /// Executes the given [`Func`] with parameters `params`.
     ///
     /// Stores the execution result into `results` upon a successful execution.
@@ -313,6 +435,35 @@ impl Engine {
         self.inner.resume_func(ctx, invocation, params, results)
     }
 
+    /// Resumes the given `invocation` that reached the epoch deadline.
+    ///
+    /// Stores the execution result into `results` upon a successful execution.
+    /// If the execution encounters a host trap or reaches the epoch deadline again
+    /// it will return a handle to the user that allows to resume the execution at that point.
+    ///
+    /// # Note
+    ///
+    /// - Assumes that the `results` are well typed.
+    ///   Type checks are done at the [`EpochYieldInvocation::resume`] API.
+    ///
+    /// # Errors
+    ///
+    /// - If the given `results` do not match the the length of the expected results of `func`.
+    /// - When encountering a Wasm trap during the execution of `func`.
+    /// - When `func` is a host function that traps.
+    #[inline]
+    pub(crate) fn resume_func_epoch_yield<T, Results>(
+        &self,
+        ctx: StoreContextMut<T>,
+        invocation: EpochYieldInvocation,
+        results: Results,
+    ) -> Result<ResumableCallBase<<Results as CallResults>::Results>, Trap>
+    where
+        Results: CallResults,
+    {
+        self.inner.resume_func_epoch_yield(ctx, invocation, results)
+    }
+
     /// Recycles the given [`Stack`] for reuse in the [`Engine`].
     pub(crate) fn recycle_stack(&self, stack: Stack) {
         self.inner.recycle_stack(stack)
@@ -332,6 +483,18 @@ pub struct EngineInner {
     /// operate on. Therefore a Wasm engine is required to provide stacks and
     /// ideally recycles old ones since creation of a new stack is rather expensive.
     stacks: Mutex<EngineStacks>,
+    /// Guards the translation of lazily translated functions.
+    ///
+    /// # Note
+    ///
+    /// This makes sure that concurrent executions do not translate the same function twice.
+    translations: Mutex<()>,
+    /// The current epoch of the engine.
+    ///
+    /// # Note
+    ///
+    /// This is only used if epoch interruption is enabled.
+    epoch: AtomicU64,
 }
 
 /// The engine's stacks for reuse.
@@ -380,6 +543,8 @@ impl EngineInner {
             config: *config,
             res: RwLock::new(EngineResources::new()),
             stacks: Mutex::new(EngineStacks::new(config)),
+            translations: Mutex::new(()),
+            epoch: AtomicU64::new(0),
         }
     }
 
@@ -388,6 +553,16 @@ impl EngineInner {
         &self.config
     }
 
+    /// Increments the epoch of the [`EngineInner`] by one.
+    fn increment_epoch(&self) {
+        self.epoch.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current epoch of the [`EngineInner`].
+    fn current_epoch(&self) -> u64 {
+        self.epoch.load(Ordering::Relaxed)
+    }
+
     /// Allocates a new function type to the [`EngineInner`].
     fn alloc_func_type(&self, func_type: FuncType) -> DedupFuncType {
         self.res.write().func_types.alloc_func_type(func_type)
@@ -415,19 +590,65 @@ impl EngineInner {
     ///
     /// - If `func` is an invalid [`CompiledFunc`] reference for this [`CodeMap`].
     /// - If `func` refers to an already initialized [`CompiledFunc`].
-    fn init_func<I>(
+    fn init_func<I, H, A>(
         &self,
         func: CompiledFunc,
         len_locals: usize,
         local_stack_height: usize,
         instrs: I,
+        handlers: H,
+        addrs: A,
     ) where
         I: IntoIterator<Item = Instruction>,
+        H: IntoIterator<Item = ExceptionHandler>,
+        A: IntoIterator<Item = AddressMapping>,
     {
-        self.res
-            .write()
-            .code_map
-            .init_func(func, len_locals, local_stack_height, instrs)
+        self.res.write().code_map.init_func(
+            func,
+            len_locals,
+            local_stack_height,
+            instrs,
+            handlers,
+            addrs,
+        )
+    }
+
+    /// Initializes the uninitialized [`CompiledFunc`] to be translated lazily from `lazy_func`.
+    ///
+    /// # Panics
+    ///
+    /// - If `func` is an invalid [`CompiledFunc`] reference for this [`CodeMap`].
+    /// - If `func` refers to an already initialized [`CompiledFunc`].
+    fn init_lazy_func(&self, func: CompiledFunc, lazy_func: LazyFunc) {
+        self.res.write().code_map.init_lazy_func(func, lazy_func)
+    }
+
+    /// Translates the lazily translated [`CompiledFunc`] unless it has already been translated.
+    ///
+    /// # Errors
+    ///
+    /// If the function body of `func` fails to validate.
+    fn translate_func(&self, engine: &Engine, func: CompiledFunc) -> Result<(), Trap> {
+        if !self.res.read().code_map.header(func).is_uninit() {
+            return Ok(());
+        }
+        let _translations = self.translations.lock();
+        let lazy_func = self.res.write().code_map.take_lazy_func(func)?;
+        let lazy_func = match lazy_func {
+            Some(lazy_func) => lazy_func,
+            None => return Ok(()),
+        };
+        // Note: The translation must not hold the lock of the engine resources
+        //       since it allocates function types, constants and instructions.
+        if let Err(error) = lazy_func.translate(engine, func) {
+            let message = error.to_string().into_boxed_str();
+            self.res
+                .write()
+                .code_map
+                .fail_lazy_func(func, message.clone());
+            return Err(Trap::new(message));
+        }
+        Ok(())
     }
 
     fn resolve_func_type<F, R>(&self, func_type: &DedupFuncType, f: F) -> R
@@ -446,6 +667,30 @@ impl EngineInner {
             .copied()
     }
 
+    fn wasm_offset(&self, func_body: CompiledFunc, instr_offset: usize) -> Option<usize> {
+        self.res
+            .read()
+            .code_map
+            .wasm_offset(func_body, instr_offset)
+    }
+
+    fn serialize_funcs(&self, funcs: &[CompiledFunc], encoder: &mut Encoder) {
+        self.res.read().serialize_funcs(funcs, encoder)
+    }
+
+    fn deserialize_funcs(
+        &self,
+        funcs: &[CompiledFunc],
+        func_types: &[DedupFuncType],
+        spaces: &IndexSpaces,
+        types: Option<ModuleTypes>,
+        decoder: &mut Decoder,
+    ) -> Result<(), SerializationError> {
+        self.res
+            .write()
+            .deserialize_funcs(funcs, func_types, spaces, types, decoder)
+    }
+
     fn execute_func<T, Results>(
         &self,
         ctx: StoreContextMut<T>,
@@ -456,9 +701,8 @@ impl EngineInner {
     where
         Results: CallResults,
     {
-        let res = self.res.read();
         let mut stack = self.stacks.lock().reuse_or_new();
-        let results = EngineExecutor::new(&res, &mut stack)
+        let results = EngineExecutor::new(&self.res, &mut stack)
             .execute_func(ctx, func, params, results)
             .map_err(TaggedTrap::into_trap);
         self.stacks.lock().recycle(stack);
@@ -475,9 +719,8 @@ impl EngineInner {
     where
         Results: CallResults,
     {
-        let res = self.res.read();
         let mut stack = self.stacks.lock().reuse_or_new();
-        let results = EngineExecutor::new(&res, &mut stack).execute_func(
+        let results = EngineExecutor::new(&self.res, &mut stack).execute_func(
             ctx.as_context_mut(),
             func,
             params,
@@ -502,6 +745,9 @@ impl EngineInner {
                 host_trap,
                 stack,
             ))),
+            Err(TaggedTrap::EpochYield) => Ok(ResumableCallBase::EpochYield(
+                EpochYieldInvocation::new(ctx.as_context().store.engine().clone(), *func, stack),
+            )),
         }
     }
 
@@ -515,9 +761,8 @@ impl EngineInner {
     where
         Results: CallResults,
     {
-        let res = self.res.read();
         let host_func = invocation.host_func();
-        let results = EngineExecutor::new(&res, &mut invocation.stack)
+        let results = EngineExecutor::new(&self.res, &mut invocation.stack)
             .resume_func(ctx, host_func, params, results);
         match results {
             Ok(results) => {
@@ -535,6 +780,39 @@ impl EngineInner {
                 invocation.update(host_func, host_trap);
                 Ok(ResumableCallBase::Resumable(invocation))
             }
+            Err(TaggedTrap::EpochYield) => {
+                Ok(ResumableCallBase::EpochYield(invocation.into_epoch_yield()))
+            }
+        }
+    }
+
+    fn resume_func_epoch_yield<T, Results>(
+        &self,
+        ctx: StoreContextMut<T>,
+        mut invocation: EpochYieldInvocation,
+        results: Results,
+    ) -> Result<ResumableCallBase<<Results as CallResults>::Results>, Trap>
+    where
+        Results: CallResults,
+    {
+        let results = EngineExecutor::new(&self.res, &mut invocation.stack)
+            .resume_func_epoch_yield(ctx, results);
+        match results {
+            Ok(results) => {
+                self.stacks.lock().recycle(invocation.take_stack());
+                Ok(ResumableCallBase::Finished(results))
+            }
+            Err(TaggedTrap::Wasm(trap)) => {
+                self.stacks.lock().recycle(invocation.take_stack());
+                Err(trap)
+            }
+            Err(TaggedTrap::Host {
+                host_func,
+                host_trap,
+            }) => Ok(ResumableCallBase::Resumable(
+                invocation.into_host_trap(host_func, host_trap),
+            )),
+            Err(TaggedTrap::EpochYield) => Ok(ResumableCallBase::EpochYield(invocation)),
         }
     }
 
@@ -580,6 +858,8 @@ enum TaggedTrap {
     Wasm(Trap),
     /// The trap is originating from a host function.
     Host { host_func: Func, host_trap: Trap },
+    /// The Wasm execution reached the epoch deadline and yielded.
+    EpochYield,
 }
 
 impl TaggedTrap {
@@ -591,11 +871,25 @@ impl TaggedTrap {
         }
     }
 
+    /// Creates a [`TaggedTrap`] from an error of a host function called without a calling frame.
+    ///
+    /// # Note
+    ///
+    /// Only calls to asynchronous host functions can be resumed in this case
+    /// since their results are the results of the whole function invocation.
+    pub fn root_host(host_func: Func, host_trap: Trap) -> Self {
+        if host_trap.downcast_ref::<AsyncHostCall>().is_some() {
+            return Self::host(host_func, host_trap);
+        }
+        Self::Wasm(host_trap)
+    }
+
     /// Returns the [`Trap`] of the [`TaggedTrap`].
     pub fn into_trap(self) -> Trap {
         match self {
             TaggedTrap::Wasm(trap) => trap,
             TaggedTrap::Host { host_trap, .. } => host_trap,
+            TaggedTrap::EpochYield => TrapCode::EpochDeadlineExceeded.into(),
         }
     }
 }
@@ -616,14 +910,21 @@ impl From<TrapCode> for TaggedTrap {
 #[derive(Debug)]
 pub struct EngineExecutor<'engine> {
     /// Shared and reusable generic engine resources.
-    res: &'engine EngineResources,
+    ///
+    /// # Note
+    ///
+    /// These are locked for the entire execution but unlocked for lazy function
+    /// translations. For [`Engine`]s that translate lazily they are also unlocked
+    /// while host functions execute since those may call Wasm functions that
+    /// require translation.
+    res: &'engine RwLock<EngineResources>,
     /// The value and call stacks.
     stack: &'engine mut Stack,
 }
 
 impl<'engine> EngineExecutor<'engine> {
     /// Creates a new [`EngineExecutor`] with the given [`StackLimits`].
-    fn new(res: &'engine EngineResources, stack: &'engine mut Stack) -> Self {
+    fn new(res: &'engine RwLock<EngineResources>, stack: &'engine mut Stack) -> Self {
         Self { res, stack }
     }
 
@@ -650,17 +951,28 @@ impl<'engine> EngineExecutor<'engine> {
         self.stack.values.extend(params.call_params());
         match ctx.as_context().store.inner.resolve_func(func) {
             FuncEntity::Wasm(wasm_func) => {
-                self.stack
-                    .prepare_wasm_call(wasm_func, &self.res.code_map)?;
-                self.execute_wasm_func(ctx.as_context_mut())?;
+                let mut res = self.res.read();
+                if res.code_map.header(wasm_func.func_body()).is_uninit() {
+                    drop(res);
+                    ctx.as_context()
+                        .store
+                        .engine()
+                        .translate_func(wasm_func.func_body())?;
+                    res = self.res.read();
+                }
+                self.stack.prepare_wasm_call(wasm_func, &res.code_map)?;
+                #[cfg(feature = "trace")]
+                {
+                    let (instance, func_body) = (*wasm_func.instance(), wasm_func.func_body());
+                    trace_func_enter(&mut ctx.store.inner, &instance, func_body);
+                }
+                self.execute_wasm_func(ctx.as_context_mut(), res)?;
             }
             FuncEntity::Host(host_func) => {
                 let host_func = *host_func;
-                self.stack.call_host_as_root(
-                    ctx.as_context_mut(),
-                    host_func,
-                    &self.res.func_types,
-                )?;
+                self.stack
+                    .call_host_as_root(ctx.as_context_mut(), host_func)
+                    .map_err(|trap| TaggedTrap::root_host(*func, trap))?;
             }
         };
         let results = self.write_results_back(results);
@@ -690,11 +1002,39 @@ impl<'engine> EngineExecutor<'engine> {
             .values
             .drop(host_func.ty(ctx.as_context()).params().len());
         self.stack.values.extend(params.call_params());
+        if self.stack.frames.peek().is_some() {
+            let res = self.res.read();
+            self.execute_wasm_func(ctx.as_context_mut(), res)?;
+        }
+        // Note: Without a frame on the call stack the resumed host function was
+        //       called as root and thus its results are the results of the call.
+        let results = self.write_results_back(results);
+        Ok(results)
+    }
+
+    /// Resumes the execution of the top most Wasm function on the [`Stack`]
+    /// after it reached the epoch deadline.
+    ///
+    /// Stores the execution result into `results` upon a successful execution.
+    ///
+    /// # Errors
+    ///
+    /// - If the given `results` do not match the the length of the expected results of `func`.
+    /// - When encountering a Wasm or host trap during the execution of `func`.
+    fn resume_func_epoch_yield<T, Results>(
+        &mut self,
+        mut ctx: StoreContextMut<T>,
+        results: Results,
+    ) -> Result<<Results as CallResults>::Results, TaggedTrap>
+    where
+        Results: CallResults,
+    {
         assert!(
             self.stack.frames.peek().is_some(),
             "a frame must be on the call stack upon resumption"
         );
-        self.execute_wasm_func(ctx.as_context_mut())?;
+        let res = self.res.read();
+        self.execute_wasm_func(ctx.as_context_mut(), res)?;
         let results = self.write_results_back(results);
         Ok(results)
     }
@@ -718,11 +1058,25 @@ impl<'engine> EngineExecutor<'engine> {
 
     /// Executes the top most Wasm function on the [`Stack`] until the [`Stack`] is empty.
     ///
+    /// The locked engine resources `res` are unlocked temporarily for lazy function translations.
+    ///
     /// # Errors
     ///
     /// When encountering a Wasm or host trap during the execution of `func`.
     #[inline(never)]
-    fn execute_wasm_func<T>(&mut self, mut ctx: StoreContextMut<T>) -> Result<(), TaggedTrap> {
+    fn execute_wasm_func<T>(
+        &mut self,
+        mut ctx: StoreContextMut<T>,
+        mut res: RwLockReadGuard<'engine, EngineResources>,
+    ) -> Result<(), TaggedTrap> {
+        let is_lazy = !matches!(
+            ctx.as_context()
+                .store
+                .engine()
+                .config()
+                .get_compilation_mode(),
+            CompilationMode::Eager
+        );
         let mut cache = self
             .stack
             .frames
@@ -730,9 +1084,23 @@ impl<'engine> EngineExecutor<'engine> {
             .map(FuncFrame::instance)
             .map(InstanceCache::from)
             .expect("must have frame on the call stack");
+        let mut exception = None;
         loop {
-            match self.execute_wasm(ctx.as_context_mut(), &mut cache)? {
+            match self.execute_wasm(ctx.as_context_mut(), &res, &mut cache, exception.take())? {
                 WasmOutcome::Return => return Ok(()),
+                WasmOutcome::EpochYield => return Err(TaggedTrap::EpochYield),
+                WasmOutcome::Throw(exception) => {
+                    return Err(TaggedTrap::Wasm(Trap::from(exception)))
+                }
+                WasmOutcome::Translate(func) => {
+                    drop(res);
+                    ctx.as_context().store.engine().translate_func(func)?;
+                    res = self.res.read();
+                    self.stack
+                        .prepare_translated_call(func, cache.instance(), &res.code_map)?;
+                    #[cfg(feature = "trace")]
+                    trace_func_enter(&mut ctx.store.inner, cache.instance(), func);
+                }
                 WasmOutcome::Call {
                     ref host_func,
                     instance,
@@ -742,31 +1110,85 @@ impl<'engine> EngineExecutor<'engine> {
                         FuncEntity::Wasm(_) => unreachable!("`func` must be a host function"),
                         FuncEntity::Host(host_func) => *host_func,
                     };
-                    let result = self.stack.call_host_impl(
-                        ctx.as_context_mut(),
-                        host_func,
-                        Some(&instance),
-                        &self.res.func_types,
-                    );
+                    let result = if is_lazy {
+                        drop(res);
+                        let result = self.stack.call_host_impl(
+                            ctx.as_context_mut(),
+                            host_func,
+                            Some(&instance),
+                        );
+                        res = self.res.read();
+                        result
+                    } else {
+                        self.stack
+                            .call_host_impl(ctx.as_context_mut(), host_func, Some(&instance))
+                    };
                     if self.stack.frames.peek().is_some() {
                         // Case: There is a frame on the call stack.
                         //
                         // This is the default case and we can easily make host function
                         // errors return a resumable call handle.
-                        result.map_err(|trap| TaggedTrap::host(*func, trap))?;
+                        // Exceptions thrown by host functions are instead thrown
+                        // to the calling Wasm function upon continuing execution.
+                        match result {
+                            Err(trap) if trap.downcast_ref::<Exception>().is_some() => {
+                                exception = trap.downcast::<Exception>();
+                            }
+                            result => result.map_err(|trap| {
+                                let trap = Self::with_host_backtrace(
+                                    trap,
+                                    &self.stack.frames,
+                                    &ctx.as_context().store.inner,
+                                    &res.code_map,
+                                );
+                                TaggedTrap::host(*func, trap)
+                            })?,
+                        }
                     } else {
                         // Case: No frame is on the call stack. (edge case)
                         //
                         // This can happen if the host function was called by a tail call.
                         // In this case we treat host function errors the same as if we called
                         // the host function as root and do not allow to resume the call.
-                        result.map_err(TaggedTrap::Wasm)?;
+                        result.map_err(|trap| TaggedTrap::root_host(*func, trap))?;
                     }
                 }
             }
         }
     }
 
+    /// Attaches a [`WasmBacktrace`] of the Wasm functions on the `call_stack` to the `trap` of a host function.
+    ///
+    /// # Note
+    ///
+    /// - If the `trap` already carries a [`WasmBacktrace`], for example because the
+    ///   host function called a trapping Wasm function, the frames are appended to it.
+    /// - Asynchronous host calls are not errors and are thus left untouched.
+    ///
+    /// [`WasmBacktrace`]: crate::core::WasmBacktrace
+    #[cold]
+    fn with_host_backtrace(
+        trap: Trap,
+        call_stack: &CallStack,
+        store: &StoreInner,
+        code_map: &CodeMap,
+    ) -> Trap {
+        if trap.downcast_ref::<AsyncHostCall>().is_some() {
+            return trap;
+        }
+        let callers = call_stack.host_backtrace(store, code_map);
+        let backtrace = match trap.backtrace() {
+            Some(backtrace) => backtrace
+                .frames()
+                .iter()
+                .chain(callers.frames())
+                .cloned()
+                .collect(),
+            None => callers,
+        };
+        trap.with_backtrace(backtrace)
+    }
+
     /// Executes the given function `frame`.
     ///
     /// # Note
@@ -781,31 +1203,43 @@ impl<'engine> EngineExecutor<'engine> {
     fn execute_wasm<T>(
         &mut self,
         ctx: StoreContextMut<T>,
+        res: &EngineResources,
         cache: &mut InstanceCache,
+        exception: Option<Exception>,
     ) -> Result<WasmOutcome, Trap> {
-        /// Converts a [`TrapCode`] into a [`Trap`].
+        /// Converts a [`TrapCode`] into a [`Trap`] with a backtrace of the `call_stack`.
         ///
         /// This function exists for performance reasons since its `#[cold]`
         /// annotation has severe effects on performance.
         #[inline]
         #[cold]
-        fn make_trap(code: TrapCode) -> Trap {
-            code.into()
+        fn make_trap(
+            code: TrapCode,
+            call_stack: &CallStack,
+            store: &StoreInner,
+            code_map: &CodeMap,
+        ) -> Trap {
+            Trap::from(code).with_backtrace(call_stack.backtrace(store, code_map))
         }
 
-        let store_inner = &mut ctx.store.inner;
+        let (store_inner, mut resource_limiter) = ctx.store.store_inner_and_resource_limiter_ref();
         let value_stack = &mut self.stack.values;
         let call_stack = &mut self.stack.frames;
-        let code_map = &self.res.code_map;
-        let const_pool = self.res.const_pool.view();
-        execute_wasm(
+        let exceptions = &mut self.stack.exceptions;
+        let code_map = &res.code_map;
+        call_stack.rebase(code_map);
+        let const_pool = res.const_pool.view();
+        let result = execute_wasm(
             store_inner,
             cache,
             value_stack,
             call_stack,
+            exceptions,
             code_map,
             const_pool,
-        )
-        .map_err(make_trap)
+            &mut resource_limiter,
+            exception,
+        );
+        result.map_err(|code| make_trap(code, call_stack, &ctx.store.inner, code_map))
     }
 }
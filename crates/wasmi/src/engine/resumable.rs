@@ -1,15 +1,20 @@
 use super::Func;
 use crate::{
     engine::Stack,
-    func::CallResultsTuple,
+    func::{AsyncHostCall, CallResultsTuple},
     AsContextMut,
     Engine,
     Error,
     Value,
     WasmResults,
 };
-use core::{fmt, marker::PhantomData, mem::replace, ops::Deref};
-use wasmi_core::Trap;
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::{replace, take},
+    ops::Deref,
+};
+use wasmi_core::{Trap, TrapCode};
 
 /// Returned by [`Engine`] methods for calling a function in a resumable way.
 ///
@@ -26,6 +31,8 @@ pub(crate) enum ResumableCallBase<T> {
     Finished(T),
     /// The resumable call encountered a host error and can be resumed.
     Resumable(ResumableInvocation),
+    /// The resumable call reached the epoch deadline and can be resumed.
+    EpochYield(EpochYieldInvocation),
 }
 
 /// Returned by calling a [`Func`] in a resumable way.
@@ -35,6 +42,10 @@ pub enum ResumableCall {
     Finished,
     /// The resumable call encountered a host error and can be resumed.
     Resumable(ResumableInvocation),
+    /// The resumable call reached the epoch deadline of the [`Store`] and can be resumed.
+    ///
+    /// [`Store`]: crate::Store
+    EpochYield(EpochYieldInvocation),
 }
 
 impl ResumableCall {
@@ -43,6 +54,7 @@ impl ResumableCall {
         match call {
             ResumableCallBase::Finished(()) => Self::Finished,
             ResumableCallBase::Resumable(invocation) => Self::Resumable(invocation),
+            ResumableCallBase::EpochYield(invocation) => Self::EpochYield(invocation),
         }
     }
 }
@@ -124,6 +136,30 @@ impl ResumableInvocation {
         self.host_func = host_func;
         self.host_error = host_error;
     }
+
+    /// Converts the [`ResumableInvocation`] into an [`EpochYieldInvocation`].
+    ///
+    /// This is used when the resumed execution reached the epoch deadline.
+    pub(super) fn into_epoch_yield(mut self) -> EpochYieldInvocation {
+        let stack = self.take_stack();
+        EpochYieldInvocation::new(self.engine.clone(), self.func, stack)
+    }
+
+    /// Takes the [`AsyncHostCall`] out of the host error if any.
+    ///
+    /// Returns `None` if the host error was not caused by calling an asynchronous host function.
+    pub(crate) fn take_async_host_call(&mut self) -> Option<AsyncHostCall> {
+        self.host_error.downcast_mut::<AsyncHostCall>().map(take)
+    }
+
+    /// Consumes the [`ResumableInvocation`] and returns its host error.
+    pub(crate) fn into_host_error(mut self) -> Trap {
+        // Note: The placeholder is never observed since `self` is dropped right after.
+        replace(
+            &mut self.host_error,
+            Trap::from(TrapCode::UnreachableCodeReached),
+        )
+    }
 }
 
 impl Drop for ResumableInvocation {
@@ -203,6 +239,10 @@ pub enum TypedResumableCall<T> {
     Finished(T),
     /// The resumable call encountered a host error and can be resumed.
     Resumable(TypedResumableInvocation<T>),
+    /// The resumable call reached the epoch deadline of the [`Store`] and can be resumed.
+    ///
+    /// [`Store`]: crate::Store
+    EpochYield(TypedEpochYieldInvocation<T>),
 }
 
 impl<Results> TypedResumableCall<Results> {
@@ -213,6 +253,9 @@ impl<Results> TypedResumableCall<Results> {
             ResumableCallBase::Resumable(invocation) => {
                 Self::Resumable(TypedResumableInvocation::new(invocation))
             }
+            ResumableCallBase::EpochYield(invocation) => {
+                Self::EpochYield(TypedEpochYieldInvocation::new(invocation))
+            }
         }
     }
 }
@@ -273,6 +316,20 @@ impl<Results> TypedResumableInvocation<Results> {
     }
 }
 
+impl<Results> TypedResumableInvocation<Results> {
+    /// Takes the [`AsyncHostCall`] out of the host error if any.
+    ///
+    /// Returns `None` if the host error was not caused by calling an asynchronous host function.
+    pub(crate) fn take_async_host_call(&mut self) -> Option<AsyncHostCall> {
+        self.invocation.take_async_host_call()
+    }
+
+    /// Consumes the [`TypedResumableInvocation`] and returns its host error.
+    pub(crate) fn into_host_error(self) -> Trap {
+        self.invocation.into_host_error()
+    }
+}
+
 impl<Results> Deref for TypedResumableInvocation<Results> {
     type Target = ResumableInvocation;
 
@@ -289,3 +346,165 @@ impl<Results> fmt::Debug for TypedResumableInvocation<Results> {
             .finish()
     }
 }
+
+/// State required to resume a [`Func`] invocation that reached the epoch deadline.
+#[derive(Debug)]
+pub struct EpochYieldInvocation {
+    /// The engine in use for the function invokation.
+    ///
+    /// # Note
+    ///
+    /// - This handle is required to resolve the function type
+    ///   of the `func` field as well as in the `Drop` impl to
+    ///   recycle the stack.
+    engine: Engine,
+    /// The underlying root function to be executed.
+    ///
+    /// # Note
+    ///
+    /// The results of this function must always match with the
+    /// results given when resuming the call.
+    func: Func,
+    /// The value and call stack in use by the [`EpochYieldInvocation`].
+    ///
+    /// # Note
+    ///
+    /// - We need to keep the stack around since the user might want to
+    ///   resume the execution.
+    /// - This stack is borrowed from the engine and needs to be given
+    ///   back to the engine when the [`EpochYieldInvocation`] goes out
+    ///   of scope.
+    pub(super) stack: Stack,
+}
+
+impl EpochYieldInvocation {
+    /// Creates a new [`EpochYieldInvocation`].
+    pub(super) fn new(engine: Engine, func: Func, stack: Stack) -> Self {
+        Self {
+            engine,
+            func,
+            stack,
+        }
+    }
+
+    /// Replaces the internal stack with an empty one that has no heap allocations.
+    pub(super) fn take_stack(&mut self) -> Stack {
+        replace(&mut self.stack, Stack::empty())
+    }
+
+    /// Converts the [`EpochYieldInvocation`] into a [`ResumableInvocation`].
+    ///
+    /// This is used when the resumed execution encountered a host error.
+    pub(super) fn into_host_trap(
+        mut self,
+        host_func: Func,
+        host_error: Trap,
+    ) -> ResumableInvocation {
+        let stack = self.take_stack();
+        ResumableInvocation::new(self.engine.clone(), self.func, host_func, host_error, stack)
+    }
+}
+
+impl Drop for EpochYieldInvocation {
+    fn drop(&mut self) {
+        let stack = self.take_stack();
+        self.engine.recycle_stack(stack);
+    }
+}
+
+impl EpochYieldInvocation {
+    /// Resumes the call to the [`Func`].
+    ///
+    /// The result is written back into the `outputs` buffer upon success.
+    ///
+    /// Returns a resumable handle to the function invocation upon
+    /// enountering host errors or reaching the epoch deadline again.
+    ///
+    /// # Note
+    ///
+    /// Users usually want to update the epoch deadline of the [`Store`]
+    /// before resuming the call. Otherwise the execution might yield again
+    /// immediately.
+    ///
+    /// # Errors
+    ///
+    /// - If the function resumption returned a Wasm [`Trap`].
+    /// - If the number of output values does not match the expected number of
+    ///   outputs required by the called function.
+    ///
+    /// [`Store`]: crate::Store
+    pub fn resume<T>(
+        self,
+        mut ctx: impl AsContextMut<UserState = T>,
+        outputs: &mut [Value],
+    ) -> Result<ResumableCall, Error> {
+        self.engine
+            .resolve_func_type(self.func.ty_dedup(ctx.as_context()), |func_type| {
+                func_type.match_results(outputs, false)?;
+                func_type.prepare_outputs(outputs);
+                <Result<(), Error>>::Ok(())
+            })?;
+        self.engine
+            .clone()
+            .resume_func_epoch_yield(ctx.as_context_mut(), self, outputs)
+            .map_err(Into::into)
+            .map(ResumableCall::new)
+    }
+}
+
+/// State required to resume a [`TypedFunc`] invocation that reached the epoch deadline.
+///
+/// [`TypedFunc`]: [`crate::TypedFunc`]
+pub struct TypedEpochYieldInvocation<Results> {
+    invocation: EpochYieldInvocation,
+    /// The parameter and result typed encoded in Rust type system.
+    results: PhantomData<fn() -> Results>,
+}
+
+impl<Results> TypedEpochYieldInvocation<Results> {
+    /// Creates a [`TypedEpochYieldInvocation`] wrapper for the given [`EpochYieldInvocation`].
+    pub(crate) fn new(invocation: EpochYieldInvocation) -> Self {
+        Self {
+            invocation,
+            results: PhantomData,
+        }
+    }
+
+    /// Resumes the call to the [`TypedFunc`].
+    ///
+    /// Returns a resumable handle to the function invocation upon
+    /// enountering host errors or reaching the epoch deadline again.
+    ///
+    /// # Errors
+    ///
+    /// If the function resumption returned a Wasm [`Trap`].
+    ///
+    /// [`TypedFunc`]: [`crate::TypedFunc`]
+    pub fn resume<T>(
+        self,
+        mut ctx: impl AsContextMut<UserState = T>,
+    ) -> Result<TypedResumableCall<Results>, Error>
+    where
+        Results: WasmResults,
+    {
+        self.invocation
+            .engine
+            .clone()
+            .resume_func_epoch_yield(
+                ctx.as_context_mut(),
+                self.invocation,
+                <CallResultsTuple<Results>>::default(),
+            )
+            .map_err(Into::into)
+            .map(TypedResumableCall::new)
+    }
+}
+
+impl<Results> fmt::Debug for TypedEpochYieldInvocation<Results> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedEpochYieldInvocation")
+            .field("invocation", &self.invocation)
+            .field("results", &self.results)
+            .finish()
+    }
+}
@@ -0,0 +1,218 @@
+//! Serialization of the compiled functions of a [`Module`].
+//!
+//! [`Module`]: crate::Module
+
+mod verify;
+
+pub use self::verify::ModuleTypes;
+use self::verify::{verify_instrs, verify_stack_heights, Arity, Frame, ModuleArities};
+use super::{
+    bytecode::{DeserializeContext, IndexSpaces, SerializeContext},
+    code_map::{AddressMapping, ExceptionHandler},
+    const_pool::{ConstPool, ConstRef},
+    CompiledFunc,
+    DedupFuncType,
+    EngineResources,
+    Instruction,
+};
+use crate::module::{Decoder, Encoder, SerializationError};
+use alloc::{collections::BTreeMap, vec::Vec};
+use wasmi_arena::ArenaIndex;
+use wasmi_core::UntypedValue;
+
+/// The [`SerializeContext`] for the compiled functions of a single [`Module`].
+///
+/// [`Module`]: crate::Module
+struct FuncsSerializer<'a> {
+    /// The constant values referenced by the serialized instructions.
+    const_pool: &'a ConstPool,
+    /// The indices of the compiled functions of the [`Module`].
+    ///
+    /// [`Module`]: crate::Module
+    funcs: BTreeMap<usize, u32>,
+}
+
+impl SerializeContext for FuncsSerializer<'_> {
+    fn const_value(&self, cref: ConstRef) -> UntypedValue {
+        self.const_pool
+            .get(cref)
+            .unwrap_or_else(|| panic!("missing constant value for {cref:?}"))
+    }
+
+    fn func_index(&self, func: CompiledFunc) -> u32 {
+        self.funcs
+            .get(&func.into_usize())
+            .copied()
+            .unwrap_or_else(|| panic!("encountered call to foreign compiled function: {func:?}"))
+    }
+}
+
+/// The [`DeserializeContext`] for the compiled functions of a single [`Module`].
+///
+/// [`Module`]: crate::Module
+struct FuncsDeserializer<'a> {
+    /// The constant pool of the [`Engine`](crate::Engine).
+    const_pool: &'a mut ConstPool,
+    /// The compiled functions of the deserialized [`Module`].
+    ///
+    /// [`Module`]: crate::Module
+    funcs: &'a [CompiledFunc],
+    /// The index spaces of the deserialized [`Module`].
+    ///
+    /// [`Module`]: crate::Module
+    spaces: &'a IndexSpaces,
+    /// The maximum value stack height of the deserialized compiled function.
+    max_stack_height: usize,
+}
+
+impl DeserializeContext for FuncsDeserializer<'_> {
+    fn alloc_const(&mut self, value: UntypedValue) -> Result<ConstRef, SerializationError> {
+        self.const_pool
+            .alloc(value)
+            .map_err(|_| SerializationError::malformed("too many constant values"))
+    }
+
+    fn func(&self, index: u32) -> Result<CompiledFunc, SerializationError> {
+        self.funcs
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| SerializationError::malformed("invalid compiled function index"))
+    }
+
+    fn index_spaces(&self) -> &IndexSpaces {
+        self.spaces
+    }
+
+    fn max_stack_height(&self) -> usize {
+        self.max_stack_height
+    }
+}
+
+impl EngineResources {
+    /// Encodes the compiled functions `funcs` of a [`Module`] using `encoder`.
+    ///
+    /// # Panics
+    ///
+    /// If any of the compiled functions refers to a compiled function not in `funcs`.
+    ///
+    /// [`Module`]: crate::Module
+    pub(super) fn serialize_funcs(&self, funcs: &[CompiledFunc], encoder: &mut Encoder) {
+        let ctx = FuncsSerializer {
+            const_pool: &self.const_pool,
+            funcs: funcs
+                .iter()
+                .zip(0_u32..)
+                .map(|(func, index)| (func.into_usize(), index))
+                .collect(),
+        };
+        for &func in funcs {
+            let header = self.code_map.header(func);
+            encoder.write_len(header.len_locals());
+            encoder.write_len(header.max_stack_height() - header.len_locals());
+            let instrs = self.code_map.instrs(func);
+            encoder.write_len(instrs.len());
+            for instr in instrs {
+                instr.serialize(encoder, &ctx);
+            }
+            let handlers = self.code_map.handlers(func);
+            encoder.write_len(handlers.len());
+            for handler in handlers {
+                handler.serialize(encoder);
+            }
+            let addrs = self.code_map.address_map(func);
+            encoder.write_len(addrs.len());
+            for mapping in addrs {
+                mapping.serialize(encoder);
+            }
+        }
+    }
+
+    /// Decodes the compiled functions `funcs` of a [`Module`] using `decoder`.
+    ///
+    /// The compiled functions have the function types `func_types` and all
+    /// indices of their instructions are bounds checked against `spaces`.
+    ///
+    /// If the function types `types` of the [`Module`] are given the value stack
+    /// heights of the instructions of all compiled functions are verified as well.
+    ///
+    /// # Errors
+    ///
+    /// If the encoded compiled functions are malformed.
+    ///
+    /// [`Module`]: crate::Module
+    pub(super) fn deserialize_funcs(
+        &mut self,
+        funcs: &[CompiledFunc],
+        func_types: &[DedupFuncType],
+        spaces: &IndexSpaces,
+        types: Option<ModuleTypes>,
+        decoder: &mut Decoder,
+    ) -> Result<(), SerializationError> {
+        assert_eq!(funcs.len(), func_types.len());
+        let arities =
+            types.map(|types| ModuleArities::new(&self.func_types, types, funcs, func_types));
+        let mut instrs = Vec::new();
+        let mut handlers = Vec::new();
+        let mut addrs = Vec::new();
+        for (&func, func_type) in funcs.iter().zip(func_types) {
+            let arity = Arity::from(self.func_types.resolve_func_type(func_type));
+            let len_params = arity.params;
+            let len_locals = decoder.read_len()?;
+            let local_stack_height = decoder.read_len()?;
+            let max_stack_height = len_locals
+                .checked_add(local_stack_height)
+                .and_then(|height| height.checked_add(len_params))
+                .ok_or_else(|| SerializationError::malformed("invalid stack height"))?;
+            let len_instrs = decoder.read_items_len()?;
+            if len_instrs == 0 {
+                return Err(SerializationError::malformed("empty function body"));
+            }
+            let mut ctx = FuncsDeserializer {
+                const_pool: &mut self.const_pool,
+                funcs,
+                spaces,
+                max_stack_height,
+            };
+            instrs.clear();
+            for _ in 0..len_instrs {
+                instrs.push(Instruction::deserialize(decoder, &mut ctx)?);
+            }
+            let len_handlers = decoder.read_items_len()?;
+            handlers.clear();
+            for _ in 0..len_handlers {
+                handlers.push(ExceptionHandler::deserialize(
+                    decoder,
+                    len_instrs as u32,
+                    len_handlers as u32,
+                    spaces.tags,
+                    max_stack_height,
+                )?);
+            }
+            verify_instrs(&instrs, &handlers)?;
+            if let Some(arities) = &arities {
+                let frame = Frame {
+                    arity,
+                    len_locals,
+                    max_height: local_stack_height,
+                };
+                verify_stack_heights(&instrs, &handlers, frame, arities)?;
+            }
+            let len_addrs = decoder.read_items_len()?;
+            addrs.clear();
+            for _ in 0..len_addrs {
+                let mapping =
+                    AddressMapping::deserialize(decoder, len_instrs as u32, addrs.last().copied())?;
+                addrs.push(mapping);
+            }
+            self.code_map.init_func(
+                func,
+                len_locals,
+                local_stack_height,
+                instrs.drain(..),
+                handlers.drain(..),
+                addrs.drain(..),
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,1027 @@
+//! Verification of deserialized compiled functions.
+
+use crate::{
+    engine::{
+        bytecode::{BranchOffset, DropKeep, FuncIdx, LocalDepth, SignatureIdx, TagIdx},
+        code_map::{ExceptionHandler, ExceptionHandlerKind},
+        func_types::FuncTypeRegistry,
+        CompiledFunc,
+        DedupFuncType,
+        Instruction,
+    },
+    module::SerializationError,
+    FuncType,
+};
+use alloc::{boxed::Box, collections::BTreeMap, vec, vec::Vec};
+
+/// Verifies the structure of the deserialized instructions `instrs` of a compiled function.
+///
+/// # Note
+///
+/// This ensures that
+///
+/// - all branches and exception handlers continue at instructions within `instrs`,
+/// - all instructions are followed by their parameter instruction words and
+/// - the execution cannot run past the end of `instrs`.
+///
+/// # Errors
+///
+/// If the structure of `instrs` is malformed.
+pub fn verify_instrs(
+    instrs: &[Instruction],
+    handlers: &[ExceptionHandler],
+) -> Result<(), SerializationError> {
+    /// Returns `true` if `instr` is a parameter instruction word of the expected kind.
+    type IsParam = fn(&Instruction) -> bool;
+    fn is_drop_keep(instr: &Instruction) -> bool {
+        matches!(instr, Instruction::Return(_))
+    }
+    fn is_table_idx(instr: &Instruction) -> bool {
+        matches!(instr, Instruction::TableGet(_))
+    }
+    fn is_memory_idx(instr: &Instruction) -> bool {
+        matches!(instr, Instruction::MemorySize(_))
+    }
+    #[cfg(feature = "simd")]
+    fn is_lane_idx(instr: &Instruction) -> bool {
+        matches!(instr, Instruction::I8x16ExtractLaneS(_))
+    }
+    let malformed = SerializationError::malformed;
+    let len = instrs.len();
+    // Stores for every instruction if execution may continue at it.
+    let mut is_target = vec![false; len];
+    let mut branches = Vec::new();
+    let mut falls_through = true;
+    let mut pc = 0;
+    while pc < len {
+        is_target[pc] = true;
+        let instr = &instrs[pc];
+        let (params, continues): (&[IsParam], bool) = match instr {
+            Instruction::Br(_)
+            | Instruction::Return(_)
+            | Instruction::Unreachable
+            | Instruction::Throw(_)
+            | Instruction::Rethrow(_) => (&[], false),
+            Instruction::BrAdjust(_)
+            | Instruction::ReturnCallInternal(_)
+            | Instruction::ReturnCall(_) => (&[is_drop_keep], false),
+            Instruction::BrAdjustIfNez(_) => (&[is_drop_keep], true),
+            Instruction::ReturnCallIndirect(_) => (&[is_drop_keep, is_table_idx], false),
+            Instruction::CallIndirect(_)
+            | Instruction::TableCopy(_)
+            | Instruction::TableInit(_) => (&[is_table_idx], true),
+            Instruction::MemoryCopy(_) | Instruction::MemoryInit(_) => (&[is_memory_idx], true),
+            #[cfg(feature = "simd")]
+            Instruction::V128Load8Lane(_)
+            | Instruction::V128Load16Lane(_)
+            | Instruction::V128Load32Lane(_)
+            | Instruction::V128Load64Lane(_)
+            | Instruction::V128Store8Lane(_)
+            | Instruction::V128Store16Lane(_)
+            | Instruction::V128Store32Lane(_)
+            | Instruction::V128Store64Lane(_) => (&[is_lane_idx], true),
+            Instruction::BrTable(targets) => {
+                // The executor always selects one of the branch targets.
+                if targets.to_usize() == 0 {
+                    return Err(malformed("empty branch table"));
+                }
+                // The branch targets are pairs of instruction words that follow the
+                // `BrTable` and are verified as ordinary instructions afterwards.
+                let targets = instrs
+                    .get(pc + 1..)
+                    .and_then(|rest| rest.get(..2 * targets.to_usize()))
+                    .ok_or_else(|| malformed("out of bounds branch table"))?;
+                for target in targets.chunks_exact(2) {
+                    if !matches!(
+                        target,
+                        [
+                            Instruction::Br(_) | Instruction::BrAdjust(_) | Instruction::Return(_),
+                            Instruction::Return(_)
+                        ]
+                    ) {
+                        return Err(malformed("invalid branch table target"));
+                    }
+                }
+                (&[], true)
+            }
+            _ => (&[], true),
+        };
+        if let Instruction::Br(offset)
+        | Instruction::BrIfEqz(offset)
+        | Instruction::BrIfNez(offset)
+        | Instruction::BrAdjust(offset)
+        | Instruction::BrAdjustIfNez(offset) = instr
+        {
+            let target = isize::try_from(pc)
+                .ok()
+                .and_then(|pc| pc.checked_add(offset.to_i32() as isize))
+                .and_then(|target| usize::try_from(target).ok());
+            branches.push(target);
+        }
+        for (n, is_param) in params.iter().enumerate() {
+            if !instrs.get(pc + 1 + n).is_some_and(is_param) {
+                return Err(malformed("missing instruction parameter"));
+            }
+        }
+        pc += 1 + params.len();
+        falls_through = continues;
+    }
+    if falls_through {
+        return Err(malformed("unterminated function body"));
+    }
+    let handler_targets = handlers
+        .iter()
+        .filter_map(ExceptionHandler::target_instr)
+        .map(|target| Some(target as usize));
+    for target in branches.into_iter().chain(handler_targets) {
+        if !target.is_some_and(|target| is_target.get(target).copied().unwrap_or(false)) {
+            return Err(malformed("invalid branch target"));
+        }
+    }
+    Ok(())
+}
+
+/// The amount of parameters and results of a function type.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Arity {
+    /// The amount of parameters.
+    pub params: usize,
+    /// The amount of results.
+    pub results: usize,
+}
+
+impl From<&'_ FuncType> for Arity {
+    fn from(func_type: &FuncType) -> Self {
+        Self {
+            params: func_type.params().len(),
+            results: func_type.results().len(),
+        }
+    }
+}
+
+/// The function types of a deserialized [`Module`].
+///
+/// Used to verify the value stack heights of its compiled functions.
+///
+/// [`Module`]: crate::Module
+#[derive(Debug, Copy, Clone)]
+pub struct ModuleTypes<'a> {
+    /// The function types of all functions including imported functions.
+    pub funcs: &'a [DedupFuncType],
+    /// The function types referenced by indirect calls.
+    pub signatures: &'a [DedupFuncType],
+    /// The function types of all exception tags including imported exception tags.
+    pub tags: &'a [DedupFuncType],
+}
+
+/// The [`Arity`] of all functions, function types and exception tags of a deserialized [`Module`].
+///
+/// [`Module`]: crate::Module
+#[derive(Debug)]
+pub struct ModuleArities {
+    /// The [`Arity`] of all functions including imported functions.
+    funcs: Box<[Arity]>,
+    /// The [`Arity`] of all function types.
+    signatures: Box<[Arity]>,
+    /// The [`Arity`] of all exception tags including imported exception tags.
+    tags: Box<[Arity]>,
+    /// The [`Arity`] of the compiled functions of the [`Module`].
+    ///
+    /// [`Module`]: crate::Module
+    compiled: BTreeMap<CompiledFunc, Arity>,
+}
+
+impl ModuleArities {
+    /// Creates the [`ModuleArities`] from the function types `types` of a deserialized [`Module`].
+    ///
+    /// The compiled functions `funcs` of the [`Module`] have the function types `func_types`.
+    ///
+    /// [`Module`]: crate::Module
+    pub fn new(
+        registry: &FuncTypeRegistry,
+        types: ModuleTypes,
+        funcs: &[CompiledFunc],
+        func_types: &[DedupFuncType],
+    ) -> Self {
+        let arities = |types: &[DedupFuncType]| -> Box<[Arity]> {
+            types
+                .iter()
+                .map(|func_type| Arity::from(registry.resolve_func_type(func_type)))
+                .collect()
+        };
+        let compiled = funcs
+            .iter()
+            .zip(func_types)
+            .map(|(&func, func_type)| (func, Arity::from(registry.resolve_func_type(func_type))))
+            .collect();
+        Self {
+            funcs: arities(types.funcs),
+            signatures: arities(types.signatures),
+            tags: arities(types.tags),
+            compiled,
+        }
+    }
+
+    /// Returns the [`Arity`] of the function at `index`.
+    fn func(&self, index: FuncIdx) -> Result<Arity, SerializationError> {
+        self.funcs
+            .get(index.to_u32() as usize)
+            .copied()
+            .ok_or_else(|| SerializationError::malformed("out of bounds FuncIdx"))
+    }
+
+    /// Returns the [`Arity`] of the function type at `index`.
+    fn signature(&self, index: SignatureIdx) -> Result<Arity, SerializationError> {
+        self.signatures
+            .get(index.to_u32() as usize)
+            .copied()
+            .ok_or_else(|| SerializationError::malformed("out of bounds SignatureIdx"))
+    }
+
+    /// Returns the [`Arity`] of the exception tag at `index`.
+    fn tag(&self, index: TagIdx) -> Result<Arity, SerializationError> {
+        self.tags
+            .get(index.to_u32() as usize)
+            .copied()
+            .ok_or_else(|| SerializationError::malformed("out of bounds TagIdx"))
+    }
+
+    /// Returns the [`Arity`] of the compiled function `func`.
+    fn compiled(&self, func: CompiledFunc) -> Result<Arity, SerializationError> {
+        self.compiled
+            .get(&func)
+            .copied()
+            .ok_or_else(|| SerializationError::malformed("invalid compiled function index"))
+    }
+}
+
+/// The function frame of a deserialized compiled function.
+#[derive(Debug, Copy, Clone)]
+pub struct Frame {
+    /// The [`Arity`] of the compiled function.
+    pub arity: Arity,
+    /// The amount of local variables of the compiled function excluding its parameters.
+    pub len_locals: usize,
+    /// The maximum value stack height of the compiled function.
+    ///
+    /// # Note
+    ///
+    /// This excludes the parameters and local variables of the compiled function.
+    pub max_height: usize,
+}
+
+/// Verifies the value stack heights of the deserialized instructions `instrs` of a compiled function.
+///
+/// # Note
+///
+/// This ensures that
+///
+/// - all instructions only access values of the function `frame`,
+/// - the value stack height never exceeds the maximum of the function `frame`,
+/// - all instructions are always executed with the same value stack height and
+/// - all returns and tail calls leave exactly the values expected by the
+///   returned to or called function on the value stack.
+///
+/// The types of the values on the value stack are not verified since the
+/// `wasmi` executor treats all values as untyped bits.
+///
+/// # Dev. Note
+///
+/// The structure of `instrs` must have been verified by [`verify_instrs`] before.
+///
+/// # Errors
+///
+/// If the value stack heights of `instrs` are malformed.
+pub fn verify_stack_heights(
+    instrs: &[Instruction],
+    handlers: &[ExceptionHandler],
+    frame: Frame,
+    arities: &ModuleArities,
+) -> Result<(), SerializationError> {
+    let mut verifier = StackVerifier {
+        instrs,
+        frame,
+        arities,
+        heights: vec![None; instrs.len()],
+        worklist: Vec::new(),
+    };
+    verifier.enter(0, 0)?;
+    for handler in handlers {
+        let (target, len_values) = match handler.kind() {
+            ExceptionHandlerKind::Catch(tag, target) => (target, arities.tag(tag)?.params),
+            ExceptionHandlerKind::CatchAll(target) => (target, 0),
+            ExceptionHandlerKind::Delegate(_) => continue,
+        };
+        // Note: Entering an exception handler pushes a hidden value referring
+        //       to the caught exception followed by the values of the exception.
+        let height = (target.height() as usize)
+            .checked_add(1 + len_values)
+            .ok_or_else(|| SerializationError::malformed("value stack overflow"))?;
+        verifier.enter(target.instr() as usize, height)?;
+    }
+    while let Some((pc, height)) = verifier.worklist.pop() {
+        verifier.verify_instr(pc, height)?;
+    }
+    Ok(())
+}
+
+/// The value stack effect of an [`Instruction`] that continues at the next instruction.
+///
+/// The effect is the amount of popped values, pushed values and the
+/// amount of parameter instruction words following the [`Instruction`].
+type Effect = (usize, usize, usize);
+
+/// Pops one value and pushes one value.
+const UNARY: Effect = (1, 1, 0);
+/// Pops two values and pushes one value.
+const BINARY: Effect = (2, 1, 0);
+/// Pops three values and pushes one value.
+const TERNARY: Effect = (3, 1, 0);
+
+/// Verifies the value stack heights of the instructions of a compiled function.
+struct StackVerifier<'a> {
+    /// The instructions of the compiled function.
+    instrs: &'a [Instruction],
+    /// The function frame of the compiled function.
+    frame: Frame,
+    /// The [`Arity`] of all functions, function types and exception tags.
+    arities: &'a ModuleArities,
+    /// The value stack height for every instruction that has been reached so far.
+    heights: Vec<Option<usize>>,
+    /// The reached instructions that have not yet been verified and their value stack heights.
+    worklist: Vec<(usize, usize)>,
+}
+
+/// Returns the value stack height after popping `n` values at `height`.
+fn pop(height: usize, n: usize) -> Result<usize, SerializationError> {
+    height
+        .checked_sub(n)
+        .ok_or_else(|| SerializationError::malformed("value stack underflow"))
+}
+
+impl StackVerifier<'_> {
+    /// Enters the instruction at `pc` with the value stack `height`.
+    fn enter(&mut self, pc: usize, height: usize) -> Result<(), SerializationError> {
+        if height > self.frame.max_height {
+            return Err(SerializationError::malformed("value stack overflow"));
+        }
+        match self.heights.get_mut(pc) {
+            Some(Some(known)) if *known != height => Err(SerializationError::malformed(
+                "inconsistent value stack height",
+            )),
+            Some(Some(_)) => Ok(()),
+            Some(entry @ None) => {
+                *entry = Some(height);
+                self.worklist.push((pc, height));
+                Ok(())
+            }
+            None => Err(SerializationError::malformed("invalid branch target")),
+        }
+    }
+
+    /// Branches from the instruction at `pc` by `offset` with the value stack `height`.
+    fn branch(
+        &mut self,
+        pc: usize,
+        offset: BranchOffset,
+        height: usize,
+    ) -> Result<(), SerializationError> {
+        let target = isize::try_from(pc)
+            .ok()
+            .and_then(|pc| pc.checked_add(offset.to_i32() as isize))
+            .and_then(|target| usize::try_from(target).ok())
+            .ok_or_else(|| SerializationError::malformed("invalid branch target"))?;
+        self.enter(target, height)
+    }
+
+    /// Returns the [`DropKeep`] parameter stored in the instruction word at `pc`.
+    fn drop_keep(&self, pc: usize) -> Result<DropKeep, SerializationError> {
+        match self.instrs.get(pc) {
+            Some(Instruction::Return(drop_keep)) => Ok(*drop_keep),
+            _ => Err(SerializationError::malformed(
+                "missing instruction parameter",
+            )),
+        }
+    }
+
+    /// Returns the amount of values of the function frame at the value stack `height`.
+    ///
+    /// This includes the parameters and local variables of the function frame.
+    fn frame_len(&self, height: usize) -> usize {
+        height
+            .saturating_add(self.frame.arity.params)
+            .saturating_add(self.frame.len_locals)
+    }
+
+    /// Verifies that the local variable at `depth` is accessible at the value stack `height`.
+    fn local(&self, height: usize, depth: LocalDepth) -> Result<(), SerializationError> {
+        let depth = depth.to_usize();
+        if depth == 0 || depth > self.frame_len(height) {
+            return Err(SerializationError::malformed("out of bounds local access"));
+        }
+        Ok(())
+    }
+
+    /// Returns the value stack height after applying `drop_keep` at `height` for a branch.
+    fn adjust(&self, height: usize, drop_keep: DropKeep) -> Result<usize, SerializationError> {
+        let drop = usize::from(drop_keep.drop());
+        let keep = usize::from(drop_keep.keep());
+        if drop + keep > height {
+            return Err(SerializationError::malformed("value stack underflow"));
+        }
+        Ok(height - drop)
+    }
+
+    /// Verifies that applying `drop_keep` at `height` leaves exactly `expected` values of the function frame.
+    fn leave(
+        &self,
+        height: usize,
+        drop_keep: DropKeep,
+        expected: usize,
+    ) -> Result<(), SerializationError> {
+        let drop = usize::from(drop_keep.drop());
+        let keep = usize::from(drop_keep.keep());
+        if keep != expected || drop + keep != self.frame_len(height) {
+            return Err(SerializationError::malformed("unbalanced value stack"));
+        }
+        Ok(())
+    }
+
+    /// Verifies a return with `drop_keep` at the value stack `height`.
+    fn ret(&self, height: usize, drop_keep: DropKeep) -> Result<(), SerializationError> {
+        self.leave(height, drop_keep, self.frame.arity.results)
+    }
+
+    /// Verifies a tail call to `callee` with `drop_keep` at the value stack `height`.
+    fn tail_call(
+        &self,
+        height: usize,
+        drop_keep: DropKeep,
+        callee: Arity,
+    ) -> Result<(), SerializationError> {
+        if callee.results != self.frame.arity.results {
+            return Err(SerializationError::malformed("unbalanced value stack"));
+        }
+        self.leave(height, drop_keep, callee.params)
+    }
+
+    /// Verifies the instruction at `pc` executed with the value stack `height`.
+    fn verify_instr(&mut self, pc: usize, height: usize) -> Result<(), SerializationError> {
+        use Instruction as Instr;
+        let (pops, pushes, params): Effect = match self.instrs[pc] {
+            Instr::LocalGet(depth) => {
+                self.local(height, depth)?;
+                (0, 1, 0)
+            }
+            Instr::LocalSet(depth) => {
+                self.local(pop(height, 1)?, depth)?;
+                (1, 0, 0)
+            }
+            Instr::LocalTee(depth) => {
+                pop(height, 1)?;
+                self.local(height, depth)?;
+                UNARY
+            }
+            Instr::Br(offset) => return self.branch(pc, offset, height),
+            Instr::BrIfEqz(offset) | Instr::BrIfNez(offset) => {
+                self.branch(pc, offset, pop(height, 1)?)?;
+                (1, 0, 0)
+            }
+            Instr::BrAdjust(offset) => {
+                let height = self.adjust(height, self.drop_keep(pc + 1)?)?;
+                return self.branch(pc, offset, height);
+            }
+            Instr::BrAdjustIfNez(offset) => {
+                let height = self.adjust(pop(height, 1)?, self.drop_keep(pc + 1)?)?;
+                self.branch(pc, offset, height)?;
+                (1, 0, 1)
+            }
+            Instr::BrTable(targets) => {
+                let height = pop(height, 1)?;
+                for n in 0..targets.to_usize() {
+                    self.enter(pc + 1 + 2 * n, height)?;
+                }
+                return Ok(());
+            }
+            Instr::Unreachable => return Ok(()),
+            Instr::ConsumeFuel(_) | Instr::CheckEpoch => (0, 0, 0),
+            Instr::Return(drop_keep) => return self.ret(height, drop_keep),
+            Instr::ReturnIfNez(drop_keep) => {
+                self.ret(pop(height, 1)?, drop_keep)?;
+                (1, 0, 0)
+            }
+            Instr::ReturnCallInternal(func) => {
+                let callee = self.arities.compiled(func)?;
+                return self.tail_call(height, self.drop_keep(pc + 1)?, callee);
+            }
+            Instr::ReturnCall(func) => {
+                let callee = self.arities.func(func)?;
+                return self.tail_call(height, self.drop_keep(pc + 1)?, callee);
+            }
+            Instr::ReturnCallIndirect(signature) => {
+                let callee = self.arities.signature(signature)?;
+                return self.tail_call(pop(height, 1)?, self.drop_keep(pc + 1)?, callee);
+            }
+            Instr::CallInternal(func) => {
+                let callee = self.arities.compiled(func)?;
+                (callee.params, callee.results, 0)
+            }
+            Instr::Call(func) => {
+                let callee = self.arities.func(func)?;
+                (callee.params, callee.results, 0)
+            }
+            Instr::CallIndirect(signature) => {
+                let callee = self.arities.signature(signature)?;
+                (1 + callee.params, callee.results, 1)
+            }
+            Instr::Throw(tag) => {
+                pop(height, self.arities.tag(tag)?.params)?;
+                return Ok(());
+            }
+            Instr::Rethrow(depth) => return self.local(height, depth),
+            Instr::DataDrop(_) | Instr::ElemDrop(_) | Instr::AtomicFence => (0, 0, 0),
+            Instr::GlobalGet(_)
+            | Instr::MemorySize(_)
+            | Instr::TableSize(_)
+            | Instr::RefFunc(_)
+            | Instr::Const32(_)
+            | Instr::I64Const32(_)
+            | Instr::ConstRef(_) => (0, 1, 0),
+            Instr::Drop | Instr::GlobalSet(_) => (1, 0, 0),
+            Instr::I32Load(_)
+            | Instr::I64Load(_)
+            | Instr::F32Load(_)
+            | Instr::F64Load(_)
+            | Instr::I32Load8S(_)
+            | Instr::I32Load8U(_)
+            | Instr::I32Load16S(_)
+            | Instr::I32Load16U(_)
+            | Instr::I64Load8S(_)
+            | Instr::I64Load8U(_)
+            | Instr::I64Load16S(_)
+            | Instr::I64Load16U(_)
+            | Instr::I64Load32S(_)
+            | Instr::I64Load32U(_)
+            | Instr::MemoryGrow(_)
+            | Instr::TableGet(_)
+            | Instr::I32Eqz
+            | Instr::I64Eqz
+            | Instr::I32Clz
+            | Instr::I32Ctz
+            | Instr::I32Popcnt
+            | Instr::I64Clz
+            | Instr::I64Ctz
+            | Instr::I64Popcnt
+            | Instr::F32Abs
+            | Instr::F32Neg
+            | Instr::F32Ceil
+            | Instr::F32Floor
+            | Instr::F32Trunc
+            | Instr::F32Nearest
+            | Instr::F32Sqrt
+            | Instr::F64Abs
+            | Instr::F64Neg
+            | Instr::F64Ceil
+            | Instr::F64Floor
+            | Instr::F64Trunc
+            | Instr::F64Nearest
+            | Instr::F64Sqrt
+            | Instr::I32WrapI64
+            | Instr::I32TruncF32S
+            | Instr::I32TruncF32U
+            | Instr::I32TruncF64S
+            | Instr::I32TruncF64U
+            | Instr::I64ExtendI32S
+            | Instr::I64ExtendI32U
+            | Instr::I64TruncF32S
+            | Instr::I64TruncF32U
+            | Instr::I64TruncF64S
+            | Instr::I64TruncF64U
+            | Instr::F32ConvertI32S
+            | Instr::F32ConvertI32U
+            | Instr::F32ConvertI64S
+            | Instr::F32ConvertI64U
+            | Instr::F32DemoteF64
+            | Instr::F64ConvertI32S
+            | Instr::F64ConvertI32U
+            | Instr::F64ConvertI64S
+            | Instr::F64ConvertI64U
+            | Instr::F64PromoteF32
+            | Instr::I32Extend8S
+            | Instr::I32Extend16S
+            | Instr::I64Extend8S
+            | Instr::I64Extend16S
+            | Instr::I64Extend32S
+            | Instr::I32TruncSatF32S
+            | Instr::I32TruncSatF32U
+            | Instr::I32TruncSatF64S
+            | Instr::I32TruncSatF64U
+            | Instr::I64TruncSatF32S
+            | Instr::I64TruncSatF32U
+            | Instr::I64TruncSatF64S
+            | Instr::I64TruncSatF64U
+            | Instr::I32AtomicLoad(_)
+            | Instr::I64AtomicLoad(_)
+            | Instr::I32AtomicLoad8U(_)
+            | Instr::I32AtomicLoad16U(_)
+            | Instr::I64AtomicLoad8U(_)
+            | Instr::I64AtomicLoad16U(_)
+            | Instr::I64AtomicLoad32U(_) => UNARY,
+            #[cfg(feature = "simd")]
+            Instr::V128Load(_)
+            | Instr::V128Load8x8S(_)
+            | Instr::V128Load8x8U(_)
+            | Instr::V128Load16x4S(_)
+            | Instr::V128Load16x4U(_)
+            | Instr::V128Load32x2S(_)
+            | Instr::V128Load32x2U(_)
+            | Instr::V128Load8Splat(_)
+            | Instr::V128Load16Splat(_)
+            | Instr::V128Load32Splat(_)
+            | Instr::V128Load64Splat(_)
+            | Instr::V128Load32Zero(_)
+            | Instr::V128Load64Zero(_)
+            | Instr::I8x16ExtractLaneS(_)
+            | Instr::I8x16ExtractLaneU(_)
+            | Instr::I16x8ExtractLaneS(_)
+            | Instr::I16x8ExtractLaneU(_)
+            | Instr::I32x4ExtractLane(_)
+            | Instr::I64x2ExtractLane(_)
+            | Instr::F32x4ExtractLane(_)
+            | Instr::F64x2ExtractLane(_)
+            | Instr::I8x16Splat
+            | Instr::I16x8Splat
+            | Instr::I32x4Splat
+            | Instr::I64x2Splat
+            | Instr::F32x4Splat
+            | Instr::F64x2Splat
+            | Instr::V128Not
+            | Instr::V128AnyTrue
+            | Instr::I8x16Abs
+            | Instr::I8x16Neg
+            | Instr::I8x16Popcnt
+            | Instr::I8x16AllTrue
+            | Instr::I8x16Bitmask
+            | Instr::I16x8ExtAddPairwiseI8x16S
+            | Instr::I16x8ExtAddPairwiseI8x16U
+            | Instr::I16x8Abs
+            | Instr::I16x8Neg
+            | Instr::I16x8AllTrue
+            | Instr::I16x8Bitmask
+            | Instr::I16x8ExtendLowI8x16S
+            | Instr::I16x8ExtendHighI8x16S
+            | Instr::I16x8ExtendLowI8x16U
+            | Instr::I16x8ExtendHighI8x16U
+            | Instr::I32x4ExtAddPairwiseI16x8S
+            | Instr::I32x4ExtAddPairwiseI16x8U
+            | Instr::I32x4Abs
+            | Instr::I32x4Neg
+            | Instr::I32x4AllTrue
+            | Instr::I32x4Bitmask
+            | Instr::I32x4ExtendLowI16x8S
+            | Instr::I32x4ExtendHighI16x8S
+            | Instr::I32x4ExtendLowI16x8U
+            | Instr::I32x4ExtendHighI16x8U
+            | Instr::I64x2Abs
+            | Instr::I64x2Neg
+            | Instr::I64x2AllTrue
+            | Instr::I64x2Bitmask
+            | Instr::I64x2ExtendLowI32x4S
+            | Instr::I64x2ExtendHighI32x4S
+            | Instr::I64x2ExtendLowI32x4U
+            | Instr::I64x2ExtendHighI32x4U
+            | Instr::F32x4Ceil
+            | Instr::F32x4Floor
+            | Instr::F32x4Trunc
+            | Instr::F32x4Nearest
+            | Instr::F32x4Abs
+            | Instr::F32x4Neg
+            | Instr::F32x4Sqrt
+            | Instr::F64x2Ceil
+            | Instr::F64x2Floor
+            | Instr::F64x2Trunc
+            | Instr::F64x2Nearest
+            | Instr::F64x2Abs
+            | Instr::F64x2Neg
+            | Instr::F64x2Sqrt
+            | Instr::I32x4TruncSatF32x4S
+            | Instr::I32x4TruncSatF32x4U
+            | Instr::F32x4ConvertI32x4S
+            | Instr::F32x4ConvertI32x4U
+            | Instr::I32x4TruncSatF64x2SZero
+            | Instr::I32x4TruncSatF64x2UZero
+            | Instr::F64x2ConvertLowI32x4S
+            | Instr::F64x2ConvertLowI32x4U
+            | Instr::F32x4DemoteF64x2Zero
+            | Instr::F64x2PromoteLowF32x4 => UNARY,
+            Instr::I32Store(_)
+            | Instr::I64Store(_)
+            | Instr::F32Store(_)
+            | Instr::F64Store(_)
+            | Instr::I32Store8(_)
+            | Instr::I32Store16(_)
+            | Instr::I64Store8(_)
+            | Instr::I64Store16(_)
+            | Instr::I64Store32(_)
+            | Instr::TableSet(_)
+            | Instr::I32AtomicStore(_)
+            | Instr::I64AtomicStore(_)
+            | Instr::I32AtomicStore8(_)
+            | Instr::I32AtomicStore16(_)
+            | Instr::I64AtomicStore8(_)
+            | Instr::I64AtomicStore16(_)
+            | Instr::I64AtomicStore32(_) => (2, 0, 0),
+            #[cfg(feature = "simd")]
+            Instr::V128Store(_) => (2, 0, 0),
+            Instr::TableGrow(_)
+            | Instr::I32Eq
+            | Instr::I32Ne
+            | Instr::I32LtS
+            | Instr::I32LtU
+            | Instr::I32GtS
+            | Instr::I32GtU
+            | Instr::I32LeS
+            | Instr::I32LeU
+            | Instr::I32GeS
+            | Instr::I32GeU
+            | Instr::I64Eq
+            | Instr::I64Ne
+            | Instr::I64LtS
+            | Instr::I64LtU
+            | Instr::I64GtS
+            | Instr::I64GtU
+            | Instr::I64LeS
+            | Instr::I64LeU
+            | Instr::I64GeS
+            | Instr::I64GeU
+            | Instr::F32Eq
+            | Instr::F32Ne
+            | Instr::F32Lt
+            | Instr::F32Gt
+            | Instr::F32Le
+            | Instr::F32Ge
+            | Instr::F64Eq
+            | Instr::F64Ne
+            | Instr::F64Lt
+            | Instr::F64Gt
+            | Instr::F64Le
+            | Instr::F64Ge
+            | Instr::I32Add
+            | Instr::I32Sub
+            | Instr::I32Mul
+            | Instr::I32DivS
+            | Instr::I32DivU
+            | Instr::I32RemS
+            | Instr::I32RemU
+            | Instr::I32And
+            | Instr::I32Or
+            | Instr::I32Xor
+            | Instr::I32Shl
+            | Instr::I32ShrS
+            | Instr::I32ShrU
+            | Instr::I32Rotl
+            | Instr::I32Rotr
+            | Instr::I64Add
+            | Instr::I64Sub
+            | Instr::I64Mul
+            | Instr::I64DivS
+            | Instr::I64DivU
+            | Instr::I64RemS
+            | Instr::I64RemU
+            | Instr::I64And
+            | Instr::I64Or
+            | Instr::I64Xor
+            | Instr::I64Shl
+            | Instr::I64ShrS
+            | Instr::I64ShrU
+            | Instr::I64Rotl
+            | Instr::I64Rotr
+            | Instr::F32Add
+            | Instr::F32Sub
+            | Instr::F32Mul
+            | Instr::F32Div
+            | Instr::F32Min
+            | Instr::F32Max
+            | Instr::F32Copysign
+            | Instr::F64Add
+            | Instr::F64Sub
+            | Instr::F64Mul
+            | Instr::F64Div
+            | Instr::F64Min
+            | Instr::F64Max
+            | Instr::F64Copysign
+            | Instr::MemoryAtomicNotify(_)
+            | Instr::I32AtomicRmwAdd(_)
+            | Instr::I64AtomicRmwAdd(_)
+            | Instr::I32AtomicRmw8AddU(_)
+            | Instr::I32AtomicRmw16AddU(_)
+            | Instr::I64AtomicRmw8AddU(_)
+            | Instr::I64AtomicRmw16AddU(_)
+            | Instr::I64AtomicRmw32AddU(_)
+            | Instr::I32AtomicRmwSub(_)
+            | Instr::I64AtomicRmwSub(_)
+            | Instr::I32AtomicRmw8SubU(_)
+            | Instr::I32AtomicRmw16SubU(_)
+            | Instr::I64AtomicRmw8SubU(_)
+            | Instr::I64AtomicRmw16SubU(_)
+            | Instr::I64AtomicRmw32SubU(_)
+            | Instr::I32AtomicRmwAnd(_)
+            | Instr::I64AtomicRmwAnd(_)
+            | Instr::I32AtomicRmw8AndU(_)
+            | Instr::I32AtomicRmw16AndU(_)
+            | Instr::I64AtomicRmw8AndU(_)
+            | Instr::I64AtomicRmw16AndU(_)
+            | Instr::I64AtomicRmw32AndU(_)
+            | Instr::I32AtomicRmwOr(_)
+            | Instr::I64AtomicRmwOr(_)
+            | Instr::I32AtomicRmw8OrU(_)
+            | Instr::I32AtomicRmw16OrU(_)
+            | Instr::I64AtomicRmw8OrU(_)
+            | Instr::I64AtomicRmw16OrU(_)
+            | Instr::I64AtomicRmw32OrU(_)
+            | Instr::I32AtomicRmwXor(_)
+            | Instr::I64AtomicRmwXor(_)
+            | Instr::I32AtomicRmw8XorU(_)
+            | Instr::I32AtomicRmw16XorU(_)
+            | Instr::I64AtomicRmw8XorU(_)
+            | Instr::I64AtomicRmw16XorU(_)
+            | Instr::I64AtomicRmw32XorU(_)
+            | Instr::I32AtomicRmwXchg(_)
+            | Instr::I64AtomicRmwXchg(_)
+            | Instr::I32AtomicRmw8XchgU(_)
+            | Instr::I32AtomicRmw16XchgU(_)
+            | Instr::I64AtomicRmw8XchgU(_)
+            | Instr::I64AtomicRmw16XchgU(_)
+            | Instr::I64AtomicRmw32XchgU(_) => BINARY,
+            #[cfg(feature = "simd")]
+            Instr::I8x16Shuffle(_)
+            | Instr::I8x16ReplaceLane(_)
+            | Instr::I16x8ReplaceLane(_)
+            | Instr::I32x4ReplaceLane(_)
+            | Instr::I64x2ReplaceLane(_)
+            | Instr::F32x4ReplaceLane(_)
+            | Instr::F64x2ReplaceLane(_)
+            | Instr::I8x16Swizzle
+            | Instr::I8x16Eq
+            | Instr::I8x16Ne
+            | Instr::I8x16LtS
+            | Instr::I8x16LtU
+            | Instr::I8x16GtS
+            | Instr::I8x16GtU
+            | Instr::I8x16LeS
+            | Instr::I8x16LeU
+            | Instr::I8x16GeS
+            | Instr::I8x16GeU
+            | Instr::I16x8Eq
+            | Instr::I16x8Ne
+            | Instr::I16x8LtS
+            | Instr::I16x8LtU
+            | Instr::I16x8GtS
+            | Instr::I16x8GtU
+            | Instr::I16x8LeS
+            | Instr::I16x8LeU
+            | Instr::I16x8GeS
+            | Instr::I16x8GeU
+            | Instr::I32x4Eq
+            | Instr::I32x4Ne
+            | Instr::I32x4LtS
+            | Instr::I32x4LtU
+            | Instr::I32x4GtS
+            | Instr::I32x4GtU
+            | Instr::I32x4LeS
+            | Instr::I32x4LeU
+            | Instr::I32x4GeS
+            | Instr::I32x4GeU
+            | Instr::I64x2Eq
+            | Instr::I64x2Ne
+            | Instr::I64x2LtS
+            | Instr::I64x2GtS
+            | Instr::I64x2LeS
+            | Instr::I64x2GeS
+            | Instr::F32x4Eq
+            | Instr::F32x4Ne
+            | Instr::F32x4Lt
+            | Instr::F32x4Gt
+            | Instr::F32x4Le
+            | Instr::F32x4Ge
+            | Instr::F64x2Eq
+            | Instr::F64x2Ne
+            | Instr::F64x2Lt
+            | Instr::F64x2Gt
+            | Instr::F64x2Le
+            | Instr::F64x2Ge
+            | Instr::V128And
+            | Instr::V128AndNot
+            | Instr::V128Or
+            | Instr::V128Xor
+            | Instr::I8x16NarrowI16x8S
+            | Instr::I8x16NarrowI16x8U
+            | Instr::I8x16Shl
+            | Instr::I8x16ShrS
+            | Instr::I8x16ShrU
+            | Instr::I8x16Add
+            | Instr::I8x16AddSatS
+            | Instr::I8x16AddSatU
+            | Instr::I8x16Sub
+            | Instr::I8x16SubSatS
+            | Instr::I8x16SubSatU
+            | Instr::I8x16MinS
+            | Instr::I8x16MinU
+            | Instr::I8x16MaxS
+            | Instr::I8x16MaxU
+            | Instr::I8x16AvgrU
+            | Instr::I16x8Q15MulrSatS
+            | Instr::I16x8NarrowI32x4S
+            | Instr::I16x8NarrowI32x4U
+            | Instr::I16x8Shl
+            | Instr::I16x8ShrS
+            | Instr::I16x8ShrU
+            | Instr::I16x8Add
+            | Instr::I16x8AddSatS
+            | Instr::I16x8AddSatU
+            | Instr::I16x8Sub
+            | Instr::I16x8SubSatS
+            | Instr::I16x8SubSatU
+            | Instr::I16x8Mul
+            | Instr::I16x8MinS
+            | Instr::I16x8MinU
+            | Instr::I16x8MaxS
+            | Instr::I16x8MaxU
+            | Instr::I16x8AvgrU
+            | Instr::I16x8ExtMulLowI8x16S
+            | Instr::I16x8ExtMulHighI8x16S
+            | Instr::I16x8ExtMulLowI8x16U
+            | Instr::I16x8ExtMulHighI8x16U
+            | Instr::I32x4Shl
+            | Instr::I32x4ShrS
+            | Instr::I32x4ShrU
+            | Instr::I32x4Add
+            | Instr::I32x4Sub
+            | Instr::I32x4Mul
+            | Instr::I32x4MinS
+            | Instr::I32x4MinU
+            | Instr::I32x4MaxS
+            | Instr::I32x4MaxU
+            | Instr::I32x4DotI16x8S
+            | Instr::I32x4ExtMulLowI16x8S
+            | Instr::I32x4ExtMulHighI16x8S
+            | Instr::I32x4ExtMulLowI16x8U
+            | Instr::I32x4ExtMulHighI16x8U
+            | Instr::I64x2Shl
+            | Instr::I64x2ShrS
+            | Instr::I64x2ShrU
+            | Instr::I64x2Add
+            | Instr::I64x2Sub
+            | Instr::I64x2Mul
+            | Instr::I64x2ExtMulLowI32x4S
+            | Instr::I64x2ExtMulHighI32x4S
+            | Instr::I64x2ExtMulLowI32x4U
+            | Instr::I64x2ExtMulHighI32x4U
+            | Instr::F32x4Add
+            | Instr::F32x4Sub
+            | Instr::F32x4Mul
+            | Instr::F32x4Div
+            | Instr::F32x4Min
+            | Instr::F32x4Max
+            | Instr::F32x4PMin
+            | Instr::F32x4PMax
+            | Instr::F64x2Add
+            | Instr::F64x2Sub
+            | Instr::F64x2Mul
+            | Instr::F64x2Div
+            | Instr::F64x2Min
+            | Instr::F64x2Max
+            | Instr::F64x2PMin
+            | Instr::F64x2PMax => BINARY,
+            Instr::MemoryFill(_) | Instr::TableFill(_) => (3, 0, 0),
+            Instr::Select
+            | Instr::MemoryAtomicWait32(_)
+            | Instr::MemoryAtomicWait64(_)
+            | Instr::I32AtomicRmwCmpxchg(_)
+            | Instr::I64AtomicRmwCmpxchg(_)
+            | Instr::I32AtomicRmw8CmpxchgU(_)
+            | Instr::I32AtomicRmw16CmpxchgU(_)
+            | Instr::I64AtomicRmw8CmpxchgU(_)
+            | Instr::I64AtomicRmw16CmpxchgU(_)
+            | Instr::I64AtomicRmw32CmpxchgU(_) => TERNARY,
+            #[cfg(feature = "simd")]
+            Instr::V128Bitselect => TERNARY,
+            Instr::MemoryCopy(_)
+            | Instr::MemoryInit(_)
+            | Instr::TableCopy(_)
+            | Instr::TableInit(_) => (3, 0, 1),
+            #[cfg(feature = "simd")]
+            Instr::V128Load8Lane(_)
+            | Instr::V128Load16Lane(_)
+            | Instr::V128Load32Lane(_)
+            | Instr::V128Load64Lane(_) => (2, 1, 1),
+            #[cfg(feature = "simd")]
+            Instr::V128Store8Lane(_)
+            | Instr::V128Store16Lane(_)
+            | Instr::V128Store32Lane(_)
+            | Instr::V128Store64Lane(_) => (2, 0, 1),
+        };
+        let height = pop(height, pops)? + pushes;
+        self.enter(pc + 1 + params, height)
+    }
+}
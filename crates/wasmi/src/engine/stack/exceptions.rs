@@ -0,0 +1,53 @@
+//! Data structures to store the exceptions caught during Wasm execution.
+
+use crate::Exception;
+use alloc::vec::Vec;
+
+/// The exceptions caught by live Wasm `catch` and `catch_all` blocks.
+///
+/// # Note
+///
+/// Upon entering an exception handler a hidden value is pushed onto the
+/// value stack that refers to the caught exception stored here. This allows
+/// `rethrow` instructions to query the caught exception.
+///
+/// Exceptions are ordered by the value stack slot of their hidden value.
+/// Entering an exception handler discards all exceptions with slots that
+/// are not below the slot of the new hidden value since their handlers
+/// are no longer live.
+#[derive(Debug, Default)]
+pub struct CaughtExceptions {
+    /// The caught exceptions and the value stack slots of their hidden values.
+    caught: Vec<(usize, Exception)>,
+}
+
+impl CaughtExceptions {
+    /// Pushes the `exception` caught by a handler with its hidden value at `slot`.
+    ///
+    /// Returns the index of the caught exception to be stored in the hidden value.
+    pub fn push(&mut self, slot: usize, exception: Exception) -> u32 {
+        let len = self.caught.partition_point(|(caught, _)| *caught < slot);
+        self.caught.truncate(len);
+        let index = u32::try_from(len)
+            .unwrap_or_else(|error| panic!("too many caught exceptions: {error}"));
+        self.caught.push((slot, exception));
+        index
+    }
+
+    /// Returns the caught [`Exception`] at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If there is no caught [`Exception`] at `index`.
+    pub fn get(&self, index: u32) -> &Exception {
+        self.caught
+            .get(index as usize)
+            .map(|(_, exception)| exception)
+            .unwrap_or_else(|| panic!("missing caught exception at index {index}"))
+    }
+
+    /// Clears all caught exceptions.
+    pub fn reset(&mut self) {
+        self.caught.clear();
+    }
+}
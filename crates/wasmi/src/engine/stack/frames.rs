@@ -1,8 +1,14 @@
 //! Data structures to represent the Wasm call stack during execution.
 
 use super::{err_stack_overflow, DEFAULT_MAX_RECURSION_DEPTH};
-use crate::{core::TrapCode, engine::code_map::InstructionPtr, Instance};
+use crate::{
+    core::{FrameInfo, TrapCode, WasmBacktrace},
+    engine::code_map::{CodeMap, InstructionPtr},
+    Instance,
+    StoreInner,
+};
 use alloc::vec::Vec;
+use core::ptr;
 
 /// A function frame of a function on the call stack.
 #[derive(Debug, Copy, Clone)]
@@ -17,17 +23,55 @@ pub struct FuncFrame {
     /// non-local to the function such as linear memories, global variables
     /// and tables.
     instance: Instance,
+    /// The height of the value stack after the parameters and locals of the function.
+    ///
+    /// # Note
+    ///
+    /// This is used to restore the value stack upon entering exception handlers.
+    base: usize,
 }
 
 impl FuncFrame {
     /// Creates a new [`FuncFrame`].
-    pub fn new(ip: InstructionPtr, instance: &Instance) -> Self {
+    pub fn new(ip: InstructionPtr, instance: &Instance, base: usize) -> Self {
         Self {
             ip,
             instance: *instance,
+            base,
         }
     }
 
+    /// Returns the [`FrameInfo`] of the [`FuncFrame`] for a [`WasmBacktrace`].
+    ///
+    /// Set `is_caller` if the [`FuncFrame`] continues after its call instruction.
+    ///
+    /// Returns `None` if the function of the [`FuncFrame`] cannot be determined.
+    fn info(&self, store: &StoreInner, code_map: &CodeMap, is_caller: bool) -> Option<FrameInfo> {
+        let (func_body, instr_offset) = code_map.func_at(code_map.instr_ref(self.ip))?;
+        let instance = store.resolve_instance(&self.instance);
+        let func_index = instance.func_index(func_body)?;
+        let names = instance.names();
+        let info = FrameInfo::new(
+            func_index,
+            names.module(),
+            names.func(func_index),
+            instr_offset,
+        );
+        // Callers continue after their call instruction which we want to map instead.
+        let call_offset = instr_offset.saturating_sub(usize::from(is_caller));
+        let wasm_offset = match code_map.wasm_offset(func_body, call_offset) {
+            Some(wasm_offset) => wasm_offset,
+            None => return Some(info),
+        };
+        let info = info.with_wasm_offset(wasm_offset);
+        #[cfg(feature = "dwarf")]
+        let info = match instance.dwarf() {
+            Some(dwarf) => info.with_source_locations(dwarf.source_locations(wasm_offset)),
+            None => info,
+        };
+        Some(info)
+    }
+
     /// Returns the current instruction pointer.
     pub fn ip(&self) -> InstructionPtr {
         self.ip
@@ -37,6 +81,11 @@ impl FuncFrame {
     pub fn instance(&self) -> &Instance {
         &self.instance
     }
+
+    /// Returns the value stack height after the parameters and locals of the [`FuncFrame`].
+    pub fn base(&self) -> usize {
+        self.base
+    }
 }
 
 /// The live function call stack storing the live function activation frames.
@@ -46,6 +95,13 @@ pub struct CallStack {
     frames: Vec<FuncFrame>,
     /// The maximum allowed depth of the `frames` stack.
     recursion_limit: usize,
+    /// The first instruction of the [`CodeMap`] the instruction pointers of `frames` point into.
+    ///
+    /// # Note
+    ///
+    /// This is used to detect and fix up dangling instruction pointers after
+    /// the instructions of the [`CodeMap`] have been reallocated.
+    base_ptr: InstructionPtr,
 }
 
 impl Default for CallStack {
@@ -60,13 +116,33 @@ impl CallStack {
         Self {
             frames: Vec::new(),
             recursion_limit,
+            base_ptr: InstructionPtr::new(ptr::null()),
         }
     }
 
+    /// Re-bases the instruction pointers of all [`FuncFrame`]s onto the instructions of `code_map`.
+    ///
+    /// # Note
+    ///
+    /// This must be called before the [`CallStack`] is used with `code_map`
+    /// since the instructions of the [`CodeMap`] might have been reallocated
+    /// by lazy function translations since the [`CallStack`] was last used.
+    #[inline]
+    pub fn rebase(&mut self, code_map: &CodeMap) {
+        let base_ptr = code_map.base_ptr();
+        if self.base_ptr == base_ptr {
+            return;
+        }
+        for frame in &mut self.frames {
+            frame.ip.rebase(self.base_ptr, base_ptr);
+        }
+        self.base_ptr = base_ptr;
+    }
+
     /// Initializes the [`CallStack`] given the Wasm function.
-    pub fn init(&mut self, ip: InstructionPtr, instance: &Instance) {
+    pub fn init(&mut self, ip: InstructionPtr, instance: &Instance, base: usize) {
         self.reset();
-        self.frames.push(FuncFrame::new(ip, instance));
+        self.frames.push(FuncFrame::new(ip, instance, base));
     }
 
     /// Pushes a Wasm caller function onto the [`CallStack`].
@@ -79,6 +155,17 @@ impl CallStack {
         Ok(())
     }
 
+    /// Pushes the frame of a Wasm callee onto the [`CallStack`] to resume its execution.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`CallStack::push`] this does not check the recursion limit
+    /// since the frame of the callee is popped again upon resumption and the
+    /// frame of its caller has already been checked.
+    pub fn push_callee(&mut self, callee: FuncFrame) {
+        self.frames.push(callee);
+    }
+
     /// Pops the last [`FuncFrame`] from the [`CallStack`] if any.
     #[inline]
     pub fn pop(&mut self) -> Option<FuncFrame> {
@@ -91,6 +178,40 @@ impl CallStack {
         self.frames.last()
     }
 
+    /// Captures a [`WasmBacktrace`] of all function frames on the [`CallStack`].
+    #[cold]
+    pub fn backtrace(&self, store: &StoreInner, code_map: &CodeMap) -> WasmBacktrace {
+        self.backtrace_impl(store, code_map, false)
+    }
+
+    /// Captures a [`WasmBacktrace`] of all function frames on the [`CallStack`] during a host call.
+    ///
+    /// # Note
+    ///
+    /// Unlike with [`CallStack::backtrace`] the top most function frame
+    /// is the caller of the host function and not a trapping function.
+    #[cold]
+    pub fn host_backtrace(&self, store: &StoreInner, code_map: &CodeMap) -> WasmBacktrace {
+        self.backtrace_impl(store, code_map, true)
+    }
+
+    /// Captures a [`WasmBacktrace`] of all function frames on the [`CallStack`].
+    ///
+    /// Set `is_host_call` if all function frames are callers.
+    fn backtrace_impl(
+        &self,
+        store: &StoreInner,
+        code_map: &CodeMap,
+        is_host_call: bool,
+    ) -> WasmBacktrace {
+        self.frames
+            .iter()
+            .rev()
+            .enumerate()
+            .filter_map(|(depth, frame)| frame.info(store, code_map, is_host_call || depth != 0))
+            .collect()
+    }
+
     /// Returns the amount of function frames on the [`CallStack`].
     #[inline]
     fn len(&self) -> usize {
@@ -1,13 +1,15 @@
+mod exceptions;
 mod frames;
 mod values;
 
 pub use self::{
+    exceptions::CaughtExceptions,
     frames::{CallStack, FuncFrame},
     values::{ValueStack, ValueStackPtr},
 };
 use crate::{
     core::UntypedValue,
-    engine::{code_map::CodeMap, func_types::FuncTypeRegistry, FuncParams},
+    engine::{code_map::CodeMap, CompiledFunc, FuncParams},
     func::{HostFuncEntity, WasmFuncEntity},
     AsContext,
     Instance,
@@ -104,6 +106,8 @@ pub struct Stack {
     pub values: ValueStack,
     /// The frame stack.
     pub frames: CallStack,
+    /// The exceptions caught by live `catch` and `catch_all` blocks.
+    pub exceptions: CaughtExceptions,
 }
 
 impl Stack {
@@ -116,7 +120,11 @@ impl Stack {
             limits.initial_value_stack_height,
             limits.maximum_value_stack_height,
         );
-        Self { values, frames }
+        Self {
+            values,
+            frames,
+            exceptions: CaughtExceptions::default(),
+        }
     }
 
     /// Create an empty [`Stack`].
@@ -128,6 +136,7 @@ impl Stack {
         Self {
             values: ValueStack::empty(),
             frames: CallStack::default(),
+            exceptions: CaughtExceptions::default(),
         }
     }
 
@@ -146,11 +155,33 @@ impl Stack {
         wasm_func: &WasmFuncEntity,
         code_map: &CodeMap,
     ) -> Result<(), TrapCode> {
+        self.frames.rebase(code_map);
         let header = code_map.header(wasm_func.func_body());
         self.values.prepare_wasm_call(header)?;
-        let ip = code_map.instr_ptr(header.iref());
         let instance = wasm_func.instance();
-        self.frames.init(ip, instance);
+        let ip = code_map.instr_ptr(header.iref());
+        self.frames.init(ip, instance, self.values.len());
+        Ok(())
+    }
+
+    /// Prepares the [`Stack`] for a call to the lazily translated Wasm function `func`.
+    ///
+    /// # Note
+    ///
+    /// This is used to resume the execution with the call to `func` after
+    /// the execution has been interrupted for the translation of `func`.
+    pub fn prepare_translated_call(
+        &mut self,
+        func: CompiledFunc,
+        instance: &Instance,
+        code_map: &CodeMap,
+    ) -> Result<(), TrapCode> {
+        self.frames.rebase(code_map);
+        let header = code_map.header(func);
+        self.values.prepare_wasm_call(header)?;
+        let ip = code_map.instr_ptr(header.iref());
+        let frame = FuncFrame::new(ip, instance, self.values.len());
+        self.frames.push_callee(frame);
         Ok(())
     }
 
@@ -159,9 +190,8 @@ impl Stack {
         &mut self,
         ctx: StoreContextMut<T>,
         host_func: HostFuncEntity,
-        func_types: &FuncTypeRegistry,
     ) -> Result<(), Trap> {
-        self.call_host_impl(ctx, host_func, None, func_types)
+        self.call_host_impl(ctx, host_func, None)
     }
 
     /// Executes the given host function.
@@ -176,17 +206,18 @@ impl Stack {
         ctx: StoreContextMut<T>,
         host_func: HostFuncEntity,
         instance: Option<&Instance>,
-        func_types: &FuncTypeRegistry,
     ) -> Result<(), Trap> {
         // The host function signature is required for properly
         // adjusting, inspecting and manipulating the value stack.
-        let (input_types, output_types) = func_types
-            .resolve_func_type(host_func.ty_dedup())
-            .params_results();
+        let (len_inputs, len_outputs) = ctx
+            .as_context()
+            .store
+            .engine()
+            .resolve_func_type(host_func.ty_dedup(), |func_type| {
+                (func_type.params().len(), func_type.results().len())
+            });
         // In case the host function returns more values than it takes
         // we are required to extend the value stack.
-        let len_inputs = input_types.len();
-        let len_outputs = output_types.len();
         let max_inout = len_inputs.max(len_outputs);
         self.values.reserve(max_inout)?;
         let delta = if len_outputs > len_inputs {
@@ -239,5 +270,6 @@ impl Stack {
     pub fn reset(&mut self) {
         self.values.reset();
         self.frames.reset();
+        self.exceptions.reset();
     }
 }
@@ -110,6 +110,17 @@ impl ValueStack {
         self.stack_ptr = offset as usize;
     }
 
+    /// Returns all values of the [`ValueStack`] up to the [`ValueStackPtr`] `sp`.
+    ///
+    /// # Note
+    ///
+    /// The [`ValueStack`] does not have to be synchronized with `sp`.
+    #[cfg(feature = "trace")]
+    pub fn values_until(&mut self, sp: ValueStackPtr) -> &[UntypedValue] {
+        let len = sp.offset_from(self.base_ptr()) as usize;
+        &self.entries[..len]
+    }
+
     /// Returns `true` if the [`ValueStack`] is empty.
     pub fn is_empty(&self) -> bool {
         self.entries.capacity() == 0
@@ -214,10 +225,21 @@ impl ValueStack {
     }
 
     /// Returns the current length of the [`ValueStack`].
-    fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         self.stack_ptr
     }
 
+    /// Shrinks the [`ValueStack`] to the given `len`.
+    ///
+    /// # Note
+    ///
+    /// This is used to unwind the [`ValueStack`] upon entering exception handlers.
+    #[inline]
+    pub fn shrink_to(&mut self, len: usize) {
+        debug_assert!(len <= self.len());
+        self.stack_ptr = len;
+    }
+
     /// Reserves enough space for `additional` entries in the [`ValueStack`].
     ///
     /// # Note
@@ -1,5 +1,5 @@
 use super::{
-    bytecode::{AddressOffset, BranchTableTargets, GlobalIdx},
+    bytecode::{AddressOffset, BranchTableTargets, GlobalIdx, MemArg, MemoryIdx},
     *,
 };
 use crate::{
@@ -1368,7 +1368,7 @@ fn metered_load_01() {
     let expected = [
         instr::consume_fuel(expected_fuel),
         instr::local_get(1),
-        Instruction::I32Load(AddressOffset::from(0)),
+        Instruction::I32Load(MemArg::new(MemoryIdx::from(0), AddressOffset::from(0)).unwrap()),
         Instruction::Return(drop_keep(1, 1)),
     ];
     assert_func_bodies_metered(wasm, [expected]);
@@ -1394,7 +1394,7 @@ fn metered_store_01() {
         instr::consume_fuel(expected_fuel),
         instr::local_get(2),
         instr::local_get(2),
-        Instruction::I32Store(AddressOffset::from(0)),
+        Instruction::I32Store(MemArg::new(MemoryIdx::from(0), AddressOffset::from(0)).unwrap()),
         Instruction::Return(drop_keep(2, 0)),
     ];
     assert_func_bodies_metered(wasm, [expected]);
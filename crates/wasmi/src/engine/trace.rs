@@ -0,0 +1,136 @@
+//! The execution tracing API of the `wasmi` interpreter.
+
+use super::bytecode::Instruction;
+use crate::Instance;
+use core::fmt;
+use wasmi_core::UntypedValue;
+
+/// Receives notifications about the execution of Wasm functions in a [`Store`].
+///
+/// A [`Tracer`] can be installed into a [`Store`] via [`Store::tracer`].
+/// All methods have empty default implementations so that implementors only
+/// have to override the notifications they are interested in.
+///
+/// # Note
+///
+/// - Only the execution of Wasm functions is traced. Calls to host functions are not.
+/// - [`Tracer::func_exit`] is not called for functions that are unwound
+///   by a trap or a Wasm exception.
+///
+/// [`Store`]: crate::Store
+/// [`Store::tracer`]: crate::Store::tracer
+pub trait Tracer: Send + Sync {
+    /// Called when the execution enters the Wasm function at `func_index` of `instance`.
+    ///
+    /// The `func_index` refers to the function index space of the module of `instance`.
+    fn func_enter(&mut self, _instance: &Instance, _func_index: u32) {}
+
+    /// Called when the execution returns from the Wasm function at `func_index` of `instance`.
+    ///
+    /// # Note
+    ///
+    /// This is also called for a function that calls another function via a tail call.
+    fn func_exit(&mut self, _instance: &Instance, _func_index: u32) {}
+
+    /// Called before the execution of every `wasmi` bytecode [`Instruction`].
+    ///
+    /// The `stack` holds all live values of the value stack with the top most value last.
+    ///
+    /// # Note
+    ///
+    /// The `wasmi` bytecode is an implementation detail and may change between versions.
+    fn instr(&mut self, _instr: &Instruction, _stack: &[UntypedValue]) {}
+
+    /// Called after a successful load instruction from a linear memory.
+    ///
+    /// # Note
+    ///
+    /// This includes atomic loads but not lane loads.
+    fn memory_load(&mut self, _access: MemoryAccess) {}
+
+    /// Called after a successful store instruction to a linear memory.
+    ///
+    /// # Note
+    ///
+    /// This includes atomic stores but not lane stores.
+    fn memory_store(&mut self, _access: MemoryAccess) {}
+
+    /// Called after a successful atomic read-modify-write instruction on a linear memory.
+    ///
+    /// The value of the `access` is the value loaded before the modification which is also
+    /// the result of the instruction. The `operand` is the operand of the modification,
+    /// for example the added value of `i32.atomic.rmw.add`.
+    fn memory_rmw(&mut self, _access: MemoryAccess, _operand: UntypedValue) {}
+
+    /// Called after a successful atomic compare-exchange instruction on a linear memory.
+    ///
+    /// The value of the `access` is the loaded value which is also the result of the
+    /// instruction. The `replacement` has been stored only if the loaded value equals `expected`.
+    fn memory_cmpxchg(
+        &mut self,
+        _access: MemoryAccess,
+        _expected: UntypedValue,
+        _replacement: UntypedValue,
+    ) {
+    }
+
+    /// Called after `memory.fill` successfully filled `len` bytes at `dst` of `memory` with `value`.
+    fn memory_fill(&mut self, _memory: u32, _dst: u64, _value: u8, _len: u64) {}
+
+    /// Called after `memory.copy` successfully copied `len` bytes from `src` of `src_memory`
+    /// to `dst` of `dst_memory`.
+    fn memory_copy(&mut self, _dst_memory: u32, _dst: u64, _src_memory: u32, _src: u64, _len: u64) {
+    }
+
+    /// Called after `memory.init` successfully copied `len` bytes from `src` of the data
+    /// segment at `segment` to `dst` of `memory`.
+    fn memory_init(&mut self, _memory: u32, _dst: u64, _segment: u32, _src: u64, _len: u64) {}
+}
+
+impl fmt::Debug for dyn Tracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tracer").finish()
+    }
+}
+
+/// A load from or store to a linear memory reported to a [`Tracer`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MemoryAccess {
+    /// The index of the accessed linear memory within the memory index space.
+    memory: u32,
+    /// The effective address of the access including the static offset.
+    address: u64,
+    /// The loaded or stored value.
+    value: UntypedValue,
+}
+
+impl MemoryAccess {
+    /// Creates a new [`MemoryAccess`].
+    pub(crate) fn new(memory: u32, address: u64, value: UntypedValue) -> Self {
+        Self {
+            memory,
+            address,
+            value,
+        }
+    }
+
+    /// Returns the index of the accessed linear memory within the memory index space.
+    pub fn memory(&self) -> u32 {
+        self.memory
+    }
+
+    /// Returns the effective address of the access including the static offset.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// Returns the loaded or stored value.
+    ///
+    /// # Note
+    ///
+    /// Loaded values are already sign or zero extended.
+    /// Stored values are not yet wrapped to the width of the store.
+    pub fn value(&self) -> UntypedValue {
+        self.value
+    }
+}
@@ -2,6 +2,7 @@ use super::errors::{
     FuelError,
     FuncError,
     GlobalError,
+    InstanceError,
     InstantiationError,
     LinkerError,
     MemoryError,
@@ -25,6 +26,8 @@ pub enum Error {
     Linker(LinkerError),
     /// A module instantiation error.
     Instantiation(InstantiationError),
+    /// A module instance error.
+    Instance(InstanceError),
     /// A module compilation, validation and translation error.
     Module(ModuleError),
     /// A store error.
@@ -48,6 +51,7 @@ impl Display for Error {
             Self::Linker(error) => Display::fmt(error, f),
             Self::Func(error) => Display::fmt(error, f),
             Self::Instantiation(error) => Display::fmt(error, f),
+            Self::Instance(error) => Display::fmt(error, f),
             Self::Module(error) => Display::fmt(error, f),
             Self::Store(error) => Display::fmt(error, f),
         }
@@ -90,6 +94,12 @@ impl From<InstantiationError> for Error {
     }
 }
 
+impl From<InstanceError> for Error {
+    fn from(error: InstanceError) -> Self {
+        Self::Instance(error)
+    }
+}
+
 impl From<ModuleError> for Error {
     fn from(error: ModuleError) -> Self {
         Self::Module(error)
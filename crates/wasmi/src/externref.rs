@@ -94,18 +94,18 @@ pub struct ExternRef {
 /// Type used to convert between [`ExternRef`] and [`UntypedValue`].
 union Transposer {
     externref: ExternRef,
-    untyped: UntypedValue,
+    bits: u64,
 }
 
 #[test]
 fn externref_sizeof() {
     // These assertions are important in order to convert `FuncRef`
-    // from and to 64-bit `UntypedValue` instances.
+    // from and to the lower 64 bits of `UntypedValue` instances.
     //
     // The following equation must be true:
-    //     size_of(ExternRef) == size_of(ExternObject) == size_of(UntypedValue)
+    //     size_of(ExternRef) == size_of(ExternObject) == size_of(u64)
     use core::mem::size_of;
-    assert_eq!(size_of::<ExternRef>(), size_of::<UntypedValue>());
+    assert_eq!(size_of::<ExternRef>(), size_of::<u64>());
     assert_eq!(size_of::<ExternRef>(), size_of::<ExternObject>());
 }
 
@@ -122,7 +122,13 @@ impl From<UntypedValue> for ExternRef {
         //         this operation cannot produce invalid [`ExternRef`]
         //         instances even though the input [`UntypedValue`]
         //         was modified arbitrarily.
-        unsafe { Transposer { untyped }.externref }.canonicalize()
+        unsafe {
+            Transposer {
+                bits: u64::from(untyped),
+            }
+            .externref
+        }
+        .canonicalize()
     }
 }
 
@@ -134,7 +140,7 @@ impl From<ExternRef> for UntypedValue {
         //         this operation cannot produce invalid [`UntypedValue`]
         //         instances even if it was possible to arbitrarily modify
         //         the input [`ExternRef`] instance.
-        unsafe { Transposer { externref }.untyped }
+        UntypedValue::from(unsafe { Transposer { externref }.bits })
     }
 }
 
@@ -165,12 +171,7 @@ impl ExternRef {
         if self.is_null() {
             // Safety: This is safe since `0u64` can be bit
             //         interpreted as a valid `ExternRef` value.
-            return unsafe {
-                Transposer {
-                    untyped: UntypedValue::from(0u64),
-                }
-                .externref
-            };
+            return unsafe { Transposer { bits: 0u64 }.externref };
         }
         self
     }
@@ -0,0 +1,172 @@
+use super::{Caller, FuncEntity};
+use crate::{
+    core::{HostError, Trap},
+    engine::FuncParams,
+    AsContextMut,
+    Func,
+    Instance,
+    Value,
+};
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    fmt,
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// The future returned by asynchronous host functions.
+pub(crate) type HostFuture<'a> = Box<dyn Future<Output = Result<(), Trap>> + Send + 'a>;
+
+/// The closure type of asynchronous host functions.
+type AsyncTrampolineFn<T> = dyn for<'a> Fn(Caller<'a, T>, &'a [Value], &'a mut [Value]) -> HostFuture<'a>
+    + Send
+    + Sync
+    + 'static;
+
+/// The trampoline of an asynchronous host function.
+pub struct AsyncTrampoline<T> {
+    /// The asynchronous host function closure.
+    closure: Arc<AsyncTrampolineFn<T>>,
+    /// Typed buffer for the parameters and results of the host function.
+    ///
+    /// # Note
+    ///
+    /// This is cloned for every call to the host function.
+    params_results: Arc<[Value]>,
+    /// The number of parameters of the host function.
+    len_params: usize,
+}
+
+impl<T> Clone for AsyncTrampoline<T> {
+    fn clone(&self) -> Self {
+        Self {
+            closure: self.closure.clone(),
+            params_results: self.params_results.clone(),
+            len_params: self.len_params,
+        }
+    }
+}
+
+impl<T> AsyncTrampoline<T> {
+    /// Creates a new [`AsyncTrampoline`] from the given asynchronous host function.
+    ///
+    /// The `params_results` buffer holds the default values of the parameter types
+    /// followed by the default values of the result types of the host function.
+    pub fn new<F>(params_results: Arc<[Value]>, len_params: usize, closure: F) -> Self
+    where
+        F: for<'a> Fn(Caller<'a, T>, &'a [Value], &'a mut [Value]) -> HostFuture<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            closure: Arc::new(closure),
+            params_results,
+            len_params,
+        }
+    }
+
+    /// Suspends the Wasm execution calling the asynchronous host function.
+    ///
+    /// Returns the [`AsyncHostCall`] error which stores the decoded `params`
+    /// so that the host function can be called once the execution is suspended.
+    pub fn suspend(&self, instance: Option<&Instance>, params: FuncParams) -> Trap {
+        let mut params_results: Box<[Value]> = self.params_results.iter().cloned().collect();
+        params
+            .decode_params_into_slice(&mut params_results[..self.len_params])
+            .unwrap();
+        Trap::from(AsyncHostCall {
+            instance: instance.copied(),
+            params_results,
+            len_params: self.len_params,
+        })
+    }
+}
+
+/// Signals that the Wasm execution called an asynchronous host function.
+///
+/// # Note
+///
+/// This host error suspends the Wasm execution in a resumable way so that
+/// [`Func::call_async`] can call the asynchronous host function and resume
+/// the execution with its results afterwards.
+#[derive(Debug, Default)]
+pub(crate) struct AsyncHostCall {
+    /// The instance of the Wasm function calling the host function, if any.
+    instance: Option<Instance>,
+    /// The parameters followed by the results of the host function call.
+    params_results: Box<[Value]>,
+    /// The number of parameters of the host function call.
+    len_params: usize,
+}
+
+impl Display for AsyncHostCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot call an asynchronous host function synchronously, use `call_async` instead"
+        )
+    }
+}
+
+impl HostError for AsyncHostCall {}
+
+impl AsyncHostCall {
+    /// Calls the asynchronous `host_func` with the parameters of the [`AsyncHostCall`].
+    ///
+    /// Returns the results of the host function call upon success.
+    ///
+    /// # Errors
+    ///
+    /// If the host function returns an error.
+    pub async fn call<T>(
+        mut self,
+        mut ctx: impl AsContextMut<UserState = T>,
+        host_func: Func,
+    ) -> Result<Box<[Value]>, Trap> {
+        let trampoline = match ctx.as_context().store.inner.resolve_func(&host_func) {
+            FuncEntity::Wasm(_) => unreachable!("`host_func` must be a host function"),
+            FuncEntity::Host(host_func) => *host_func.trampoline(),
+        };
+        let trampoline = ctx
+            .as_context()
+            .store
+            .resolve_trampoline(&trampoline)
+            .as_async()
+            .cloned()
+            .expect("`host_func` must be an asynchronous host function");
+        let (params, results) = self.params_results.split_at_mut(self.len_params);
+        let caller = <Caller<T>>::new(&mut ctx, self.instance.as_ref());
+        Pin::from((trampoline.closure)(caller, params, results)).await?;
+        Ok(results.into())
+    }
+}
+
+/// A future that yields control back to the async executor exactly once.
+///
+/// # Note
+///
+/// This is used by [`Func::call_async`] to cooperatively yield
+/// upon reaching the epoch deadline of the [`Store`].
+///
+/// [`Store`]: crate::Store
+#[derive(Debug, Default)]
+pub(crate) struct YieldNow {
+    /// Is `true` if the future already yielded.
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
@@ -17,18 +17,18 @@ impl From<Func> for FuncRef {
 /// Type used to convert between [`FuncRef`] and [`UntypedValue`].
 union Transposer {
     funcref: FuncRef,
-    untyped: UntypedValue,
+    bits: u64,
 }
 
 #[test]
 fn funcref_sizeof() {
     // These assertions are important in order to convert `FuncRef`
-    // from and to 64-bit `UntypedValue` instances.
+    // from and to the lower 64 bits of `UntypedValue` instances.
     //
     // The following equation must be true:
-    //     size_of(Func) == size_of(UntypedValue) == size_of(FuncRef)
+    //     size_of(Func) == size_of(u64) == size_of(FuncRef)
     use core::mem::size_of;
-    assert_eq!(size_of::<Func>(), size_of::<UntypedValue>());
+    assert_eq!(size_of::<Func>(), size_of::<u64>());
     assert_eq!(size_of::<Func>(), size_of::<FuncRef>());
 }
 
@@ -45,7 +45,13 @@ impl From<UntypedValue> for FuncRef {
         //         this operation cannot produce invalid [`FuncRef`]
         //         instances even though the input [`UntypedValue`]
         //         was modified arbitrarily.
-        unsafe { Transposer { untyped }.funcref }.canonicalize()
+        unsafe {
+            Transposer {
+                bits: u64::from(untyped),
+            }
+            .funcref
+        }
+        .canonicalize()
     }
 }
 
@@ -57,7 +63,7 @@ impl From<FuncRef> for UntypedValue {
         //         this operation cannot produce invalid [`UntypedValue`]
         //         instances even if it was possible to arbitrarily modify
         //         the input [`FuncRef`] instance.
-        unsafe { Transposer { funcref }.untyped }
+        UntypedValue::from(unsafe { Transposer { funcref }.bits })
     }
 }
 
@@ -80,12 +86,7 @@ impl FuncRef {
         if self.is_null() {
             // Safety: This is safe since `0u64` can be bit
             //         interpreted as a valid `FuncRef` value.
-            return unsafe {
-                Transposer {
-                    untyped: UntypedValue::from(0u64),
-                }
-                .funcref
-            };
+            return unsafe { Transposer { bits: 0u64 }.funcref };
         }
         self
     }
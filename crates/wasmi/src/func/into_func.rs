@@ -184,6 +184,10 @@ impl_wasm_type! {
     type FuncRef = FuncRef;
     type ExternRef = ExternRef;
 }
+#[cfg(feature = "simd")]
+impl_wasm_type! {
+    type crate::core::V128 = V128;
+}
 
 /// A list of [`WasmType`] types.
 ///
@@ -1,3 +1,4 @@
+mod async_func;
 mod caller;
 mod error;
 mod func_type;
@@ -5,8 +6,9 @@ mod funcref;
 mod into_func;
 mod typed_func;
 
-pub(crate) use self::typed_func::CallResultsTuple;
+use self::async_func::HostFuture;
 pub use self::{
+    async_func::AsyncTrampoline,
     caller::Caller,
     error::FuncError,
     func_type::FuncType,
@@ -14,6 +16,10 @@ pub use self::{
     into_func::{IntoFunc, WasmRet, WasmType, WasmTypeList},
     typed_func::{TypedFunc, WasmParams, WasmResults},
 };
+pub(crate) use self::{
+    async_func::{AsyncHostCall, YieldNow},
+    typed_func::CallResultsTuple,
+};
 use super::{
     engine::{CompiledFunc, DedupFuncType, FuncFinished, FuncParams},
     AsContext,
@@ -22,10 +28,11 @@ use super::{
     StoreContext,
     Stored,
 };
-use crate::{core::Trap, engine::ResumableCall, Engine, Error, Value};
-use alloc::{boxed::Box, sync::Arc};
-use core::{fmt, fmt::Debug, num::NonZeroU32};
+use crate::{core::Trap, engine::ResumableCall, value::WithType, Engine, Error, Value};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{fmt, fmt::Debug, future::Future, num::NonZeroU32, pin::Pin};
 use wasmi_arena::ArenaIndex;
+use wasmi_core::UntypedValue;
 
 /// A raw index to a function entity.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -232,6 +239,56 @@ impl<T> HostFuncTrampolineEntity<T> {
         Self { ty, trampoline }
     }
 
+    /// Creates a new host function trampoline from the given dynamically typed asynchronous closure.
+    pub fn new_async(
+        engine: &Engine,
+        ty: FuncType,
+        func: impl for<'a> Fn(Caller<'a, T>, &'a [Value], &'a mut [Value]) -> HostFuture<'a>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        let params_iter = ty.params().iter().copied().map(Value::default);
+        let results_iter = ty.results().iter().copied().map(Value::default);
+        let len_params = ty.params().len();
+        let params_results: Arc<[Value]> = params_iter.chain(results_iter).collect();
+        let trampoline = <TrampolineEntity<T>>::new_async(params_results, len_params, func);
+        let ty = engine.alloc_func_type(ty);
+        Self { ty, trampoline }
+    }
+
+    /// Creates a new host function trampoline from the given statically typed asynchronous closure.
+    pub fn wrap_async<Params, Results>(
+        engine: &Engine,
+        func: impl for<'a> Fn(Caller<'a, T>, Params) -> Box<dyn Future<Output = Results> + Send + 'a>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        Params: WasmTypeList,
+        Results: WasmRet + 'static,
+    {
+        let ty = FuncType::new(
+            <Params as WasmTypeList>::types(),
+            <<Results as WasmRet>::Ok as WasmTypeList>::types(),
+        );
+        Self::new_async(engine, ty, move |caller, params, results| {
+            let params: Vec<UntypedValue> =
+                params.iter().cloned().map(UntypedValue::from).collect();
+            let params = <Params as WasmTypeList>::from_values(&params)
+                .expect("encountered unexpected invalid host function parameters");
+            let future = Pin::from(func(caller, params));
+            Box::new(async move {
+                let values = future.await.into_fallible()?.values();
+                for (dst, src) in results.iter_mut().zip(values) {
+                    *dst = src.with_type(dst.ty());
+                }
+                Ok(())
+            })
+        })
+    }
+
     /// Returns the signature of the host function.
     pub fn ty_dedup(&self) -> &DedupFuncType {
         &self.ty
@@ -247,7 +304,24 @@ type TrampolineFn<T> =
     dyn Fn(Caller<T>, FuncParams) -> Result<FuncFinished, Trap> + Send + Sync + 'static;
 
 pub struct TrampolineEntity<T> {
-    closure: Arc<TrampolineFn<T>>,
+    closure: TrampolineClosure<T>,
+}
+
+/// The closure of a synchronous or asynchronous host function.
+enum TrampolineClosure<T> {
+    /// A synchronous host function.
+    Sync(Arc<TrampolineFn<T>>),
+    /// An asynchronous host function.
+    Async(AsyncTrampoline<T>),
+}
+
+impl<T> Clone for TrampolineClosure<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Sync(closure) => Self::Sync(closure.clone()),
+            Self::Async(trampoline) => Self::Async(trampoline.clone()),
+        }
+    }
 }
 
 impl<T> Debug for TrampolineEntity<T> {
@@ -263,21 +337,59 @@ impl<T> TrampolineEntity<T> {
         F: Fn(Caller<T>, FuncParams) -> Result<FuncFinished, Trap> + Send + Sync + 'static,
     {
         Self {
-            closure: Arc::new(trampoline),
+            closure: TrampolineClosure::Sync(Arc::new(trampoline)),
+        }
+    }
+
+    /// Creates a new [`TrampolineEntity`] from the given asynchronous host function.
+    ///
+    /// The `params_results` buffer holds the default values of the parameter types
+    /// followed by the default values of the result types of the host function.
+    pub fn new_async<F>(params_results: Arc<[Value]>, len_params: usize, trampoline: F) -> Self
+    where
+        F: for<'a> Fn(Caller<'a, T>, &'a [Value], &'a mut [Value]) -> HostFuture<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            closure: TrampolineClosure::Async(AsyncTrampoline::new(
+                params_results,
+                len_params,
+                trampoline,
+            )),
+        }
+    }
+
+    /// Returns the [`AsyncTrampoline`] if the host function is asynchronous.
+    pub fn as_async(&self) -> Option<&AsyncTrampoline<T>> {
+        match &self.closure {
+            TrampolineClosure::Sync(_) => None,
+            TrampolineClosure::Async(trampoline) => Some(trampoline),
         }
     }
 
     /// Calls the host function trampoline with the given inputs.
     ///
     /// The result is written back into the `outputs` buffer.
+    ///
+    /// # Note
+    ///
+    /// Calling an asynchronous host function suspends the Wasm execution
+    /// by returning an [`AsyncHostCall`] error instead.
     pub fn call(
         &self,
         mut ctx: impl AsContextMut<UserState = T>,
         instance: Option<&Instance>,
         params: FuncParams,
     ) -> Result<FuncFinished, Trap> {
-        let caller = <Caller<T>>::new(&mut ctx, instance);
-        (self.closure)(caller, params)
+        match &self.closure {
+            TrampolineClosure::Sync(closure) => {
+                let caller = <Caller<T>>::new(&mut ctx, instance);
+                closure(caller, params)
+            }
+            TrampolineClosure::Async(trampoline) => Err(trampoline.suspend(instance, params)),
+        }
     }
 }
 
@@ -360,6 +472,70 @@ impl Func {
             .alloc_func(HostFuncEntity::new(ty_dedup, func).into())
     }
 
+    /// Creates a new asynchronous host [`Func`] with the given arguments.
+    ///
+    /// This is the asynchronous counterpart of [`Func::new`] where `func`
+    /// returns a [`Future`] that resolves once the host function finished.
+    ///
+    /// # Note
+    ///
+    /// - Asynchronous host functions can only be called via [`Func::call_async`]
+    ///   or [`TypedFunc::call_async`]. Calling them synchronously returns an error.
+    /// - When called using [`Func::call_resumable`] the asynchronous host function is
+    ///   not called but the execution is suspended with a resumable host error instead.
+    /// - The same responsibilities regarding the results buffer as for [`Func::new`] apply.
+    pub fn new_async<T>(
+        mut ctx: impl AsContextMut<UserState = T>,
+        ty: FuncType,
+        func: impl for<'a> Fn(
+                Caller<'a, T>,
+                &'a [Value],
+                &'a mut [Value],
+            ) -> Box<dyn Future<Output = Result<(), Trap>> + Send + 'a>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        let engine = ctx.as_context().store.engine();
+        let host_func = HostFuncTrampolineEntity::new_async(engine, ty, func);
+        let ty_dedup = *host_func.ty_dedup();
+        let trampoline = host_func.trampoline().clone();
+        let func = ctx.as_context_mut().store.alloc_trampoline(trampoline);
+        ctx.as_context_mut()
+            .store
+            .inner
+            .alloc_func(HostFuncEntity::new(ty_dedup, func).into())
+    }
+
+    /// Creates a new asynchronous host function from the given closure.
+    ///
+    /// This is the asynchronous counterpart of [`Func::wrap`] where `func`
+    /// receives its parameters as a tuple and returns a [`Future`] that
+    /// resolves to the results of the host function.
+    ///
+    /// For more information see [`Func::new_async`].
+    pub fn wrap_async<T, Params, Results>(
+        mut ctx: impl AsContextMut<UserState = T>,
+        func: impl for<'a> Fn(Caller<'a, T>, Params) -> Box<dyn Future<Output = Results> + Send + 'a>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        Params: WasmTypeList,
+        Results: WasmRet + 'static,
+    {
+        let engine = ctx.as_context().store.engine();
+        let host_func = HostFuncTrampolineEntity::wrap_async(engine, func);
+        let ty_dedup = *host_func.ty_dedup();
+        let trampoline = host_func.trampoline().clone();
+        let func = ctx.as_context_mut().store.alloc_trampoline(trampoline);
+        ctx.as_context_mut()
+            .store
+            .inner
+            .alloc_func(HostFuncEntity::new(ty_dedup, func).into())
+    }
+
     /// Returns the signature of the function.
     pub(crate) fn ty_dedup<'a, T: 'a>(
         &self,
@@ -376,6 +552,38 @@ impl Func {
             .resolve_func_type(self.ty_dedup(&ctx))
     }
 
+    /// Returns the offset of the Wasm operator within the Wasm binary of its [`Module`]
+    /// from which the `wasmi` instruction at `instr_offset` of the [`Func`] was translated.
+    ///
+    /// The `instr_offset` is relative to the first instruction of the [`Func`]
+    /// as reported by [`FrameInfo::instr_offset`] of Wasm backtraces.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if
+    ///
+    /// - the [`Func`] is a host function,
+    /// - the [`Func`] has not been compiled, yet, for example due to lazy compilation,
+    /// - the [`Func`] has been compiled without an address map, or
+    /// - `instr_offset` is out of bounds for the instructions of the [`Func`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Func`].
+    ///
+    /// [`Module`]: crate::Module
+    /// [`FrameInfo::instr_offset`]: crate::core::FrameInfo::instr_offset
+    pub fn wasm_offset(&self, ctx: impl AsContext, instr_offset: usize) -> Option<usize> {
+        let ctx = ctx.as_context();
+        match ctx.store.inner.resolve_func(self) {
+            FuncEntity::Wasm(func) => ctx
+                .store
+                .engine()
+                .wasm_offset(func.func_body(), instr_offset),
+            FuncEntity::Host(_) => None,
+        }
+    }
+
     /// Calls the Wasm or host function with the given inputs.
     ///
     /// The result is written back into the `outputs` buffer.
@@ -389,6 +597,17 @@ impl Func {
     ///   inputs required by the function signature of `self`.
     /// - If the number of output values does not match the expected number of
     ///   outputs required by the function signature of `self`.
+    ///
+    /// # Note
+    ///
+    /// This call cannot yield. If the epoch deadline of the [`Store`] is reached
+    /// the execution traps with [`TrapCode::EpochDeadlineExceeded`] even if
+    /// [`Store::epoch_deadline_yield_and_update`] was configured.
+    /// Use [`Func::call_resumable`] to let the execution yield instead.
+    ///
+    /// [`Store`]: crate::Store
+    /// [`Store::epoch_deadline_yield_and_update`]: crate::Store::epoch_deadline_yield_and_update
+    /// [`TrapCode::EpochDeadlineExceeded`]: crate::core::TrapCode::EpochDeadlineExceeded
     pub fn call<T>(
         &self,
         mut ctx: impl AsContextMut<UserState = T>,
@@ -446,6 +665,51 @@ impl Func {
             .map(ResumableCall::new)
     }
 
+    /// Calls the Wasm or host function with the given inputs asynchronously.
+    ///
+    /// The result is written back into the `outputs` buffer.
+    ///
+    /// The execution is suspended whenever it calls an asynchronous host function
+    /// that is not yet ready. The execution also yields back to the async executor
+    /// upon reaching the epoch deadline of the [`Store`] if it is configured to yield.
+    ///
+    /// # Errors
+    ///
+    /// - If the function returned a [`Trap`].
+    /// - If the types of the `inputs` do not match the expected types for the
+    ///   function signature of `self`.
+    /// - If the number of input values does not match the expected number of
+    ///   inputs required by the function signature of `self`.
+    /// - If the number of output values does not match the expected number of
+    ///   outputs required by the function signature of `self`.
+    ///
+    /// [`Store`]: crate::Store
+    pub async fn call_async<T>(
+        &self,
+        mut ctx: impl AsContextMut<UserState = T>,
+        inputs: &[Value],
+        outputs: &mut [Value],
+    ) -> Result<(), Error> {
+        let mut call = self.call_resumable(&mut ctx, inputs, outputs)?;
+        loop {
+            call = match call {
+                ResumableCall::Finished => return Ok(()),
+                ResumableCall::Resumable(mut invocation) => {
+                    let host_call = match invocation.take_async_host_call() {
+                        Some(host_call) => host_call,
+                        None => return Err(invocation.into_host_error().into()),
+                    };
+                    let results = host_call.call(&mut ctx, invocation.host_func()).await?;
+                    invocation.resume(&mut ctx, &results, outputs)?
+                }
+                ResumableCall::EpochYield(invocation) => {
+                    YieldNow::default().await;
+                    invocation.resume(&mut ctx, outputs)?
+                }
+            }
+        }
+    }
+
     /// Verify that the `inputs` and `outputs` value types match the function signature.
     ///
     /// Since [`Func`] is a dynamically typed function instance there is
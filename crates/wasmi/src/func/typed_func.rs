@@ -1,4 +1,4 @@
-use super::{into_func::WasmTypeList, Func};
+use super::{into_func::WasmTypeList, Func, YieldNow};
 use crate::{
     engine::{CallParams, CallResults},
     AsContext,
@@ -93,6 +93,11 @@ where
     /// # Errors
     ///
     /// If the execution of the called Wasm function traps.
+    /// This includes reaching the epoch deadline even if the [`Store`]
+    /// was configured to yield via [`Store::epoch_deadline_yield_and_update`].
+    ///
+    /// [`Store`]: crate::Store
+    /// [`Store::epoch_deadline_yield_and_update`]: crate::Store::epoch_deadline_yield_and_update
     pub fn call(&self, mut ctx: impl AsContextMut, params: Params) -> Result<Results, Trap> {
         // Note: Cloning an [`Engine`] is intentionally a cheap operation.
         ctx.as_context().store.engine().clone().execute_func(
@@ -136,6 +141,58 @@ where
             )
             .map(TypedResumableCall::new)
     }
+
+    /// Calls this Wasm or host function with the specified parameters asynchronously.
+    ///
+    /// Returns either the results of the call, or a [`Trap`] if one happened.
+    ///
+    /// For more information, see the [`Func::typed`] and [`Func::call_async`]
+    /// documentation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`TypedFunc`].
+    ///
+    /// # Errors
+    ///
+    /// If the execution of the called Wasm function traps.
+    pub async fn call_async<T>(
+        &self,
+        mut ctx: impl AsContextMut<UserState = T>,
+        params: Params,
+    ) -> Result<Results, Trap> {
+        let mut call = self.call_resumable(&mut ctx, params)?;
+        loop {
+            call = match call {
+                TypedResumableCall::Finished(results) => return Ok(results),
+                TypedResumableCall::Resumable(mut invocation) => {
+                    let host_call = match invocation.take_async_host_call() {
+                        Some(host_call) => host_call,
+                        None => return Err(invocation.into_host_error()),
+                    };
+                    let results = host_call.call(&mut ctx, invocation.host_func()).await?;
+                    invocation.resume(&mut ctx, &results).map_err(into_trap)?
+                }
+                TypedResumableCall::EpochYield(invocation) => {
+                    YieldNow::default().await;
+                    invocation.resume(&mut ctx).map_err(into_trap)?
+                }
+            }
+        }
+    }
+}
+
+/// Converts the `error` of resuming a [`TypedFunc`] invocation into a [`Trap`].
+///
+/// # Note
+///
+/// The resumptions of [`TypedFunc::call_async`] are well typed
+/// and therefore can only fail with a [`Trap`].
+fn into_trap(error: Error) -> Trap {
+    match error {
+        Error::Trap(trap) => trap,
+        error => panic!("encountered unexpected error upon resuming a typed call: {error}"),
+    }
 }
 
 impl<Params> CallParams for Params
@@ -1,6 +1,6 @@
-use super::{AsContext, AsContextMut, Stored};
+use super::{AsContext, AsContextMut, FuncRefCounts, Stored};
 use crate::{core::ValueType, value::WithType, Value};
-use core::{fmt, fmt::Display, ptr::NonNull};
+use core::{fmt, fmt::Display, mem, ptr::NonNull};
 use wasmi_arena::ArenaIndex;
 use wasmi_core::UntypedValue;
 
@@ -166,7 +166,11 @@ impl GlobalEntity {
     ///
     /// - If the global variable is immutable.
     /// - If there is a type mismatch between the global variable and the new value.
-    pub fn set(&mut self, new_value: Value) -> Result<(), GlobalError> {
+    pub fn set(
+        &mut self,
+        new_value: Value,
+        func_refs: &mut FuncRefCounts,
+    ) -> Result<(), GlobalError> {
         if !self.ty().mutability().is_mut() {
             return Err(GlobalError::ImmutableWrite);
         }
@@ -176,7 +180,7 @@ impl GlobalEntity {
                 encountered: new_value.ty(),
             });
         }
-        self.set_untyped(new_value.into());
+        self.set_untyped(new_value.into(), func_refs);
         Ok(())
     }
 
@@ -188,8 +192,11 @@ impl GlobalEntity {
     /// for efficient `global.set` through the interpreter which is
     /// safe since the interpreter only handles validated Wasm code
     /// where the checks in [`Global::set`] cannot fail.
-    pub(crate) fn set_untyped(&mut self, new_value: UntypedValue) {
-        self.value = new_value;
+    pub(crate) fn set_untyped(&mut self, new_value: UntypedValue, func_refs: &mut FuncRefCounts) {
+        let old_value = mem::replace(&mut self.value, new_value);
+        if matches!(self.ty().content(), ValueType::FuncRef) {
+            func_refs.replace(old_value, new_value);
+        }
     }
 
     /// Returns the current value of the global variable.
@@ -254,11 +261,12 @@ impl Global {
     ///
     /// Panics if `ctx` does not own this [`Global`].
     pub fn set(&self, mut ctx: impl AsContextMut, new_value: Value) -> Result<(), GlobalError> {
-        ctx.as_context_mut()
+        let (global, func_refs) = ctx
+            .as_context_mut()
             .store
             .inner
-            .resolve_global_mut(self)
-            .set(new_value)
+            .resolve_global_and_func_refs_mut(self);
+        global.set(new_value, func_refs)
     }
 
     /// Returns the current value of the global variable.
@@ -1,8 +1,11 @@
-use super::InstanceEntity;
+use super::{ImportedLen, InstanceEntity};
+#[cfg(feature = "dwarf")]
+use crate::module::DwarfInfo;
 use crate::{
-    engine::DedupFuncType,
+    engine::{CompiledFunc, DedupFuncType},
     memory::DataSegment,
-    module::FuncIdx,
+    module::{FuncIdx, ModuleIdx, ModuleNames},
+    pool::InstanceSlot,
     ElementSegment,
     Extern,
     ExternType,
@@ -11,21 +14,30 @@ use crate::{
     Memory,
     Module,
     Table,
+    Tag,
 };
 use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
 
 /// A module instance entity builder.
 #[derive(Debug)]
 pub struct InstanceEntityBuilder {
+    module_idx: ModuleIdx,
     func_types: Arc<[DedupFuncType]>,
     tables: Vec<Table>,
     funcs: Vec<Func>,
     memories: Vec<Memory>,
     globals: Vec<Global>,
+    tags: Vec<Tag>,
     start_fn: Option<FuncIdx>,
     exports: BTreeMap<Box<str>, Extern>,
     data_segments: Vec<DataSegment>,
     elem_segments: Vec<ElementSegment>,
+    imported: ImportedLen,
+    func_indices: Arc<BTreeMap<CompiledFunc, u32>>,
+    names: Arc<ModuleNames>,
+    #[cfg(feature = "dwarf")]
+    dwarf: Option<Arc<DwarfInfo>>,
+    pool_slot: Option<InstanceSlot>,
 }
 
 impl InstanceEntityBuilder {
@@ -36,36 +48,49 @@ impl InstanceEntityBuilder {
             v.reserve_exact(capacity);
             v
         }
-        let mut len_funcs = module.len_funcs();
-        let mut len_globals = module.len_globals();
-        let mut len_tables = module.len_tables();
-        let mut len_memories = module.len_memories();
+        let mut imported = ImportedLen::default();
         for import in module.imports() {
             match import.ty() {
                 ExternType::Func(_) => {
-                    len_funcs += 1;
+                    imported.funcs += 1;
                 }
                 ExternType::Table(_) => {
-                    len_tables += 1;
+                    imported.tables += 1;
                 }
                 ExternType::Memory(_) => {
-                    len_memories += 1;
+                    imported.memories += 1;
                 }
                 ExternType::Global(_) => {
-                    len_globals += 1;
+                    imported.globals += 1;
+                }
+                ExternType::Tag(_) => {
+                    imported.tags += 1;
                 }
             }
         }
+        let len_funcs = module.len_funcs() + imported.funcs;
+        let len_globals = module.len_globals() + imported.globals;
+        let len_tables = module.len_tables() + imported.tables;
+        let len_memories = module.len_memories() + imported.memories;
+        let len_tags = module.len_tags() + imported.tags;
         Self {
+            module_idx: module.idx(),
             func_types: module.func_types_cloned(),
             tables: vec_with_capacity_exact(len_tables),
             funcs: vec_with_capacity_exact(len_funcs),
             memories: vec_with_capacity_exact(len_memories),
             globals: vec_with_capacity_exact(len_globals),
+            tags: vec_with_capacity_exact(len_tags),
             start_fn: None,
             exports: BTreeMap::default(),
             data_segments: Vec::new(),
             elem_segments: Vec::new(),
+            imported,
+            func_indices: module.func_indices_cloned(),
+            names: module.names_cloned(),
+            #[cfg(feature = "dwarf")]
+            dwarf: module.dwarf_cloned(),
+            pool_slot: None,
         }
     }
 
@@ -124,6 +149,18 @@ impl InstanceEntityBuilder {
             .unwrap_or_else(|| panic!("missing `Global` at index: {index}"))
     }
 
+    /// Returns the [`Tag`] at the `index`.
+    ///
+    /// # Panics
+    ///
+    /// If there is no [`Tag`] at the given `index.
+    pub fn get_tag(&self, index: u32) -> Tag {
+        self.tags
+            .get(index as usize)
+            .copied()
+            .unwrap_or_else(|| panic!("missing `Tag` at index: {index}"))
+    }
+
     /// Returns the function at the `index`.
     ///
     /// # Panics
@@ -151,6 +188,11 @@ impl InstanceEntityBuilder {
         self.globals.push(global);
     }
 
+    /// Pushes a new [`Tag`] to the [`InstanceEntity`] under construction.
+    pub fn push_tag(&mut self, tag: Tag) {
+        self.tags.push(tag);
+    }
+
     /// Pushes a new [`Func`] to the [`InstanceEntity`] under construction.
     pub fn push_func(&mut self, func: Func) {
         self.funcs.push(func);
@@ -181,18 +223,33 @@ impl InstanceEntityBuilder {
         self.elem_segments.push(segment);
     }
 
+    /// Sets the [`PoolingAllocator`] slot held by the [`InstanceEntity`] under construction.
+    ///
+    /// [`PoolingAllocator`]: crate::PoolingAllocator
+    pub fn set_pool_slot(&mut self, slot: InstanceSlot) {
+        self.pool_slot = Some(slot);
+    }
+
     /// Finishes constructing the [`InstanceEntity`].
     pub fn finish(self) -> InstanceEntity {
         InstanceEntity {
             initialized: true,
+            module_idx: Some(self.module_idx),
             func_types: self.func_types,
             tables: self.tables.into(),
             funcs: self.funcs.into(),
             memories: self.memories.into(),
             globals: self.globals.into(),
+            tags: self.tags.into(),
             exports: self.exports,
             data_segments: self.data_segments.into(),
             elem_segments: self.elem_segments.into(),
+            imported: self.imported,
+            func_indices: self.func_indices,
+            names: self.names,
+            #[cfg(feature = "dwarf")]
+            dwarf: self.dwarf,
+            _pool_slot: self.pool_slot,
         }
     }
 }
@@ -1,7 +1,7 @@
 pub(crate) use self::builder::InstanceEntityBuilder;
 pub use self::exports::{Export, ExportsIter, Extern, ExternType};
 use super::{
-    engine::DedupFuncType,
+    engine::{CompiledFunc, DedupFuncType},
     AsContext,
     Func,
     Global,
@@ -10,10 +10,15 @@ use super::{
     StoreContext,
     Stored,
     Table,
+    Tag,
 };
+#[cfg(feature = "dwarf")]
+use crate::module::DwarfInfo;
 use crate::{
     func::FuncError,
     memory::DataSegment,
+    module::{ModuleIdx, ModuleNames},
+    pool::InstanceSlot,
     ElementSegment,
     Error,
     TypedFunc,
@@ -21,6 +26,7 @@ use crate::{
     WasmResults,
 };
 use alloc::{boxed::Box, collections::BTreeMap, sync::Arc};
+use core::{fmt, fmt::Display};
 use wasmi_arena::ArenaIndex;
 
 mod builder;
@@ -47,14 +53,34 @@ impl ArenaIndex for InstanceIdx {
 #[derive(Debug)]
 pub struct InstanceEntity {
     initialized: bool,
+    /// The index of the [`Module`] the instance has been instantiated from if initialized.
+    module_idx: Option<ModuleIdx>,
     func_types: Arc<[DedupFuncType]>,
     tables: Box<[Table]>,
     funcs: Box<[Func]>,
     memories: Box<[Memory]>,
     globals: Box<[Global]>,
+    tags: Box<[Tag]>,
     exports: BTreeMap<Box<str>, Extern>,
     data_segments: Box<[DataSegment]>,
     elem_segments: Box<[ElementSegment]>,
+    /// The number of imported entities per kind of entity.
+    imported: ImportedLen,
+    /// The indices of the functions defined by the instance by their [`CompiledFunc`].
+    func_indices: Arc<BTreeMap<CompiledFunc, u32>>,
+    /// The debug names of the [`Module`] of the instance.
+    names: Arc<ModuleNames>,
+    /// The DWARF debug information of the [`Module`] of the instance if any.
+    #[cfg(feature = "dwarf")]
+    dwarf: Option<Arc<DwarfInfo>>,
+    /// The slot of the [`PoolingAllocator`] held by the instance if any.
+    ///
+    /// # Note
+    ///
+    /// The slot is returned to the [`PoolingAllocator`] when the instance is dropped.
+    ///
+    /// [`PoolingAllocator`]: crate::PoolingAllocator
+    _pool_slot: Option<InstanceSlot>,
 }
 
 impl InstanceEntity {
@@ -62,14 +88,22 @@ impl InstanceEntity {
     pub fn uninitialized() -> InstanceEntity {
         Self {
             initialized: false,
+            module_idx: None,
             func_types: Arc::new([]),
             tables: [].into(),
             funcs: [].into(),
             memories: [].into(),
             globals: [].into(),
+            tags: [].into(),
             exports: BTreeMap::new(),
             data_segments: [].into(),
             elem_segments: [].into(),
+            imported: ImportedLen::default(),
+            func_indices: Arc::default(),
+            names: Arc::default(),
+            #[cfg(feature = "dwarf")]
+            dwarf: None,
+            _pool_slot: None,
         }
     }
 
@@ -98,6 +132,11 @@ impl InstanceEntity {
         self.globals.get(index as usize).copied()
     }
 
+    /// Returns the tag at the `index` if any.
+    pub fn get_tag(&self, index: u32) -> Option<Tag> {
+        self.tags.get(index as usize).copied()
+    }
+
     /// Returns the function at the `index` if any.
     pub fn get_func(&self, index: u32) -> Option<Func> {
         self.funcs.get(index as usize).copied()
@@ -108,6 +147,13 @@ impl InstanceEntity {
         self.func_types.get(index as usize)
     }
 
+    /// Returns the index of the [`Module`] the [`InstanceEntity`] was instantiated from.
+    ///
+    /// Returns `None` if the [`InstanceEntity`] is uninitialized.
+    pub fn module_idx(&self) -> Option<ModuleIdx> {
+        self.module_idx
+    }
+
     /// Returns the [`DataSegment`] at the `index` if any.
     pub fn get_data_segment(&self, index: u32) -> Option<DataSegment> {
         self.data_segments.get(index as usize).copied()
@@ -118,6 +164,74 @@ impl InstanceEntity {
         self.elem_segments.get(index as usize).copied()
     }
 
+    /// Returns the functions defined by the [`InstanceEntity`] excluding imported functions.
+    pub fn owned_funcs(&self) -> &[Func] {
+        &self.funcs[self.imported.funcs..]
+    }
+
+    /// Returns the tables defined by the [`InstanceEntity`] excluding imported tables.
+    pub fn owned_tables(&self) -> &[Table] {
+        &self.tables[self.imported.tables..]
+    }
+
+    /// Returns the linear memories defined by the [`InstanceEntity`] excluding imported ones.
+    pub fn owned_memories(&self) -> &[Memory] {
+        &self.memories[self.imported.memories..]
+    }
+
+    /// Returns the global variables defined by the [`InstanceEntity`] excluding imported ones.
+    pub fn owned_globals(&self) -> &[Global] {
+        &self.globals[self.imported.globals..]
+    }
+
+    /// Returns the tags defined by the [`InstanceEntity`] excluding imported tags.
+    pub fn owned_tags(&self) -> &[Tag] {
+        &self.tags[self.imported.tags..]
+    }
+
+    /// Returns an iterator over all entities imported by the [`InstanceEntity`].
+    pub fn imports(&self) -> impl Iterator<Item = Extern> + '_ {
+        let funcs = self.funcs[..self.imported.funcs].iter().copied();
+        let tables = self.tables[..self.imported.tables].iter().copied();
+        let memories = self.memories[..self.imported.memories].iter().copied();
+        let globals = self.globals[..self.imported.globals].iter().copied();
+        let tags = self.tags[..self.imported.tags].iter().copied();
+        funcs
+            .map(Extern::Func)
+            .chain(tables.map(Extern::Table))
+            .chain(memories.map(Extern::Memory))
+            .chain(globals.map(Extern::Global))
+            .chain(tags.map(Extern::Tag))
+    }
+
+    /// Returns the index of the function compiled to `func_body` within the function index space.
+    ///
+    /// Returns `None` if `func_body` has not been defined by the [`InstanceEntity`].
+    pub fn func_index(&self, func_body: CompiledFunc) -> Option<u32> {
+        self.func_indices.get(&func_body).copied()
+    }
+
+    /// Returns the debug names of the [`Module`] of the [`InstanceEntity`].
+    pub fn names(&self) -> &ModuleNames {
+        &self.names
+    }
+
+    /// Returns the DWARF debug information of the [`Module`] of the [`InstanceEntity`] if any.
+    #[cfg(feature = "dwarf")]
+    pub fn dwarf(&self) -> Option<&DwarfInfo> {
+        self.dwarf.as_deref()
+    }
+
+    /// Returns the [`DataSegment`]s of the [`InstanceEntity`].
+    pub fn data_segments(&self) -> &[DataSegment] {
+        &self.data_segments
+    }
+
+    /// Returns the [`ElementSegment`]s of the [`InstanceEntity`].
+    pub fn elem_segments(&self) -> &[ElementSegment] {
+        &self.elem_segments
+    }
+
     /// Returns the value exported to the given `name` if any.
     pub fn get_export(&self, name: &str) -> Option<Extern> {
         self.exports.get(name).copied()
@@ -131,6 +245,61 @@ impl InstanceEntity {
     }
 }
 
+/// An error that may occur upon removing an [`Instance`] from its [`Store`].
+///
+/// [`Store`]: crate::Store
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum InstanceError {
+    /// Encountered when another [`Instance`] imports an entity owned by the removed [`Instance`].
+    ImportedEntity {
+        /// The imported entity owned by the removed [`Instance`].
+        entity: Extern,
+    },
+    /// Encountered when a table or global variable refers to a function owned by the removed [`Instance`].
+    ReferencedFunc {
+        /// The referenced function owned by the removed [`Instance`].
+        func: Func,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InstanceError {}
+
+impl Display for InstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ImportedEntity { entity } => {
+                write!(
+                    f,
+                    "cannot remove instance since another instance imports its entity: {entity:?}"
+                )
+            }
+            Self::ReferencedFunc { func } => {
+                write!(
+                    f,
+                    "cannot remove instance since a table or global variable refers to its function: {func:?}"
+                )
+            }
+        }
+    }
+}
+
+/// The number of imported entities of an [`InstanceEntity`] per kind of entity.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ImportedLen {
+    /// The number of imported [`Func`]s.
+    pub funcs: usize,
+    /// The number of imported [`Table`]s.
+    pub tables: usize,
+    /// The number of imported [`Memory`]s.
+    pub memories: usize,
+    /// The number of imported [`Global`]s.
+    pub globals: usize,
+    /// The number of imported [`Tag`]s.
+    pub tags: usize,
+}
+
 /// An instantiated WebAssembly [`Module`].
 ///
 /// This type represents an instantiation of a [`Module`].
@@ -177,6 +346,31 @@ impl Instance {
             .get_func(index)
     }
 
+    /// Returns the offset of the Wasm operator within the Wasm binary of its [`Module`]
+    /// from which the `wasmi` instruction at `instr_offset` of the function at `func_index` was translated.
+    ///
+    /// This resolves the [`FrameInfo::func_index`] and [`FrameInfo::instr_offset`]
+    /// of Wasm backtraces. See [`Func::wasm_offset`] for details.
+    ///
+    /// Returns `None` if there is no function at `func_index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this [`Instance`].
+    ///
+    /// [`Module`]: crate::Module
+    /// [`FrameInfo::func_index`]: crate::core::FrameInfo::func_index
+    /// [`FrameInfo::instr_offset`]: crate::core::FrameInfo::instr_offset
+    pub fn wasm_offset(
+        &self,
+        store: impl AsContext,
+        func_index: u32,
+        instr_offset: usize,
+    ) -> Option<usize> {
+        self.get_func_by_index(&store, func_index)?
+            .wasm_offset(&store, instr_offset)
+    }
+
     /// Returns the value exported to the given `name` if any.
     ///
     /// # Panics
@@ -268,6 +462,18 @@ impl Instance {
         self.get_export(store, name)?.into_memory()
     }
 
+    /// Looks up an exported [`Tag`] value by `name`.
+    ///
+    /// Returns `None` if there was no export named `name`,
+    /// or if there was but it wasn’t a tag.
+    ///
+    /// # Panics
+    ///
+    /// If `store` does not own this [`Instance`].
+    pub fn get_tag(&self, store: impl AsContext, name: &str) -> Option<Tag> {
+        self.get_export(store, name)?.into_tag()
+    }
+
     /// Returns an iterator over the exports of the [`Instance`].
     ///
     /// The order of the yielded exports is not specified.
@@ -93,11 +93,14 @@ mod externref;
 mod func;
 mod global;
 mod instance;
+mod limits;
 mod linker;
 mod memory;
 mod module;
+mod pool;
 mod store;
 mod table;
+mod tag;
 mod value;
 
 /// Definitions from the `wasmi_core` crate.
@@ -109,22 +112,56 @@ pub mod errors {
     pub use super::{
         func::FuncError,
         global::GlobalError,
+        instance::InstanceError,
         linker::LinkerError,
         memory::MemoryError,
-        module::{InstantiationError, ModuleError},
+        module::{InstantiationError, ModuleError, SerializationError, SnapshotError},
         store::FuelError,
         table::TableError,
+        tag::TagError,
+    };
+}
+
+/// Defines the API to trace the execution of Wasm functions.
+#[cfg(feature = "trace")]
+pub mod trace {
+    pub use super::engine::{
+        bytecode::{
+            AddressOffset,
+            BlockFuel,
+            BranchOffset,
+            BranchTableTargets,
+            DataSegmentIdx,
+            DropKeep,
+            ElementSegmentIdx,
+            FuncIdx,
+            GlobalIdx,
+            Instruction,
+            LaneIdx,
+            LocalDepth,
+            MemArg,
+            MemoryIdx,
+            SignatureIdx,
+            TableIdx,
+            TagIdx,
+        },
+        ConstRef,
+        MemoryAccess,
+        Tracer,
     };
 }
 
 pub use self::{
     engine::{
+        CompilationMode,
         Config,
         Engine,
+        EpochYieldInvocation,
         FuelConsumptionMode,
         ResumableCall,
         ResumableInvocation,
         StackLimits,
+        TypedEpochYieldInvocation,
         TypedResumableCall,
         TypedResumableInvocation,
     },
@@ -145,26 +182,33 @@ pub use self::{
     },
     global::{Global, GlobalType, Mutability},
     instance::{Export, ExportsIter, Extern, ExternType, Instance},
-    linker::Linker,
-    memory::{Memory, MemoryType},
+    limits::{ResourceLimiter, StoreLimits, StoreLimitsBuilder},
+    linker::{LinkedModule, Linker},
+    memory::{LinearMemory, Memory, MemoryCreator, MemoryType, SharedMemory},
     module::{
+        CustomSectionsIter,
         ExportType,
         ImportType,
         InstancePre,
         Module,
         ModuleExportsIter,
         ModuleImportsIter,
+        ModuleNames,
         Read,
     },
+    pool::{PoolMetrics, PoolingAllocationConfig, PoolingAllocator},
     store::{AsContext, AsContextMut, Store, StoreContext, StoreContextMut},
     table::{Table, TableType},
+    tag::{Exception, Tag, TagType},
     value::Value,
 };
 use self::{
     func::{FuncEntity, FuncIdx},
     global::{GlobalEntity, GlobalIdx},
     instance::{InstanceEntity, InstanceEntityBuilder, InstanceIdx},
+    limits::ResourceLimiterRef,
     memory::{DataSegmentEntity, DataSegmentIdx, MemoryEntity, MemoryIdx},
-    store::{StoreInner, Stored},
+    store::{FuncRefCounts, StoreInner, Stored},
     table::{ElementSegment, ElementSegmentEntity, ElementSegmentIdx, TableEntity, TableIdx},
+    tag::{TagEntity, TagIdx},
 };
@@ -0,0 +1,359 @@
+use crate::{errors::TableError, memory::MemoryError};
+use alloc::boxed::Box;
+use core::fmt::{self, Debug};
+
+/// Value returned by [`ResourceLimiter::instances`] default method.
+pub const DEFAULT_INSTANCE_LIMIT: usize = 10_000;
+
+/// Value returned by [`ResourceLimiter::tables`] default method.
+pub const DEFAULT_TABLE_LIMIT: usize = 10_000;
+
+/// Value returned by [`ResourceLimiter::memories`] default method.
+pub const DEFAULT_MEMORY_LIMIT: usize = 10_000;
+
+/// Used by hosts to limit resource consumption of instances.
+///
+/// Resources limited via this trait are primarily related to memory.
+///
+/// A [`ResourceLimiter`] can be installed into a [`Store`] via [`Store::limiter`]
+/// and is then consulted whenever a linear memory or table is created or grown
+/// and whenever a new module instance is about to be created.
+///
+/// [`Store`]: crate::Store
+/// [`Store::limiter`]: crate::Store::limiter
+pub trait ResourceLimiter {
+    /// Notifies the resource limiter that an instance's linear memory has been
+    /// requested to grow.
+    ///
+    /// - `current` is the current size of the linear memory in bytes.
+    /// - `desired` is the desired size of the linear memory in bytes.
+    /// - `maximum` is either the linear memory's maximum or a maximum from an
+    ///   instance allocator, also in bytes. A value of `None`
+    ///   indicates that the linear memory is unbounded.
+    ///
+    /// The `current` and `desired` amounts are guaranteed to always be
+    /// multiples of the WebAssembly page size, 64KiB.
+    ///
+    /// # Return Value
+    ///
+    /// - `Ok(true)`: the linear memory is allowed to grow.
+    /// - `Ok(false)`: the linear memory is not allowed to grow. The Wasm
+    ///   `memory.grow` instruction returns `-1` in this case and all host side
+    ///   APIs return an appropriate [`MemoryError`].
+    ///
+    /// # Errors
+    ///
+    /// If an `Err` is returned the growth is denied. If the growth was requested
+    /// by the `memory.grow` instruction the Wasm execution traps with
+    /// [`TrapCode::GrowthOperationLimited`] and otherwise the error is forwarded
+    /// to the host side caller.
+    ///
+    /// [`TrapCode::GrowthOperationLimited`]: crate::core::TrapCode::GrowthOperationLimited
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> Result<bool, MemoryError>;
+
+    /// Notifies the resource limiter that an instance's table has been requested to grow.
+    ///
+    /// - `current` is the current number of elements in the table.
+    /// - `desired` is the desired number of elements in the table.
+    /// - `maximum` is either the table's maximum or a maximum from an instance allocator.
+    ///   A value of `None` indicates that the table is unbounded.
+    ///
+    /// # Return Value
+    ///
+    /// - `Ok(true)`: the table is allowed to grow.
+    /// - `Ok(false)`: the table is not allowed to grow. The Wasm
+    ///   `table.grow` instruction returns `-1` in this case and all host side
+    ///   APIs return an appropriate [`TableError`].
+    ///
+    /// # Errors
+    ///
+    /// If an `Err` is returned the growth is denied. If the growth was requested
+    /// by the `table.grow` instruction the Wasm execution traps with
+    /// [`TrapCode::GrowthOperationLimited`] and otherwise the error is forwarded
+    /// to the host side caller.
+    ///
+    /// [`TrapCode::GrowthOperationLimited`]: crate::core::TrapCode::GrowthOperationLimited
+    fn table_growing(
+        &mut self,
+        current: u32,
+        desired: u32,
+        maximum: Option<u32>,
+    ) -> Result<bool, TableError>;
+
+    /// Notifies the resource limiter that growing a linear memory, permitted by
+    /// the [`ResourceLimiter::memory_growing`] method, has failed.
+    fn memory_grow_failed(&mut self, _error: &MemoryError) {}
+
+    /// Notifies the resource limiter that growing a table, permitted by
+    /// the [`ResourceLimiter::table_growing`] method, has failed.
+    fn table_grow_failed(&mut self, _error: &TableError) {}
+
+    /// The maximum number of instances that can be created for a [`Store`].
+    ///
+    /// Module instantiation will fail if this limit is exceeded.
+    ///
+    /// This value defaults to 10,000.
+    ///
+    /// [`Store`]: crate::Store
+    fn instances(&self) -> usize {
+        DEFAULT_INSTANCE_LIMIT
+    }
+
+    /// The maximum number of tables that can be created for a [`Store`].
+    ///
+    /// Creation of tables will fail if this limit is exceeded.
+    ///
+    /// This value defaults to 10,000.
+    ///
+    /// [`Store`]: crate::Store
+    fn tables(&self) -> usize {
+        DEFAULT_TABLE_LIMIT
+    }
+
+    /// The maximum number of linear memories that can be created for a [`Store`].
+    ///
+    /// Creation of linear memories will fail if this limit is exceeded.
+    ///
+    /// This value defaults to 10,000.
+    ///
+    /// [`Store`]: crate::Store
+    fn memories(&self) -> usize {
+        DEFAULT_MEMORY_LIMIT
+    }
+}
+
+/// The type of the closure that queries the [`ResourceLimiter`] from the host state `T`.
+type ResourceLimiterQueryFn<T> =
+    dyn FnMut(&mut T) -> &mut (dyn ResourceLimiter) + Send + Sync + 'static;
+
+/// Queries the [`ResourceLimiter`] of a [`Store`] from its host state `T`.
+///
+/// [`Store`]: crate::Store
+pub(crate) struct ResourceLimiterQuery<T>(Box<ResourceLimiterQueryFn<T>>);
+
+impl<T> Debug for ResourceLimiterQuery<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResourceLimiterQuery").finish()
+    }
+}
+
+impl<T> ResourceLimiterQuery<T> {
+    /// Creates a new [`ResourceLimiterQuery`] from the given closure.
+    pub fn new(
+        query: impl FnMut(&mut T) -> &mut (dyn ResourceLimiter) + Send + Sync + 'static,
+    ) -> Self {
+        Self(Box::new(query))
+    }
+
+    /// Queries the [`ResourceLimiter`] from the host state `data`.
+    pub fn query<'a>(&mut self, data: &'a mut T) -> &'a mut dyn ResourceLimiter {
+        (self.0)(data)
+    }
+}
+
+/// An optional exclusive reference to the [`ResourceLimiter`] of a [`Store`].
+///
+/// # Note
+///
+/// This is used internally to thread the [`ResourceLimiter`] through the
+/// APIs that are not generic over the host state `T` such as the executor.
+///
+/// [`Store`]: crate::Store
+#[derive(Default)]
+pub struct ResourceLimiterRef<'a>(Option<&'a mut dyn ResourceLimiter>);
+
+impl Debug for ResourceLimiterRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ResourceLimiterRef")
+            .field(&self.0.is_some())
+            .finish()
+    }
+}
+
+impl<'a> From<&'a mut dyn ResourceLimiter> for ResourceLimiterRef<'a> {
+    fn from(limiter: &'a mut dyn ResourceLimiter) -> Self {
+        Self(Some(limiter))
+    }
+}
+
+impl ResourceLimiterRef<'_> {
+    /// Returns an exclusive reference to the [`ResourceLimiter`] if any.
+    pub fn as_resource_limiter(&mut self) -> Option<&mut dyn ResourceLimiter> {
+        match self.0.as_mut() {
+            Some(limiter) => Some(*limiter),
+            None => None,
+        }
+    }
+}
+
+/// Provides limits for a [`Store`].
+///
+/// This type is created with a [`StoreLimitsBuilder`] and is typically used in
+/// conjunction with [`Store::limiter`].
+///
+/// This is a convenience type included to avoid needing to implement the
+/// [`ResourceLimiter`] trait if your use case fits in the static configuration
+/// that this [`StoreLimits`] provides.
+///
+/// [`Store`]: crate::Store
+/// [`Store::limiter`]: crate::Store::limiter
+#[derive(Debug, Clone)]
+pub struct StoreLimits {
+    memory_size: Option<usize>,
+    table_elements: Option<u32>,
+    instances: usize,
+    tables: usize,
+    memories: usize,
+    trap_on_grow_failure: bool,
+}
+
+impl Default for StoreLimits {
+    fn default() -> Self {
+        Self {
+            memory_size: None,
+            table_elements: None,
+            instances: DEFAULT_INSTANCE_LIMIT,
+            tables: DEFAULT_TABLE_LIMIT,
+            memories: DEFAULT_MEMORY_LIMIT,
+            trap_on_grow_failure: false,
+        }
+    }
+}
+
+impl ResourceLimiter for StoreLimits {
+    fn memory_growing(
+        &mut self,
+        _current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> Result<bool, MemoryError> {
+        let allow = match self.memory_size {
+            Some(limit) if desired > limit => false,
+            _ => !matches!(maximum, Some(max) if desired > max),
+        };
+        if !allow && self.trap_on_grow_failure {
+            return Err(MemoryError::ResourceLimiterDeniedAllocation);
+        }
+        Ok(allow)
+    }
+
+    fn table_growing(
+        &mut self,
+        _current: u32,
+        desired: u32,
+        maximum: Option<u32>,
+    ) -> Result<bool, TableError> {
+        let allow = match self.table_elements {
+            Some(limit) if desired > limit => false,
+            _ => !matches!(maximum, Some(max) if desired > max),
+        };
+        if !allow && self.trap_on_grow_failure {
+            return Err(TableError::ResourceLimiterDeniedAllocation);
+        }
+        Ok(allow)
+    }
+
+    fn instances(&self) -> usize {
+        self.instances
+    }
+
+    fn tables(&self) -> usize {
+        self.tables
+    }
+
+    fn memories(&self) -> usize {
+        self.memories
+    }
+}
+
+/// Used to build [`StoreLimits`].
+#[derive(Debug, Default, Clone)]
+pub struct StoreLimitsBuilder(StoreLimits);
+
+impl StoreLimitsBuilder {
+    /// Creates a new [`StoreLimitsBuilder`].
+    ///
+    /// See the documentation of each builder method for the default value of each limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of bytes a linear memory can grow to.
+    ///
+    /// Growing a linear memory beyond this limit will fail. This limit is
+    /// applied to each linear memory individually, so if a Wasm module has
+    /// multiple linear memories then they're all allowed to reach up to the
+    /// `limit` specified.
+    ///
+    /// By default, linear memory will not be limited.
+    pub fn memory_size(mut self, limit: usize) -> Self {
+        self.0.memory_size = Some(limit);
+        self
+    }
+
+    /// The maximum number of elements in a table.
+    ///
+    /// Growing a table beyond this limit will fail. This limit is applied
+    /// to each table individually, so if a Wasm module has multiple tables
+    /// then they're all allowed to reach up to the `limit` specified.
+    ///
+    /// By default, table elements will not be limited.
+    pub fn table_elements(mut self, limit: u32) -> Self {
+        self.0.table_elements = Some(limit);
+        self
+    }
+
+    /// The maximum number of instances that can be created for a [`Store`](crate::Store).
+    ///
+    /// Module instantiation will fail if this limit is exceeded.
+    ///
+    /// This value defaults to 10,000.
+    pub fn instances(mut self, limit: usize) -> Self {
+        self.0.instances = limit;
+        self
+    }
+
+    /// The maximum number of tables that can be created for a [`Store`](crate::Store).
+    ///
+    /// Module instantiation will fail if this limit is exceeded.
+    ///
+    /// This value defaults to 10,000.
+    pub fn tables(mut self, tables: usize) -> Self {
+        self.0.tables = tables;
+        self
+    }
+
+    /// The maximum number of linear memories that can be created for a [`Store`](crate::Store).
+    ///
+    /// Instantiation will fail with an error if this limit is exceeded.
+    ///
+    /// This value defaults to 10,000.
+    pub fn memories(mut self, memories: usize) -> Self {
+        self.0.memories = memories;
+        self
+    }
+
+    /// Indicates that a trap should be raised whenever a growth operation
+    /// would fail.
+    ///
+    /// This operation will force `memory.grow` and `table.grow` instructions
+    /// to raise a trap on failure instead of returning -1. This is not
+    /// necessarily spec-compliant, but it can be quite handy when debugging a
+    /// module that fails to allocate memory and might behave oddly as a result.
+    ///
+    /// This value defaults to `false`.
+    pub fn trap_on_grow_failure(mut self, trap: bool) -> Self {
+        self.0.trap_on_grow_failure = trap;
+        self
+    }
+
+    /// Consumes this builder and returns the [`StoreLimits`].
+    pub fn build(self) -> StoreLimits {
+        self.0
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    core::Trap,
-    func::{FuncEntity, HostFuncEntity, HostFuncTrampolineEntity},
+    core::{Trap, ValueType},
+    func::{FuncEntity, HostFuncEntity, HostFuncTrampolineEntity, WasmRet, WasmTypeList},
     module::{ImportName, ImportType},
     AsContext,
     AsContextMut,
@@ -12,21 +12,27 @@ use crate::{
     Func,
     FuncType,
     GlobalType,
+    Instance,
     InstancePre,
     IntoFunc,
     MemoryType,
     Module,
     TableType,
+    TagType,
     Value,
 };
 use alloc::{
+    boxed::Box,
     collections::{btree_map::Entry, BTreeMap},
+    format,
+    string::ToString,
     sync::Arc,
     vec::Vec,
 };
 use core::{
     fmt,
     fmt::{Debug, Display},
+    future::Future,
     num::NonZeroUsize,
     ops::Deref,
 };
@@ -39,6 +45,15 @@ pub enum LinkerError {
         /// The duplicate import name of the definition.
         import_name: ImportName,
     },
+    /// Encountered when trying to alias a definition that does not exist.
+    UnknownDefinition {
+        /// The module of the definition that does not exist.
+        module: Box<str>,
+        /// The name of the definition that does not exist if any.
+        ///
+        /// This is `None` if there are no definitions in `module` at all.
+        name: Option<Box<str>>,
+    },
     /// Encountered when no definition for an import is found.
     MissingDefinition {
         /// The name of the import for which no definition was found.
@@ -91,6 +106,15 @@ pub enum LinkerError {
         /// The mismatching [`GlobalType`] found.
         found: GlobalType,
     },
+    /// Encountered when a [`TagType`] does not match the expected [`TagType`].
+    TagTypeMismatch {
+        /// The name of the import with the mismatched type.
+        name: ImportName,
+        /// The expected [`TagType`].
+        expected: TagType,
+        /// The mismatching [`TagType`] found.
+        found: TagType,
+    },
 }
 
 impl LinkerError {
@@ -146,6 +170,15 @@ impl LinkerError {
             found: *found,
         }
     }
+
+    /// Create a new [`LinkerError`] for when a [`TagType`] mismatched.
+    fn tag_type_mismatch(name: &ImportName, expected: &TagType, found: &TagType) -> Self {
+        Self::TagTypeMismatch {
+            name: name.clone(),
+            expected: expected.clone(),
+            found: found.clone(),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -160,6 +193,15 @@ impl Display for LinkerError {
                     "encountered duplicate definition with name `{import_name}`",
                 )
             }
+            Self::UnknownDefinition {
+                module,
+                name: Some(name),
+            } => {
+                write!(f, "cannot find definition with name `{module}::{name}`")
+            }
+            Self::UnknownDefinition { module, name: None } => {
+                write!(f, "cannot find any definition in module `{module}`")
+            }
             Self::MissingDefinition { name, ty } => {
                 write!(
                     f,
@@ -207,6 +249,17 @@ impl Display for LinkerError {
                     expected {expected:?} but found {found:?}",
                 )
             }
+            Self::TagTypeMismatch {
+                name,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "tag type mismatch for import {name}: \
+                    expected {expected:?} but found {found:?}",
+                )
+            }
         }
     }
 }
@@ -405,6 +458,41 @@ impl<'a, T> Debug for DebugDefinitions<'a, T> {
     }
 }
 
+/// [`Debug`]-wrapper for the resolved definitions of a [`LinkedModule`].
+pub struct DebugResolvedDefinitions<'a, T> {
+    /// The [`Engine`] of the [`LinkedModule`].
+    engine: &'a Engine,
+    /// The resolved definitions of the [`LinkedModule`].
+    definitions: &'a [Definition<T>],
+}
+
+impl<'a, T> DebugResolvedDefinitions<'a, T> {
+    /// Create a new [`Debug`]-wrapper for the [`LinkedModule`] definitions.
+    fn new(engine: &'a Engine, definitions: &'a [Definition<T>]) -> Self {
+        Self {
+            engine,
+            definitions,
+        }
+    }
+}
+
+impl<'a, T> Debug for DebugResolvedDefinitions<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for definition in self.definitions {
+            match definition {
+                Definition::Extern(definition) => {
+                    list.entry(definition);
+                }
+                Definition::HostFunc(definition) => {
+                    list.entry(&DebugHostFuncEntity::new(self.engine, definition));
+                }
+            }
+        }
+        list.finish()
+    }
+}
+
 /// [`Debug`]-wrapper for [`HostFuncTrampolineEntity`] in the [`Linker`].
 pub struct DebugHostFuncEntity<'a, T> {
     /// The [`Engine`] of the [`Linker`].
@@ -442,6 +530,8 @@ pub struct Linker<T> {
     strings: StringInterner,
     /// Stores the definitions given their names.
     definitions: BTreeMap<ImportKey, Definition<T>>,
+    /// Is `true` if definitions may shadow previous definitions with the same name.
+    allow_shadowing: bool,
 }
 
 impl<T> Debug for Linker<T> {
@@ -449,6 +539,7 @@ impl<T> Debug for Linker<T> {
         f.debug_struct("Linker")
             .field("strings", &self.strings)
             .field("definitions", &DebugDefinitions::new(self))
+            .field("allow_shadowing", &self.allow_shadowing)
             .finish()
     }
 }
@@ -459,6 +550,7 @@ impl<T> Clone for Linker<T> {
             engine: self.engine.clone(),
             strings: self.strings.clone(),
             definitions: self.definitions.clone(),
+            allow_shadowing: self.allow_shadowing,
         }
     }
 }
@@ -476,6 +568,7 @@ impl<T> Linker<T> {
             engine: engine.clone(),
             strings: StringInterner::default(),
             definitions: BTreeMap::default(),
+            allow_shadowing: false,
         }
     }
 
@@ -484,6 +577,21 @@ impl<T> Linker<T> {
         &self.engine
     }
 
+    /// Configures whether definitions of this [`Linker`] may shadow previous definitions.
+    ///
+    /// By default this is `false` and defining an item under a name that is already
+    /// defined in this [`Linker`] returns [`LinkerError::DuplicateDefinition`].
+    /// If set to `true` the new definition replaces the previous one instead.
+    ///
+    /// # Note
+    ///
+    /// Shadowing a definition does not affect [`Instance`]s that have already
+    /// been instantiated using the previous definition.
+    pub fn allow_shadowing(&mut self, allow: bool) -> &mut Self {
+        self.allow_shadowing = allow;
+        self
+    }
+
     /// Define a new item in this [`Linker`].
     ///
     /// # Errors
@@ -553,6 +661,341 @@ impl<T> Linker<T> {
         Ok(self)
     }
 
+    /// Creates a new named [`Func::new_async`]-style asynchronous host [`Func`] for this [`Linker`].
+    ///
+    /// For more information see [`Linker::func_wrap`] and [`Func::new_async`].
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name for this [`Linker`].
+    pub fn func_new_async(
+        &mut self,
+        module: &str,
+        name: &str,
+        ty: FuncType,
+        func: impl for<'a> Fn(
+                Caller<'a, T>,
+                &'a [Value],
+                &'a mut [Value],
+            ) -> Box<dyn Future<Output = Result<(), Trap>> + Send + 'a>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Result<&mut Self, LinkerError> {
+        let func = HostFuncTrampolineEntity::new_async(&self.engine, ty, func);
+        let key = self.import_key(module, name);
+        self.insert(key, Definition::HostFunc(func))?;
+        Ok(self)
+    }
+
+    /// Creates a new named [`Func::wrap_async`]-style asynchronous host [`Func`] for this [`Linker`].
+    ///
+    /// For more information see [`Linker::func_wrap`] and [`Func::wrap_async`].
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name for this [`Linker`].
+    pub fn func_wrap_async<Params, Results>(
+        &mut self,
+        module: &str,
+        name: &str,
+        func: impl for<'a> Fn(Caller<'a, T>, Params) -> Box<dyn Future<Output = Results> + Send + 'a>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Result<&mut Self, LinkerError>
+    where
+        Params: WasmTypeList,
+        Results: WasmRet + 'static,
+    {
+        let func = HostFuncTrampolineEntity::wrap_async(&self.engine, func);
+        let key = self.import_key(module, name);
+        self.insert(key, Definition::HostFunc(func))?;
+        Ok(self)
+    }
+
+    /// Defines all exports of the [`Instance`] under the `module_name` in this [`Linker`].
+    ///
+    /// This is useful to link multiple Wasm modules together where the exports
+    /// of one [`Instance`] satisfy the imports of another [`Module`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `context` are not the same.
+    ///
+    /// # Errors
+    ///
+    /// If any export of the [`Instance`] is already defined under the same name in this [`Linker`].
+    pub fn instance(
+        &mut self,
+        context: impl AsContext<UserState = T>,
+        module_name: &str,
+        instance: Instance,
+    ) -> Result<&mut Self, LinkerError> {
+        assert!(Engine::same(self.engine(), context.as_context().engine()));
+        let exports = instance
+            .exports(context.as_context())
+            .map(|export| (Box::<str>::from(export.name()), export.into_extern()))
+            .collect::<Vec<_>>();
+        for (name, item) in exports {
+            self.define(module_name, &name, item)?;
+        }
+        Ok(self)
+    }
+
+    /// Instantiates the [`Module`] and defines its exports under `module_name` in this [`Linker`].
+    ///
+    /// This follows the WASI conventions for commands and reactors:
+    ///
+    /// - If the [`Module`] is a command, i.e. it exports a `_start` function, then a new
+    ///   [`Linker`] defined host function is created for each of its exported functions.
+    ///   Each call to such a function instantiates the [`Module`] anew, calls its
+    ///   `_initialize` function if any and finally calls the exported function.
+    ///   Non-function exports of commands are not defined.
+    /// - Otherwise the [`Module`] is a reactor which is instantiated exactly once.
+    ///   After instantiation its `_initialize` function is called if any and then
+    ///   all of its exports are defined just like with [`Linker::instance`].
+    ///
+    /// # Note
+    ///
+    /// The imports of the [`Module`] are resolved using the definitions of this
+    /// [`Linker`] at the time of calling this method.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `context` are not the same.
+    ///
+    /// # Errors
+    ///
+    /// - If the [`Module`] is a reactor and its instantiation or initialization fails.
+    /// - If any export of the [`Module`] is already defined under the same name in this [`Linker`].
+    pub fn module(
+        &mut self,
+        mut context: impl AsContextMut<UserState = T>,
+        module_name: &str,
+        module: &Module,
+    ) -> Result<&mut Self, Error>
+    where
+        T: 'static,
+    {
+        assert!(Engine::same(self.engine(), context.as_context().engine()));
+        match module.get_export("_start") {
+            Some(ExternType::Func(_)) => self.command(module_name, module),
+            _ => self.reactor(context.as_context_mut(), module_name, module),
+        }
+    }
+
+    /// Defines the exported functions of the command [`Module`] under the `module_name`.
+    ///
+    /// For more information see [`Linker::module`].
+    fn command(&mut self, module_name: &str, module: &Module) -> Result<&mut Self, Error>
+    where
+        T: 'static,
+    {
+        for export in module.exports() {
+            let func_type = match export.ty() {
+                ExternType::Func(func_type) => func_type.clone(),
+                _ => continue,
+            };
+            let export_name = export.name();
+            if export_name == "_initialize" {
+                continue;
+            }
+            let linker = self.clone();
+            let module = module.clone();
+            let func_name: Box<str> = export_name.into();
+            self.func_new(
+                module_name,
+                export_name,
+                func_type,
+                move |mut caller: Caller<T>, params: &[Value], results: &mut [Value]| {
+                    let instance = linker
+                        .instantiate(&mut caller, &module)
+                        .and_then(|pre| pre.start(&mut caller))
+                        .map_err(into_trap)?;
+                    if let Some(initialize) = instance.get_func(&caller, "_initialize") {
+                        initialize
+                            .call(&mut caller, &[], &mut [])
+                            .map_err(into_trap)?;
+                    }
+                    instance
+                        .get_func(&caller, &func_name)
+                        .ok_or_else(|| {
+                            Trap::new(format!(
+                                "missing exported function `{func_name}` of command module"
+                            ))
+                        })?
+                        .call(&mut caller, params, results)
+                        .map_err(into_trap)
+                },
+            )?;
+        }
+        Ok(self)
+    }
+
+    /// Instantiates and initializes the reactor [`Module`] and defines its exports.
+    ///
+    /// For more information see [`Linker::module`].
+    fn reactor(
+        &mut self,
+        mut context: impl AsContextMut<UserState = T>,
+        module_name: &str,
+        module: &Module,
+    ) -> Result<&mut Self, Error> {
+        let instance = self
+            .instantiate(&mut context, module)?
+            .start(&mut context)?;
+        if let Some(initialize) = instance.get_func(&context, "_initialize") {
+            initialize
+                .typed::<(), ()>(&context)?
+                .call(&mut context, ())?;
+        }
+        self.instance(&context, module_name, instance)?;
+        Ok(self)
+    }
+
+    /// Aliases the definition named `name` in `module` under the new name `as_name` in `as_module`.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no definition named `name` in `module` in this [`Linker`].
+    /// - If there already is a definition named `as_name` in `as_module` in this [`Linker`].
+    pub fn alias(
+        &mut self,
+        module: &str,
+        name: &str,
+        as_module: &str,
+        as_name: &str,
+    ) -> Result<&mut Self, LinkerError> {
+        let definition = self.definition(module, name).cloned().ok_or_else(|| {
+            LinkerError::UnknownDefinition {
+                module: module.into(),
+                name: Some(name.into()),
+            }
+        })?;
+        let key = self.import_key(as_module, as_name);
+        self.insert(key, definition)?;
+        Ok(self)
+    }
+
+    /// Aliases all definitions of `module` under the new module name `as_module`.
+    ///
+    /// # Note
+    ///
+    /// Definitions that are added to `module` after this call are not aliased.
+    ///
+    /// # Errors
+    ///
+    /// - If there are no definitions in `module` in this [`Linker`].
+    /// - If any of the aliased definitions is already defined in `as_module` in this [`Linker`].
+    pub fn alias_module(
+        &mut self,
+        module: &str,
+        as_module: &str,
+    ) -> Result<&mut Self, LinkerError> {
+        let unknown_module = || LinkerError::UnknownDefinition {
+            module: module.into(),
+            name: None,
+        };
+        let module = self.strings.get(module).ok_or_else(unknown_module)?;
+        let as_module = self.strings.get_or_intern(as_module);
+        let aliases = self
+            .definitions
+            .iter()
+            .filter(|(key, _)| key.module == module)
+            .map(|(key, definition)| {
+                let key = ImportKey {
+                    module: as_module,
+                    name: key.name,
+                };
+                (key, definition.clone())
+            })
+            .collect::<Vec<_>>();
+        if aliases.is_empty() {
+            return Err(unknown_module());
+        }
+        for (key, definition) in aliases {
+            self.insert(key, definition)?;
+        }
+        Ok(self)
+    }
+
+    /// Defines all undefined function imports of the [`Module`] as trapping host functions.
+    ///
+    /// This allows to instantiate a [`Module`] with imports that are not supported
+    /// by the host. Executions only fail once they actually call any of those imports.
+    ///
+    /// # Note
+    ///
+    /// Only function imports are defined, other undefined imports still cause
+    /// an error upon instantiation.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `module` are not the same.
+    pub fn define_unknown_imports_as_traps(&mut self, module: &Module) -> &mut Self {
+        self.define_unknown_func_imports(module, |import_name, _func_type| {
+            let message = format!("unknown import: `{import_name}` has not been defined");
+            move |_caller: Caller<'_, T>, _params: &[Value], _results: &mut [Value]| {
+                Err(Trap::new(message.clone()))
+            }
+        })
+    }
+
+    /// Defines all undefined function imports of the [`Module`] as host functions returning defaults.
+    ///
+    /// The defined host functions do nothing and return the default value of each of
+    /// their result types, such as zero for numeric types and `null` for references.
+    ///
+    /// # Note
+    ///
+    /// Only function imports are defined, other undefined imports still cause
+    /// an error upon instantiation.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `module` are not the same.
+    pub fn define_unknown_imports_as_default_values(&mut self, module: &Module) -> &mut Self {
+        self.define_unknown_func_imports(module, |_import_name, func_type| {
+            let result_types: Box<[ValueType]> = func_type.results().into();
+            move |_caller: Caller<'_, T>, _params: &[Value], results: &mut [Value]| {
+                for (result, ty) in results.iter_mut().zip(result_types.iter()) {
+                    *result = Value::default(*ty);
+                }
+                Ok(())
+            }
+        })
+    }
+
+    /// Defines all undefined function imports of the [`Module`] using `make_func`.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `module` are not the same.
+    fn define_unknown_func_imports<F>(
+        &mut self,
+        module: &Module,
+        mut make_func: impl FnMut(&ImportName, &FuncType) -> F,
+    ) -> &mut Self
+    where
+        F: Fn(Caller<'_, T>, &[Value], &mut [Value]) -> Result<(), Trap> + Send + Sync + 'static,
+    {
+        assert!(Engine::same(self.engine(), module.engine()));
+        for import in module.imports() {
+            let func_type = match import.ty() {
+                ExternType::Func(func_type) => func_type,
+                _ => continue,
+            };
+            if self.definition(import.module(), import.name()).is_some() {
+                continue;
+            }
+            let func = make_func(import.import_name(), func_type);
+            self.func_new(import.module(), import.name(), func_type.clone(), func)
+                .expect("cannot have duplicate definitions for undefined imports");
+        }
+        self
+    }
+
     /// Returns the import key for the module name and item name.
     fn import_key(&mut self, module: &str, name: &str) -> ImportKey {
         ImportKey {
@@ -572,9 +1015,13 @@ impl<T> Linker<T> {
     ///
     /// # Errors
     ///
-    /// If there already is a definition for the import key for this [`Linker`].
+    /// If there already is a definition for the import key for this [`Linker`]
+    /// unless shadowing is allowed via [`Linker::allow_shadowing`].
     fn insert(&mut self, key: ImportKey, item: Definition<T>) -> Result<(), LinkerError> {
         match self.definitions.entry(key) {
+            Entry::Occupied(mut entry) if self.allow_shadowing => {
+                entry.insert(item);
+            }
             Entry::Occupied(_) => {
                 let (module_name, field_name) = self
                     .resolve_import_key(key)
@@ -626,6 +1073,13 @@ impl<T> Linker<T> {
             context.as_context().store.engine(),
             self.engine()
         ));
+        self.definition(module, name)
+    }
+
+    /// Looks up a [`Definition`] by name in this [`Linker`] without a store.
+    ///
+    /// Returns `None` if this name was not previously defined in this [`Linker`].
+    fn definition(&self, module: &str, name: &str) -> Option<&Definition<T>> {
         let key = ImportKey {
             module: self.strings.get(module)?,
             name: self.strings.get(name)?,
@@ -656,6 +1110,77 @@ impl<T> Linker<T> {
         module.instantiate(context, externals)
     }
 
+    /// Resolves and type checks the imports of the [`Module`] once for many instantiations.
+    ///
+    /// The returned [`LinkedModule`] can be cheaply cloned and instantiated into many
+    /// [`Store`]s without looking up any of the imports in this [`Linker`] again.
+    ///
+    /// # Note
+    ///
+    /// - Imports resolved to [`Linker`] defined host functions are type checked once
+    ///   and can be instantiated into any [`Store`] using the [`Engine`] of this [`Linker`].
+    /// - Imports resolved to [`Extern`] items are owned by the [`Store`] that defined them
+    ///   and thus are type checked upon every [`LinkedModule::instantiate`].
+    /// - Later definitions in this [`Linker`] do not affect the [`LinkedModule`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `module` are not the same.
+    ///
+    /// # Errors
+    ///
+    /// - If the linker does not define imports of the [`Module`].
+    /// - If any imported host function does not satisfy its type requirements.
+    ///
+    /// [`Store`]: crate::Store
+    pub fn instantiate_pre(&self, module: &Module) -> Result<LinkedModule<T>, LinkerError> {
+        assert!(Engine::same(self.engine(), module.engine()));
+        let definitions = module
+            .imports()
+            .map(|import| self.resolve_import(&import))
+            .collect::<Result<Arc<[Definition<T>]>, LinkerError>>()?;
+        Ok(LinkedModule {
+            module: Arc::new(module.clone()),
+            definitions,
+        })
+    }
+
+    /// Resolves a single [`Module`] import without a store.
+    ///
+    /// Only type checks the import if it resolves to a [`Linker`] defined host function.
+    ///
+    /// # Errors
+    ///
+    /// - If the imported item is not defined in this [`Linker`].
+    /// - If the imported host function does not satisfy its type requirements.
+    fn resolve_import(&self, import: &ImportType) -> Result<Definition<T>, LinkerError> {
+        let resolved = self
+            .definition(import.module(), import.name())
+            .ok_or_else(|| LinkerError::missing_definition(import))?;
+        if let Definition::HostFunc(host_func) = resolved {
+            let found_type = self
+                .engine
+                .resolve_func_type(host_func.ty_dedup(), FuncType::clone);
+            match import.ty() {
+                ExternType::Func(expected_type) if expected_type == &found_type => {}
+                ExternType::Func(expected_type) => {
+                    return Err(LinkerError::func_type_mismatch(
+                        import.import_name(),
+                        expected_type,
+                        &found_type,
+                    ))
+                }
+                _ => {
+                    return Err(LinkerError::invalid_type_definition(
+                        import,
+                        &ExternType::Func(found_type),
+                    ))
+                }
+            }
+        }
+        Ok(resolved.clone())
+    }
+
     /// Processes a single [`Module`] import.
     ///
     /// # Panics
@@ -667,17 +1192,30 @@ impl<T> Linker<T> {
     /// If the imported item does not satisfy constraints set by the [`Module`].
     fn process_import(
         &self,
-        mut context: impl AsContextMut<UserState = T>,
+        context: impl AsContextMut<UserState = T>,
         import: ImportType,
     ) -> Result<Extern, Error> {
         assert!(Engine::same(self.engine(), context.as_context().engine()));
-        let import_name = import.import_name();
         let module_name = import.module();
         let field_name = import.name();
         let resolved = self
             .get_definition(context.as_context(), module_name, field_name)
             .ok_or_else(|| LinkerError::missing_definition(&import))?;
-        let invalid_type = || LinkerError::invalid_type_definition(&import, &resolved.ty(&context));
+        Self::process_definition(context, &import, resolved)
+    }
+
+    /// Processes the `resolved` [`Definition`] of a single [`Module`] import.
+    ///
+    /// # Errors
+    ///
+    /// If the resolved item does not satisfy constraints set by the [`Module`].
+    fn process_definition(
+        mut context: impl AsContextMut<UserState = T>,
+        import: &ImportType,
+        resolved: &Definition<T>,
+    ) -> Result<Extern, Error> {
+        let import_name = import.import_name();
+        let invalid_type = || LinkerError::invalid_type_definition(import, &resolved.ty(&context));
         match import.ty() {
             ExternType::Func(expected_type) => {
                 let found_type = resolved
@@ -739,10 +1277,111 @@ impl<T> Linker<T> {
                 }
                 Ok(Extern::Global(global))
             }
+            ExternType::Tag(expected_type) => {
+                let tag = resolved
+                    .as_extern()
+                    .copied()
+                    .and_then(Extern::into_tag)
+                    .ok_or_else(invalid_type)?;
+                let found_type = tag.ty(context);
+                if &found_type != expected_type {
+                    return Err(LinkerError::tag_type_mismatch(
+                        import_name,
+                        expected_type,
+                        &found_type,
+                    )
+                    .into());
+                }
+                Ok(Extern::Tag(tag))
+            }
         }
     }
 }
 
+/// A [`Module`] with imports resolved by a [`Linker`] that can be instantiated many times.
+///
+/// Created by [`Linker::instantiate_pre`].
+pub struct LinkedModule<T> {
+    /// The [`Module`] to instantiate.
+    module: Arc<Module>,
+    /// The resolved definitions for all imports of the [`Module`] in order.
+    definitions: Arc<[Definition<T>]>,
+}
+
+impl<T> Clone for LinkedModule<T> {
+    fn clone(&self) -> Self {
+        Self {
+            module: self.module.clone(),
+            definitions: self.definitions.clone(),
+        }
+    }
+}
+
+impl<T> Debug for LinkedModule<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LinkedModule")
+            .field("module", &self.module)
+            .field(
+                "definitions",
+                &DebugResolvedDefinitions::new(self.module.engine(), &self.definitions),
+            )
+            .finish()
+    }
+}
+
+impl<T> LinkedModule<T> {
+    /// Returns the [`Module`] of the [`LinkedModule`].
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Instantiates the [`Module`] using the resolved imports of the [`LinkedModule`].
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Engine`] of the [`Module`] and `context` are not the same.
+    /// - If any import resolved to an [`Extern`] item that is not owned by `context`.
+    ///
+    /// # Errors
+    ///
+    /// - If any import resolved to an [`Extern`] item does not satisfy its type requirements.
+    /// - If the instantiation of the [`Module`] fails.
+    pub fn instantiate(
+        &self,
+        mut context: impl AsContextMut<UserState = T>,
+    ) -> Result<InstancePre, Error> {
+        assert!(Engine::same(
+            self.module.engine(),
+            context.as_context().engine()
+        ));
+        let externals = self
+            .module
+            .imports()
+            .zip(self.definitions.iter())
+            .map(|(import, definition)| match definition {
+                Definition::HostFunc(_) => {
+                    let func = definition
+                        .as_func(&mut context)
+                        .expect("host function definitions are always functions");
+                    Ok(Extern::Func(func))
+                }
+                Definition::Extern(_) => {
+                    Linker::process_definition(&mut context, &import, definition)
+                }
+            })
+            .collect::<Result<Vec<Extern>, Error>>()?;
+        self.module.instantiate(context, externals)
+    }
+}
+
+/// Converts the [`Error`] of a nested Wasm call into a [`Trap`] of a host function.
+fn into_trap(error: Error) -> Trap {
+    match error {
+        Error::Trap(trap) => trap,
+        error => Trap::new(error.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use wasmi_core::ValueType;
@@ -1,3 +1,4 @@
+use super::{LinearMemory, MemoryError};
 use alloc::{vec, vec::Vec};
 
 /// A `Vec`-based byte buffer implementation.
@@ -15,38 +16,39 @@ pub struct ByteBuffer {
 impl ByteBuffer {
     /// Creates a new byte buffer with the given initial length.
     ///
+    /// # Note
+    ///
+    /// The `maximum_len` is ignored since the byte buffer reallocates upon growth.
+    ///
     /// # Errors
     ///
-    /// - If the initial length is 0.
-    /// - If the initial length exceeds the maximum supported limit.
-    pub fn new(initial_len: usize) -> Self {
-        Self {
+    /// This never fails and only returns a `Result` to mirror the OS virtual memory
+    /// based byte buffer implementation.
+    pub fn new(initial_len: usize, _maximum_len: Option<usize>) -> Result<Self, MemoryError> {
+        Ok(Self {
             bytes: vec![0x00_u8; initial_len],
-        }
+        })
     }
+}
 
-    /// Grows the byte buffer to the given `new_size`.
-    ///
-    /// # Panics
-    ///
-    /// If the current size of the [`ByteBuffer`] is larger than `new_size`.
-    pub fn grow(&mut self, new_size: usize) {
-        assert!(new_size >= self.len());
-        self.bytes.resize(new_size, 0x00_u8);
+impl LinearMemory for ByteBuffer {
+    fn data(&self) -> &[u8] {
+        &self.bytes[..]
     }
 
-    /// Returns the length of the byte buffer in bytes.
-    pub fn len(&self) -> usize {
-        self.bytes.len()
+    fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes[..]
     }
 
-    /// Returns a shared slice to the bytes underlying to the byte buffer.
-    pub fn data(&self) -> &[u8] {
-        &self.bytes[..]
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        assert!(new_len >= self.bytes.len());
+        self.bytes.resize(new_len, 0x00_u8);
+        Ok(())
     }
 
-    /// Returns an exclusive slice to the bytes underlying to the byte buffer.
-    pub fn data_mut(&mut self) -> &mut [u8] {
-        &mut self.bytes[..]
+    fn reset(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        self.bytes.clear();
+        self.bytes.resize(new_len, 0x00_u8);
+        Ok(())
     }
 }
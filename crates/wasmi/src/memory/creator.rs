@@ -0,0 +1,107 @@
+use super::{LinearBuffer, MemoryError, MemoryType};
+use alloc::boxed::Box;
+use core::fmt;
+
+/// The bytes of a non-shared linear memory.
+///
+/// # Note
+///
+/// Custom [`LinearMemory`] implementations allow hosts to control where the bytes
+/// of linear memories live, e.g. in a pool, a pre-reserved region or a host shared buffer.
+/// They are created by the [`MemoryCreator`] installed into a [`Store`].
+///
+/// [`Store`]: crate::Store
+pub trait LinearMemory: Send + Sync {
+    /// Returns a shared slice to the bytes of the [`LinearMemory`].
+    fn data(&self) -> &[u8];
+
+    /// Returns an exclusive slice to the bytes of the [`LinearMemory`].
+    fn data_mut(&mut self) -> &mut [u8];
+
+    /// Grows the [`LinearMemory`] to `new_len` bytes.
+    ///
+    /// # Note
+    ///
+    /// - All bytes added by the operation must be zeroed.
+    /// - `wasmi` guarantees that `new_len` is not smaller than the current length
+    ///   and does not exceed the maximum length of the [`LinearMemory`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`LinearMemory`] cannot grow to `new_len` bytes.
+    ///
+    /// Upon [`MemoryError::OutOfBoundsAllocation`] or [`MemoryError::OutOfBoundsGrowth`]
+    /// the Wasm `memory.grow` instruction returns `-1`, upon any other error it traps.
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError>;
+
+    /// Resets the [`LinearMemory`] to `new_len` zeroed bytes.
+    ///
+    /// # Note
+    ///
+    /// `wasmi` guarantees that `new_len` does not exceed the current length.
+    ///
+    /// # Errors
+    ///
+    /// If the [`LinearMemory`] cannot be reset.
+    fn reset(&mut self, new_len: usize) -> Result<(), MemoryError>;
+}
+
+impl fmt::Debug for dyn LinearMemory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LinearMemory")
+            .field("len", &self.data().len())
+            .finish()
+    }
+}
+
+/// Creates the [`LinearMemory`] of new non-shared linear memories of a [`Store`].
+///
+/// A [`MemoryCreator`] can be installed into a [`Store`] via [`Store::memory_creator`].
+///
+/// [`Store`]: crate::Store
+/// [`Store::memory_creator`]: crate::Store::memory_creator
+pub trait MemoryCreator: Send + Sync {
+    /// Creates a new [`LinearMemory`] for a linear memory of type `ty`.
+    ///
+    /// - `initial_len` is the length in bytes of the returned [`LinearMemory`].
+    /// - `maximum_len` is the maximum length in bytes the [`LinearMemory`] may grow to.
+    ///   A value of `None` indicates that the linear memory is unbounded.
+    ///
+    /// # Note
+    ///
+    /// All bytes of the returned [`LinearMemory`] must be zeroed.
+    ///
+    /// # Errors
+    ///
+    /// If the [`LinearMemory`] cannot be created.
+    fn new_memory(
+        &self,
+        ty: MemoryType,
+        initial_len: usize,
+        maximum_len: Option<usize>,
+    ) -> Result<Box<dyn LinearMemory>, MemoryError>;
+}
+
+impl fmt::Debug for dyn MemoryCreator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryCreator").finish()
+    }
+}
+
+/// The [`MemoryCreator`] used by a [`Store`] without a custom [`MemoryCreator`].
+///
+/// [`Store`]: crate::Store
+#[derive(Debug, Default, Copy, Clone)]
+pub struct DefaultMemoryCreator;
+
+impl MemoryCreator for DefaultMemoryCreator {
+    fn new_memory(
+        &self,
+        _ty: MemoryType,
+        initial_len: usize,
+        maximum_len: Option<usize>,
+    ) -> Result<Box<dyn LinearMemory>, MemoryError> {
+        let bytes = LinearBuffer::new(initial_len, maximum_len)?;
+        Ok(Box::new(bytes))
+    }
+}
@@ -13,6 +13,16 @@ pub enum MemoryError {
     OutOfBoundsAccess,
     /// Tried to create an invalid linear memory type.
     InvalidMemoryType,
+    /// Tried to create more linear memories than permitted by the [`ResourceLimiter`].
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    TooManyMemories,
+    /// The [`ResourceLimiter`] denied the allocation of linear memory.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    ResourceLimiterDeniedAllocation,
+    /// Tried to reset a shared linear memory.
+    SharedMemoryReset,
     /// Occurs when `ty` is not a subtype of `other`.
     InvalidSubtype {
         /// The [`MemoryType`] which is not a subtype of `other`.
@@ -37,6 +47,18 @@ impl Display for MemoryError {
             Self::InvalidMemoryType => {
                 write!(f, "tried to create an invalid virtual memory type")
             }
+            Self::TooManyMemories => {
+                write!(f, "too many memories")
+            }
+            Self::ResourceLimiterDeniedAllocation => {
+                write!(
+                    f,
+                    "a resource limiter denied to allocate or grow the linear memory"
+                )
+            }
+            Self::SharedMemoryReset => {
+                write!(f, "cannot reset a shared linear memory")
+            }
             Self::InvalidSubtype { ty, other } => {
                 write!(f, "memory type {ty:?} is not a subtype of {other:?}",)
             }
@@ -0,0 +1,300 @@
+use super::{LinearMemory, MemoryError};
+use core::{ptr, ptr::NonNull, slice};
+
+/// The length of the inaccessible guard region following the reserved bytes.
+///
+/// # Note
+///
+/// This equals the size of a single Wasm page.
+const GUARD_LEN: usize = 0x1_0000;
+
+/// The maximum number of bytes reserved upfront by a single [`MmapBuffer`].
+///
+/// # Note
+///
+/// On 64-bit platforms this covers the whole address space of 32-bit linear memories.
+/// Linear memories that grow beyond their reservation are moved to a larger reservation.
+#[cfg(target_pointer_width = "64")]
+const MAX_RESERVED_LEN: usize = 1 << 32;
+
+/// The maximum number of bytes reserved upfront by a single [`MmapBuffer`].
+///
+/// # Note
+///
+/// Linear memories that grow beyond their reservation are moved to a larger reservation.
+#[cfg(not(target_pointer_width = "64"))]
+const MAX_RESERVED_LEN: usize = 1 << 26;
+
+/// A byte buffer implementation based on OS provided virtual memory.
+///
+/// # Note
+///
+/// - The address space for the maximum length of the byte buffer is reserved upfront
+///   so that growing the byte buffer only commits additional pages without moving bytes.
+/// - The reserved address space is followed by an inaccessible guard region.
+/// - Resetting the byte buffer replaces all of its pages with fresh zeroed pages
+///   instead of writing zeros to each of its bytes.
+#[derive(Debug)]
+pub struct MmapBuffer {
+    /// The start of the virtual memory mapping.
+    ptr: NonNull<u8>,
+    /// The length of the byte buffer in bytes.
+    len: usize,
+    /// The number of reserved bytes excluding the guard region.
+    reserved_len: usize,
+    /// The maximum length of the byte buffer in bytes.
+    maximum_len: usize,
+}
+
+// Safety: `MmapBuffer` exclusively owns its virtual memory mapping.
+unsafe impl Send for MmapBuffer {}
+// Safety: `MmapBuffer` only hands out shared references to its bytes via `&self`.
+unsafe impl Sync for MmapBuffer {}
+
+impl MmapBuffer {
+    /// Creates a new byte buffer with the given initial length.
+    ///
+    /// Reserves the address space for up to `maximum_len` bytes if possible.
+    ///
+    /// # Errors
+    ///
+    /// If the initial length cannot be allocated.
+    pub fn new(initial_len: usize, maximum_len: Option<usize>) -> Result<Self, MemoryError> {
+        let maximum_len = maximum_len.unwrap_or(usize::MAX).max(initial_len);
+        let reserved_len = maximum_len.min(MAX_RESERVED_LEN).max(initial_len);
+        Self::with_reservation(initial_len, reserved_len, maximum_len)
+    }
+
+    /// Creates a new byte buffer with `len` accessible bytes out of `reserved_len` bytes.
+    ///
+    /// Falls back to reserving only `len` bytes if `reserved_len` bytes cannot be reserved.
+    ///
+    /// # Errors
+    ///
+    /// If `len` bytes cannot be allocated.
+    fn with_reservation(
+        len: usize,
+        reserved_len: usize,
+        maximum_len: usize,
+    ) -> Result<Self, MemoryError> {
+        let mut buffer = match Self::reserve(reserved_len, maximum_len) {
+            Ok(buffer) => buffer,
+            Err(_) if reserved_len > len => Self::reserve(len, maximum_len)?,
+            Err(error) => return Err(error),
+        };
+        buffer.commit(len)?;
+        buffer.len = len;
+        Ok(buffer)
+    }
+
+    /// Reserves inaccessible address space for `reserved_len` bytes and the guard region.
+    ///
+    /// # Errors
+    ///
+    /// If the address space cannot be reserved.
+    fn reserve(reserved_len: usize, maximum_len: usize) -> Result<Self, MemoryError> {
+        let reserved_len = round_to_page_size(reserved_len)?;
+        let ptr = reserve(reserved_len)?;
+        Ok(Self {
+            ptr,
+            len: 0,
+            reserved_len,
+            maximum_len,
+        })
+    }
+
+    /// Makes the first `new_len` bytes of the byte buffer accessible.
+    ///
+    /// # Errors
+    ///
+    /// If the pages for `new_len` bytes cannot be committed.
+    fn commit(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        debug_assert!(new_len <= self.reserved_len);
+        // Safety: the committed range lies within the reserved address space.
+        unsafe { commit(self.ptr, self.len, new_len) }
+    }
+}
+
+impl LinearMemory for MmapBuffer {
+    fn data(&self) -> &[u8] {
+        // Safety: the first `len` bytes are committed and owned by the byte buffer.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    fn data_mut(&mut self) -> &mut [u8] {
+        // Safety: the first `len` bytes are committed and exclusively owned by the byte buffer.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Grows the byte buffer to the given `new_len`.
+    ///
+    /// # Note
+    ///
+    /// The bytes only move if `new_len` exceeds the reserved address space.
+    ///
+    /// # Errors
+    ///
+    /// If the additional bytes cannot be allocated.
+    ///
+    /// # Panics
+    ///
+    /// If the current length of the [`MmapBuffer`] is larger than `new_len`.
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        assert!(new_len >= self.len);
+        if new_len > self.maximum_len {
+            return Err(MemoryError::OutOfBoundsAllocation);
+        }
+        if new_len <= self.reserved_len {
+            self.commit(new_len)?;
+            self.len = new_len;
+            return Ok(());
+        }
+        // The new length exceeds the reserved address space so we have to move the bytes.
+        let reserved_len = self
+            .reserved_len
+            .saturating_mul(2)
+            .clamp(new_len, self.maximum_len);
+        let mut buffer = Self::with_reservation(new_len, reserved_len, self.maximum_len)?;
+        buffer.data_mut()[..self.len].copy_from_slice(self.data());
+        *self = buffer;
+        Ok(())
+    }
+
+    /// Resets the byte buffer to `new_len` zeroed bytes.
+    ///
+    /// # Note
+    ///
+    /// The bytes are zeroed by replacing the committed pages with fresh pages.
+    ///
+    /// # Errors
+    ///
+    /// If the pages of the byte buffer cannot be replaced.
+    ///
+    /// # Panics
+    ///
+    /// If `new_len` exceeds the reserved address space of the [`MmapBuffer`].
+    fn reset(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        assert!(new_len <= self.reserved_len);
+        let committed_len = round_to_page_size(self.len)?;
+        if committed_len != 0 {
+            // Safety: the replaced range lies within the reserved address space.
+            let ptr = unsafe {
+                libc::mmap(
+                    self.ptr.as_ptr().cast(),
+                    committed_len,
+                    libc::PROT_NONE,
+                    libc::MAP_PRIVATE | libc::MAP_ANON | libc::MAP_FIXED,
+                    -1,
+                    0,
+                )
+            };
+            if ptr == libc::MAP_FAILED {
+                return Err(MemoryError::OutOfBoundsAllocation);
+            }
+        }
+        self.len = 0;
+        self.commit(new_len)?;
+        self.len = new_len;
+        Ok(())
+    }
+}
+
+impl Drop for MmapBuffer {
+    fn drop(&mut self) {
+        // Safety: the mapping was created with this length in `MmapBuffer::reserve`.
+        unsafe { release(self.ptr, self.reserved_len) }
+    }
+}
+
+/// Reserves inaccessible address space for `reserved_len` bytes followed by the guard region.
+///
+/// # Note
+///
+/// `reserved_len` must be a multiple of the OS page size.
+///
+/// # Errors
+///
+/// If the address space cannot be reserved.
+pub(super) fn reserve(reserved_len: usize) -> Result<NonNull<u8>, MemoryError> {
+    let mapping_len = reserved_len
+        .checked_add(GUARD_LEN)
+        .ok_or(MemoryError::OutOfBoundsAllocation)?;
+    // Safety: we create a new anonymous mapping that does not alias any other memory.
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            mapping_len,
+            libc::PROT_NONE,
+            libc::MAP_PRIVATE | libc::MAP_ANON,
+            -1,
+            0,
+        )
+    };
+    if ptr == libc::MAP_FAILED {
+        return Err(MemoryError::OutOfBoundsAllocation);
+    }
+    NonNull::new(ptr.cast::<u8>()).ok_or(MemoryError::OutOfBoundsAllocation)
+}
+
+/// Makes the bytes of the reservation at `ptr` accessible up to `new_len`
+/// given that they are already accessible up to `committed_len`.
+///
+/// # Note
+///
+/// Committing already accessible bytes is a no-op. Therefore this is
+/// safe to call concurrently for overlapping ranges.
+///
+/// # Errors
+///
+/// If the pages for `new_len` bytes cannot be committed.
+///
+/// # Safety
+///
+/// The first `new_len` bytes must lie within a reservation made by [`reserve`].
+pub(super) unsafe fn commit(
+    ptr: NonNull<u8>,
+    committed_len: usize,
+    new_len: usize,
+) -> Result<(), MemoryError> {
+    let committed_len = round_to_page_size(committed_len)?;
+    let new_committed_len = round_to_page_size(new_len)?;
+    if new_committed_len <= committed_len {
+        return Ok(());
+    }
+    let result = libc::mprotect(
+        ptr.as_ptr().add(committed_len).cast(),
+        new_committed_len - committed_len,
+        libc::PROT_READ | libc::PROT_WRITE,
+    );
+    if result != 0 {
+        return Err(MemoryError::OutOfBoundsAllocation);
+    }
+    Ok(())
+}
+
+/// Releases the reservation of `reserved_len` bytes at `ptr` including its guard region.
+///
+/// # Safety
+///
+/// The reservation must have been made by [`reserve`] with the same `reserved_len`
+/// and its bytes must no longer be accessed.
+pub(super) unsafe fn release(ptr: NonNull<u8>, reserved_len: usize) {
+    libc::munmap(ptr.as_ptr().cast(), reserved_len + GUARD_LEN);
+}
+
+/// Rounds `len` up to the next multiple of the OS page size.
+///
+/// # Errors
+///
+/// If the rounded length overflows.
+pub(super) fn round_to_page_size(len: usize) -> Result<usize, MemoryError> {
+    // Safety: querying the page size has no preconditions.
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    let page_size = usize::try_from(page_size)
+        .ok()
+        .filter(|page_size| page_size.is_power_of_two())
+        .unwrap_or(GUARD_LEN);
+    len.checked_add(page_size - 1)
+        .map(|len| len & !(page_size - 1))
+        .ok_or(MemoryError::OutOfBoundsAllocation)
+}
@@ -1,16 +1,29 @@
+#[cfg(not(all(feature = "std", unix)))]
 mod buffer;
+mod creator;
 mod data;
 mod error;
+#[cfg(all(feature = "std", unix))]
+mod mmap;
+mod shared;
 
 #[cfg(test)]
 mod tests;
 
-use self::buffer::ByteBuffer;
+#[cfg(not(all(feature = "std", unix)))]
+pub(crate) use self::buffer::ByteBuffer as LinearBuffer;
+pub(crate) use self::creator::DefaultMemoryCreator;
+#[cfg(all(feature = "std", unix))]
+pub(crate) use self::mmap::MmapBuffer as LinearBuffer;
 pub use self::{
+    creator::{LinearMemory, MemoryCreator},
     data::{DataSegment, DataSegmentEntity, DataSegmentIdx},
     error::MemoryError,
+    shared::SharedMemory,
 };
 use super::{AsContext, AsContextMut, StoreContext, StoreContextMut, Stored};
+use crate::ResourceLimiterRef;
+use alloc::boxed::Box;
 use wasmi_arena::ArenaIndex;
 use wasmi_core::Pages;
 
@@ -36,6 +49,8 @@ impl ArenaIndex for MemoryIdx {
 pub struct MemoryType {
     initial_pages: Pages,
     maximum_pages: Option<Pages>,
+    memory64: bool,
+    shared: bool,
 }
 
 impl MemoryType {
@@ -56,9 +71,86 @@ impl MemoryType {
         Ok(Self {
             initial_pages,
             maximum_pages,
+            memory64: false,
+            shared: false,
         })
     }
 
+    /// Creates a new 64-bit memory type with initial and optional maximum pages.
+    ///
+    /// # Note
+    ///
+    /// Linear memories of this type are indexed using `i64` addresses
+    /// as defined by the [`memory64`] Wasm proposal.
+    ///
+    /// # Errors
+    ///
+    /// If the linear memory type initial or maximum size exceeds the
+    /// maximum limits of 2^48 pages.
+    ///
+    /// [`memory64`]: https://github.com/WebAssembly/memory64
+    pub fn new64(initial: u64, maximum: Option<u64>) -> Result<Self, MemoryError> {
+        let initial_pages = Pages::new64(initial).ok_or(MemoryError::InvalidMemoryType)?;
+        let maximum_pages = match maximum {
+            Some(maximum) => Pages::new64(maximum)
+                .ok_or(MemoryError::InvalidMemoryType)?
+                .into(),
+            None => None,
+        };
+        Ok(Self {
+            initial_pages,
+            maximum_pages,
+            memory64: true,
+            shared: false,
+        })
+    }
+
+    /// Creates a new shared memory type with initial and maximum pages.
+    ///
+    /// # Note
+    ///
+    /// Linear memories of this type can be shared between multiple threads
+    /// as defined by the [`threads`] Wasm proposal. See [`SharedMemory`].
+    ///
+    /// # Errors
+    ///
+    /// If the linear memory type initial or maximum size exceeds the
+    /// maximum limits of 2^16 pages.
+    ///
+    /// [`threads`]: https://github.com/WebAssembly/threads
+    pub fn new_shared(initial: u32, maximum: u32) -> Result<Self, MemoryError> {
+        Self::new(initial, Some(maximum))?.into_shared()
+    }
+
+    /// Turns `self` into a shared memory type.
+    ///
+    /// # Errors
+    ///
+    /// If `self` has no maximum size since shared linear memories must have one.
+    pub(crate) fn into_shared(self) -> Result<Self, MemoryError> {
+        if self.maximum_pages().is_none() {
+            return Err(MemoryError::InvalidMemoryType);
+        }
+        Ok(Self {
+            shared: true,
+            ..self
+        })
+    }
+
+    /// Returns `true` if this is a shared memory type.
+    pub fn is_shared(self) -> bool {
+        self.shared
+    }
+
+    /// Returns `true` if this is a 64-bit memory type.
+    ///
+    /// # Note
+    ///
+    /// 64-bit linear memories are indexed using `i64` instead of `i32` addresses.
+    pub fn is_64(self) -> bool {
+        self.memory64
+    }
+
     /// Returns the initial pages of the memory type.
     pub fn initial_pages(self) -> Pages {
         self.initial_pages
@@ -69,11 +161,25 @@ impl MemoryType {
     /// # Note
     ///
     /// - Returns `None` if there is no limit set.
-    /// - Maximum memory size cannot exceed `65536` pages or 4GiB.
+    /// - Maximum memory size cannot exceed `65536` pages or 4GiB
+    ///   unless this is a 64-bit memory type.
     pub fn maximum_pages(self) -> Option<Pages> {
         self.maximum_pages
     }
 
+    /// Returns the absolute maximum pages of the memory type.
+    ///
+    /// # Note
+    ///
+    /// This is the maximum pages if set or otherwise the maximum
+    /// amount of pages for the respective index type.
+    pub(crate) fn absolute_maximum_pages(self) -> Pages {
+        self.maximum_pages().unwrap_or_else(|| match self.is_64() {
+            true => Pages::max64(),
+            false => Pages::max(),
+        })
+    }
+
     /// Checks if `self` is a subtype of `other`.
     ///
     /// # Note
@@ -106,6 +212,9 @@ impl MemoryType {
     /// [import subtyping]:
     /// https://webassembly.github.io/spec/core/valid/types.html#import-subtyping
     pub(crate) fn is_subtype_of(&self, other: &MemoryType) -> bool {
+        if self.is_64() != other.is_64() || self.is_shared() != other.is_shared() {
+            return false;
+        }
         if self.initial_pages() < other.initial_pages() {
             return false;
         }
@@ -120,26 +229,91 @@ impl MemoryType {
 /// A linear memory entity.
 #[derive(Debug)]
 pub struct MemoryEntity {
-    bytes: ByteBuffer,
+    storage: MemoryStorage,
     memory_type: MemoryType,
-    current_pages: Pages,
+}
+
+/// The underlying bytes of a [`MemoryEntity`].
+#[derive(Debug)]
+enum MemoryStorage {
+    /// The bytes are exclusively owned by the [`MemoryEntity`].
+    Owned {
+        bytes: Box<dyn LinearMemory>,
+        current_pages: Pages,
+    },
+    /// The bytes are shared with other [`MemoryEntity`] of potentially other [`Store`]s.
+    ///
+    /// [`Store`]: crate::Store
+    Shared(SharedMemory),
 }
 
 impl MemoryEntity {
     /// Creates a new memory entity with the given memory type.
-    pub fn new(memory_type: MemoryType) -> Result<Self, MemoryError> {
+    ///
+    /// # Note
+    ///
+    /// Creates a new [`SharedMemory`] if the memory type is shared.
+    ///
+    /// # Errors
+    ///
+    /// - If the initial size of the memory type cannot be allocated.
+    /// - If the [`ResourceLimiter`] denies the allocation of the linear memory.
+    /// - If the `creator` fails to create the [`LinearMemory`] of the linear memory.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    pub fn new(
+        memory_type: MemoryType,
+        limiter: &mut ResourceLimiterRef<'_>,
+        creator: &dyn MemoryCreator,
+    ) -> Result<Self, MemoryError> {
         let initial_pages = memory_type.initial_pages();
         let initial_len = initial_pages
             .to_bytes()
             .ok_or(MemoryError::OutOfBoundsAllocation)?;
+        let maximum_len = memory_type.maximum_pages().and_then(Pages::to_bytes);
+        if let Some(limiter) = limiter.as_resource_limiter() {
+            if !limiter.memory_growing(0, initial_len, maximum_len)? {
+                // There is no meaningful way to return `-1` upon creation
+                // so we have to return an appropriate error instead.
+                return Err(MemoryError::ResourceLimiterDeniedAllocation);
+            }
+        }
+        let storage = match memory_type.is_shared() {
+            true => MemoryStorage::Shared(SharedMemory::new(memory_type)?),
+            false => {
+                let bytes = creator.new_memory(memory_type, initial_len, maximum_len)?;
+                if bytes.data().len() != initial_len {
+                    return Err(MemoryError::OutOfBoundsAllocation);
+                }
+                MemoryStorage::Owned {
+                    bytes,
+                    current_pages: initial_pages,
+                }
+            }
+        };
         let memory = Self {
-            bytes: ByteBuffer::new(initial_len),
+            storage,
             memory_type,
-            current_pages: initial_pages,
         };
         Ok(memory)
     }
 
+    /// Creates a new memory entity referencing the given [`SharedMemory`].
+    pub fn from_shared(memory: SharedMemory) -> Self {
+        Self {
+            memory_type: memory.ty(),
+            storage: MemoryStorage::Shared(memory),
+        }
+    }
+
+    /// Returns the [`SharedMemory`] if the linear memory is shared.
+    pub fn shared(&self) -> Option<&SharedMemory> {
+        match &self.storage {
+            MemoryStorage::Owned { .. } => None,
+            MemoryStorage::Shared(memory) => Some(memory),
+        }
+    }
+
     /// Returns the memory type of the linear memory.
     pub fn ty(&self) -> MemoryType {
         self.memory_type
@@ -152,15 +326,18 @@ impl MemoryEntity {
     /// This respects the current size of the [`MemoryEntity`] as
     /// its minimum size and is useful for import subtyping checks.
     pub fn dynamic_ty(&self) -> MemoryType {
-        let current_pages = self.current_pages().into();
-        let maximum_pages = self.ty().maximum_pages().map(Into::into);
-        MemoryType::new(current_pages, maximum_pages)
-            .unwrap_or_else(|_| panic!("must result in valid memory type due to invariants"))
+        MemoryType {
+            initial_pages: self.current_pages(),
+            ..self.ty()
+        }
     }
 
     /// Returns the amount of pages in use by the linear memory.
     pub fn current_pages(&self) -> Pages {
-        self.current_pages
+        match &self.storage {
+            MemoryStorage::Owned { current_pages, .. } => *current_pages,
+            MemoryStorage::Shared(memory) => memory.current_pages(),
+        }
     }
 
     /// Grows the linear memory by the given amount of new pages.
@@ -169,37 +346,134 @@ impl MemoryEntity {
     ///
     /// # Errors
     ///
-    /// If the linear memory would grow beyond its maximum limit after
-    /// the grow operation.
-    pub fn grow(&mut self, additional: Pages) -> Result<Pages, MemoryError> {
+    /// - If the linear memory would grow beyond its maximum limit after
+    ///   the grow operation.
+    /// - If the [`ResourceLimiter`] denies the growth of the linear memory.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    pub fn grow(
+        &mut self,
+        additional: Pages,
+        limiter: &mut ResourceLimiterRef<'_>,
+    ) -> Result<Pages, MemoryError> {
         let current_pages = self.current_pages();
         if additional == Pages::from(0) {
             // Nothing to do in this case. Bail out early.
             return Ok(current_pages);
         }
-        let maximum_pages = self.ty().maximum_pages().unwrap_or_else(Pages::max);
-        let new_pages = current_pages
-            .checked_add(additional)
-            .filter(|&new_pages| new_pages <= maximum_pages)
-            .ok_or(MemoryError::OutOfBoundsGrowth)?;
-        let new_size = new_pages
-            .to_bytes()
-            .ok_or(MemoryError::OutOfBoundsAllocation)?;
-        // At this point it is okay to grow the underlying virtual memory
-        // by the given amount of additional pages.
-        self.bytes.grow(new_size);
-        self.current_pages = new_pages;
-        Ok(current_pages)
+        let maximum_pages = self.ty().absolute_maximum_pages();
+        let desired_pages = current_pages.checked_add(additional);
+        if let Some(limiter) = limiter.as_resource_limiter() {
+            // The resource limiter gets the first look at the growth request.
+            let current_size = current_pages.to_bytes().unwrap_or(usize::MAX);
+            let desired_size = desired_pages
+                .and_then(Pages::to_bytes)
+                .unwrap_or(usize::MAX);
+            let maximum_size = self.ty().maximum_pages().and_then(Pages::to_bytes);
+            if !limiter.memory_growing(current_size, desired_size, maximum_size)? {
+                return Err(MemoryError::OutOfBoundsGrowth);
+            }
+        }
+        let result = match &mut self.storage {
+            MemoryStorage::Owned {
+                bytes,
+                current_pages,
+            } => desired_pages
+                .filter(|&new_pages| new_pages <= maximum_pages)
+                .ok_or(MemoryError::OutOfBoundsGrowth)
+                .and_then(|new_pages| {
+                    let new_size = new_pages
+                        .to_bytes()
+                        .ok_or(MemoryError::OutOfBoundsAllocation)?;
+                    // At this point it is okay to grow the underlying virtual memory
+                    // by the given amount of additional pages.
+                    bytes.grow(new_size)?;
+                    if bytes.data().len() != new_size {
+                        return Err(MemoryError::OutOfBoundsAllocation);
+                    }
+                    Ok(core::mem::replace(current_pages, new_pages))
+                }),
+            // Shared linear memories might concurrently grow on other threads.
+            MemoryStorage::Shared(memory) => memory.grow(additional),
+        };
+        if let Err(error) = &result {
+            // The resource limiter is notified about failed growth operations.
+            if let Some(limiter) = limiter.as_resource_limiter() {
+                limiter.memory_grow_failed(error)
+            }
+        }
+        result
+    }
+
+    /// Resets the linear memory to its initial size with all bytes zeroed.
+    ///
+    /// # Errors
+    ///
+    /// - If the linear memory is shared.
+    /// - If the underlying virtual memory cannot be reset.
+    pub fn reset(&mut self) -> Result<(), MemoryError> {
+        let initial_pages = self.ty().initial_pages();
+        match &mut self.storage {
+            MemoryStorage::Owned {
+                bytes,
+                current_pages,
+            } => {
+                let initial_len = initial_pages
+                    .to_bytes()
+                    .ok_or(MemoryError::OutOfBoundsAllocation)?;
+                bytes.reset(initial_len)?;
+                *current_pages = initial_pages;
+                Ok(())
+            }
+            MemoryStorage::Shared(_) => Err(MemoryError::SharedMemoryReset),
+        }
     }
 
     /// Returns a shared slice to the bytes underlying to the byte buffer.
+    ///
+    /// # Panics
+    ///
+    /// If the linear memory is shared.
     pub fn data(&self) -> &[u8] {
-        self.bytes.data()
+        match &self.storage {
+            MemoryStorage::Owned { bytes, .. } => bytes.data(),
+            MemoryStorage::Shared(_) => shared_memory_slice(),
+        }
     }
 
     /// Returns an exclusive slice to the bytes underlying to the byte buffer.
+    ///
+    /// # Panics
+    ///
+    /// If the linear memory is shared.
     pub fn data_mut(&mut self) -> &mut [u8] {
-        self.bytes.data_mut()
+        match &mut self.storage {
+            MemoryStorage::Owned { bytes, .. } => bytes.data_mut(),
+            MemoryStorage::Shared(_) => shared_memory_slice(),
+        }
+    }
+
+    /// Returns a raw pointer to the bytes underlying to the byte buffer.
+    ///
+    /// # Note
+    ///
+    /// - The bytes of shared linear memories are never accessed through
+    ///   references since they might be concurrently accessed by other threads.
+    /// - The returned bytes are valid for reads and writes until the
+    ///   linear memory is accessed or grows again.
+    pub fn raw_data(&mut self) -> *mut [u8] {
+        match &mut self.storage {
+            MemoryStorage::Owned { bytes, .. } => bytes.data_mut(),
+            MemoryStorage::Shared(memory) => memory.raw_data(),
+        }
+    }
+
+    /// Returns the number of bytes underlying to the byte buffer.
+    pub fn data_size(&self) -> usize {
+        match &self.storage {
+            MemoryStorage::Owned { bytes, .. } => bytes.data().len(),
+            MemoryStorage::Shared(memory) => memory.data_size(),
+        }
     }
 
     /// Reads `n` bytes from `memory[offset..offset+n]` into `buffer`
@@ -209,6 +483,9 @@ impl MemoryEntity {
     ///
     /// If this operation accesses out of bounds linear memory.
     pub fn read(&self, offset: usize, buffer: &mut [u8]) -> Result<(), MemoryError> {
+        if let MemoryStorage::Shared(memory) = &self.storage {
+            return memory.read(offset, buffer);
+        }
         let len_buffer = buffer.len();
         let slice = self
             .data()
@@ -225,6 +502,9 @@ impl MemoryEntity {
     ///
     /// If this operation accesses out of bounds linear memory.
     pub fn write(&mut self, offset: usize, buffer: &[u8]) -> Result<(), MemoryError> {
+        if let MemoryStorage::Shared(memory) = &self.storage {
+            return memory.write(offset, buffer);
+        }
         let len_buffer = buffer.len();
         let slice = self
             .data_mut()
@@ -235,6 +515,18 @@ impl MemoryEntity {
     }
 }
 
+/// Panics when trying to create a slice to the bytes of a shared linear memory.
+///
+/// # Note
+///
+/// Rust references to the bytes of shared linear memories are unsound since
+/// the bytes might be concurrently modified by other threads.
+#[cold]
+#[inline(never)]
+fn shared_memory_slice() -> ! {
+    panic!("cannot create slices to the bytes of a shared linear memory")
+}
+
 /// A Wasm linear memory reference.
 #[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
@@ -251,17 +543,86 @@ impl Memory {
         &self.0
     }
 
+    /// Returns `true` if `lhs` and `rhs` [`Memory`] refer to the same entity.
+    ///
+    /// # Note
+    ///
+    /// We do not implement `Eq` and `PartialEq` and
+    /// intentionally keep this API hidden from users.
+    #[inline]
+    pub(crate) fn eq(lhs: &Self, rhs: &Self) -> bool {
+        lhs.as_inner() == rhs.as_inner()
+    }
+
     /// Creates a new linear memory to the store.
     ///
     /// # Errors
     ///
-    /// If more than [`u32::MAX`] much linear memory is allocated.
+    /// - If more than [`u32::MAX`] much linear memory is allocated.
+    /// - If the [`ResourceLimiter`] of the [`Store`] denies the allocation.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`Store`]: crate::Store
     pub fn new(mut ctx: impl AsContextMut, ty: MemoryType) -> Result<Self, MemoryError> {
-        let entity = MemoryEntity::new(ty)?;
-        let memory = ctx.as_context_mut().store.inner.alloc_memory(entity);
+        let (inner, mut limiter) = ctx
+            .as_context_mut()
+            .store
+            .store_inner_and_resource_limiter_ref();
+        if let Some(limiter) = limiter.as_resource_limiter() {
+            if inner.len_memories() >= limiter.memories() {
+                return Err(MemoryError::TooManyMemories);
+            }
+        }
+        let entity = MemoryEntity::new(ty, &mut limiter, inner.memory_creator())?;
+        let memory = inner.alloc_memory(entity);
         Ok(memory)
     }
 
+    /// Creates a new linear memory to the store referencing the [`SharedMemory`].
+    ///
+    /// # Note
+    ///
+    /// This allows to share the same linear memory between multiple [`Store`]s
+    /// which might even live on different OS threads.
+    ///
+    /// # Errors
+    ///
+    /// If the [`ResourceLimiter`] of the [`Store`] denies another linear memory.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`Store`]: crate::Store
+    pub fn from_shared(
+        mut ctx: impl AsContextMut,
+        memory: &SharedMemory,
+    ) -> Result<Self, MemoryError> {
+        let (inner, mut limiter) = ctx
+            .as_context_mut()
+            .store
+            .store_inner_and_resource_limiter_ref();
+        if let Some(limiter) = limiter.as_resource_limiter() {
+            if inner.len_memories() >= limiter.memories() {
+                return Err(MemoryError::TooManyMemories);
+            }
+        }
+        let entity = MemoryEntity::from_shared(memory.clone());
+        let memory = inner.alloc_memory(entity);
+        Ok(memory)
+    }
+
+    /// Returns the [`SharedMemory`] if this is a shared linear memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn shared(&self, ctx: impl AsContext) -> Option<SharedMemory> {
+        ctx.as_context()
+            .store
+            .inner
+            .resolve_memory(self)
+            .shared()
+            .cloned()
+    }
+
     /// Returns the memory type of the linear memory.
     ///
     /// # Panics
@@ -308,29 +669,60 @@ impl Memory {
     ///
     /// # Errors
     ///
-    /// If the linear memory would grow beyond its maximum limit after
-    /// the grow operation.
+    /// - If the linear memory would grow beyond its maximum limit after
+    ///   the grow operation.
+    /// - If the [`ResourceLimiter`] of the [`Store`] denies the growth.
     ///
     /// # Panics
     ///
     /// Panics if `ctx` does not own this [`Memory`].
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`Store`]: crate::Store
     pub fn grow(
         &self,
         mut ctx: impl AsContextMut,
         additional: Pages,
     ) -> Result<Pages, MemoryError> {
+        let (inner, mut limiter) = ctx
+            .as_context_mut()
+            .store
+            .store_inner_and_resource_limiter_ref();
+        inner
+            .resolve_memory_mut(self)
+            .grow(additional, &mut limiter)
+    }
+
+    /// Resets the linear memory to its initial size with all bytes zeroed.
+    ///
+    /// # Note
+    ///
+    /// This allows to reuse a [`Memory`] without allocating a new one.
+    /// On platforms with OS virtual memory support this replaces the pages of the
+    /// [`Memory`] with fresh zeroed pages instead of writing zeros to all of its bytes.
+    ///
+    /// # Errors
+    ///
+    /// - If the linear memory is shared.
+    /// - If the underlying virtual memory cannot be reset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn reset(&self, mut ctx: impl AsContextMut) -> Result<(), MemoryError> {
         ctx.as_context_mut()
             .store
             .inner
             .resolve_memory_mut(self)
-            .grow(additional)
+            .reset()
     }
 
     /// Returns a shared slice to the bytes underlying the [`Memory`].
     ///
     /// # Panics
     ///
-    /// Panics if `ctx` does not own this [`Memory`].
+    /// - Panics if `ctx` does not own this [`Memory`].
+    /// - Panics if this is a shared linear memory. Use [`SharedMemory::data`] instead.
     pub fn data<'a, T: 'a>(&self, ctx: impl Into<StoreContext<'a, T>>) -> &'a [u8] {
         ctx.into().store.inner.resolve_memory(self).data()
     }
@@ -339,7 +731,8 @@ impl Memory {
     ///
     /// # Panics
     ///
-    /// Panics if `ctx` does not own this [`Memory`].
+    /// - Panics if `ctx` does not own this [`Memory`].
+    /// - Panics if this is a shared linear memory. Use [`SharedMemory::data`] instead.
     pub fn data_mut<'a, T: 'a>(&self, ctx: impl Into<StoreContextMut<'a, T>>) -> &'a mut [u8] {
         ctx.into().store.inner.resolve_memory_mut(self).data_mut()
     }
@@ -349,7 +742,8 @@ impl Memory {
     ///
     /// # Panics
     ///
-    /// Panics if `ctx` does not own this [`Memory`].
+    /// - Panics if `ctx` does not own this [`Memory`].
+    /// - Panics if this is a shared linear memory. Use [`SharedMemory::data`] instead.
     pub fn data_and_store_mut<'a, T: 'a>(
         &self,
         ctx: impl Into<StoreContextMut<'a, T>>,
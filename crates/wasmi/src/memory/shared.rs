@@ -0,0 +1,511 @@
+use super::{MemoryError, MemoryType};
+use alloc::sync::Arc;
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    ptr::{self, NonNull},
+    slice,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use wasmi_core::Pages;
+
+#[cfg(feature = "std")]
+use self::parking::ParkingSpot;
+
+/// A Wasm linear memory that can be shared between multiple [`Store`]s.
+///
+/// # Note
+///
+/// - Shared linear memories are defined by the Wasm [`threads`] proposal and
+///   can be safely referenced from [`Store`]s living on different OS threads.
+/// - The address space of a [`SharedMemory`] is reserved upfront for its maximum size
+///   so that its bytes never move when the [`SharedMemory`] grows.
+///   Only the pages in use are committed on platforms with OS virtual memory support.
+/// - Concurrent non-atomic accesses to the same bytes are racy in the same way
+///   as they are racy in Wasm. Atomic Wasm instructions use atomic operations.
+/// - Since its bytes might be modified concurrently by other threads there
+///   are no Rust references to the bytes of a [`SharedMemory`]. Instead they are
+///   accessible via [`SharedMemory::data`] or [`SharedMemory::data_ptr`].
+///
+/// [`Store`]: crate::Store
+/// [`threads`]: https://github.com/WebAssembly/threads
+#[derive(Debug, Clone)]
+pub struct SharedMemory {
+    inner: Arc<SharedMemoryInner>,
+}
+
+/// The shared state of a [`SharedMemory`].
+struct SharedMemoryInner {
+    /// The type of the shared linear memory.
+    memory_type: MemoryType,
+    /// The reserved address space for the maximum size of the shared linear memory.
+    bytes: SharedBytes,
+    /// The amount of pages currently in use.
+    current_pages: AtomicU64,
+    /// The threads waiting on addresses of the shared linear memory.
+    #[cfg(feature = "std")]
+    parking_spot: ParkingSpot,
+}
+
+impl fmt::Debug for SharedMemoryInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedMemoryInner")
+            .field("memory_type", &self.memory_type)
+            .field("current_pages", &self.current_pages)
+            .finish()
+    }
+}
+
+/// The result of a `memory.atomic.wait` operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WaitResult {
+    /// The waiting thread was woken up by a `memory.atomic.notify`.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    Ok = 0,
+    /// The loaded value did not match the expected value.
+    Mismatch = 1,
+    /// The waiting thread was not woken up before the timeout elapsed.
+    TimedOut = 2,
+}
+
+impl SharedMemory {
+    /// Creates a new [`SharedMemory`] with the given [`MemoryType`].
+    ///
+    /// # Errors
+    ///
+    /// - If `ty` is not a shared [`MemoryType`].
+    /// - If the maximum size of `ty` cannot be reserved.
+    /// - If the maximum size of `ty` exceeds 1 GiB on platforms without
+    ///   OS virtual memory support where the maximum size is allocated upfront.
+    pub fn new(ty: MemoryType) -> Result<Self, MemoryError> {
+        if !ty.is_shared() {
+            return Err(MemoryError::InvalidMemoryType);
+        }
+        let initial_len = ty
+            .initial_pages()
+            .to_bytes()
+            .ok_or(MemoryError::OutOfBoundsAllocation)?;
+        let maximum_len = ty
+            .absolute_maximum_pages()
+            .to_bytes()
+            .ok_or(MemoryError::OutOfBoundsAllocation)?;
+        let bytes = SharedBytes::new(initial_len, maximum_len)?;
+        Ok(Self {
+            inner: Arc::new(SharedMemoryInner {
+                memory_type: ty,
+                bytes,
+                current_pages: AtomicU64::new(u64::from(ty.initial_pages())),
+                #[cfg(feature = "std")]
+                parking_spot: ParkingSpot::default(),
+            }),
+        })
+    }
+
+    /// Returns `true` if `lhs` and `rhs` refer to the same shared linear memory.
+    pub fn same(lhs: &Self, rhs: &Self) -> bool {
+        Arc::ptr_eq(&lhs.inner, &rhs.inner)
+    }
+
+    /// Returns the [`MemoryType`] of the [`SharedMemory`].
+    pub fn ty(&self) -> MemoryType {
+        self.inner.memory_type
+    }
+
+    /// Returns the amount of pages in use by the [`SharedMemory`].
+    pub fn current_pages(&self) -> Pages {
+        let current_pages = self.inner.current_pages.load(Ordering::Acquire);
+        Pages::new64(current_pages)
+            .unwrap_or_else(|| panic!("shared memory pages are out of bounds: {current_pages}"))
+    }
+
+    /// Returns the number of currently accessible bytes of the [`SharedMemory`].
+    pub fn data_size(&self) -> usize {
+        // This cannot fail since the maximum size has been reserved upon creation.
+        self.current_pages().to_bytes().unwrap_or_default()
+    }
+
+    /// Returns the currently accessible bytes of the [`SharedMemory`].
+    ///
+    /// # Note
+    ///
+    /// The bytes are wrapped in [`UnsafeCell`] since they might be
+    /// concurrently modified by other threads.
+    pub fn data(&self) -> &[UnsafeCell<u8>] {
+        // Safety: `UnsafeCell<u8>` has the same memory layout as `u8` and
+        //         the accessible bytes never move or shrink.
+        unsafe { slice::from_raw_parts(self.data_ptr().cast(), self.data_size()) }
+    }
+
+    /// Returns a raw pointer to the bytes of the [`SharedMemory`].
+    ///
+    /// # Note
+    ///
+    /// - The pointer stays the same for the whole lifetime of the [`SharedMemory`].
+    /// - Only the first [`SharedMemory::data_size`] bytes are accessible.
+    pub fn data_ptr(&self) -> *mut u8 {
+        self.inner.bytes.as_ptr()
+    }
+
+    /// Returns a raw pointer to the currently accessible bytes of the [`SharedMemory`].
+    pub(crate) fn raw_data(&self) -> *mut [u8] {
+        ptr::slice_from_raw_parts_mut(self.data_ptr(), self.data_size())
+    }
+
+    /// Grows the [`SharedMemory`] by the given amount of new pages.
+    ///
+    /// Returns the amount of pages before the operation upon success.
+    ///
+    /// # Note
+    ///
+    /// Shared linear memories can be grown concurrently from multiple threads.
+    ///
+    /// # Errors
+    ///
+    /// If the [`SharedMemory`] would grow beyond its maximum limit.
+    pub fn grow(&self, additional: Pages) -> Result<Pages, MemoryError> {
+        let maximum_pages = self.ty().absolute_maximum_pages();
+        let mut current_pages = self.current_pages();
+        loop {
+            let new_pages = current_pages
+                .checked_add(additional)
+                .filter(|&new_pages| new_pages <= maximum_pages)
+                .ok_or(MemoryError::OutOfBoundsGrowth)?;
+            // The pages have to be committed before they become accessible.
+            // Concurrently growing threads might commit overlapping pages
+            // which is fine since committing never makes pages inaccessible.
+            let current_len = current_pages.to_bytes().unwrap_or_default();
+            let new_len = new_pages
+                .to_bytes()
+                .ok_or(MemoryError::OutOfBoundsAllocation)?;
+            self.inner.bytes.commit(current_len, new_len)?;
+            match self.inner.current_pages.compare_exchange(
+                u64::from(current_pages),
+                u64::from(new_pages),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return Ok(current_pages),
+                Err(_) => current_pages = self.current_pages(),
+            }
+        }
+    }
+
+    /// Reads `n` bytes from `memory[offset..offset+n]` into `buffer`
+    /// where `n` is the length of `buffer`.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    pub fn read(&self, offset: usize, buffer: &mut [u8]) -> Result<(), MemoryError> {
+        let len_buffer = buffer.len();
+        offset
+            .checked_add(len_buffer)
+            .filter(|&end| end <= self.data_size())
+            .ok_or(MemoryError::OutOfBoundsAccess)?;
+        // Safety: the accessed bytes are within the accessible bytes of the
+        //         shared linear memory which never move.
+        unsafe {
+            ptr::copy_nonoverlapping(self.data_ptr().add(offset), buffer.as_mut_ptr(), len_buffer)
+        };
+        Ok(())
+    }
+
+    /// Writes `n` bytes to `memory[offset..offset+n]` from `buffer`
+    /// where `n` if the length of `buffer`.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    pub fn write(&self, offset: usize, buffer: &[u8]) -> Result<(), MemoryError> {
+        let len_buffer = buffer.len();
+        offset
+            .checked_add(len_buffer)
+            .filter(|&end| end <= self.data_size())
+            .ok_or(MemoryError::OutOfBoundsAccess)?;
+        // Safety: the accessed bytes are within the accessible bytes of the
+        //         shared linear memory which never move.
+        unsafe {
+            ptr::copy_nonoverlapping(buffer.as_ptr(), self.data_ptr().add(offset), len_buffer)
+        };
+        Ok(())
+    }
+
+    /// Wakes up at most `count` threads waiting on the `address` of the [`SharedMemory`].
+    ///
+    /// Returns the number of woken up threads.
+    ///
+    /// # Note
+    ///
+    /// The `address` must have been validated by the caller.
+    pub(crate) fn atomic_notify(&self, address: usize, count: u32) -> u32 {
+        #[cfg(feature = "std")]
+        {
+            self.inner.parking_spot.notify(address, count)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            // Without `std` support there cannot be any waiting threads.
+            let _ = (address, count);
+            0
+        }
+    }
+
+    /// Blocks the current thread on `address` while `is_expected` holds.
+    ///
+    /// Waits until notified or the optional `timeout` elapsed.
+    ///
+    /// # Note
+    ///
+    /// - `is_expected` is evaluated while holding the lock of the waiters so that
+    ///   no notification may be lost between the check and going to sleep.
+    /// - Without `std` support this never blocks and instead reports a timeout
+    ///   if the expected value matches since no other thread could notify.
+    pub(crate) fn atomic_wait(
+        &self,
+        address: usize,
+        is_expected: impl FnOnce() -> bool,
+        timeout: Option<Duration>,
+    ) -> WaitResult {
+        #[cfg(feature = "std")]
+        {
+            self.inner.parking_spot.wait(address, is_expected, timeout)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = (address, timeout);
+            match is_expected() {
+                true => WaitResult::TimedOut,
+                false => WaitResult::Mismatch,
+            }
+        }
+    }
+}
+
+/// The reserved and zero initialized bytes of a [`SharedMemory`].
+///
+/// # Note
+///
+/// - With OS virtual memory support only the address space for the maximum size
+///   is reserved upfront and pages are committed as the [`SharedMemory`] grows.
+/// - Otherwise the bytes for the maximum size are allocated upfront which is why
+///   the maximum size is limited to 1 GiB in this case.
+struct SharedBytes {
+    ptr: NonNull<u8>,
+    /// The number of reserved bytes.
+    reserved_len: usize,
+}
+
+// Safety: `SharedBytes` exclusively owns its reserved bytes which never move.
+unsafe impl Send for SharedBytes {}
+// Safety: accesses to the bytes go through Wasm semantics for shared memories.
+unsafe impl Sync for SharedBytes {}
+
+#[cfg(all(feature = "std", unix))]
+impl SharedBytes {
+    /// Reserves the address space for `maximum_len` bytes and commits the first `initial_len`.
+    ///
+    /// # Errors
+    ///
+    /// If the address space cannot be reserved or the initial bytes cannot be committed.
+    fn new(initial_len: usize, maximum_len: usize) -> Result<Self, MemoryError> {
+        let reserved_len = super::mmap::round_to_page_size(maximum_len)?;
+        let ptr = super::mmap::reserve(reserved_len)?;
+        let bytes = Self { ptr, reserved_len };
+        bytes.commit(0, initial_len)?;
+        Ok(bytes)
+    }
+
+    /// Commits the bytes from `current_len` up to `new_len`.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes cannot be committed.
+    fn commit(&self, current_len: usize, new_len: usize) -> Result<(), MemoryError> {
+        debug_assert!(new_len <= self.reserved_len);
+        // Safety: the committed bytes lie within the reserved address space.
+        unsafe { super::mmap::commit(self.ptr, current_len, new_len) }
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl Drop for SharedBytes {
+    fn drop(&mut self) {
+        // Safety: the address space was reserved in `SharedBytes::new`.
+        unsafe { super::mmap::release(self.ptr, self.reserved_len) }
+    }
+}
+
+#[cfg(not(all(feature = "std", unix)))]
+impl SharedBytes {
+    /// The maximum number of bytes that are allocated upfront.
+    const MAX_ALLOC_LEN: usize = 1 << 30;
+
+    /// The alignment of the allocated bytes.
+    ///
+    /// This guarantees that naturally aligned Wasm atomic accesses are also
+    /// properly aligned for the atomic operations of the host.
+    const ALIGN: usize = 16;
+
+    /// Allocates `maximum_len` zero initialized bytes.
+    ///
+    /// # Errors
+    ///
+    /// - If `maximum_len` exceeds [`SharedBytes::MAX_ALLOC_LEN`].
+    /// - If the bytes cannot be allocated.
+    fn new(_initial_len: usize, maximum_len: usize) -> Result<Self, MemoryError> {
+        if maximum_len > Self::MAX_ALLOC_LEN {
+            return Err(MemoryError::OutOfBoundsAllocation);
+        }
+        let layout = Self::layout(maximum_len)?;
+        // Safety: `layout` has a non-zero size.
+        let ptr = unsafe { alloc::alloc::alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr).ok_or(MemoryError::OutOfBoundsAllocation)?;
+        Ok(Self {
+            ptr,
+            reserved_len: maximum_len,
+        })
+    }
+
+    /// Returns the [`Layout`] of `len` allocated bytes.
+    ///
+    /// [`Layout`]: alloc::alloc::Layout
+    fn layout(len: usize) -> Result<alloc::alloc::Layout, MemoryError> {
+        alloc::alloc::Layout::from_size_align(len.max(1), Self::ALIGN)
+            .map_err(|_| MemoryError::OutOfBoundsAllocation)
+    }
+
+    /// Does nothing since all bytes have already been allocated.
+    fn commit(&self, _current_len: usize, new_len: usize) -> Result<(), MemoryError> {
+        debug_assert!(new_len <= self.reserved_len);
+        Ok(())
+    }
+}
+
+#[cfg(not(all(feature = "std", unix)))]
+impl Drop for SharedBytes {
+    fn drop(&mut self) {
+        let layout = Self::layout(self.reserved_len)
+            .unwrap_or_else(|_| unreachable!("the layout has been validated upon allocation"));
+        // Safety: `ptr` was allocated with `layout` in `SharedBytes::new`.
+        unsafe { alloc::alloc::dealloc(self.ptr.as_ptr(), layout) }
+    }
+}
+
+impl SharedBytes {
+    /// Returns a raw pointer to the reserved bytes.
+    fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+}
+
+#[cfg(feature = "std")]
+mod parking {
+    use super::WaitResult;
+    use core::time::Duration;
+    use std::{
+        collections::{BTreeMap, BTreeSet, VecDeque},
+        sync::{Condvar, Mutex, PoisonError},
+        time::Instant,
+    };
+
+    /// Keeps track of the threads waiting on addresses of a shared linear memory.
+    #[derive(Debug, Default)]
+    pub struct ParkingSpot {
+        state: Mutex<ParkingState>,
+        condvar: Condvar,
+    }
+
+    /// The state of a [`ParkingSpot`] protected by its lock.
+    #[derive(Debug, Default)]
+    struct ParkingState {
+        /// The ticket of the next waiting thread.
+        next_ticket: u64,
+        /// The tickets of the waiting threads per address in order of arrival.
+        waiters: BTreeMap<usize, VecDeque<u64>>,
+        /// The tickets of the notified threads that have not yet woken up.
+        notified: BTreeSet<u64>,
+    }
+
+    impl ParkingSpot {
+        /// Wakes up at most `count` threads waiting on `address`.
+        ///
+        /// Returns the number of woken up threads.
+        pub fn notify(&self, address: usize, count: u32) -> u32 {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            let state = &mut *state;
+            let Some(queue) = state.waiters.get_mut(&address) else {
+                return 0;
+            };
+            let mut woken = 0;
+            while woken < count {
+                let Some(ticket) = queue.pop_front() else {
+                    break;
+                };
+                state.notified.insert(ticket);
+                woken += 1;
+            }
+            if queue.is_empty() {
+                state.waiters.remove(&address);
+            }
+            if woken > 0 {
+                self.condvar.notify_all();
+            }
+            woken
+        }
+
+        /// Blocks the current thread on `address` if `is_expected` holds.
+        pub fn wait(
+            &self,
+            address: usize,
+            is_expected: impl FnOnce() -> bool,
+            timeout: Option<Duration>,
+        ) -> WaitResult {
+            let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if !is_expected() {
+                return WaitResult::Mismatch;
+            }
+            let ticket = state.next_ticket;
+            state.next_ticket += 1;
+            state.waiters.entry(address).or_default().push_back(ticket);
+            loop {
+                if state.notified.remove(&ticket) {
+                    return WaitResult::Ok;
+                }
+                state = match (timeout, deadline) {
+                    (None, _) => self
+                        .condvar
+                        .wait(state)
+                        .unwrap_or_else(PoisonError::into_inner),
+                    (Some(_), Some(deadline)) => {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            Self::remove_waiter(&mut state, address, ticket);
+                            return WaitResult::TimedOut;
+                        }
+                        self.condvar
+                            .wait_timeout(state, deadline - now)
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .0
+                    }
+                    // The deadline overflowed so we wait as if there was no timeout.
+                    (Some(_), None) => self
+                        .condvar
+                        .wait(state)
+                        .unwrap_or_else(PoisonError::into_inner),
+                };
+            }
+        }
+
+        /// Removes the `ticket` of a timed out thread waiting on `address`.
+        fn remove_waiter(state: &mut ParkingState, address: usize, ticket: u64) {
+            if let Some(queue) = state.waiters.get_mut(&address) {
+                queue.retain(|&waiter| waiter != ticket);
+                if queue.is_empty() {
+                    state.waiters.remove(&address);
+                }
+            }
+        }
+    }
+}
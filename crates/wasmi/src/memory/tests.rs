@@ -1,4 +1,5 @@
 use super::*;
+use crate::{Engine, Store};
 
 fn memory_type(minimum: u32, maximum: impl Into<Option<u32>>) -> MemoryType {
     MemoryType::new(minimum, maximum.into()).unwrap()
@@ -14,3 +15,74 @@ fn subtyping_works() {
     assert!(memory_type(0, 1).is_subtype_of(&memory_type(0, None)));
     assert!(!memory_type(0, None).is_subtype_of(&memory_type(0, 1)));
 }
+
+fn memory_type64(minimum: u64, maximum: impl Into<Option<u64>>) -> MemoryType {
+    MemoryType::new64(minimum, maximum.into()).unwrap()
+}
+
+#[test]
+fn subtyping_64_works() {
+    assert!(memory_type64(0, 1).is_subtype_of(&memory_type64(0, 1)));
+    assert!(memory_type64(1 << 20, None).is_subtype_of(&memory_type64(1, None)));
+    assert!(!memory_type64(0, 1).is_subtype_of(&memory_type(0, 1)));
+    assert!(!memory_type(0, 1).is_subtype_of(&memory_type64(0, 1)));
+}
+
+#[test]
+fn memory_type64_limits() {
+    assert!(MemoryType::new64(1 << 48, None).is_ok());
+    assert!(MemoryType::new64((1 << 48) + 1, None).is_err());
+    assert!(MemoryType::new64(0, Some((1 << 48) + 1)).is_err());
+    assert!(MemoryType::new(0, Some(65537)).is_err());
+}
+
+fn new_memory(minimum: u32, maximum: impl Into<Option<u32>>) -> (Store<()>, Memory) {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, memory_type(minimum, maximum)).unwrap();
+    (store, memory)
+}
+
+#[test]
+fn grow_preserves_bytes() {
+    let (mut store, memory) = new_memory(1, 4);
+    memory.data_mut(&mut store)[42] = 1;
+    let ptr = memory.data(&store).as_ptr();
+    assert_eq!(
+        memory.grow(&mut store, Pages::from(2)).unwrap(),
+        Pages::from(1)
+    );
+    assert_eq!(memory.data(&store).len(), 3 * 0x1_0000);
+    assert_eq!(memory.data(&store)[42], 1);
+    assert!(memory.data(&store)[43..].iter().all(|&byte| byte == 0x00));
+    if cfg!(all(feature = "std", unix)) {
+        // The bytes of virtual memory based linear memories do not move upon growth.
+        assert_eq!(memory.data(&store).as_ptr(), ptr);
+    }
+    assert!(memory.grow(&mut store, Pages::from(2)).is_err());
+}
+
+#[test]
+fn reset_works() {
+    let (mut store, memory) = new_memory(1, None);
+    memory.grow(&mut store, Pages::from(3)).unwrap();
+    memory.data_mut(&mut store).fill(0xFF);
+    memory.reset(&mut store).unwrap();
+    assert_eq!(memory.current_pages(&store), Pages::from(1));
+    assert_eq!(memory.data(&store).len(), 0x1_0000);
+    assert!(memory.data(&store).iter().all(|&byte| byte == 0x00));
+    memory.grow(&mut store, Pages::from(1)).unwrap();
+    assert!(memory.data(&store).iter().all(|&byte| byte == 0x00));
+}
+
+#[test]
+fn reset_shared_fails() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let shared = SharedMemory::new(MemoryType::new_shared(1, 1).unwrap()).unwrap();
+    let memory = Memory::from_shared(&mut store, &shared).unwrap();
+    assert!(matches!(
+        memory.reset(&mut store),
+        Err(MemoryError::SharedMemoryReset)
+    ));
+}
@@ -1,4 +1,5 @@
 use super::{
+    custom::CustomSection,
     export::ExternIdx,
     import::FuncTypeIdx,
     ConstExpr,
@@ -11,6 +12,7 @@ use super::{
     Import,
     ImportName,
     Module,
+    TagIdx,
 };
 use crate::{
     engine::{CompiledFunc, DedupFuncType},
@@ -34,11 +36,14 @@ pub struct ModuleBuilder<'engine> {
     pub memories: Vec<MemoryType>,
     pub globals: Vec<GlobalType>,
     pub globals_init: Vec<ConstExpr>,
+    pub tags: Vec<DedupFuncType>,
     pub exports: BTreeMap<Box<str>, ExternIdx>,
     pub start: Option<FuncIdx>,
     pub compiled_funcs: Vec<CompiledFunc>,
     pub element_segments: Vec<ElementSegment>,
     pub data_segments: Vec<DataSegment>,
+    pub custom_sections: Vec<CustomSection>,
+    pub code_offset: usize,
 }
 
 /// The import names of the [`Module`] imports.
@@ -48,6 +53,7 @@ pub struct ModuleImports {
     pub tables: Vec<ImportName>,
     pub memories: Vec<ImportName>,
     pub globals: Vec<ImportName>,
+    pub tags: Vec<ImportName>,
 }
 
 impl ModuleImports {
@@ -65,33 +71,75 @@ impl ModuleImports {
 /// The resources of a [`Module`] required for translating function bodies.
 #[derive(Debug, Copy, Clone)]
 pub struct ModuleResources<'a> {
-    res: &'a ModuleBuilder<'a>,
+    engine: &'a Engine,
+    func_types: &'a [DedupFuncType],
+    funcs: &'a [DedupFuncType],
+    globals: &'a [GlobalType],
+    globals_init: &'a [ConstExpr],
+    tags: &'a [DedupFuncType],
+    compiled_funcs: &'a [CompiledFunc],
+    len_imported_funcs: usize,
+    len_imported_globals: usize,
 }
 
 impl<'a> ModuleResources<'a> {
     /// Returns the [`Engine`] of the [`ModuleResources`].
     pub fn engine(&'a self) -> &'a Engine {
-        self.res.engine
+        self.engine
     }
 
     /// Creates new [`ModuleResources`] from the given [`ModuleBuilder`].
     pub fn new(res: &'a ModuleBuilder) -> Self {
-        Self { res }
+        Self {
+            engine: res.engine,
+            func_types: &res.func_types,
+            funcs: &res.funcs,
+            globals: &res.globals,
+            globals_init: &res.globals_init,
+            tags: &res.tags,
+            compiled_funcs: &res.compiled_funcs,
+            len_imported_funcs: res.imports.len_funcs(),
+            len_imported_globals: res.imports.len_globals(),
+        }
+    }
+
+    /// Creates new [`ModuleResources`] from the given [`LazyModuleResources`].
+    pub fn from_lazy(engine: &'a Engine, res: &'a LazyModuleResources) -> Self {
+        Self {
+            engine,
+            func_types: &res.func_types,
+            funcs: &res.funcs,
+            globals: &res.globals,
+            globals_init: &res.globals_init,
+            tags: &res.tags,
+            compiled_funcs: &res.compiled_funcs,
+            len_imported_funcs: res.len_imported_funcs,
+            len_imported_globals: res.len_imported_globals,
+        }
     }
 
     /// Returns the [`FuncType`] at the given index.
     pub fn get_func_type(&self, func_type_idx: FuncTypeIdx) -> &DedupFuncType {
-        &self.res.func_types[func_type_idx.into_u32() as usize]
+        &self.func_types[func_type_idx.into_u32() as usize]
     }
 
     /// Returns the [`FuncType`] of the indexed function.
     pub fn get_type_of_func(&self, func_idx: FuncIdx) -> &DedupFuncType {
-        &self.res.funcs[func_idx.into_u32() as usize]
+        &self.funcs[func_idx.into_u32() as usize]
     }
 
     /// Returns the [`GlobalType`] the the indexed global variable.
     pub fn get_type_of_global(&self, global_idx: GlobalIdx) -> GlobalType {
-        self.res.globals[global_idx.into_u32() as usize]
+        self.globals[global_idx.into_u32() as usize]
+    }
+
+    /// Returns the [`FuncType`] of the indexed tag.
+    ///
+    /// # Note
+    ///
+    /// The parameters of the [`FuncType`] describe the exception payload.
+    pub fn get_type_of_tag(&self, tag_idx: TagIdx) -> &DedupFuncType {
+        &self.tags[tag_idx.into_u32() as usize]
     }
 
     /// Returns the [`CompiledFunc`] for the given [`FuncIdx`].
@@ -99,29 +147,66 @@ impl<'a> ModuleResources<'a> {
     /// Returns `None` if [`FuncIdx`] refers to an imported function.
     pub fn get_compiled_func(&self, func_idx: FuncIdx) -> Option<CompiledFunc> {
         let index = func_idx.into_u32() as usize;
-        let len_imported = self.res.imports.len_funcs();
-        let index = index.checked_sub(len_imported)?;
+        let index = index.checked_sub(self.len_imported_funcs)?;
         // Note: It is a bug if this index access is out of bounds
         //       therefore we panic here instead of using `get`.
-        Some(self.res.compiled_funcs[index])
+        Some(self.compiled_funcs[index])
     }
 
     /// Returns the global variable type and optional initial value.
     pub fn get_global(&self, global_idx: GlobalIdx) -> (GlobalType, Option<&ConstExpr>) {
         let index = global_idx.into_u32() as usize;
-        let len_imports = self.res.imports.len_globals();
+        let len_imports = self.len_imported_globals;
         let global_type = self.get_type_of_global(global_idx);
         if index < len_imports {
             // The index refers to an imported global without init value.
             (global_type, None)
         } else {
             // The index refers to an internal global with init value.
-            let init_expr = &self.res.globals_init[index - len_imports];
+            let init_expr = &self.globals_init[index - len_imports];
             (global_type, Some(init_expr))
         }
     }
 }
 
+/// The resources of a [`Module`] required for lazily translating function bodies.
+///
+/// # Note
+///
+/// This is a snapshot of the [`ModuleResources`] that is shared by all
+/// function bodies of a [`Module`] whose translation has been deferred.
+#[derive(Debug)]
+pub struct LazyModuleResources {
+    func_types: Box<[DedupFuncType]>,
+    funcs: Box<[DedupFuncType]>,
+    globals: Box<[GlobalType]>,
+    globals_init: Box<[ConstExpr]>,
+    tags: Box<[DedupFuncType]>,
+    compiled_funcs: Box<[CompiledFunc]>,
+    len_imported_funcs: usize,
+    len_imported_globals: usize,
+}
+
+impl LazyModuleResources {
+    /// Creates new [`LazyModuleResources`] from the given [`ModuleBuilder`].
+    ///
+    /// # Note
+    ///
+    /// This must be called after all sections preceding the code section have been processed.
+    pub fn new(res: &ModuleBuilder) -> Self {
+        Self {
+            func_types: res.func_types.as_slice().into(),
+            funcs: res.funcs.as_slice().into(),
+            globals: res.globals.as_slice().into(),
+            globals_init: res.globals_init.as_slice().into(),
+            tags: res.tags.as_slice().into(),
+            compiled_funcs: res.compiled_funcs.as_slice().into(),
+            len_imported_funcs: res.imports.len_funcs(),
+            len_imported_globals: res.imports.len_globals(),
+        }
+    }
+}
+
 impl<'engine> ModuleBuilder<'engine> {
     /// Creates a new [`ModuleBuilder`] for the given [`Engine`].
     pub fn new(engine: &'engine Engine) -> Self {
@@ -134,11 +219,14 @@ impl<'engine> ModuleBuilder<'engine> {
             memories: Vec::new(),
             globals: Vec::new(),
             globals_init: Vec::new(),
+            tags: Vec::new(),
             exports: BTreeMap::new(),
             start: None,
             compiled_funcs: Vec::new(),
             element_segments: Vec::new(),
             data_segments: Vec::new(),
+            custom_sections: Vec::new(),
+            code_offset: 0,
         }
     }
 
@@ -206,6 +294,11 @@ impl<'engine> ModuleBuilder<'engine> {
                     self.imports.globals.push(name);
                     self.globals.push(global_type);
                 }
+                ExternTypeIdx::Tag(func_type_idx) => {
+                    self.imports.tags.push(name);
+                    let func_type = self.func_types[func_type_idx.into_u32() as usize];
+                    self.tags.push(func_type);
+                }
             }
         }
         Ok(())
@@ -315,6 +408,32 @@ impl<'engine> ModuleBuilder<'engine> {
         Ok(())
     }
 
+    /// Pushes the given tag declarations to the [`Module`] under construction.
+    ///
+    /// # Errors
+    ///
+    /// If a tag declaration fails to validate.
+    ///
+    /// # Panics
+    ///
+    /// If this function has already been called on the same [`ModuleBuilder`].
+    pub fn push_tags<T>(&mut self, tags: T) -> Result<(), ModuleError>
+    where
+        T: IntoIterator<Item = Result<FuncTypeIdx, ModuleError>>,
+    {
+        assert_eq!(
+            self.tags.len(),
+            self.imports.tags.len(),
+            "tried to initialize module tag declarations twice"
+        );
+        for tag in tags {
+            let func_type_idx = tag?;
+            let func_type = self.func_types[func_type_idx.into_u32() as usize];
+            self.tags.push(func_type);
+        }
+        Ok(())
+    }
+
     /// Pushes the given exports to the [`Module`] under construction.
     ///
     /// # Errors
@@ -390,6 +509,16 @@ impl<'engine> ModuleBuilder<'engine> {
         Ok(())
     }
 
+    /// Pushes the given custom section to the [`Module`] under construction.
+    pub fn push_custom_section(&mut self, section: CustomSection) {
+        self.custom_sections.push(section);
+    }
+
+    /// Sets the offset of the code section contents within the Wasm binary.
+    pub fn set_code_offset(&mut self, offset: usize) {
+        self.code_offset = offset;
+    }
+
     /// Finishes construction of the WebAssembly [`Module`].
     pub fn finish(self) -> Module {
         Module::from_builder(self)
@@ -1,10 +1,19 @@
 pub use self::block_type::BlockType;
-use super::{parser::ReusableAllocations, FuncIdx, ModuleResources};
+use super::{builder::LazyModuleResources, parser::ReusableAllocations, FuncIdx, ModuleResources};
 use crate::{
     engine::{CompiledFunc, FuncBuilder, FuncTranslatorAllocations},
     errors::ModuleError,
+    Engine,
+};
+use alloc::{boxed::Box, sync::Arc};
+use core::fmt;
+use wasmparser::{
+    FuncToValidate,
+    FuncValidator,
+    FuncValidatorAllocations,
+    FunctionBody,
+    ValidatorResources,
 };
-use wasmparser::{FuncValidator, FunctionBody, ValidatorResources};
 
 mod block_type;
 
@@ -14,7 +23,8 @@ mod block_type;
 ///
 /// - Uses the given `engine` as target for the translation.
 /// - Uses the given `parser` and `validator` for parsing and validation of
-///   the incoming Wasm bytecode stream.
+///   the incoming Wasm bytecode stream. The `validator` is `None` if the
+///   Wasm bytecode has already been validated.
 /// - Uses the given module resources `res` as shared immutable data of the
 ///   already parsed and validated module parts required for the translation.
 ///
@@ -25,19 +35,101 @@ pub fn translate<'parser>(
     func: FuncIdx,
     compiled_func: CompiledFunc,
     func_body: FunctionBody<'parser>,
-    validator: FuncValidator<ValidatorResources>,
+    validator: Option<FuncValidator<ValidatorResources>>,
     res: ModuleResources<'parser>,
     allocations: FuncTranslatorAllocations,
 ) -> Result<ReusableAllocations, ModuleError> {
     FunctionTranslator::new(func, compiled_func, func_body, validator, res, allocations).translate()
 }
 
+/// A Wasm function body whose translation is deferred until its first execution.
+pub struct LazyFunc {
+    /// The index of the function within its [`Module`](crate::Module).
+    func: FuncIdx,
+    /// The offset of the function body within the Wasm binary.
+    offset: usize,
+    /// The Wasm encoding of the function body.
+    bytes: Box<[u8]>,
+    /// The module resources shared by all lazily translated functions of the module.
+    res: Arc<LazyModuleResources>,
+    /// The function validator if the function body has yet to be validated.
+    validator: Option<FuncToValidate<ValidatorResources>>,
+}
+
+impl fmt::Debug for LazyFunc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyFunc")
+            .field("func", &self.func)
+            .field("offset", &self.offset)
+            .field("len_bytes", &self.bytes.len())
+            .field("validated", &self.validator.is_none())
+            .finish()
+    }
+}
+
+impl LazyFunc {
+    /// Creates a new [`LazyFunc`] for the given `func_body`.
+    ///
+    /// The `validator` is `None` if `func_body` has already been validated.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes of `func_body` cannot be read.
+    pub fn new(
+        func: FuncIdx,
+        func_body: FunctionBody,
+        res: Arc<LazyModuleResources>,
+        validator: Option<FuncToValidate<ValidatorResources>>,
+    ) -> Result<Self, ModuleError> {
+        let mut reader = func_body.get_binary_reader();
+        let offset = reader.original_position();
+        let bytes = reader.read_bytes(reader.bytes_remaining())?.into();
+        Ok(Self {
+            func,
+            offset,
+            bytes,
+            res,
+            validator,
+        })
+    }
+
+    /// Translates the [`LazyFunc`] into the `compiled_func` of the `engine`.
+    ///
+    /// # Errors
+    ///
+    /// If the function body fails to validate.
+    pub fn translate(
+        self,
+        engine: &Engine,
+        compiled_func: CompiledFunc,
+    ) -> Result<(), ModuleError> {
+        let func_body = FunctionBody::new(self.offset, &self.bytes);
+        let res = ModuleResources::from_lazy(engine, &self.res);
+        let validator = self
+            .validator
+            .map(|validator| validator.into_validator(FuncValidatorAllocations::default()));
+        translate(
+            self.func,
+            compiled_func,
+            func_body,
+            validator,
+            res,
+            FuncTranslatorAllocations::default(),
+        )?;
+        Ok(())
+    }
+}
+
 /// Translates Wasm bytecode into `wasmi` bytecode for a single Wasm function.
 struct FunctionTranslator<'parser> {
     /// The function body that shall be translated.
     func_body: FunctionBody<'parser>,
     /// The interface to incrementally build up the `wasmi` bytecode function.
     func_builder: FuncBuilder<'parser>,
+    /// Is `true` if the `memory64` Wasm proposal is enabled.
+    ///
+    /// In this case memory access offsets are decoded as 64-bit integers.
+    memory64: bool,
 }
 
 impl<'parser> FunctionTranslator<'parser> {
@@ -46,14 +138,16 @@ impl<'parser> FunctionTranslator<'parser> {
         func: FuncIdx,
         compiled_func: CompiledFunc,
         func_body: FunctionBody<'parser>,
-        validator: FuncValidator<ValidatorResources>,
+        validator: Option<FuncValidator<ValidatorResources>>,
         res: ModuleResources<'parser>,
         allocations: FuncTranslatorAllocations,
     ) -> Self {
+        let memory64 = res.engine().config().wasm_features().memory64;
         let func_builder = FuncBuilder::new(func, compiled_func, res, validator, allocations);
         Self {
             func_body,
             func_builder,
+            memory64,
         }
     }
 
@@ -89,6 +183,7 @@ impl<'parser> FunctionTranslator<'parser> {
     /// Returns the offset of the `End` Wasm operator.
     fn translate_operators(&mut self) -> Result<usize, ModuleError> {
         let mut reader = self.func_body.get_operators_reader()?;
+        reader.allow_memarg64(self.memory64);
         while !reader.eof() {
             let pos = reader.original_position();
             self.func_builder.update_pos(pos);
@@ -0,0 +1,162 @@
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::slice::Iter as SliceIter;
+use wasmparser::{Name, NameMap, NameSectionReader};
+
+/// The name of the standard Wasm `name` custom section.
+const NAME_SECTION: &str = "name";
+
+/// A custom section of a [`Module`].
+///
+/// [`Module`]: super::Module
+#[derive(Debug, Clone)]
+pub struct CustomSection {
+    /// The name of the custom section.
+    name: Box<str>,
+    /// The contents of the custom section.
+    data: Box<[u8]>,
+}
+
+impl CustomSection {
+    /// Creates a new [`CustomSection`] with the given `name` and `data`.
+    pub fn new(name: &str, data: &[u8]) -> Self {
+        Self {
+            name: name.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Returns the name of the [`CustomSection`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the contents of the [`CustomSection`].
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// An iterator over the contents of all custom sections of a [`Module`] with a given name.
+///
+/// [`Module`]: super::Module
+#[derive(Debug)]
+pub struct CustomSectionsIter<'a> {
+    /// The name of the yielded custom sections.
+    name: &'a str,
+    /// The custom sections of the [`Module`].
+    ///
+    /// [`Module`]: super::Module
+    sections: SliceIter<'a, CustomSection>,
+}
+
+impl<'a> CustomSectionsIter<'a> {
+    /// Creates a new [`CustomSectionsIter`] over the `sections` named `name`.
+    pub fn new(sections: &'a [CustomSection], name: &'a str) -> Self {
+        Self {
+            name,
+            sections: sections.iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for CustomSectionsIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let name = self.name;
+        self.sections
+            .find(|section| section.name() == name)
+            .map(CustomSection::data)
+    }
+}
+
+/// The debug names of a [`Module`] as found in its `name` custom section.
+///
+/// # Note
+///
+/// Malformed parts of the `name` custom section are ignored since custom
+/// sections must not affect the validity of a Wasm module.
+///
+/// [`Module`]: super::Module
+#[derive(Debug, Default)]
+pub struct ModuleNames {
+    /// The name of the [`Module`] if any.
+    ///
+    /// [`Module`]: super::Module
+    module: Option<Box<str>>,
+    /// The names of the functions by their function index.
+    funcs: BTreeMap<u32, Box<str>>,
+    /// The names of the local variables by their function and local index.
+    locals: BTreeMap<u32, BTreeMap<u32, Box<str>>>,
+}
+
+impl ModuleNames {
+    /// Creates the [`ModuleNames`] from the first `name` custom section in `sections` if any.
+    pub fn from_custom_sections(sections: &[CustomSection]) -> Self {
+        let mut names = Self::default();
+        let section = match sections
+            .iter()
+            .find(|section| section.name() == NAME_SECTION)
+        {
+            Some(section) => section,
+            None => return names,
+        };
+        for subsection in NameSectionReader::new(section.data(), 0) {
+            match subsection {
+                Ok(Name::Module { name, .. }) => {
+                    names.module = Some(name.into());
+                }
+                Ok(Name::Function(map)) => {
+                    names.funcs = collect_name_map(map);
+                }
+                Ok(Name::Local(map)) => {
+                    names.locals = map
+                        .into_iter()
+                        .map_while(Result::ok)
+                        .map(|naming| (naming.index, collect_name_map(naming.names)))
+                        .collect();
+                }
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+        names
+    }
+
+    /// Returns the name of the [`Module`] if any.
+    ///
+    /// [`Module`]: super::Module
+    pub fn module(&self) -> Option<&str> {
+        self.module.as_deref()
+    }
+
+    /// Returns the name of the function at `func_index` if any.
+    ///
+    /// # Note
+    ///
+    /// The `func_index` refers to the function index space of the [`Module`]
+    /// which starts with the imported functions.
+    ///
+    /// [`Module`]: super::Module
+    pub fn func(&self, func_index: u32) -> Option<&str> {
+        self.funcs.get(&func_index).map(|name| &name[..])
+    }
+
+    /// Returns the name of the local variable at `local_index` of the function at `func_index`.
+    ///
+    /// Returns `None` if the local variable has no name.
+    pub fn local(&self, func_index: u32, local_index: u32) -> Option<&str> {
+        self.locals
+            .get(&func_index)?
+            .get(&local_index)
+            .map(|name| &name[..])
+    }
+}
+
+/// Collects the well-formed entries of the `name` custom section [`NameMap`].
+fn collect_name_map(map: NameMap) -> BTreeMap<u32, Box<str>> {
+    map.into_iter()
+        .map_while(Result::ok)
+        .map(|naming| (naming.index, naming.name.into()))
+        .collect()
+}
@@ -1,10 +1,10 @@
-use super::{ConstExpr, MemoryIdx};
+use super::{ConstExpr, Decoder, Encoder, MemoryIdx, SerializationError};
 use alloc::sync::Arc;
 
 /// A Wasm [`Module`] data segment.
 ///
 /// [`Module`]: [`super::Module`]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DataSegment {
     /// The kind of the data segment.
     kind: DataSegmentKind,
@@ -13,7 +13,7 @@ pub struct DataSegment {
 }
 
 /// The kind of a Wasm module [`DataSegment`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DataSegmentKind {
     /// A passive data segment from the `bulk-memory` Wasm proposal.
     Passive,
@@ -22,7 +22,7 @@ pub enum DataSegmentKind {
 }
 
 /// An active data segment.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ActiveDataSegment {
     /// The linear memory that is to be initialized with this active segment.
     memory_index: MemoryIdx,
@@ -70,6 +70,15 @@ impl From<wasmparser::Data<'_>> for DataSegment {
 }
 
 impl DataSegment {
+    /// Creates a new active [`DataSegment`] writing `bytes` at `offset` into the linear memory.
+    pub fn new_active(memory_index: MemoryIdx, offset: ConstExpr, bytes: Arc<[u8]>) -> Self {
+        let kind = DataSegmentKind::Active(ActiveDataSegment {
+            memory_index,
+            offset,
+        });
+        Self { kind, bytes }
+    }
+
     /// Returns the [`DataSegmentKind`] of the [`DataSegment`].
     pub fn kind(&self) -> &DataSegmentKind {
         &self.kind
@@ -84,4 +93,39 @@ impl DataSegment {
     pub fn clone_bytes(&self) -> Arc<[u8]> {
         self.bytes.clone()
     }
+
+    /// Encodes the [`DataSegment`] using `encoder`.
+    pub fn serialize(&self, encoder: &mut Encoder) {
+        match &self.kind {
+            DataSegmentKind::Passive => encoder.write_u8(0),
+            DataSegmentKind::Active(segment) => {
+                encoder.write_u8(1);
+                encoder.write_u32(segment.memory_index.into_u32());
+                segment.offset.serialize(encoder);
+            }
+        }
+        encoder.write_bytes(&self.bytes);
+    }
+
+    /// Decodes a [`DataSegment`] using `decoder`.
+    ///
+    /// # Errors
+    ///
+    /// If the encoded [`DataSegment`] is malformed.
+    pub fn deserialize(decoder: &mut Decoder) -> Result<Self, SerializationError> {
+        let kind = match decoder.read_u8()? {
+            0 => DataSegmentKind::Passive,
+            1 => {
+                let memory_index = MemoryIdx::from(decoder.read_u32()?);
+                let offset = ConstExpr::deserialize(decoder)?;
+                DataSegmentKind::Active(ActiveDataSegment {
+                    memory_index,
+                    offset,
+                })
+            }
+            _ => return Err(SerializationError::malformed("invalid data segment kind")),
+        };
+        let bytes = decoder.read_bytes()?.into();
+        Ok(Self { kind, bytes })
+    }
 }
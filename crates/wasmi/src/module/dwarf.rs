@@ -0,0 +1,106 @@
+use super::custom::CustomSection;
+use crate::core::SourceLocation;
+use addr2line::Context;
+use alloc::{sync::Arc, vec::Vec};
+use core::fmt;
+use gimli::{Dwarf, EndianReader, LittleEndian};
+use spin::Mutex;
+
+/// The name of the custom section that marks a [`Module`] as having DWARF debug information.
+///
+/// [`Module`]: super::Module
+const DEBUG_INFO_SECTION: &str = ".debug_info";
+
+/// The reader for the DWARF sections of a [`Module`].
+///
+/// [`Module`]: super::Module
+type DwarfReader = EndianReader<LittleEndian, Arc<[u8]>>;
+
+/// The DWARF debug information of a [`Module`] as found in its `.debug_*` custom sections.
+///
+/// # Note
+///
+/// DWARF for Wasm refers to code by offsets relative to the start of the
+/// code section contents while the address maps of compiled functions refer
+/// to offsets relative to the start of the Wasm binary.
+///
+/// [`Module`]: super::Module
+pub struct DwarfInfo {
+    /// The offset of the code section contents within the Wasm binary.
+    code_offset: usize,
+    /// The context that lazily parses the DWARF debug information.
+    ///
+    /// # Note
+    ///
+    /// The [`Mutex`] is required since the lazy parsing is not synchronized.
+    context: Mutex<Context<DwarfReader>>,
+}
+
+impl fmt::Debug for DwarfInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DwarfInfo")
+            .field("code_offset", &self.code_offset)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DwarfInfo {
+    /// Creates the [`DwarfInfo`] from the `.debug_*` custom sections in `sections`.
+    ///
+    /// The `code_offset` is the offset of the code section contents within the Wasm binary.
+    ///
+    /// Returns `None` if there is no `.debug_info` custom section or if it is malformed.
+    pub fn from_custom_sections(sections: &[CustomSection], code_offset: usize) -> Option<Self> {
+        let find_section = |name: &str| {
+            sections
+                .iter()
+                .find(|section| section.name() == name)
+                .map(CustomSection::data)
+        };
+        find_section(DEBUG_INFO_SECTION)?;
+        let dwarf = Dwarf::load(|id| -> Result<DwarfReader, gimli::Error> {
+            let data = Arc::from(find_section(id.name()).unwrap_or_default());
+            Ok(DwarfReader::new(data, LittleEndian))
+        })
+        .ok()?;
+        let context = Context::from_dwarf(dwarf).ok()?;
+        Some(Self {
+            code_offset,
+            context: Mutex::new(context),
+        })
+    }
+
+    /// Returns the [`SourceLocation`] of the Wasm operator at `wasm_offset` of the Wasm binary.
+    ///
+    /// # Note
+    ///
+    /// The source locations are ordered from the innermost inlined function
+    /// to the function that contains the Wasm operator.
+    /// Returns an empty list if the DWARF debug information does not cover `wasm_offset`.
+    pub fn source_locations(&self, wasm_offset: usize) -> Vec<SourceLocation> {
+        let mut locations = Vec::new();
+        let address = match wasm_offset.checked_sub(self.code_offset) {
+            Some(address) => address as u64,
+            None => return locations,
+        };
+        let context = self.context.lock();
+        let mut frames = match context.find_frames(address) {
+            Ok(frames) => frames,
+            Err(_) => return locations,
+        };
+        while let Ok(Some(frame)) = frames.next() {
+            let func = frame
+                .function
+                .as_ref()
+                .and_then(|func| func.demangle().ok());
+            let location = frame.location.as_ref();
+            locations.push(SourceLocation::new(
+                func.as_deref(),
+                location.and_then(|location| location.file),
+                location.and_then(|location| location.line),
+                location.and_then(|location| location.column),
+            ));
+        }
+        locations
+    }
+}
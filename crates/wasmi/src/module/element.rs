@@ -1,12 +1,12 @@
-use super::{ConstExpr, TableIdx};
+use super::{ConstExpr, Decoder, Encoder, SerializationError, TableIdx};
 use crate::module::utils::WasmiValueType;
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec::Vec};
 use wasmi_core::ValueType;
 
 /// A table element segment within a [`Module`].
 ///
 /// [`Module`]: [`super::Module`]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ElementSegment {
     /// The kind of the [`ElementSegment`].
     kind: ElementSegmentKind,
@@ -57,7 +57,7 @@ impl ElementSegmentItems {
 }
 
 /// The kind of a Wasm [`ElementSegment`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ElementSegmentKind {
     /// A passive [`ElementSegment`] from the `bulk-memory` Wasm proposal.
     Passive,
@@ -68,7 +68,7 @@ pub enum ElementSegmentKind {
 }
 
 /// An active Wasm element segment.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ActiveElementSegment {
     /// The index of the Wasm table that is to be initialized.
     table_index: TableIdx,
@@ -137,4 +137,64 @@ impl ElementSegment {
     pub fn items_cloned(&self) -> ElementSegmentItems {
         self.items.clone()
     }
+
+    /// Encodes the [`ElementSegment`] using `encoder`.
+    pub fn serialize(&self, encoder: &mut Encoder) {
+        match &self.kind {
+            ElementSegmentKind::Passive => encoder.write_u8(0),
+            ElementSegmentKind::Active(segment) => {
+                encoder.write_u8(1);
+                encoder.write_u32(segment.table_index.into_u32());
+                segment.offset.serialize(encoder);
+            }
+            ElementSegmentKind::Declared => encoder.write_u8(2),
+        }
+        encoder.write_value_type(self.ty);
+        let items = self.items.items();
+        encoder.write_len(items.len());
+        for item in items {
+            item.serialize(encoder);
+        }
+    }
+
+    /// Decodes an [`ElementSegment`] using `decoder`.
+    ///
+    /// # Errors
+    ///
+    /// If the encoded [`ElementSegment`] is malformed.
+    pub fn deserialize(decoder: &mut Decoder) -> Result<Self, SerializationError> {
+        let kind = match decoder.read_u8()? {
+            0 => ElementSegmentKind::Passive,
+            1 => {
+                let table_index = TableIdx::from(decoder.read_u32()?);
+                let offset = ConstExpr::deserialize(decoder)?;
+                ElementSegmentKind::Active(ActiveElementSegment {
+                    table_index,
+                    offset,
+                })
+            }
+            2 => ElementSegmentKind::Declared,
+            _ => {
+                return Err(SerializationError::malformed(
+                    "invalid element segment kind",
+                ))
+            }
+        };
+        let ty = decoder.read_value_type()?;
+        if !ty.is_ref() {
+            return Err(SerializationError::malformed(
+                "invalid element segment type",
+            ));
+        }
+        let len_items = decoder.read_items_len()?;
+        let exprs = (0..len_items)
+            .map(|_| ConstExpr::deserialize(decoder))
+            .collect::<Result<Vec<_>, _>>()?
+            .into();
+        Ok(Self {
+            kind,
+            ty,
+            items: ElementSegmentItems { exprs },
+        })
+    }
 }
@@ -1,4 +1,4 @@
-use super::ReadError;
+use super::{ReadError, SerializationError};
 use crate::engine::TranslationError;
 use core::{
     fmt,
@@ -15,6 +15,10 @@ pub enum ModuleError {
     Parser(ParserError),
     /// Encountered when there is a Wasm to `wasmi` translation error.
     Translation(TranslationError),
+    /// Encountered when there is a [`Module`] deserialization error.
+    ///
+    /// [`Module`]: crate::Module
+    Serialization(SerializationError),
 }
 
 impl Display for ModuleError {
@@ -23,6 +27,7 @@ impl Display for ModuleError {
             ModuleError::Read(error) => Display::fmt(error, f),
             ModuleError::Parser(error) => Display::fmt(error, f),
             ModuleError::Translation(error) => Display::fmt(error, f),
+            ModuleError::Serialization(error) => Display::fmt(error, f),
         }
     }
 }
@@ -44,3 +49,9 @@ impl From<TranslationError> for ModuleError {
         Self::Translation(error)
     }
 }
+
+impl From<SerializationError> for ModuleError {
+    fn from(error: SerializationError) -> Self {
+        Self::Serialization(error)
+    }
+}
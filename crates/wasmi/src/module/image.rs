@@ -0,0 +1,127 @@
+use super::{DataSegmentKind, Module, ModuleInner};
+use crate::MemoryType;
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+
+/// The length of [`MemoryImage`] spans that are accepted regardless of their density.
+///
+/// # Note
+///
+/// This equals the size of a single Wasm page.
+const MIN_SPARSE_IMAGE_LEN: usize = 0x1_0000;
+
+/// The pre-merged active data segments of an internal linear memory of a [`Module`].
+///
+/// # Note
+///
+/// The image holds all bytes written by the active data segments of the linear memory
+/// merged into a single span. This way new linear memories are initialized using a single
+/// copy upon instantiation instead of evaluating and writing each of their active data segments.
+///
+/// The image is not mapped copy-on-write into new linear memories. Every instantiation
+/// still allocates its linear memories and copies the bytes of the image into them.
+#[derive(Debug, Clone)]
+pub struct MemoryImage {
+    /// The offset of the first byte of the image within the linear memory.
+    offset: usize,
+    /// The bytes of the image starting at `offset`.
+    bytes: Arc<[u8]>,
+}
+
+impl MemoryImage {
+    /// Returns the offset of the [`MemoryImage`] within its linear memory.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the bytes of the [`MemoryImage`].
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes[..]
+    }
+
+    /// Creates the [`MemoryImage`]s for all internal linear memories of the `module`.
+    ///
+    /// For more information about when no [`MemoryImage`] is created see [`MemoryImage::new`].
+    pub(super) fn build_all(module: &ModuleInner) -> Box<[Option<MemoryImage>]> {
+        let len_imported = module.imports.len_memories;
+        module.memories[len_imported..]
+            .iter()
+            .enumerate()
+            .map(|(n, memory_type)| {
+                let memory_index = u32::try_from(len_imported + n).unwrap_or_else(|error| {
+                    panic!("encountered out of bounds memory index: {error}")
+                });
+                Self::new(module, memory_index, memory_type)
+            })
+            .collect()
+    }
+
+    /// Creates the [`MemoryImage`] for the internal linear memory at `memory_index`.
+    ///
+    /// Returns `None` if:
+    ///
+    /// - The linear memory is shared.
+    /// - Any of its active data segments has a non-constant offset.
+    /// - Any of its active data segments does not fit into its initial size.
+    /// - Its active data segments are too sparse to be efficiently represented by an image.
+    fn new(module: &ModuleInner, memory_index: u32, memory_type: &MemoryType) -> Option<Self> {
+        if memory_type.is_shared() {
+            return None;
+        }
+        let initial_len = memory_type.initial_pages().to_bytes()?;
+        let mut segments = Vec::new();
+        let mut start = usize::MAX;
+        let mut end = 0;
+        let mut len_bytes = 0_usize;
+        for segment in &module.data_segments[..] {
+            let active = match segment.kind() {
+                DataSegmentKind::Active(active) => active,
+                DataSegmentKind::Passive => continue,
+            };
+            if active.memory_index().into_u32() != memory_index {
+                continue;
+            }
+            // Note: offsets of 32-bit linear memories are zero-extended to 64-bit.
+            let offset = usize::try_from(u64::from(active.offset().eval_const()?)).ok()?;
+            let bytes = segment.bytes();
+            let segment_end = offset
+                .checked_add(bytes.len())
+                .filter(|&segment_end| segment_end <= initial_len)?;
+            if bytes.is_empty() {
+                continue;
+            }
+            start = start.min(offset);
+            end = end.max(segment_end);
+            len_bytes += bytes.len();
+            segments.push((offset, bytes));
+        }
+        if segments.is_empty() {
+            return Some(Self {
+                offset: 0,
+                bytes: Arc::new([]),
+            });
+        }
+        let len_image = end - start;
+        if len_image > MIN_SPARSE_IMAGE_LEN && len_image / 2 > len_bytes {
+            return None;
+        }
+        let mut image = vec![0x00_u8; len_image];
+        for (offset, bytes) in segments {
+            let offset = offset - start;
+            image[offset..offset + bytes.len()].copy_from_slice(bytes);
+        }
+        Some(Self {
+            offset: start,
+            bytes: image.into(),
+        })
+    }
+}
+
+impl Module {
+    /// Returns the [`MemoryImage`] of the linear memory at `memory_index` if any.
+    ///
+    /// Returns `None` for imported linear memories.
+    pub(crate) fn memory_image(&self, memory_index: u32) -> Option<&MemoryImage> {
+        let index = (memory_index as usize).checked_sub(self.inner.imports.len_memories)?;
+        self.inner.memory_images.get(index)?.as_ref()
+    }
+}
@@ -6,11 +6,13 @@
 //!
 //! [`s1vm`]: https://github.com/Neopallium/s1vm
 
-use super::FuncIdx;
+use super::{Decoder, Encoder, FuncIdx, SerializationError};
 use crate::{ExternRef, FuncRef, Value};
-use alloc::boxed::Box;
+use alloc::{boxed::Box, sync::Arc};
 use core::fmt;
 use smallvec::SmallVec;
+#[cfg(feature = "simd")]
+use wasmi_core::V128;
 use wasmi_core::{UntypedValue, F32, F64};
 
 /// Types that allow evluation given an evaluation context.
@@ -43,7 +45,7 @@ impl EvalContext for EmptyEvalContext {
 }
 
 /// An input parameter to a [`ConstExpr`] operator.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Op {
     /// A constant value.
     Const(ConstOp),
@@ -64,7 +66,7 @@ pub enum Op {
 /// - `f32.const`
 /// - `f64.const`
 /// - `ref.null`
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ConstOp {
     /// The underlying precomputed untyped value.
     value: UntypedValue,
@@ -78,7 +80,7 @@ impl Eval for ConstOp {
 
 /// Represents a Wasm `global.get` operator.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GlobalOp {
     /// The index of the global variable.
     global_index: u32,
@@ -92,7 +94,7 @@ impl Eval for GlobalOp {
 
 /// Represents a Wasm `func.ref` operator.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FuncRefOp {
     /// The index of the function.
     function_index: u32,
@@ -114,10 +116,11 @@ impl Eval for FuncRefOp {
 /// - `i64.add`
 /// - `i64.sub`
 /// - `i64.mul`
+#[derive(Clone)]
 #[allow(clippy::type_complexity)]
 pub struct ExprOp {
     /// The underlying closure that implements the expression.
-    expr: Box<dyn Fn(&dyn EvalContext) -> Option<UntypedValue> + Send + Sync>,
+    expr: Arc<dyn Fn(&dyn EvalContext) -> Option<UntypedValue> + Send + Sync>,
 }
 
 impl fmt::Debug for ExprOp {
@@ -159,7 +162,7 @@ impl Op {
         T: Fn(&dyn EvalContext) -> Option<UntypedValue> + Send + Sync + 'static,
     {
         Self::Expr(ExprOp {
-            expr: Box::new(expr),
+            expr: Arc::new(expr),
         })
     }
 }
@@ -180,10 +183,17 @@ impl Eval for Op {
 /// These are used to determine the offsets of memory data
 /// and table element segments as well as the initial value
 /// of global variables.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ConstExpr {
     /// The root operator of the [`ConstExpr`].
     op: Op,
+    /// The Wasm encoding of the [`ConstExpr`] if its root operator is an [`Op::Expr`].
+    ///
+    /// # Note
+    ///
+    /// This is required to serialize the [`ConstExpr`] since [`Op::Expr`]
+    /// operators cannot be inspected.
+    source: Option<Box<[u8]>>,
 }
 
 impl Eval for ConstExpr {
@@ -265,6 +275,12 @@ impl ConstExpr {
                 wasmparser::Operator::F64Const { value } => {
                     stack.push(Op::constant(F64::from(value.bits())));
                 }
+                #[cfg(feature = "simd")]
+                wasmparser::Operator::V128Const { value } => {
+                    stack.push(Op::constant(V128::from(u128::from_le_bytes(
+                        *value.bytes(),
+                    ))));
+                }
                 wasmparser::Operator::GlobalGet { global_index } => {
                     stack.push(Op::global(global_index));
                 }
@@ -299,7 +315,28 @@ impl ConstExpr {
             stack.is_empty(),
             "due to Wasm validation operator stack must be empty now"
         );
-        Self { op }
+        let source = match op {
+            Op::Expr(_) => {
+                let mut reader = expr.get_binary_reader();
+                let source = reader
+                    .read_bytes(reader.bytes_remaining())
+                    .expect("due to Wasm validation this is guaranteed to succeed");
+                Some(source.into())
+            }
+            _ => None,
+        };
+        Self { op, source }
+    }
+
+    /// Create a new [`ConstExpr`] evaluating to the constant `value`.
+    pub fn new_const<T>(value: T) -> Self
+    where
+        T: Into<Value>,
+    {
+        Self {
+            op: Op::constant(value),
+            source: None,
+        }
     }
 
     /// Create a new `ref.func x` [`ConstExpr`].
@@ -310,9 +347,57 @@ impl ConstExpr {
     pub fn new_funcref(function_index: u32) -> Self {
         Self {
             op: Op::FuncRef(FuncRefOp { function_index }),
+            source: None,
         }
     }
 
+    /// Encodes the [`ConstExpr`] using `encoder`.
+    pub fn serialize(&self, encoder: &mut Encoder) {
+        match &self.op {
+            Op::Const(op) => {
+                encoder.write_u8(0);
+                encoder.write_untyped(op.value);
+            }
+            Op::Global(op) => {
+                encoder.write_u8(1);
+                encoder.write_u32(op.global_index);
+            }
+            Op::FuncRef(op) => {
+                encoder.write_u8(2);
+                encoder.write_u32(op.function_index);
+            }
+            Op::Expr(_) => {
+                let source = self
+                    .source
+                    .as_deref()
+                    .expect("expression operators always have a Wasm encoding");
+                encoder.write_u8(3);
+                encoder.write_bytes(source);
+            }
+        }
+    }
+
+    /// Decodes a [`ConstExpr`] using `decoder`.
+    ///
+    /// # Errors
+    ///
+    /// If the encoded [`ConstExpr`] is malformed.
+    pub fn deserialize(decoder: &mut Decoder) -> Result<Self, SerializationError> {
+        let op = match decoder.read_u8()? {
+            0 => Op::Const(ConstOp {
+                value: decoder.read_untyped()?,
+            }),
+            1 => Op::global(decoder.read_u32()?),
+            2 => Op::funcref(decoder.read_u32()?),
+            3 => {
+                let source = decoder.read_bytes()?;
+                return Ok(Self::new(wasmparser::ConstExpr::new(source, 0)));
+            }
+            _ => return Err(SerializationError::malformed("invalid constant expression")),
+        };
+        Ok(Self { op, source: None })
+    }
+
     /// Returns `Some(index)` if the [`ConstExpr`] is a `funcref(index)`.
     ///
     /// Otherwise returns `None`.
@@ -1,6 +1,7 @@
 use crate::{
     errors::{MemoryError, TableError},
     global::GlobalError,
+    tag::TagError,
     Extern,
     ExternType,
     FuncType,
@@ -35,6 +36,8 @@ pub enum InstantiationError {
     Memory(MemoryError),
     /// Occurs when an imported global variable does not satisfy the required global type.
     Global(GlobalError),
+    /// Occurs when an imported tag does not satisfy the required tag type.
+    Tag(TagError),
     /// Caused when an element segment does not fit into the specified table instance.
     ElementSegmentDoesNotFit {
         /// The table of the element segment.
@@ -44,6 +47,10 @@ pub enum InstantiationError {
         /// The amount of elements with which the table is initialized at the `offset`.
         amount: u32,
     },
+    /// Caused when creating more instances than permitted by the [`ResourceLimiter`].
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    TooManyInstances,
     /// Caused when the `start` function was unexpectedly found in the instantiated module.
     FoundStartFn {
         /// The index of the found `start` function.
@@ -79,12 +86,14 @@ impl Display for InstantiationError {
                 f,
                 "out of bounds table access: {table:?} does not fit {amount} elements starting from offset {offset}",
             ),
+            Self::TooManyInstances => write!(f, "too many instances"),
             Self::FoundStartFn { index } => {
                 write!(f, "found an unexpected start function with index {index}")
             }
             Self::Table(error) => Display::fmt(error, f),
             Self::Memory(error) => Display::fmt(error, f),
             Self::Global(error) => Display::fmt(error, f),
+            Self::Tag(error) => Display::fmt(error, f),
         }
     }
 }
@@ -106,3 +115,9 @@ impl From<GlobalError> for InstantiationError {
         Self::Global(error)
     }
 }
+
+impl From<TagError> for InstantiationError {
+    fn from(error: TagError) -> Self {
+        Self::Tag(error)
+    }
+}
@@ -8,7 +8,8 @@ pub use self::{error::InstantiationError, pre::InstancePre};
 use super::{element::ElementSegmentKind, export, ConstExpr, DataSegmentKind, Module};
 use crate::{
     func::WasmFuncEntity,
-    memory::DataSegment,
+    memory::{DataSegment, MemoryError},
+    pool::InstanceSlot,
     value::WithType,
     AsContext,
     AsContextMut,
@@ -24,6 +25,7 @@ use crate::{
     InstanceEntityBuilder,
     Memory,
     Table,
+    Tag,
     Value,
 };
 use wasmi_core::{Trap, UntypedValue};
@@ -54,14 +56,20 @@ impl Module {
     where
         I: IntoIterator<Item = Extern>,
     {
+        Self::check_new_instances_limit(&mut context)?;
+        let pool_slot = Self::acquire_pool_slot(&context)?;
         let handle = context.as_context_mut().store.inner.alloc_instance();
         let mut builder = InstanceEntity::build(self);
+        if let Some(pool_slot) = pool_slot {
+            builder.set_pool_slot(pool_slot);
+        }
 
         self.extract_imports(&mut context, &mut builder, externals)?;
         self.extract_functions(&mut context, &mut builder, handle);
         self.extract_tables(&mut context, &mut builder)?;
-        self.extract_memories(&mut context, &mut builder);
+        self.extract_memories(&mut context, &mut builder)?;
         self.extract_globals(&mut context, &mut builder);
+        self.extract_tags(&mut context, &mut builder);
         self.extract_exports(&mut builder);
         self.extract_start_fn(&mut builder);
 
@@ -73,6 +81,51 @@ impl Module {
         Ok(InstancePre::new(handle, builder))
     }
 
+    /// Checks that a new [`Instance`] may be created for the [`Store`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`ResourceLimiter`] of the [`Store`] does not permit another [`Instance`].
+    ///
+    /// [`Store`]: crate::Store
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    fn check_new_instances_limit(
+        context: &mut impl AsContextMut,
+    ) -> Result<(), InstantiationError> {
+        let (inner, mut limiter) = context
+            .as_context_mut()
+            .store
+            .store_inner_and_resource_limiter_ref();
+        if let Some(limiter) = limiter.as_resource_limiter() {
+            if inner.len_instances() >= limiter.instances() {
+                return Err(InstantiationError::TooManyInstances);
+            }
+        }
+        Ok(())
+    }
+
+    /// Acquires a slot for a new [`Instance`] from the [`PoolingAllocator`] of the [`Store`].
+    ///
+    /// Returns `None` if the [`Store`] has no [`PoolingAllocator`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`PoolingAllocator`] of the [`Store`] does not permit another [`Instance`].
+    ///
+    /// [`Store`]: crate::Store
+    /// [`PoolingAllocator`]: crate::PoolingAllocator
+    fn acquire_pool_slot(
+        context: &impl AsContext,
+    ) -> Result<Option<InstanceSlot>, InstantiationError> {
+        match context.as_context().store.inner.pooling_allocator() {
+            Some(pool) => pool
+                .acquire_instance()
+                .map(Some)
+                .ok_or(InstantiationError::TooManyInstances),
+            None => Ok(None),
+        }
+    }
+
     /// Extract the Wasm imports from the module and zips them with the given external values.
     ///
     /// This also stores imported references into the [`Instance`] under construction.
@@ -83,8 +136,8 @@ impl Module {
     /// - If the zipped import and given external have mismatching types, e.g. on index `i`
     ///   the module requires a function import but on index `i` the externals provide a global
     ///   variable external value.
-    /// - If the externally provided [`Table`], [`Memory`], [`Func`] or [`Global`] has a type
-    ///   mismatch with the expected module import type.
+    /// - If the externally provided [`Table`], [`Memory`], [`Func`], [`Global`] or [`Tag`]
+    ///   has a type mismatch with the expected module import type.
     ///
     /// [`Func`]: [`crate::Func`]
     fn extract_imports<I>(
@@ -114,7 +167,7 @@ impl Module {
                 (ExternType::Func(expected_signature), Extern::Func(func)) => {
                     let actual_signature = func.ty_dedup(context.as_context());
                     let actual_signature = self
-                        .engine
+                        .engine()
                         .resolve_func_type(actual_signature, FuncType::clone);
                     // Note: We can compare function signatures without resolving them because
                     //       we deduplicate them before registering. Therefore two equal instances of
@@ -143,6 +196,11 @@ impl Module {
                     required.satisfies(&imported)?;
                     builder.push_global(global);
                 }
+                (ExternType::Tag(required), Extern::Tag(tag)) => {
+                    let imported = tag.ty(context.as_context());
+                    required.satisfies(&imported)?;
+                    builder.push_tag(tag);
+                }
                 (expected_import, actual_extern_val) => {
                     return Err(InstantiationError::ImportsExternalsMismatch {
                         expected: expected_import.clone(),
@@ -199,22 +257,27 @@ impl Module {
     ///
     /// This also stores [`Memory`] references into the [`Instance`] under construction.
     ///
+    /// # Note
+    ///
+    /// Linear memories with a [`MemoryImage`] are initialized with a copy of their
+    /// pre-merged active data segments.
+    ///
     /// [`Store`]: struct.Store.html
+    /// [`MemoryImage`]: super::MemoryImage
     fn extract_memories(
         &self,
         context: &mut impl AsContextMut,
         builder: &mut InstanceEntityBuilder,
-    ) {
-        for memory_type in self.internal_memories().copied() {
-            let memory =
-                Memory::new(context.as_context_mut(), memory_type).unwrap_or_else(|error| {
-                    panic!(
-                        "encountered unexpected invalid memory type \
-                        {memory_type:?} after Wasm validation: {error}",
-                    )
-                });
+    ) -> Result<(), InstantiationError> {
+        let internal_memories = self.internal_memories().copied();
+        for (memory_type, image) in internal_memories.zip(self.inner.memory_images.iter()) {
+            let memory = Memory::new(context.as_context_mut(), memory_type)?;
+            if let Some(image) = image {
+                memory.write(&mut *context, image.offset(), image.bytes())?;
+            }
             builder.push_memory(memory);
         }
+        Ok(())
     }
 
     /// Extracts the Wasm global variables from the module and stores them into the [`Store`].
@@ -240,6 +303,18 @@ impl Module {
         }
     }
 
+    /// Extracts the Wasm tags from the module and stores them into the [`Store`].
+    ///
+    /// This also stores [`Tag`] references into the [`Instance`] under construction.
+    ///
+    /// [`Store`]: struct.Store.html
+    fn extract_tags(&self, context: &mut impl AsContextMut, builder: &mut InstanceEntityBuilder) {
+        for tag_type in self.internal_tags() {
+            let tag = Tag::new(context.as_context_mut(), tag_type);
+            builder.push_tag(tag);
+        }
+    }
+
     /// Evaluates the given initializer expression using the partially constructed [`Instance`].
     fn eval_init_expr(
         context: impl AsContext,
@@ -256,7 +331,7 @@ impl Module {
 
     /// Extracts the Wasm exports from the module and registers them into the [`Instance`].
     fn extract_exports(&self, builder: &mut InstanceEntityBuilder) {
-        for (field, idx) in &self.exports {
+        for (field, idx) in &self.inner.exports {
             let external = match idx {
                 export::ExternIdx::Func(func_index) => {
                     let func_index = func_index.into_u32();
@@ -278,6 +353,11 @@ impl Module {
                     let global = builder.get_global(global_index);
                     Extern::Global(global)
                 }
+                export::ExternIdx::Tag(tag_index) => {
+                    let tag_index = tag_index.into_u32();
+                    let tag = builder.get_tag(tag_index);
+                    Extern::Tag(tag)
+                }
             };
             builder.push_export(field, external);
         }
@@ -285,7 +365,7 @@ impl Module {
 
     /// Extracts the optional start function for the build instance.
     fn extract_start_fn(&self, builder: &mut InstanceEntityBuilder) {
-        if let Some(start_fn) = self.start {
+        if let Some(start_fn) = self.inner.start {
             builder.set_start(start_fn)
         }
     }
@@ -296,7 +376,7 @@ impl Module {
         mut context: &mut impl AsContextMut,
         builder: &mut InstanceEntityBuilder,
     ) -> Result<(), Error> {
-        for segment in &self.element_segments[..] {
+        for segment in &self.inner.element_segments[..] {
             let element = ElementSegment::new(context.as_context_mut(), segment);
             if let ElementSegmentKind::Active(active) = segment.kind() {
                 let dst_index = u32::from(Self::eval_init_expr(
@@ -320,15 +400,20 @@ impl Module {
                     })?;
                 // Finally do the actual initialization of the table elements.
                 {
-                    let (table, element) = context
+                    let (table, element, func_refs) = context
                         .as_context_mut()
                         .store
                         .inner
                         .resolve_table_element(&table, &element);
                     table
-                        .init(dst_index, element, 0, len_items, |func_index| {
-                            builder.get_func(func_index)
-                        })
+                        .init(
+                            dst_index,
+                            element,
+                            0,
+                            len_items,
+                            |func_index| builder.get_func(func_index),
+                            func_refs,
+                        )
                         .map_err(Trap::from)?;
                 }
                 // Now drop the active element segment as commanded by the Wasm spec.
@@ -345,14 +430,22 @@ impl Module {
         context: &mut impl AsContextMut,
         builder: &mut InstanceEntityBuilder,
     ) -> Result<(), Error> {
-        for segment in &self.data_segments[..] {
-            let bytes = segment.bytes();
-            if let DataSegmentKind::Active(segment) = segment.kind() {
-                let offset_expr = segment.offset();
-                let offset =
-                    u32::from(Self::eval_init_expr(&mut *context, builder, offset_expr)) as usize;
-                let memory = builder.get_memory(segment.memory_index().into_u32());
-                memory.write(&mut *context, offset, bytes)?;
+        for segment in &self.inner.data_segments[..] {
+            if let DataSegmentKind::Active(active) = segment.kind() {
+                let memory_index = active.memory_index().into_u32();
+                // Note: linear memories with a memory image already are initialized.
+                if self.memory_image(memory_index).is_none() {
+                    // Note: offsets of 32-bit linear memories are zero-extended to 64-bit.
+                    let offset = u64::from(Self::eval_init_expr(
+                        &mut *context,
+                        builder,
+                        active.offset(),
+                    ));
+                    let offset =
+                        usize::try_from(offset).map_err(|_| MemoryError::OutOfBoundsAccess)?;
+                    let memory = builder.get_memory(memory_index);
+                    memory.write(&mut *context, offset, segment.bytes())?;
+                }
             }
             builder.push_data_segment(DataSegment::new(context.as_context_mut(), segment));
         }
@@ -3,7 +3,7 @@
 //!
 //! The problem was that Wasm memories (and tables) were defined twice for a
 //! `wasmi` instance for every imported Wasm memory (or table). Since `wasmi`
-//! does not support the `multi-memory` Wasm proposal this resulted Wasm
+//! did not support the `multi-memory` Wasm proposal this resulted Wasm
 //! instances with more than 1 memory (or table) if the Wasm module imported
 //! those entities.
 
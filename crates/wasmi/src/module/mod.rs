@@ -1,21 +1,31 @@
 mod builder;
 mod compile;
+mod custom;
 mod data;
+#[cfg(feature = "dwarf")]
+mod dwarf;
 mod element;
 mod error;
 mod export;
 mod global;
+mod image;
 mod import;
 mod init_expr;
 mod instantiate;
 mod parser;
 mod read;
+mod serialize;
+mod snapshot;
 mod utils;
 
+#[cfg(feature = "dwarf")]
+pub(crate) use self::dwarf::DwarfInfo;
 use self::{
     builder::ModuleBuilder,
+    custom::CustomSection,
     export::ExternIdx,
     global::Global,
+    image::MemoryImage,
     import::{ExternTypeIdx, Import},
     parser::parse,
     read::ReadError,
@@ -23,19 +33,26 @@ use self::{
 pub use self::{
     builder::ModuleResources,
     compile::BlockType,
+    custom::{CustomSectionsIter, ModuleNames},
     error::ModuleError,
-    export::{ExportType, FuncIdx, MemoryIdx, ModuleExportsIter, TableIdx},
+    export::{ExportType, FuncIdx, MemoryIdx, ModuleExportsIter, TableIdx, TagIdx},
     global::GlobalIdx,
     import::{FuncTypeIdx, ImportName},
     instantiate::{InstancePre, InstantiationError},
     parser::ReusableAllocations,
     read::Read,
+    serialize::SerializationError,
+    snapshot::SnapshotError,
 };
 pub(crate) use self::{
+    compile::LazyFunc,
     data::{DataSegment, DataSegmentKind},
     element::{ElementSegment, ElementSegmentItems, ElementSegmentKind},
     init_expr::ConstExpr,
+    serialize::{Decoder, Encoder},
 };
+#[cfg(feature = "dwarf")]
+use crate::core::SourceLocation;
 use crate::{
     engine::{CompiledFunc, DedupFuncType},
     Engine,
@@ -45,13 +62,50 @@ use crate::{
     GlobalType,
     MemoryType,
     TableType,
+    TagType,
 };
+#[cfg(feature = "dwarf")]
+use alloc::vec::Vec;
 use alloc::{boxed::Box, collections::BTreeMap, sync::Arc};
-use core::{iter, slice::Iter as SliceIter};
+use core::{
+    iter,
+    slice::Iter as SliceIter,
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 /// A parsed and validated WebAssembly module.
-#[derive(Debug)]
+///
+/// # Note
+///
+/// Cloning a [`Module`] is cheap since it only clones a reference to its shared contents.
+#[derive(Debug, Clone)]
 pub struct Module {
+    /// The unique index of the [`Module`].
+    idx: ModuleIdx,
+    inner: Arc<ModuleInner>,
+}
+
+/// A unique module index.
+///
+/// # Note
+///
+/// Used to identify the [`Module`] an instance has been instantiated from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ModuleIdx(u32);
+
+impl ModuleIdx {
+    /// Returns a new unique [`ModuleIdx`].
+    fn new() -> Self {
+        /// A static module index counter.
+        static CURRENT_MODULE_IDX: AtomicU32 = AtomicU32::new(0);
+        let next_idx = CURRENT_MODULE_IDX.fetch_add(1, Ordering::AcqRel);
+        Self(next_idx)
+    }
+}
+
+/// The shared contents of a [`Module`].
+#[derive(Debug, Clone)]
+struct ModuleInner {
     engine: Engine,
     func_types: Arc<[DedupFuncType]>,
     imports: ModuleImports,
@@ -60,18 +114,32 @@ pub struct Module {
     memories: Box<[MemoryType]>,
     globals: Box<[GlobalType]>,
     globals_init: Box<[ConstExpr]>,
+    tags: Box<[DedupFuncType]>,
     exports: BTreeMap<Box<str>, ExternIdx>,
     start: Option<FuncIdx>,
     compiled_funcs: Box<[CompiledFunc]>,
     element_segments: Box<[ElementSegment]>,
     data_segments: Box<[DataSegment]>,
+    /// The pre-merged active data segments of the internal linear memories if any.
+    memory_images: Box<[Option<MemoryImage>]>,
+    /// The indices of the internal functions within the function index space by their [`CompiledFunc`].
+    func_indices: Arc<BTreeMap<CompiledFunc, u32>>,
+    /// The custom sections of the module in the order of their appearance.
+    custom_sections: Box<[CustomSection]>,
+    /// The debug names of the module found in its `name` custom section.
+    names: Arc<ModuleNames>,
+    /// The offset of the code section contents within the Wasm binary of the module.
+    code_offset: usize,
+    /// The DWARF debug information of the module found in its `.debug_*` custom sections.
+    #[cfg(feature = "dwarf")]
+    dwarf: Option<Arc<DwarfInfo>>,
 }
 
 /// The index of the default Wasm linear memory.
 pub(crate) const DEFAULT_MEMORY_INDEX: u32 = 0;
 
 /// An imported item declaration in the [`Module`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Imported {
     /// The name of an imported [`Func`].
     ///
@@ -87,10 +155,14 @@ pub enum Imported {
     Memory(ImportName),
     /// The name of an imported [`Global`].
     Global(ImportName),
+    /// The name of an imported [`Tag`].
+    ///
+    /// [`Tag`]: [`crate::Tag`]
+    Tag(ImportName),
 }
 
 /// The import names of the [`Module`] imports.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ModuleImports {
     /// All names and types of all imported items.
     items: Box<[Imported]>,
@@ -108,6 +180,10 @@ pub struct ModuleImports {
     ///
     /// [`Table`]: [`crate::Table`]
     len_tables: usize,
+    /// The amount of imported [`Tag`].
+    ///
+    /// [`Tag`]: [`crate::Tag`]
+    len_tags: usize,
 }
 
 impl ModuleImports {
@@ -117,14 +193,17 @@ impl ModuleImports {
         let len_globals = imports.globals.len();
         let len_memories = imports.memories.len();
         let len_tables = imports.tables.len();
+        let len_tags = imports.tags.len();
         let funcs = imports.funcs.into_iter().map(Imported::Func);
         let tables = imports.tables.into_iter().map(Imported::Table);
         let memories = imports.memories.into_iter().map(Imported::Memory);
         let globals = imports.globals.into_iter().map(Imported::Global);
+        let tags = imports.tags.into_iter().map(Imported::Tag);
         let items = funcs
             .chain(tables)
             .chain(memories)
             .chain(globals)
+            .chain(tags)
             .collect::<Box<[_]>>();
         Self {
             items,
@@ -132,6 +211,7 @@ impl ModuleImports {
             len_globals,
             len_memories,
             len_tables,
+            len_tags,
         }
     }
 }
@@ -149,12 +229,12 @@ impl Module {
 
     /// Returns the [`Engine`] used during creation of the [`Module`].
     pub fn engine(&self) -> &Engine {
-        &self.engine
+        &self.inner.engine
     }
 
     /// Creates a new [`Module`] from the [`ModuleBuilder`].
     fn from_builder(builder: ModuleBuilder) -> Self {
-        Self {
+        Self::from_inner(ModuleInner {
             engine: builder.engine().clone(),
             func_types: builder.func_types.into(),
             imports: ModuleImports::from_builder(builder.imports),
@@ -163,29 +243,74 @@ impl Module {
             memories: builder.memories.into(),
             globals: builder.globals.into(),
             globals_init: builder.globals_init.into(),
+            tags: builder.tags.into(),
             exports: builder.exports,
             start: builder.start,
             compiled_funcs: builder.compiled_funcs.into(),
             element_segments: builder.element_segments.into(),
             data_segments: builder.data_segments.into(),
+            memory_images: Box::default(),
+            func_indices: Arc::default(),
+            names: Arc::new(ModuleNames::from_custom_sections(&builder.custom_sections)),
+            #[cfg(feature = "dwarf")]
+            dwarf: DwarfInfo::from_custom_sections(&builder.custom_sections, builder.code_offset)
+                .map(Arc::new),
+            code_offset: builder.code_offset,
+            custom_sections: builder.custom_sections.into(),
+        })
+    }
+
+    /// Creates a new [`Module`] from its [`ModuleInner`] contents.
+    ///
+    /// This also creates the [`MemoryImage`]s of all internal linear memories
+    /// and the indices of all internal functions by their [`CompiledFunc`].
+    fn from_inner(mut inner: ModuleInner) -> Self {
+        inner.memory_images = MemoryImage::build_all(&inner);
+        let len_imported_funcs = inner.imports.len_funcs;
+        inner.func_indices = Arc::new(
+            inner
+                .compiled_funcs
+                .iter()
+                .enumerate()
+                .map(|(n, func_body)| {
+                    let func_index =
+                        u32::try_from(len_imported_funcs + n).unwrap_or_else(|error| {
+                            panic!("encountered out of bounds function index: {error}")
+                        });
+                    (*func_body, func_index)
+                })
+                .collect(),
+        );
+        Self {
+            idx: ModuleIdx::new(),
+            inner: Arc::new(inner),
         }
     }
 
+    /// Returns the unique [`ModuleIdx`] of the [`Module`].
+    pub(crate) fn idx(&self) -> ModuleIdx {
+        self.idx
+    }
+
     /// Returns the number of non-imported functions of the [`Module`].
     pub(crate) fn len_funcs(&self) -> usize {
-        self.funcs.len()
+        self.inner.funcs.len()
     }
     /// Returns the number of non-imported tables of the [`Module`].
     pub(crate) fn len_tables(&self) -> usize {
-        self.tables.len()
+        self.inner.tables.len()
     }
     /// Returns the number of non-imported linear memories of the [`Module`].
     pub(crate) fn len_memories(&self) -> usize {
-        self.memories.len()
+        self.inner.memories.len()
     }
     /// Returns the number of non-imported global variables of the [`Module`].
     pub(crate) fn len_globals(&self) -> usize {
-        self.memories.len()
+        self.inner.memories.len()
+    }
+    /// Returns the number of non-imported tags of the [`Module`].
+    pub(crate) fn len_tags(&self) -> usize {
+        self.inner.tags.len() - self.inner.imports.len_tags
     }
 
     /// Returns a slice to the function types of the [`Module`].
@@ -194,20 +319,50 @@ impl Module {
     ///
     /// The slice is stored in a `Arc` so that this operation is very cheap.
     pub(crate) fn func_types_cloned(&self) -> Arc<[DedupFuncType]> {
-        self.func_types.clone()
+        self.inner.func_types.clone()
+    }
+
+    /// Returns the debug names of the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// The names are stored in a `Arc` so that this operation is very cheap.
+    pub(crate) fn names_cloned(&self) -> Arc<ModuleNames> {
+        self.inner.names.clone()
+    }
+
+    /// Returns the indices of the internal functions of the [`Module`] by their [`CompiledFunc`].
+    ///
+    /// # Note
+    ///
+    /// The indices are stored in a `Arc` so that this operation is very cheap.
+    pub(crate) fn func_indices_cloned(&self) -> Arc<BTreeMap<CompiledFunc, u32>> {
+        self.inner.func_indices.clone()
+    }
+
+    /// Returns the DWARF debug information of the [`Module`] if any.
+    ///
+    /// # Note
+    ///
+    /// The debug information is stored in a `Arc` so that this operation is very cheap.
+    #[cfg(feature = "dwarf")]
+    pub(crate) fn dwarf_cloned(&self) -> Option<Arc<DwarfInfo>> {
+        self.inner.dwarf.clone()
     }
 
     /// Returns an iterator over the imports of the [`Module`].
     pub fn imports(&self) -> ModuleImportsIter {
-        let len_imported_funcs = self.imports.len_funcs;
-        let len_imported_globals = self.imports.len_globals;
+        let len_imported_funcs = self.inner.imports.len_funcs;
+        let len_imported_globals = self.inner.imports.len_globals;
+        let len_imported_tags = self.inner.imports.len_tags;
         ModuleImportsIter {
-            engine: &self.engine,
-            names: self.imports.items.iter(),
-            funcs: self.funcs[..len_imported_funcs].iter(),
-            tables: self.tables.iter(),
-            memories: self.memories.iter(),
-            globals: self.globals[..len_imported_globals].iter(),
+            engine: &self.inner.engine,
+            names: self.inner.imports.items.iter(),
+            funcs: self.inner.funcs[..len_imported_funcs].iter(),
+            tables: self.inner.tables.iter(),
+            memories: self.inner.memories.iter(),
+            globals: self.inner.globals[..len_imported_globals].iter(),
+            tags: self.inner.tags[..len_imported_tags].iter(),
         }
     }
 
@@ -215,12 +370,12 @@ impl Module {
     ///
     /// [`Func`]: [`crate::Func`]
     pub(crate) fn internal_funcs(&self) -> InternalFuncsIter {
-        let len_imported = self.imports.len_funcs;
+        let len_imported = self.inner.imports.len_funcs;
         // We skip the first `len_imported` elements in `funcs`
         // since they refer to imported and not internally defined
         // functions.
-        let funcs = &self.funcs[len_imported..];
-        let compiled_funcs = &self.compiled_funcs[..];
+        let funcs = &self.inner.funcs[len_imported..];
+        let compiled_funcs = &self.inner.compiled_funcs[..];
         assert_eq!(funcs.len(), compiled_funcs.len());
         InternalFuncsIter {
             iter: funcs.iter().zip(compiled_funcs),
@@ -229,42 +384,102 @@ impl Module {
 
     /// Returns an iterator over the [`MemoryType`] of internal linear memories.
     fn internal_memories(&self) -> SliceIter<MemoryType> {
-        let len_imported = self.imports.len_memories;
+        let len_imported = self.inner.imports.len_memories;
         // We skip the first `len_imported` elements in `memories`
         // since they refer to imported and not internally defined
         // linear memories.
-        let memories = &self.memories[len_imported..];
+        let memories = &self.inner.memories[len_imported..];
         memories.iter()
     }
 
     /// Returns an iterator over the [`TableType`] of internal tables.
     fn internal_tables(&self) -> SliceIter<TableType> {
-        let len_imported = self.imports.len_tables;
+        let len_imported = self.inner.imports.len_tables;
         // We skip the first `len_imported` elements in `memories`
         // since they refer to imported and not internally defined
         // linear memories.
-        let tables = &self.tables[len_imported..];
+        let tables = &self.inner.tables[len_imported..];
         tables.iter()
     }
 
     /// Returns an iterator over the internally defined [`Global`].
     fn internal_globals(&self) -> InternalGlobalsIter {
-        let len_imported = self.imports.len_globals;
+        let len_imported = self.inner.imports.len_globals;
         // We skip the first `len_imported` elements in `globals`
         // since they refer to imported and not internally defined
         // global variables.
-        let globals = self.globals[len_imported..].iter();
-        let global_inits = self.globals_init.iter();
+        let globals = self.inner.globals[len_imported..].iter();
+        let global_inits = self.inner.globals_init.iter();
         InternalGlobalsIter {
             iter: globals.zip(global_inits),
         }
     }
 
+    /// Returns an iterator over the [`TagType`] of internal tags.
+    fn internal_tags(&self) -> impl Iterator<Item = TagType> + '_ {
+        let len_imported = self.inner.imports.len_tags;
+        // We skip the first `len_imported` elements in `tags`
+        // since they refer to imported and not internally defined tags.
+        self.inner.tags[len_imported..]
+            .iter()
+            .map(|dedup| self.tag_type(dedup))
+    }
+
+    /// Returns the [`TagType`] of a tag given the [`DedupFuncType`] of its declaration.
+    fn tag_type(&self, dedup: &DedupFuncType) -> TagType {
+        self.inner.engine.resolve_func_type(dedup, |func_type| {
+            TagType::new(func_type.params().iter().copied())
+        })
+    }
+
     /// Returns an iterator over the exports of the [`Module`].
     pub fn exports(&self) -> ModuleExportsIter {
         ModuleExportsIter::new(self)
     }
 
+    /// Returns an iterator over the contents of all custom sections of the [`Module`] named `name`.
+    ///
+    /// The custom sections are yielded in the order of their appearance in the Wasm binary.
+    pub fn custom_sections<'a>(&'a self, name: &'a str) -> CustomSectionsIter<'a> {
+        CustomSectionsIter::new(&self.inner.custom_sections, name)
+    }
+
+    /// Returns the debug names of the [`Module`] found in its `name` custom section.
+    ///
+    /// # Note
+    ///
+    /// The returned [`ModuleNames`] are empty if the [`Module`] has no `name` custom section.
+    pub fn names(&self) -> &ModuleNames {
+        &self.inner.names
+    }
+
+    /// Returns the name of the [`Module`] found in its `name` custom section if any.
+    pub fn name(&self) -> Option<&str> {
+        self.inner.names.module()
+    }
+
+    /// Resolves the [`SourceLocation`] of the Wasm operator at `wasm_offset` of the Wasm binary.
+    ///
+    /// The source locations are resolved from the DWARF debug information found in the
+    /// `.debug_*` custom sections of the [`Module`] and are ordered from the innermost
+    /// inlined function to the function that contains the Wasm operator.
+    ///
+    /// # Note
+    ///
+    /// Returns an empty list if the [`Module`] has no DWARF debug information
+    /// or if its DWARF debug information does not cover `wasm_offset`.
+    /// The Wasm offsets of the frames of a [`WasmBacktrace`] can be resolved this way.
+    ///
+    /// [`WasmBacktrace`]: crate::core::WasmBacktrace
+    #[cfg(feature = "dwarf")]
+    pub fn source_locations(&self, wasm_offset: usize) -> Vec<SourceLocation> {
+        self.inner
+            .dwarf
+            .as_ref()
+            .map(|dwarf| dwarf.source_locations(wasm_offset))
+            .unwrap_or_default()
+    }
+
     /// Looks up an export in this [`Module`] by its `name`.
     ///
     /// Returns `None` if no export with the name was found.
@@ -273,7 +488,7 @@ impl Module {
     ///
     /// This function will return the type of an export with the given `name`.
     pub fn get_export(&self, name: &str) -> Option<ExternType> {
-        let idx = self.exports.get(name).copied()?;
+        let idx = self.inner.exports.get(name).copied()?;
         let ty = self.get_extern_type(idx);
         Some(ty)
     }
@@ -286,22 +501,26 @@ impl Module {
     fn get_extern_type(&self, idx: ExternIdx) -> ExternType {
         match idx {
             ExternIdx::Func(index) => {
-                let dedup = &self.funcs[index.into_u32() as usize];
-                let func_type = self.engine.resolve_func_type(dedup, Clone::clone);
+                let dedup = &self.inner.funcs[index.into_u32() as usize];
+                let func_type = self.inner.engine.resolve_func_type(dedup, Clone::clone);
                 ExternType::Func(func_type)
             }
             ExternIdx::Table(index) => {
-                let table_type = self.tables[index.into_u32() as usize];
+                let table_type = self.inner.tables[index.into_u32() as usize];
                 ExternType::Table(table_type)
             }
             ExternIdx::Memory(index) => {
-                let memory_type = self.memories[index.into_u32() as usize];
+                let memory_type = self.inner.memories[index.into_u32() as usize];
                 ExternType::Memory(memory_type)
             }
             ExternIdx::Global(index) => {
-                let global_type = self.globals[index.into_u32() as usize];
+                let global_type = self.inner.globals[index.into_u32() as usize];
                 ExternType::Global(global_type)
             }
+            ExternIdx::Tag(index) => {
+                let tag_type = self.tag_type(&self.inner.tags[index.into_u32() as usize]);
+                ExternType::Tag(tag_type)
+            }
         }
     }
 }
@@ -315,6 +534,7 @@ pub struct ModuleImportsIter<'a> {
     tables: SliceIter<'a, TableType>,
     memories: SliceIter<'a, MemoryType>,
     globals: SliceIter<'a, GlobalType>,
+    tags: SliceIter<'a, DedupFuncType>,
 }
 
 impl<'a> Iterator for ModuleImportsIter<'a> {
@@ -349,6 +569,16 @@ impl<'a> Iterator for ModuleImportsIter<'a> {
                     });
                     ImportType::new(name, *global_type)
                 }
+                Imported::Tag(name) => {
+                    let tag_type = self
+                        .tags
+                        .next()
+                        .unwrap_or_else(|| panic!("unexpected missing imported tag for {name:?}"));
+                    let tag_type = self.engine.resolve_func_type(tag_type, |func_type| {
+                        TagType::new(func_type.params().iter().copied())
+                    });
+                    ImportType::new(name, tag_type)
+                }
             },
         };
         Some(import)
@@ -1,5 +1,7 @@
 use super::{
-    compile::translate,
+    builder::LazyModuleResources,
+    compile::{translate, LazyFunc},
+    custom::CustomSection,
     export::ExternIdx,
     global::Global,
     import::{FuncTypeIdx, Import},
@@ -14,18 +16,20 @@ use super::{
 };
 use crate::{
     engine::{CompiledFunc, FuncTranslatorAllocations},
+    CompilationMode,
     Engine,
     FuncType,
     MemoryType,
     TableType,
 };
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use core::{
     mem::{replace, take},
     ops::Range,
 };
 use wasmparser::{
     Chunk,
+    CustomSectionReader,
     DataSectionReader,
     ElementSectionReader,
     Encoding,
@@ -68,6 +72,10 @@ pub struct ModuleParser<'engine> {
     compiled_funcs: u32,
     /// Reusable allocations for validating and translation functions.
     allocations: ReusableAllocations,
+    /// The module resources shared by all lazily translated functions.
+    ///
+    /// This is `None` unless the [`Engine`] translates function bodies lazily.
+    lazy_resources: Option<Arc<LazyModuleResources>>,
 }
 
 /// Reusable heap allocations for function validation and translation.
@@ -89,6 +97,7 @@ impl<'engine> ModuleParser<'engine> {
             parser,
             compiled_funcs: 0,
             allocations: ReusableAllocations::default(),
+            lazy_resources: None,
         }
     }
 
@@ -178,7 +187,7 @@ impl<'engine> ModuleParser<'engine> {
             Payload::ElementSection(section) => self.process_element(section),
             Payload::DataCountSection { count, range } => self.process_data_count(count, range),
             Payload::DataSection(section) => self.process_data(section),
-            Payload::CustomSection { .. } => Ok(()),
+            Payload::CustomSection(section) => self.process_custom(section),
             Payload::CodeSectionStart { count, range, .. } => self.process_code_start(count, range),
             Payload::CodeSectionEntry(func_body) => self.process_code_entry(func_body),
             Payload::UnknownSection { id, range, .. } => self.process_unknown(id, range),
@@ -352,10 +361,20 @@ impl<'engine> ModuleParser<'engine> {
     ///
     /// # Note
     ///
-    /// This is part of the module linking Wasm proposal and not yet supported
-    /// by `wasmi`.
+    /// This extracts all tag declarations into the [`Module`] under construction.
+    /// Tags are part of the Wasm `exception-handling` proposal.
+    ///
+    /// # Errors
+    ///
+    /// If a tag declaration fails to validate.
     fn process_tags(&mut self, section: wasmparser::TagSectionReader) -> Result<(), ModuleError> {
-        self.validator.tag_section(&section).map_err(Into::into)
+        self.validator.tag_section(&section)?;
+        let tags = section.into_iter().map(|tag| {
+            tag.map(|tag| FuncTypeIdx::from(tag.func_type_idx))
+                .map_err(ModuleError::from)
+        });
+        self.builder.push_tags(tags)?;
+        Ok(())
     }
 
     /// Process module global variable declarations.
@@ -473,6 +492,10 @@ impl<'engine> ModuleParser<'engine> {
     /// If the code start section fails to validate.
     fn process_code_start(&mut self, count: u32, range: Range<usize>) -> Result<(), ModuleError> {
         self.validator.code_section_start(count, &range)?;
+        self.builder.set_code_offset(range.start);
+        if self.compilation_mode() != CompilationMode::Eager {
+            self.lazy_resources = Some(Arc::new(LazyModuleResources::new(&self.builder)));
+        }
         Ok(())
     }
 
@@ -495,7 +518,8 @@ impl<'engine> ModuleParser<'engine> {
     ///
     /// This contains the local variables and Wasm instructions of
     /// a single function body.
-    /// This procedure is translating the Wasm bytecode into `wasmi` bytecode.
+    /// This procedure is translating the Wasm bytecode into `wasmi` bytecode
+    /// unless the translation is deferred by the [`CompilationMode`].
     ///
     /// # Errors
     ///
@@ -503,20 +527,54 @@ impl<'engine> ModuleParser<'engine> {
     fn process_code_entry(&mut self, func_body: FunctionBody) -> Result<(), ModuleError> {
         let (func, compiled_func) = self.next_func();
         let validator = self.validator.code_section_entry(&func_body)?;
-        let module_resources = ModuleResources::new(&self.builder);
-        let allocations = take(&mut self.allocations);
-        let allocations = translate(
-            func,
-            compiled_func,
-            func_body,
-            validator.into_validator(allocations.validation),
-            module_resources,
-            allocations.translation,
-        )?;
-        let _ = replace(&mut self.allocations, allocations);
+        let lazy_func = match self.compilation_mode() {
+            CompilationMode::Eager => {
+                let module_resources = ModuleResources::new(&self.builder);
+                let allocations = take(&mut self.allocations);
+                let allocations = translate(
+                    func,
+                    compiled_func,
+                    func_body,
+                    Some(validator.into_validator(allocations.validation)),
+                    module_resources,
+                    allocations.translation,
+                )?;
+                let _ = replace(&mut self.allocations, allocations);
+                return Ok(());
+            }
+            CompilationMode::LazyTranslation => {
+                let allocations = take(&mut self.allocations.validation);
+                let mut validator = validator.into_validator(allocations);
+                validator.validate(&func_body)?;
+                self.allocations.validation = validator.into_allocations();
+                LazyFunc::new(func, func_body, self.lazy_resources(), None)?
+            }
+            CompilationMode::Lazy => {
+                LazyFunc::new(func, func_body, self.lazy_resources(), Some(validator))?
+            }
+        };
+        self.builder
+            .engine()
+            .init_lazy_func(compiled_func, lazy_func);
         Ok(())
     }
 
+    /// Returns the [`CompilationMode`] of the [`Engine`].
+    fn compilation_mode(&self) -> CompilationMode {
+        self.builder.engine().config().get_compilation_mode()
+    }
+
+    /// Returns the module resources shared by all lazily translated functions.
+    ///
+    /// # Panics
+    ///
+    /// If the code section has not yet been started.
+    fn lazy_resources(&self) -> Arc<LazyModuleResources> {
+        self.lazy_resources
+            .clone()
+            .unwrap_or_else(|| panic!("missing lazy module resources"))
+    }
+
     /// Process the entries for the Wasm component model proposal.
     fn process_unsupported_component_model(
         &mut self,
@@ -528,6 +586,18 @@ impl<'engine> ModuleParser<'engine> {
         )
     }
 
+    /// Process a custom section.
+    ///
+    /// # Note
+    ///
+    /// This retains the custom section in the [`Module`] under construction.
+    /// Custom sections are not validated since they must not affect the validity of a module.
+    fn process_custom(&mut self, section: CustomSectionReader) -> Result<(), ModuleError> {
+        let section = CustomSection::new(section.name(), section.data());
+        self.builder.push_custom_section(section);
+        Ok(())
+    }
+
     /// Process an unknown Wasm module section.
     ///
     /// # Note
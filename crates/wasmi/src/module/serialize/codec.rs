@@ -0,0 +1,252 @@
+//! The low-level binary encoding of serialized [`Module`] data.
+//!
+//! All integers are encoded in little-endian byte order using their full width.
+//! Lengths are encoded as `u32` values followed by their items.
+//!
+//! [`Module`]: crate::Module
+
+use super::SerializationError;
+use alloc::vec::Vec;
+use core::str;
+use wasmi_core::{UntypedValue, ValueType};
+
+/// Encodes values into the binary format of serialized [`Module`] data.
+///
+/// [`Module`]: crate::Module
+#[derive(Debug, Default)]
+pub struct Encoder {
+    /// The encoded bytes.
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    /// Returns the encoded bytes.
+    pub fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Encodes a `u8` value.
+    pub fn write_u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    /// Encodes a `u16` value.
+    pub fn write_u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Encodes a `u32` value.
+    pub fn write_u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Encodes a `u64` value.
+    pub fn write_u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Encodes a `u128` value.
+    pub fn write_u128(&mut self, value: u128) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Encodes a `bool` value.
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_u8(u8::from(value));
+    }
+
+    /// Encodes a length or index value.
+    ///
+    /// # Panics
+    ///
+    /// If `value` does not fit into a `u32`.
+    pub fn write_len(&mut self, value: usize) {
+        let value = u32::try_from(value)
+            .unwrap_or_else(|_| panic!("out of bounds length for serialization: {value}"));
+        self.write_u32(value);
+    }
+
+    /// Encodes a length prefixed sequence of bytes.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_len(bytes.len());
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Encodes a length prefixed UTF-8 string.
+    pub fn write_str(&mut self, value: &str) {
+        self.write_bytes(value.as_bytes());
+    }
+
+    /// Encodes a [`ValueType`].
+    pub fn write_value_type(&mut self, value: ValueType) {
+        let value = match value {
+            ValueType::I32 => 0,
+            ValueType::I64 => 1,
+            ValueType::F32 => 2,
+            ValueType::F64 => 3,
+            #[cfg(feature = "simd")]
+            ValueType::V128 => 4,
+            ValueType::FuncRef => 5,
+            ValueType::ExternRef => 6,
+        };
+        self.write_u8(value);
+    }
+
+    /// Encodes an [`UntypedValue`].
+    ///
+    /// # Note
+    ///
+    /// All [`UntypedValue`]s are encoded with 128 bits so that the format
+    /// does not depend on whether the `simd` crate feature is enabled.
+    pub fn write_untyped(&mut self, value: UntypedValue) {
+        #[cfg(feature = "simd")]
+        let bits = value.to_bits128();
+        #[cfg(not(feature = "simd"))]
+        let bits = u128::from(value.to_bits());
+        self.write_u128(bits);
+    }
+}
+
+/// Decodes values from the binary format of serialized [`Module`] data.
+///
+/// [`Module`]: crate::Module
+#[derive(Debug)]
+pub struct Decoder<'a> {
+    /// The remaining bytes to decode.
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    /// Creates a new [`Decoder`] for the given `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Ensures that all bytes have been decoded.
+    ///
+    /// # Errors
+    ///
+    /// If there are bytes left to decode.
+    pub fn finish(self) -> Result<(), SerializationError> {
+        if !self.bytes.is_empty() {
+            return Err(SerializationError::TrailingBytes);
+        }
+        Ok(())
+    }
+
+    /// Decodes the next `N` bytes.
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], SerializationError> {
+        let bytes = self.read_raw(N)?;
+        let mut array = [0x00_u8; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+
+    /// Decodes the next `len` bytes.
+    fn read_raw(&mut self, len: usize) -> Result<&'a [u8], SerializationError> {
+        if self.bytes.len() < len {
+            return Err(SerializationError::UnexpectedEnd);
+        }
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+
+    /// Decodes a `u8` value.
+    pub fn read_u8(&mut self) -> Result<u8, SerializationError> {
+        self.read_array().map(u8::from_le_bytes)
+    }
+
+    /// Decodes a `u16` value.
+    pub fn read_u16(&mut self) -> Result<u16, SerializationError> {
+        self.read_array().map(u16::from_le_bytes)
+    }
+
+    /// Decodes a `u32` value.
+    pub fn read_u32(&mut self) -> Result<u32, SerializationError> {
+        self.read_array().map(u32::from_le_bytes)
+    }
+
+    /// Decodes a `u64` value.
+    pub fn read_u64(&mut self) -> Result<u64, SerializationError> {
+        self.read_array().map(u64::from_le_bytes)
+    }
+
+    /// Decodes a `u128` value.
+    pub fn read_u128(&mut self) -> Result<u128, SerializationError> {
+        self.read_array().map(u128::from_le_bytes)
+    }
+
+    /// Decodes a `bool` value.
+    pub fn read_bool(&mut self) -> Result<bool, SerializationError> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(SerializationError::malformed("invalid boolean value")),
+        }
+    }
+
+    /// Decodes a length or index value.
+    pub fn read_len(&mut self) -> Result<usize, SerializationError> {
+        self.read_u32().map(|value| value as usize)
+    }
+
+    /// Decodes the length of a sequence of items.
+    ///
+    /// # Note
+    ///
+    /// Since every item is encoded using at least one byte the length
+    /// is guaranteed to not exceed the amount of remaining bytes.
+    /// This protects against huge allocations for malformed inputs.
+    pub fn read_items_len(&mut self) -> Result<usize, SerializationError> {
+        let len = self.read_len()?;
+        if len > self.bytes.len() {
+            return Err(SerializationError::UnexpectedEnd);
+        }
+        Ok(len)
+    }
+
+    /// Decodes a length prefixed sequence of bytes.
+    pub fn read_bytes(&mut self) -> Result<&'a [u8], SerializationError> {
+        let len = self.read_len()?;
+        self.read_raw(len)
+    }
+
+    /// Decodes a length prefixed UTF-8 string.
+    pub fn read_str(&mut self) -> Result<&'a str, SerializationError> {
+        let bytes = self.read_bytes()?;
+        str::from_utf8(bytes).map_err(|_| SerializationError::malformed("invalid UTF-8 string"))
+    }
+
+    /// Decodes a [`ValueType`].
+    pub fn read_value_type(&mut self) -> Result<ValueType, SerializationError> {
+        let value = match self.read_u8()? {
+            0 => ValueType::I32,
+            1 => ValueType::I64,
+            2 => ValueType::F32,
+            3 => ValueType::F64,
+            #[cfg(feature = "simd")]
+            4 => ValueType::V128,
+            5 => ValueType::FuncRef,
+            6 => ValueType::ExternRef,
+            _ => return Err(SerializationError::malformed("invalid value type")),
+        };
+        Ok(value)
+    }
+
+    /// Decodes an [`UntypedValue`].
+    ///
+    /// # Errors
+    ///
+    /// If the value does not fit into an [`UntypedValue`] without the `simd` crate feature.
+    pub fn read_untyped(&mut self) -> Result<UntypedValue, SerializationError> {
+        let bits = self.read_u128()?;
+        #[cfg(feature = "simd")]
+        let value = UntypedValue::from_bits128(bits);
+        #[cfg(not(feature = "simd"))]
+        let value = u64::try_from(bits)
+            .map(UntypedValue::from)
+            .map_err(|_| SerializationError::malformed("unsupported v128 value"))?;
+        Ok(value)
+    }
+}
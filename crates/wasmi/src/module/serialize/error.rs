@@ -0,0 +1,75 @@
+use core::{fmt, fmt::Display};
+
+/// Errors that may occur upon deserializing a [`Module`].
+///
+/// [`Module`]: crate::Module
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SerializationError {
+    /// The serialized bytes do not start with the expected magic bytes.
+    InvalidMagic,
+    /// The serialized bytes use an unsupported format version.
+    UnsupportedFormat {
+        /// The format version found in the serialized bytes.
+        version: u32,
+    },
+    /// The serialized bytes were produced by another version of `wasmi`.
+    IncompatibleVersion,
+    /// The serialized bytes were produced by an [`Engine`] with an incompatible [`Config`].
+    ///
+    /// [`Engine`]: crate::Engine
+    /// [`Config`]: crate::Config
+    IncompatibleConfig,
+    /// Encountered the end of the serialized bytes unexpectedly.
+    UnexpectedEnd,
+    /// Encountered trailing bytes after the serialized [`Module`].
+    ///
+    /// [`Module`]: crate::Module
+    TrailingBytes,
+    /// Encountered an unknown `wasmi` bytecode opcode.
+    InvalidOpcode {
+        /// The unknown opcode.
+        opcode: u16,
+    },
+    /// Encountered malformed serialized data.
+    Malformed {
+        /// A description of the malformed data.
+        reason: &'static str,
+    },
+}
+
+impl SerializationError {
+    /// Creates a new [`SerializationError::Malformed`] with the given `reason`.
+    pub(crate) fn malformed(reason: &'static str) -> Self {
+        Self::Malformed { reason }
+    }
+}
+
+impl Display for SerializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "not a serialized wasmi module"),
+            Self::UnsupportedFormat { version } => {
+                write!(f, "unsupported serialized module format version: {version}")
+            }
+            Self::IncompatibleVersion => {
+                write!(f, "serialized module was produced by another wasmi version")
+            }
+            Self::IncompatibleConfig => {
+                write!(
+                    f,
+                    "serialized module was produced by an engine with an incompatible config"
+                )
+            }
+            Self::UnexpectedEnd => write!(f, "encountered unexpected end of serialized module"),
+            Self::TrailingBytes => write!(f, "encountered trailing bytes after serialized module"),
+            Self::InvalidOpcode { opcode } => {
+                write!(
+                    f,
+                    "encountered invalid serialized bytecode opcode: {opcode}"
+                )
+            }
+            Self::Malformed { reason } => write!(f, "malformed serialized module: {reason}"),
+        }
+    }
+}
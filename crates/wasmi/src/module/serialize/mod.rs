@@ -0,0 +1,545 @@
+//! Serialization and deserialization of translated Wasm [`Module`]s.
+//!
+//! # Format
+//!
+//! A serialized [`Module`] starts with a header that identifies the format version,
+//! the `wasmi` version and a fingerprint of the translation relevant [`Config`]
+//! settings of the [`Engine`] that translated the [`Module`].
+//! The header is followed by the [`Module`] metadata and the `wasmi` bytecode
+//! of all its internal functions.
+//!
+//! [`Config`]: crate::Config
+
+mod codec;
+mod error;
+
+pub(crate) use self::codec::{Decoder, Encoder};
+pub use self::error::SerializationError;
+#[cfg(feature = "dwarf")]
+use super::DwarfInfo;
+use super::{
+    export::ExternIdx,
+    ConstExpr,
+    CustomSection,
+    DataSegment,
+    ElementSegment,
+    FuncIdx,
+    GlobalIdx,
+    ImportName,
+    Imported,
+    MemoryIdx,
+    Module,
+    ModuleError,
+    ModuleImports,
+    ModuleInner,
+    ModuleNames,
+    TableIdx,
+    TagIdx,
+};
+use crate::{
+    engine::{DedupFuncType, IndexSpaces, ModuleTypes},
+    Engine,
+    Error,
+    FuncType,
+    GlobalType,
+    MemoryType,
+    Mutability,
+    TableType,
+};
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+
+/// The magic bytes at the start of every serialized [`Module`].
+const MAGIC: &[u8] = b"\0wasmi-module";
+
+/// The version of the serialization format.
+///
+/// # Note
+///
+/// This must be bumped whenever the serialization format changes.
+const FORMAT_VERSION: u32 = 1;
+
+/// The version of `wasmi` that produced the serialized [`Module`].
+const WASMI_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+impl Module {
+    /// Serializes the translated [`Module`] into a versioned binary format.
+    ///
+    /// The serialized [`Module`] can be turned back into a [`Module`] via
+    /// [`Module::deserialize`] without parsing, validating and translating
+    /// the original Wasm bytes again.
+    ///
+    /// # Note
+    ///
+    /// The serialized [`Module`] can only be deserialized by the same version
+    /// of `wasmi` using an [`Engine`] with a compatible [`Config`].
+    ///
+    /// Function bodies that have not yet been translated due to the
+    /// [`CompilationMode`] of the [`Engine`] are translated beforehand.
+    ///
+    /// # Errors
+    ///
+    /// If the translation of a lazily translated function body fails.
+    ///
+    /// [`Config`]: crate::Config
+    /// [`CompilationMode`]: crate::CompilationMode
+    pub fn serialize(&self) -> Result<Vec<u8>, Error> {
+        for &func in &self.inner.compiled_funcs[..] {
+            self.inner.engine.translate_func(func)?;
+        }
+        let mut encoder = Encoder::default();
+        encoder.write_bytes(MAGIC);
+        encoder.write_u32(FORMAT_VERSION);
+        encoder.write_str(WASMI_VERSION);
+        encoder.write_u64(self.inner.engine.config().translation_fingerprint());
+        self.serialize_metadata(&mut encoder);
+        self.inner
+            .engine
+            .serialize_funcs(&self.inner.compiled_funcs, &mut encoder);
+        Ok(encoder.finish())
+    }
+
+    /// Deserializes a [`Module`] from `bytes` produced by [`Module::serialize`].
+    ///
+    /// # Errors
+    ///
+    /// - If `bytes` is not a serialized [`Module`].
+    /// - If `bytes` was serialized by another version of `wasmi`.
+    /// - If `bytes` was serialized by an [`Engine`] with a [`Config`] that is
+    ///   incompatible with the [`Config`] of `engine`.
+    /// - If the structure of the serialized [`Module`] is malformed.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` has been produced by [`Module::serialize`]
+    /// and has not been tampered with since.
+    ///
+    /// All indices of the serialized `wasmi` bytecode are bounds checked and its control
+    /// flow is checked to stay within the bounds of its function bodies.
+    /// However, the serialized `wasmi` bytecode is not validated since this would
+    /// defeat the purpose of avoiding the translation of the original Wasm bytes.
+    /// For example, the value stack heights and the types of the operands of the
+    /// serialized `wasmi` bytecode are not verified.
+    /// Executing malformed `wasmi` bytecode is undefined behavior.
+    ///
+    /// Use [`Module::deserialize_checked`] to deserialize untrusted `bytes`.
+    ///
+    /// [`Config`]: crate::Config
+    pub unsafe fn deserialize(engine: &Engine, bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize_impl(engine, bytes, false)
+            .map_err(ModuleError::from)
+            .map_err(Into::into)
+    }
+
+    /// Deserializes a [`Module`] from `bytes` produced by [`Module::serialize`].
+    ///
+    /// In contrast to [`Module::deserialize`] this verifies the serialized `wasmi` bytecode
+    /// so that executing the deserialized [`Module`] never causes undefined behavior.
+    ///
+    /// # Note
+    ///
+    /// Besides the checks of [`Module::deserialize`] this verifies that the operands of all
+    /// instructions, all branch targets, all returns and all calls are consistent with the
+    /// value stack heights of the serialized `wasmi` bytecode and the function types of the
+    /// serialized [`Module`].
+    /// The types of the operands are not verified. Therefore, executing a [`Module`]
+    /// deserialized from tampered `bytes` might still produce wrong results, trap or panic.
+    ///
+    /// # Errors
+    ///
+    /// - If `bytes` is not a serialized [`Module`].
+    /// - If `bytes` was serialized by another version of `wasmi`.
+    /// - If `bytes` was serialized by an [`Engine`] with a [`Config`] that is
+    ///   incompatible with the [`Config`] of `engine`.
+    /// - If the serialized [`Module`] or its `wasmi` bytecode is malformed.
+    ///
+    /// [`Config`]: crate::Config
+    pub fn deserialize_checked(engine: &Engine, bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize_impl(engine, bytes, true)
+            .map_err(ModuleError::from)
+            .map_err(Into::into)
+    }
+
+    /// Deserializes a [`Module`] from `bytes` produced by [`Module::serialize`].
+    ///
+    /// Verifies the value stack heights of the serialized `wasmi` bytecode if `verify` is `true`.
+    ///
+    /// See [`Module::deserialize`] and [`Module::deserialize_checked`] for details.
+    fn deserialize_impl(
+        engine: &Engine,
+        bytes: &[u8],
+        verify: bool,
+    ) -> Result<Self, SerializationError> {
+        let mut decoder = Decoder::new(bytes);
+        match decoder.read_bytes() {
+            Ok(magic) if magic == MAGIC => {}
+            _ => return Err(SerializationError::InvalidMagic),
+        }
+        let version = decoder.read_u32()?;
+        if version != FORMAT_VERSION {
+            return Err(SerializationError::UnsupportedFormat { version });
+        }
+        if decoder.read_str()? != WASMI_VERSION {
+            return Err(SerializationError::IncompatibleVersion);
+        }
+        if decoder.read_u64()? != engine.config().translation_fingerprint() {
+            return Err(SerializationError::IncompatibleConfig);
+        }
+        let module = Self::deserialize_metadata(engine, &mut decoder)?;
+        let spaces = IndexSpaces {
+            funcs: module.inner.funcs.len(),
+            tables: module.inner.tables.len(),
+            memories: module.inner.memories.len(),
+            globals: module.inner.globals.len(),
+            signatures: module.inner.func_types.len(),
+            tags: module.inner.tags.len(),
+            data_segments: module.inner.data_segments.len(),
+            element_segments: module.inner.element_segments.len(),
+        };
+        let types = verify.then(|| ModuleTypes {
+            funcs: &module.inner.funcs,
+            signatures: &module.inner.func_types,
+            tags: &module.inner.tags,
+        });
+        engine.deserialize_funcs(
+            &module.inner.compiled_funcs,
+            &module.inner.funcs[module.inner.imports.len_funcs..],
+            &spaces,
+            types,
+            &mut decoder,
+        )?;
+        decoder.finish()?;
+        Ok(module)
+    }
+
+    /// Encodes the metadata of the [`Module`] using `encoder`.
+    fn serialize_metadata(&self, encoder: &mut Encoder) {
+        encoder.write_len(self.inner.func_types.len());
+        for func_type in &self.inner.func_types[..] {
+            self.inner.engine.resolve_func_type(func_type, |func_type| {
+                serialize_func_type(func_type, encoder)
+            });
+        }
+        encoder.write_len(self.inner.imports.items.len());
+        for imported in &self.inner.imports.items[..] {
+            let (kind, name) = match imported {
+                Imported::Func(name) => (0, name),
+                Imported::Table(name) => (1, name),
+                Imported::Memory(name) => (2, name),
+                Imported::Global(name) => (3, name),
+                Imported::Tag(name) => (4, name),
+            };
+            encoder.write_u8(kind);
+            encoder.write_str(name.module());
+            encoder.write_str(name.name());
+        }
+        self.serialize_func_type_indices(&self.inner.funcs, encoder);
+        encoder.write_len(self.inner.tables.len());
+        for table_type in &self.inner.tables[..] {
+            serialize_table_type(table_type, encoder);
+        }
+        encoder.write_len(self.inner.memories.len());
+        for memory_type in &self.inner.memories[..] {
+            serialize_memory_type(memory_type, encoder);
+        }
+        encoder.write_len(self.inner.globals.len());
+        for global_type in &self.inner.globals[..] {
+            encoder.write_value_type(global_type.content());
+            encoder.write_bool(global_type.mutability().is_mut());
+        }
+        encoder.write_len(self.inner.globals_init.len());
+        for init in &self.inner.globals_init[..] {
+            init.serialize(encoder);
+        }
+        self.serialize_func_type_indices(&self.inner.tags, encoder);
+        encoder.write_len(self.inner.exports.len());
+        for (name, idx) in &self.inner.exports {
+            encoder.write_str(name);
+            let (kind, index) = match idx {
+                ExternIdx::Func(index) => (0, index.into_u32()),
+                ExternIdx::Table(index) => (1, index.into_u32()),
+                ExternIdx::Memory(index) => (2, index.into_u32()),
+                ExternIdx::Global(index) => (3, index.into_u32()),
+                ExternIdx::Tag(index) => (4, index.into_u32()),
+            };
+            encoder.write_u8(kind);
+            encoder.write_u32(index);
+        }
+        match self.inner.start {
+            Some(start) => {
+                encoder.write_bool(true);
+                encoder.write_u32(start.into_u32());
+            }
+            None => encoder.write_bool(false),
+        }
+        encoder.write_len(self.inner.element_segments.len());
+        for segment in &self.inner.element_segments[..] {
+            segment.serialize(encoder);
+        }
+        encoder.write_len(self.inner.data_segments.len());
+        for segment in &self.inner.data_segments[..] {
+            segment.serialize(encoder);
+        }
+        encoder.write_len(self.inner.custom_sections.len());
+        for section in &self.inner.custom_sections[..] {
+            encoder.write_str(section.name());
+            encoder.write_bytes(section.data());
+        }
+        encoder.write_len(self.inner.code_offset);
+    }
+
+    /// Encodes the `dedups` as indices into the function types of the [`Module`].
+    fn serialize_func_type_indices(&self, dedups: &[DedupFuncType], encoder: &mut Encoder) {
+        encoder.write_len(dedups.len());
+        for dedup in dedups {
+            let index = self
+                .inner
+                .func_types
+                .iter()
+                .position(|func_type| func_type == dedup)
+                .unwrap_or_else(|| panic!("missing function type for {dedup:?}"));
+            encoder.write_len(index);
+        }
+    }
+
+    /// Decodes the metadata of a [`Module`] using `decoder`.
+    ///
+    /// # Note
+    ///
+    /// This allocates the function types and compiled functions of the
+    /// [`Module`] in the `engine` but does not initialize the compiled functions.
+    fn deserialize_metadata(
+        engine: &Engine,
+        decoder: &mut Decoder,
+    ) -> Result<Self, SerializationError> {
+        let func_types = deserialize_items(decoder, |decoder| {
+            deserialize_func_type(decoder).map(|func_type| engine.alloc_func_type(func_type))
+        })?;
+        let imported = deserialize_items(decoder, |decoder| {
+            let kind = decoder.read_u8()?;
+            let module = decoder.read_str()?;
+            let field = decoder.read_str()?;
+            let name = ImportName::new(module, field);
+            let imported = match kind {
+                0 => Imported::Func(name),
+                1 => Imported::Table(name),
+                2 => Imported::Memory(name),
+                3 => Imported::Global(name),
+                4 => Imported::Tag(name),
+                _ => return Err(SerializationError::malformed("invalid import kind")),
+            };
+            Ok(imported)
+        })?;
+        let imports = ModuleImports::from_items(imported);
+        let funcs = deserialize_func_type_indices(&func_types, decoder)?;
+        let tables = deserialize_items(decoder, deserialize_table_type)?;
+        let memories = deserialize_items(decoder, deserialize_memory_type)?;
+        let globals = deserialize_items(decoder, |decoder| {
+            let content = decoder.read_value_type()?;
+            let mutability = match decoder.read_bool()? {
+                true => Mutability::Var,
+                false => Mutability::Const,
+            };
+            Ok(GlobalType::new(content, mutability))
+        })?;
+        let globals_init = deserialize_items(decoder, ConstExpr::deserialize)?;
+        let tags = deserialize_func_type_indices(&func_types, decoder)?;
+        let is_valid = |len_imported: usize, len: usize| len_imported <= len;
+        if !is_valid(imports.len_funcs, funcs.len())
+            || !is_valid(imports.len_tables, tables.len())
+            || !is_valid(imports.len_memories, memories.len())
+            || !is_valid(imports.len_globals, globals.len())
+            || !is_valid(imports.len_tags, tags.len())
+            || globals.len() - imports.len_globals != globals_init.len()
+        {
+            return Err(SerializationError::malformed("invalid amount of imports"));
+        }
+        let exports = deserialize_items(decoder, |decoder| {
+            let name = Box::<str>::from(decoder.read_str()?);
+            let kind = decoder.read_u8()?;
+            let index = decoder.read_u32()?;
+            let (idx, len) = match kind {
+                0 => (ExternIdx::Func(FuncIdx::from(index)), funcs.len()),
+                1 => (ExternIdx::Table(TableIdx::from(index)), tables.len()),
+                2 => (ExternIdx::Memory(MemoryIdx::from(index)), memories.len()),
+                3 => (ExternIdx::Global(GlobalIdx::from(index)), globals.len()),
+                4 => (ExternIdx::Tag(TagIdx::from(index)), tags.len()),
+                _ => return Err(SerializationError::malformed("invalid export kind")),
+            };
+            if index as usize >= len {
+                return Err(SerializationError::malformed("invalid export index"));
+            }
+            Ok((name, idx))
+        })?
+        .into_vec()
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+        let start = match decoder.read_bool()? {
+            true => {
+                let start = decoder.read_u32()?;
+                if start as usize >= funcs.len() {
+                    return Err(SerializationError::malformed("invalid start function"));
+                }
+                Some(FuncIdx::from(start))
+            }
+            false => None,
+        };
+        let element_segments = deserialize_items(decoder, ElementSegment::deserialize)?;
+        let data_segments = deserialize_items(decoder, DataSegment::deserialize)?;
+        let custom_sections = deserialize_items(decoder, |decoder| {
+            let name = decoder.read_str()?;
+            let data = decoder.read_bytes()?;
+            Ok(CustomSection::new(name, data))
+        })?;
+        let code_offset = decoder.read_len()?;
+        let compiled_funcs = (imports.len_funcs..funcs.len())
+            .map(|_| engine.alloc_func())
+            .collect();
+        Ok(Self::from_inner(ModuleInner {
+            engine: engine.clone(),
+            func_types: Arc::from(func_types),
+            imports,
+            funcs,
+            tables,
+            memories,
+            globals,
+            globals_init,
+            tags,
+            exports,
+            start,
+            compiled_funcs,
+            element_segments,
+            data_segments,
+            memory_images: Box::default(),
+            func_indices: Arc::default(),
+            names: Arc::new(ModuleNames::from_custom_sections(&custom_sections)),
+            #[cfg(feature = "dwarf")]
+            dwarf: DwarfInfo::from_custom_sections(&custom_sections, code_offset).map(Arc::new),
+            code_offset,
+            custom_sections,
+        }))
+    }
+}
+
+impl ModuleImports {
+    /// Creates a new [`ModuleImports`] from the deserialized `items`.
+    fn from_items(items: Box<[Imported]>) -> Self {
+        let count = |f: fn(&Imported) -> bool| items.iter().filter(|item| f(item)).count();
+        Self {
+            len_funcs: count(|item| matches!(item, Imported::Func(_))),
+            len_globals: count(|item| matches!(item, Imported::Global(_))),
+            len_memories: count(|item| matches!(item, Imported::Memory(_))),
+            len_tables: count(|item| matches!(item, Imported::Table(_))),
+            len_tags: count(|item| matches!(item, Imported::Tag(_))),
+            items,
+        }
+    }
+}
+
+/// Decodes a length prefixed sequence of items using `f`.
+fn deserialize_items<'a, T, F>(
+    decoder: &mut Decoder<'a>,
+    mut f: F,
+) -> Result<Box<[T]>, SerializationError>
+where
+    F: FnMut(&mut Decoder<'a>) -> Result<T, SerializationError>,
+{
+    let len = decoder.read_items_len()?;
+    (0..len).map(|_| f(decoder)).collect()
+}
+
+/// Decodes indices into `func_types` and returns the referenced function types.
+fn deserialize_func_type_indices(
+    func_types: &[DedupFuncType],
+    decoder: &mut Decoder,
+) -> Result<Box<[DedupFuncType]>, SerializationError> {
+    deserialize_items(decoder, |decoder| {
+        func_types
+            .get(decoder.read_len()?)
+            .copied()
+            .ok_or_else(|| SerializationError::malformed("invalid function type index"))
+    })
+}
+
+/// Encodes the [`FuncType`] using `encoder`.
+fn serialize_func_type(func_type: &FuncType, encoder: &mut Encoder) {
+    for types in [func_type.params(), func_type.results()] {
+        encoder.write_len(types.len());
+        for &ty in types {
+            encoder.write_value_type(ty);
+        }
+    }
+}
+
+/// Decodes a [`FuncType`] using `decoder`.
+fn deserialize_func_type(decoder: &mut Decoder) -> Result<FuncType, SerializationError> {
+    let params = deserialize_items(decoder, Decoder::read_value_type)?;
+    let results = deserialize_items(decoder, Decoder::read_value_type)?;
+    Ok(FuncType::new(params.into_vec(), results.into_vec()))
+}
+
+/// Encodes the [`TableType`] using `encoder`.
+fn serialize_table_type(table_type: &TableType, encoder: &mut Encoder) {
+    encoder.write_value_type(table_type.element());
+    encoder.write_u32(table_type.minimum());
+    match table_type.maximum() {
+        Some(maximum) => {
+            encoder.write_bool(true);
+            encoder.write_u32(maximum);
+        }
+        None => encoder.write_bool(false),
+    }
+}
+
+/// Decodes a [`TableType`] using `decoder`.
+fn deserialize_table_type(decoder: &mut Decoder) -> Result<TableType, SerializationError> {
+    let element = decoder.read_value_type()?;
+    let minimum = decoder.read_u32()?;
+    let maximum = match decoder.read_bool()? {
+        true => Some(decoder.read_u32()?),
+        false => None,
+    };
+    if !element.is_ref() || matches!(maximum, Some(maximum) if maximum < minimum) {
+        return Err(SerializationError::malformed("invalid table type"));
+    }
+    Ok(TableType::new(element, minimum, maximum))
+}
+
+/// Encodes the [`MemoryType`] using `encoder`.
+fn serialize_memory_type(memory_type: &MemoryType, encoder: &mut Encoder) {
+    encoder.write_bool(memory_type.is_64());
+    encoder.write_bool(memory_type.is_shared());
+    encoder.write_u64(u64::from(memory_type.initial_pages()));
+    match memory_type.maximum_pages() {
+        Some(maximum) => {
+            encoder.write_bool(true);
+            encoder.write_u64(u64::from(maximum));
+        }
+        None => encoder.write_bool(false),
+    }
+}
+
+/// Decodes a [`MemoryType`] using `decoder`.
+fn deserialize_memory_type(decoder: &mut Decoder) -> Result<MemoryType, SerializationError> {
+    let is_64 = decoder.read_bool()?;
+    let is_shared = decoder.read_bool()?;
+    let initial = decoder.read_u64()?;
+    let maximum = match decoder.read_bool()? {
+        true => Some(decoder.read_u64()?),
+        false => None,
+    };
+    let memory_type = match is_64 {
+        true => MemoryType::new64(initial, maximum).ok(),
+        false => {
+            let initial = u32::try_from(initial).ok();
+            let maximum = maximum.map(u32::try_from).transpose().ok();
+            initial
+                .zip(maximum)
+                .and_then(|(initial, maximum)| MemoryType::new(initial, maximum).ok())
+        }
+    };
+    let memory_type = match (memory_type, is_shared) {
+        (Some(memory_type), true) => memory_type.into_shared().ok(),
+        (memory_type, _) => memory_type,
+    };
+    memory_type.ok_or_else(|| SerializationError::malformed("invalid memory type"))
+}
@@ -0,0 +1,195 @@
+use super::{ConstExpr, DataSegment, DataSegmentKind, MemoryIdx, Module, ModuleInner};
+use crate::{AsContext, Instance, MemoryType, Mutability, Value};
+use alloc::{sync::Arc, vec::Vec};
+use core::{fmt, fmt::Display, ops::Range};
+
+/// The granularity in bytes at which zeroed regions of linear memories are skipped by snapshots.
+const SNAPSHOT_CHUNK_LEN: usize = 0x1000;
+
+/// An error that may occur upon taking a snapshot of an [`Instance`].
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// Encountered when a mutable global variable holds a non-null reference.
+    ///
+    /// # Note
+    ///
+    /// References cannot be represented by the initializer of a global variable.
+    ReferenceGlobal {
+        /// The index of the global variable holding the reference.
+        index: u32,
+    },
+    /// Encountered when an internal linear memory is shared.
+    ///
+    /// # Note
+    ///
+    /// The bytes of shared linear memories might be concurrently modified by other threads.
+    SharedMemory {
+        /// The index of the shared linear memory.
+        index: u32,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotError {}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ReferenceGlobal { index } => {
+                write!(
+                    f,
+                    "cannot snapshot non-null reference of global variable at index {index}"
+                )
+            }
+            Self::SharedMemory { index } => {
+                write!(f, "cannot snapshot shared linear memory at index {index}")
+            }
+        }
+    }
+}
+
+impl Module {
+    /// Creates a new [`Module`] from the current state of the `instance` of this [`Module`].
+    ///
+    /// This allows to run the initialization code of a [`Module`], e.g. its `start` function,
+    /// only once and start all subsequent instantiations from the resulting state instead.
+    ///
+    /// The returned [`Module`] equals this [`Module`] except that:
+    ///
+    /// - Its internal linear memories are initialized with their current contents and sizes.
+    /// - Its internal mutable global variables are initialized with their current values.
+    /// - It has no `start` function.
+    ///
+    /// # Note
+    ///
+    /// - The state of imported items is not part of the snapshot.
+    /// - Tables and passive segments are initialized from this [`Module`] again.
+    ///   Therefore their changes during initialization are not part of the snapshot.
+    /// - The returned [`Module`] shares the compiled functions of this [`Module`].
+    ///
+    /// # Panics
+    ///
+    /// - If `ctx` does not own the `instance`.
+    /// - If `instance` has not been instantiated from this [`Module`].
+    ///
+    /// # Errors
+    ///
+    /// - If an internal mutable global variable holds a non-null reference.
+    /// - If an internal linear memory is shared.
+    pub fn snapshot(
+        &self,
+        ctx: impl AsContext,
+        instance: &Instance,
+    ) -> Result<Module, SnapshotError> {
+        let entity = ctx.as_context().store.inner.resolve_instance(instance);
+        assert!(
+            entity.module_idx() == Some(self.idx()),
+            "the instance has not been instantiated from the module"
+        );
+        let len_imported_globals = self.inner.imports.len_globals;
+        let globals_init = self
+            .internal_globals()
+            .enumerate()
+            .map(|(n, (global_type, global_init))| {
+                if global_type.mutability() == Mutability::Const {
+                    return Ok(global_init.clone());
+                }
+                let index = (len_imported_globals + n) as u32;
+                let global = entity
+                    .get_global(index)
+                    .unwrap_or_else(|| panic!("missing global variable at index {index}"));
+                match global.get(&ctx) {
+                    Value::FuncRef(value) if !value.is_null() => {
+                        Err(SnapshotError::ReferenceGlobal { index })
+                    }
+                    Value::ExternRef(value) if !value.is_null() => {
+                        Err(SnapshotError::ReferenceGlobal { index })
+                    }
+                    value => Ok(ConstExpr::new_const(value)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let len_imported_memories = self.inner.imports.len_memories;
+        let mut memories = self.inner.memories.to_vec();
+        // The active data segments of internal linear memories are replaced by empty
+        // segments so that the indices of all other data segments stay the same.
+        let mut data_segments = self
+            .inner
+            .data_segments
+            .iter()
+            .map(|segment| match segment.kind() {
+                DataSegmentKind::Active(active)
+                    if active.memory_index().into_u32() as usize >= len_imported_memories =>
+                {
+                    let memory_index = active.memory_index();
+                    let memory_type = self.inner.memories[memory_index.into_u32() as usize];
+                    DataSegment::new_active(memory_index, offset_expr(memory_type, 0), Arc::new([]))
+                }
+                _ => segment.clone(),
+            })
+            .collect::<Vec<_>>();
+        for (index, memory_type) in memories.iter_mut().enumerate().skip(len_imported_memories) {
+            let memory = entity
+                .get_memory(index as u32)
+                .unwrap_or_else(|| panic!("missing linear memory at index {index}"));
+            if memory_type.is_shared() {
+                return Err(SnapshotError::SharedMemory {
+                    index: index as u32,
+                });
+            }
+            *memory_type = memory.dynamic_ty(&ctx);
+            let bytes = memory.data(&ctx);
+            for range in non_zero_ranges(bytes) {
+                data_segments.push(DataSegment::new_active(
+                    MemoryIdx::from(index as u32),
+                    offset_expr(*memory_type, range.start as u64),
+                    bytes[range].into(),
+                ));
+            }
+        }
+        Ok(Self::from_inner(ModuleInner {
+            memories: memories.into(),
+            globals_init: globals_init.into(),
+            data_segments: data_segments.into(),
+            start: None,
+            ..(*self.inner).clone()
+        }))
+    }
+}
+
+/// Returns the offset [`ConstExpr`] for an active data segment of a linear memory.
+fn offset_expr(memory_type: MemoryType, offset: u64) -> ConstExpr {
+    match memory_type.is_64() {
+        true => ConstExpr::new_const(offset as i64),
+        false => ConstExpr::new_const(offset as u32 as i32),
+    }
+}
+
+/// Returns the ranges of `bytes` that contain non-zero bytes.
+///
+/// # Note
+///
+/// Ranges that are separated by less than [`SNAPSHOT_CHUNK_LEN`] zero bytes may be merged.
+fn non_zero_ranges(bytes: &[u8]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (n, chunk) in bytes.chunks(SNAPSHOT_CHUNK_LEN).enumerate() {
+        if chunk.iter().all(|&byte| byte == 0x00) {
+            continue;
+        }
+        let start = n * SNAPSHOT_CHUNK_LEN;
+        let end = start + chunk.len();
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    for range in &mut ranges {
+        while bytes[range.start] == 0x00 {
+            range.start += 1;
+        }
+        while bytes[range.end - 1] == 0x00 {
+            range.end -= 1;
+        }
+    }
+    ranges
+}
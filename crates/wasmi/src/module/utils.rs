@@ -24,25 +24,26 @@ impl MemoryType {
     /// We do not use the `From` trait here so that this conversion
     /// routine does not become part of the public API of [`MemoryType`].
     pub(crate) fn from_wasmparser(memory_type: wasmparser::MemoryType) -> Self {
-        assert!(
-            !memory_type.memory64,
-            "wasmi does not support the `memory64` Wasm proposal"
-        );
-        assert!(
-            !memory_type.shared,
-            "wasmi does not support the `threads` Wasm proposal"
-        );
-        let initial: u32 = memory_type
-            .initial
-            .try_into()
-            .expect("wasm32 memories must have a valid u32 minimum size");
-        let maximum: Option<u32> = memory_type
-            .maximum
-            .map(TryInto::try_into)
-            .transpose()
-            .expect("wasm32 memories must have a valid u32 maximum size if any");
-        Self::new(initial, maximum)
-            .expect("encountered invalid wasmparser::MemoryType after validation")
+        let ty = match memory_type.memory64 {
+            true => Self::new64(memory_type.initial, memory_type.maximum),
+            false => {
+                let initial: u32 = memory_type
+                    .initial
+                    .try_into()
+                    .expect("wasm32 memories must have a valid u32 minimum size");
+                let maximum: Option<u32> = memory_type
+                    .maximum
+                    .map(TryInto::try_into)
+                    .transpose()
+                    .expect("wasm32 memories must have a valid u32 maximum size if any");
+                Self::new(initial, maximum)
+            }
+        };
+        let ty = match memory_type.shared {
+            true => ty.and_then(Self::into_shared),
+            false => ty,
+        };
+        ty.expect("encountered invalid wasmparser::MemoryType after validation")
     }
 }
 
@@ -114,6 +115,9 @@ impl From<wasmparser::ValType> for WasmiValueType {
             wasmparser::ValType::I64 => Self::from(ValueType::I64),
             wasmparser::ValType::F32 => Self::from(ValueType::F32),
             wasmparser::ValType::F64 => Self::from(ValueType::F64),
+            #[cfg(feature = "simd")]
+            wasmparser::ValType::V128 => Self::from(ValueType::V128),
+            #[cfg(not(feature = "simd"))]
             wasmparser::ValType::V128 => panic!("wasmi does not support the `simd` Wasm proposal"),
             wasmparser::ValType::FuncRef => Self::from(ValueType::FuncRef),
             wasmparser::ValType::ExternRef => Self::from(ValueType::ExternRef),
@@ -0,0 +1,390 @@
+use crate::{
+    core::UntypedValue,
+    memory::{LinearBuffer, MemoryError},
+    LinearMemory,
+    MemoryCreator,
+    MemoryType,
+};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    fmt,
+    sync::atomic::{AtomicU32, Ordering},
+};
+use spin::Mutex;
+
+/// The default maximum number of concurrently pooled instances.
+const DEFAULT_TOTAL_INSTANCES: u32 = 1_000;
+
+/// The default maximum number of concurrently pooled linear memories.
+const DEFAULT_TOTAL_MEMORIES: u32 = 1_000;
+
+/// The default maximum number of concurrently pooled tables.
+const DEFAULT_TOTAL_TABLES: u32 = 1_000;
+
+/// The default maximum size in bytes of pooled linear memories.
+const DEFAULT_MAX_MEMORY_SIZE: usize = 10 * 0x1_0000;
+
+/// The default maximum number of elements of pooled tables.
+const DEFAULT_TABLE_ELEMENTS: u32 = 10_000;
+
+/// Configuration for a [`PoolingAllocator`].
+#[derive(Debug, Copy, Clone)]
+pub struct PoolingAllocationConfig {
+    /// The maximum number of concurrently pooled instances.
+    total_instances: u32,
+    /// The maximum number of concurrently pooled linear memories.
+    total_memories: u32,
+    /// The maximum number of concurrently pooled tables.
+    total_tables: u32,
+    /// The maximum size in bytes of pooled linear memories.
+    max_memory_size: usize,
+    /// The maximum number of elements of pooled tables.
+    table_elements: u32,
+}
+
+impl Default for PoolingAllocationConfig {
+    fn default() -> Self {
+        Self {
+            total_instances: DEFAULT_TOTAL_INSTANCES,
+            total_memories: DEFAULT_TOTAL_MEMORIES,
+            total_tables: DEFAULT_TOTAL_TABLES,
+            max_memory_size: DEFAULT_MAX_MEMORY_SIZE,
+            table_elements: DEFAULT_TABLE_ELEMENTS,
+        }
+    }
+}
+
+impl PoolingAllocationConfig {
+    /// Sets the maximum number of concurrently pooled instances.
+    ///
+    /// Module instantiation fails if this limit is exceeded.
+    ///
+    /// By default this is 1,000.
+    pub fn total_instances(&mut self, limit: u32) -> &mut Self {
+        self.total_instances = limit;
+        self
+    }
+
+    /// Sets the maximum number of concurrently pooled linear memories.
+    ///
+    /// Creating a linear memory fails if this limit is exceeded.
+    ///
+    /// By default this is 1,000.
+    pub fn total_memories(&mut self, limit: u32) -> &mut Self {
+        self.total_memories = limit;
+        self
+    }
+
+    /// Sets the maximum number of concurrently pooled tables.
+    ///
+    /// Creating a table fails if this limit is exceeded.
+    ///
+    /// By default this is 1,000.
+    pub fn total_tables(&mut self, limit: u32) -> &mut Self {
+        self.total_tables = limit;
+        self
+    }
+
+    /// Sets the maximum size in bytes of pooled linear memories.
+    ///
+    /// Creating or growing a linear memory beyond this size fails.
+    ///
+    /// By default this is 10 Wasm pages.
+    pub fn max_memory_size(&mut self, size: usize) -> &mut Self {
+        self.max_memory_size = size;
+        self
+    }
+
+    /// Sets the maximum number of elements of pooled tables.
+    ///
+    /// Creating or growing a table beyond this number of elements fails.
+    ///
+    /// By default this is 10,000.
+    pub fn table_elements(&mut self, limit: u32) -> &mut Self {
+        self.table_elements = limit;
+        self
+    }
+}
+
+/// An allocator that pools the linear memories and tables of instances.
+///
+/// A [`PoolingAllocator`] can be installed into any number of [`Store`]s via
+/// [`Store::pooling_allocator`] and is shared between all of them.
+///
+/// # Note
+///
+/// - Linear memories and tables are allocated with their configured maximum size upon
+///   first use and returned to the pool with zeroed contents when their [`Store`] is dropped.
+///   Subsequently created linear memories and tables reuse them without allocating.
+/// - Pooled linear memories reserve the address space for the configured maximum size
+///   if OS provided virtual memory is available so that they never move when they grow.
+/// - The [`PoolingAllocator`] limits the number of concurrently alive instances,
+///   linear memories and tables across all of its [`Store`]s.
+///
+/// [`Store`]: crate::Store
+/// [`Store::pooling_allocator`]: crate::Store::pooling_allocator
+#[derive(Debug, Clone)]
+pub struct PoolingAllocator {
+    inner: Arc<PoolInner>,
+}
+
+/// The shared state of a [`PoolingAllocator`].
+struct PoolInner {
+    /// The configuration of the [`PoolingAllocator`].
+    config: PoolingAllocationConfig,
+    /// The number of instances currently in use.
+    instances_in_use: AtomicU32,
+    /// The number of linear memories currently in use.
+    memories_in_use: AtomicU32,
+    /// The number of tables currently in use.
+    tables_in_use: AtomicU32,
+    /// The zeroed linear memories available for reuse.
+    memories: Mutex<Vec<LinearBuffer>>,
+    /// The empty table element buffers available for reuse.
+    tables: Mutex<Vec<Vec<UntypedValue>>>,
+}
+
+impl fmt::Debug for PoolInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolInner")
+            .field("config", &self.config)
+            .field("instances_in_use", &self.instances_in_use)
+            .field("memories_in_use", &self.memories_in_use)
+            .field("tables_in_use", &self.tables_in_use)
+            .finish()
+    }
+}
+
+/// The utilization of a [`PoolingAllocator`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PoolMetrics {
+    instances_in_use: u32,
+    memories_in_use: u32,
+    tables_in_use: u32,
+    memories_idle: u32,
+    tables_idle: u32,
+}
+
+impl PoolMetrics {
+    /// Returns the number of instances currently in use.
+    pub fn instances_in_use(&self) -> u32 {
+        self.instances_in_use
+    }
+
+    /// Returns the number of linear memories currently in use.
+    pub fn memories_in_use(&self) -> u32 {
+        self.memories_in_use
+    }
+
+    /// Returns the number of tables currently in use.
+    pub fn tables_in_use(&self) -> u32 {
+        self.tables_in_use
+    }
+
+    /// Returns the number of allocated linear memories that are ready for reuse.
+    pub fn memories_idle(&self) -> u32 {
+        self.memories_idle
+    }
+
+    /// Returns the number of allocated tables that are ready for reuse.
+    pub fn tables_idle(&self) -> u32 {
+        self.tables_idle
+    }
+}
+
+impl PoolingAllocator {
+    /// Creates a new [`PoolingAllocator`] with the given [`PoolingAllocationConfig`].
+    pub fn new(config: &PoolingAllocationConfig) -> Self {
+        Self {
+            inner: Arc::new(PoolInner {
+                config: *config,
+                instances_in_use: AtomicU32::new(0),
+                memories_in_use: AtomicU32::new(0),
+                tables_in_use: AtomicU32::new(0),
+                memories: Mutex::new(Vec::new()),
+                tables: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Returns the [`PoolingAllocationConfig`] of the [`PoolingAllocator`].
+    pub fn config(&self) -> &PoolingAllocationConfig {
+        &self.inner.config
+    }
+
+    /// Returns the current [`PoolMetrics`] of the [`PoolingAllocator`].
+    pub fn metrics(&self) -> PoolMetrics {
+        let len = |len: usize| u32::try_from(len).unwrap_or(u32::MAX);
+        PoolMetrics {
+            instances_in_use: self.inner.instances_in_use.load(Ordering::Acquire),
+            memories_in_use: self.inner.memories_in_use.load(Ordering::Acquire),
+            tables_in_use: self.inner.tables_in_use.load(Ordering::Acquire),
+            memories_idle: len(self.inner.memories.lock().len()),
+            tables_idle: len(self.inner.tables.lock().len()),
+        }
+    }
+
+    /// Acquires a slot for a new instance.
+    ///
+    /// The slot is returned to the pool when the returned [`InstanceSlot`] is dropped.
+    ///
+    /// Returns `None` if the maximum number of concurrent instances is in use.
+    pub(crate) fn acquire_instance(&self) -> Option<InstanceSlot> {
+        acquire(
+            &self.inner.instances_in_use,
+            self.inner.config.total_instances,
+        )?;
+        Some(InstanceSlot {
+            pool: self.inner.clone(),
+        })
+    }
+
+    /// Returns the maximum number of elements of pooled tables.
+    pub(crate) fn table_elements(&self) -> u32 {
+        self.inner.config.table_elements
+    }
+
+    /// Acquires an empty table element buffer.
+    ///
+    /// The buffer must be returned via [`PoolingAllocator::release_table`].
+    ///
+    /// Returns `None` if the maximum number of concurrent tables is in use.
+    pub(crate) fn acquire_table(&self) -> Option<Vec<UntypedValue>> {
+        acquire(&self.inner.tables_in_use, self.inner.config.total_tables)?;
+        let elements = self.inner.tables.lock().pop().unwrap_or_else(|| {
+            let mut elements = Vec::new();
+            elements.reserve_exact(self.table_elements() as usize);
+            elements
+        });
+        Some(elements)
+    }
+
+    /// Returns the table element buffer `elements` to the pool.
+    pub(crate) fn release_table(&self, mut elements: Vec<UntypedValue>) {
+        elements.clear();
+        self.inner.tables.lock().push(elements);
+        self.inner.tables_in_use.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Increments `in_use` if it is below `total`.
+///
+/// Returns `None` if `in_use` has already reached `total`.
+fn acquire(in_use: &AtomicU32, total: u32) -> Option<()> {
+    in_use
+        .fetch_update(Ordering::AcqRel, Ordering::Acquire, |in_use| {
+            (in_use < total).then_some(in_use + 1)
+        })
+        .ok()
+        .map(|_| ())
+}
+
+impl MemoryCreator for PoolingAllocator {
+    fn new_memory(
+        &self,
+        _ty: MemoryType,
+        initial_len: usize,
+        _maximum_len: Option<usize>,
+    ) -> Result<Box<dyn LinearMemory>, MemoryError> {
+        let max_memory_size = self.inner.config.max_memory_size;
+        if initial_len > max_memory_size {
+            return Err(MemoryError::OutOfBoundsAllocation);
+        }
+        acquire(
+            &self.inner.memories_in_use,
+            self.inner.config.total_memories,
+        )
+        .ok_or(MemoryError::TooManyMemories)?;
+        let pooled = self.inner.memories.lock().pop();
+        let bytes = match pooled {
+            Some(bytes) => bytes,
+            None => match LinearBuffer::new(0, Some(max_memory_size)) {
+                Ok(bytes) => bytes,
+                Err(error) => {
+                    self.inner.memories_in_use.fetch_sub(1, Ordering::AcqRel);
+                    return Err(error);
+                }
+            },
+        };
+        let mut memory = PooledMemory {
+            bytes: Some(bytes),
+            pool: self.inner.clone(),
+        };
+        memory.grow(initial_len)?;
+        Ok(Box::new(memory))
+    }
+}
+
+/// A slot of a [`PoolingAllocator`] held by an instance.
+#[derive(Debug)]
+pub struct InstanceSlot {
+    pool: Arc<PoolInner>,
+}
+
+impl Drop for InstanceSlot {
+    fn drop(&mut self) {
+        self.pool.instances_in_use.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// A linear memory borrowed from a [`PoolingAllocator`].
+#[derive(Debug)]
+struct PooledMemory {
+    /// The bytes of the linear memory.
+    ///
+    /// # Note
+    ///
+    /// This is `None` only while the [`PooledMemory`] is dropped.
+    bytes: Option<LinearBuffer>,
+    /// The pool to which the bytes are returned.
+    pool: Arc<PoolInner>,
+}
+
+impl PooledMemory {
+    /// Returns a shared reference to the bytes of the [`PooledMemory`].
+    fn bytes(&self) -> &LinearBuffer {
+        self.bytes
+            .as_ref()
+            .unwrap_or_else(|| panic!("missing bytes of pooled linear memory"))
+    }
+
+    /// Returns an exclusive reference to the bytes of the [`PooledMemory`].
+    fn bytes_mut(&mut self) -> &mut LinearBuffer {
+        self.bytes
+            .as_mut()
+            .unwrap_or_else(|| panic!("missing bytes of pooled linear memory"))
+    }
+}
+
+impl LinearMemory for PooledMemory {
+    fn data(&self) -> &[u8] {
+        self.bytes().data()
+    }
+
+    fn data_mut(&mut self) -> &mut [u8] {
+        self.bytes_mut().data_mut()
+    }
+
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        if new_len > self.pool.config.max_memory_size {
+            return Err(MemoryError::OutOfBoundsAllocation);
+        }
+        self.bytes_mut().grow(new_len)
+    }
+
+    fn reset(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        self.bytes_mut().reset(new_len)
+    }
+}
+
+impl Drop for PooledMemory {
+    fn drop(&mut self) {
+        if let Some(mut bytes) = self.bytes.take() {
+            // Linear memories that cannot be zeroed are not reused.
+            if bytes.reset(0).is_ok() {
+                self.pool.memories.lock().push(bytes);
+            }
+        }
+        self.pool.memories_in_use.fetch_sub(1, Ordering::AcqRel);
+    }
+}
@@ -1,17 +1,24 @@
+#[cfg(feature = "trace")]
+use crate::engine::Tracer;
 use crate::{
+    core::{UntypedValue, ValueType},
     engine::DedupFuncType,
+    errors::InstanceError,
     externref::{ExternObject, ExternObjectEntity, ExternObjectIdx},
     func::{Trampoline, TrampolineEntity, TrampolineIdx},
-    memory::DataSegment,
+    limits::{ResourceLimiterQuery, ResourceLimiterRef},
+    memory::{DataSegment, DefaultMemoryCreator},
     DataSegmentEntity,
     DataSegmentIdx,
     ElementSegment,
     ElementSegmentEntity,
     ElementSegmentIdx,
     Engine,
+    Extern,
     Func,
     FuncEntity,
     FuncIdx,
+    FuncRef,
     FuncType,
     Global,
     GlobalEntity,
@@ -20,11 +27,23 @@ use crate::{
     InstanceEntity,
     InstanceIdx,
     Memory,
+    MemoryCreator,
     MemoryEntity,
     MemoryIdx,
+    PoolingAllocator,
+    ResourceLimiter,
     Table,
     TableEntity,
     TableIdx,
+    Tag,
+    TagEntity,
+    TagIdx,
+};
+#[cfg(feature = "trace")]
+use alloc::boxed::Box;
+use alloc::{
+    collections::{btree_map::Entry, BTreeMap},
+    sync::Arc,
 };
 use core::{
     fmt::{self, Debug},
@@ -81,6 +100,8 @@ pub struct Store<T> {
     trampolines: Arena<TrampolineIdx, TrampolineEntity<T>>,
     /// User provided host data owned by the [`Store`].
     data: T,
+    /// User provided hook to retrieve a [`ResourceLimiter`].
+    limiter: Option<ResourceLimiterQuery<T>>,
 }
 
 /// The inner store that owns all data not associated to the host state.
@@ -98,6 +119,8 @@ pub struct StoreInner {
     tables: Arena<TableIdx, TableEntity>,
     /// Stored global variables.
     globals: Arena<GlobalIdx, GlobalEntity>,
+    /// Stored tags.
+    tags: Arena<TagIdx, TagEntity>,
     /// Stored module instances.
     instances: Arena<InstanceIdx, InstanceEntity>,
     /// Stored data segments.
@@ -108,12 +131,25 @@ pub struct StoreInner {
     ///
     /// [`ExternRef`]: [`crate::ExternRef`]
     extern_objects: Arena<ExternObjectIdx, ExternObjectEntity>,
+    /// The number of [`Instance`]s importing each imported entity.
+    imports: BTreeMap<ImportedIdx, usize>,
+    /// The references to functions held by tables and global variables.
+    func_refs: FuncRefCounts,
     /// The [`Engine`] in use by the [`Store`].
     ///
     /// Amongst others the [`Engine`] stores the Wasm function definitions.
     engine: Engine,
     /// The fuel of the [`Store`].
     fuel: Fuel,
+    /// The epoch deadline of the [`Store`].
+    epoch_deadline: EpochDeadline,
+    /// The custom [`MemoryCreator`] of the [`Store`] if any.
+    memory_creator: Option<Arc<dyn MemoryCreator>>,
+    /// The [`PoolingAllocator`] of the [`Store`] if any.
+    pooling_allocator: Option<PoolingAllocator>,
+    /// The [`Tracer`] of the [`Store`] if any.
+    #[cfg(feature = "trace")]
+    tracer: Option<Box<dyn Tracer>>,
 }
 
 #[test]
@@ -221,6 +257,153 @@ impl Fuel {
     }
 }
 
+/// The behavior of a Wasm execution once the epoch deadline of its [`Store`] has been reached.
+#[derive(Debug, Default, Copy, Clone)]
+pub enum EpochDeadlineBehavior {
+    /// The execution traps with [`TrapCode::EpochDeadlineExceeded`].
+    #[default]
+    Trap,
+    /// The execution yields and the epoch deadline is moved `delta` epochs past the current epoch.
+    ///
+    /// # Note
+    ///
+    /// Only resumable executions such as [`Func::call_resumable`] may yield
+    /// by returning [`ResumableCall::EpochYield`].
+    /// Non-resumable executions such as [`Func::call`] and [`TypedFunc::call`]
+    /// cannot yield and trap with [`TrapCode::EpochDeadlineExceeded`] instead.
+    ///
+    /// [`ResumableCall::EpochYield`]: crate::ResumableCall::EpochYield
+    YieldAndUpdate(u64),
+}
+
+/// The index of an entity of a [`StoreInner`] that can be imported by an [`Instance`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum ImportedIdx {
+    Func(FuncIdx),
+    Table(TableIdx),
+    Memory(MemoryIdx),
+    Global(GlobalIdx),
+    Tag(TagIdx),
+}
+
+/// The number of references to each [`Func`] held by tables and global variables of a [`Store`].
+///
+/// # Note
+///
+/// Every write of a `funcref` table element or global variable must be
+/// recorded so that [`Store::remove_instance`] can tell if the functions
+/// of an [`Instance`] are still referenced without scanning the [`Store`].
+#[derive(Debug, Default)]
+pub struct FuncRefCounts {
+    /// The reference counts indexed by the bits of non-`null` [`FuncRef`] values.
+    counts: BTreeMap<u64, usize>,
+}
+
+impl FuncRefCounts {
+    /// Returns the key of the `funcref` `value` or `None` if it is `null`.
+    fn key(value: UntypedValue) -> Option<u64> {
+        if FuncRef::from(value).is_null() {
+            return None;
+        }
+        Some(u64::from(value))
+    }
+
+    /// Records `n` new references of the `funcref` `value`.
+    pub fn add(&mut self, value: UntypedValue, n: usize) {
+        if n == 0 {
+            return;
+        }
+        if let Some(key) = Self::key(value) {
+            *self.counts.entry(key).or_default() += n;
+        }
+    }
+
+    /// Drops `n` references of the `funcref` `value`.
+    ///
+    /// # Panics
+    ///
+    /// If fewer than `n` references of `value` have been recorded.
+    pub fn sub(&mut self, value: UntypedValue, n: usize) {
+        if n == 0 {
+            return;
+        }
+        let Some(key) = Self::key(value) else { return };
+        let Entry::Occupied(mut entry) = self.counts.entry(key) else {
+            panic!("missing references for funcref: {value:?}")
+        };
+        let count = entry.get_mut();
+        *count = count
+            .checked_sub(n)
+            .unwrap_or_else(|| panic!("too few references for funcref: {value:?}"));
+        if *count == 0 {
+            entry.remove();
+        }
+    }
+
+    /// Replaces a reference of the `funcref` `old` with a reference of the `funcref` `new`.
+    pub fn replace(&mut self, old: UntypedValue, new: UntypedValue) {
+        if u64::from(old) == u64::from(new) {
+            return;
+        }
+        self.sub(old, 1);
+        self.add(new, 1);
+    }
+
+    /// Records a reference for each of the `funcref` `values`.
+    pub fn add_all(&mut self, values: &[UntypedValue]) {
+        for value in values {
+            self.add(*value, 1);
+        }
+    }
+
+    /// Drops a reference for each of the `funcref` `values`.
+    pub fn sub_all(&mut self, values: &[UntypedValue]) {
+        for value in values {
+            self.sub(*value, 1);
+        }
+    }
+
+    /// Drops all references recorded by `other`.
+    fn sub_counts(&mut self, other: &Self) {
+        for (key, count) in &other.counts {
+            self.sub(UntypedValue::from(*key), *count);
+        }
+    }
+
+    /// Returns the number of recorded references to `func`.
+    fn count(&self, func: &Func) -> usize {
+        Self::key(FuncRef::new(*func).into())
+            .and_then(|key| self.counts.get(&key).copied())
+            .unwrap_or(0)
+    }
+}
+
+/// The epoch deadline of a [`Store`] and what happens once it has been reached.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EpochDeadline {
+    /// The epoch at which Wasm executions are interrupted.
+    deadline: u64,
+    /// What happens to Wasm executions once the `deadline` has been reached.
+    behavior: EpochDeadlineBehavior,
+}
+
+impl EpochDeadline {
+    /// Returns the epoch at which Wasm executions are interrupted.
+    pub fn deadline(&self) -> u64 {
+        self.deadline
+    }
+
+    /// Returns the [`EpochDeadlineBehavior`] once the deadline has been reached.
+    pub fn behavior(&self) -> EpochDeadlineBehavior {
+        self.behavior
+    }
+
+    /// Sets the deadline to `delta` epochs past the `current` epoch.
+    pub fn set_deadline(&mut self, current: u64, delta: u64) {
+        self.deadline = current.saturating_add(delta);
+    }
+}
+
 impl StoreInner {
     /// Creates a new [`StoreInner`] for the given [`Engine`].
     pub fn new(engine: &Engine) -> Self {
@@ -231,11 +414,19 @@ impl StoreInner {
             memories: Arena::new(),
             tables: Arena::new(),
             globals: Arena::new(),
+            tags: Arena::new(),
             instances: Arena::new(),
             datas: Arena::new(),
             elems: Arena::new(),
             extern_objects: Arena::new(),
+            imports: BTreeMap::new(),
+            func_refs: FuncRefCounts::default(),
             fuel: Fuel::default(),
+            epoch_deadline: EpochDeadline::default(),
+            memory_creator: None,
+            pooling_allocator: None,
+            #[cfg(feature = "trace")]
+            tracer: None,
         }
     }
 
@@ -244,6 +435,26 @@ impl StoreInner {
         &self.engine
     }
 
+    /// Returns the [`PoolingAllocator`] of the [`Store`] if any.
+    pub fn pooling_allocator(&self) -> Option<&PoolingAllocator> {
+        self.pooling_allocator.as_ref()
+    }
+
+    /// Returns the [`MemoryCreator`] used to create new non-shared linear memories.
+    pub fn memory_creator(&self) -> &dyn MemoryCreator {
+        match &self.memory_creator {
+            Some(creator) => &**creator,
+            None => &DefaultMemoryCreator,
+        }
+    }
+
+    /// Returns an exclusive reference to the [`Tracer`] of the [`Store`] if any.
+    #[cfg(feature = "trace")]
+    #[inline]
+    pub fn tracer_mut(&mut self) -> Option<&mut (dyn Tracer + 'static)> {
+        self.tracer.as_deref_mut()
+    }
+
     /// Returns a shared reference to the [`Fuel`] counters.
     pub fn fuel(&self) -> &Fuel {
         &self.fuel
@@ -254,6 +465,16 @@ impl StoreInner {
         &mut self.fuel
     }
 
+    /// Returns a shared reference to the [`EpochDeadline`].
+    pub fn epoch_deadline(&self) -> &EpochDeadline {
+        &self.epoch_deadline
+    }
+
+    /// Returns an exclusive reference to the [`EpochDeadline`].
+    pub fn epoch_deadline_mut(&mut self) -> &mut EpochDeadline {
+        &mut self.epoch_deadline
+    }
+
     /// Wraps an entitiy `Idx` (index type) as a [`Stored<Idx>`] type.
     ///
     /// # Note
@@ -281,14 +502,41 @@ impl StoreInner {
         })
     }
 
+    /// Returns the number of [`MemoryEntity`] allocated in the [`StoreInner`].
+    pub fn len_memories(&self) -> usize {
+        self.memories.len()
+    }
+
+    /// Returns the number of [`TableEntity`] allocated in the [`StoreInner`].
+    pub fn len_tables(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Returns the number of [`InstanceEntity`] allocated in the [`StoreInner`].
+    pub fn len_instances(&self) -> usize {
+        self.instances.len()
+    }
+
     /// Allocates a new [`GlobalEntity`] and returns a [`Global`] reference to it.
     pub fn alloc_global(&mut self, global: GlobalEntity) -> Global {
+        if matches!(global.ty().content(), ValueType::FuncRef) {
+            self.func_refs.add(global.get_untyped(), 1);
+        }
         let global = self.globals.alloc(global);
         Global::from_inner(self.wrap_stored(global))
     }
 
+    /// Allocates a new [`TagEntity`] and returns a [`Tag`] reference to it.
+    pub fn alloc_tag(&mut self, tag: TagEntity) -> Tag {
+        let tag = self.tags.alloc(tag);
+        Tag::from_inner(self.wrap_stored(tag))
+    }
+
     /// Allocates a new [`TableEntity`] and returns a [`Table`] reference to it.
     pub fn alloc_table(&mut self, table: TableEntity) -> Table {
+        if matches!(table.ty().element(), ValueType::FuncRef) {
+            self.func_refs.add_all(table.elements());
+        }
         let table = self.tables.alloc(table);
         Table::from_inner(self.wrap_stored(table))
     }
@@ -351,6 +599,10 @@ impl StoreInner {
             init.is_initialized(),
             "encountered an uninitialized new instance entity: {init:?}",
         );
+        for import in init.imports() {
+            let idx = self.imported_idx(&import);
+            *self.imports.entry(idx).or_default() += 1;
+        }
         let idx = self.unwrap_stored(instance.as_inner());
         let uninit = self
             .instances
@@ -363,6 +615,141 @@ impl StoreInner {
         *uninit = init;
     }
 
+    /// Returns the [`ImportedIdx`] of the entity referred to by `import`.
+    ///
+    /// # Panics
+    ///
+    /// If the entity does not originate from this [`Store`].
+    fn imported_idx(&self, import: &Extern) -> ImportedIdx {
+        match import {
+            Extern::Func(func) => ImportedIdx::Func(self.unwrap_stored(func.as_inner())),
+            Extern::Table(table) => ImportedIdx::Table(self.unwrap_stored(table.as_inner())),
+            Extern::Memory(memory) => ImportedIdx::Memory(self.unwrap_stored(memory.as_inner())),
+            Extern::Global(global) => ImportedIdx::Global(self.unwrap_stored(global.as_inner())),
+            Extern::Tag(tag) => ImportedIdx::Tag(self.unwrap_stored(tag.as_inner())),
+        }
+    }
+
+    /// Removes the [`Instance`] and all entities exclusively owned by it from the [`StoreInner`].
+    ///
+    /// # Note
+    ///
+    /// Imported entities are not owned by the [`Instance`] and thus not removed.
+    ///
+    /// # Errors
+    ///
+    /// If entities owned by the [`Instance`] are still in use by other entities of the [`StoreInner`].
+    /// In this case nothing is removed.
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Instance`] does not originate from this [`Store`].
+    /// - If the [`Instance`] has already been removed.
+    pub fn remove_instance(&mut self, instance: &Instance) -> Result<(), InstanceError> {
+        let idx = self.unwrap_stored(instance.as_inner());
+        let entity = self
+            .instances
+            .get(idx)
+            .unwrap_or_else(|| panic!("failed to remove already removed instance: {idx:?}"));
+        let owned_refs = self.ensure_unused(entity)?;
+        let entity = self
+            .instances
+            .remove(idx)
+            .unwrap_or_else(|| panic!("failed to remove already removed instance: {idx:?}"));
+        self.func_refs.sub_counts(&owned_refs);
+        for import in entity.imports() {
+            let idx = self.imported_idx(&import);
+            if let Entry::Occupied(mut entry) = self.imports.entry(idx) {
+                *entry.get_mut() -= 1;
+                if *entry.get() == 0 {
+                    entry.remove();
+                }
+            }
+        }
+        for func in entity.owned_funcs() {
+            let idx = self.unwrap_stored(func.as_inner());
+            self.funcs.remove(idx);
+        }
+        for table in entity.owned_tables() {
+            let idx = self.unwrap_stored(table.as_inner());
+            self.tables.remove(idx);
+        }
+        for memory in entity.owned_memories() {
+            let idx = self.unwrap_stored(memory.as_inner());
+            self.memories.remove(idx);
+        }
+        for global in entity.owned_globals() {
+            let idx = self.unwrap_stored(global.as_inner());
+            self.globals.remove(idx);
+        }
+        for tag in entity.owned_tags() {
+            let idx = self.unwrap_stored(tag.as_inner());
+            self.tags.remove(idx);
+        }
+        for segment in entity.data_segments() {
+            let idx = self.unwrap_stored(segment.as_inner());
+            self.datas.remove(idx);
+        }
+        for segment in entity.elem_segments() {
+            let idx = self.unwrap_stored(segment.as_inner());
+            self.elems.remove(idx);
+        }
+        Ok(())
+    }
+
+    /// Returns `Ok` if no entity owned by the [`InstanceEntity`] is in use by other entities.
+    ///
+    /// Upon success returns the references to functions held by the
+    /// tables and global variables owned by the [`InstanceEntity`].
+    ///
+    /// # Note
+    ///
+    /// An owned entity is in use if it is imported by another [`InstanceEntity`]
+    /// or if it is a function that is referenced by a table or global variable
+    /// that is not owned by the [`InstanceEntity`].
+    ///
+    /// This only visits the entities owned by the [`InstanceEntity`] since
+    /// imports and function references are counted as they are created.
+    ///
+    /// # Errors
+    ///
+    /// If an entity owned by the [`InstanceEntity`] is in use.
+    fn ensure_unused(&self, entity: &InstanceEntity) -> Result<FuncRefCounts, InstanceError> {
+        let owned = entity
+            .owned_funcs()
+            .iter()
+            .copied()
+            .map(Extern::Func)
+            .chain(entity.owned_tables().iter().copied().map(Extern::Table))
+            .chain(entity.owned_memories().iter().copied().map(Extern::Memory))
+            .chain(entity.owned_globals().iter().copied().map(Extern::Global))
+            .chain(entity.owned_tags().iter().copied().map(Extern::Tag));
+        for owned in owned {
+            if self.imports.contains_key(&self.imported_idx(&owned)) {
+                return Err(InstanceError::ImportedEntity { entity: owned });
+            }
+        }
+        let mut owned_refs = FuncRefCounts::default();
+        for table in entity.owned_tables() {
+            let table = self.resolve_table(table);
+            if matches!(table.ty().element(), ValueType::FuncRef) {
+                owned_refs.add_all(table.elements());
+            }
+        }
+        for global in entity.owned_globals() {
+            let global = self.resolve_global(global);
+            if matches!(global.ty().content(), ValueType::FuncRef) {
+                owned_refs.add(global.get_untyped(), 1);
+            }
+        }
+        for func in entity.owned_funcs() {
+            if self.func_refs.count(func) > owned_refs.count(func) {
+                return Err(InstanceError::ReferencedFunc { func: *func });
+            }
+        }
+        Ok(owned_refs)
+    }
+
     /// Returns a shared reference to the entity indexed by the given `idx`.
     ///
     /// # Panics
@@ -447,6 +834,44 @@ impl StoreInner {
         Self::resolve_mut(idx, &mut self.globals)
     }
 
+    /// Returns a pair of:
+    ///
+    /// - An exclusive reference to the [`GlobalEntity`] associated to the given [`Global`].
+    /// - An exclusive reference to the [`FuncRefCounts`] of the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// This method exists to properly handle use cases where
+    /// otherwise the Rust borrow-checker would not accept.
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Global`] does not originate from this [`Store`].
+    /// - If the [`Global`] cannot be resolved to its entity.
+    pub fn resolve_global_and_func_refs_mut(
+        &mut self,
+        global: &Global,
+    ) -> (&mut GlobalEntity, &mut FuncRefCounts) {
+        let idx = self.unwrap_stored(global.as_inner());
+        let global = Self::resolve_mut(idx, &mut self.globals);
+        (global, &mut self.func_refs)
+    }
+
+    /// Returns an exclusive reference to the [`FuncRefCounts`] of the [`Store`].
+    pub fn func_refs_mut(&mut self) -> &mut FuncRefCounts {
+        &mut self.func_refs
+    }
+
+    /// Returns a shared reference to the [`TagEntity`] associated to the given [`Tag`].
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Tag`] does not originate from this [`Store`].
+    /// - If the [`Tag`] cannot be resolved to its entity.
+    pub fn resolve_tag(&self, tag: &Tag) -> &TagEntity {
+        self.resolve(tag.as_inner(), &self.tags)
+    }
+
     /// Returns a shared reference to the [`TableEntity`] associated to the given [`Table`].
     ///
     /// # Panics
@@ -457,39 +882,62 @@ impl StoreInner {
         self.resolve(table.as_inner(), &self.tables)
     }
 
-    /// Returns an exclusive reference to the [`TableEntity`] associated to the given [`Table`].
+    /// Returns a pair of:
+    ///
+    /// - An exclusive reference to the [`TableEntity`] associated to the given [`Table`].
+    /// - An exclusive reference to the [`FuncRefCounts`] of the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// This method exists to properly handle use cases where
+    /// otherwise the Rust borrow-checker would not accept.
     ///
     /// # Panics
     ///
     /// - If the [`Table`] does not originate from this [`Store`].
     /// - If the [`Table`] cannot be resolved to its entity.
-    pub fn resolve_table_mut(&mut self, table: &Table) -> &mut TableEntity {
+    pub fn resolve_table_and_func_refs_mut(
+        &mut self,
+        table: &Table,
+    ) -> (&mut TableEntity, &mut FuncRefCounts) {
         let idx = self.unwrap_stored(table.as_inner());
-        Self::resolve_mut(idx, &mut self.tables)
+        let table = Self::resolve_mut(idx, &mut self.tables);
+        (table, &mut self.func_refs)
     }
 
-    /// Returns an exclusive reference to the [`TableEntity`] associated to the given [`Table`].
+    /// Returns a triple of:
+    ///
+    /// - An exclusive reference to the [`TableEntity`] associated to `fst`.
+    /// - An exclusive reference to the [`TableEntity`] associated to `snd`.
+    /// - An exclusive reference to the [`FuncRefCounts`] of the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// This method exists to properly handle use cases where
+    /// otherwise the Rust borrow-checker would not accept.
     ///
     /// # Panics
     ///
-    /// - If the [`Table`] does not originate from this [`Store`].
-    /// - If the [`Table`] cannot be resolved to its entity.
-    pub fn resolve_table_pair_mut(
+    /// - If the [`Table`]s do not originate from this [`Store`].
+    /// - If the [`Table`]s cannot be resolved to their entities.
+    pub fn resolve_table_pair_and_func_refs_mut(
         &mut self,
         fst: &Table,
         snd: &Table,
-    ) -> (&mut TableEntity, &mut TableEntity) {
+    ) -> (&mut TableEntity, &mut TableEntity, &mut FuncRefCounts) {
         let fst = self.unwrap_stored(fst.as_inner());
         let snd = self.unwrap_stored(snd.as_inner());
-        self.tables.get_pair_mut(fst, snd).unwrap_or_else(|| {
+        let (fst, snd) = self.tables.get_pair_mut(fst, snd).unwrap_or_else(|| {
             panic!("failed to resolve stored pair of entities: {fst:?} and {snd:?}")
-        })
+        });
+        (fst, snd, &mut self.func_refs)
     }
 
     /// Returns a triple of:
     ///
     /// - An exclusive reference to the [`TableEntity`] associated to the given [`Table`].
     /// - A shared reference to the [`ElementSegmentEntity`] associated to the given [`ElementSegment`].
+    /// - An exclusive reference to the [`FuncRefCounts`] of the [`Store`].
     ///
     /// # Note
     ///
@@ -506,19 +954,20 @@ impl StoreInner {
         &mut self,
         table: &Table,
         segment: &ElementSegment,
-    ) -> (&mut TableEntity, &ElementSegmentEntity) {
+    ) -> (&mut TableEntity, &ElementSegmentEntity, &mut FuncRefCounts) {
         let table_idx = self.unwrap_stored(table.as_inner());
         let elem_idx = segment.as_inner();
         let elem = self.resolve(elem_idx, &self.elems);
         let table = Self::resolve_mut(table_idx, &mut self.tables);
-        (table, elem)
+        (table, elem, &mut self.func_refs)
     }
 
-    /// Returns a triple of:
+    /// Returns a quadruple of:
     ///
     /// - A shared reference to the [`InstanceEntity`] associated to the given [`Instance`].
     /// - An exclusive reference to the [`TableEntity`] associated to the given [`Table`].
     /// - A shared reference to the [`ElementSegmentEntity`] associated to the given [`ElementSegment`].
+    /// - An exclusive reference to the [`FuncRefCounts`] of the [`Store`].
     ///
     /// # Note
     ///
@@ -538,14 +987,19 @@ impl StoreInner {
         instance: &Instance,
         table: &Table,
         segment: &ElementSegment,
-    ) -> (&InstanceEntity, &mut TableEntity, &ElementSegmentEntity) {
+    ) -> (
+        &InstanceEntity,
+        &mut TableEntity,
+        &ElementSegmentEntity,
+        &mut FuncRefCounts,
+    ) {
         let mem_idx = self.unwrap_stored(table.as_inner());
         let data_idx = segment.as_inner();
         let instance_idx = instance.as_inner();
         let instance = self.resolve(instance_idx, &self.instances);
         let data = self.resolve(data_idx, &self.elems);
         let mem = Self::resolve_mut(mem_idx, &mut self.tables);
-        (instance, mem, data)
+        (instance, mem, data, &mut self.func_refs)
     }
 
     /// Returns a shared reference to the [`ElementSegmentEntity`] associated to the given [`ElementSegment`].
@@ -594,6 +1048,25 @@ impl StoreInner {
         Self::resolve_mut(idx, &mut self.memories)
     }
 
+    /// Returns a pair of exclusive references to the [`MemoryEntity`]s associated to the given [`Memory`]s.
+    ///
+    /// # Panics
+    ///
+    /// - If any of the [`Memory`]s does not originate from this [`Store`].
+    /// - If any of the [`Memory`]s cannot be resolved to its entity.
+    /// - If both [`Memory`]s refer to the same entity.
+    pub fn resolve_memory_pair_mut(
+        &mut self,
+        fst: &Memory,
+        snd: &Memory,
+    ) -> (&mut MemoryEntity, &mut MemoryEntity) {
+        let fst = self.unwrap_stored(fst.as_inner());
+        let snd = self.unwrap_stored(snd.as_inner());
+        self.memories.get_pair_mut(fst, snd).unwrap_or_else(|| {
+            panic!("failed to resolve stored pair of entities: {fst:?} and {snd:?}")
+        })
+    }
+
     /// Returns a pair of:
     ///
     /// - An exclusive reference to the [`MemoryEntity`] associated to the given [`Memory`].
@@ -691,6 +1164,7 @@ impl<T> Store<T> {
             inner: StoreInner::new(engine),
             trampolines: Arena::new(),
             data,
+            limiter: None,
         }
     }
 
@@ -714,6 +1188,110 @@ impl<T> Store<T> {
         self.data
     }
 
+    /// Installs a function into the [`Store`] that will be called with the user
+    /// data type `T` to retrieve a [`ResourceLimiter`] any time a limited,
+    /// growable resource such as a linear memory or table is allocated or grown.
+    ///
+    /// The limiter is also consulted upon module instantiation to limit the
+    /// number of instances, linear memories and tables of the [`Store`].
+    pub fn limiter(
+        &mut self,
+        limiter: impl FnMut(&mut T) -> &mut (dyn ResourceLimiter) + Send + Sync + 'static,
+    ) {
+        self.limiter = Some(ResourceLimiterQuery::new(limiter))
+    }
+
+    /// Installs a [`MemoryCreator`] into the [`Store`] that creates the bytes of
+    /// all non-shared linear memories subsequently created in the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// Without a custom [`MemoryCreator`] the [`Store`] uses OS provided virtual memory
+    /// if available and falls back to heap allocated linear memories otherwise.
+    pub fn memory_creator(&mut self, creator: Arc<dyn MemoryCreator>) {
+        self.inner.memory_creator = Some(creator);
+    }
+
+    /// Installs a [`PoolingAllocator`] into the [`Store`] from which all instances,
+    /// non-shared linear memories and tables subsequently created in the [`Store`]
+    /// are allocated.
+    ///
+    /// # Note
+    ///
+    /// This replaces the [`MemoryCreator`] of the [`Store`] with the [`PoolingAllocator`].
+    pub fn pooling_allocator(&mut self, allocator: PoolingAllocator) {
+        self.inner.memory_creator = Some(Arc::new(allocator.clone()));
+        self.inner.pooling_allocator = Some(allocator);
+    }
+
+    /// Installs a [`Tracer`] into the [`Store`] that is notified about the execution
+    /// of Wasm functions, their instructions and their linear memory accesses.
+    ///
+    /// # Note
+    ///
+    /// This replaces the previously installed [`Tracer`] if any.
+    #[cfg(feature = "trace")]
+    pub fn tracer(&mut self, tracer: impl Tracer + 'static) {
+        self.inner.tracer = Some(Box::new(tracer));
+    }
+
+    /// Removes the [`Tracer`] from the [`Store`] and returns it if any.
+    #[cfg(feature = "trace")]
+    pub fn take_tracer(&mut self) -> Option<Box<dyn Tracer>> {
+        self.inner.tracer.take()
+    }
+
+    /// Removes the [`Instance`] and all entities exclusively owned by it from the [`Store`].
+    ///
+    /// This frees the functions, tables, linear memories, global variables, tags as well as
+    /// data and element segments defined by the [`Instance`] so that long-lived [`Store`]s
+    /// that instantiate many short-lived modules do not grow without bounds.
+    /// Entities imported by the [`Instance`] are not owned by it and stay alive.
+    /// Slots of the [`PoolingAllocator`] held by the [`Instance`] are returned to it.
+    ///
+    /// # Note
+    ///
+    /// The slots of removed entities are reused for new entities, however,
+    /// references to removed entities are still detected as such.
+    /// Using a reference to a removed entity results in a panic. This includes
+    /// exports of the removed [`Instance`] held by the host, for example in a [`Linker`].
+    ///
+    /// Imports and function references are counted as they are created so that
+    /// removal only needs to inspect the entities owned by the [`Instance`].
+    ///
+    /// # Errors
+    ///
+    /// - If another [`Instance`] imports an entity owned by the [`Instance`].
+    /// - If a table or global variable of the [`Store`] refers to a function owned by the [`Instance`].
+    ///
+    /// In both cases the [`Instance`] and its entities stay alive.
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Instance`] does not originate from this [`Store`].
+    /// - If the [`Instance`] has already been removed.
+    ///
+    /// [`Linker`]: crate::Linker
+    pub fn remove_instance(&mut self, instance: Instance) -> Result<(), InstanceError> {
+        self.inner.remove_instance(&instance)
+    }
+
+    /// Returns a pair of the [`StoreInner`] and the optional [`ResourceLimiter`].
+    ///
+    /// # Note
+    ///
+    /// This method exists to properly handle use cases where
+    /// otherwise the Rust borrow-checker would not accept.
+    pub(crate) fn store_inner_and_resource_limiter_ref(
+        &mut self,
+    ) -> (&mut StoreInner, ResourceLimiterRef<'_>) {
+        let resource_limiter = match &mut self.limiter {
+            Some(query) => ResourceLimiterRef::from(query.query(&mut self.data)),
+            None => ResourceLimiterRef::default(),
+        };
+        (&mut self.inner, resource_limiter)
+    }
+
     /// Returns `true` if fuel metering has been enabled.
     fn is_fuel_metering_enabled(&self) -> bool {
         self.engine().config().get_consume_fuel()
@@ -772,6 +1350,50 @@ impl<T> Store<T> {
             .map_err(|_error| FuelError::out_of_fuel())
     }
 
+    /// Sets the epoch deadline of the [`Store`] to `ticks_beyond_current` epochs
+    /// past the current epoch of its [`Engine`].
+    ///
+    /// # Note
+    ///
+    /// - Wasm executions check the epoch deadline at function entries and loop headers
+    ///   and are interrupted once the current epoch of the [`Engine`] has reached it.
+    /// - The epoch deadline of a newly created [`Store`] is the epoch `0` so that
+    ///   Wasm executions are interrupted immediately unless a deadline is set.
+    /// - This has no effect unless epoch interruption has been enabled via
+    ///   [`Config::epoch_interruption`](crate::Config::epoch_interruption).
+    pub fn set_epoch_deadline(&mut self, ticks_beyond_current: u64) {
+        let current = self.engine().current_epoch();
+        self.inner
+            .epoch_deadline
+            .set_deadline(current, ticks_beyond_current);
+    }
+
+    /// Configures Wasm executions to trap once the epoch deadline has been reached.
+    ///
+    /// The trap is a [`TrapCode::EpochDeadlineExceeded`].
+    ///
+    /// # Note
+    ///
+    /// This is the default behavior of a [`Store`].
+    pub fn epoch_deadline_trap(&mut self) {
+        self.inner.epoch_deadline.behavior = EpochDeadlineBehavior::Trap;
+    }
+
+    /// Configures Wasm executions to yield once the epoch deadline has been reached.
+    ///
+    /// Upon yielding the epoch deadline is moved `delta` epochs past the current epoch.
+    ///
+    /// # Note
+    ///
+    /// - Only resumable executions such as [`Func::call_resumable`] yield and return
+    ///   a [`ResumableCall::EpochYield`](crate::ResumableCall::EpochYield) handle that
+    ///   allows to resume the execution at a later point in time.
+    /// - Non-resumable executions such as [`Func::call`] trap with
+    ///   [`TrapCode::EpochDeadlineExceeded`] instead.
+    pub fn epoch_deadline_yield_and_update(&mut self, delta: u64) {
+        self.inner.epoch_deadline.behavior = EpochDeadlineBehavior::YieldAndUpdate(delta);
+    }
+
     /// Allocates a new [`TrampolineEntity`] and returns a [`Trampoline`] reference to it.
     pub(super) fn alloc_trampoline(&mut self, func: TrampolineEntity<T>) -> Trampoline {
         let idx = self.trampolines.alloc(func);
@@ -31,6 +31,14 @@ pub enum TableError {
     },
     /// Occur when coping elements of tables out of bounds.
     CopyOutOfBounds,
+    /// Tried to create more tables than permitted by the [`ResourceLimiter`].
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    TooManyTables,
+    /// The [`ResourceLimiter`] denied the allocation or growth of the table.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    ResourceLimiterDeniedAllocation,
     /// Occurs when `ty` is not a subtype of `other`.
     InvalidSubtype {
         /// The [`TableType`] which is not a subtype of `other`.
@@ -67,6 +75,12 @@ impl Display for TableError {
             Self::CopyOutOfBounds => {
                 write!(f, "out of bounds access of table elements while copying")
             }
+            Self::TooManyTables => {
+                write!(f, "too many tables")
+            }
+            Self::ResourceLimiterDeniedAllocation => {
+                write!(f, "a resource limiter denied to allocate or grow the table")
+            }
             Self::InvalidSubtype { ty, other } => {
                 write!(f, "table type {ty:?} is not a subtype of {other:?}",)
             }
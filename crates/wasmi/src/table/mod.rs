@@ -2,10 +2,18 @@ pub use self::{
     element::{ElementSegment, ElementSegmentEntity, ElementSegmentIdx},
     error::TableError,
 };
-use super::{AsContext, AsContextMut, Stored};
-use crate::{module::FuncIdx, value::WithType, Func, FuncRef, Value};
+use super::{AsContext, AsContextMut, FuncRefCounts, Stored};
+use crate::{
+    module::FuncIdx,
+    value::WithType,
+    Func,
+    FuncRef,
+    PoolingAllocator,
+    ResourceLimiterRef,
+    Value,
+};
 use alloc::vec::Vec;
-use core::cmp::max;
+use core::{cmp::max, mem};
 use wasmi_arena::ArenaIndex;
 use wasmi_core::{TrapCode, UntypedValue, ValueType};
 
@@ -137,6 +145,16 @@ impl TableType {
 pub struct TableEntity {
     ty: TableType,
     elements: Vec<UntypedValue>,
+    /// The [`PoolingAllocator`] from which the `elements` are borrowed if any.
+    pool: Option<PoolingAllocator>,
+}
+
+impl Drop for TableEntity {
+    fn drop(&mut self) {
+        if let Some(pool) = &self.pool {
+            pool.release_table(mem::take(&mut self.elements));
+        }
+    }
 }
 
 impl TableEntity {
@@ -144,11 +162,44 @@ impl TableEntity {
     ///
     /// # Errors
     ///
-    /// If `init` does not match the [`TableType`] element type.
-    pub fn new(ty: TableType, init: Value) -> Result<Self, TableError> {
+    /// - If `init` does not match the [`TableType`] element type.
+    /// - If the [`ResourceLimiter`] denies the allocation of the table.
+    /// - If the `pool` has no table available or its tables are too small.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    pub fn new(
+        ty: TableType,
+        init: Value,
+        limiter: &mut ResourceLimiterRef<'_>,
+        pool: Option<&PoolingAllocator>,
+    ) -> Result<Self, TableError> {
         ty.matches_element_type(init.ty())?;
-        let elements = vec![init.into(); ty.minimum() as usize];
-        Ok(Self { ty, elements })
+        if let Some(limiter) = limiter.as_resource_limiter() {
+            if !limiter.table_growing(0, ty.minimum(), ty.maximum())? {
+                // There is no meaningful way to return `-1` upon creation
+                // so we have to return an appropriate error instead.
+                return Err(TableError::ResourceLimiterDeniedAllocation);
+            }
+        }
+        let mut elements = match pool {
+            Some(pool) => {
+                if ty.minimum() > pool.table_elements() {
+                    return Err(TableError::GrowOutOfBounds {
+                        maximum: pool.table_elements(),
+                        current: 0,
+                        delta: ty.minimum(),
+                    });
+                }
+                pool.acquire_table().ok_or(TableError::TooManyTables)?
+            }
+            None => Vec::new(),
+        };
+        elements.resize(ty.minimum() as usize, init.into());
+        Ok(Self {
+            ty,
+            elements,
+            pool: pool.cloned(),
+        })
     }
 
     /// Returns the resizable limits of the table.
@@ -171,6 +222,23 @@ impl TableEntity {
         self.elements.len() as u32
     }
 
+    /// Returns the untyped elements of the [`Table`].
+    pub fn elements(&self) -> &[UntypedValue] {
+        &self.elements
+    }
+
+    /// Returns the [`FuncRefCounts`] if the [`Table`] holds `funcref` elements.
+    ///
+    /// # Note
+    ///
+    /// Writes to `funcref` tables must be recorded in the [`FuncRefCounts`]
+    /// of the [`Store`] which otherwise are left untouched.
+    ///
+    /// [`Store`]: crate::Store
+    fn func_refs<'a>(&self, func_refs: &'a mut FuncRefCounts) -> Option<&'a mut FuncRefCounts> {
+        matches!(self.ty.element(), ValueType::FuncRef).then_some(func_refs)
+    }
+
     /// Grows the table by the given amount of elements.
     ///
     /// Returns the old size of the [`Table`] upon success.
@@ -183,9 +251,18 @@ impl TableEntity {
     ///
     /// - If the table is grown beyond its maximum limits.
     /// - If `value` does not match the [`Table`] element type.
-    pub fn grow(&mut self, delta: u32, init: Value) -> Result<u32, TableError> {
+    /// - If the [`ResourceLimiter`] denies the growth of the table.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    pub fn grow(
+        &mut self,
+        delta: u32,
+        init: Value,
+        limiter: &mut ResourceLimiterRef<'_>,
+        func_refs: &mut FuncRefCounts,
+    ) -> Result<u32, TableError> {
         self.ty().matches_element_type(init.ty())?;
-        self.grow_untyped(delta, init.into())
+        self.grow_untyped(delta, init.into(), limiter, func_refs)
     }
 
     /// Grows the table by the given amount of elements.
@@ -200,20 +277,54 @@ impl TableEntity {
     ///
     /// # Errors
     ///
-    /// If the table is grown beyond its maximum limits.
-    pub fn grow_untyped(&mut self, delta: u32, init: UntypedValue) -> Result<u32, TableError> {
-        let maximum = self.ty.maximum().unwrap_or(u32::MAX);
+    /// - If the table is grown beyond its maximum limits.
+    /// - If the [`ResourceLimiter`] denies the growth of the table.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    pub fn grow_untyped(
+        &mut self,
+        delta: u32,
+        init: UntypedValue,
+        limiter: &mut ResourceLimiterRef<'_>,
+        func_refs: &mut FuncRefCounts,
+    ) -> Result<u32, TableError> {
+        let mut maximum = self.ty.maximum().unwrap_or(u32::MAX);
+        if let Some(pool) = &self.pool {
+            maximum = maximum.min(pool.table_elements());
+        }
         let current = self.size();
-        let new_len = current
-            .checked_add(delta)
+        let desired = current.checked_add(delta);
+        let out_of_bounds = || TableError::GrowOutOfBounds {
+            maximum,
+            current,
+            delta,
+        };
+        if let Some(limiter) = limiter.as_resource_limiter() {
+            // The resource limiter gets the first look at the growth request.
+            let desired = desired.unwrap_or(u32::MAX);
+            if !limiter.table_growing(current, desired, self.ty.maximum())? {
+                return Err(out_of_bounds());
+            }
+        }
+        let result = desired
             .filter(|&new_len| new_len <= maximum)
-            .ok_or(TableError::GrowOutOfBounds {
-                maximum,
-                current,
-                delta,
-            })? as usize;
-        self.elements.resize(new_len, init);
-        Ok(current)
+            .ok_or_else(out_of_bounds)
+            .map(|new_len| {
+                self.elements.resize(new_len as usize, init);
+                current
+            });
+        if result.is_ok() {
+            if let Some(func_refs) = self.func_refs(func_refs) {
+                func_refs.add(init, delta as usize);
+            }
+        }
+        if let Err(error) = &result {
+            // The resource limiter is notified about failed growth operations.
+            if let Some(limiter) = limiter.as_resource_limiter() {
+                limiter.table_grow_failed(error)
+            }
+        }
+        result
     }
 
     /// Converts the internal [`UntypedValue`] into a [`Value`] for this [`Table`] element type.
@@ -247,9 +358,14 @@ impl TableEntity {
     ///
     /// - If `index` is out of bounds.
     /// - If `value` does not match the [`Table`] element type.
-    pub fn set(&mut self, index: u32, value: Value) -> Result<(), TableError> {
+    pub fn set(
+        &mut self,
+        index: u32,
+        value: Value,
+        func_refs: &mut FuncRefCounts,
+    ) -> Result<(), TableError> {
         self.ty().matches_element_type(value.ty())?;
-        self.set_untyped(index, value.into())
+        self.set_untyped(index, value.into(), func_refs)
     }
 
     /// Returns the [`UntypedValue`] of the [`Table`] at `index`.
@@ -257,8 +373,14 @@ impl TableEntity {
     /// # Errors
     ///
     /// If `index` is out of bounds.
-    pub fn set_untyped(&mut self, index: u32, value: UntypedValue) -> Result<(), TableError> {
+    pub fn set_untyped(
+        &mut self,
+        index: u32,
+        value: UntypedValue,
+        func_refs: &mut FuncRefCounts,
+    ) -> Result<(), TableError> {
         let current = self.size();
+        let func_refs = self.func_refs(func_refs);
         let untyped =
             self.elements
                 .get_mut(index as usize)
@@ -266,7 +388,10 @@ impl TableEntity {
                     current,
                     offset: index,
                 })?;
-        *untyped = value;
+        let old_value = mem::replace(untyped, value);
+        if let Some(func_refs) = func_refs {
+            func_refs.replace(old_value, value);
+        }
         Ok(())
     }
 
@@ -292,6 +417,7 @@ impl TableEntity {
         src_index: u32,
         len: u32,
         get_func: impl Fn(u32) -> Func,
+        func_refs: &mut FuncRefCounts,
     ) -> Result<(), TrapCode> {
         let table_type = self.ty();
         assert!(
@@ -328,7 +454,9 @@ impl TableEntity {
                 // Initialize element interpreted as Wasm `funrefs`.
                 dst_items.iter_mut().zip(src_items).for_each(|(dst, src)| {
                     let func_or_null = src.funcref().map(FuncIdx::into_u32).map(&get_func);
-                    *dst = FuncRef::new(func_or_null).into();
+                    let funcref = FuncRef::new(func_or_null).into();
+                    func_refs.replace(*dst, funcref);
+                    *dst = funcref;
                 });
             }
             ValueType::ExternRef => {
@@ -355,7 +483,9 @@ impl TableEntity {
         src_table: &Self,
         src_index: u32,
         len: u32,
+        func_refs: &mut FuncRefCounts,
     ) -> Result<(), TrapCode> {
+        let func_refs = dst_table.func_refs(func_refs);
         // Turn parameters into proper slice indices.
         let src_index = src_index as usize;
         let dst_index = dst_index as usize;
@@ -372,6 +502,10 @@ impl TableEntity {
             .and_then(|items| items.get(..len))
             .ok_or(TrapCode::TableOutOfBounds)?;
         // Finally, copy elements in-place for the table.
+        if let Some(func_refs) = func_refs {
+            func_refs.sub_all(dst_items);
+            func_refs.add_all(src_items);
+        }
         dst_items.copy_from_slice(src_items);
         Ok(())
     }
@@ -386,6 +520,7 @@ impl TableEntity {
         dst_index: u32,
         src_index: u32,
         len: u32,
+        func_refs: &mut FuncRefCounts,
     ) -> Result<(), TrapCode> {
         // These accesses just perform the bounds checks required by the Wasm spec.
         let max_offset = max(dst_index, src_index);
@@ -398,8 +533,14 @@ impl TableEntity {
         let dst_index = dst_index as usize;
         let len = len as usize;
         // Finally, copy elements in-place for the table.
-        self.elements
-            .copy_within(src_index..src_index.wrapping_add(len), dst_index);
+        let src = src_index..src_index.wrapping_add(len);
+        let dst = dst_index..dst_index.wrapping_add(len);
+        let func_refs = self.func_refs(func_refs);
+        if let Some(func_refs) = func_refs {
+            func_refs.sub_all(&self.elements[dst.clone()]);
+            func_refs.add_all(&self.elements[src.clone()]);
+        }
+        self.elements.copy_within(src, dst_index);
         Ok(())
     }
 
@@ -416,11 +557,17 @@ impl TableEntity {
     /// If `ctx` does not own `dst_table` or `src_table`.
     ///
     /// [`Store`]: [`crate::Store`]
-    pub fn fill(&mut self, dst: u32, val: Value, len: u32) -> Result<(), TrapCode> {
+    pub fn fill(
+        &mut self,
+        dst: u32,
+        val: Value,
+        len: u32,
+        func_refs: &mut FuncRefCounts,
+    ) -> Result<(), TrapCode> {
         self.ty()
             .matches_element_type(val.ty())
             .map_err(|_| TrapCode::BadSignature)?;
-        self.fill_untyped(dst, val.into(), len)
+        self.fill_untyped(dst, val.into(), len, func_refs)
     }
 
     /// Fill `table[dst..(dst + len)]` with the given value.
@@ -438,14 +585,25 @@ impl TableEntity {
     /// If `ctx` does not own `dst_table` or `src_table`.
     ///
     /// [`Store`]: [`crate::Store`]
-    pub fn fill_untyped(&mut self, dst: u32, val: UntypedValue, len: u32) -> Result<(), TrapCode> {
+    pub fn fill_untyped(
+        &mut self,
+        dst: u32,
+        val: UntypedValue,
+        len: u32,
+        func_refs: &mut FuncRefCounts,
+    ) -> Result<(), TrapCode> {
         let dst_index = dst as usize;
         let len = len as usize;
+        let func_refs = self.func_refs(func_refs);
         let dst = self
             .elements
             .get_mut(dst_index..)
             .and_then(|elements| elements.get_mut(..len))
             .ok_or(TrapCode::TableOutOfBounds)?;
+        if let Some(func_refs) = func_refs {
+            func_refs.sub_all(dst);
+            func_refs.add(val, len);
+        }
         dst.fill(val);
         Ok(())
     }
@@ -471,10 +629,23 @@ impl Table {
     ///
     /// # Errors
     ///
-    /// If `init` does not match the [`TableType`] element type.
+    /// - If `init` does not match the [`TableType`] element type.
+    /// - If the [`ResourceLimiter`] of the [`Store`] denies the allocation.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`Store`]: crate::Store
     pub fn new(mut ctx: impl AsContextMut, ty: TableType, init: Value) -> Result<Self, TableError> {
-        let entity = TableEntity::new(ty, init)?;
-        let table = ctx.as_context_mut().store.inner.alloc_table(entity);
+        let (inner, mut limiter) = ctx
+            .as_context_mut()
+            .store
+            .store_inner_and_resource_limiter_ref();
+        if let Some(limiter) = limiter.as_resource_limiter() {
+            if inner.len_tables() >= limiter.tables() {
+                return Err(TableError::TooManyTables);
+            }
+        }
+        let entity = TableEntity::new(ty, init, &mut limiter, inner.pooling_allocator())?;
+        let table = inner.alloc_table(entity);
         Ok(table)
     }
 
@@ -526,21 +697,26 @@ impl Table {
     ///
     /// - If the table is grown beyond its maximum limits.
     /// - If `value` does not match the [`Table`] element type.
+    /// - If the [`ResourceLimiter`] of the [`Store`] denies the growth.
     ///
     /// # Panics
     ///
     /// Panics if `ctx` does not own this [`Table`].
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`Store`]: crate::Store
     pub fn grow(
         &self,
         mut ctx: impl AsContextMut,
         delta: u32,
         init: Value,
     ) -> Result<u32, TableError> {
-        ctx.as_context_mut()
+        let (inner, mut limiter) = ctx
+            .as_context_mut()
             .store
-            .inner
-            .resolve_table_mut(self)
-            .grow(delta, init)
+            .store_inner_and_resource_limiter_ref();
+        let (table, func_refs) = inner.resolve_table_and_func_refs_mut(self);
+        table.grow(delta, init, &mut limiter, func_refs)
     }
 
     /// Returns the [`Table`] element value at `index`.
@@ -570,11 +746,12 @@ impl Table {
         index: u32,
         value: Value,
     ) -> Result<(), TableError> {
-        ctx.as_context_mut()
+        let (table, func_refs) = ctx
+            .as_context_mut()
             .store
             .inner
-            .resolve_table_mut(self)
-            .set(index, value)
+            .resolve_table_and_func_refs_mut(self);
+        table.set(index, value, func_refs)
     }
 
     /// Returns `true` if `lhs` and `rhs` [`Table`] refer to the same entity.
@@ -610,13 +787,13 @@ impl Table {
         if Self::eq(dst_table, src_table) {
             // The `dst_table` and `src_table` are the same table
             // therefore we have to copy within the same table.
-            let table = store
+            let (table, func_refs) = store
                 .as_context_mut()
                 .store
                 .inner
-                .resolve_table_mut(dst_table);
+                .resolve_table_and_func_refs_mut(dst_table);
             table
-                .copy_within(dst_index, src_index, len)
+                .copy_within(dst_index, src_index, len, func_refs)
                 .map_err(|_| TableError::CopyOutOfBounds)
         } else {
             // The `dst_table` and `src_table` are different entities
@@ -624,12 +801,12 @@ impl Table {
             let dst_ty = dst_table.ty(&store);
             let src_ty = src_table.ty(&store).element();
             dst_ty.matches_element_type(src_ty)?;
-            let (dst_table, src_table) = store
+            let (dst_table, src_table, func_refs) = store
                 .as_context_mut()
                 .store
                 .inner
-                .resolve_table_pair_mut(dst_table, src_table);
-            TableEntity::copy(dst_table, dst_index, src_table, src_index, len)
+                .resolve_table_pair_and_func_refs_mut(dst_table, src_table);
+            TableEntity::copy(dst_table, dst_index, src_table, src_index, len, func_refs)
                 .map_err(|_| TableError::CopyOutOfBounds)
         }
     }
@@ -654,10 +831,11 @@ impl Table {
         val: Value,
         len: u32,
     ) -> Result<(), TrapCode> {
-        ctx.as_context_mut()
+        let (table, func_refs) = ctx
+            .as_context_mut()
             .store
             .inner
-            .resolve_table_mut(self)
-            .fill(dst, val, len)
+            .resolve_table_and_func_refs_mut(self);
+        table.fill(dst, val, len, func_refs)
     }
 }
@@ -0,0 +1,251 @@
+use super::{AsContext, AsContextMut, Stored};
+use crate::{core::ValueType, Value};
+use alloc::{boxed::Box, sync::Arc};
+use core::{fmt, fmt::Display};
+use wasmi_arena::ArenaIndex;
+use wasmi_core::HostError;
+
+/// A raw index to a tag entity.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TagIdx(u32);
+
+impl ArenaIndex for TagIdx {
+    fn into_usize(self) -> usize {
+        self.0 as usize
+    }
+
+    fn from_usize(value: usize) -> Self {
+        let value = value
+            .try_into()
+            .unwrap_or_else(|error| panic!("index {value} is out of bounds as tag index: {error}"));
+        Self(value)
+    }
+}
+
+/// An error that may occur upon operating on tags.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TagError {
+    /// Occurs when a tag type does not satisfy the constraints of another.
+    UnsatisfyingTagType {
+        /// The unsatisfying [`TagType`].
+        unsatisfying: TagType,
+        /// The required [`TagType`].
+        required: TagType,
+    },
+    /// Occurs when the values of an [`Exception`] do not match its [`TagType`].
+    ValuesMismatch {
+        /// The [`TagType`] of the [`Exception`].
+        expected: TagType,
+    },
+}
+
+impl Display for TagError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnsatisfyingTagType {
+                unsatisfying,
+                required,
+            } => {
+                write!(
+                    f,
+                    "tag type {unsatisfying:?} does not \
+                    satisfy requirements of {required:?}",
+                )
+            }
+            Self::ValuesMismatch { expected } => {
+                write!(
+                    f,
+                    "exception values do not match the parameters of tag type {expected:?}",
+                )
+            }
+        }
+    }
+}
+
+/// The type of a tag.
+///
+/// # Note
+///
+/// Tags are used by the Wasm `exception-handling` proposal to identify
+/// exceptions and to describe the types of their payload values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagType {
+    /// The value types of the exception payload.
+    params: Arc<[ValueType]>,
+}
+
+impl TagType {
+    /// Creates a new [`TagType`] with the given parameter types.
+    pub fn new<P>(params: P) -> Self
+    where
+        P: IntoIterator<Item = ValueType>,
+    {
+        Self {
+            params: params.into_iter().collect(),
+        }
+    }
+
+    /// Returns the value types of the exception payload.
+    pub fn params(&self) -> &[ValueType] {
+        &self.params
+    }
+
+    /// Checks if `self` satisfies the given `TagType`.
+    ///
+    /// # Errors
+    ///
+    /// If the parameters of the `required` [`TagType`] differ from `self`.
+    pub(crate) fn satisfies(&self, required: &TagType) -> Result<(), TagError> {
+        if self != required {
+            return Err(TagError::UnsatisfyingTagType {
+                unsatisfying: self.clone(),
+                required: required.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A tag entity.
+#[derive(Debug)]
+pub struct TagEntity {
+    /// The type of the tag.
+    ty: TagType,
+}
+
+impl TagEntity {
+    /// Creates a new tag entity with the given [`TagType`].
+    pub fn new(ty: TagType) -> Self {
+        Self { ty }
+    }
+
+    /// Returns the [`TagType`] of the tag.
+    pub fn ty(&self) -> &TagType {
+        &self.ty
+    }
+}
+
+/// A Wasm tag reference.
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct Tag(Stored<TagIdx>);
+
+impl Tag {
+    /// Creates a new stored tag reference.
+    ///
+    /// # Note
+    ///
+    /// This API is primarily used by the [`Store`] itself.
+    ///
+    /// [`Store`]: [`crate::Store`]
+    pub(super) fn from_inner(stored: Stored<TagIdx>) -> Self {
+        Self(stored)
+    }
+
+    /// Returns the underlying stored representation.
+    pub(super) fn as_inner(&self) -> &Stored<TagIdx> {
+        &self.0
+    }
+
+    /// Creates a new tag with the given [`TagType`] to the store.
+    pub fn new(mut ctx: impl AsContextMut, ty: TagType) -> Self {
+        ctx.as_context_mut()
+            .store
+            .inner
+            .alloc_tag(TagEntity::new(ty))
+    }
+
+    /// Returns the [`TagType`] of the tag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Tag`].
+    pub fn ty(&self, ctx: impl AsContext) -> TagType {
+        ctx.as_context().store.inner.resolve_tag(self).ty().clone()
+    }
+
+    /// Returns `true` if `self` and `other` refer to the same tag entity.
+    ///
+    /// # Note
+    ///
+    /// Tags are compared by identity and not by their [`TagType`].
+    pub fn same(&self, other: &Tag) -> bool {
+        self.as_inner() == other.as_inner()
+    }
+}
+
+/// A Wasm exception thrown by `throw` or by a host function.
+///
+/// # Note
+///
+/// - Host functions may throw an [`Exception`] by returning it as error
+///   which can then be caught by Wasm `catch` handlers.
+/// - Exceptions that are not caught by any Wasm handler are returned to
+///   the host as [`Trap`] and can be inspected via [`Trap::downcast_ref`].
+///
+/// [`Trap`]: [`crate::core::Trap`]
+/// [`Trap::downcast_ref`]: [`crate::core::Trap::downcast_ref`]
+#[derive(Debug, Clone)]
+pub struct Exception {
+    /// The tag identifying the exception.
+    tag: Tag,
+    /// The payload values of the exception.
+    values: Box<[Value]>,
+}
+
+impl Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "uncaught Wasm exception with values {:?}", self.values)
+    }
+}
+
+impl HostError for Exception {}
+
+impl Exception {
+    /// Creates a new [`Exception`] for the given [`Tag`] and payload `values`.
+    ///
+    /// # Errors
+    ///
+    /// If the types of `values` do not match the parameters of the [`TagType`] of `tag`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own the [`Tag`].
+    pub fn new<V>(ctx: impl AsContext, tag: Tag, values: V) -> Result<Self, TagError>
+    where
+        V: Into<Box<[Value]>>,
+    {
+        let values = values.into();
+        let ty = tag.ty(ctx);
+        let matches = ty.params().len() == values.len()
+            && ty
+                .params()
+                .iter()
+                .zip(values.iter())
+                .all(|(ty, value)| *ty == value.ty());
+        if !matches {
+            return Err(TagError::ValuesMismatch { expected: ty });
+        }
+        Ok(Self { tag, values })
+    }
+
+    /// Creates a new [`Exception`] without checking its payload.
+    ///
+    /// # Note
+    ///
+    /// Used by the executor which only handles validated Wasm code.
+    pub(crate) fn new_unchecked(tag: Tag, values: Box<[Value]>) -> Self {
+        Self { tag, values }
+    }
+
+    /// Returns the [`Tag`] identifying the [`Exception`].
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Returns the payload values of the [`Exception`].
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+}
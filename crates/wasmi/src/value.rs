@@ -1,4 +1,6 @@
 use crate::{ExternRef, Func, FuncRef};
+#[cfg(feature = "simd")]
+use wasmi_core::V128;
 use wasmi_core::{UntypedValue, ValueType, F32, F64};
 
 /// Untyped instances that allow to be typed.
@@ -19,6 +21,8 @@ impl WithType for UntypedValue {
             ValueType::I64 => Value::I64(self.into()),
             ValueType::F32 => Value::F32(self.into()),
             ValueType::F64 => Value::F64(self.into()),
+            #[cfg(feature = "simd")]
+            ValueType::V128 => Value::V128(self.into()),
             ValueType::FuncRef => Value::FuncRef(self.into()),
             ValueType::ExternRef => Value::ExternRef(self.into()),
         }
@@ -32,6 +36,8 @@ impl From<Value> for UntypedValue {
             Value::I64(value) => value.into(),
             Value::F32(value) => value.into(),
             Value::F64(value) => value.into(),
+            #[cfg(feature = "simd")]
+            Value::V128(value) => value.into(),
             Value::FuncRef(value) => value.into(),
             Value::ExternRef(value) => value.into(),
         }
@@ -42,6 +48,7 @@ impl From<Value> for UntypedValue {
 ///
 /// Wasm code manipulate values of the four basic value types:
 /// integers and floating-point (IEEE 754-2008) data of 32 or 64 bit width each, respectively.
+/// With the `simd` crate feature there additionally are 128-bit vector values.
 ///
 /// There is no distinction between signed and unsigned integer types. Instead, integers are
 /// interpreted by respective operations as either unsigned or signed in two’s complement representation.
@@ -55,6 +62,9 @@ pub enum Value {
     F32(F32),
     /// Value of 64-bit IEEE 754-2008 floating point number.
     F64(F64),
+    /// Value of 128-bit vector of packed integer or floating point lanes.
+    #[cfg(feature = "simd")]
+    V128(V128),
     /// A nullable [`Func`][`crate::Func`] reference, a.k.a. [`FuncRef`].
     FuncRef(FuncRef),
     /// A nullable external object reference, a.k.a. [`ExternRef`].
@@ -70,6 +80,8 @@ impl Value {
             ValueType::I64 => Self::I64(0),
             ValueType::F32 => Self::F32(0f32.into()),
             ValueType::F64 => Self::F64(0f64.into()),
+            #[cfg(feature = "simd")]
+            ValueType::V128 => Self::V128(V128::default()),
             ValueType::FuncRef => Self::from(FuncRef::null()),
             ValueType::ExternRef => Self::from(ExternRef::null()),
         }
@@ -83,6 +95,8 @@ impl Value {
             Self::I64(_) => ValueType::I64,
             Self::F32(_) => ValueType::F32,
             Self::F64(_) => ValueType::F64,
+            #[cfg(feature = "simd")]
+            Self::V128(_) => ValueType::V128,
             Self::FuncRef(_) => ValueType::FuncRef,
             Self::ExternRef(_) => ValueType::ExternRef,
         }
@@ -120,6 +134,15 @@ impl Value {
         }
     }
 
+    /// Returns the underlying `v128` if the type matches otherwise returns `None`.
+    #[cfg(feature = "simd")]
+    pub fn v128(&self) -> Option<V128> {
+        match self {
+            Self::V128(value) => Some(*value),
+            _ => None,
+        }
+    }
+
     /// Returns the underlying `funcref` if the type matches otherwise returns `None`.
     pub fn funcref(&self) -> Option<&FuncRef> {
         match self {
@@ -165,6 +188,14 @@ impl From<F64> for Value {
     }
 }
 
+#[cfg(feature = "simd")]
+impl From<V128> for Value {
+    #[inline]
+    fn from(val: V128) -> Self {
+        Self::V128(val)
+    }
+}
+
 impl From<FuncRef> for Value {
     #[inline]
     fn from(funcref: FuncRef) -> Self {
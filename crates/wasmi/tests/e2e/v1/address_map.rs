@@ -0,0 +1,156 @@
+//! Tests to check if compiled functions map their instructions to Wasm offsets as intended.
+
+use super::{call_outer, instantiate};
+use wasmi::{core::Trap, CompilationMode, Config, Engine, Func, Linker, Module, Store};
+
+/// The Wasm opcode of the `unreachable` operator.
+const UNREACHABLE: u8 = 0x00;
+/// The Wasm opcode of the `call` operator.
+const CALL: u8 = 0x10;
+/// The Wasm opcode of the `i32.const` operator.
+const I32_CONST: u8 = 0x41;
+
+/// Returns the Wasm binary used by the address map tests.
+fn test_wasm() -> Vec<u8> {
+    wat::parse_str(
+        r#"
+        (module
+            (func $outer (export "outer")
+                (call $inner (i32.const 1) (i32.const 2))
+            )
+            (func $inner (param i32 i32)
+                (drop (i32.add (local.get 0) (local.get 1)))
+                (unreachable)
+            )
+        )
+    "#,
+    )
+    .unwrap()
+}
+
+/// Returns the Wasm offsets of the backtrace of the `trap`.
+fn wasm_offsets(trap: &Trap) -> Vec<Option<usize>> {
+    trap.backtrace()
+        .expect("missing backtrace")
+        .frames()
+        .iter()
+        .map(|frame| frame.wasm_offset())
+        .collect()
+}
+
+/// Asserts that the backtrace of the `trap` refers to the trapping `unreachable`
+/// and the `call` operator of the `wasm` binary.
+fn assert_offsets(wasm: &[u8], trap: &Trap) {
+    let offsets = wasm_offsets(trap);
+    assert_eq!(offsets.len(), 2);
+    assert_eq!(wasm[offsets[0].unwrap()], UNREACHABLE);
+    assert_eq!(wasm[offsets[1].unwrap()], CALL);
+}
+
+#[test]
+fn address_map_works() {
+    let wasm = test_wasm();
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let trap = call_outer(&module);
+    assert_offsets(&wasm, &trap);
+    let offset = wasm_offsets(&trap)[0].unwrap();
+    assert!(trap
+        .to_string()
+        .contains(&format!("wasm offset {offset:#x})")));
+}
+
+#[test]
+fn address_map_with_fuel_metering_works() {
+    let wasm = test_wasm();
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    let engine = Engine::new(&config);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    store.add_fuel(1).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let trap = instance
+        .get_typed_func::<(), ()>(&store, "outer")
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap_err();
+    // The fuel metering of the function entry is mapped to the first Wasm operator.
+    let offsets = wasm_offsets(&trap);
+    assert_eq!(offsets.len(), 1);
+    assert_eq!(wasm[offsets[0].unwrap()], I32_CONST);
+}
+
+#[test]
+fn address_map_of_lazy_translation_works() {
+    let wasm = test_wasm();
+    for mode in [CompilationMode::LazyTranslation, CompilationMode::Lazy] {
+        let mut config = Config::default();
+        config.compilation_mode(mode);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        assert_offsets(&wasm, &call_outer(&module));
+    }
+}
+
+#[test]
+fn address_map_survives_serialization() {
+    let wasm = test_wasm();
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let serialized = module.serialize().unwrap();
+    // SAFETY: The serialized module is produced by `Module::serialize`.
+    let module = unsafe { Module::deserialize(&engine, &serialized) }.unwrap();
+    assert_offsets(&wasm, &call_outer(&module));
+}
+
+#[test]
+fn disabled_address_map_works() {
+    let wasm = test_wasm();
+    let mut config = Config::default();
+    config.generate_address_map(false);
+    let engine = Engine::new(&config);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let trap = call_outer(&module);
+    assert_eq!(wasm_offsets(&trap), [None, None]);
+    assert!(!trap.to_string().contains("wasm offset"));
+}
+
+#[test]
+fn wasm_offset_lookup_works() {
+    let wasm = test_wasm();
+    let mut config = Config::default();
+    config.compilation_mode(CompilationMode::Lazy);
+    let (mut store, instance) = instantiate(&config, &wasm);
+    let outer = instance.get_func(&store, "outer").unwrap();
+    // Lazily compiled functions have no address map before their first call.
+    assert_eq!(outer.wasm_offset(&store, 0), None);
+    let trap = outer
+        .typed::<(), ()>(&store)
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap_err();
+    let frames = trap.backtrace().unwrap().frames();
+    let (inner, caller) = (&frames[0], &frames[1]);
+    assert_eq!(
+        instance.wasm_offset(&store, inner.func_index(), inner.instr_offset()),
+        inner.wasm_offset(),
+    );
+    // Callers continue after their call instruction.
+    let call_offset = caller.instr_offset() - 1;
+    assert_eq!(
+        instance.wasm_offset(&store, caller.func_index(), call_offset),
+        caller.wasm_offset(),
+    );
+    assert_eq!(outer.wasm_offset(&store, call_offset), caller.wasm_offset());
+    assert_eq!(wasm[outer.wasm_offset(&store, 0).unwrap()], I32_CONST);
+    // Out of bounds instructions and functions as well as host functions have no Wasm offset.
+    assert_eq!(outer.wasm_offset(&store, usize::MAX), None);
+    assert_eq!(instance.wasm_offset(&store, 2, 0), None);
+    let host = Func::wrap(&mut store, || {});
+    assert_eq!(host.wasm_offset(&store, 0), None);
+}
@@ -0,0 +1,212 @@
+//! Tests to check if Wasm backtraces are attached to traps as intended.
+
+use super::{instantiate_with, wat2wasm};
+use wasmi::{
+    core::{Trap, TrapCode},
+    Caller,
+    Engine,
+    Error,
+    Extern,
+    Instance,
+    Linker,
+    Store,
+};
+
+/// Calls the exported function `name` of the `instance` and returns its [`Trap`].
+fn call_trap(store: &mut Store<()>, instance: Instance, name: &str) -> Trap {
+    instance
+        .get_typed_func::<(), ()>(&*store, name)
+        .unwrap()
+        .call(&mut *store, ())
+        .unwrap_err()
+}
+
+/// Returns the `(module, function, index)` triplets of the backtrace of the `trap`.
+fn frames(trap: &Trap) -> Vec<(Option<&str>, Option<&str>, u32)> {
+    trap.backtrace()
+        .expect("missing backtrace")
+        .frames()
+        .iter()
+        .map(|frame| (frame.module_name(), frame.func_name(), frame.func_index()))
+        .collect()
+}
+
+const TEST_WAT: &str = r#"
+    (module $test
+        (import "env" "host" (func $host))
+        (func $outer (export "outer")
+            (call $middle)
+        )
+        (func $middle
+            (nop)
+            (call $inner)
+        )
+        (func $inner
+            (unreachable)
+        )
+        (func (export "anonymous")
+            (call $inner)
+        )
+        (func $recurse (export "recurse")
+            (call $recurse)
+        )
+        (func $call_host (export "host")
+            (nop)
+            (call $host)
+        )
+    )
+"#;
+
+/// Instantiates the [`TEST_WAT`] module with a trapping `env.host` function.
+fn backtrace_test_setup() -> (Store<()>, Instance) {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap("env", "host", || -> Result<(), Trap> {
+            Err(Trap::new("host failure"))
+        })
+        .unwrap();
+    let instance = instantiate_with(&mut store, &linker, &wat2wasm(TEST_WAT));
+    (store, instance)
+}
+
+#[test]
+fn backtrace_works() {
+    let (mut store, instance) = backtrace_test_setup();
+    let trap = call_trap(&mut store, instance, "outer");
+    assert_eq!(trap.trap_code(), Some(TrapCode::UnreachableCodeReached));
+    assert_eq!(
+        frames(&trap),
+        [
+            (Some("test"), Some("inner"), 3),
+            (Some("test"), Some("middle"), 2),
+            (Some("test"), Some("outer"), 1),
+        ]
+    );
+    let backtrace = trap.backtrace().unwrap();
+    assert_eq!(backtrace.frames()[0].instr_offset(), 0);
+    assert!(backtrace.frames()[1].instr_offset() > 0);
+    let display = trap.to_string();
+    assert!(display.starts_with("wasm `unreachable` instruction executed"));
+    assert!(display.contains("wasm backtrace:"), "{display}");
+    assert!(display.contains("0: test!inner (func 3"), "{display}");
+    assert!(display.contains("2: test!outer (func 1"), "{display}");
+}
+
+#[test]
+fn backtrace_without_names_works() {
+    let (mut store, instance) = backtrace_test_setup();
+    let trap = call_trap(&mut store, instance, "anonymous");
+    assert_eq!(
+        frames(&trap),
+        [(Some("test"), Some("inner"), 3), (Some("test"), None, 4)]
+    );
+    assert!(trap.to_string().contains("test!<wasm function 4>"));
+}
+
+#[test]
+fn backtrace_of_stack_overflow_works() {
+    let (mut store, instance) = backtrace_test_setup();
+    let trap = call_trap(&mut store, instance, "recurse");
+    assert_eq!(trap.trap_code(), Some(TrapCode::StackOverflow));
+    let frames = frames(&trap);
+    assert!(frames.len() > 1);
+    assert!(frames
+        .iter()
+        .all(|frame| *frame == (Some("test"), Some("recurse"), 5)));
+}
+
+#[test]
+fn backtrace_across_instances_works() {
+    let (mut store, instance) = backtrace_test_setup();
+    let mut linker = <Linker<()>>::new(store.engine());
+    let outer = instance.get_func(&store, "outer").unwrap();
+    linker.define("test", "outer", outer).unwrap();
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (import "test" "outer" (func $outer))
+            (func $caller (export "caller")
+                (call $outer)
+            )
+        )
+    "#,
+    );
+    let caller = instantiate_with(&mut store, &linker, &wasm);
+    let trap = call_trap(&mut store, caller, "caller");
+    assert_eq!(
+        frames(&trap),
+        [
+            (Some("test"), Some("inner"), 3),
+            (Some("test"), Some("middle"), 2),
+            (Some("test"), Some("outer"), 1),
+            (None, Some("caller"), 1),
+        ]
+    );
+    assert!(trap.to_string().contains("<unknown>!caller"));
+}
+
+#[test]
+fn host_trap_backtrace_works() {
+    let (mut store, instance) = backtrace_test_setup();
+    let trap = call_trap(&mut store, instance, "host");
+    assert_eq!(frames(&trap), [(Some("test"), Some("call_host"), 6)]);
+    let frame = &trap.backtrace().unwrap().frames()[0];
+    assert!(frame.instr_offset() > 0);
+    assert!(frame.wasm_offset().is_some());
+    let display = trap.to_string();
+    assert!(
+        display.starts_with("host failure\n\nwasm backtrace:"),
+        "{display}"
+    );
+    assert!(display.contains("0: test!call_host (func 6"), "{display}");
+    let error = Error::from(trap);
+    assert_eq!(error.to_string(), display);
+}
+
+#[test]
+fn nested_host_trap_backtrace_works() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap(
+            "env",
+            "callback",
+            |mut caller: Caller<()>| -> Result<(), Trap> {
+                caller
+                    .get_export("trap")
+                    .and_then(Extern::into_func)
+                    .unwrap()
+                    .typed::<(), ()>(&caller)
+                    .unwrap()
+                    .call(&mut caller, ())
+            },
+        )
+        .unwrap();
+    let wasm = wat2wasm(
+        r#"
+        (module $nested
+            (import "env" "callback" (func $callback))
+            (func $trap (export "trap")
+                (unreachable)
+            )
+            (func $entry (export "entry")
+                (call $callback)
+            )
+        )
+    "#,
+    );
+    let instance = instantiate_with(&mut store, &linker, &wasm);
+    let trap = call_trap(&mut store, instance, "entry");
+    assert_eq!(trap.trap_code(), Some(TrapCode::UnreachableCodeReached));
+    // The frames of the host function caller are appended to the frames of the trapping call.
+    assert_eq!(
+        frames(&trap),
+        [
+            (Some("nested"), Some("trap"), 1),
+            (Some("nested"), Some("entry"), 2),
+        ]
+    );
+}
@@ -0,0 +1,256 @@
+//! Tests to check if asynchronous host functions and async calls work as intended.
+
+use super::{instantiate_with, wat2wasm};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+use wasmi::{Caller, Config, Engine, Error, Func, FuncType, Instance, Linker, Store, Value};
+use wasmi_core::{Trap, ValueType};
+
+/// A [`Waker`] that does nothing since [`block_on`] polls in a busy loop.
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Polls the `future` to completion and returns its output and the number of times it was pending.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    let mut pending = 0;
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return (output, pending),
+            Poll::Pending => pending += 1,
+        }
+    }
+}
+
+/// Asserts that `future` can be sent across threads.
+fn assert_send<F: Future + Send>(future: F) -> F {
+    future
+}
+
+/// A future that is pending `n` times before it resolves.
+#[derive(Debug)]
+struct PendingFor(usize);
+
+impl Future for PendingFor {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0 == 0 {
+            return Poll::Ready(());
+        }
+        self.0 -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Instantiates the Wasm module `wat` with asynchronous host functions.
+///
+/// - `env.double`: doubles its input after being pending twice.
+/// - `env.add`: adds its inputs after being pending once and counts its calls.
+/// - `env.fail`: returns an error after being pending once.
+/// - `env.sync_fail`: a synchronous host function returning an error.
+fn async_test_setup(wat: &str) -> (Store<u32>, Instance) {
+    let mut config = Config::default();
+    config.wasm_tail_call(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, 0);
+    let mut linker = <Linker<u32>>::new(&engine);
+    linker
+        .func_wrap_async("env", "double", |_caller: Caller<u32>, input: i32| {
+            Box::new(async move {
+                PendingFor(2).await;
+                input * 2
+            })
+        })
+        .unwrap()
+        .func_new_async(
+            "env",
+            "add",
+            FuncType::new([ValueType::I64, ValueType::I64], [ValueType::I64]),
+            |mut caller, params, results| {
+                Box::new(async move {
+                    PendingFor(1).await;
+                    *caller.data_mut() += 1;
+                    let lhs = params[0].i64().unwrap();
+                    let rhs = params[1].i64().unwrap();
+                    results[0] = Value::I64(lhs + rhs);
+                    Ok(())
+                })
+            },
+        )
+        .unwrap()
+        .func_wrap_async("env", "fail", |_caller: Caller<u32>, ()| {
+            Box::new(async move {
+                PendingFor(1).await;
+                <Result<(), Trap>>::Err(Trap::new("async failure"))
+            })
+        })
+        .unwrap()
+        .func_wrap("env", "sync_fail", || -> Result<(), Trap> {
+            Err(Trap::new("sync failure"))
+        })
+        .unwrap();
+    let instance = instantiate_with(&mut store, &linker, &wat2wasm(wat));
+    (store, instance)
+}
+
+const TEST_WAT: &str = r#"
+    (module
+        (import "env" "double" (func $double (param i32) (result i32)))
+        (import "env" "add" (func $add (param i64 i64) (result i64)))
+        (import "env" "fail" (func $fail))
+        (import "env" "sync_fail" (func $sync_fail))
+        (func (export "double_twice") (param i32) (result i32)
+            (call $double (call $double (local.get 0)))
+        )
+        (func (export "sum") (param $n i64) (result i64)
+            (local $sum i64)
+            (block $exit
+                (loop $continue
+                    (br_if $exit (i64.eqz (local.get $n)))
+                    (local.set $sum (call $add (local.get $sum) (local.get $n)))
+                    (local.set $n (i64.sub (local.get $n) (i64.const 1)))
+                    (br $continue)
+                )
+            )
+            (local.get $sum)
+        )
+        (func (export "tail_add") (param i64 i64) (result i64)
+            (return_call $add (local.get 0) (local.get 1))
+        )
+        (func (export "fail")
+            (call $fail)
+        )
+        (func (export "sync_fail")
+            (call $sync_fail)
+        )
+    )
+"#;
+
+#[test]
+fn call_async_works() {
+    let (mut store, instance) = async_test_setup(TEST_WAT);
+    let double_twice = instance
+        .get_typed_func::<i32, i32>(&store, "double_twice")
+        .unwrap();
+    let (result, pending) = block_on(assert_send(double_twice.call_async(&mut store, 5)));
+    assert_eq!(result.unwrap(), 20);
+    assert_eq!(pending, 4);
+    let sum = instance.get_func(&store, "sum").unwrap();
+    let mut results = [Value::I64(0)];
+    let (result, pending) = block_on(assert_send(sum.call_async(
+        &mut store,
+        &[Value::I64(10)],
+        &mut results,
+    )));
+    result.unwrap();
+    assert_eq!(results[0].i64(), Some(55));
+    assert_eq!(pending, 10);
+    assert_eq!(*store.data(), 10);
+}
+
+#[test]
+fn call_async_tail_call_works() {
+    let (mut store, instance) = async_test_setup(TEST_WAT);
+    let tail_add = instance
+        .get_typed_func::<(i64, i64), i64>(&store, "tail_add")
+        .unwrap();
+    let (result, pending) = block_on(tail_add.call_async(&mut store, (1, 2)));
+    assert_eq!(result.unwrap(), 3);
+    assert_eq!(pending, 1);
+}
+
+#[test]
+fn call_async_host_func_as_root_works() {
+    let (mut store, _instance) = async_test_setup(TEST_WAT);
+    let add = Func::new_async(
+        &mut store,
+        FuncType::new([ValueType::I32], [ValueType::I32]),
+        |_caller, params, results| {
+            Box::new(async move {
+                PendingFor(3).await;
+                results[0] = Value::I32(params[0].i32().unwrap() + 1);
+                Ok(())
+            })
+        },
+    );
+    let (result, pending) = block_on(
+        add.typed::<i32, i32>(&store)
+            .unwrap()
+            .call_async(&mut store, 41),
+    );
+    assert_eq!(result.unwrap(), 42);
+    assert_eq!(pending, 3);
+}
+
+#[test]
+fn call_async_errors_propagate() {
+    let (mut store, instance) = async_test_setup(TEST_WAT);
+    let fail = instance.get_typed_func::<(), ()>(&store, "fail").unwrap();
+    let (result, _) = block_on(fail.call_async(&mut store, ()));
+    assert_eq!(result.unwrap_err().to_string(), "async failure");
+    let sync_fail = instance.get_func(&store, "sync_fail").unwrap();
+    let (result, _) = block_on(sync_fail.call_async(&mut store, &[], &mut []));
+    match result.unwrap_err() {
+        Error::Trap(trap) => {
+            assert!(trap.backtrace().is_some());
+            assert!(trap
+                .to_string()
+                .starts_with("sync failure\n\nwasm backtrace:"));
+        }
+        error => panic!("expected a trap but found: {error}"),
+    }
+}
+
+#[test]
+fn sync_call_of_async_host_func_fails() {
+    let (mut store, instance) = async_test_setup(TEST_WAT);
+    let double_twice = instance
+        .get_typed_func::<i32, i32>(&store, "double_twice")
+        .unwrap();
+    let trap = double_twice.call(&mut store, 5).unwrap_err();
+    assert!(trap.to_string().contains("call_async"));
+}
+
+#[test]
+fn call_async_yields_upon_epoch_deadline() {
+    let mut config = Config::default();
+    config.epoch_interruption(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    store.epoch_deadline_yield_and_update(1);
+    let tick = Func::wrap(&mut store, |caller: Caller<()>| {
+        caller.engine().increment_epoch();
+    });
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "tick", tick).unwrap();
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (import "env" "tick" (func $tick))
+            (func (export "test") (param $n i32)
+                (loop $continue
+                    (call $tick)
+                    (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br_if $continue)
+                )
+            )
+        )
+    "#,
+    );
+    let instance = instantiate_with(&mut store, &linker, &wasm);
+    let test = instance.get_typed_func::<i32, ()>(&store, "test").unwrap();
+    let (result, pending) = block_on(test.call_async(&mut store, 5));
+    result.unwrap();
+    assert!(pending >= 4, "pending: {pending}");
+}
@@ -0,0 +1,243 @@
+//! Tests to check if the lazy compilation modes of `wasmi` work as intended.
+
+use super::{instantiate_module, instantiate_with, wat2wasm};
+use wasmi::{Caller, CompilationMode, Config, Engine, Error, Extern, Func, Linker, Module, Store};
+
+/// All compilation modes supported by `wasmi`.
+const MODES: [CompilationMode; 3] = [
+    CompilationMode::Eager,
+    CompilationMode::LazyTranslation,
+    CompilationMode::Lazy,
+];
+
+/// Creates an [`Engine`] with the given [`CompilationMode`].
+fn engine(mode: CompilationMode) -> Engine {
+    let mut config = Config::default();
+    config.compilation_mode(mode);
+    config.wasm_tail_call(true);
+    config.wasm_exceptions(true);
+    Engine::new(&config)
+}
+
+/// Instantiates the `module` and returns the [`Store`] and the exported `test` function.
+///
+/// The host function `env.host` calls the exported `square` function of the instance.
+fn instantiate_test(engine: &Engine, module: &Module) -> (Store<()>, Func) {
+    let mut store = Store::new(engine, ());
+    let mut linker = <Linker<()>>::new(engine);
+    linker
+        .func_wrap("env", "host", |mut caller: Caller<()>, input: i64| -> i64 {
+            caller
+                .get_export("square")
+                .and_then(Extern::into_func)
+                .unwrap()
+                .typed::<i64, i64>(&caller)
+                .unwrap()
+                .call(&mut caller, input)
+                .unwrap()
+        })
+        .unwrap();
+    let instance = instantiate_module(&mut store, &linker, module);
+    let func = instance.get_func(&store, "test").unwrap();
+    (store, func)
+}
+
+const TEST_WAT: &str = r#"
+    (module
+        (import "env" "host" (func $host (param i64) (result i64)))
+        (type $unop (func (param i64) (result i64)))
+        (tag $e (param i64))
+        (table 2 funcref)
+        (elem (i32.const 0) $fib $throw)
+        (func $fib (param i64) (result i64)
+            (if (result i64) (i64.lt_u (local.get 0) (i64.const 2))
+                (then (local.get 0))
+                (else
+                    (i64.add
+                        (call $fib (i64.sub (local.get 0) (i64.const 1)))
+                        (call $fib (i64.sub (local.get 0) (i64.const 2)))
+                    )
+                )
+            )
+        )
+        (func $throw (param i64) (result i64)
+            (throw $e (local.get 0))
+        )
+        (func $count (param i64 i64) (result i64)
+            (if (result i64) (i64.eqz (local.get 0))
+                (then (local.get 1))
+                (else
+                    (return_call $count
+                        (i64.sub (local.get 0) (i64.const 1))
+                        (i64.add (local.get 1) (i64.const 1))
+                    )
+                )
+            )
+        )
+        (func (export "square") (param i64) (result i64)
+            (i64.mul (local.get 0) (local.get 0))
+        )
+        (func (export "test") (param i32 i64) (result i64)
+            (block $count
+                (block $host
+                    (block $throw
+                        (block $fib
+                            (br_table $fib $throw $host $count (local.get 0))
+                        )
+                        (return (call_indirect (type $unop) (local.get 1) (i32.const 0)))
+                    )
+                    try (result i64)
+                        (call_indirect (type $unop) (local.get 1) (i32.const 1))
+                    catch $e
+                        (i64.add (i64.const 1))
+                    end
+                    (return)
+                )
+                (return (i64.add (call $host (local.get 1)) (i64.const 1)))
+            )
+            (call $count (local.get 1) (i64.const 0))
+        )
+    )
+"#;
+
+#[test]
+fn lazy_compilation_behaves_like_eager() {
+    let wasm = wat2wasm(TEST_WAT);
+    for mode in MODES {
+        let engine = engine(mode);
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let (mut store, func) = instantiate_test(&engine, &module);
+        let func = func.typed::<(i32, i64), i64>(&store).unwrap();
+        assert_eq!(func.call(&mut store, (0, 20)).unwrap(), 6765, "{mode:?}");
+        assert_eq!(func.call(&mut store, (1, 41)).unwrap(), 42, "{mode:?}");
+        assert_eq!(func.call(&mut store, (2, 5)).unwrap(), 26, "{mode:?}");
+        assert_eq!(func.call(&mut store, (3, 1000)).unwrap(), 1000, "{mode:?}");
+        // Calling the functions again uses their already translated function bodies.
+        assert_eq!(func.call(&mut store, (0, 10)).unwrap(), 55, "{mode:?}");
+        assert_eq!(func.call(&mut store, (2, 6)).unwrap(), 37, "{mode:?}");
+    }
+}
+
+#[test]
+fn lazy_compilation_of_module_instantiated_twice() {
+    let wasm = wat2wasm(TEST_WAT);
+    for mode in MODES {
+        let engine = engine(mode);
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let (mut store_a, func_a) = instantiate_test(&engine, &module);
+        let (mut store_b, func_b) = instantiate_test(&engine, &module);
+        let func_a = func_a.typed::<(i32, i64), i64>(&store_a).unwrap();
+        let func_b = func_b.typed::<(i32, i64), i64>(&store_b).unwrap();
+        assert_eq!(func_a.call(&mut store_a, (0, 15)).unwrap(), 610, "{mode:?}");
+        assert_eq!(func_b.call(&mut store_b, (0, 15)).unwrap(), 610, "{mode:?}");
+    }
+}
+
+#[test]
+fn lazy_compilation_of_deeply_nested_calls() {
+    // Every function is translated while the frames of all its callers are on the
+    // call stack so that the translated instructions are reallocated in between.
+    const LEN_FUNCS: usize = 200;
+    let mut wat = String::from("(module");
+    for n in 0..LEN_FUNCS {
+        let callee = match n + 1 {
+            LEN_FUNCS => String::from("(local.get 0)"),
+            next => format!("(call $f{next} (i64.add (local.get 0) (i64.const 1)))"),
+        };
+        wat.push_str(&format!(
+            "(func $f{n} (export \"f{n}\") (param i64) (result i64) (local i64)
+                (local.set 1 {callee})
+                (i64.sub (i64.mul (local.get 1) (i64.const 3)) (i64.mul (local.get 1) (i64.const 2)))
+            )"
+        ));
+    }
+    wat.push(')');
+    let wasm = wat2wasm(&wat);
+    for mode in MODES {
+        let engine = engine(mode);
+        let mut store = Store::new(&engine, ());
+        let instance = instantiate_with(&mut store, &Linker::new(&engine), &wasm);
+        let func = instance.get_typed_func::<i64, i64>(&store, "f190").unwrap();
+        assert_eq!(func.call(&mut store, 0).unwrap(), 9, "{mode:?}");
+        let func = instance.get_typed_func::<i64, i64>(&store, "f0").unwrap();
+        assert_eq!(func.call(&mut store, 0).unwrap(), 199, "{mode:?}");
+    }
+}
+
+/// A Wasm module with the invalid function `$invalid` that is only called by `invalid`.
+const INVALID_WAT: &str = r#"
+    (module
+        (func $invalid (result i32)
+            (i64.const 1)
+        )
+        (func (export "valid") (result i32)
+            (i32.const 1)
+        )
+        (func (export "invalid") (result i32)
+            (call $invalid)
+        )
+    )
+"#;
+
+/// Returns the Wasm binary of [`INVALID_WAT`].
+///
+/// # Note
+///
+/// The `wat` crate does not validate the Wasm module.
+fn invalid_wasm() -> Vec<u8> {
+    wat2wasm(INVALID_WAT)
+}
+
+#[test]
+fn eager_validation_fails_upon_module_creation() {
+    let wasm = invalid_wasm();
+    for mode in [CompilationMode::Eager, CompilationMode::LazyTranslation] {
+        let engine = engine(mode);
+        assert!(
+            matches!(Module::new(&engine, &wasm[..]), Err(Error::Module(_))),
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn lazy_validation_fails_upon_first_execution() {
+    let wasm = invalid_wasm();
+    let engine = engine(CompilationMode::Lazy);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = instantiate_module(&mut store, &<Linker<()>>::new(&engine), &module);
+    let valid = instance.get_typed_func::<(), i32>(&store, "valid").unwrap();
+    let invalid = instance
+        .get_typed_func::<(), i32>(&store, "invalid")
+        .unwrap();
+    assert_eq!(valid.call(&mut store, ()).unwrap(), 1);
+    let trap = invalid.call(&mut store, ()).unwrap_err();
+    assert!(trap.trap_code().is_none());
+    // The function fails to translate again with the same error.
+    assert_eq!(
+        invalid.call(&mut store, ()).unwrap_err().to_string(),
+        trap.to_string()
+    );
+    assert_eq!(valid.call(&mut store, ()).unwrap(), 1);
+    assert!(matches!(module.serialize(), Err(Error::Trap(_))));
+}
+
+#[test]
+fn serialize_lazily_compiled_module() {
+    let wasm = wat2wasm(TEST_WAT);
+    let serialized = Module::new(&engine(CompilationMode::Eager), &wasm[..])
+        .unwrap()
+        .serialize()
+        .unwrap();
+    for mode in [CompilationMode::LazyTranslation, CompilationMode::Lazy] {
+        let engine = engine(mode);
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        assert_eq!(module.serialize().unwrap(), serialized, "{mode:?}");
+        // SAFETY: The serialized module has been produced by `Module::serialize`.
+        let module = unsafe { Module::deserialize(&engine, &serialized) }.unwrap();
+        let (mut store, func) = instantiate_test(&engine, &module);
+        let func = func.typed::<(i32, i64), i64>(&store).unwrap();
+        assert_eq!(func.call(&mut store, (0, 20)).unwrap(), 6765, "{mode:?}");
+    }
+}
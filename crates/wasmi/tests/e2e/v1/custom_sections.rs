@@ -0,0 +1,73 @@
+//! Tests to check if custom sections and the `name` section are retained as intended.
+
+use super::append_custom_section;
+use wasmi::{Engine, Module};
+
+/// Returns the Wasm binary used by the custom section tests.
+fn test_wasm() -> Vec<u8> {
+    let mut wasm = wat::parse_str(
+        r#"
+        (module $test
+            (import "env" "host" (func $host))
+            (func $add (export "add") (param $lhs i32) (param $rhs i32) (result i32)
+                (local $tmp i32)
+                (i32.add (local.get $lhs) (local.get $rhs))
+            )
+            (func (export "anonymous"))
+        )
+    "#,
+    )
+    .unwrap();
+    append_custom_section(&mut wasm, "manifest", b"first");
+    append_custom_section(&mut wasm, "producers", b"\x00");
+    append_custom_section(&mut wasm, "manifest", b"second");
+    wasm
+}
+
+#[test]
+fn custom_sections_work() {
+    let engine = Engine::default();
+    let module = Module::new(&engine, &test_wasm()[..]).unwrap();
+    let manifests = module.custom_sections("manifest").collect::<Vec<_>>();
+    assert_eq!(manifests, [&b"first"[..], &b"second"[..]]);
+    assert_eq!(module.custom_sections("producers").count(), 1);
+    assert_eq!(module.custom_sections("unknown").count(), 0);
+}
+
+#[test]
+fn name_section_works() {
+    let engine = Engine::default();
+    let module = Module::new(&engine, &test_wasm()[..]).unwrap();
+    assert_eq!(module.name(), Some("test"));
+    let names = module.names();
+    assert_eq!(names.func(0), Some("host"));
+    assert_eq!(names.func(1), Some("add"));
+    assert_eq!(names.func(2), None);
+    assert_eq!(names.local(1, 0), Some("lhs"));
+    assert_eq!(names.local(1, 1), Some("rhs"));
+    assert_eq!(names.local(1, 2), Some("tmp"));
+    assert_eq!(names.local(1, 3), None);
+}
+
+#[test]
+fn malformed_name_section_is_ignored() {
+    let mut wasm = wat::parse_str("(module)").unwrap();
+    append_custom_section(&mut wasm, "name", b"\x00\x05\x10");
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    assert_eq!(module.name(), None);
+    assert_eq!(module.custom_sections("name").count(), 1);
+}
+
+#[test]
+fn custom_sections_survive_serialization() {
+    let engine = Engine::default();
+    let module = Module::new(&engine, &test_wasm()[..]).unwrap();
+    let serialized = module.serialize().unwrap();
+    // SAFETY: The serialized module is produced by `Module::serialize`.
+    let module = unsafe { Module::deserialize(&engine, &serialized) }.unwrap();
+    let manifests = module.custom_sections("manifest").collect::<Vec<_>>();
+    assert_eq!(manifests, [&b"first"[..], &b"second"[..]]);
+    assert_eq!(module.name(), Some("test"));
+    assert_eq!(module.names().func(1), Some("add"));
+}
@@ -0,0 +1,284 @@
+//! Tests to check if DWARF debug information resolves source locations of traps as intended.
+
+use super::{append_custom_section, call_outer};
+use gimli::{
+    constants,
+    write::{Address, AttributeValue, DwarfUnit, EndianVec, LineProgram, LineString, Sections},
+    Encoding,
+    Format,
+    LineEncoding,
+    LittleEndian,
+};
+use wasmi::{core::SourceLocation, Engine, Module};
+
+/// The Wasm module used by the DWARF tests.
+const TEST_WAT: &str = r#"
+    (module
+        (func $outer (export "outer")
+            (call $inner)
+        )
+        (func $inner
+            (unreachable)
+        )
+    )
+"#;
+
+/// The Wasm offsets of the operators of the [`TEST_WAT`] module that are described by DWARF.
+#[derive(Debug, Copy, Clone)]
+struct Offsets {
+    /// The offset of the code section contents within the Wasm binary.
+    code: usize,
+    /// The size of the code section contents.
+    code_len: usize,
+    /// The offset of the `call` operator of `$outer` within the Wasm binary.
+    call: usize,
+    /// The offset of the `unreachable` operator of `$inner` within the Wasm binary.
+    unreachable: usize,
+}
+
+/// Reads the LEB128 encoded `u32` at the start of `bytes` and returns it with its size.
+fn read_leb128(bytes: &[u8]) -> (usize, usize) {
+    let mut value = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        value |= usize::from(byte & 0x7F) << (7 * index);
+        if byte & 0x80 == 0 {
+            return (value, index + 1);
+        }
+    }
+    panic!("unterminated LEB128 encoding")
+}
+
+/// Returns the offset and size of the code section contents of the `wasm` binary.
+fn code_section(wasm: &[u8]) -> (usize, usize) {
+    // Skip the magic bytes and version of the Wasm binary.
+    let mut pos = 8;
+    loop {
+        let id = wasm[pos];
+        let (len, leb_len) = read_leb128(&wasm[pos + 1..]);
+        pos += 1 + leb_len;
+        if id == 10 {
+            return (pos, len);
+        }
+        pos += len;
+    }
+}
+
+/// Returns the Wasm binary of the [`TEST_WAT`] module and the [`Offsets`] of its operators.
+fn test_wasm() -> (Vec<u8>, Offsets) {
+    let wasm = wat::parse_str(TEST_WAT).unwrap();
+    let module = Module::new(&Engine::default(), &wasm[..]).unwrap();
+    let trap = call_outer(&module);
+    let frames = trap.backtrace().unwrap().frames();
+    let (code, code_len) = code_section(&wasm);
+    let offsets = Offsets {
+        code,
+        code_len,
+        unreachable: frames[0].wasm_offset().unwrap(),
+        call: frames[1].wasm_offset().unwrap(),
+    };
+    (wasm, offsets)
+}
+
+/// Appends the DWARF debug information of the [`TEST_WAT`] module to the `wasm` binary.
+///
+/// The DWARF debug information describes the following Rust source code:
+///
+/// ```text
+/// // src/lib.rs
+/// fn outer() {
+///     inner(); // line 10
+/// }
+///
+/// fn inner() {
+///     helper(); // line 30, `helper` is inlined
+/// }
+///
+/// fn helper() {
+///     unreachable(); // line 20
+/// }
+/// ```
+fn append_dwarf(wasm: &mut Vec<u8>, offsets: Offsets) {
+    let encoding = Encoding {
+        format: Format::Dwarf32,
+        version: 4,
+        address_size: 4,
+    };
+    let mut dwarf = DwarfUnit::new(encoding);
+    let mut program = LineProgram::new(
+        encoding,
+        LineEncoding::default(),
+        LineString::String(b"/guest".to_vec()),
+        LineString::String(b"src/lib.rs".to_vec()),
+        None,
+    );
+    let dir = program.default_directory();
+    let file = program.add_file(LineString::String(b"src/lib.rs".to_vec()), dir, None);
+    // DWARF for Wasm refers to code by offsets relative to the code section contents.
+    let call = (offsets.call - offsets.code) as u64;
+    let unreachable = (offsets.unreachable - offsets.code) as u64;
+    let code_len = offsets.code_len as u64;
+    program.begin_sequence(Some(Address::Constant(0)));
+    for (address, line) in [(call, 10), (unreachable, 20)] {
+        let row = program.row();
+        row.address_offset = address;
+        row.file = file;
+        row.line = line;
+        row.column = 5;
+        program.generate_row();
+    }
+    program.end_sequence(code_len);
+    dwarf.unit.line_program = program;
+
+    let unit = &mut dwarf.unit;
+    let root = unit.root();
+    let entry = unit.get_mut(root);
+    entry.set(
+        constants::DW_AT_name,
+        AttributeValue::String(b"src/lib.rs".to_vec()),
+    );
+    entry.set(
+        constants::DW_AT_comp_dir,
+        AttributeValue::String(b"/guest".to_vec()),
+    );
+    entry.set(
+        constants::DW_AT_low_pc,
+        AttributeValue::Address(Address::Constant(0)),
+    );
+    entry.set(constants::DW_AT_high_pc, AttributeValue::Udata(code_len));
+    let mut add_func = |name: &str, low_pc: u64, high_pc: u64| {
+        let id = unit.add(root, constants::DW_TAG_subprogram);
+        let entry = unit.get_mut(id);
+        entry.set(
+            constants::DW_AT_name,
+            AttributeValue::String(name.as_bytes().to_vec()),
+        );
+        entry.set(
+            constants::DW_AT_low_pc,
+            AttributeValue::Address(Address::Constant(low_pc)),
+        );
+        entry.set(
+            constants::DW_AT_high_pc,
+            AttributeValue::Udata(high_pc - low_pc),
+        );
+        id
+    };
+    add_func("outer", 0, unreachable);
+    let inner = add_func("inner", unreachable, code_len);
+    let helper = unit.add(root, constants::DW_TAG_subprogram);
+    let entry = unit.get_mut(helper);
+    entry.set(
+        constants::DW_AT_name,
+        AttributeValue::String(b"helper".to_vec()),
+    );
+    entry.set(
+        constants::DW_AT_inline,
+        AttributeValue::Inline(constants::DW_INL_inlined),
+    );
+    let inlined = unit.add(inner, constants::DW_TAG_inlined_subroutine);
+    let entry = unit.get_mut(inlined);
+    entry.set(
+        constants::DW_AT_abstract_origin,
+        AttributeValue::UnitRef(helper),
+    );
+    entry.set(
+        constants::DW_AT_low_pc,
+        AttributeValue::Address(Address::Constant(unreachable)),
+    );
+    entry.set(constants::DW_AT_high_pc, AttributeValue::Udata(1));
+    entry.set(
+        constants::DW_AT_call_file,
+        AttributeValue::FileIndex(Some(file)),
+    );
+    entry.set(constants::DW_AT_call_line, AttributeValue::Udata(30));
+    entry.set(constants::DW_AT_call_column, AttributeValue::Udata(5));
+
+    let mut sections = Sections::new(EndianVec::new(LittleEndian));
+    dwarf.write(&mut sections).unwrap();
+    sections
+        .for_each(|id, data| {
+            if !data.slice().is_empty() {
+                append_custom_section(wasm, id.name(), data.slice());
+            }
+            Ok::<(), gimli::write::Error>(())
+        })
+        .unwrap();
+}
+
+/// Returns the source location for `func` at `line` and column 5 of `src/lib.rs`.
+fn location(func: &str, line: u32) -> SourceLocation {
+    SourceLocation::new(Some(func), Some("/guest/src/lib.rs"), Some(line), Some(5))
+}
+
+#[test]
+fn dwarf_source_locations_work() {
+    let (mut wasm, offsets) = test_wasm();
+    append_dwarf(&mut wasm, offsets);
+    let module = Module::new(&Engine::default(), &wasm[..]).unwrap();
+    assert_eq!(
+        module.source_locations(offsets.unreachable),
+        [location("helper", 20), location("inner", 30)]
+    );
+    assert_eq!(
+        module.source_locations(offsets.call),
+        [location("outer", 10)]
+    );
+    // Offsets before the code section are not covered by DWARF.
+    assert!(module.source_locations(0).is_empty());
+}
+
+#[test]
+fn dwarf_backtrace_works() {
+    let (mut wasm, offsets) = test_wasm();
+    append_dwarf(&mut wasm, offsets);
+    let module = Module::new(&Engine::default(), &wasm[..]).unwrap();
+    let trap = call_outer(&module);
+    let frames = trap.backtrace().unwrap().frames();
+    assert_eq!(
+        frames[0].source_locations(),
+        [location("helper", 20), location("inner", 30)]
+    );
+    assert_eq!(frames[1].source_locations(), [location("outer", 10)]);
+    let display = trap.to_string();
+    assert!(
+        display.contains("\n        at helper (/guest/src/lib.rs:20:5)"),
+        "{display}"
+    );
+    assert!(
+        display.contains("\n        at outer (/guest/src/lib.rs:10:5)"),
+        "{display}"
+    );
+}
+
+#[test]
+fn dwarf_survives_serialization() {
+    let (mut wasm, offsets) = test_wasm();
+    append_dwarf(&mut wasm, offsets);
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let serialized = module.serialize().unwrap();
+    // SAFETY: The serialized module is produced by `Module::serialize`.
+    let module = unsafe { Module::deserialize(&engine, &serialized) }.unwrap();
+    let trap = call_outer(&module);
+    let frames = trap.backtrace().unwrap().frames();
+    assert_eq!(frames[1].source_locations(), [location("outer", 10)]);
+}
+
+#[test]
+fn missing_dwarf_works() {
+    let (wasm, offsets) = test_wasm();
+    let module = Module::new(&Engine::default(), &wasm[..]).unwrap();
+    assert!(module.source_locations(offsets.unreachable).is_empty());
+    let trap = call_outer(&module);
+    let frames = trap.backtrace().unwrap().frames();
+    assert!(frames[0].source_locations().is_empty());
+    assert!(!trap.to_string().contains("\n        at "));
+}
+
+#[test]
+fn malformed_dwarf_is_ignored() {
+    let (mut wasm, offsets) = test_wasm();
+    append_custom_section(&mut wasm, ".debug_info", b"\xFF\xFF");
+    let module = Module::new(&Engine::default(), &wasm[..]).unwrap();
+    assert!(module.source_locations(offsets.unreachable).is_empty());
+    assert!(call_outer(&module).backtrace().is_some());
+}
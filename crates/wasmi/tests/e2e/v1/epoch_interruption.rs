@@ -0,0 +1,172 @@
+//! Tests to check if wasmi's epoch interruption works as intended.
+
+use super::{instantiate_with, wat2wasm};
+use wasmi::{
+    Caller,
+    Config,
+    Engine,
+    Func,
+    Linker,
+    ResumableCall,
+    Store,
+    TypedResumableCall,
+    Value,
+};
+use wasmi_core::{Trap, TrapCode};
+
+/// Setup [`Store`] and the exported `test` function of the `wasm` module for epoch interruption.
+///
+/// The host function `env.tick` increments the epoch of the [`Engine`]
+/// and counts how often it has been called in the host state.
+fn epoch_test_setup(wasm: &[u8]) -> (Store<u32>, Func) {
+    let mut config = Config::default();
+    config.epoch_interruption(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, 0);
+    let mut linker = Linker::new(&engine);
+    let tick = Func::wrap(&mut store, |mut caller: Caller<u32>| {
+        *caller.data_mut() += 1;
+        caller.engine().increment_epoch();
+    });
+    linker.define("env", "tick", tick).unwrap();
+    let instance = instantiate_with(&mut store, &linker, wasm);
+    let func = instance.get_func(&store, "test").unwrap();
+    (store, func)
+}
+
+/// Returns the Wasm module bytes of a module that calls `env.tick` in a loop `$n` times.
+fn tick_loop_wasm() -> Vec<u8> {
+    wat2wasm(
+        r#"
+        (module
+            (import "env" "tick" (func $tick))
+            (func (export "test") (param $n i32) (result i32)
+                (local $i i32)
+                (block $exit
+                    (loop $continue
+                        (br_if $exit (i32.ge_u (local.get $i) (local.get $n)))
+                        (call $tick)
+                        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                        (br $continue)
+                    )
+                )
+                (local.get $i)
+            )
+        )
+    "#,
+    )
+}
+
+/// Asserts that the call trapped with [`TrapCode::EpochDeadlineExceeded`].
+fn assert_epoch_deadline_exceeded<T>(call_result: Result<T, Trap>) {
+    assert!(matches!(
+        call_result.map(|_| ()).unwrap_err().trap_code(),
+        Some(TrapCode::EpochDeadlineExceeded),
+    ));
+}
+
+#[test]
+fn default_deadline_traps_immediately() {
+    let (mut store, func) = epoch_test_setup(&tick_loop_wasm());
+    let func = func.typed::<i32, i32>(&store).unwrap();
+    assert_epoch_deadline_exceeded(func.call(&mut store, 10));
+    assert_eq!(*store.data(), 0);
+}
+
+#[test]
+fn runs_until_deadline() {
+    let (mut store, func) = epoch_test_setup(&tick_loop_wasm());
+    let func = func.typed::<i32, i32>(&store).unwrap();
+    store.set_epoch_deadline(5);
+    assert_eq!(func.call(&mut store, 3).unwrap(), 3);
+    assert_eq!(*store.data(), 3);
+    // The loop header observes the epoch incremented by the 5th tick.
+    assert_epoch_deadline_exceeded(func.call(&mut store, 10));
+    assert_eq!(*store.data(), 5);
+    // Moving the deadline allows further executions.
+    store.set_epoch_deadline(10);
+    assert_eq!(func.call(&mut store, 9).unwrap(), 9);
+    assert_eq!(*store.data(), 14);
+}
+
+#[test]
+fn interrupt_infinite_loop_from_thread() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (export "test")
+                (loop $continue
+                    (br $continue)
+                )
+            )
+        )
+    "#,
+    );
+    let (mut store, func) = epoch_test_setup(&wasm);
+    store.set_epoch_deadline(1);
+    let engine = store.engine().clone();
+    let handle = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        engine.increment_epoch();
+    });
+    let func = func.typed::<(), ()>(&store).unwrap();
+    assert_epoch_deadline_exceeded(func.call(&mut store, ()));
+    handle.join().unwrap();
+}
+
+#[test]
+fn yield_and_resume() {
+    let (mut store, func) = epoch_test_setup(&tick_loop_wasm());
+    store.set_epoch_deadline(1);
+    store.epoch_deadline_yield_and_update(1);
+    let mut results = [Value::I32(0)];
+    let mut call = func
+        .call_resumable(&mut store, &[Value::I32(5)], &mut results)
+        .unwrap();
+    let mut yields = 0;
+    loop {
+        match call {
+            ResumableCall::Finished => break,
+            ResumableCall::EpochYield(invocation) => {
+                yields += 1;
+                call = invocation.resume(&mut store, &mut results).unwrap();
+            }
+            ResumableCall::Resumable(_) => panic!("unexpected host trap"),
+        }
+    }
+    assert_eq!(yields, 5);
+    assert_eq!(results[0].i32(), Some(5));
+    assert_eq!(*store.data(), 5);
+}
+
+#[test]
+fn typed_yield_and_resume() {
+    let (mut store, func) = epoch_test_setup(&tick_loop_wasm());
+    store.set_epoch_deadline(1);
+    store.epoch_deadline_yield_and_update(2);
+    let func = func.typed::<i32, i32>(&store).unwrap();
+    let mut call = func.call_resumable(&mut store, 6).unwrap();
+    let mut yields = 0;
+    let result = loop {
+        match call {
+            TypedResumableCall::Finished(result) => break result,
+            TypedResumableCall::EpochYield(invocation) => {
+                yields += 1;
+                call = invocation.resume(&mut store).unwrap();
+            }
+            TypedResumableCall::Resumable(_) => panic!("unexpected host trap"),
+        }
+    };
+    assert_eq!(yields, 3);
+    assert_eq!(result, 6);
+}
+
+#[test]
+fn yield_traps_for_non_resumable_calls() {
+    let (mut store, func) = epoch_test_setup(&tick_loop_wasm());
+    store.set_epoch_deadline(1);
+    store.epoch_deadline_yield_and_update(1);
+    let func = func.typed::<i32, i32>(&store).unwrap();
+    assert_epoch_deadline_exceeded(func.call(&mut store, 5));
+    assert_eq!(*store.data(), 1);
+}
@@ -0,0 +1,302 @@
+//! Tests to check if wasmi's support for the Wasm `exception-handling` proposal works as intended.
+
+use wasmi::{
+    core::{Trap, ValueType},
+    Caller,
+    Config,
+    Engine,
+    Exception,
+    Func,
+    Instance,
+    Linker,
+    Module,
+    Store,
+    Tag,
+    TagType,
+    Value,
+};
+
+/// Converts the `wat` string source into `wasm` encoded byte.
+fn wat2wasm(wat: &str) -> Vec<u8> {
+    wat::parse_str(wat).unwrap()
+}
+
+/// Creates an [`Engine`] with the `exception-handling` Wasm proposal enabled.
+fn test_engine() -> Engine {
+    let mut config = Config::default();
+    config.wasm_exceptions(true);
+    Engine::new(&config)
+}
+
+/// Instantiates the `wasm` module using the `linker` and returns the [`Instance`].
+fn instantiate(store: &mut Store<()>, linker: &Linker<()>, wasm: &[u8]) -> Instance {
+    let module = Module::new(store.engine(), wasm).unwrap();
+    linker
+        .instantiate(&mut *store, &module)
+        .unwrap()
+        .start(&mut *store)
+        .unwrap()
+}
+
+/// Instantiates the `wasm` module and returns its exported `test` function.
+fn default_test_setup(wasm: &[u8]) -> (Store<()>, Instance, Func) {
+    let engine = test_engine();
+    let mut store = Store::new(&engine, ());
+    let linker = <Linker<()>>::new(&engine);
+    let instance = instantiate(&mut store, &linker, wasm);
+    let func = instance.get_func(&store, "test").unwrap();
+    (store, instance, func)
+}
+
+/// Calls `func` with the `i32` parameter and returns its `i32` result.
+fn call_i32(store: &mut Store<()>, func: Func, input: i32) -> Result<i32, Trap> {
+    func.typed::<i32, i32>(&*store)
+        .unwrap()
+        .call(&mut *store, input)
+}
+
+#[test]
+fn exceptions_are_disabled_by_default() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (tag (param i32))
+        )
+    "#,
+    );
+    let engine = Engine::default();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+fn throw_and_catch() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (tag $e0 (param i32))
+            (tag $e1 (param i32 i32))
+            (func (export "test") (param i32) (result i32)
+                try (result i32)
+                    (if (i32.eqz (local.get 0))
+                        (then (throw $e0 (i32.const 10)))
+                    )
+                    (throw $e1 (local.get 0) (i32.const 20))
+                catch $e0
+                    (i32.add (i32.const 1))
+                catch $e1
+                    (i32.add)
+                end
+            )
+        )
+    "#,
+    );
+    let (mut store, _instance, func) = default_test_setup(&wasm);
+    assert_eq!(call_i32(&mut store, func, 0).unwrap(), 11);
+    assert_eq!(call_i32(&mut store, func, 5).unwrap(), 25);
+}
+
+#[test]
+fn catch_all_unwinds_frames() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (tag $e)
+            (func $thrower (param i32) (result i32)
+                (local i64)
+                (if (local.get 0)
+                    (then (throw $e))
+                )
+                (local.get 0)
+            )
+            (func $middle (param i32) (result i32)
+                (i32.add
+                    (i32.const 100)
+                    (call $thrower (local.get 0))
+                )
+            )
+            (func (export "test") (param i32) (result i32)
+                (local $x i32)
+                (local.set $x (i32.const 7))
+                (local.get $x)
+                try (result i32)
+                    (call $middle (local.get 0))
+                catch_all
+                    (i32.const -1)
+                end
+                (i32.add)
+            )
+        )
+    "#,
+    );
+    let (mut store, _instance, func) = default_test_setup(&wasm);
+    assert_eq!(call_i32(&mut store, func, 0).unwrap(), 107);
+    assert_eq!(call_i32(&mut store, func, 1).unwrap(), 6);
+    // The stacks must be in a proper state after an exception has been caught.
+    assert_eq!(call_i32(&mut store, func, 0).unwrap(), 107);
+}
+
+#[test]
+fn branches_out_of_catch_blocks() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (tag $e (param i32))
+            (func (export "test") (param i32) (result i32)
+                (block $exit (result i32)
+                    try (result i32)
+                        (throw $e (local.get 0))
+                    catch $e
+                        (if (i32.eqz)
+                            (then (br $exit (i32.const 1)))
+                        )
+                        (i32.const 2)
+                        (br 0)
+                    end
+                    (i32.add (i32.const 10))
+                )
+            )
+        )
+    "#,
+    );
+    let (mut store, _instance, func) = default_test_setup(&wasm);
+    assert_eq!(call_i32(&mut store, func, 0).unwrap(), 1);
+    assert_eq!(call_i32(&mut store, func, 1).unwrap(), 12);
+}
+
+#[test]
+fn rethrow() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (tag $e (param i32))
+            (func (export "test") (param i32) (result i32)
+                try (result i32)
+                    try
+                        (throw $e (local.get 0))
+                    catch $e
+                        (drop)
+                        try
+                            (throw $e (i32.const 0))
+                        catch_all
+                        end
+                        (rethrow 0)
+                    end
+                    (i32.const 0)
+                catch $e
+                    (i32.add (i32.const 1))
+                end
+            )
+        )
+    "#,
+    );
+    let (mut store, _instance, func) = default_test_setup(&wasm);
+    assert_eq!(call_i32(&mut store, func, 41).unwrap(), 42);
+}
+
+#[test]
+fn delegate() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (tag $e (param i32))
+            (func (export "test") (param i32) (result i32)
+                try (result i32)
+                    try (result i32)
+                        try (result i32)
+                            (throw $e (local.get 0))
+                        delegate 1
+                    catch $e
+                        (drop)
+                        (i32.const -1)
+                    end
+                catch $e
+                    (i32.add (i32.const 1))
+                end
+            )
+        )
+    "#,
+    );
+    let (mut store, _instance, func) = default_test_setup(&wasm);
+    assert_eq!(call_i32(&mut store, func, 1).unwrap(), 2);
+}
+
+#[test]
+fn uncaught_exception_is_returned_to_host() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (tag $e (export "e") (param i32 i64))
+            (tag $other)
+            (func $thrower
+                (throw $e (i32.const 1) (i64.const 2))
+            )
+            (func (export "test") (param i32) (result i32)
+                try
+                    (call $thrower)
+                catch $other
+                end
+                (local.get 0)
+            )
+        )
+    "#,
+    );
+    let (mut store, instance, func) = default_test_setup(&wasm);
+    let error = call_i32(&mut store, func, 0).unwrap_err();
+    let exception = error.downcast_ref::<Exception>().unwrap();
+    let tag = instance.get_tag(&store, "e").unwrap();
+    assert!(exception.tag().same(&tag));
+    assert_eq!(
+        tag.ty(&store),
+        TagType::new([ValueType::I32, ValueType::I64])
+    );
+    assert!(matches!(exception.values(), [Value::I32(1), Value::I64(2)]));
+}
+
+#[test]
+fn host_exception_is_caught_by_wasm() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (import "env" "e" (tag $e (param i32)))
+            (import "env" "host" (func $host (param i32)))
+            (func (export "test") (param i32) (result i32)
+                try (result i32)
+                    (call $host (local.get 0))
+                    (i32.const 0)
+                catch $e
+                end
+            )
+        )
+    "#,
+    );
+    let engine = test_engine();
+    let mut store = Store::new(&engine, ());
+    let tag = Tag::new(&mut store, TagType::new([ValueType::I32]));
+    let host = Func::wrap(
+        &mut store,
+        move |caller: Caller<()>, input: i32| -> Result<(), Trap> {
+            if input != 0 {
+                let exception = Exception::new(&caller, tag, [Value::I32(input * 2)]).unwrap();
+                return Err(exception.into());
+            }
+            Ok(())
+        },
+    );
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "e", tag).unwrap();
+    linker.define("env", "host", host).unwrap();
+    let instance = instantiate(&mut store, &linker, &wasm);
+    let func = instance.get_func(&store, "test").unwrap();
+    assert_eq!(call_i32(&mut store, func, 0).unwrap(), 0);
+    assert_eq!(call_i32(&mut store, func, 5).unwrap(), 10);
+}
+
+#[test]
+fn exception_values_must_match_tag_type() {
+    let engine = test_engine();
+    let mut store = Store::new(&engine, ());
+    let tag = Tag::new(&mut store, TagType::new([ValueType::I32]));
+    assert!(Exception::new(&store, tag, [Value::I64(1)]).is_err());
+    assert!(Exception::new(&store, tag, []).is_err());
+    assert!(Exception::new(&store, tag, [Value::I32(1)]).is_ok());
+}
@@ -0,0 +1,440 @@
+//! Tests to check if linking multiple Wasm modules via the [`Linker`] works as intended.
+
+use wasmi::{
+    errors::LinkerError,
+    Caller,
+    Engine,
+    Error,
+    Global,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Value,
+};
+use wasmi_core::ValueType;
+
+/// Converts the `wat` string source into `wasm` encoded byte.
+fn wat2wasm(wat: &str) -> Vec<u8> {
+    wat::parse_str(wat).unwrap()
+}
+
+/// Compiles the `wat` string source into a [`Module`].
+fn compile(engine: &Engine, wat: &str) -> Module {
+    Module::new(engine, &wat2wasm(wat)[..]).unwrap()
+}
+
+fn test_setup() -> (Store<()>, Linker<()>) {
+    let engine = Engine::default();
+    let store = Store::new(&engine, ());
+    let linker = <Linker<()>>::new(&engine);
+    (store, linker)
+}
+
+/// A reactor module that counts the calls to its exported `inc` function.
+const COUNTER: &str = r#"
+    (module
+        (global $counter (export "counter") (mut i32) (i32.const 0))
+        (func (export "_initialize")
+            (global.set $counter (i32.const 10))
+        )
+        (func (export "inc") (result i32)
+            (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+            (global.get $counter)
+        )
+    )
+"#;
+
+/// A module that imports the exports of [`COUNTER`] from `module`.
+fn counter_user(module: &str) -> String {
+    format!(
+        r#"
+        (module
+            (import "{module}" "inc" (func $inc (result i32)))
+            (import "{module}" "counter" (global $counter (mut i32)))
+            (func (export "inc_twice") (result i32)
+                (drop (call $inc))
+                (call $inc)
+            )
+            (func (export "counter") (result i32)
+                (global.get $counter)
+            )
+        )
+    "#
+    )
+}
+
+#[test]
+fn linker_instance_works() {
+    let (mut store, mut linker) = test_setup();
+    let counter = compile(store.engine(), COUNTER);
+    let counter = linker
+        .instantiate(&mut store, &counter)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    linker.instance(&store, "counter", counter).unwrap();
+    let user = compile(store.engine(), &counter_user("counter"));
+    let user = linker
+        .instantiate(&mut store, &user)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let inc_twice = user.get_typed_func::<(), i32>(&store, "inc_twice").unwrap();
+    assert_eq!(inc_twice.call(&mut store, ()).unwrap(), 2);
+    assert_eq!(inc_twice.call(&mut store, ()).unwrap(), 4);
+    // Defining the same instance twice under the same name must fail.
+    assert!(matches!(
+        linker.instance(&store, "counter", counter),
+        Err(LinkerError::DuplicateDefinition { .. })
+    ));
+}
+
+#[test]
+fn linker_module_reactor_works() {
+    let (mut store, mut linker) = test_setup();
+    let counter = compile(store.engine(), COUNTER);
+    linker.module(&mut store, "counter", &counter).unwrap();
+    let user = compile(store.engine(), &counter_user("counter"));
+    let user = linker
+        .instantiate(&mut store, &user)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let inc_twice = user.get_typed_func::<(), i32>(&store, "inc_twice").unwrap();
+    let counter = user.get_typed_func::<(), i32>(&store, "counter").unwrap();
+    // The reactor has been initialized and its state is shared between calls.
+    assert_eq!(counter.call(&mut store, ()).unwrap(), 10);
+    assert_eq!(inc_twice.call(&mut store, ()).unwrap(), 12);
+    assert_eq!(inc_twice.call(&mut store, ()).unwrap(), 14);
+    assert_eq!(counter.call(&mut store, ()).unwrap(), 14);
+}
+
+#[test]
+fn linker_module_command_works() {
+    let (mut store, mut linker) = test_setup();
+    let command = compile(
+        store.engine(),
+        r#"
+        (module
+            (global $counter (export "counter") (mut i32) (i32.const 0))
+            (func (export "_start"))
+            (func (export "_initialize")
+                (global.set $counter (i32.const 10))
+            )
+            (func (export "inc") (result i32)
+                (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+                (global.get $counter)
+            )
+        )
+    "#,
+    );
+    linker.module(&mut store, "command", &command).unwrap();
+    // Only functions of commands are defined except for `_initialize`.
+    assert!(linker.get(&store, "command", "counter").is_none());
+    let user = compile(
+        store.engine(),
+        r#"
+        (module
+            (import "command" "inc" (func $inc (result i32)))
+            (import "command" "_start" (func $start))
+            (func (export "inc_twice") (result i32)
+                (call $start)
+                (drop (call $inc))
+                (call $inc)
+            )
+        )
+    "#,
+    );
+    let user = linker
+        .instantiate(&mut store, &user)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let inc_twice = user.get_typed_func::<(), i32>(&store, "inc_twice").unwrap();
+    // Every call into the command uses a freshly initialized instance.
+    assert_eq!(inc_twice.call(&mut store, ()).unwrap(), 11);
+    assert_eq!(inc_twice.call(&mut store, ()).unwrap(), 11);
+}
+
+#[test]
+fn linker_module_reactor_initialize_trap_fails() {
+    let (mut store, mut linker) = test_setup();
+    let reactor = compile(
+        store.engine(),
+        r#"
+        (module
+            (func (export "_initialize")
+                (unreachable)
+            )
+        )
+    "#,
+    );
+    assert!(matches!(
+        linker.module(&mut store, "reactor", &reactor),
+        Err(Error::Trap(_))
+    ));
+    assert!(linker.get(&store, "reactor", "_initialize").is_none());
+}
+
+#[test]
+fn linker_alias_works() {
+    let (mut store, mut linker) = test_setup();
+    let counter = compile(store.engine(), COUNTER);
+    linker.module(&mut store, "counter", &counter).unwrap();
+    linker
+        .alias("counter", "inc", "alias", "inc")
+        .unwrap()
+        .alias("counter", "counter", "alias", "counter")
+        .unwrap();
+    let user = compile(store.engine(), &counter_user("alias"));
+    let user = linker
+        .instantiate(&mut store, &user)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let inc_twice = user.get_typed_func::<(), i32>(&store, "inc_twice").unwrap();
+    assert_eq!(inc_twice.call(&mut store, ()).unwrap(), 12);
+    assert!(matches!(
+        linker.alias("counter", "missing", "alias", "missing"),
+        Err(LinkerError::UnknownDefinition { .. })
+    ));
+    assert!(matches!(
+        linker.alias("counter", "inc", "alias", "inc"),
+        Err(LinkerError::DuplicateDefinition { .. })
+    ));
+}
+
+#[test]
+fn linker_alias_module_works() {
+    let (mut store, mut linker) = test_setup();
+    linker
+        .func_wrap("host", "forty_two", || 42_i32)
+        .unwrap()
+        .alias_module("host", "env")
+        .unwrap();
+    assert!(matches!(
+        linker.alias_module("missing", "env"),
+        Err(LinkerError::UnknownDefinition { name: None, .. })
+    ));
+    let user = compile(
+        store.engine(),
+        r#"
+        (module
+            (import "env" "forty_two" (func $forty_two (result i32)))
+            (func (export "test") (result i32)
+                (call $forty_two)
+            )
+        )
+    "#,
+    );
+    let user = linker
+        .instantiate(&mut store, &user)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let test = user.get_typed_func::<(), i32>(&store, "test").unwrap();
+    assert_eq!(test.call(&mut store, ()).unwrap(), 42);
+    assert!(matches!(
+        linker.alias_module("host", "env"),
+        Err(LinkerError::DuplicateDefinition { .. })
+    ));
+}
+
+#[test]
+fn linker_allow_shadowing_works() {
+    let (mut store, mut linker) = test_setup();
+    linker.func_wrap("host", "value", || 1_i32).unwrap();
+    assert!(matches!(
+        linker.func_wrap("host", "value", || 2_i32),
+        Err(LinkerError::DuplicateDefinition { .. })
+    ));
+    linker
+        .allow_shadowing(true)
+        .func_wrap("host", "value", || 3_i32)
+        .unwrap();
+    let user = compile(
+        store.engine(),
+        r#"
+        (module
+            (import "host" "value" (func $value (result i32)))
+            (func (export "test") (result i32)
+                (call $value)
+            )
+        )
+    "#,
+    );
+    let user = linker
+        .instantiate(&mut store, &user)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let test = user.get_typed_func::<(), i32>(&store, "test").unwrap();
+    assert_eq!(test.call(&mut store, ()).unwrap(), 3);
+}
+
+/// A module with imports that are partially defined by [`unknown_imports_setup`].
+const UNKNOWN_IMPORTS: &str = r#"
+    (module
+        (import "host" "known" (func $known (result i32)))
+        (import "host" "unknown" (func $unknown (param i32) (result i32 i64 f32 externref)))
+        (func (export "call_known") (result i32)
+            (call $known)
+        )
+        (func (export "call_unknown") (result i32 i64 f32 externref)
+            (call $unknown (i32.const 42))
+        )
+    )
+"#;
+
+fn unknown_imports_setup(
+    define: impl FnOnce(&mut Linker<()>, &Module),
+) -> (Store<()>, wasmi::Instance) {
+    let (mut store, mut linker) = test_setup();
+    linker.func_wrap("host", "known", || 1_i32).unwrap();
+    let module = compile(store.engine(), UNKNOWN_IMPORTS);
+    assert!(linker.instantiate(&mut store, &module).is_err());
+    define(&mut linker, &module);
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let call_known = instance
+        .get_typed_func::<(), i32>(&store, "call_known")
+        .unwrap();
+    assert_eq!(call_known.call(&mut store, ()).unwrap(), 1);
+    (store, instance)
+}
+
+#[test]
+fn linker_define_unknown_imports_as_traps_works() {
+    let (mut store, instance) = unknown_imports_setup(|linker, module| {
+        linker.define_unknown_imports_as_traps(module);
+    });
+    let call_unknown = instance.get_func(&store, "call_unknown").unwrap();
+    let mut results = [
+        Value::I32(0),
+        Value::I64(0),
+        Value::F32(0.0.into()),
+        Value::default(ValueType::ExternRef),
+    ];
+    let error = call_unknown
+        .call(&mut store, &[], &mut results)
+        .unwrap_err();
+    assert!(error.to_string().contains("host::unknown"), "{error}");
+}
+
+#[test]
+fn linker_define_unknown_imports_as_default_values_works() {
+    let (mut store, instance) = unknown_imports_setup(|linker, module| {
+        linker.define_unknown_imports_as_default_values(module);
+    });
+    let call_unknown = instance.get_func(&store, "call_unknown").unwrap();
+    let mut results = [
+        Value::I32(1),
+        Value::I64(1),
+        Value::F32(1.0.into()),
+        Value::default(ValueType::ExternRef),
+    ];
+    call_unknown.call(&mut store, &[], &mut results).unwrap();
+    assert_eq!(results[0].i32(), Some(0));
+    assert_eq!(results[1].i64(), Some(0));
+    assert_eq!(results[2].f32(), Some(0.0.into()));
+    assert!(results[3].externref().unwrap().is_null());
+}
+
+#[test]
+fn linker_instantiate_pre_works() {
+    let engine = Engine::default();
+    let mut linker = <Linker<i32>>::new(&engine);
+    linker
+        .func_wrap("host", "data", |caller: Caller<i32>| *caller.data())
+        .unwrap();
+    let module = compile(
+        &engine,
+        r#"
+        (module
+            (import "host" "data" (func $data (result i32)))
+            (func (export "test") (result i32)
+                (call $data)
+            )
+        )
+    "#,
+    );
+    let linked = linker.instantiate_pre(&module).unwrap();
+    // Later definitions of the `Linker` do not affect the `LinkedModule`.
+    linker
+        .allow_shadowing(true)
+        .func_wrap("host", "data", || -1_i32)
+        .unwrap();
+    for data in 0..3 {
+        let mut store = Store::new(&engine, data);
+        let instance = linked
+            .clone()
+            .instantiate(&mut store)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let test = instance.get_typed_func::<(), i32>(&store, "test").unwrap();
+        assert_eq!(test.call(&mut store, ()).unwrap(), data);
+    }
+}
+
+#[test]
+fn linker_instantiate_pre_with_externs_works() {
+    let (mut store, mut linker) = test_setup();
+    let global = Global::new(&mut store, Value::I32(42), Mutability::Const);
+    linker.define("env", "global", global).unwrap();
+    let module = compile(
+        store.engine(),
+        r#"
+        (module
+            (import "env" "global" (global $global i32))
+            (func (export "test") (result i32)
+                (global.get $global)
+            )
+        )
+    "#,
+    );
+    let linked = linker.instantiate_pre(&module).unwrap();
+    for _ in 0..2 {
+        let instance = linked
+            .instantiate(&mut store)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let test = instance.get_typed_func::<(), i32>(&store, "test").unwrap();
+        assert_eq!(test.call(&mut store, ()).unwrap(), 42);
+    }
+}
+
+#[test]
+fn linker_instantiate_pre_fails() {
+    let (store, mut linker) = test_setup();
+    linker.func_wrap("host", "func", || 1_i64).unwrap();
+    let missing = compile(
+        store.engine(),
+        r#"(module (import "host" "missing" (func)))"#,
+    );
+    assert!(matches!(
+        linker.instantiate_pre(&missing),
+        Err(LinkerError::MissingDefinition { .. })
+    ));
+    let mismatch = compile(
+        store.engine(),
+        r#"(module (import "host" "func" (func (result i32))))"#,
+    );
+    assert!(matches!(
+        linker.instantiate_pre(&mismatch),
+        Err(LinkerError::FuncTypeMismatch { .. })
+    ));
+    let invalid = compile(
+        store.engine(),
+        r#"(module (import "host" "func" (global i32)))"#,
+    );
+    assert!(matches!(
+        linker.instantiate_pre(&invalid),
+        Err(LinkerError::InvalidTypeDefinition { .. })
+    ));
+}
@@ -0,0 +1,43 @@
+//! Tests to check if wasmi's support for the Wasm `memory64` proposal works as intended.
+
+use super::{instantiate, wat2wasm};
+use wasmi::{core::Pages, Config};
+
+/// Returns a [`Config`] with the `memory64` Wasm proposal enabled.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.wasm_memory64(true);
+    config
+}
+
+#[test]
+fn exported_memory() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory (export "memory") i64 1 3)
+            (func (export "test") (param i64) (result i64 i32)
+                (memory.size)
+                (i32.load (local.get 0))
+            )
+        )
+    "#,
+    );
+    let (mut store, instance) = instantiate(&config(), &wasm);
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    let ty = memory.ty(&store);
+    assert!(ty.is_64());
+    assert_eq!(u64::from(ty.initial_pages()), 1);
+    assert_eq!(ty.maximum_pages().map(u64::from), Some(3));
+    let old_pages = memory.grow(&mut store, Pages::from(1_u16)).unwrap();
+    assert_eq!(u64::from(old_pages), 1);
+    assert!(memory.grow(&mut store, Pages::from(2_u16)).is_err());
+    memory
+        .write(&mut store, 0x1_0000, &42_i32.to_le_bytes())
+        .unwrap();
+    let func = instance
+        .get_typed_func::<i64, (i64, i32)>(&store, "test")
+        .unwrap();
+    assert_eq!(func.call(&mut store, 0x1_0000).unwrap(), (2, 42));
+    assert!(func.call(&mut store, 0x2_0000).is_err());
+}
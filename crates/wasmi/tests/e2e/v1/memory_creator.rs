@@ -0,0 +1,130 @@
+//! Tests to check if custom linear memory creators work as intended.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use wasmi::{
+    errors::MemoryError,
+    Engine,
+    LinearMemory,
+    Linker,
+    Memory,
+    MemoryCreator,
+    MemoryType,
+    Module,
+    Store,
+};
+use wasmi_core::Pages;
+
+/// A [`LinearMemory`] with a fixed capacity that never reallocates.
+struct FixedMemory {
+    bytes: Box<[u8]>,
+    len: usize,
+}
+
+impl LinearMemory for FixedMemory {
+    fn data(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        if new_len > self.bytes.len() {
+            return Err(MemoryError::OutOfBoundsAllocation);
+        }
+        self.len = new_len;
+        Ok(())
+    }
+
+    fn reset(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        self.bytes.fill(0x00);
+        self.len = new_len;
+        Ok(())
+    }
+}
+
+/// A [`MemoryCreator`] creating [`FixedMemory`] instances with `capacity` bytes.
+struct FixedMemoryCreator {
+    capacity: usize,
+    created: AtomicUsize,
+}
+
+impl FixedMemoryCreator {
+    fn new(capacity: usize) -> Arc<Self> {
+        Arc::new(Self {
+            capacity,
+            created: AtomicUsize::new(0),
+        })
+    }
+}
+
+impl MemoryCreator for FixedMemoryCreator {
+    fn new_memory(
+        &self,
+        _ty: MemoryType,
+        initial_len: usize,
+        _maximum_len: Option<usize>,
+    ) -> Result<Box<dyn LinearMemory>, MemoryError> {
+        if initial_len > self.capacity {
+            return Err(MemoryError::OutOfBoundsAllocation);
+        }
+        self.created.fetch_add(1, Ordering::SeqCst);
+        Ok(Box::new(FixedMemory {
+            bytes: vec![0x00; self.capacity].into(),
+            len: initial_len,
+        }))
+    }
+}
+
+#[test]
+fn memory_creator_works() {
+    let engine = Engine::default();
+    let creator = FixedMemoryCreator::new(2 * 0x1_0000);
+    let mut store = Store::new(&engine, ());
+    store.memory_creator(creator.clone());
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (memory (export "memory") 1)
+            (data (i32.const 0) "\01\02")
+            (func (export "memory_grow") (param $delta i32) (result i32)
+                (memory.grow (local.get $delta))
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    assert_eq!(creator.created.load(Ordering::SeqCst), 1);
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    assert_eq!(memory.data(&store)[..3], [1, 2, 0]);
+    let memory_grow = instance
+        .get_typed_func::<i32, i32>(&store, "memory_grow")
+        .unwrap();
+    assert_eq!(memory_grow.call(&mut store, 1).unwrap(), 1);
+    assert_eq!(memory.data(&store).len(), 2 * 0x1_0000);
+    // The `FixedMemory` cannot grow beyond its capacity.
+    assert_eq!(memory_grow.call(&mut store, 1).unwrap(), -1);
+    assert_eq!(memory.current_pages(&store), Pages::from(2));
+}
+
+#[test]
+fn memory_creator_fails() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    store.memory_creator(FixedMemoryCreator::new(0x1_0000));
+    assert!(Memory::new(&mut store, MemoryType::new(1, None).unwrap()).is_ok());
+    assert!(matches!(
+        Memory::new(&mut store, MemoryType::new(2, None).unwrap()),
+        Err(MemoryError::OutOfBoundsAllocation)
+    ));
+}
@@ -1,5 +1,122 @@
+mod address_map;
+mod backtrace;
+mod call_async;
+mod compilation_mode;
+mod custom_sections;
+#[cfg(feature = "dwarf")]
+mod dwarf;
+mod epoch_interruption;
+mod exceptions;
 mod fuel_consumption_mode;
 mod fuel_metering;
 mod func;
 mod host_calls_wasm;
+mod linker;
+mod memory64;
+mod memory_creator;
+mod multi_memory;
+mod pooling_allocator;
+mod remove_instance;
+mod resource_limiter;
 mod resumable_call;
+mod serialize;
+#[cfg(feature = "simd")]
+mod simd;
+mod snapshot;
+mod threads;
+#[cfg(feature = "trace")]
+mod trace;
+
+use wasmi::{core::Trap, Config, Engine, Func, Instance, Linker, Module, Store};
+
+/// Converts the `wat` string source into `wasm` encoded byte.
+fn wat2wasm(wat: &str) -> Vec<u8> {
+    wat::parse_str(wat).unwrap()
+}
+
+/// Instantiates the `module` in the `store` using the definitions of the `linker`.
+fn instantiate_module<T>(store: &mut Store<T>, linker: &Linker<T>, module: &Module) -> Instance {
+    linker
+        .instantiate(&mut *store, module)
+        .unwrap()
+        .start(&mut *store)
+        .unwrap()
+}
+
+/// Instantiates the `wasm` module in the `store` using the definitions of the `linker`.
+fn instantiate_with<T>(store: &mut Store<T>, linker: &Linker<T>, wasm: &[u8]) -> Instance {
+    let module = Module::new(store.engine(), wasm).unwrap();
+    instantiate_module(store, linker, &module)
+}
+
+/// Instantiates the `wasm` module with an [`Engine`] using the `config`.
+fn instantiate(config: &Config, wasm: &[u8]) -> (Store<()>, Instance) {
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let instance = instantiate_with(&mut store, &<Linker<()>>::new(&engine), wasm);
+    (store, instance)
+}
+
+/// Instantiates the `wasm` module with an [`Engine`] using the `config`
+/// and returns its exported `test` function.
+fn test_setup(config: &Config, wasm: &[u8]) -> (Store<()>, Func) {
+    let (store, instance) = instantiate(config, wasm);
+    let func = instance.get_func(&store, "test").unwrap();
+    (store, func)
+}
+
+/// Calls the exported `outer` function of the `module` and returns its [`Trap`].
+fn call_outer(module: &Module) -> Trap {
+    let mut store = Store::new(module.engine(), ());
+    let instance = instantiate_module(&mut store, &<Linker<()>>::new(module.engine()), module);
+    instance
+        .get_typed_func::<(), ()>(&store, "outer")
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap_err()
+}
+
+/// Appends a custom section with the given `name` and `data` to the `wasm` binary.
+fn append_custom_section(wasm: &mut Vec<u8>, name: &str, data: &[u8]) {
+    let mut contents = Vec::new();
+    leb128_write(&mut contents, name.len());
+    contents.extend_from_slice(name.as_bytes());
+    contents.extend_from_slice(data);
+    wasm.push(0x00);
+    leb128_write(wasm, contents.len());
+    wasm.extend_from_slice(&contents);
+}
+
+/// Appends the LEB128 encoding of `value` to `bytes`.
+fn leb128_write(bytes: &mut Vec<u8>, mut value: usize) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+#[test]
+fn proposals_are_disabled_by_default() {
+    let modules = [
+        (
+            "simd",
+            "(module (func (result v128) (v128.const i32x4 0 0 0 0)))",
+        ),
+        ("multi-memory", "(module (memory 1) (memory 1))"),
+        ("memory64", "(module (memory i64 1))"),
+        ("threads", "(module (memory 1 1 shared))"),
+    ];
+    let engine = Engine::default();
+    for (proposal, wat) in modules {
+        let wasm = wat2wasm(wat);
+        assert!(
+            Module::new(&engine, &wasm[..]).is_err(),
+            "the `{proposal}` proposal is enabled by default"
+        );
+    }
+}
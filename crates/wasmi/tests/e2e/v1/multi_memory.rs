@@ -0,0 +1,40 @@
+//! Tests to check if wasmi's support for the Wasm `multi-memory` proposal works as intended.
+
+use super::{instantiate, wat2wasm};
+use wasmi::Config;
+
+/// Returns a [`Config`] with the `multi-memory` Wasm proposal enabled.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.wasm_multi_memory(true);
+    config
+}
+
+#[test]
+fn exported_memories() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 (export "m0") 1)
+            (memory $m1 (export "m1") 2)
+            (func (export "test") (result i32)
+                (i32.store $m0 (i32.const 8) (i32.const 100))
+                (i32.load $m1 (i32.const 8))
+            )
+        )
+    "#,
+    );
+    let (mut store, instance) = instantiate(&config(), &wasm);
+    let m0 = instance.get_memory(&store, "m0").unwrap();
+    let m1 = instance.get_memory(&store, "m1").unwrap();
+    assert_eq!(m0.data(&store).len(), 0x1_0000);
+    assert_eq!(m1.data(&store).len(), 0x2_0000);
+    m1.write(&mut store, 8, &42_i32.to_le_bytes()).unwrap();
+    let func = instance.get_typed_func::<(), i32>(&store, "test").unwrap();
+    assert_eq!(func.call(&mut store, ()).unwrap(), 42);
+    let mut buffer = [0x00; 4];
+    m0.read(&store, 8, &mut buffer).unwrap();
+    assert_eq!(i32::from_le_bytes(buffer), 100);
+    m1.read(&store, 8, &mut buffer).unwrap();
+    assert_eq!(i32::from_le_bytes(buffer), 42);
+}
@@ -0,0 +1,123 @@
+//! Tests to check if the pooling allocator works as intended.
+
+use wasmi::{
+    errors::InstantiationError,
+    Engine,
+    Error,
+    Instance,
+    Linker,
+    Module,
+    PoolingAllocationConfig,
+    PoolingAllocator,
+    Store,
+};
+
+/// Returns the Wasm module used by the pooling allocator tests.
+fn test_module(engine: &Engine) -> Module {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (memory (export "memory") 1)
+            (table 1 funcref)
+            (func (export "memory_grow") (param $delta i32) (result i32)
+                (memory.grow (local.get $delta))
+            )
+            (func (export "table_grow") (param $delta i32) (result i32)
+                (table.grow (ref.null func) (local.get $delta))
+            )
+            (func (export "store") (param $value i32)
+                (i32.store (i32.const 0) (local.get $value))
+            )
+            (func (export "load") (result i32)
+                (i32.load (i32.const 0))
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    Module::new(engine, &wasm[..]).unwrap()
+}
+
+/// Creates a [`PoolingAllocator`] for at most `total` instances, linear memories and tables.
+fn test_pool(total: u32) -> PoolingAllocator {
+    let mut config = PoolingAllocationConfig::default();
+    config
+        .total_instances(total)
+        .total_memories(total)
+        .total_tables(total)
+        .max_memory_size(2 * 0x1_0000)
+        .table_elements(2);
+    PoolingAllocator::new(&config)
+}
+
+/// Instantiates the [`test_module`] in a new [`Store`] using the `pool`.
+fn instantiate(pool: &PoolingAllocator, module: &Module) -> Result<(Store<()>, Instance), Error> {
+    let mut store = Store::new(module.engine(), ());
+    store.pooling_allocator(pool.clone());
+    let instance = <Linker<()>>::new(module.engine())
+        .instantiate(&mut store, module)?
+        .start(&mut store)?;
+    Ok((store, instance))
+}
+
+#[test]
+fn pooling_allocator_limits_instances() {
+    let engine = Engine::default();
+    let module = test_module(&engine);
+    let pool = test_pool(2);
+    let first = instantiate(&pool, &module).unwrap();
+    let second = instantiate(&pool, &module).unwrap();
+    let metrics = pool.metrics();
+    assert_eq!(metrics.instances_in_use(), 2);
+    assert_eq!(metrics.memories_in_use(), 2);
+    assert_eq!(metrics.tables_in_use(), 2);
+    assert!(matches!(
+        instantiate(&pool, &module),
+        Err(Error::Instantiation(InstantiationError::TooManyInstances))
+    ));
+    drop(first);
+    let metrics = pool.metrics();
+    assert_eq!(metrics.instances_in_use(), 1);
+    assert_eq!(metrics.memories_in_use(), 1);
+    assert_eq!(metrics.tables_in_use(), 1);
+    assert_eq!(metrics.memories_idle(), 1);
+    assert_eq!(metrics.tables_idle(), 1);
+    let _third = instantiate(&pool, &module).unwrap();
+    assert_eq!(pool.metrics().instances_in_use(), 2);
+    assert_eq!(pool.metrics().memories_idle(), 0);
+    drop(second);
+}
+
+#[test]
+fn pooling_allocator_reuses_zeroed_memories() {
+    let engine = Engine::default();
+    let module = test_module(&engine);
+    let pool = test_pool(1);
+    for _ in 0..3 {
+        let (mut store, instance) = instantiate(&pool, &module).unwrap();
+        let load = instance.get_typed_func::<(), i32>(&store, "load").unwrap();
+        let store_fn = instance.get_typed_func::<i32, ()>(&store, "store").unwrap();
+        assert_eq!(load.call(&mut store, ()).unwrap(), 0);
+        store_fn.call(&mut store, 42).unwrap();
+        assert_eq!(load.call(&mut store, ()).unwrap(), 42);
+    }
+    assert_eq!(pool.metrics().memories_idle(), 1);
+}
+
+#[test]
+fn pooling_allocator_limits_growth() {
+    let engine = Engine::default();
+    let module = test_module(&engine);
+    let pool = test_pool(1);
+    let (mut store, instance) = instantiate(&pool, &module).unwrap();
+    let memory_grow = instance
+        .get_typed_func::<i32, i32>(&store, "memory_grow")
+        .unwrap();
+    let table_grow = instance
+        .get_typed_func::<i32, i32>(&store, "table_grow")
+        .unwrap();
+    assert_eq!(memory_grow.call(&mut store, 1).unwrap(), 1);
+    assert_eq!(memory_grow.call(&mut store, 1).unwrap(), -1);
+    assert_eq!(table_grow.call(&mut store, 1).unwrap(), 1);
+    assert_eq!(table_grow.call(&mut store, 1).unwrap(), -1);
+}
@@ -0,0 +1,301 @@
+//! Tests to check if removing instances from a store works as intended.
+
+use wasmi::{
+    core::ValueType,
+    errors::InstanceError,
+    Config,
+    Engine,
+    FuncRef,
+    Instance,
+    Linker,
+    Memory,
+    MemoryType,
+    Module,
+    PoolingAllocationConfig,
+    PoolingAllocator,
+    Store,
+    StoreLimits,
+    StoreLimitsBuilder,
+    Table,
+    TableType,
+    Value,
+};
+
+/// Returns the Wasm module used by the instance removal tests.
+///
+/// The module imports the `env.memory` linear memory and defines its own linear memory.
+fn test_module(engine: &Engine) -> Module {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "memory" (memory $imported 1))
+            (memory $owned (export "memory") 1)
+            (table 1 funcref)
+            (global (export "global") (mut i32) (i32.const 0))
+            (data (memory $owned) (i32.const 0) "\01")
+            (elem (i32.const 0) func $inc)
+            (func $inc (export "inc") (result i32)
+                (i32.store8 (i32.const 0)
+                    (i32.add (i32.load8_u (i32.const 0)) (i32.const 1))
+                )
+                (i32.load8_u (i32.const 0))
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    Module::new(engine, &wasm[..]).unwrap()
+}
+
+/// Creates an [`Engine`] with all Wasm proposals used by the tests enabled.
+fn test_engine() -> Engine {
+    let mut config = Config::default();
+    config.wasm_multi_memory(true);
+    Engine::new(&config)
+}
+
+/// Instantiates the [`test_module`] with `memory` as its `env.memory` import.
+fn instantiate<T>(store: &mut Store<T>, module: &Module, memory: Memory) -> Instance {
+    let mut linker = <Linker<T>>::new(module.engine());
+    linker.define("env", "memory", memory).unwrap();
+    linker
+        .instantiate(&mut *store, module)
+        .unwrap()
+        .start(&mut *store)
+        .unwrap()
+}
+
+#[test]
+fn remove_instance_frees_owned_entities() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let limits = StoreLimitsBuilder::new().instances(1).memories(2).build();
+    let mut store = <Store<StoreLimits>>::new(&engine, limits);
+    store.limiter(|limits| limits);
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    // The limits only allow a single live instance so this requires removal of instances.
+    for expected in 1..=10 {
+        let instance = instantiate(&mut store, &module, memory);
+        let inc = instance.get_typed_func::<(), i32>(&store, "inc").unwrap();
+        assert_eq!(inc.call(&mut store, ()).unwrap(), expected);
+        store.remove_instance(instance).unwrap();
+    }
+    // The imported linear memory is not owned by the removed instances.
+    assert_eq!(memory.data(&store)[0], 10);
+}
+
+#[test]
+fn remove_instance_returns_pool_slots() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let mut config = PoolingAllocationConfig::default();
+    config.total_instances(1).total_memories(1).total_tables(1);
+    let pool = PoolingAllocator::new(&config);
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    store.pooling_allocator(pool.clone());
+    for _ in 0..10 {
+        let instance = instantiate(&mut store, &module, memory);
+        assert_eq!(pool.metrics().instances_in_use(), 1);
+        assert_eq!(pool.metrics().memories_in_use(), 1);
+        assert_eq!(pool.metrics().tables_in_use(), 1);
+        store.remove_instance(instance).unwrap();
+        assert_eq!(pool.metrics().instances_in_use(), 0);
+        assert_eq!(pool.metrics().memories_in_use(), 0);
+        assert_eq!(pool.metrics().tables_in_use(), 0);
+    }
+}
+
+#[test]
+fn remove_imported_instance_fails() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    let exporter = instantiate(&mut store, &module, memory);
+    let exported = exporter.get_memory(&store, "memory").unwrap();
+    let importer = instantiate(&mut store, &module, exported);
+    assert!(matches!(
+        store.remove_instance(exporter),
+        Err(InstanceError::ImportedEntity { .. })
+    ));
+    // The exporting instance is still alive after the failed removal.
+    let inc = exporter.get_typed_func::<(), i32>(&store, "inc").unwrap();
+    assert_eq!(inc.call(&mut store, ()).unwrap(), 1);
+    store.remove_instance(importer).unwrap();
+    store.remove_instance(exporter).unwrap();
+}
+
+#[test]
+fn remove_referenced_instance_fails() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    let instance = instantiate(&mut store, &module, memory);
+    let inc = instance.get_func(&store, "inc").unwrap();
+    let table = Table::new(
+        &mut store,
+        TableType::new(ValueType::FuncRef, 1, None),
+        Value::from(FuncRef::new(inc)),
+    )
+    .unwrap();
+    assert!(matches!(
+        store.remove_instance(instance),
+        Err(InstanceError::ReferencedFunc { .. })
+    ));
+    table
+        .set(&mut store, 0, Value::from(FuncRef::null()))
+        .unwrap();
+    store.remove_instance(instance).unwrap();
+}
+
+/// Returns a Wasm module that copies references of the imported `env.table` into its own entities.
+fn holder_module(engine: &Engine) -> Module {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "table" (table $shared 2 funcref))
+            (table $owned 0 funcref)
+            (global $global (mut funcref) (ref.null func))
+            (func (export "copy_within")
+                (table.copy $shared $shared (i32.const 1) (i32.const 0) (i32.const 1))
+            )
+            (func (export "copy")
+                (drop (table.grow $owned (ref.null func) (i32.const 1)))
+                (table.copy $owned $shared (i32.const 0) (i32.const 0) (i32.const 1))
+            )
+            (func (export "grow")
+                (drop (table.grow $owned (table.get $shared (i32.const 0)) (i32.const 2)))
+            )
+            (func (export "fill")
+                (table.fill $owned (i32.const 0) (table.get $shared (i32.const 0)) (table.size $owned))
+            )
+            (func (export "global")
+                (global.set $global (table.get $shared (i32.const 0)))
+            )
+            (func (export "clear")
+                (table.set $shared (i32.const 1) (ref.null func))
+                (table.fill $owned (i32.const 0) (ref.null func) (table.size $owned))
+                (global.set $global (ref.null func))
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    Module::new(engine, &wasm[..]).unwrap()
+}
+
+/// Instantiates the [`holder_module`] with `table` as its `env.table` import.
+fn instantiate_holder(store: &mut Store<()>, table: Table) -> Instance {
+    let module = holder_module(store.engine());
+    let mut linker = <Linker<()>>::new(store.engine());
+    linker.define("env", "table", table).unwrap();
+    linker
+        .instantiate(&mut *store, &module)
+        .unwrap()
+        .start(&mut *store)
+        .unwrap()
+}
+
+#[test]
+fn remove_instance_referenced_by_wasm_fails() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    let instance = instantiate(&mut store, &module, memory);
+    let inc = Value::from(FuncRef::new(instance.get_func(&store, "inc").unwrap()));
+    let null = Value::from(FuncRef::null());
+    let table = Table::new(
+        &mut store,
+        TableType::new(ValueType::FuncRef, 2, None),
+        null.clone(),
+    )
+    .unwrap();
+    let holder = instantiate_holder(&mut store, table);
+    let clear = holder.get_typed_func::<(), ()>(&store, "clear").unwrap();
+    for name in ["copy_within", "copy", "grow", "fill", "global"] {
+        table.set(&mut store, 0, inc.clone()).unwrap();
+        let func = holder.get_typed_func::<(), ()>(&store, name).unwrap();
+        func.call(&mut store, ()).unwrap();
+        table.set(&mut store, 0, null.clone()).unwrap();
+        assert!(
+            matches!(
+                store.remove_instance(instance),
+                Err(InstanceError::ReferencedFunc { .. })
+            ),
+            "removal must fail after `{name}`"
+        );
+        clear.call(&mut store, ()).unwrap();
+    }
+    store.remove_instance(instance).unwrap();
+}
+
+#[test]
+fn remove_instance_drops_references_of_owned_entities() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    let instance = instantiate(&mut store, &module, memory);
+    let inc = Value::from(FuncRef::new(instance.get_func(&store, "inc").unwrap()));
+    let table = Table::new(&mut store, TableType::new(ValueType::FuncRef, 2, None), inc).unwrap();
+    let holder = instantiate_holder(&mut store, table);
+    for name in ["grow", "global"] {
+        let func = holder.get_typed_func::<(), ()>(&store, name).unwrap();
+        func.call(&mut store, ()).unwrap();
+    }
+    table
+        .fill(&mut store, 0, Value::from(FuncRef::null()), 2)
+        .unwrap();
+    assert!(matches!(
+        store.remove_instance(instance),
+        Err(InstanceError::ReferencedFunc { .. })
+    ));
+    // Removing the holder drops the references held by its own table and global variable.
+    store.remove_instance(holder).unwrap();
+    store.remove_instance(instance).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "failed to resolve stored entity")]
+fn use_of_removed_export_panics_after_reuse() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    let instance = instantiate(&mut store, &module, memory);
+    let exported = instance.get_memory(&store, "memory").unwrap();
+    store.remove_instance(instance).unwrap();
+    // The new instance reuses the slots of the removed instance.
+    let instance = instantiate(&mut store, &module, memory);
+    let inc = instance.get_typed_func::<(), i32>(&store, "inc").unwrap();
+    assert_eq!(inc.call(&mut store, ()).unwrap(), 1);
+    exported.data(&store);
+}
+
+#[test]
+#[should_panic]
+fn use_of_removed_export_panics() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    let instance = instantiate(&mut store, &module, memory);
+    let exported = instance.get_memory(&store, "memory").unwrap();
+    store.remove_instance(instance).unwrap();
+    exported.data(&store);
+}
+
+#[test]
+#[should_panic]
+fn remove_removed_instance_panics() {
+    let engine = test_engine();
+    let module = test_module(&engine);
+    let mut store = Store::new(&engine, ());
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    let instance = instantiate(&mut store, &module, memory);
+    store.remove_instance(instance).unwrap();
+    let _ = store.remove_instance(instance);
+}
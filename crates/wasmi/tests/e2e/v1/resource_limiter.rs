@@ -0,0 +1,185 @@
+//! Tests to check if wasmi's resource limiter works as intended.
+
+use wasmi::{
+    errors::{InstantiationError, MemoryError, TableError},
+    Config,
+    Engine,
+    Error,
+    Linker,
+    Memory,
+    MemoryType,
+    Module,
+    Store,
+    StoreLimits,
+    StoreLimitsBuilder,
+    Table,
+    TableType,
+    TypedFunc,
+    Value,
+};
+use wasmi_core::{Pages, TrapCode, ValueType};
+
+/// The host state of the [`Store`] used in the resource limiter tests.
+struct TestHostState {
+    limits: StoreLimits,
+}
+
+/// Converts the `wat` string source into `wasm` encoded byte.
+fn wat2wasm(wat: &str) -> Vec<u8> {
+    wat::parse_str(wat).unwrap()
+}
+
+/// Creates a new [`Store`] with the given [`StoreLimits`] installed as its limiter.
+fn test_setup(limits: StoreLimits) -> Store<TestHostState> {
+    let engine = Engine::new(&Config::default());
+    let mut store = Store::new(&engine, TestHostState { limits });
+    store.limiter(|state| &mut state.limits);
+    store
+}
+
+/// Instantiates the `wasm` module within the `store`.
+fn instantiate(store: &mut Store<TestHostState>, wasm: &[u8]) -> Result<wasmi::Instance, Error> {
+    let module = Module::new(store.engine(), wasm)?;
+    let linker = <Linker<TestHostState>>::new(store.engine());
+    linker.instantiate(&mut *store, &module)?.start(&mut *store)
+}
+
+/// Returns the Wasm module bytes of a module exporting memory and table growth functions.
+fn grow_wasm() -> Vec<u8> {
+    wat2wasm(
+        r#"
+        (module
+            (memory 1)
+            (table 1 funcref)
+            (func (export "memory_grow") (param $delta i32) (result i32)
+                (memory.grow (local.get $delta))
+            )
+            (func (export "table_grow") (param $delta i32) (result i32)
+                (table.grow (ref.null func) (local.get $delta))
+            )
+        )
+    "#,
+    )
+}
+
+/// Returns the exported `memory_grow` and `table_grow` functions.
+fn grow_funcs(store: &mut Store<TestHostState>) -> (TypedFunc<i32, i32>, TypedFunc<i32, i32>) {
+    let instance = instantiate(store, &grow_wasm()).unwrap();
+    let memory_grow = instance
+        .get_typed_func::<i32, i32>(&*store, "memory_grow")
+        .unwrap();
+    let table_grow = instance
+        .get_typed_func::<i32, i32>(&*store, "table_grow")
+        .unwrap();
+    (memory_grow, table_grow)
+}
+
+#[test]
+fn wasm_grow_denied() {
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(3 * 65536)
+        .table_elements(10)
+        .build();
+    let mut store = test_setup(limits);
+    let (memory_grow, table_grow) = grow_funcs(&mut store);
+    assert_eq!(memory_grow.call(&mut store, 1).unwrap(), 1);
+    assert_eq!(memory_grow.call(&mut store, 1).unwrap(), 2);
+    assert_eq!(memory_grow.call(&mut store, 1).unwrap(), -1);
+    assert_eq!(table_grow.call(&mut store, 5).unwrap(), 1);
+    assert_eq!(table_grow.call(&mut store, 4).unwrap(), 6);
+    assert_eq!(table_grow.call(&mut store, 1).unwrap(), -1);
+}
+
+#[test]
+fn wasm_grow_trap_on_failure() {
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(2 * 65536)
+        .table_elements(2)
+        .trap_on_grow_failure(true)
+        .build();
+    let mut store = test_setup(limits);
+    let (memory_grow, table_grow) = grow_funcs(&mut store);
+    assert_eq!(memory_grow.call(&mut store, 1).unwrap(), 1);
+    assert!(matches!(
+        memory_grow.call(&mut store, 1).unwrap_err().trap_code(),
+        Some(TrapCode::GrowthOperationLimited)
+    ));
+    assert_eq!(table_grow.call(&mut store, 1).unwrap(), 1);
+    assert!(matches!(
+        table_grow.call(&mut store, 1).unwrap_err().trap_code(),
+        Some(TrapCode::GrowthOperationLimited)
+    ));
+}
+
+#[test]
+fn host_grow_denied() {
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(2 * 65536)
+        .table_elements(2)
+        .build();
+    let mut store = test_setup(limits);
+    let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    assert!(memory.grow(&mut store, Pages::new(1).unwrap()).is_ok());
+    assert!(matches!(
+        memory.grow(&mut store, Pages::new(1).unwrap()),
+        Err(MemoryError::OutOfBoundsGrowth)
+    ));
+    let table_type = TableType::new(ValueType::FuncRef, 1, None);
+    let init = Value::default(ValueType::FuncRef);
+    let table = Table::new(&mut store, table_type, init.clone()).unwrap();
+    assert!(table.grow(&mut store, 1, init.clone()).is_ok());
+    assert!(matches!(
+        table.grow(&mut store, 1, init),
+        Err(TableError::GrowOutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn initial_size_denied() {
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(65536)
+        .table_elements(1)
+        .build();
+    let mut store = test_setup(limits);
+    assert!(matches!(
+        Memory::new(&mut store, MemoryType::new(2, None).unwrap()),
+        Err(MemoryError::ResourceLimiterDeniedAllocation)
+    ));
+    let table_type = TableType::new(ValueType::FuncRef, 2, None);
+    assert!(matches!(
+        Table::new(&mut store, table_type, Value::default(ValueType::FuncRef)),
+        Err(TableError::ResourceLimiterDeniedAllocation)
+    ));
+    let wasm = wat2wasm(r#"(module (memory 2))"#);
+    assert!(matches!(
+        instantiate(&mut store, &wasm),
+        Err(Error::Instantiation(InstantiationError::Memory(
+            MemoryError::ResourceLimiterDeniedAllocation
+        )))
+    ));
+}
+
+#[test]
+fn entity_count_limits() {
+    let limits = StoreLimitsBuilder::new()
+        .instances(1)
+        .memories(1)
+        .tables(1)
+        .build();
+    let mut store = test_setup(limits);
+    let wasm = wat2wasm(r#"(module (memory 1) (table 1 funcref))"#);
+    assert!(instantiate(&mut store, &wasm).is_ok());
+    assert!(matches!(
+        instantiate(&mut store, &wasm),
+        Err(Error::Instantiation(InstantiationError::TooManyInstances))
+    ));
+    assert!(matches!(
+        Memory::new(&mut store, MemoryType::new(1, None).unwrap()),
+        Err(MemoryError::TooManyMemories)
+    ));
+    let table_type = TableType::new(ValueType::FuncRef, 1, None);
+    assert!(matches!(
+        Table::new(&mut store, table_type, Value::default(ValueType::FuncRef)),
+        Err(TableError::TooManyTables)
+    ));
+}
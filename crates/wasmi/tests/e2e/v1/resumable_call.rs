@@ -67,6 +67,7 @@ impl<Results> UnwrapResumable for Result<TypedResumableCall<Results>, Trap> {
         match self.unwrap() {
             TypedResumableCall::Resumable(invocation) => invocation,
             TypedResumableCall::Finished(_) => panic!("expected TypedResumableCall::Resumable"),
+            TypedResumableCall::EpochYield(_) => panic!("expected TypedResumableCall::Resumable"),
         }
     }
 }
@@ -87,6 +88,7 @@ fn resumable_call_smoldot_01() {
     match invocation.resume(&mut store, &[Value::I32(42)]).unwrap() {
         TypedResumableCall::Finished(result) => assert_eq!(result, 42),
         TypedResumableCall::Resumable(_) => panic!("expected TypeResumableCall::Finished"),
+        TypedResumableCall::EpochYield(_) => panic!("expected TypeResumableCall::Finished"),
     }
 }
 
@@ -130,6 +132,7 @@ fn resumable_call_smoldot_tail_02() {
     match invocation.resume(&mut store, &[Value::I32(42)]).unwrap() {
         TypedResumableCall::Finished(result) => assert_eq!(result, 42),
         TypedResumableCall::Resumable(_) => panic!("expected TypeResumableCall::Finished"),
+        TypedResumableCall::EpochYield(_) => panic!("expected TypeResumableCall::Finished"),
     }
 }
 
@@ -156,6 +159,7 @@ fn resumable_call_smoldot_02() {
     match invocation.resume(&mut store, &[Value::I32(42)]).unwrap() {
         TypedResumableCall::Finished(result) => assert_eq!(result, 11),
         TypedResumableCall::Resumable(_) => panic!("expected TypeResumableCall::Finished"),
+        TypedResumableCall::EpochYield(_) => panic!("expected TypeResumableCall::Finished"),
     }
 }
 
@@ -265,6 +269,7 @@ impl AssertResumable for ResumableCall {
                 invocation
             }
             Self::Finished => panic!("expected host function trap with exit code 10"),
+            Self::EpochYield(_) => panic!("expected host function trap with exit code 10"),
         }
     }
 
@@ -272,6 +277,7 @@ impl AssertResumable for ResumableCall {
         match self {
             Self::Finished => (),
             Self::Resumable(_) => panic!("expected the resumable call to finish"),
+            Self::EpochYield(_) => panic!("expected the resumable call to finish"),
         }
     }
 }
@@ -324,6 +330,7 @@ impl<Results> AssertResumable for TypedResumableCall<Results> {
                 invocation
             }
             Self::Finished(_) => panic!("expected host function trap with exit code 10"),
+            Self::EpochYield(_) => panic!("expected host function trap with exit code 10"),
         }
     }
 
@@ -331,6 +338,7 @@ impl<Results> AssertResumable for TypedResumableCall<Results> {
         match self {
             Self::Finished(results) => results,
             Self::Resumable(_) => panic!("expected the resumable call to finish"),
+            Self::EpochYield(_) => panic!("expected the resumable call to finish"),
         }
     }
 }
@@ -0,0 +1,265 @@
+//! Tests to check if serialized `wasmi` modules behave like their originals.
+
+use wasmi::{
+    core::Trap,
+    errors::{ModuleError, SerializationError},
+    Config,
+    Engine,
+    Error,
+    Func,
+    Linker,
+    Module,
+    Store,
+};
+
+/// Converts the `wat` string source into `wasm` encoded byte.
+fn wat2wasm(wat: &str) -> Vec<u8> {
+    wat::parse_str(wat).unwrap()
+}
+
+/// Creates a [`Config`] with all Wasm proposals used by the tests enabled.
+fn test_config() -> Config {
+    let mut config = Config::default();
+    config.wasm_extended_const(true);
+    config.wasm_exceptions(true);
+    config
+}
+
+/// Serializes the Wasm module in `wasm` after translating it with `engine`.
+fn serialize(engine: &Engine, wasm: &[u8]) -> Vec<u8> {
+    Module::new(engine, wasm).unwrap().serialize().unwrap()
+}
+
+/// Deserializes the serialized `module` with `engine`.
+fn deserialize(engine: &Engine, module: &[u8]) -> Result<Module, Error> {
+    // SAFETY: The serialized modules of these tests are produced by `Module::serialize`.
+    unsafe { Module::deserialize(engine, module) }
+}
+
+/// Returns the [`SerializationError`] of a failed deserialization.
+fn serialization_error(result: Result<Module, Error>) -> SerializationError {
+    match result.unwrap_err() {
+        Error::Module(ModuleError::Serialization(error)) => error,
+        error => panic!("expected a serialization error but found: {error}"),
+    }
+}
+
+/// Instantiates the `module` and returns its exported `test` function.
+fn instantiate(engine: &Engine, module: &Module) -> (Store<()>, Func) {
+    let mut store = Store::new(engine, ());
+    let mut linker = <Linker<()>>::new(engine);
+    linker
+        .func_wrap("env", "sub", |lhs: i64, rhs: i64| lhs - rhs)
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_func(&store, "test").unwrap();
+    (store, func)
+}
+
+/// Calls `func` with the `i32` parameter and returns its `i64` result.
+fn call(store: &mut Store<()>, func: Func, input: i32) -> Result<i64, Trap> {
+    func.typed::<i32, i64>(&*store)
+        .unwrap()
+        .call(&mut *store, input)
+}
+
+const TEST_WAT: &str = r#"
+    (module
+        (import "env" "sub" (func $sub (param i64 i64) (result i64)))
+        (type $unop (func (param i64) (result i64)))
+        (tag $e (param i64))
+        (memory 1)
+        (table 2 funcref)
+        (global $base (mut i64) (i64.const 0))
+        (global $offset i32 (i32.add (i32.const 4) (i32.const 4)))
+        (data (i32.const 8) "\2a\00\00\00\00\00\00\00")
+        (elem (i32.const 0) $double $square)
+        (start $init)
+        (func $init
+            (global.set $base (i64.load (global.get $offset)))
+        )
+        (func $double (param i64) (result i64)
+            (i64.mul (local.get 0) (i64.const 2))
+        )
+        (func $square (param i64) (result i64)
+            (i64.mul (local.get 0) (local.get 0))
+        )
+        (func $apply (param i32 i64) (result i64)
+            (call_indirect (type $unop) (local.get 1) (local.get 0))
+        )
+        (func (export "test") (param i32) (result i64)
+            (block $large
+                (block $sub
+                    (block $square
+                        (block $double
+                            (br_table $double $square $sub $large (local.get 0))
+                        )
+                        (return (call $apply (i32.const 0) (global.get $base)))
+                    )
+                    (return (call $apply (i32.const 1) (global.get $base)))
+                )
+                (return (call $sub (global.get $base) (i64.const 2)))
+            )
+            try (result i64)
+                (throw $e (i64.const 0x1_0000_0000))
+            catch $e
+                (i64.add (global.get $base))
+            end
+        )
+    )
+"#;
+
+#[test]
+fn serialized_module_behaves_like_original() {
+    let engine = Engine::new(&test_config());
+    let wasm = wat2wasm(TEST_WAT);
+    let original = Module::new(&engine, &wasm[..]).unwrap();
+    let module = deserialize(&engine, &original.serialize().unwrap()).unwrap();
+    assert_eq!(
+        module
+            .imports()
+            .map(|import| import.name().to_string())
+            .collect::<Vec<_>>(),
+        original
+            .imports()
+            .map(|import| import.name().to_string())
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(module.exports().count(), original.exports().count());
+    assert!(module.get_export("test").is_some());
+    let (mut store, func) = instantiate(&engine, &module);
+    assert_eq!(call(&mut store, func, 0).unwrap(), 84);
+    assert_eq!(call(&mut store, func, 1).unwrap(), 42 * 42);
+    assert_eq!(call(&mut store, func, 2).unwrap(), 40);
+    assert_eq!(call(&mut store, func, 3).unwrap(), 0x1_0000_0000 + 42);
+}
+
+#[test]
+fn deserialize_checked_behaves_like_original() {
+    let engine = Engine::new(&test_config());
+    let serialized = serialize(&engine, &wat2wasm(TEST_WAT));
+    let module = Module::deserialize_checked(&engine, &serialized).unwrap();
+    let (mut store, func) = instantiate(&engine, &module);
+    assert_eq!(call(&mut store, func, 0).unwrap(), 84);
+    assert_eq!(call(&mut store, func, 1).unwrap(), 42 * 42);
+    assert_eq!(call(&mut store, func, 2).unwrap(), 40);
+    assert_eq!(call(&mut store, func, 3).unwrap(), 0x1_0000_0000 + 42);
+}
+
+#[test]
+fn deserialize_into_another_engine() {
+    let wasm = wat2wasm(TEST_WAT);
+    let serialized = serialize(&Engine::new(&test_config()), &wasm);
+    let engine = Engine::new(&test_config());
+    let module = deserialize(&engine, &serialized).unwrap();
+    let (mut store, func) = instantiate(&engine, &module);
+    assert_eq!(call(&mut store, func, 3).unwrap(), 0x1_0000_0000 + 42);
+    // Serializing a deserialized module yields the same bytes.
+    assert_eq!(module.serialize().unwrap(), serialized);
+}
+
+#[test]
+fn deserialize_with_incompatible_config_fails() {
+    let wasm = wat2wasm(TEST_WAT);
+    let serialized = serialize(&Engine::new(&test_config()), &wasm);
+    let mut config = test_config();
+    config.consume_fuel(true);
+    let engine = Engine::new(&config);
+    assert_eq!(
+        serialization_error(deserialize(&engine, &serialized)),
+        SerializationError::IncompatibleConfig,
+    );
+}
+
+#[test]
+fn deserialize_malformed_fails() {
+    let engine = Engine::new(&test_config());
+    let wasm = wat2wasm(TEST_WAT);
+    assert_eq!(
+        serialization_error(deserialize(&engine, &wasm)),
+        SerializationError::InvalidMagic,
+    );
+    let serialized = serialize(&engine, &wasm);
+    assert_eq!(
+        serialization_error(deserialize(&engine, &serialized[..serialized.len() - 1])),
+        SerializationError::UnexpectedEnd,
+    );
+    let mut trailing = serialized;
+    trailing.push(0x00);
+    assert_eq!(
+        serialization_error(deserialize(&engine, &trailing)),
+        SerializationError::TrailingBytes,
+    );
+}
+
+#[test]
+fn deserialize_out_of_bounds_index_fails() {
+    let engine = Engine::new(&test_config());
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (global (mut i32) (i32.const 0))
+            (global (mut i32) (i32.const 1))
+            (global (mut i32) (i32.const 2))
+            (func (export "test") (result i32)
+                (global.get 2)
+            )
+        )
+    "#,
+    );
+    let mut serialized = serialize(&engine, &wasm);
+    // The encoded `global.get 2` instruction: a `u16` opcode followed by a `u32` index.
+    let global_get = [0x16, 0x00, 0x02, 0x00, 0x00, 0x00];
+    let mut positions = serialized
+        .windows(global_get.len())
+        .enumerate()
+        .filter(|(_, window)| *window == global_get)
+        .map(|(position, _)| position);
+    let position = positions.next().unwrap();
+    assert!(positions.next().is_none());
+    serialized[position + 2] = 0x03;
+    assert_eq!(
+        serialization_error(deserialize(&engine, &serialized)),
+        SerializationError::Malformed {
+            reason: "out of bounds GlobalIdx"
+        },
+    );
+}
+
+#[test]
+fn deserialize_checked_unbalanced_return_fails() {
+    let engine = Engine::new(&test_config());
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (export "test") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1))
+            )
+        )
+    "#,
+    );
+    let mut serialized = serialize(&engine, &wasm);
+    // The encoded `return` instruction: a `u16` opcode followed by
+    // the `u16` amount of dropped and the `u16` amount of kept values.
+    let ret = [0x0C, 0x00, 0x02, 0x00, 0x01, 0x00];
+    let mut positions = serialized
+        .windows(ret.len())
+        .enumerate()
+        .filter(|(_, window)| *window == ret)
+        .map(|(position, _)| position);
+    let position = positions.next().unwrap();
+    assert!(positions.next().is_none());
+    serialized[position + 2] = 0x01;
+    // The unchecked deserialization only verifies the structure of the bytecode.
+    assert!(deserialize(&engine, &serialized).is_ok());
+    assert_eq!(
+        serialization_error(Module::deserialize_checked(&engine, &serialized)),
+        SerializationError::Malformed {
+            reason: "unbalanced value stack"
+        },
+    );
+}
@@ -0,0 +1,73 @@
+//! Tests to check if wasmi's support for the Wasm `simd` proposal works as intended.
+
+use super::{instantiate, test_setup, wat2wasm};
+use wasmi::{core::V128, Config, Value};
+
+/// Returns a [`Config`] with the `simd` Wasm proposal enabled.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.wasm_simd(true);
+    config
+}
+
+/// Creates a [`V128`] from its `i32x4` lanes.
+fn i32x4(lanes: [i32; 4]) -> V128 {
+    let bytes: Vec<u8> = lanes.iter().flat_map(|lane| lane.to_le_bytes()).collect();
+    V128::from_bits(u128::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+#[test]
+fn params_and_results() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (export "test") (param v128 v128) (result v128)
+                (i32x4.add (local.get 0) (local.get 1))
+            )
+        )
+    "#,
+    );
+    let (mut store, func) = test_setup(&config(), &wasm);
+    let mut results = [Value::V128(V128::default())];
+    func.call(
+        &mut store,
+        &[
+            Value::V128(i32x4([1, 2, 3, i32::MAX])),
+            Value::V128(i32x4([10, 20, 30, 1])),
+        ],
+        &mut results,
+    )
+    .unwrap();
+    assert_eq!(results[0].v128(), Some(i32x4([11, 22, 33, i32::MIN])));
+    let typed = func.typed::<(V128, V128), V128>(&store).unwrap();
+    assert_eq!(
+        typed
+            .call(&mut store, (i32x4([1, 1, 1, 1]), i32x4([-1, 0, 1, 2])))
+            .unwrap(),
+        i32x4([0, 1, 2, 3])
+    );
+}
+
+#[test]
+fn exported_global() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (global (export "g") (mut v128) (v128.const i32x4 1 2 3 4))
+            (func (export "test") (param i32)
+                (global.set 0 (i32x4.splat (local.get 0)))
+            )
+        )
+    "#,
+    );
+    let (mut store, instance) = instantiate(&config(), &wasm);
+    let global = instance.get_global(&store, "g").unwrap();
+    assert_eq!(global.get(&store).v128(), Some(i32x4([1, 2, 3, 4])));
+    let func = instance.get_typed_func::<i32, ()>(&store, "test").unwrap();
+    func.call(&mut store, 5).unwrap();
+    assert_eq!(global.get(&store).v128(), Some(i32x4([5, 5, 5, 5])));
+    global
+        .set(&mut store, Value::V128(i32x4([-1, 0, 1, 2])))
+        .unwrap();
+    assert_eq!(global.get(&store).v128(), Some(i32x4([-1, 0, 1, 2])));
+}
@@ -0,0 +1,198 @@
+//! Tests to check if memory images and instance snapshots work as intended.
+
+use wasmi::{
+    errors::SnapshotError,
+    Caller,
+    Config,
+    Engine,
+    Error,
+    Global,
+    Instance,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Value,
+};
+
+/// Converts the `wat` string source into `wasm` encoded byte.
+fn wat2wasm(wat: &str) -> Vec<u8> {
+    wat::parse_str(wat).unwrap()
+}
+
+/// Creates an [`Engine`] with all Wasm proposals used by the tests enabled.
+fn test_engine() -> Engine {
+    let mut config = Config::default();
+    config.wasm_multi_memory(true);
+    Engine::new(&config)
+}
+
+/// Instantiates the `module` with the `env.offset` global variable and `env.init` host function.
+///
+/// The `env.init` host function counts its calls in the [`Store`] data.
+fn instantiate(store: &mut Store<u32>, module: &Module) -> Result<Instance, Error> {
+    let mut linker = <Linker<u32>>::new(store.engine());
+    let offset = Global::new(&mut *store, Value::I32(4), Mutability::Const);
+    linker.define("env", "offset", offset)?;
+    linker.func_wrap("env", "init", |mut caller: Caller<u32>| {
+        *caller.data_mut() += 1;
+    })?;
+    linker.instantiate(&mut *store, module)?.start(&mut *store)
+}
+
+/// Returns the first `len` bytes of the exported linear memory `name`.
+fn memory_prefix(store: &Store<u32>, instance: Instance, name: &str, len: usize) -> Vec<u8> {
+    instance.get_memory(store, name).unwrap().data(store)[..len].to_vec()
+}
+
+#[test]
+fn memory_images_work() {
+    let engine = test_engine();
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (import "env" "offset" (global $offset i32))
+            (memory $m0 (export "m0") 1)
+            (memory $m1 (export "m1") 1)
+            (data (memory $m0) (i32.const 2) "\01\02\03\04")
+            (data (memory $m0) (i32.const 4) "\05\06")
+            (data "passive")
+            (data (memory $m1) (global.get $offset) "\07\08")
+            (data (memory $m1) (i32.const 1) "\09")
+        )
+    "#,
+    );
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, 0);
+    let instance = instantiate(&mut store, &module).unwrap();
+    assert_eq!(
+        memory_prefix(&store, instance, "m0", 8),
+        [0, 0, 1, 2, 5, 6, 0, 0]
+    );
+    assert_eq!(
+        memory_prefix(&store, instance, "m1", 8),
+        [0, 9, 0, 0, 7, 8, 0, 0]
+    );
+}
+
+#[test]
+fn memory_images_out_of_bounds_fails() {
+    let engine = test_engine();
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (import "env" "offset" (global $offset i32))
+            (memory 1)
+            (data (i32.const 65535) "\01\02")
+        )
+    "#,
+    );
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, 0);
+    assert!(instantiate(&mut store, &module).is_err());
+}
+
+const SNAPSHOT_WAT: &str = r#"
+    (module
+        (import "env" "offset" (global $offset i32))
+        (import "env" "init" (func $init))
+        (memory (export "memory") 1)
+        (global $counter (export "counter") (mut i32) (i32.const 0))
+        (global $constant (export "constant") i32 (global.get $offset))
+        (global $funcref (mut funcref) (ref.null func))
+        (data (i32.const 0) "\01\02\03")
+        (elem declare func $start)
+        (func $start
+            (call $init)
+            (global.set $counter (i32.const 100))
+            (drop (memory.grow (i32.const 2)))
+            (i32.store8 (i32.const 1) (i32.const 42))
+            (i32.store8 (i32.const 0x20000) (i32.const 7))
+        )
+        (func (export "inc") (result i32)
+            (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+            (global.get $counter)
+        )
+        (func (export "set_funcref")
+            (global.set $funcref (ref.func $start))
+        )
+        (start $start)
+    )
+"#;
+
+#[test]
+fn snapshot_works() {
+    let engine = test_engine();
+    let module = Module::new(&engine, &wat2wasm(SNAPSHOT_WAT)[..]).unwrap();
+    let mut store = Store::new(&engine, 0);
+    let instance = instantiate(&mut store, &module).unwrap();
+    assert_eq!(*store.data(), 1);
+    let snapshot = module.snapshot(&store, &instance).unwrap();
+    // Mutating the instance after the snapshot does not affect the snapshot.
+    let inc = instance.get_typed_func::<(), i32>(&store, "inc").unwrap();
+    assert_eq!(inc.call(&mut store, ()).unwrap(), 101);
+    // The snapshot can also be serialized and deserialized.
+    let serialized = snapshot.serialize().unwrap();
+    // SAFETY: The serialized module is produced by `Module::serialize`.
+    let serialized = unsafe { Module::deserialize(&engine, &serialized) }.unwrap();
+    for snapshot in [&snapshot, &serialized] {
+        let mut store = Store::new(&engine, 0);
+        let instance = instantiate(&mut store, snapshot).unwrap();
+        // The `start` function is not executed again.
+        assert_eq!(*store.data(), 0);
+        let memory = instance.get_memory(&store, "memory").unwrap();
+        assert_eq!(memory.current_pages(&store), 3.into());
+        assert_eq!(memory.data(&store)[..4], [1, 42, 3, 0]);
+        assert_eq!(memory.data(&store)[0x20000], 7);
+        let inc = instance.get_typed_func::<(), i32>(&store, "inc").unwrap();
+        assert_eq!(inc.call(&mut store, ()).unwrap(), 101);
+        let constant = instance.get_global(&store, "constant").unwrap();
+        assert_eq!(constant.get(&store).i32(), Some(4));
+    }
+}
+
+#[test]
+#[should_panic]
+fn snapshot_of_foreign_instance_panics() {
+    let engine = test_engine();
+    let wasm = wat2wasm(SNAPSHOT_WAT);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, 0);
+    let instance = instantiate(&mut store, &module).unwrap();
+    // Clones of the module share its identity.
+    let snapshot = module.clone().snapshot(&store, &instance).unwrap();
+    // The snapshot shares the function types of the module but not its identity.
+    let _ = snapshot.snapshot(&store, &instance);
+}
+
+#[test]
+fn snapshot_of_reference_global_fails() {
+    let engine = test_engine();
+    let module = Module::new(&engine, &wat2wasm(SNAPSHOT_WAT)[..]).unwrap();
+    let mut store = Store::new(&engine, 0);
+    let instance = instantiate(&mut store, &module).unwrap();
+    instance
+        .get_typed_func::<(), ()>(&store, "set_funcref")
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap();
+    assert!(matches!(
+        module.snapshot(&store, &instance),
+        Err(SnapshotError::ReferenceGlobal { index: 3 })
+    ));
+}
+
+#[test]
+fn snapshot_of_shared_memory_fails() {
+    let mut config = Config::default();
+    config.wasm_threads(true);
+    let engine = Engine::new(&config);
+    let wasm = wat2wasm("(module (memory 1 1 shared))");
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, 0);
+    let instance = instantiate(&mut store, &module).unwrap();
+    assert!(matches!(
+        module.snapshot(&store, &instance),
+        Err(SnapshotError::SharedMemory { index: 0 })
+    ));
+}
@@ -0,0 +1,159 @@
+//! Tests to check if wasmi's support for the Wasm `threads` proposal works as intended.
+
+use super::wat2wasm;
+use std::{thread, time::Duration};
+use wasmi::{Config, Engine, Func, Linker, Memory, MemoryType, Module, SharedMemory, Store};
+
+/// Creates an [`Engine`] with the `threads` Wasm proposal enabled.
+fn test_engine() -> Engine {
+    let mut config = Config::default();
+    config.wasm_threads(true);
+    Engine::new(&config)
+}
+
+/// Instantiates the `module` importing `memory` as `env.memory` into a new [`Store`].
+///
+/// Returns the exported `test` function of the instance.
+///
+/// # Note
+///
+/// The `module` is compiled upfront since compiling a [`Module`] has to wait
+/// for all executions of the same [`Engine`] to finish, including blocked ones.
+fn shared_test_setup(module: &Module, memory: &SharedMemory) -> (Store<()>, Func) {
+    let engine = module.engine();
+    let mut store = Store::new(engine, ());
+    let memory = Memory::from_shared(&mut store, memory).unwrap();
+    let mut linker = <Linker<()>>::new(engine);
+    linker.define("env", "memory", memory).unwrap();
+    let instance = linker
+        .instantiate(&mut store, module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_func(&store, "test").unwrap();
+    (store, func)
+}
+
+#[test]
+fn shared_memory_type() {
+    assert!(MemoryType::new_shared(1, 2).unwrap().is_shared());
+    assert!(!MemoryType::new(1, Some(2)).unwrap().is_shared());
+    assert!(SharedMemory::new(MemoryType::new(1, Some(2)).unwrap()).is_err());
+    let shared = SharedMemory::new(MemoryType::new_shared(1, 2).unwrap()).unwrap();
+    let engine = test_engine();
+    let mut store = <Store<()>>::new(&engine, ());
+    let memory = Memory::from_shared(&mut store, &shared).unwrap();
+    assert!(SharedMemory::same(&memory.shared(&store).unwrap(), &shared));
+    // Growth is visible through all handles of the shared memory.
+    assert_eq!(memory.grow(&mut store, 1.into()).unwrap(), 1.into());
+    assert_eq!(shared.current_pages(), 2.into());
+    assert!(shared.grow(1.into()).is_err());
+    shared.write(65536, &[42]).unwrap();
+    let mut buffer = [0x00];
+    memory.read(&store, 65536, &mut buffer).unwrap();
+    assert_eq!(buffer, [42]);
+    assert_eq!(shared.data().len(), 2 * 65536);
+    // Safety: there are no concurrent accesses to the shared memory.
+    assert_eq!(unsafe { *shared.data()[65536].get() }, 42);
+    assert_eq!(shared.data_ptr(), memory.shared(&store).unwrap().data_ptr());
+}
+
+#[test]
+#[should_panic]
+fn shared_memory_has_no_slices() {
+    let shared = SharedMemory::new(MemoryType::new_shared(1, 1).unwrap()).unwrap();
+    let mut store = <Store<()>>::new(&test_engine(), ());
+    let memory = Memory::from_shared(&mut store, &shared).unwrap();
+    memory.data(&store);
+}
+
+#[test]
+fn shared_memory_reserves_maximum_lazily() {
+    // Only the address space of the maximum size is reserved upfront.
+    let shared = SharedMemory::new(MemoryType::new_shared(1, 65536).unwrap()).unwrap();
+    assert_eq!(shared.data_size(), 65536);
+    let data_ptr = shared.data_ptr();
+    assert_eq!(shared.grow(1000.into()).unwrap(), 1.into());
+    // The bytes never move and new pages are zero initialized.
+    assert_eq!(shared.data_ptr(), data_ptr);
+    let mut buffer = [0xFF; 16];
+    shared.read(1000 * 65536, &mut buffer).unwrap();
+    assert_eq!(buffer, [0x00; 16]);
+    shared.write(1001 * 65536 - 1, &[1]).unwrap();
+    assert!(shared.write(1001 * 65536, &[1]).is_err());
+}
+
+#[test]
+fn atomic_counter_across_threads() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (import "env" "memory" (memory 1 1 shared))
+            (func (export "test") (param i32)
+                (loop $continue
+                    (drop (i32.atomic.rmw.add (i32.const 0) (i32.const 1)))
+                    (br_if $continue
+                        (local.tee 0 (i32.sub (local.get 0) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    "#,
+    );
+    let module = Module::new(&test_engine(), &wasm[..]).unwrap();
+    let shared = SharedMemory::new(MemoryType::new_shared(1, 1).unwrap()).unwrap();
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                let (mut store, func) = shared_test_setup(&module, &shared);
+                let func = func.typed::<i32, ()>(&store).unwrap();
+                func.call(&mut store, 1_000).unwrap();
+            });
+        }
+    });
+    let mut counter = [0x00_u8; 4];
+    shared.read(0, &mut counter).unwrap();
+    assert_eq!(u32::from_le_bytes(counter), 4_000);
+}
+
+#[test]
+fn wait_and_notify_across_threads() {
+    let wait_wasm = wat2wasm(
+        r#"
+        (module
+            (import "env" "memory" (memory 1 1 shared))
+            (func (export "test") (result i32)
+                (memory.atomic.wait32 (i32.const 4) (i32.const 0) (i64.const -1))
+            )
+        )
+    "#,
+    );
+    let notify_wasm = wat2wasm(
+        r#"
+        (module
+            (import "env" "memory" (memory 1 1 shared))
+            (func (export "test") (result i32)
+                (memory.atomic.notify (i32.const 4) (i32.const 1))
+            )
+        )
+    "#,
+    );
+    let engine = test_engine();
+    let wait_module = Module::new(&engine, &wait_wasm[..]).unwrap();
+    let notify_module = Module::new(&engine, &notify_wasm[..]).unwrap();
+    let shared = SharedMemory::new(MemoryType::new_shared(1, 1).unwrap()).unwrap();
+    let (mut store, notify) = shared_test_setup(&notify_module, &shared);
+    let notify = notify.typed::<(), i32>(&store).unwrap();
+    thread::scope(|scope| {
+        let waiter = scope.spawn(|| {
+            let (mut store, func) = shared_test_setup(&wait_module, &shared);
+            let func = func.typed::<(), i32>(&store).unwrap();
+            func.call(&mut store, ()).unwrap()
+        });
+        // Notify until the waiting thread has been woken up.
+        while notify.call(&mut store, ()).unwrap() == 0 {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(waiter.join().unwrap(), 0);
+    });
+}
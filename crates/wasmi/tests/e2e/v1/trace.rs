@@ -0,0 +1,419 @@
+//! Tests to check if the execution tracing notifies the installed tracer as intended.
+
+use std::sync::{Arc, Mutex};
+use wasmi::{
+    core::UntypedValue,
+    trace::{Instruction, MemoryAccess, Tracer},
+    CompilationMode,
+    Config,
+    Engine,
+    Instance,
+    Linker,
+    Module,
+    Store,
+};
+
+/// An event reported to the [`TestTracer`].
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    /// Entered the function at the index.
+    Enter(u32),
+    /// Returned from the function at the index.
+    Exit(u32),
+    /// Loaded the value from the address of the linear memory.
+    Load {
+        memory: u32,
+        address: u64,
+        value: u64,
+    },
+    /// Stored the value to the address of the linear memory.
+    Store {
+        memory: u32,
+        address: u64,
+        value: u64,
+    },
+    /// Atomically modified the value at the address of the linear memory using the operand.
+    Rmw {
+        memory: u32,
+        address: u64,
+        value: u64,
+        operand: u64,
+    },
+    /// Atomically compared and exchanged the value at the address of the linear memory.
+    Cmpxchg {
+        memory: u32,
+        address: u64,
+        value: u64,
+        expected: u64,
+        replacement: u64,
+    },
+    /// Filled the bytes of the linear memory with the value.
+    Fill {
+        memory: u32,
+        dst: u64,
+        value: u8,
+        len: u64,
+    },
+    /// Copied the bytes between the linear memories.
+    Copy {
+        dst_memory: u32,
+        dst: u64,
+        src_memory: u32,
+        src: u64,
+        len: u64,
+    },
+    /// Copied the bytes of the data segment to the linear memory.
+    Init {
+        memory: u32,
+        dst: u64,
+        segment: u32,
+        src: u64,
+        len: u64,
+    },
+    /// Executed the instruction with the stack values.
+    Instr(Instruction, Vec<u64>),
+}
+
+/// A [`Tracer`] that records all events into a shared list.
+#[derive(Debug, Default, Clone)]
+struct TestTracer {
+    events: Arc<Mutex<Vec<Event>>>,
+}
+
+impl TestTracer {
+    /// Returns all recorded events but the executed instructions.
+    fn calls_and_accesses(&self) -> Vec<Event> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| !matches!(event, Event::Instr(..)))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the recorded executed instructions with their stack values.
+    fn instrs(&self) -> Vec<(Instruction, Vec<u64>)> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|event| match event {
+                Event::Instr(instr, stack) => Some((*instr, stack.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Records the `event`.
+    fn push(&self, event: Event) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl Tracer for TestTracer {
+    fn func_enter(&mut self, _instance: &Instance, func_index: u32) {
+        self.push(Event::Enter(func_index))
+    }
+
+    fn func_exit(&mut self, _instance: &Instance, func_index: u32) {
+        self.push(Event::Exit(func_index))
+    }
+
+    fn instr(&mut self, instr: &Instruction, stack: &[UntypedValue]) {
+        let stack = stack.iter().copied().map(u64::from).collect();
+        self.push(Event::Instr(*instr, stack))
+    }
+
+    fn memory_load(&mut self, access: MemoryAccess) {
+        self.push(Event::Load {
+            memory: access.memory(),
+            address: access.address(),
+            value: u64::from(access.value()),
+        })
+    }
+
+    fn memory_store(&mut self, access: MemoryAccess) {
+        self.push(Event::Store {
+            memory: access.memory(),
+            address: access.address(),
+            value: u64::from(access.value()),
+        })
+    }
+
+    fn memory_rmw(&mut self, access: MemoryAccess, operand: UntypedValue) {
+        self.push(Event::Rmw {
+            memory: access.memory(),
+            address: access.address(),
+            value: u64::from(access.value()),
+            operand: u64::from(operand),
+        })
+    }
+
+    fn memory_cmpxchg(
+        &mut self,
+        access: MemoryAccess,
+        expected: UntypedValue,
+        replacement: UntypedValue,
+    ) {
+        self.push(Event::Cmpxchg {
+            memory: access.memory(),
+            address: access.address(),
+            value: u64::from(access.value()),
+            expected: u64::from(expected),
+            replacement: u64::from(replacement),
+        })
+    }
+
+    fn memory_fill(&mut self, memory: u32, dst: u64, value: u8, len: u64) {
+        self.push(Event::Fill {
+            memory,
+            dst,
+            value,
+            len,
+        })
+    }
+
+    fn memory_copy(&mut self, dst_memory: u32, dst: u64, src_memory: u32, src: u64, len: u64) {
+        self.push(Event::Copy {
+            dst_memory,
+            dst,
+            src_memory,
+            src,
+            len,
+        })
+    }
+
+    fn memory_init(&mut self, memory: u32, dst: u64, segment: u32, src: u64, len: u64) {
+        self.push(Event::Init {
+            memory,
+            dst,
+            segment,
+            src,
+            len,
+        })
+    }
+}
+
+/// Instantiates the Wasm module `wat` using the `config` with a [`TestTracer`] installed.
+fn setup(config: &Config, wat: &str) -> (Store<()>, Instance, TestTracer) {
+    let engine = Engine::new(config);
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let tracer = TestTracer::default();
+    store.tracer(tracer.clone());
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance, tracer)
+}
+
+/// The Wasm module used to test tracing of calls and memory accesses.
+const CALLS_WAT: &str = r#"
+    (module
+        (memory 1)
+        (func $run (export "run") (param i32) (result i32)
+            (call $write (local.get 0))
+            (i32.load offset=4 (i32.const 8))
+        )
+        (func $write (param i32)
+            (i32.store offset=4 (i32.const 8) (local.get 0))
+        )
+    )
+"#;
+
+#[test]
+fn trace_calls_and_memory_accesses_works() {
+    for mode in [
+        CompilationMode::Eager,
+        CompilationMode::LazyTranslation,
+        CompilationMode::Lazy,
+    ] {
+        let mut config = Config::default();
+        config.compilation_mode(mode);
+        let (mut store, instance, tracer) = setup(&config, CALLS_WAT);
+        let result = instance
+            .get_typed_func::<i32, i32>(&store, "run")
+            .unwrap()
+            .call(&mut store, 42)
+            .unwrap();
+        assert_eq!(result, 42);
+        assert_eq!(
+            tracer.calls_and_accesses(),
+            [
+                Event::Enter(0),
+                Event::Enter(1),
+                Event::Store {
+                    memory: 0,
+                    address: 12,
+                    value: 42
+                },
+                Event::Exit(1),
+                Event::Load {
+                    memory: 0,
+                    address: 12,
+                    value: 42
+                },
+                Event::Exit(0),
+            ],
+            "{mode:?}"
+        );
+    }
+}
+
+/// The Wasm module used to test tracing of atomic and bulk memory accesses.
+const ATOMICS_WAT: &str = r#"
+    (module
+        (memory 1)
+        (data $data "\01\02\03\04")
+        (func (export "run") (result i32)
+            (i32.atomic.store offset=4 (i32.const 4) (i32.const 9))
+            (drop (i32.atomic.rmw.add (i32.const 0) (i32.const 5)))
+            (drop (i32.atomic.rmw.cmpxchg (i32.const 0) (i32.const 5) (i32.const 7)))
+            (memory.fill (i32.const 16) (i32.const 0xAB) (i32.const 4))
+            (memory.copy (i32.const 32) (i32.const 16) (i32.const 4))
+            (memory.init $data (i32.const 48) (i32.const 1) (i32.const 3))
+            (i32.atomic.load (i32.const 0))
+        )
+    )
+"#;
+
+#[test]
+fn trace_atomic_and_bulk_memory_accesses_works() {
+    let mut config = Config::default();
+    config.wasm_threads(true);
+    let (mut store, instance, tracer) = setup(&config, ATOMICS_WAT);
+    let result = instance
+        .get_typed_func::<(), i32>(&store, "run")
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap();
+    assert_eq!(result, 7);
+    assert_eq!(
+        tracer.calls_and_accesses(),
+        [
+            Event::Enter(0),
+            Event::Store {
+                memory: 0,
+                address: 8,
+                value: 9
+            },
+            Event::Rmw {
+                memory: 0,
+                address: 0,
+                value: 0,
+                operand: 5
+            },
+            Event::Cmpxchg {
+                memory: 0,
+                address: 0,
+                value: 5,
+                expected: 5,
+                replacement: 7
+            },
+            Event::Fill {
+                memory: 0,
+                dst: 16,
+                value: 0xAB,
+                len: 4
+            },
+            Event::Copy {
+                dst_memory: 0,
+                dst: 32,
+                src_memory: 0,
+                src: 16,
+                len: 4
+            },
+            Event::Init {
+                memory: 0,
+                dst: 48,
+                segment: 0,
+                src: 1,
+                len: 3
+            },
+            Event::Load {
+                memory: 0,
+                address: 0,
+                value: 7
+            },
+            Event::Exit(0),
+        ]
+    );
+}
+
+#[test]
+fn trace_instructions_works() {
+    let (mut store, instance, tracer) = setup(
+        &Config::default(),
+        r#"
+        (module
+            (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1))
+            )
+        )
+    "#,
+    );
+    let result = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "add")
+        .unwrap()
+        .call(&mut store, (1, 2))
+        .unwrap();
+    assert_eq!(result, 3);
+    let instrs = tracer.instrs();
+    let (_, stack) = instrs
+        .iter()
+        .find(|(instr, _)| *instr == Instruction::I32Add)
+        .expect("missing i32.add instruction");
+    // The parameters are followed by the operands of `i32.add`.
+    assert_eq!(stack, &[1, 2, 1, 2]);
+    let (last, stack) = instrs.last().unwrap();
+    assert!(matches!(last, Instruction::Return(_)));
+    assert_eq!(stack.last(), Some(&3));
+}
+
+#[test]
+fn trace_tail_calls_works() {
+    let mut config = Config::default();
+    config.wasm_tail_call(true);
+    let (mut store, instance, tracer) = setup(
+        &config,
+        r#"
+        (module
+            (func (export "run")
+                (return_call $callee)
+            )
+            (func $callee)
+        )
+    "#,
+    );
+    instance
+        .get_typed_func::<(), ()>(&store, "run")
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap();
+    assert_eq!(
+        tracer.calls_and_accesses(),
+        [
+            Event::Enter(0),
+            Event::Exit(0),
+            Event::Enter(1),
+            Event::Exit(1)
+        ]
+    );
+}
+
+#[test]
+fn take_tracer_works() {
+    let (mut store, instance, tracer) = setup(&Config::default(), CALLS_WAT);
+    let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+    run.call(&mut store, 1).unwrap();
+    let len_events = tracer.events.lock().unwrap().len();
+    assert!(len_events > 0);
+    assert!(store.take_tracer().is_some());
+    assert!(store.take_tracer().is_none());
+    run.call(&mut store, 2).unwrap();
+    assert_eq!(tracer.events.lock().unwrap().len(), len_events);
+}
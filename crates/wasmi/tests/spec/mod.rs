@@ -80,6 +80,38 @@ fn make_config() -> Config {
         .wasm_reference_types(true)
         .wasm_tail_call(true)
         .wasm_extended_const(true);
+    #[cfg(feature = "simd")]
+    config.wasm_simd(true);
+    config
+}
+
+/// Create a [`Config`] with all Wasm feature supported by `wasmi` enabled
+/// as well as the `multi-memory` Wasm proposal.
+///
+/// # Note
+///
+/// The `multi-memory` Wasm proposal is only enabled for its own test suite
+/// since other Wasm spec tests assert that modules with more than one
+/// linear memory are invalid.
+fn make_multi_memory_config() -> Config {
+    let mut config = make_config();
+    config.wasm_multi_memory(true);
+    config
+}
+
+/// Create a [`Config`] with all Wasm feature supported by `wasmi` enabled
+/// as well as the `memory64` Wasm proposal.
+fn make_memory64_config() -> Config {
+    let mut config = make_config();
+    config.wasm_memory64(true);
+    config
+}
+
+/// Create a [`Config`] with all Wasm feature supported by `wasmi` enabled
+/// as well as the `threads` Wasm proposal.
+fn make_threads_config() -> Config {
+    let mut config = make_config();
+    config.wasm_threads(true);
     config
 }
 
@@ -157,6 +189,120 @@ define_spec_tests! {
     fn wasm_return("return");
     fn wasm_select("select");
     fn wasm_skip_stack_guard_page("skip-stack-guard-page");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_address("simd_address");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_align("simd_align");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_bit_shift("simd_bit_shift");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_bitwise("simd_bitwise");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_boolean("simd_boolean");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_const("simd_const");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_conversions("simd_conversions");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f32x4("simd_f32x4");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f32x4_arith("simd_f32x4_arith");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f32x4_cmp("simd_f32x4_cmp");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f32x4_pmin_pmax("simd_f32x4_pmin_pmax");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f32x4_rounding("simd_f32x4_rounding");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f64x2("simd_f64x2");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f64x2_arith("simd_f64x2_arith");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f64x2_cmp("simd_f64x2_cmp");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f64x2_pmin_pmax("simd_f64x2_pmin_pmax");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_f64x2_rounding("simd_f64x2_rounding");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i16x8_arith("simd_i16x8_arith");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i16x8_arith2("simd_i16x8_arith2");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i16x8_cmp("simd_i16x8_cmp");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i16x8_extadd_pairwise_i8x16("simd_i16x8_extadd_pairwise_i8x16");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i16x8_extmul_i8x16("simd_i16x8_extmul_i8x16");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i16x8_q15mulr_sat_s("simd_i16x8_q15mulr_sat_s");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i16x8_sat_arith("simd_i16x8_sat_arith");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i32x4_arith("simd_i32x4_arith");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i32x4_arith2("simd_i32x4_arith2");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i32x4_cmp("simd_i32x4_cmp");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i32x4_dot_i16x8("simd_i32x4_dot_i16x8");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i32x4_extadd_pairwise_i16x8("simd_i32x4_extadd_pairwise_i16x8");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i32x4_extmul_i16x8("simd_i32x4_extmul_i16x8");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i32x4_trunc_sat_f32x4("simd_i32x4_trunc_sat_f32x4");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i32x4_trunc_sat_f64x2("simd_i32x4_trunc_sat_f64x2");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i64x2_arith("simd_i64x2_arith");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i64x2_arith2("simd_i64x2_arith2");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i64x2_cmp("simd_i64x2_cmp");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i64x2_extmul_i32x4("simd_i64x2_extmul_i32x4");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i8x16_arith("simd_i8x16_arith");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i8x16_arith2("simd_i8x16_arith2");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i8x16_cmp("simd_i8x16_cmp");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_i8x16_sat_arith("simd_i8x16_sat_arith");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_int_to_int_extend("simd_int_to_int_extend");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_lane("simd_lane");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_linking("simd_linking");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_load("simd_load");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_load16_lane("simd_load16_lane");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_load32_lane("simd_load32_lane");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_load64_lane("simd_load64_lane");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_load8_lane("simd_load8_lane");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_load_extend("simd_load_extend");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_load_splat("simd_load_splat");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_load_zero("simd_load_zero");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_splat("simd_splat");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_store("simd_store");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_store16_lane("simd_store16_lane");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_store32_lane("simd_store32_lane");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_store64_lane("simd_store64_lane");
+    #[cfg(feature = "simd")]
+    fn wasm_simd_store8_lane("simd_store8_lane");
     fn wasm_stack("stack");
     fn wasm_start("start");
     fn wasm_store("store");
@@ -182,3 +328,79 @@ define_spec_tests! {
     fn wasm_utf8_import_module("utf8-import-module");
     fn wasm_utf8_invalid_encoding("utf8-invalid-encoding");
 }
+
+define_spec_tests! {
+    let config = make_multi_memory_config();
+    let runner = run::run_wasm_spec_test;
+
+    fn wasm_multi_memory_address0("proposals/multi-memory/address0");
+    fn wasm_multi_memory_address1("proposals/multi-memory/address1");
+    fn wasm_multi_memory_align0("proposals/multi-memory/align0");
+    fn wasm_multi_memory_binary("proposals/multi-memory/binary");
+    fn wasm_multi_memory_binary0("proposals/multi-memory/binary0");
+    fn wasm_multi_memory_data("proposals/multi-memory/data");
+    fn wasm_multi_memory_data0("proposals/multi-memory/data0");
+    fn wasm_multi_memory_data1("proposals/multi-memory/data1");
+    fn wasm_multi_memory_data_drop0("proposals/multi-memory/data_drop0");
+    fn wasm_multi_memory_exports0("proposals/multi-memory/exports0");
+    fn wasm_multi_memory_float_exprs0("proposals/multi-memory/float_exprs0");
+    fn wasm_multi_memory_float_exprs1("proposals/multi-memory/float_exprs1");
+    fn wasm_multi_memory_float_memory0("proposals/multi-memory/float_memory0");
+    fn wasm_multi_memory_imports("proposals/multi-memory/imports");
+    fn wasm_multi_memory_imports0("proposals/multi-memory/imports0");
+    fn wasm_multi_memory_imports1("proposals/multi-memory/imports1");
+    fn wasm_multi_memory_imports2("proposals/multi-memory/imports2");
+    fn wasm_multi_memory_imports3("proposals/multi-memory/imports3");
+    fn wasm_multi_memory_imports4("proposals/multi-memory/imports4");
+    fn wasm_multi_memory_linking0("proposals/multi-memory/linking0");
+    fn wasm_multi_memory_linking1("proposals/multi-memory/linking1");
+    fn wasm_multi_memory_linking2("proposals/multi-memory/linking2");
+    fn wasm_multi_memory_linking3("proposals/multi-memory/linking3");
+    fn wasm_multi_memory_load("proposals/multi-memory/load");
+    fn wasm_multi_memory_load0("proposals/multi-memory/load0");
+    fn wasm_multi_memory_load1("proposals/multi-memory/load1");
+    fn wasm_multi_memory_load2("proposals/multi-memory/load2");
+    fn wasm_multi_memory_memory("proposals/multi-memory/memory");
+    fn wasm_multi_memory_memory_multi("proposals/multi-memory/memory-multi");
+    fn wasm_multi_memory_memory_copy0("proposals/multi-memory/memory_copy0");
+    fn wasm_multi_memory_memory_copy1("proposals/multi-memory/memory_copy1");
+    fn wasm_multi_memory_memory_fill0("proposals/multi-memory/memory_fill0");
+    fn wasm_multi_memory_memory_grow("proposals/multi-memory/memory_grow");
+    fn wasm_multi_memory_memory_init0("proposals/multi-memory/memory_init0");
+    fn wasm_multi_memory_memory_size("proposals/multi-memory/memory_size");
+    fn wasm_multi_memory_memory_size0("proposals/multi-memory/memory_size0");
+    fn wasm_multi_memory_memory_size1("proposals/multi-memory/memory_size1");
+    fn wasm_multi_memory_memory_size2("proposals/multi-memory/memory_size2");
+    fn wasm_multi_memory_memory_size3("proposals/multi-memory/memory_size3");
+    fn wasm_multi_memory_memory_trap0("proposals/multi-memory/memory_trap0");
+    fn wasm_multi_memory_memory_trap1("proposals/multi-memory/memory_trap1");
+    fn wasm_multi_memory_start0("proposals/multi-memory/start0");
+    fn wasm_multi_memory_store("proposals/multi-memory/store");
+    fn wasm_multi_memory_store0("proposals/multi-memory/store0");
+    fn wasm_multi_memory_store1("proposals/multi-memory/store1");
+    fn wasm_multi_memory_traps0("proposals/multi-memory/traps0");
+}
+
+define_spec_tests! {
+    let config = make_memory64_config();
+    let runner = run::run_wasm_spec_test;
+
+    fn wasm_memory64_address64("proposals/memory64/address64");
+    fn wasm_memory64_align64("proposals/memory64/align64");
+    fn wasm_memory64_bulk64("proposals/memory64/bulk64");
+    fn wasm_memory64_endianness64("proposals/memory64/endianness64");
+    fn wasm_memory64_float_memory64("proposals/memory64/float_memory64");
+    fn wasm_memory64_load64("proposals/memory64/load64");
+    fn wasm_memory64_memory64("proposals/memory64/memory64");
+    fn wasm_memory64_memory_grow64("proposals/memory64/memory_grow64");
+    fn wasm_memory64_memory_redundancy64("proposals/memory64/memory_redundancy64");
+    fn wasm_memory64_memory_trap64("proposals/memory64/memory_trap64");
+}
+
+define_spec_tests! {
+    let config = make_threads_config();
+    let runner = run::run_wasm_spec_test;
+
+    fn wasm_threads_atomic("proposals/threads/atomic");
+    fn wasm_threads_memory("proposals/threads/memory");
+}
@@ -15,6 +15,8 @@ use wast::{
     WastRet,
     Wat,
 };
+#[cfg(feature = "simd")]
+use {wasmi_core::V128, wast::core::V128Pattern};
 
 /// Runs the Wasm test spec identified by the given name.
 pub fn run_wasm_spec_test(name: &str, config: Config) {
@@ -262,6 +264,10 @@ fn assert_results(context: &TestContext, span: Span, results: &[Value], expected
                     );
                 }
             },
+            #[cfg(feature = "simd")]
+            (Value::V128(result), WastRetCore::V128(expected)) => {
+                assert_v128(context, span, *result, expected)
+            }
             (Value::FuncRef(funcref), WastRetCore::RefNull(Some(HeapType::Func))) => {
                 assert!(funcref.is_null());
             }
@@ -286,6 +292,66 @@ fn assert_results(context: &TestContext, span: Span, results: &[Value], expected
     }
 }
 
+/// Asserts that the `v128` `result` matches the `expected` pattern.
+#[cfg(feature = "simd")]
+fn assert_v128(context: &TestContext, span: Span, result: V128, expected: &V128Pattern) {
+    let bytes = result.to_bits().to_le_bytes();
+    match expected {
+        V128Pattern::I8x16(expected) => {
+            let expected = expected.map(i8::to_le_bytes).concat();
+            assert_eq!(&bytes[..], &expected[..], "in {}", context.spanned(span))
+        }
+        V128Pattern::I16x8(expected) => {
+            let expected = expected.map(i16::to_le_bytes).concat();
+            assert_eq!(&bytes[..], &expected[..], "in {}", context.spanned(span))
+        }
+        V128Pattern::I32x4(expected) => {
+            let expected = expected.map(i32::to_le_bytes).concat();
+            assert_eq!(&bytes[..], &expected[..], "in {}", context.spanned(span))
+        }
+        V128Pattern::I64x2(expected) => {
+            let expected = expected.map(i64::to_le_bytes).concat();
+            assert_eq!(&bytes[..], &expected[..], "in {}", context.spanned(span))
+        }
+        V128Pattern::F32x4(expected) => {
+            for (lane, expected) in bytes.chunks_exact(4).zip(expected) {
+                let lane = F32::from_bits(u32::from_le_bytes(lane.try_into().unwrap()));
+                match expected {
+                    NanPattern::CanonicalNan | NanPattern::ArithmeticNan => {
+                        assert!(lane.is_nan(), "in {}", context.spanned(span))
+                    }
+                    NanPattern::Value(expected) => {
+                        assert_eq!(
+                            lane.to_bits(),
+                            expected.bits,
+                            "in {}",
+                            context.spanned(span)
+                        )
+                    }
+                }
+            }
+        }
+        V128Pattern::F64x2(expected) => {
+            for (lane, expected) in bytes.chunks_exact(8).zip(expected) {
+                let lane = F64::from_bits(u64::from_le_bytes(lane.try_into().unwrap()));
+                match expected {
+                    NanPattern::CanonicalNan | NanPattern::ArithmeticNan => {
+                        assert!(lane.is_nan(), "in {}", context.spanned(span))
+                    }
+                    NanPattern::Value(expected) => {
+                        assert_eq!(
+                            lane.to_bits(),
+                            expected.bits,
+                            "in {}",
+                            context.spanned(span)
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn extract_module(quote_wat: QuoteWat) -> Option<wast::core::Module> {
     match quote_wat {
         QuoteWat::Wat(Wat::Module(module)) => Some(module),
@@ -389,6 +455,10 @@ fn value(ctx: &mut wasmi::Store<()>, value: &wast::core::WastArgCore) -> Option<
         wast::core::WastArgCore::I64(arg) => Value::I64(*arg),
         wast::core::WastArgCore::F32(arg) => Value::F32(F32::from_bits(arg.bits)),
         wast::core::WastArgCore::F64(arg) => Value::F64(F64::from_bits(arg.bits)),
+        #[cfg(feature = "simd")]
+        wast::core::WastArgCore::V128(arg) => {
+            Value::V128(V128::from_bits(u128::from_le_bytes(arg.to_le_bytes())))
+        }
         wast::core::WastArgCore::RefNull(HeapType::Func) => Value::FuncRef(FuncRef::null()),
         wast::core::WastArgCore::RefNull(HeapType::Extern) => Value::ExternRef(ExternRef::null()),
         wast::core::WastArgCore::RefExtern(value) => Value::ExternRef(ExternRef::new(ctx, *value)),